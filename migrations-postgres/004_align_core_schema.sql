@@ -0,0 +1,17 @@
+-- Migration 004: Align core tables with the SQLite schema
+-- The initial Postgres schema drifted from ./migrations. Bring the tables read
+-- by the query layer back in line so the same queries work on both backends.
+
+-- Tier is computed on demand and never stored on SQLite
+DROP INDEX IF EXISTS idx_prs_layer_tier;
+ALTER TABLE pull_requests DROP COLUMN IF EXISTS tier;
+
+ALTER TABLE economic_nodes RENAME COLUMN qualification_proof TO qualification_data;
+ALTER TABLE economic_nodes ALTER COLUMN qualification_data SET DEFAULT '{}';
+ALTER TABLE economic_nodes RENAME COLUMN last_verified TO last_verified_at;
+ALTER TABLE economic_nodes ALTER COLUMN last_verified_at DROP DEFAULT;
+ALTER TABLE economic_nodes ADD COLUMN IF NOT EXISTS verified_at TIMESTAMP;
+ALTER TABLE economic_nodes ADD COLUMN IF NOT EXISTS created_by TEXT;
+ALTER TABLE economic_nodes ADD COLUMN IF NOT EXISTS notes TEXT DEFAULT '';
+
+ALTER TABLE veto_signals RENAME COLUMN created_at TO timestamp;
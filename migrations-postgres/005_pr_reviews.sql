@@ -0,0 +1,11 @@
+-- Migration 005: Pull request reviews
+-- Tracks the latest review state submitted by each reviewer on a PR
+
+CREATE TABLE IF NOT EXISTS pr_reviews (
+  repo_name TEXT NOT NULL,
+  pr_number INTEGER NOT NULL,
+  reviewer TEXT NOT NULL,
+  state TEXT NOT NULL,
+  updated_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+  PRIMARY KEY (repo_name, pr_number, reviewer)
+);
@@ -0,0 +1,14 @@
+-- Migration 006: Manual tier overrides
+-- Records every maintainer override of a PR's auto-classified tier; the newest wins
+
+CREATE TABLE IF NOT EXISTS tier_overrides (
+  id SERIAL PRIMARY KEY,
+  repo_name TEXT NOT NULL,
+  pr_number INTEGER NOT NULL,
+  tier INTEGER NOT NULL CHECK (tier BETWEEN 1 AND 5),
+  rationale TEXT NOT NULL,
+  actor TEXT NOT NULL,
+  created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS idx_tier_overrides_pr ON tier_overrides(repo_name, pr_number);
@@ -0,0 +1,25 @@
+-- Migration 010: Key metadata
+-- Ports SQLite migration 006 so key_metadata exists on both backends
+
+CREATE TABLE IF NOT EXISTS key_metadata (
+  id SERIAL PRIMARY KEY,
+  key_id TEXT NOT NULL UNIQUE,
+  key_type TEXT NOT NULL, -- 'maintainer', 'economic_node', 'emergency', 'github_app', 'system'
+  owner TEXT NOT NULL,
+  public_key TEXT NOT NULL,
+  status TEXT NOT NULL DEFAULT 'pending', -- 'active', 'pending', 'revoked', 'expired', 'compromised'
+  created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+  expires_at TIMESTAMP NOT NULL,
+  last_used TIMESTAMP,
+  usage_count INTEGER DEFAULT 0,
+  metadata JSONB NOT NULL DEFAULT '{}'
+);
+
+CREATE INDEX IF NOT EXISTS idx_key_metadata_key_type ON key_metadata(key_type);
+CREATE INDEX IF NOT EXISTS idx_key_metadata_owner ON key_metadata(owner);
+CREATE INDEX IF NOT EXISTS idx_key_metadata_status ON key_metadata(status);
+CREATE INDEX IF NOT EXISTS idx_key_metadata_expires_at ON key_metadata(expires_at);
+CREATE INDEX IF NOT EXISTS idx_key_metadata_last_used ON key_metadata(last_used);
+CREATE INDEX IF NOT EXISTS idx_key_metadata_type_status ON key_metadata(key_type, status);
+CREATE INDEX IF NOT EXISTS idx_key_metadata_owner_status ON key_metadata(owner, status);
+CREATE INDEX IF NOT EXISTS idx_key_metadata_expires_status ON key_metadata(expires_at, status);
@@ -0,0 +1,14 @@
+-- Migration 013: Posted merge decisions
+-- The decision last posted as governance/merge-check for each PR, so a merge
+-- is judged against what GitHub was shown rather than a recomputation
+
+CREATE TABLE IF NOT EXISTS merge_decisions (
+  repo_name TEXT NOT NULL,
+  pr_number INTEGER NOT NULL,
+  head_sha TEXT NOT NULL,
+  tier INTEGER NOT NULL,
+  blocked BOOLEAN NOT NULL,
+  reasons JSONB NOT NULL DEFAULT '[]',
+  decided_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+  PRIMARY KEY (repo_name, pr_number)
+);
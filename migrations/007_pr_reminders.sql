@@ -0,0 +1,11 @@
+-- Migration 007: Signature reminders
+-- Tracks the last reminder comment posted on each PR so reminders can be rate-limited
+
+CREATE TABLE IF NOT EXISTS pr_reminders (
+  repo_name TEXT NOT NULL,
+  pr_number INTEGER NOT NULL,
+  comment_id INTEGER,
+  last_reminded_at TIMESTAMP NOT NULL,
+  reminder_count INTEGER NOT NULL DEFAULT 1,
+  PRIMARY KEY (repo_name, pr_number)
+);
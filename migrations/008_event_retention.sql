@@ -0,0 +1,17 @@
+-- Migration 008: Event retention
+-- Records anchored registries so pruning never removes events that have not
+-- yet been covered by an OpenTimestamps anchor, and allows individual events
+-- to be held for compliance.
+
+CREATE TABLE IF NOT EXISTS registry_anchors (
+  month_key TEXT PRIMARY KEY,
+  registry_hash TEXT NOT NULL,
+  registry_path TEXT NOT NULL,
+  proof_path TEXT NOT NULL,
+  covers_until TIMESTAMP NOT NULL,
+  anchored_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP
+);
+
+ALTER TABLE governance_events ADD COLUMN retain_for_compliance BOOLEAN DEFAULT false;
+
+CREATE INDEX IF NOT EXISTS idx_registry_anchors_covers_until ON registry_anchors(covers_until DESC);
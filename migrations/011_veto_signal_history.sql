@@ -0,0 +1,18 @@
+-- Migration 011: Veto signal history
+-- Records every withdrawal or amendment of an economic node signal so position
+-- changes stay auditable after the live row in veto_signals is replaced
+
+CREATE TABLE IF NOT EXISTS veto_signal_history (
+  id INTEGER PRIMARY KEY AUTOINCREMENT,
+  pr_id INTEGER NOT NULL,
+  node_id INTEGER NOT NULL,
+  action TEXT NOT NULL CHECK (action IN ('withdrawn', 'amended')),
+  previous_signal_type TEXT NOT NULL,
+  new_signal_type TEXT, -- NULL for withdrawals
+  signature TEXT NOT NULL, -- Node signature over the change
+  rationale TEXT NOT NULL,
+  changed_at TIMESTAMP NOT NULL,
+  FOREIGN KEY (node_id) REFERENCES economic_nodes(id)
+);
+
+CREATE INDEX IF NOT EXISTS idx_veto_signal_history_pr ON veto_signal_history(pr_id);
@@ -0,0 +1,6 @@
+-- Migration 012: Fork decision type
+-- Distinguishes adopting decisions from rejections so only a node's latest
+-- adopting decision counts towards a ruleset's adoption
+
+ALTER TABLE fork_decisions ADD COLUMN decision TEXT NOT NULL DEFAULT 'adopt'
+  CHECK (decision IN ('adopt', 'support', 'reject', 'abandon'));
@@ -0,0 +1,10 @@
+-- Migration 013: Server revocation list
+-- A revoked server fails authorization even if its registry record is still active
+
+CREATE TABLE IF NOT EXISTS revoked_servers (
+  id INTEGER PRIMARY KEY AUTOINCREMENT,
+  server_id TEXT NOT NULL UNIQUE,
+  reason TEXT NOT NULL,
+  signed_by TEXT NOT NULL, -- JSON array of the operator server ids that approved
+  revoked_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP
+);
@@ -0,0 +1,11 @@
+-- Migration 014: Webhook delivery deduplication
+-- GitHub redelivers webhooks on timeout; processed X-GitHub-Delivery ids are
+-- remembered so a redelivery is acknowledged without running side effects twice
+
+CREATE TABLE IF NOT EXISTS webhook_deliveries (
+  delivery_id TEXT PRIMARY KEY,
+  event_name TEXT NOT NULL,
+  received_at TIMESTAMP NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_webhook_deliveries_received_at ON webhook_deliveries(received_at);
@@ -0,0 +1,17 @@
+-- Migration 015: Key rotation approvals
+-- Rotations under a policy that requires approval wait here until enough
+-- maintainers have signed the rotation request
+
+CREATE TABLE IF NOT EXISTS key_rotations (
+  rotation_id TEXT PRIMARY KEY,
+  key_id TEXT NOT NULL,
+  new_owner TEXT,
+  status TEXT NOT NULL DEFAULT 'pending', -- 'pending', 'completed'
+  approval_threshold INTEGER NOT NULL,
+  approvals TEXT NOT NULL DEFAULT '[]',
+  new_key_id TEXT,
+  requested_at TIMESTAMP NOT NULL,
+  completed_at TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS idx_key_rotations_key_status ON key_rotations(key_id, status);
@@ -0,0 +1,13 @@
+-- Migration 016: Maintainer change sequence
+-- Approvals for a maintainer set change sign the next sequence number. It is
+-- advanced in the same transaction as the change itself instead of being
+-- counted from governance_events, which retention may prune
+
+CREATE TABLE IF NOT EXISTS maintainer_change_sequence (
+  id INTEGER PRIMARY KEY CHECK (id = 1),
+  sequence BIGINT NOT NULL
+);
+
+INSERT INTO maintainer_change_sequence (id, sequence)
+SELECT 1, COUNT(*) FROM governance_events
+WHERE event_type IN ('maintainer_added', 'maintainer_removed');
@@ -0,0 +1,6 @@
+-- Migration 018: Drop the tier override table
+-- Manual overrides are read from the tier_override / tier_override_cleared
+-- governance events, which every override was already recorded as
+
+DROP INDEX IF EXISTS idx_tier_overrides_pr;
+DROP TABLE IF EXISTS tier_overrides;
@@ -126,7 +126,7 @@ pub fn create_genesis_entry(server_id: String) -> AuditLogEntry {
 
 /// Job execution wrapper for audit logging
 pub async fn execute_with_audit<F, T, E>(
-    logger: &mut AuditLogger,
+    logger: &AuditLogger,
     job_type: &str,
     server_id: &str,
     inputs: &[u8],
@@ -145,9 +145,6 @@ where
     hasher.update(inputs);
     let inputs_hash = format!("sha256:{}", hex::encode(hasher.finalize()));
 
-    // Get previous log hash
-    let previous_log_hash = logger.get_head_hash().await;
-
     // Execute job
     let result = job().map_err(|e| anyhow!("Job execution failed: {}", e))?;
 
@@ -163,19 +160,17 @@ where
     metadata.insert("execution_time".to_string(), Utc::now().to_rfc3339());
     metadata.insert("success".to_string(), "true".to_string());
 
-    // Create audit entry
-    let entry = AuditLogEntry::new(
-        job_id,
-        job_type.to_string(),
-        server_id.to_string(),
-        inputs_hash,
-        outputs_hash,
-        previous_log_hash,
-        metadata,
-    );
-
-    // Append to log
-    logger.append_entry(entry).await?;
+    // Append to log, linked to whatever the head is at write time
+    logger
+        .append(
+            job_id,
+            job_type.to_string(),
+            server_id.to_string(),
+            inputs_hash,
+            outputs_hash,
+            metadata,
+        )
+        .await?;
 
     Ok(result)
 }
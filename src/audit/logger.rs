@@ -6,6 +6,7 @@
 use anyhow::{anyhow, Result};
 use std::fs::{File, OpenOptions};
 use std::io::{BufRead, BufReader, Write};
+use std::collections::HashMap;
 use std::path::Path;
 use std::sync::Arc;
 use tokio::sync::Mutex;
@@ -13,91 +14,252 @@ use tracing::{debug, error, info, warn};
 
 use crate::audit::entry::AuditLogEntry;
 
+/// Job type of the entry that opens a rotated log file
+pub const ROTATION_JOB_TYPE: &str = "log_rotation";
+
+/// Metadata key naming the file a rotation entry continues from
+pub const PREVIOUS_LOG_FILE_KEY: &str = "previous_log_file";
+
 /// Audit logger managing append-only JSONL file
+///
+/// Clones share one file handle and chain head, so the logger can be handed
+/// to request handlers and background tasks alike.
 #[derive(Clone)]
 pub struct AuditLogger {
     log_path: String,
-    file: Arc<Mutex<Option<File>>>,
-    head_hash: Arc<Mutex<String>>,
-    entry_count: Arc<Mutex<u64>>,
+    max_file_size: Option<u64>,
+    state: Arc<Mutex<LoggerState>>,
+}
+
+/// Mutable logger state, guarded as a unit so a write and the head update
+/// it causes can never be observed separately
+struct LoggerState {
+    file: Option<File>,
+    head_hash: String,
+    entry_count: u64,
 }
 
 impl AuditLogger {
     /// Create new audit logger
-    pub fn new(log_path: String) -> Result<Self> {
+    ///
+    /// An existing log is replayed to restore the head hash; a new or empty
+    /// log starts with a genesis entry.
+    pub async fn new(log_path: String) -> Result<Self> {
         // Ensure directory exists
         if let Some(parent) = Path::new(&log_path).parent() {
             std::fs::create_dir_all(parent)
                 .map_err(|e| anyhow!("Failed to create log directory: {}", e))?;
         }
 
-        // Open file for appending
-        let file = OpenOptions::new()
-            .create(true)
-            .append(true)
-            .open(&log_path)
-            .map_err(|e| anyhow!("Failed to open audit log file: {}", e))?;
+        let file = Self::open_log_file(&log_path)?;
 
         let logger = Self {
             log_path,
-            file: Arc::new(Mutex::new(Some(file))),
-            head_hash: Arc::new(Mutex::new(String::new())),
-            entry_count: Arc::new(Mutex::new(0)),
+            max_file_size: None,
+            state: Arc::new(Mutex::new(LoggerState {
+                file: Some(file),
+                head_hash: String::new(),
+                entry_count: 0,
+            })),
         };
 
+        logger.load_existing_entries().await?;
         Ok(logger)
     }
 
+    /// Rotate automatically once the log file reaches `max_file_size` bytes
+    pub fn with_max_file_size(mut self, max_file_size: u64) -> Self {
+        self.max_file_size = Some(max_file_size);
+        self
+    }
+
+    fn open_log_file(log_path: &str) -> Result<File> {
+        OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(log_path)
+            .map_err(|e| anyhow!("Failed to open audit log file: {}", e))
+    }
+
     /// Append new entry to audit log
+    ///
+    /// The entry must extend the current head. Callers racing with other
+    /// writers should prefer [`AuditLogger::append`], which links the entry
+    /// to the head while holding the lock.
     pub async fn append_entry(&self, entry: AuditLogEntry) -> Result<()> {
+        let mut state = self.state.lock().await;
+        self.write_entry(&mut state, &entry)?;
+        self.rotate_if_oversized(&mut state, &entry)
+    }
+
+    /// Build an entry on top of the current head and append it atomically
+    pub async fn append(
+        &self,
+        job_id: String,
+        job_type: String,
+        server_id: String,
+        inputs_hash: String,
+        outputs_hash: String,
+        metadata: HashMap<String, String>,
+    ) -> Result<AuditLogEntry> {
+        let mut state = self.state.lock().await;
+        let entry = AuditLogEntry::new(
+            job_id,
+            job_type,
+            server_id,
+            inputs_hash,
+            outputs_hash,
+            state.head_hash.clone(),
+            metadata,
+        );
+        self.write_entry(&mut state, &entry)?;
+        self.rotate_if_oversized(&mut state, &entry)?;
+        Ok(entry)
+    }
+
+    /// Write one line and advance the head; caller holds the state lock
+    fn write_entry(&self, state: &mut LoggerState, entry: &AuditLogEntry) -> Result<()> {
         // Verify entry hash
         if !entry.verify_hash() {
             return Err(anyhow!("Invalid entry hash"));
         }
 
-        // Serialize entry to JSON
-        let json = serde_json::to_string(&entry)
+        if !state.head_hash.is_empty() && entry.previous_log_hash != state.head_hash {
+            return Err(anyhow!(
+                "Entry {} does not extend the chain head: expected {}, got {}",
+                entry.job_id,
+                state.head_hash,
+                entry.previous_log_hash
+            ));
+        }
+
+        // Serialize entry to JSON, newline included, so it goes out in one write
+        let mut line = serde_json::to_string(entry)
             .map_err(|e| anyhow!("Failed to serialize entry: {}", e))?;
+        line.push('\n');
 
-        // Write to file
-        if let Some(mut file) = self.file.lock().await.as_mut() {
-            writeln!(file, "{}", json)
-                .map_err(|e| anyhow!("Failed to write to audit log: {}", e))?;
-            file.flush()
-                .map_err(|e| anyhow!("Failed to flush audit log: {}", e))?;
-        } else {
-            return Err(anyhow!("Audit log file not available"));
-        }
+        let file = state
+            .file
+            .as_mut()
+            .ok_or_else(|| anyhow!("Audit log file not available"))?;
+        file.write_all(line.as_bytes())
+            .map_err(|e| anyhow!("Failed to write to audit log: {}", e))?;
+        file.flush()
+            .map_err(|e| anyhow!("Failed to flush audit log: {}", e))?;
 
-        // Update head hash and count
-        {
-            let mut head_hash = self.head_hash.lock().await;
-            *head_hash = entry.this_log_hash.clone();
+        state.head_hash = entry.this_log_hash.clone();
+        state.entry_count += 1;
+
+        debug!("Appended audit entry: {}", entry.summary());
+        Ok(())
+    }
+
+    fn rotate_if_oversized(&self, state: &mut LoggerState, entry: &AuditLogEntry) -> Result<()> {
+        let Some(max_file_size) = self.max_file_size else {
+            return Ok(());
+        };
+        // Never rotate on the linking entry itself, or an undersized limit would loop
+        if entry.job_type == ROTATION_JOB_TYPE {
+            return Ok(());
         }
 
-        {
-            let mut count = self.entry_count.lock().await;
-            *count += 1;
+        let file_size = state
+            .file
+            .as_ref()
+            .and_then(|f| f.metadata().ok())
+            .map(|m| m.len())
+            .unwrap_or(0);
+        if file_size >= max_file_size {
+            info!(
+                "Audit log {} reached {} bytes, rotating",
+                self.log_path, file_size
+            );
+            self.rotate_locked(state, &entry.server_id)?;
         }
+        Ok(())
+    }
 
-        debug!("Appended audit entry: {}", entry.summary());
+    /// Wait for any in-progress append and sync the log file to disk
+    pub async fn flush(&self) -> Result<()> {
+        let state = self.state.lock().await;
+        if let Some(file) = state.file.as_ref() {
+            file.sync_all()
+                .map_err(|e| anyhow!("Failed to sync audit log: {}", e))?;
+        }
         Ok(())
     }
 
+    /// Close the current log file and continue the chain in a fresh one
+    ///
+    /// The current file is renamed with a UTC timestamp suffix, and the new
+    /// file opens with a `log_rotation` entry whose `previous_log_hash` is
+    /// the final hash of the rotated file. Returns the rotated file's path.
+    pub async fn rotate(&self, server_id: &str) -> Result<String> {
+        let mut state = self.state.lock().await;
+        self.rotate_locked(&mut state, server_id)
+    }
+
+    fn rotate_locked(&self, state: &mut LoggerState, server_id: &str) -> Result<String> {
+        if let Some(current) = state.file.as_mut() {
+            current
+                .flush()
+                .map_err(|e| anyhow!("Failed to flush audit log before rotation: {}", e))?;
+        }
+        state.file = None;
+
+        let stamp = chrono::Utc::now().format("%Y%m%dT%H%M%S%.3fZ").to_string();
+        let mut rotated_path = format!("{}.{}", self.log_path, stamp);
+        let mut suffix = 1;
+        while Path::new(&rotated_path).exists() {
+            rotated_path = format!("{}.{}-{}", self.log_path, stamp, suffix);
+            suffix += 1;
+        }
+        std::fs::rename(&self.log_path, &rotated_path)
+            .map_err(|e| anyhow!("Failed to rename audit log for rotation: {}", e))?;
+
+        state.file = Some(Self::open_log_file(&self.log_path)?);
+        let previous_count = std::mem::replace(&mut state.entry_count, 0);
+
+        let rotated_name = Path::new(&rotated_path)
+            .file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_else(|| rotated_path.clone());
+
+        let mut metadata = HashMap::new();
+        metadata.insert(PREVIOUS_LOG_FILE_KEY.to_string(), rotated_name);
+        metadata.insert("previous_entry_count".to_string(), previous_count.to_string());
+
+        let previous_hash = state.head_hash.clone();
+        let link = AuditLogEntry::new(
+            format!("{}-{}", ROTATION_JOB_TYPE, chrono::Utc::now().timestamp_millis()),
+            ROTATION_JOB_TYPE.to_string(),
+            server_id.to_string(),
+            previous_hash.clone(),
+            previous_hash.clone(),
+            previous_hash,
+            metadata,
+        );
+        self.write_entry(state, &link)?;
+
+        info!("Rotated audit log {} to {}", self.log_path, rotated_path);
+        Ok(rotated_path)
+    }
+
     /// Get current head hash
     pub async fn get_head_hash(&self) -> String {
-        self.head_hash.lock().await.clone()
+        self.state.lock().await.head_hash.clone()
     }
 
     /// Get entry count
     pub async fn get_entry_count(&self) -> u64 {
-        *self.entry_count.lock().await
+        self.state.lock().await.entry_count
     }
 
     /// Load existing entries to initialize head hash and count
     async fn load_existing_entries(&self) -> Result<()> {
         let path = Path::new(&self.log_path);
-        if !path.exists() {
+        let is_empty = std::fs::metadata(path).map(|m| m.len() == 0).unwrap_or(true);
+        if is_empty {
             // Create genesis entry for new log
             let genesis = crate::audit::entry::create_genesis_entry("governance-01".to_string());
             self.append_entry(genesis).await?;
@@ -135,13 +297,9 @@ impl AuditLogger {
 
         // Update state
         {
-            let mut head_hash = self.head_hash.lock().await;
-            *head_hash = last_hash;
-        }
-
-        {
-            let mut entry_count = self.entry_count.lock().await;
-            *entry_count = count;
+            let mut state = self.state.lock().await;
+            state.head_hash = last_hash;
+            state.entry_count = count;
         }
 
         info!("Loaded {} existing audit entries", count);
@@ -206,7 +364,7 @@ impl AuditLogger {
 
     /// Close the audit logger
     pub async fn close(&self) -> Result<()> {
-        if let Some(mut file) = self.file.lock().await.as_mut() {
+        if let Some(file) = self.state.lock().await.file.as_mut() {
             file.flush()
                 .map_err(|e| anyhow!("Failed to flush audit log on close: {}", e))?;
         }
@@ -287,4 +445,65 @@ mod tests {
             assert_eq!(entries[i].previous_log_hash, entries[i-1].this_log_hash);
         }
     }
+
+    #[tokio::test]
+    async fn test_concurrent_appends_keep_chain_consistent() {
+        let temp_dir = tempdir().unwrap();
+        let log_path = temp_dir.path().join("audit.log").to_string_lossy().to_string();
+        let logger = AuditLogger::new(log_path.clone()).await.unwrap();
+
+        let mut handles = Vec::new();
+        for task in 0..8 {
+            let logger = logger.clone();
+            handles.push(tokio::spawn(async move {
+                for i in 0..25 {
+                    let mut metadata = HashMap::new();
+                    metadata.insert("payload".to_string(), "x".repeat(512));
+                    logger
+                        .append(
+                            format!("task-{}-{}", task, i),
+                            "test_type".to_string(),
+                            "governance-01".to_string(),
+                            "sha256:input".to_string(),
+                            "sha256:output".to_string(),
+                            metadata,
+                        )
+                        .await
+                        .unwrap();
+                }
+            }));
+        }
+        for handle in handles {
+            handle.await.unwrap();
+        }
+
+        assert_eq!(logger.get_entry_count().await, 201); // Genesis + 8 * 25
+        // Every line parses, so no writes interleaved
+        let entries = crate::audit::load_audit_log_from_file(&log_path).unwrap();
+        assert_eq!(entries.len(), 201);
+        assert!(crate::audit::verify_audit_log(&entries).unwrap());
+        assert_eq!(entries.last().unwrap().this_log_hash, logger.get_head_hash().await);
+    }
+
+    #[tokio::test]
+    async fn test_stale_entry_is_rejected() {
+        let temp_dir = tempdir().unwrap();
+        let log_path = temp_dir.path().join("audit.log").to_string_lossy().to_string();
+        let logger = AuditLogger::new(log_path).await.unwrap();
+
+        let stale_head = logger.get_head_hash().await;
+        let entry = |id: &str| AuditLogEntry::new(
+            id.to_string(),
+            "test_type".to_string(),
+            "governance-01".to_string(),
+            "sha256:input".to_string(),
+            "sha256:output".to_string(),
+            stale_head.clone(),
+            HashMap::new(),
+        );
+
+        logger.append_entry(entry("first")).await.unwrap();
+        assert!(logger.append_entry(entry("second")).await.is_err());
+        assert_eq!(logger.get_entry_count().await, 2);
+    }
 }
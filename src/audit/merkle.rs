@@ -4,6 +4,7 @@
 //! to enable efficient anchoring of large audit logs to Bitcoin.
 
 use anyhow::{anyhow, Result};
+use serde::{Deserialize, Serialize};
 use sha2::{Digest, Sha256};
 use std::collections::VecDeque;
 use tracing::{debug, info};
@@ -21,8 +22,13 @@ pub struct MerkleNode {
 impl MerkleNode {
     /// Create leaf node from audit entry
     pub fn leaf(entry: &AuditLogEntry) -> Self {
+        Self::from_hash(entry.this_log_hash.clone())
+    }
+
+    /// Create leaf node from an already computed hash
+    pub fn from_hash(hash: String) -> Self {
         Self {
-            hash: entry.this_log_hash.clone(),
+            hash,
             left: None,
             right: None,
         }
@@ -30,13 +36,8 @@ impl MerkleNode {
 
     /// Create internal node from two child nodes
     pub fn internal(left: MerkleNode, right: MerkleNode) -> Self {
-        let combined = format!("{}{}", left.hash, right.hash);
-        let mut hasher = Sha256::new();
-        hasher.update(combined.as_bytes());
-        let hash = format!("sha256:{}", hex::encode(hasher.finalize()));
-
         Self {
-            hash,
+            hash: hash_pair(&left.hash, &right.hash),
             left: Some(Box::new(left)),
             right: Some(Box::new(right)),
         }
@@ -45,19 +46,20 @@ impl MerkleNode {
     /// Create single child node (for odd number of entries)
     pub fn single_child(child: MerkleNode) -> Self {
         // For odd number of entries, duplicate the last entry
-        let combined = format!("{}{}", child.hash, child.hash);
-        let mut hasher = Sha256::new();
-        hasher.update(combined.as_bytes());
-        let hash = format!("sha256:{}", hex::encode(hasher.finalize()));
-
         Self {
-            hash,
+            hash: hash_pair(&child.hash, &child.hash),
             left: Some(Box::new(child.clone())),
             right: Some(Box::new(child)),
         }
     }
 }
 
+fn hash_pair(left: &str, right: &str) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(format!("{}{}", left, right).as_bytes());
+    format!("sha256:{}", hex::encode(hasher.finalize()))
+}
+
 /// Build Merkle tree from audit log entries
 pub fn build_merkle_tree(entries: &[AuditLogEntry]) -> Result<MerkleNode> {
     if entries.is_empty() {
@@ -65,11 +67,20 @@ pub fn build_merkle_tree(entries: &[AuditLogEntry]) -> Result<MerkleNode> {
     }
 
     info!("Building Merkle tree from {} entries", entries.len());
+    build_merkle_tree_from_hashes(&entry_hashes(entries))
+}
+
+/// Build Merkle tree over arbitrary leaf hashes
+pub fn build_merkle_tree_from_hashes(leaves: &[String]) -> Result<MerkleNode> {
+    if leaves.is_empty() {
+        return Err(anyhow!("Cannot build Merkle tree from empty leaves"));
+    }
 
     // Create leaf nodes
-    let mut nodes: VecDeque<MerkleNode> = entries
+    let mut nodes: VecDeque<MerkleNode> = leaves
         .iter()
-        .map(MerkleNode::leaf)
+        .cloned()
+        .map(MerkleNode::from_hash)
         .collect();
 
     // Build tree bottom-up
@@ -96,6 +107,85 @@ pub fn build_merkle_tree(entries: &[AuditLogEntry]) -> Result<MerkleNode> {
     Ok(root)
 }
 
+/// Merkle tree over leaf hashes that can change one leaf at a time
+///
+/// Keeps every level of the tree so `update_leaf` rehashes only the path from
+/// the changed leaf to the root, O(log n) instead of a full rebuild. Pairing
+/// matches `build_merkle_tree_from_hashes`, so roots agree with the batch
+/// builder for the same leaves.
+#[derive(Debug, Clone)]
+pub struct IncrementalMerkleTree {
+    /// `levels[0]` holds the leaves, the last level holds the root alone
+    levels: Vec<Vec<String>>,
+}
+
+impl IncrementalMerkleTree {
+    /// Build the tree over `leaves`
+    pub fn from_leaves(leaves: &[String]) -> Result<Self> {
+        if leaves.is_empty() {
+            return Err(anyhow!("Cannot build Merkle tree from empty leaves"));
+        }
+
+        let mut levels = vec![leaves.to_vec()];
+        while levels.last().unwrap().len() > 1 {
+            let next = levels
+                .last()
+                .unwrap()
+                .chunks(2)
+                .map(|pair| hash_pair(&pair[0], pair.get(1).unwrap_or(&pair[0])))
+                .collect();
+            levels.push(next);
+        }
+        Ok(Self { levels })
+    }
+
+    /// Build the tree over audit log entries
+    pub fn from_entries(entries: &[AuditLogEntry]) -> Result<Self> {
+        Self::from_leaves(&entry_hashes(entries))
+    }
+
+    pub fn root(&self) -> &str {
+        &self.levels.last().unwrap()[0]
+    }
+
+    pub fn len(&self) -> usize {
+        self.levels[0].len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.levels[0].is_empty()
+    }
+
+    pub fn leaf(&self, index: usize) -> Option<&str> {
+        self.levels[0].get(index).map(String::as_str)
+    }
+
+    /// Replace the leaf at `index` and return the new root
+    pub fn update_leaf(&mut self, index: usize, new_hash: String) -> Result<String> {
+        if index >= self.len() {
+            return Err(anyhow!("Leaf index out of range"));
+        }
+
+        self.levels[0][index] = new_hash;
+        let mut index = index;
+        for level in 0..self.levels.len() - 1 {
+            let left = index & !1;
+            let nodes = &self.levels[level];
+            // The last node of an odd level is paired with itself
+            let parent = hash_pair(&nodes[left], nodes.get(left + 1).unwrap_or(&nodes[left]));
+            index /= 2;
+            self.levels[level + 1][index] = parent;
+        }
+
+        debug!("Merkle tree root after updating leaf: {}", self.root());
+        Ok(self.root().to_string())
+    }
+}
+
+fn entry_hashes(entries: &[AuditLogEntry]) -> Vec<String> {
+    entries.iter().map(|e| e.this_log_hash.clone()).collect()
+}
+
 /// Get Merkle root hash
 pub fn get_merkle_root(entries: &[AuditLogEntry]) -> Result<String> {
     let tree = build_merkle_tree(entries)?;
@@ -113,39 +203,45 @@ pub fn generate_merkle_proof(entries: &[AuditLogEntry], entry_index: usize) -> R
     if entry_index >= entries.len() {
         return Err(anyhow!("Entry index out of range"));
     }
+    generate_merkle_proof_from_hashes(&entry_hashes(entries), entry_index)
+}
+
+/// Generate Merkle proof for the leaf at `leaf_index`
+///
+/// Walks the same pairing as `build_merkle_tree_from_hashes` from the leaf
+/// up, so proof hashes are ordered leaf to root.
+pub fn generate_merkle_proof_from_hashes(leaves: &[String], leaf_index: usize) -> Result<MerkleProof> {
+    if leaf_index >= leaves.len() {
+        return Err(anyhow!("Leaf index out of range"));
+    }
 
-    let tree = build_merkle_tree(entries)?;
-    let mut proof = Vec::new();
-    let mut current_index = entry_index;
-    let mut current_level_size = entries.len();
-
-    // Traverse tree to find path to leaf
-    let mut current_node = &tree;
-    while current_node.left.is_some() && current_node.right.is_some() {
-        let left_size = (current_level_size + 1) / 2;
-        
-        if current_index < left_size {
-            // Entry is in left subtree
-            if let Some(right) = &current_node.right {
-                proof.push(right.hash.clone());
-            }
-            current_node = current_node.left.as_ref().unwrap();
-            current_level_size = left_size;
+    let mut level = leaves.to_vec();
+    let mut index = leaf_index;
+    let mut proof_hashes = Vec::new();
+    let mut sibling_on_left = Vec::new();
+
+    while level.len() > 1 {
+        let sibling = if index % 2 == 1 {
+            &level[index - 1]
         } else {
-            // Entry is in right subtree
-            if let Some(left) = &current_node.left {
-                proof.push(left.hash.clone());
-            }
-            current_node = current_node.right.as_ref().unwrap();
-            current_index -= left_size;
-            current_level_size = current_level_size - left_size;
-        }
+            // The last node of an odd level is paired with itself
+            level.get(index + 1).unwrap_or(&level[index])
+        };
+        proof_hashes.push(sibling.clone());
+        sibling_on_left.push(index % 2 == 1);
+
+        level = level
+            .chunks(2)
+            .map(|pair| hash_pair(&pair[0], pair.get(1).unwrap_or(&pair[0])))
+            .collect();
+        index /= 2;
     }
 
     Ok(MerkleProof {
-        leaf_hash: entries[entry_index].this_log_hash.clone(),
-        proof_hashes: proof,
-        root_hash: tree.hash,
+        leaf_hash: leaves[leaf_index].clone(),
+        proof_hashes,
+        sibling_on_left,
+        root_hash: level.remove(0),
     })
 }
 
@@ -153,22 +249,26 @@ pub fn generate_merkle_proof(entries: &[AuditLogEntry], entry_index: usize) -> R
 pub fn verify_merkle_proof(proof: &MerkleProof, leaf_hash: &str, root_hash: &str) -> bool {
     let mut current_hash = leaf_hash.to_string();
 
-    for proof_hash in &proof.proof_hashes {
-        // Combine with proof hash (order depends on tree structure)
-        let combined = format!("{}{}", current_hash, proof_hash);
-        let mut hasher = Sha256::new();
-        hasher.update(combined.as_bytes());
-        current_hash = format!("sha256:{}", hex::encode(hasher.finalize()));
+    for (i, proof_hash) in proof.proof_hashes.iter().enumerate() {
+        current_hash = if proof.sibling_on_left.get(i).copied().unwrap_or(false) {
+            hash_pair(proof_hash, &current_hash)
+        } else {
+            hash_pair(&current_hash, proof_hash)
+        };
     }
 
     current_hash == root_hash
 }
 
 /// Merkle proof structure
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MerkleProof {
     pub leaf_hash: String,
+    /// Sibling hashes from the leaf up to the root
     pub proof_hashes: Vec<String>,
+    /// Whether each sibling is the left operand when hashing the pair
+    #[serde(default)]
+    pub sibling_on_left: Vec<bool>,
     pub root_hash: String,
 }
 
@@ -309,6 +409,46 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_merkle_proof_for_every_leaf_of_odd_tree() {
+        let entries = create_test_entries(5);
+        let root = get_merkle_root(&entries).unwrap();
+
+        for index in 0..entries.len() {
+            let proof = generate_merkle_proof(&entries, index).unwrap();
+            assert_eq!(proof.root_hash, root);
+            assert!(proof.verify(), "proof for leaf {} failed", index);
+            assert!(!verify_merkle_proof(&proof, &entries[(index + 1) % 5].this_log_hash, &root));
+        }
+    }
+
+    #[test]
+    fn test_incremental_update_matches_full_rebuild() {
+        let mut leaves: Vec<String> = create_test_entries(7)
+            .iter()
+            .map(|e| e.this_log_hash.clone())
+            .collect();
+        let mut tree = IncrementalMerkleTree::from_leaves(&leaves).unwrap();
+        assert_eq!(tree.root(), build_merkle_tree_from_hashes(&leaves).unwrap().hash);
+
+        // Both an inner leaf and the unpaired last leaf of the odd tree
+        for (index, hash) in [(2, "sha256:changed"), (6, "sha256:last")] {
+            leaves[index] = hash.to_string();
+            let root = tree.update_leaf(index, hash.to_string()).unwrap();
+            assert_eq!(root, build_merkle_tree_from_hashes(&leaves).unwrap().hash);
+            assert_eq!(tree.leaf(index), Some(hash));
+        }
+
+        assert!(tree.update_leaf(7, "sha256:missing".to_string()).is_err());
+    }
+
+    #[test]
+    fn test_incremental_single_leaf_tree() {
+        let mut tree = IncrementalMerkleTree::from_leaves(&["sha256:only".to_string()]).unwrap();
+        assert_eq!(tree.root(), "sha256:only");
+        assert_eq!(tree.update_leaf(0, "sha256:new".to_string()).unwrap(), "sha256:new");
+    }
+
     #[test]
     fn test_monthly_merkle_root() {
         let entries = create_test_entries(10);
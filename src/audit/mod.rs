@@ -10,5 +10,5 @@ pub mod merkle;
 
 pub use entry::AuditLogEntry;
 pub use logger::AuditLogger;
-pub use verify::{verify_audit_log, verify_audit_log_file, load_audit_log_from_file};
-pub use merkle::{build_merkle_tree, verify_merkle_root};
+pub use verify::{verify_audit_log, verify_audit_log_file, load_audit_log_from_file, find_first_fault, ChainFault};
+pub use merkle::{build_merkle_tree, verify_merkle_root, get_merkle_root};
@@ -9,6 +9,7 @@ use std::path::Path;
 use tracing::{debug, info, warn};
 
 use crate::audit::entry::AuditLogEntry;
+use crate::audit::logger::{PREVIOUS_LOG_FILE_KEY, ROTATION_JOB_TYPE};
 
 /// Verify complete audit log hash chain
 pub fn verify_audit_log(entries: &[AuditLogEntry]) -> Result<bool> {
@@ -16,10 +17,10 @@ pub fn verify_audit_log(entries: &[AuditLogEntry]) -> Result<bool> {
         return Err(anyhow!("Empty audit log"));
     }
 
-    // Check first entry (should be genesis)
+    // Check first entry (genesis, or the link entry of a rotated log)
     let first_entry = &entries[0];
-    if first_entry.job_type != "genesis" {
-        return Err(anyhow!("First entry must be genesis"));
+    if first_entry.job_type != "genesis" && first_entry.job_type != ROTATION_JOB_TYPE {
+        return Err(anyhow!("First entry must be genesis or a log rotation"));
     }
 
     // Verify each entry's hash
@@ -94,7 +95,38 @@ pub fn verify_audit_log_file(path: &str) -> Result<bool> {
     }
 
     let entries = load_audit_log_from_file(path)?;
-    verify_audit_log(&entries)
+    verify_audit_log(&entries)?;
+
+    // A rotated log continues the chain of the file it names
+    let first_entry = &entries[0];
+    if first_entry.job_type == ROTATION_JOB_TYPE {
+        let previous_name = first_entry
+            .metadata
+            .get(PREVIOUS_LOG_FILE_KEY)
+            .ok_or_else(|| anyhow!("Rotation entry in {} does not name the previous log", path))?;
+        let previous_path = Path::new(path)
+            .parent()
+            .unwrap_or_else(|| Path::new(""))
+            .join(previous_name);
+        let previous_path = previous_path.to_string_lossy();
+
+        let previous_entries = load_audit_log_from_file(&previous_path)?;
+        let previous_head = previous_entries
+            .last()
+            .ok_or_else(|| anyhow!("Previous audit log {} is empty", previous_path))?;
+        if first_entry.previous_log_hash != previous_head.this_log_hash {
+            return Err(anyhow!(
+                "Hash chain broken across rotation from {}: expected {}, got {}",
+                previous_path,
+                previous_head.this_log_hash,
+                first_entry.previous_log_hash
+            ));
+        }
+
+        verify_audit_log_file(&previous_path)?;
+    }
+
+    Ok(true)
 }
 
 /// Verify audit log file and return detailed results
@@ -271,6 +303,45 @@ pub fn detect_gaps(entries: &[AuditLogEntry]) -> Vec<GapInfo> {
     gaps
 }
 
+/// First point at which an audit log stops verifying
+#[derive(Debug, Clone, PartialEq)]
+pub enum ChainFault {
+    /// Entry's stored hash doesn't match its contents
+    InvalidHash { index: usize },
+    /// Entry doesn't point at its predecessor's hash
+    BrokenLink {
+        index: usize,
+        expected: String,
+        actual: String,
+    },
+}
+
+impl ChainFault {
+    /// Index of the offending entry
+    pub fn index(&self) -> usize {
+        match self {
+            ChainFault::InvalidHash { index } | ChainFault::BrokenLink { index, .. } => *index,
+        }
+    }
+}
+
+/// Walk the log in order and report the first invalid hash or broken link
+pub fn find_first_fault(entries: &[AuditLogEntry]) -> Option<ChainFault> {
+    for (i, entry) in entries.iter().enumerate() {
+        if i > 0 && entry.previous_log_hash != entries[i - 1].this_log_hash {
+            return Some(ChainFault::BrokenLink {
+                index: i,
+                expected: entries[i - 1].this_log_hash.clone(),
+                actual: entry.previous_log_hash.clone(),
+            });
+        }
+        if !entry.verify_hash() {
+            return Some(ChainFault::InvalidHash { index: i });
+        }
+    }
+    None
+}
+
 /// Information about a gap in the audit log
 #[derive(Debug, Clone)]
 pub struct GapInfo {
@@ -362,4 +433,82 @@ mod tests {
         assert_eq!(tampered.len(), 1);
         assert_eq!(tampered[0], 1);
     }
+
+    #[tokio::test]
+    async fn test_chain_verifies_across_rotation() {
+        use std::fs;
+
+        let temp_dir = tempdir().unwrap();
+        let log_path = temp_dir.path().join("audit.log").to_string_lossy().to_string();
+        let logger = crate::audit::AuditLogger::new(log_path.clone()).await.unwrap();
+
+        let append = |i: usize| {
+            let logger = logger.clone();
+            async move {
+                let entry = AuditLogEntry::new(
+                    format!("job-{}", i),
+                    "test_type".to_string(),
+                    "governance-01".to_string(),
+                    format!("sha256:input{}", i),
+                    format!("sha256:output{}", i),
+                    logger.get_head_hash().await,
+                    HashMap::new(),
+                );
+                logger.append_entry(entry).await.unwrap();
+            }
+        };
+
+        for i in 0..3 {
+            append(i).await;
+        }
+        let rotated_path = logger.rotate("governance-01").await.unwrap();
+        for i in 3..6 {
+            append(i).await;
+        }
+
+        let rotated = load_audit_log_from_file(&rotated_path).unwrap();
+        let current = load_audit_log_from_file(&log_path).unwrap();
+        assert_eq!(rotated.len(), 4); // Genesis + 3
+        assert_eq!(current.len(), 4); // Rotation link + 3
+        assert_eq!(current[0].job_type, ROTATION_JOB_TYPE);
+        assert_eq!(current[0].previous_log_hash, rotated.last().unwrap().this_log_hash);
+
+        assert!(verify_audit_log_file(&log_path).unwrap());
+
+        // Tampering with the rotated file breaks verification of the live one
+        let mut tampered = rotated.clone();
+        tampered.last_mut().unwrap().this_log_hash = "sha256:tampered".to_string();
+        let lines: Vec<String> = tampered.iter().map(|e| serde_json::to_string(e).unwrap()).collect();
+        fs::write(&rotated_path, lines.join("\n") + "\n").unwrap();
+        assert!(verify_audit_log_file(&log_path).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_size_triggered_rotation() {
+        use std::fs;
+
+        let temp_dir = tempdir().unwrap();
+        let log_path = temp_dir.path().join("audit.log").to_string_lossy().to_string();
+        let logger = crate::audit::AuditLogger::new(log_path.clone())
+            .await
+            .unwrap()
+            .with_max_file_size(1024);
+
+        for i in 0..10 {
+            let entry = AuditLogEntry::new(
+                format!("job-{}", i),
+                "test_type".to_string(),
+                "governance-01".to_string(),
+                "sha256:input".to_string(),
+                "sha256:output".to_string(),
+                logger.get_head_hash().await,
+                HashMap::new(),
+            );
+            logger.append_entry(entry).await.unwrap();
+        }
+
+        let rotated_files = fs::read_dir(temp_dir.path()).unwrap().count() - 1;
+        assert!(rotated_files >= 1);
+        assert!(verify_audit_log_file(&log_path).unwrap());
+    }
 }
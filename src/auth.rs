@@ -0,0 +1,116 @@
+//! Request Authentication
+//!
+//! Shared checks for the operator-only HTTP endpoints and for GitHub's
+//! webhook signatures.
+
+use axum::http::HeaderMap;
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+use crate::error::GovernanceError;
+
+/// Header GitHub puts the HMAC-SHA256 of the webhook body in
+pub const WEBHOOK_SIGNATURE_HEADER: &str = "x-hub-signature-256";
+
+/// Whether the request carries `Authorization: Bearer <expected>`
+///
+/// The comparison takes the same time wherever the tokens differ, so the
+/// token can't be recovered byte by byte from response timings.
+pub fn bearer_token_matches(headers: &HeaderMap, expected: &str) -> bool {
+    let presented = headers
+        .get("authorization")
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.strip_prefix("Bearer "));
+    match presented {
+        Some(token) => constant_time_eq(token.as_bytes(), expected.as_bytes()),
+        None => false,
+    }
+}
+
+/// Check an operator endpoint's bearer token
+///
+/// `expected` is the configured token; without one the endpoint is disabled
+/// and answers 404, so its existence isn't advertised.
+pub fn require_bearer(headers: &HeaderMap, expected: Option<&str>) -> Result<(), GovernanceError> {
+    let expected =
+        expected.ok_or_else(|| GovernanceError::not_found("endpoint", "no token configured"))?;
+    if !bearer_token_matches(headers, expected) {
+        return Err(GovernanceError::Unauthorized(
+            "missing or invalid bearer token".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+/// Whether `X-Hub-Signature-256` is the HMAC of `body` under the webhook secret
+pub fn webhook_signature_matches(headers: &HeaderMap, body: &[u8], secret: &str) -> bool {
+    let presented = headers
+        .get(WEBHOOK_SIGNATURE_HEADER)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.strip_prefix("sha256="));
+    match presented {
+        Some(signature) => {
+            constant_time_eq(signature.as_bytes(), sign_webhook_body(body, secret).as_bytes())
+        }
+        None => false,
+    }
+}
+
+/// Hex HMAC-SHA256 of a webhook body, as GitHub computes it
+pub fn sign_webhook_body(body: &[u8], secret: &str) -> String {
+    let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_bytes()).expect("HMAC accepts any key length");
+    mac.update(body);
+    hex::encode(mac.finalize().into_bytes())
+}
+
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    a.iter().zip(b).fold(0u8, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::http::HeaderValue;
+
+    #[test]
+    fn test_bearer_token_matches() {
+        let mut headers = HeaderMap::new();
+        assert!(!bearer_token_matches(&headers, "secret"));
+
+        headers.insert("authorization", HeaderValue::from_static("Bearer secret"));
+        assert!(bearer_token_matches(&headers, "secret"));
+        assert!(!bearer_token_matches(&headers, "secret2"));
+        assert!(!bearer_token_matches(&headers, "secreT"));
+
+        headers.insert("authorization", HeaderValue::from_static("Basic secret"));
+        assert!(!bearer_token_matches(&headers, "secret"));
+    }
+
+    #[test]
+    fn test_require_bearer() {
+        let mut headers = HeaderMap::new();
+        headers.insert("authorization", HeaderValue::from_static("Bearer secret"));
+        assert!(require_bearer(&headers, Some("secret")).is_ok());
+
+        let wrong = require_bearer(&headers, Some("other")).unwrap_err();
+        assert!(matches!(wrong, GovernanceError::Unauthorized(_)));
+        let disabled = require_bearer(&headers, None).unwrap_err();
+        assert_eq!(disabled.status_code(), axum::http::StatusCode::NOT_FOUND);
+    }
+
+    #[test]
+    fn test_webhook_signature_matches() {
+        let body = br#"{"action":"opened"}"#;
+        let mut headers = HeaderMap::new();
+        assert!(!webhook_signature_matches(&headers, body, "secret"));
+
+        let signature = format!("sha256={}", sign_webhook_body(body, "secret"));
+        headers.insert(WEBHOOK_SIGNATURE_HEADER, HeaderValue::from_str(&signature).unwrap());
+        assert!(webhook_signature_matches(&headers, body, "secret"));
+        assert!(!webhook_signature_matches(&headers, body, "other-secret"));
+        assert!(!webhook_signature_matches(&headers, br#"{"action":"closed"}"#, "secret"));
+    }
+}
@@ -3,8 +3,10 @@
 //! Manages authorized governance servers and their verification
 //! to prevent unauthorized servers from masquerading as official infrastructure.
 
+pub mod revocation;
 pub mod server;
 pub mod verification;
 
 pub use server::{AuthorizedServer, ServerStatus, OperatorInfo, ServerKeys, InfrastructureInfo};
-pub use verification::verify_server_authorization;
+pub use revocation::{revoke_server, sign_revocation, OperatorSignature};
+pub use verification::{verify_server_authorization, AuthorizationChallenge};
@@ -0,0 +1,220 @@
+//! Server Revocation
+//!
+//! Lets existing operators jointly revoke a server whose key has been
+//! compromised. Revocations live in the database and are checked by
+//! `verify_server_authorization` ahead of the server's registry status.
+
+use anyhow::{anyhow, Result};
+use nostr_sdk::nostr::secp256k1::Message;
+use nostr_sdk::prelude::Keys;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::HashSet;
+use tracing::{info, warn};
+
+use crate::authorization::server::AuthorizedServer;
+use crate::authorization::verification::verify_operator_signature;
+use crate::database::models::ServerRevocation;
+use crate::database::Database;
+
+/// Distinct operator signatures needed to revoke a server
+pub const REVOCATION_THRESHOLD: usize = 2;
+
+/// An operator's approval of a revocation
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OperatorSignature {
+    /// Server id of the approving operator
+    pub server_id: String,
+    /// Hex Schnorr signature over the revocation by that server's Nostr key
+    pub signature: String,
+}
+
+/// Sign a revocation of `server_id` with an operator's Nostr keys
+pub fn sign_revocation(server_id: &str, reason: &str, keys: &Keys) -> Result<String> {
+    let signature = keys
+        .sign_schnorr(&revocation_message(server_id, reason)?)
+        .map_err(|e| anyhow!("Failed to sign revocation: {}", e))?;
+    Ok(signature.to_string())
+}
+
+/// Revoke a server once enough other active operators have signed off
+///
+/// Signatures from the server being revoked, from unknown or inactive
+/// operators, and duplicates from the same operator are not counted.
+pub async fn revoke_server(
+    database: &Database,
+    operators: &[AuthorizedServer],
+    server_id: &str,
+    reason: &str,
+    signed_by: &[OperatorSignature],
+) -> Result<ServerRevocation> {
+    if let Some(existing) = database.get_server_revocation(server_id).await? {
+        return Err(anyhow!(
+            "Server {} was already revoked at {}",
+            server_id,
+            existing.revoked_at.to_rfc3339()
+        ));
+    }
+
+    let message = revocation_message(server_id, reason)?;
+    let mut approvals = HashSet::new();
+    for approval in signed_by {
+        if approval.server_id == server_id {
+            warn!("Ignoring self-signed revocation of {}", server_id);
+            continue;
+        }
+        let Some(operator) = operators
+            .iter()
+            .find(|o| o.server_id == approval.server_id && o.is_authorized())
+        else {
+            warn!("Ignoring revocation signature from unknown or inactive operator {}", approval.server_id);
+            continue;
+        };
+        if verify_operator_signature(operator, &message, &approval.signature)? {
+            approvals.insert(operator.server_id.clone());
+        } else {
+            warn!("Invalid revocation signature from operator {}", operator.server_id);
+        }
+    }
+
+    if approvals.len() < REVOCATION_THRESHOLD {
+        return Err(anyhow!(
+            "Revocation of {} has {} valid operator signatures, {} required",
+            server_id,
+            approvals.len(),
+            REVOCATION_THRESHOLD
+        ));
+    }
+
+    let mut signed_by: Vec<String> = approvals.into_iter().collect();
+    signed_by.sort();
+    database
+        .record_server_revocation(server_id, reason, &signed_by)
+        .await?;
+    database
+        .log_governance_event(
+            "server_revoked",
+            None,
+            None,
+            None,
+            &serde_json::json!({
+                "server_id": server_id,
+                "reason": reason,
+                "signed_by": signed_by,
+            }),
+        )
+        .await?;
+    info!("Revoked server {} ({}), approved by {:?}", server_id, reason, signed_by);
+
+    database
+        .get_server_revocation(server_id)
+        .await?
+        .ok_or_else(|| anyhow!("Revocation of {} was not recorded", server_id))
+}
+
+/// Digest operators sign to approve revoking `server_id` for `reason`
+fn revocation_message(server_id: &str, reason: &str) -> Result<Message> {
+    let digest = Sha256::digest(format!("btcdecoded-server-revoke:{}:{}", server_id, reason).as_bytes());
+    Message::from_slice(&digest).map_err(|e| anyhow!("Invalid revocation digest: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::authorization::server::{InfrastructureInfo, OperatorInfo, ServerKeys};
+    use crate::authorization::verification::{verify_server_authorization, AuthorizationChallenge};
+    use nostr_sdk::prelude::ToBech32;
+
+    fn operator(server_id: &str, keys: &Keys) -> AuthorizedServer {
+        AuthorizedServer::new(
+            server_id.to_string(),
+            OperatorInfo {
+                name: server_id.to_string(),
+                jurisdiction: "United States".to_string(),
+                contact: None,
+            },
+            ServerKeys {
+                nostr_npub: keys.public_key().to_bech32().unwrap(),
+                ssh_fingerprint: "SHA256:xyz789".to_string(),
+            },
+            InfrastructureInfo {
+                vpn_ip: None,
+                github_runner: true,
+                ots_enabled: true,
+            },
+        )
+    }
+
+    fn approval(server_id: &str, keys: &Keys, target: &str, reason: &str) -> OperatorSignature {
+        OperatorSignature {
+            server_id: server_id.to_string(),
+            signature: sign_revocation(target, reason, keys).unwrap(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_revoked_server_fails_verification_with_reason() {
+        let database = Database::new_in_memory().await.unwrap();
+        let keys: Vec<Keys> = (0..3).map(|_| Keys::generate()).collect();
+        let operators = vec![
+            operator("governance-01", &keys[0]),
+            operator("governance-02", &keys[1]),
+            operator("governance-03", &keys[2]),
+        ];
+        let reason = "nsec leaked in backup";
+
+        let revocation = revoke_server(
+            &database,
+            &operators,
+            "governance-01",
+            reason,
+            &[
+                approval("governance-02", &keys[1], "governance-01", reason),
+                approval("governance-03", &keys[2], "governance-01", reason),
+            ],
+        )
+        .await
+        .unwrap();
+        assert_eq!(revocation.signed_by, vec!["governance-02", "governance-03"]);
+
+        // The registry record is untouched, but the revocation still wins
+        assert!(operators[0].is_authorized());
+        let challenge = AuthorizationChallenge::new("governance-01");
+        let signature = challenge.sign(&keys[0]).unwrap();
+        let stored = database.get_server_revocation("governance-01").await.unwrap();
+        let err = verify_server_authorization(&operators[0], &challenge, &signature, stored.as_ref())
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains(reason));
+        assert!(err.contains(&revocation.revoked_at.to_rfc3339()));
+    }
+
+    #[tokio::test]
+    async fn test_revocation_requires_operator_threshold() {
+        let database = Database::new_in_memory().await.unwrap();
+        let keys: Vec<Keys> = (0..3).map(|_| Keys::generate()).collect();
+        let operators = vec![
+            operator("governance-01", &keys[0]),
+            operator("governance-02", &keys[1]),
+            operator("governance-03", &keys[2]),
+        ];
+        let reason = "operator unreachable";
+
+        // Self-signature, a duplicate, and a signature from the wrong key don't count
+        let result = revoke_server(
+            &database,
+            &operators,
+            "governance-01",
+            reason,
+            &[
+                approval("governance-01", &keys[0], "governance-01", reason),
+                approval("governance-02", &keys[1], "governance-01", reason),
+                approval("governance-02", &keys[1], "governance-01", reason),
+                approval("governance-03", &Keys::generate(), "governance-01", reason),
+            ],
+        )
+        .await;
+        assert!(result.is_err());
+        assert!(database.get_server_revocation("governance-01").await.unwrap().is_none());
+    }
+}
@@ -4,43 +4,148 @@
 //! the authorized server registry.
 
 use anyhow::{anyhow, Result};
+use chrono::{DateTime, Duration, Utc};
+use nostr_sdk::nostr::secp256k1::schnorr::Signature;
+use nostr_sdk::nostr::secp256k1::{Message, XOnlyPublicKey};
+use nostr_sdk::nostr::SECP256K1;
+use nostr_sdk::prelude::{FromBech32, Keys};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
 use std::collections::HashMap;
+use std::str::FromStr;
 use tracing::{debug, info, warn};
 
 use crate::authorization::server::{AuthorizedServer, ServerStatus};
+use crate::database::models::ServerRevocation;
 use crate::ots::anchor::GovernanceRegistry;
 
-/// Verify if a server is authorized
+/// How long a server has to answer an authorization challenge
+pub const CHALLENGE_TTL_SECS: i64 = 300;
+
+/// Nonce a server must sign with its registered Nostr key to prove identity
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuthorizationChallenge {
+    pub server_id: String,
+    /// 32 random bytes, hex encoded
+    pub nonce: String,
+    pub issued_at: DateTime<Utc>,
+    pub expires_at: DateTime<Utc>,
+}
+
+impl AuthorizationChallenge {
+    /// Issue a fresh challenge for `server_id`
+    pub fn new(server_id: &str) -> Self {
+        let nonce: [u8; 32] = rand::random();
+        let issued_at = Utc::now();
+        Self {
+            server_id: server_id.to_string(),
+            nonce: hex::encode(nonce),
+            issued_at,
+            expires_at: issued_at + Duration::seconds(CHALLENGE_TTL_SECS),
+        }
+    }
+
+    /// Check if the response window has passed
+    pub fn is_expired(&self) -> bool {
+        Utc::now() > self.expires_at
+    }
+
+    /// Sign the challenge with the server's Nostr keys, returning a hex Schnorr signature
+    pub fn sign(&self, keys: &Keys) -> Result<String> {
+        let signature = keys
+            .sign_schnorr(&self.message()?)
+            .map_err(|e| anyhow!("Failed to sign challenge: {}", e))?;
+        Ok(signature.to_string())
+    }
+
+    /// Digest that gets signed; binds the nonce to the server it was issued for
+    fn message(&self) -> Result<Message> {
+        let digest = Sha256::digest(
+            format!("btcdecoded-server-auth:{}:{}", self.server_id, self.nonce).as_bytes(),
+        );
+        Message::from_slice(&digest).map_err(|e| anyhow!("Invalid challenge digest: {}", e))
+    }
+}
+
+/// Verify a server's response to an authorization challenge
+///
+/// The signature must be a Schnorr signature over the challenge by the
+/// server's registered `nostr_npub`, the challenge must have been issued to
+/// this server and not be expired, and the server must still be active.
+/// Returns an error for compromised or revoked servers so callers can alert
+/// on them; a revocation wins even if the registry still lists the server
+/// as active.
 pub fn verify_server_authorization(
-    server_id: &str,
-    nostr_npub: &str,
-    registry: &GovernanceRegistry,
+    server: &AuthorizedServer,
+    challenge: &AuthorizationChallenge,
+    signature: &str,
+    revocation: Option<&ServerRevocation>,
 ) -> Result<bool> {
-    debug!("Verifying server authorization: {} with npub {}", server_id, nostr_npub);
+    debug!("Verifying server authorization: {} with npub {}", server.server_id, server.keys.nostr_npub);
+
+    if let Some(revocation) = revocation {
+        warn!("Server {} was revoked at {}", server.server_id, revocation.revoked_at);
+        return Err(anyhow!(
+            "Server {} was revoked at {}: {}",
+            server.server_id,
+            revocation.revoked_at.to_rfc3339(),
+            revocation.reason
+        ));
+    }
+    if server.is_compromised() {
+        warn!("Server {} is marked as compromised", server.server_id);
+        return Err(anyhow!("Server {} is marked as compromised", server.server_id));
+    }
+    if !server.is_authorized() {
+        warn!("Server {} is not active (status: {})", server.server_id, server.status);
+        return Ok(false);
+    }
+    if challenge.server_id != server.server_id {
+        warn!(
+            "Challenge for {} answered as server {}",
+            challenge.server_id, server.server_id
+        );
+        return Ok(false);
+    }
+    if challenge.is_expired() {
+        warn!("Authorization challenge for {} expired at {}", server.server_id, challenge.expires_at);
+        return Ok(false);
+    }
 
-    // Check if server is in authorized list
-    let server = registry.authorized_servers
-        .iter()
-        .find(|s| s.server_id == server_id && s.keys.nostr_npub == nostr_npub);
+    if verify_operator_signature(server, &challenge.message()?, signature)? {
+        info!("Server {} is authorized and active", server.server_id);
+        Ok(true)
+    } else {
+        warn!("Challenge signature from {} does not match its registered key", server.server_id);
+        Ok(false)
+    }
+}
 
-    match server {
-        Some(s) if s.status == "active" => {
-            info!("Server {} is authorized and active", server_id);
-            Ok(true)
-        }
-        Some(s) if s.status == "compromised" => {
-            warn!("Server {} is marked as compromised", server_id);
-            Err(anyhow!("Server {} is marked as compromised", server_id))
-        }
-        Some(s) => {
-            warn!("Server {} is not active (status: {})", server_id, s.status);
-            Ok(false)
-        }
-        None => {
-            warn!("Server {} not found in authorized list", server_id);
-            Ok(false)
+/// Check a hex Schnorr signature over `message` against a server's registered key
+///
+/// Malformed signatures count as invalid; only an unparseable registered key
+/// is an error.
+pub(crate) fn verify_operator_signature(
+    server: &AuthorizedServer,
+    message: &Message,
+    signature: &str,
+) -> Result<bool> {
+    let public_key = parse_npub(&server.keys.nostr_npub)?;
+    let signature = match Signature::from_str(signature) {
+        Ok(signature) => signature,
+        Err(e) => {
+            warn!("Malformed signature from {}: {}", server.server_id, e);
+            return Ok(false);
         }
-    }
+    };
+    Ok(SECP256K1.verify_schnorr(&signature, message, &public_key).is_ok())
+}
+
+/// Parse a registered Nostr key, accepting either npub or hex form
+fn parse_npub(npub: &str) -> Result<XOnlyPublicKey> {
+    XOnlyPublicKey::from_bech32(npub)
+        .or_else(|_| XOnlyPublicKey::from_str(npub))
+        .map_err(|_| anyhow!("Invalid registered Nostr key: {}", npub))
 }
 
 /// Verify server authorization with detailed result
@@ -337,6 +442,7 @@ mod tests {
                 },
             ],
             audit_logs: HashMap::new(),
+            config_hashes: HashMap::new(),
             multisig_config: MultisigConfig {
                 required_signatures: 3,
                 total_maintainers: 5,
@@ -344,23 +450,69 @@ mod tests {
         }
     }
 
+    fn server_with_keys(keys: &Keys, status: ServerStatus) -> AuthorizedServer {
+        use nostr_sdk::prelude::ToBech32;
+
+        let mut server = AuthorizedServer::new(
+            "governance-01".to_string(),
+            crate::authorization::server::OperatorInfo {
+                name: "Alice".to_string(),
+                jurisdiction: "United States".to_string(),
+                contact: None,
+            },
+            crate::authorization::server::ServerKeys {
+                nostr_npub: keys.public_key().to_bech32().unwrap(),
+                ssh_fingerprint: "SHA256:xyz789".to_string(),
+            },
+            crate::authorization::server::InfrastructureInfo {
+                vpn_ip: None,
+                github_runner: true,
+                ots_enabled: true,
+            },
+        );
+        server.status = status;
+        server
+    }
+
     #[test]
     fn test_verify_server_authorization() {
-        let registry = create_test_registry();
-        
-        // Test authorized server
-        let result = verify_server_authorization("governance-01", "npub1abc123", &registry);
-        assert!(result.is_ok());
-        assert!(result.unwrap());
-
-        // Test unauthorized server
-        let result = verify_server_authorization("governance-01", "npub1wrong", &registry);
-        assert!(result.is_ok());
-        assert!(!result.unwrap());
-
-        // Test compromised server
-        let result = verify_server_authorization("governance-02", "npub1def456", &registry);
-        assert!(result.is_err());
+        let keys = Keys::generate();
+        let server = server_with_keys(&keys, ServerStatus::Active);
+        let challenge = AuthorizationChallenge::new("governance-01");
+        let signature = challenge.sign(&keys).unwrap();
+
+        assert!(verify_server_authorization(&server, &challenge, &signature, None).unwrap());
+
+        // The same signature doesn't answer a different nonce
+        let other = AuthorizationChallenge::new("governance-01");
+        assert!(!verify_server_authorization(&server, &other, &signature, None).unwrap());
+
+        // Nor a challenge that has expired
+        let mut expired = challenge.clone();
+        expired.expires_at = Utc::now() - Duration::seconds(1);
+        assert!(!verify_server_authorization(&server, &expired, &signature, None).unwrap());
+    }
+
+    #[test]
+    fn test_revoked_server_fails_authorization() {
+        let keys = Keys::generate();
+        let challenge = AuthorizationChallenge::new("governance-01");
+        let signature = challenge.sign(&keys).unwrap();
+
+        let inactive = server_with_keys(&keys, ServerStatus::Inactive);
+        assert!(!verify_server_authorization(&inactive, &challenge, &signature, None).unwrap());
+
+        let compromised = server_with_keys(&keys, ServerStatus::Compromised);
+        assert!(verify_server_authorization(&compromised, &challenge, &signature, None).is_err());
+    }
+
+    #[test]
+    fn test_signature_from_wrong_key_fails_authorization() {
+        let server = server_with_keys(&Keys::generate(), ServerStatus::Active);
+        let challenge = AuthorizationChallenge::new("governance-01");
+        let signature = challenge.sign(&Keys::generate()).unwrap();
+
+        assert!(!verify_server_authorization(&server, &challenge, &signature, None).unwrap());
     }
 
     #[test]
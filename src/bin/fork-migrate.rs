@@ -5,6 +5,10 @@
 use std::env;
 use std::fs;
 use clap::{Parser, Subcommand};
+use governance_app::fork::export::GovernanceExporter;
+use governance_app::audit::AuditLogger;
+use governance_app::database::Database;
+use governance_app::fork::{GovernanceExport, MigrationRecord, RulesetDiff, RulesetVersion, RulesetVersioning};
 use serde_json::json;
 
 #[derive(Parser)]
@@ -38,6 +42,10 @@ enum Commands {
         /// Backup current configuration
         #[arg(short, long)]
         backup: bool,
+
+        /// Allow moving to a new major version
+        #[arg(long)]
+        allow_major: bool,
     },
     /// Create a new ruleset from current configuration
     Create {
@@ -62,6 +70,10 @@ enum Commands {
         /// Second ruleset ID
         #[arg(short, long)]
         ruleset2: String,
+
+        /// Output format (text, json)
+        #[arg(long, default_value = "text")]
+        format: String,
     },
     /// Validate a ruleset
     Validate {
@@ -75,6 +87,24 @@ enum Commands {
         #[arg(short, long, default_value = "10")]
         limit: usize,
     },
+    /// Export the ruleset a running governance server is enforcing
+    ExportLive {
+        /// Server base URL
+        #[arg(short, long, default_value = "http://localhost:3000")]
+        url: String,
+
+        /// Admin token (defaults to ADMIN_TOKEN)
+        #[arg(short, long)]
+        token: Option<String>,
+
+        /// Ruleset ID to assign (defaults to the server ID)
+        #[arg(short, long)]
+        ruleset: Option<String>,
+
+        /// Version (e.g., "1.0.0")
+        #[arg(short, long)]
+        version: Option<String>,
+    },
     /// Rollback to previous ruleset
     Rollback {
         /// Target ruleset ID
@@ -97,14 +127,15 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         Commands::Current => {
             show_current_ruleset()?;
         }
-        Commands::Migrate { ruleset, force, backup } => {
-            migrate_to_ruleset(&ruleset, force, backup)?;
+        Commands::Migrate { ruleset, force, backup, allow_major } => {
+            let versioning = RulesetVersioning::new().with_major_migration(allow_major);
+            migrate_to_ruleset(&ruleset, force, backup, &versioning, "migrate")?;
         }
         Commands::Create { name, description, version } => {
             create_ruleset(&name, description, version)?;
         }
-        Commands::Compare { ruleset1, ruleset2 } => {
-            compare_rulesets(&ruleset1, &ruleset2)?;
+        Commands::Compare { ruleset1, ruleset2, format } => {
+            compare_rulesets(&ruleset1, &ruleset2, &format)?;
         }
         Commands::Validate { ruleset } => {
             validate_ruleset(&ruleset)?;
@@ -112,6 +143,9 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         Commands::History { limit } => {
             show_migration_history(limit)?;
         }
+        Commands::ExportLive { url, token, ruleset, version } => {
+            export_live_ruleset(&url, token, ruleset, version)?;
+        }
         Commands::Rollback { ruleset, force } => {
             rollback_to_ruleset(&ruleset, force)?;
         }
@@ -215,6 +249,8 @@ fn migrate_to_ruleset(
     ruleset: &str,
     force: bool,
     backup: bool,
+    versioning: &RulesetVersioning,
+    action: &str,
 ) -> Result<(), Box<dyn std::error::Error>> {
     println!("🔄 Migrating to ruleset: {}", ruleset);
     
@@ -228,8 +264,9 @@ fn migrate_to_ruleset(
     let content = fs::read_to_string(&ruleset_file)?;
     let target_ruleset: serde_json::Value = serde_json::from_str(&content)?;
     
-    // Validate the ruleset
-    validate_ruleset_content(&target_ruleset)?;
+    // Validate the ruleset, including the move from the current one
+    let current = load_current_version()?;
+    validate_ruleset_content(&target_ruleset, current.as_ref(), versioning)?;
     
     if !force {
         println!("⚠️  This will change the current governance ruleset");
@@ -257,10 +294,16 @@ fn migrate_to_ruleset(
     }
     
     // Perform migration
+    let from_ruleset = load_current_ruleset_id()?;
     fs::copy(&ruleset_file, "governance-exports/current.json")?;
     
     // Log migration
-    log_migration("migrate", ruleset, "Migration completed successfully")?;
+    let message = if action == "rollback" {
+        "Rollback completed successfully"
+    } else {
+        "Migration completed successfully"
+    };
+    log_migration(&MigrationRecord::new(action, from_ruleset.as_deref(), ruleset, "fork-migrate", message))?;
     
     println!("✅ Migration completed successfully!");
     println!("   Current ruleset: {}", ruleset);
@@ -329,8 +372,11 @@ fn create_ruleset(
 fn compare_rulesets(
     ruleset1: &str,
     ruleset2: &str,
+    format: &str,
 ) -> Result<(), Box<dyn std::error::Error>> {
-    println!("🔍 Comparing rulesets: {} vs {}", ruleset1, ruleset2);
+    if format != "text" && format != "json" {
+        return Err(format!("Invalid format: {}. Must be one of: text, json", format).into());
+    }
     
     // Load both rulesets
     let file1 = format!("governance-exports/{}.json", ruleset1);
@@ -350,46 +396,71 @@ fn compare_rulesets(
     let ruleset1_data: serde_json::Value = serde_json::from_str(&content1)?;
     let ruleset2_data: serde_json::Value = serde_json::from_str(&content2)?;
     
-    // Compare key components
-    println!("📊 Comparison results:");
-    
-    // Compare action tiers
-    compare_json_section("Action Tiers", &ruleset1_data["action_tiers"], &ruleset2_data["action_tiers"]);
-    
-    // Compare economic nodes
-    compare_json_section("Economic Nodes", &ruleset1_data["economic_nodes"], &ruleset2_data["economic_nodes"]);
-    
-    // Compare maintainers
-    compare_json_section("Maintainers", &ruleset1_data["maintainers"], &ruleset2_data["maintainers"]);
-    
-    // Compare repositories
-    compare_json_section("Repositories", &ruleset1_data["repositories"], &ruleset2_data["repositories"]);
+    let diff = RulesetDiff::between(&ruleset1_data, &ruleset2_data);
+    if format == "json" {
+        println!("{}", serde_json::to_string_pretty(&diff.to_json()?)?);
+    } else {
+        println!("🔍 Comparing rulesets: {} vs {}", ruleset1, ruleset2);
+        print!("{}", diff.render_text());
+    }
     
     Ok(())
 }
 
-fn compare_json_section(name: &str, section1: &serde_json::Value, section2: &serde_json::Value) {
-    if section1 == section2 {
-        println!("  ✅ {}: Identical", name);
-    } else {
-        println!("  ❌ {}: Different", name);
-        
-        // Show some differences
-        if let (Some(obj1), Some(obj2)) = (section1.as_object(), section2.as_object()) {
-            let keys1: std::collections::HashSet<_> = obj1.keys().collect();
-            let keys2: std::collections::HashSet<_> = obj2.keys().collect();
-            
-            let only_in_1: Vec<_> = keys1.difference(&keys2).collect();
-            let only_in_2: Vec<_> = keys2.difference(&keys1).collect();
-            
-            if !only_in_1.is_empty() {
-                println!("    Only in first: {:?}", only_in_1);
-            }
-            if !only_in_2.is_empty() {
-                println!("    Only in second: {:?}", only_in_2);
-            }
+fn export_live_ruleset(
+    url: &str,
+    token: Option<String>,
+    ruleset: Option<String>,
+    version: Option<String>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    println!("📥 Exporting live ruleset from: {}", url);
+
+    let token = match token.or_else(|| env::var("ADMIN_TOKEN").ok()) {
+        Some(token) => token,
+        None => return Err("Admin token required (--token or ADMIN_TOKEN)".into()),
+    };
+    let mut query = Vec::new();
+    if let Some(ruleset) = &ruleset {
+        query.push(("ruleset_id", ruleset.as_str()));
+    }
+    if let Some(version) = &version {
+        query.push(("version", version.as_str()));
+    }
+
+    let runtime = tokio::runtime::Runtime::new()?;
+    let export: serde_json::Value = runtime.block_on(async {
+        let response = reqwest::Client::new()
+            .get(format!("{}/governance/export", url.trim_end_matches('/')))
+            .bearer_auth(&token)
+            .query(&query)
+            .send()
+            .await?;
+        if !response.status().is_success() {
+            return Err(format!("Server returned {}", response.status()).into());
         }
+        Ok::<_, Box<dyn std::error::Error>>(response.json().await?)
+    })?;
+
+    validate_ruleset_content(&export, None, &RulesetVersioning::new())?;
+
+    let parsed: GovernanceExport = serde_json::from_value(export.clone())?;
+    if GovernanceExporter::new(".").verify_export_signature(&parsed)? {
+        println!("  ✅ Signed by {}", parsed.metadata.signer_public_key.as_deref().unwrap_or("unknown"));
+    } else {
+        println!("  ⚠️  Export is not signed by the server");
     }
+
+    let ruleset_file = format!("governance-exports/{}.json", parsed.ruleset_id);
+    fs::create_dir_all("governance-exports")?;
+    fs::write(&ruleset_file, serde_json::to_string_pretty(&export)?)?;
+
+    println!("✅ Live ruleset exported successfully!");
+    println!("   File: {}", ruleset_file);
+    println!("   ID: {}", parsed.ruleset_id);
+    println!("   Exported by: {}", parsed.metadata.exported_by);
+    println!("   Commit: {}", parsed.metadata.commit_hash);
+
+    Ok(())
 }
 
 fn validate_ruleset(ruleset: &str) -> Result<(), Box<dyn std::error::Error>> {
@@ -403,38 +474,48 @@ fn validate_ruleset(ruleset: &str) -> Result<(), Box<dyn std::error::Error>> {
     let content = fs::read_to_string(&ruleset_file)?;
     let ruleset_data: serde_json::Value = serde_json::from_str(&content)?;
     
-    validate_ruleset_content(&ruleset_data)?;
+    let current = load_current_version()?;
+    validate_ruleset_content(&ruleset_data, current.as_ref(), &RulesetVersioning::new())?;
     
     println!("✅ Ruleset validation passed!");
     Ok(())
 }
 
-fn validate_ruleset_content(ruleset: &serde_json::Value) -> Result<(), Box<dyn std::error::Error>> {
-    // Check required fields
-    let required_fields = ["ruleset_id", "ruleset_version", "action_tiers", "economic_nodes", "maintainers"];
-    
-    for field in &required_fields {
-        if !ruleset.get(field).is_some() {
-            return Err(format!("Missing required field: {}", field).into());
-        }
-    }
-    
-    // Validate version format
-    if let Some(version) = ruleset.get("ruleset_version") {
-        if let Some(major) = version.get("major") {
-            if !major.is_number() {
-                return Err("Invalid version format: major must be a number".into());
-            }
-        }
-    }
-    
-    println!("  ✅ All required fields present");
-    println!("  ✅ Version format valid");
+/// Validate a ruleset document with the same checks the server applies
+///
+/// When `current` is given, the version change from it must also be allowed.
+fn validate_ruleset_content(
+    ruleset: &serde_json::Value,
+    current: Option<&RulesetVersion>,
+    versioning: &RulesetVersioning,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let export: GovernanceExport = serde_json::from_value(ruleset.clone())
+        .map_err(|e| format!("Invalid ruleset structure: {}", e))?;
     println!("  ✅ JSON structure valid");
+    println!("  ✅ Version format valid ({})", export.ruleset_version.to_string());
+
+    governance_app::fork::validate_ruleset(&export.config(), &export.ruleset_version, current, versioning)?;
+    println!("  ✅ All required sections present");
+    if let Some(current) = current {
+        println!("  ✅ Compatible with current version {}", current.to_string());
+    }
     
     Ok(())
 }
 
+/// Version of the current ruleset, if one has been migrated to
+fn load_current_version() -> Result<Option<RulesetVersion>, Box<dyn std::error::Error>> {
+    let current_file = "governance-exports/current.json";
+    if fs::metadata(current_file).is_err() {
+        return Ok(None);
+    }
+    let current: serde_json::Value = serde_json::from_str(&fs::read_to_string(current_file)?)?;
+    match current.get("ruleset_version") {
+        Some(version) => Ok(Some(serde_json::from_value(version.clone())?)),
+        None => Ok(None),
+    }
+}
+
 fn show_migration_history(limit: usize) -> Result<(), Box<dyn std::error::Error>> {
     println!("📜 Migration history (last {} entries):", limit);
     
@@ -469,11 +550,11 @@ fn rollback_to_ruleset(
 ) -> Result<(), Box<dyn std::error::Error>> {
     println!("↩️ Rolling back to ruleset: {}", ruleset);
     
-    // This is essentially the same as migrate
-    migrate_to_ruleset(ruleset, force, true)?;
-    
-    // Log as rollback
-    log_migration("rollback", ruleset, "Rollback completed successfully")?;
+    // This is essentially the same as migrate, but going back is the point
+    let versioning = RulesetVersioning::new()
+        .with_allow_downgrade(true)
+        .with_major_migration(true);
+    migrate_to_ruleset(ruleset, force, true, &versioning, "rollback")?;
     
     println!("✅ Rollback completed successfully!");
     Ok(())
@@ -500,12 +581,44 @@ fn load_current_governance_config() -> Result<serde_json::Value, Box<dyn std::er
     Ok(serde_json::Value::Object(config))
 }
 
-fn log_migration(action: &str, ruleset: &str, message: &str) -> Result<(), Box<dyn std::error::Error>> {
+/// ID of the current ruleset, if one has been migrated to
+fn load_current_ruleset_id() -> Result<Option<String>, Box<dyn std::error::Error>> {
+    let current_file = "governance-exports/current.json";
+    if fs::metadata(current_file).is_err() {
+        return Ok(None);
+    }
+    let current: serde_json::Value = serde_json::from_str(&fs::read_to_string(current_file)?)?;
+    Ok(current.get("ruleset_id").and_then(|id| id.as_str()).map(str::to_string))
+}
+
+/// Record a migration in the governance database when DATABASE_URL is set,
+/// and always in the local JSONL history
+fn log_migration(record: &MigrationRecord) -> Result<(), Box<dyn std::error::Error>> {
+    if let Ok(database_url) = env::var("DATABASE_URL") {
+        let runtime = tokio::runtime::Runtime::new()?;
+        runtime.block_on(async {
+            let database = Database::new(&database_url).await?;
+            let audit_enabled = env::var("AUDIT_ENABLED")
+                .unwrap_or_else(|_| "true".to_string())
+                .parse()
+                .unwrap_or(true);
+            let audit_logger = match env::var("AUDIT_LOG_PATH") {
+                Ok(path) if audit_enabled => Some(AuditLogger::new(path).await?),
+                _ => None,
+            };
+            let server_id = env::var("SERVER_ID").unwrap_or_else(|_| "fork-migrate".to_string());
+            governance_app::fork::log_migration(&database, audit_logger.as_ref(), &server_id, record).await?;
+            Ok::<_, Box<dyn std::error::Error>>(())
+        })?;
+        println!("🗄️  Migration recorded in governance database");
+    }
+
     let log_entry = json!({
-        "action": action,
-        "ruleset": ruleset,
-        "timestamp": chrono::Utc::now().to_rfc3339(),
-        "message": message
+        "action": record.action,
+        "ruleset": record.to_ruleset,
+        "from_ruleset": record.from_ruleset,
+        "timestamp": record.timestamp.to_rfc3339(),
+        "message": record.message
     });
     
     let history_file = "governance-exports/migration-history.jsonl";
@@ -521,3 +634,98 @@ fn log_migration(action: &str, ruleset: &str, message: &str) -> Result<(), Box<d
     
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_signed_export_validates_as_ruleset() {
+        let config_dir = tempfile::tempdir().unwrap();
+        fs::write(
+            config_dir.path().join("action-tiers.yml"),
+            "tiers:\n  - tier: 1\n    name: Routine Maintenance\n",
+        )
+        .unwrap();
+        fs::create_dir(config_dir.path().join("maintainers")).unwrap();
+        fs::write(
+            config_dir.path().join("maintainers/layer-1-2.yml"),
+            "maintainers:\n  - github: alice\n",
+        )
+        .unwrap();
+
+        let exporter = GovernanceExporter::new(config_dir.path().to_str().unwrap());
+        let mut export = exporter
+            .export_governance_config(
+                "governance-01",
+                &RulesetVersion::new(1, 2, 0),
+                "governance-01",
+                "BTCDecoded/governance",
+                "0123abcd",
+            )
+            .await
+            .unwrap();
+        let signing_key = secp256k1::SecretKey::from_slice(&[7u8; 32]).unwrap();
+        exporter.sign_export(&mut export, &signing_key).unwrap();
+
+        let document = serde_json::to_value(&export).unwrap();
+        validate_ruleset_content(&document, None, &RulesetVersioning::new()).unwrap();
+        assert_eq!(document["metadata"]["commit_hash"], "0123abcd");
+        assert_eq!(document["metadata"]["exported_by"], "governance-01");
+        assert_eq!(document["maintainers"]["layer-1-2"]["maintainers"][0]["github"], "alice");
+
+        // The signature survives the JSON round trip the CLI makes, and covers the content
+        let mut parsed: GovernanceExport = serde_json::from_value(document).unwrap();
+        assert!(exporter.verify_export_signature(&parsed).unwrap());
+        parsed.metadata.commit_hash = "feedface".to_string();
+        assert!(!exporter.verify_export_signature(&parsed).unwrap());
+    }
+
+    #[test]
+    fn test_cli_rejects_what_the_server_rejects() {
+        let versioning = RulesetVersioning::new();
+        let document = json!({
+            "version": "1.0",
+            "ruleset_id": "governance-02",
+            "ruleset_version": { "major": 2, "minor": 0, "patch": 0 },
+            "created_at": "2025-01-01T00:00:00Z",
+            "action_tiers": {},
+            "economic_nodes": {},
+            "maintainers": null,
+            "repositories": {},
+            "governance_fork": {},
+            "metadata": {
+                "exported_by": "governance-02",
+                "source_repository": "BTCDecoded/governance",
+                "commit_hash": "0123abcd",
+                "export_tool_version": "1.0.0"
+            }
+        });
+        let export: GovernanceExport = serde_json::from_value(document.clone()).unwrap();
+
+        // A missing section
+        let server = governance_app::fork::validate_ruleset(&export.config(), &export.ruleset_version, None, &versioning);
+        let cli = validate_ruleset_content(&document, None, &versioning);
+        assert_eq!(cli.unwrap_err().to_string(), server.unwrap_err().to_string());
+
+        // An unapproved major version bump
+        let mut complete = document.clone();
+        complete["maintainers"] = json!({});
+        let current = RulesetVersion::new(1, 4, 0);
+        let server = governance_app::fork::validate_ruleset(
+            &serde_json::from_value::<GovernanceExport>(complete.clone()).unwrap().config(),
+            &export.ruleset_version,
+            Some(&current),
+            &versioning,
+        );
+        assert!(server.is_err());
+        assert!(validate_ruleset_content(&complete, Some(&current), &versioning).is_err());
+        let permissive = RulesetVersioning::new().with_major_migration(true);
+        assert!(validate_ruleset_content(&complete, Some(&current), &permissive).is_ok());
+
+        // A version the server cannot parse
+        let mut malformed = complete;
+        malformed["ruleset_version"]["major"] = json!("two");
+        assert!(validate_ruleset_content(&malformed, None, &versioning).is_err());
+    }
+}
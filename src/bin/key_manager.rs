@@ -3,7 +3,9 @@
 //! Command-line tool for managing keys in the BTCDecoded Governance System
 
 use clap::{Parser, Subcommand};
-use governance_app::crypto::key_management::{KeyManagementConfig, KeyManager, KeyStatus, KeyType};
+use governance_app::crypto::key_management::{
+    KeyManagementConfig, KeyManager, KeyStatus, KeyType, RotationOutcome,
+};
 use governance_app::database::Database;
 use std::collections::HashMap;
 
@@ -68,6 +70,20 @@ enum Commands {
         new_owner: Option<String>,
     },
 
+    /// Approve a pending key rotation
+    ApproveRotation {
+        /// Rotation ID
+        rotation_id: String,
+
+        /// Approving maintainer's GitHub username
+        #[arg(short, long)]
+        signer: String,
+
+        /// Hex signature over the rotation's signing message
+        #[arg(long)]
+        signature: String,
+    },
+
     /// Revoke a key
     Revoke {
         /// Key ID to revoke
@@ -107,7 +123,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         encryption_enabled: false,
         rotation_policies: vec![],
     };
-    let key_manager = KeyManager::new(db.pool().unwrap().clone(), config);
+    let key_manager = KeyManager::new(db.pool().unwrap().clone(), config)?;
 
     // Execute command
     match cli.command {
@@ -160,15 +176,21 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         }
 
         Commands::Rotate { key_id, new_owner } => {
-            let new_key = key_manager
+            let outcome = key_manager
                 .rotate_key(&key_id, new_owner.as_deref())
                 .await?;
+            print_rotation_outcome(&key_id, &outcome);
+        }
 
-            println!("Key rotated successfully:");
-            println!("  Old Key ID: {}", key_id);
-            println!("  New Key ID: {}", new_key.key_id);
-            println!("  Owner: {}", new_key.owner);
-            println!("  Expires: {}", new_key.expires_at);
+        Commands::ApproveRotation {
+            rotation_id,
+            signer,
+            signature,
+        } => {
+            let outcome = key_manager
+                .approve_rotation(&rotation_id, &signer, &signature)
+                .await?;
+            print_rotation_outcome(&rotation_id, &outcome);
         }
 
         Commands::Revoke { key_id, reason } => {
@@ -296,6 +318,29 @@ fn print_key_details(key: &governance_app::crypto::key_management::KeyMetadata)
     }
 }
 
+fn print_rotation_outcome(id: &str, outcome: &RotationOutcome) {
+    match outcome {
+        RotationOutcome::Completed(new_key) => {
+            println!("Key rotated successfully:");
+            println!("  Rotated: {}", id);
+            println!("  New Key ID: {}", new_key.key_id);
+            println!("  Owner: {}", new_key.owner);
+            println!("  Expires: {}", new_key.expires_at);
+        }
+        RotationOutcome::Pending(rotation) => {
+            println!("Rotation awaiting approval:");
+            println!("  Rotation ID: {}", rotation.rotation_id);
+            println!("  Key ID: {}", rotation.key_id);
+            println!(
+                "  Approvals: {}/{}",
+                rotation.approvals.len(),
+                rotation.approval_threshold
+            );
+            println!("  Sign: {}", rotation.signing_message());
+        }
+    }
+}
+
 
 
 
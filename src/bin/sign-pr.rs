@@ -9,7 +9,7 @@ use std::path::Path;
 use clap::{Parser, Subcommand};
 use serde_json::json;
 
-use governance_app::crypto::signatures::SignatureManager;
+use governance_app::crypto::signatures::{build_signing_message, SignatureManager};
 
 #[derive(Parser)]
 #[command(name = "sign-pr")]
@@ -35,7 +35,15 @@ enum Commands {
         #[arg(short, long)]
         pr: u64,
         
-        /// Optional message to sign (defaults to "PR #X in Y")
+        /// Head commit SHA the signature approves
+        #[arg(long)]
+        head_sha: String,
+        
+        /// Governance tier the PR is classified as
+        #[arg(short, long)]
+        tier: u32,
+        
+        /// Optional message to sign (defaults to the canonical signing message)
         #[arg(short, long)]
         message: Option<String>,
     },
@@ -69,8 +77,8 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     let cli = Cli::parse();
     
     match cli.command {
-        Commands::Sign { key, repo, pr, message } => {
-            sign_pr(&key, &repo, pr, message)?;
+        Commands::Sign { key, repo, pr, head_sha, tier, message } => {
+            sign_pr(&key, &repo, pr, &head_sha, tier, message)?;
         }
         Commands::Generate { output, username } => {
             generate_keypair(&output, &username)?;
@@ -87,6 +95,8 @@ fn sign_pr(
     key_path: &str,
     repo: &str,
     pr: u64,
+    head_sha: &str,
+    tier: u32,
     message: Option<String>,
 ) -> Result<(), Box<dyn std::error::Error>> {
     println!("🔐 Signing PR #{} in {}", pr, repo);
@@ -95,7 +105,7 @@ fn sign_pr(
     let private_key = fs::read_to_string(key_path)?;
     
     // Create message to sign
-    let message = message.unwrap_or_else(|| format!("PR #{} in {}", pr, repo));
+    let message = message.unwrap_or_else(|| build_signing_message(repo, pr as i32, head_sha, tier));
     println!("📝 Message to sign: {}", message);
     
     // Initialize signature manager
@@ -112,6 +122,8 @@ fn sign_pr(
     println!("🔍 Signature details:");
     println!("  Repository: {}", repo);
     println!("  PR Number: {}", pr);
+    println!("  Head SHA: {}", head_sha);
+    println!("  Tier: {}", tier);
     println!("  Message: {}", message);
     println!("  Signature: {}", signature);
     
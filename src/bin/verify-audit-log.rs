@@ -1,19 +1,38 @@
+//! Audit Log Verification CLI
+//!
+//! Checks an exported audit log offline. `verify-chain <PATH>` checks only
+//! the hash chain, reports the first broken entry and exits non-zero so it
+//! can gate CI jobs; `--log-path` runs the full check, including the Merkle
+//! root and log health.
+
 use anyhow::{anyhow, Result};
-use clap::{Arg, Command};
+use clap::{Arg, ArgAction, Command};
 use std::path::Path;
 use tracing::{info, error};
 
-use governance_app::audit::{AuditLogger, verify_audit_log, build_merkle_tree, verify_merkle_root};
+use governance_app::audit::{
+    AuditLogger, verify_audit_log, build_merkle_tree, verify_merkle_root, find_first_fault,
+    get_merkle_root, load_audit_log_from_file, ChainFault,
+};
+use governance_app::audit::entry::AuditLogEntry;
 
 #[tokio::main]
 async fn main() -> Result<()> {
-    // Initialize logging
-    tracing_subscriber::fmt::init();
-
     // Parse command line arguments
     let matches = Command::new("verify-audit-log")
         .version("1.0.0")
         .about("Verify BTCDecoded governance audit log integrity")
+        .subcommand_negates_reqs(true)
+        .subcommand(
+            Command::new("verify-chain")
+                .about("Verify the hash chain of an audit log file")
+                .arg(
+                    Arg::new("path")
+                        .value_name("PATH")
+                        .help("Audit log file (JSONL)")
+                        .required(true)
+                )
+        )
         .arg(
             Arg::new("log-path")
                 .short('l')
@@ -33,31 +52,40 @@ async fn main() -> Result<()> {
             Arg::new("verbose")
                 .short('v')
                 .long("verbose")
+                .action(ArgAction::SetTrue)
                 .help("Enable verbose output")
         )
         .arg(
             Arg::new("quiet")
                 .short('q')
                 .long("quiet")
+                .action(ArgAction::SetTrue)
                 .help("Suppress output except errors")
         )
         .get_matches();
 
+    if let Some(chain) = matches.subcommand_matches("verify-chain") {
+        let path = chain.get_one::<String>("path").unwrap();
+        if !verify_chain(path) {
+            std::process::exit(1);
+        }
+        return Ok(());
+    }
+
     let log_path = matches.get_one::<String>("log-path").unwrap();
     let expected_merkle_root = matches.get_one::<String>("merkle-root");
     let verbose = matches.get_flag("verbose");
     let quiet = matches.get_flag("quiet");
 
     // Set log level based on flags
-    if quiet {
-        tracing_subscriber::fmt()
-            .with_max_level(tracing::Level::ERROR)
-            .init();
+    let level = if quiet {
+        tracing::Level::ERROR
     } else if verbose {
-        tracing_subscriber::fmt()
-            .with_max_level(tracing::Level::DEBUG)
-            .init();
-    }
+        tracing::Level::DEBUG
+    } else {
+        tracing::Level::INFO
+    };
+    tracing_subscriber::fmt().with_max_level(level).init();
 
     // Verify audit log
     if let Err(e) = verify_audit_log_file(log_path, expected_merkle_root, verbose).await {
@@ -72,6 +100,50 @@ async fn main() -> Result<()> {
     Ok(())
 }
 
+/// Check only the hash chain, printing the entry count and Merkle root when it holds
+fn verify_chain(path: &str) -> bool {
+    let entries = match load_audit_log_from_file(path) {
+        Ok(entries) => entries,
+        Err(e) => {
+            eprintln!("❌ Failed to load {}: {}", path, e);
+            return false;
+        }
+    };
+
+    if let Err(e) = verify_audit_log(&entries) {
+        report_chain_fault(&entries, &e);
+        return false;
+    }
+
+    match get_merkle_root(&entries) {
+        Ok(root) => {
+            println!("✅ Audit log chain is valid");
+            println!("   Entries: {}", entries.len());
+            println!("   Merkle root: {}", root);
+            true
+        }
+        Err(e) => {
+            eprintln!("❌ Failed to compute Merkle root: {}", e);
+            false
+        }
+    }
+}
+
+/// Print the first entry that breaks the hash chain
+fn report_chain_fault(entries: &[AuditLogEntry], error: &anyhow::Error) {
+    match find_first_fault(entries) {
+        Some(ChainFault::BrokenLink { index, expected, actual }) => {
+            eprintln!("❌ Broken link at entry {}", index);
+            eprintln!("   expected previous hash: {}", expected);
+            eprintln!("   actual previous hash:   {}", actual);
+        }
+        Some(ChainFault::InvalidHash { index }) => {
+            eprintln!("❌ Invalid hash at entry {}", index);
+        }
+        None => eprintln!("❌ Audit log verification failed: {}", error),
+    }
+}
+
 async fn verify_audit_log_file(
     log_path: &str,
     expected_merkle_root: Option<&String>,
@@ -85,7 +157,7 @@ async fn verify_audit_log_file(
     }
 
     // Load audit logger
-    let logger = AuditLogger::new(log_path.to_string())?;
+    let logger = AuditLogger::new(log_path.to_string()).await?;
     
     // Load all entries
     let entries = logger.load_all_entries().await?;
@@ -100,7 +172,10 @@ async fn verify_audit_log_file(
 
     // Verify hash chain
     info!("Verifying hash chain integrity");
-    verify_audit_log(&entries)?;
+    if let Err(e) = verify_audit_log(&entries) {
+        report_chain_fault(&entries, &e);
+        return Err(e);
+    }
     
     if verbose {
         println!("✓ Hash chain integrity verified");
@@ -143,7 +218,7 @@ async fn verify_audit_log_file(
     Ok(())
 }
 
-fn check_audit_log_health(entries: &[governance_app::audit::entry::AuditLogEntry], verbose: bool) -> Result<()> {
+fn check_audit_log_health(entries: &[AuditLogEntry], verbose: bool) -> Result<()> {
     info!("Checking audit log health");
 
     // Check for duplicate job IDs
@@ -0,0 +1,140 @@
+//! Least-Recently-Used Cache
+//!
+//! Backs the caches of content addressed by a Git SHA, such as files fetched
+//! at a commit and hashes of a blob, where entries only leave to make room.
+
+use std::collections::{BTreeMap, HashMap};
+use std::hash::Hash;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+
+/// Thread-safe cache that evicts the least recently used entry when full
+///
+/// A capacity of 0 disables caching. Lookups and inserts take O(log n).
+pub struct LruCache<K, V> {
+    capacity: usize,
+    entries: Mutex<LruEntries<K, V>>,
+    hits: AtomicU64,
+    misses: AtomicU64,
+}
+
+struct LruEntries<K, V> {
+    /// Each value with the tick of its last use
+    values: HashMap<K, (V, u64)>,
+    /// Keys by the tick of their last use, least recent first
+    order: BTreeMap<u64, K>,
+    next_tick: u64,
+}
+
+impl<K, V> LruEntries<K, V> {
+    fn tick(&mut self) -> u64 {
+        self.next_tick += 1;
+        self.next_tick
+    }
+}
+
+impl<K: Clone + Eq + Hash, V: Clone> LruCache<K, V> {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            entries: Mutex::new(LruEntries {
+                values: HashMap::new(),
+                order: BTreeMap::new(),
+                next_tick: 0,
+            }),
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+        }
+    }
+
+    /// Cached value for `key`, marking it most recently used
+    pub fn get(&self, key: &K) -> Option<V> {
+        let mut entries = self.entries.lock().unwrap();
+        let tick = entries.tick();
+        let Some((value, last_used)) = entries.values.get_mut(key) else {
+            self.misses.fetch_add(1, Ordering::Relaxed);
+            return None;
+        };
+        let value = value.clone();
+        let previous = std::mem::replace(last_used, tick);
+        entries.order.remove(&previous);
+        entries.order.insert(tick, key.clone());
+        self.hits.fetch_add(1, Ordering::Relaxed);
+        Some(value)
+    }
+
+    /// Store `value` under `key`, evicting the least recently used entries past capacity
+    pub fn insert(&self, key: K, value: V) {
+        if self.capacity == 0 {
+            return;
+        }
+        let mut entries = self.entries.lock().unwrap();
+        let tick = entries.tick();
+        if let Some((_, previous)) = entries.values.insert(key.clone(), (value, tick)) {
+            entries.order.remove(&previous);
+        }
+        entries.order.insert(tick, key);
+        while entries.values.len() > self.capacity {
+            match entries.order.pop_first() {
+                Some((_, oldest)) => {
+                    entries.values.remove(&oldest);
+                }
+                None => break,
+            }
+        }
+    }
+
+    /// Cache (hits, misses) since creation
+    pub fn stats(&self) -> (u64, u64) {
+        (self.hits.load(Ordering::Relaxed), self.misses.load(Ordering::Relaxed))
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.lock().unwrap().values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_evicts_least_recently_used() {
+        let cache = LruCache::new(2);
+        cache.insert("a", 1);
+        cache.insert("b", 2);
+        assert_eq!(cache.get(&"a"), Some(1));
+        cache.insert("c", 3);
+
+        assert_eq!(cache.get(&"b"), None);
+        assert_eq!(cache.get(&"a"), Some(1));
+        assert_eq!(cache.get(&"c"), Some(3));
+        assert_eq!(cache.len(), 2);
+        assert_eq!(cache.stats(), (3, 1));
+    }
+
+    #[test]
+    fn test_reinsert_refreshes_entry() {
+        let cache = LruCache::new(2);
+        cache.insert("a", 1);
+        cache.insert("b", 2);
+        cache.insert("a", 10);
+        cache.insert("c", 3);
+
+        assert_eq!(cache.get(&"a"), Some(10));
+        assert_eq!(cache.get(&"b"), None);
+        assert_eq!(cache.len(), 2);
+    }
+
+    #[test]
+    fn test_zero_capacity_stores_nothing() {
+        let cache = LruCache::new(0);
+        cache.insert("a", 1);
+        assert!(cache.is_empty());
+        assert_eq!(cache.get(&"a"), None);
+    }
+}
@@ -1,30 +1,78 @@
 use serde::{Deserialize, Serialize};
 use std::env;
+use std::fs::OpenOptions;
+use std::path::Path;
+
+use crate::enforcement::violations::SINK_NAMES;
+use crate::error::GovernanceError;
+use crate::validation::review_period::ReviewPeriodPolicy;
 
 pub mod loader;
+pub mod secret;
+
+pub use secret::Secret;
+
+use loader::EconomicQuorums;
+
+/// Default webhook secret, rejected by `validate` so it never reaches production
+pub const PLACEHOLDER_WEBHOOK_SECRET: &str = "your_webhook_secret_here";
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AppConfig {
-    pub database_url: String,
+    pub database_url: Secret,
+    pub database_connect_max_attempts: u32,
+    pub database_connect_base_delay_ms: u64,
+    pub slow_query_threshold_ms: f64,
     pub github_app_id: u64,
-    pub github_private_key_path: String,
-    pub github_webhook_secret: String,
+    pub github_private_key: Option<Secret>,
+    pub github_webhook_secret: Secret,
+    pub github_max_retry_attempts: u32,
+    pub github_api_url: String,
+    pub webhook_delivery_ttl_secs: u64,
+    pub webhook_queue_capacity: usize,
+    pub webhook_workers: usize,
+    /// Attempts at processing a queued webhook before it is dead-lettered
+    pub webhook_max_attempts: u32,
+    pub webhook_retry_base_delay_ms: u64,
     pub governance_repo: String,
     pub server_host: String,
     pub server_port: u16,
+    pub shutdown_timeout_secs: u64,
     pub dry_run_mode: bool,
     pub log_enforcement_decisions: bool,
     pub enforcement_log_path: Option<String>,
     pub server_id: String,
+    pub admin_token: Option<Secret>,
+    pub status_token: Option<Secret>,
+    pub record_signing_key_path: Option<String>,
+    pub version_manifest_path: String,
+    pub governance_config_path: String,
+    /// Loaded once from `governance_config_path` at startup
+    pub economic_quorums: EconomicQuorums,
+    pub governance_config_commit: Option<String>,
+    pub github_file_cache_capacity: usize,
+    pub content_hash_cache_capacity: usize,
+    /// Harness that runs Consensus Proof implementations for equivalence proofs
+    pub equivalence_harness_path: Option<String>,
+    pub equivalence_harness_args: Vec<String>,
+    /// Report merge decisions as check runs as well as commit statuses
+    pub github_check_runs: bool,
+    pub review_period_policy: ReviewPeriodPolicy,
+    pub violation_sinks: Vec<String>,
+    pub key_expiry_sweep_interval_secs: u64,
+    /// How often pending PRs are re-synced with GitHub; 0 disables reconciliation
+    pub reconcile_interval_secs: u64,
     pub nostr: NostrConfig,
     pub ots: OtsConfig,
     pub audit: AuditConfig,
+    pub reminders: ReminderConfig,
+    pub retention: RetentionConfig,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NostrConfig {
     pub enabled: bool,
-    pub server_nsec_path: String,
+    pub server_nsec: Option<Secret>,
     pub relays: Vec<String>,
     pub publish_interval_secs: u64,
 }
@@ -36,6 +84,11 @@ pub struct OtsConfig {
     pub monthly_anchor_day: u8,
     pub registry_path: String,
     pub proofs_path: String,
+    pub config_paths: Vec<String>,
+    pub refuse_start_on_config_mismatch: bool,
+    pub verification_calendars: Vec<String>,
+    pub verification_quorum: usize,
+    pub upgrade_interval_secs: u64,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -43,22 +96,96 @@ pub struct AuditConfig {
     pub enabled: bool,
     pub log_path: String,
     pub rotation_interval_days: u32,
+    pub max_file_size_bytes: Option<u64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReminderConfig {
+    pub enabled: bool,
+    pub check_interval_secs: u64,
+    pub reminder_interval_hours: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RetentionConfig {
+    pub enabled: bool,
+    pub events_retention_days: u32,
+    pub batch_size: u32,
+    pub prune_interval_secs: u64,
+    pub retained_event_types: Vec<String>,
 }
 
 impl AppConfig {
     pub fn load() -> Result<Self, Box<dyn std::error::Error>> {
-        let database_url =
-            env::var("DATABASE_URL").unwrap_or_else(|_| "sqlite://governance.db".to_string());
+        let database_url = Secret::resolve("DATABASE_URL", "sqlite://governance.db")?;
+
+        // Postgres may still be starting when the app comes up under compose or k8s
+        let database_connect_max_attempts = env::var("DATABASE_CONNECT_MAX_ATTEMPTS")
+            .unwrap_or_else(|_| "5".to_string())
+            .parse()
+            .unwrap_or(5);
+
+        let database_connect_base_delay_ms = env::var("DATABASE_CONNECT_BASE_DELAY_MS")
+            .unwrap_or_else(|_| "500".to_string())
+            .parse()
+            .unwrap_or(500);
+
+        let slow_query_threshold_ms = env::var("DATABASE_SLOW_QUERY_THRESHOLD_MS")
+            .unwrap_or_else(|_| "100".to_string())
+            .parse()
+            .unwrap_or(100.0);
 
         let github_app_id = env::var("GITHUB_APP_ID")
             .unwrap_or_else(|_| "123456".to_string())
             .parse()?;
 
-        let github_private_key_path = env::var("GITHUB_PRIVATE_KEY_PATH")
-            .unwrap_or_else(|_| "/path/to/private-key.pem".to_string());
+        // GITHUB_PRIVATE_KEY_PATH is the older name for GITHUB_PRIVATE_KEY_FILE
+        let github_private_key = match Secret::from_env("GITHUB_PRIVATE_KEY")? {
+            Some(key) => Some(key),
+            None => env::var("GITHUB_PRIVATE_KEY_PATH")
+                .ok()
+                .filter(|p| !p.is_empty())
+                .map(|p| Secret::from_file(&p))
+                .transpose()?,
+        };
+
+        let github_webhook_secret =
+            Secret::resolve("GITHUB_WEBHOOK_SECRET", PLACEHOLDER_WEBHOOK_SECRET)?;
+
+        let github_max_retry_attempts = env::var("GITHUB_MAX_RETRY_ATTEMPTS")
+            .unwrap_or_else(|_| "4".to_string())
+            .parse()
+            .unwrap_or(4);
+
+        let github_api_url =
+            env::var("GITHUB_API_URL").unwrap_or_else(|_| "https://api.github.com".to_string());
+
+        // GitHub only redelivers within a few days, so remembered delivery ids can expire
+        let webhook_delivery_ttl_secs = env::var("WEBHOOK_DELIVERY_TTL_SECS")
+            .unwrap_or_else(|_| "604800".to_string())
+            .parse()
+            .unwrap_or(604800);
+
+        let webhook_queue_capacity = env::var("WEBHOOK_QUEUE_CAPACITY")
+            .unwrap_or_else(|_| "256".to_string())
+            .parse()
+            .unwrap_or(256);
+
+        let webhook_workers = env::var("WEBHOOK_WORKERS")
+            .unwrap_or_else(|_| "4".to_string())
+            .parse()
+            .unwrap_or(4);
+
+        // GitHub doesn't redeliver once a webhook was answered, so failures are retried here
+        let webhook_max_attempts = env::var("WEBHOOK_MAX_ATTEMPTS")
+            .unwrap_or_else(|_| "3".to_string())
+            .parse()
+            .unwrap_or(3);
 
-        let github_webhook_secret = env::var("GITHUB_WEBHOOK_SECRET")
-            .unwrap_or_else(|_| "your_webhook_secret_here".to_string());
+        let webhook_retry_base_delay_ms = env::var("WEBHOOK_RETRY_BASE_DELAY_MS")
+            .unwrap_or_else(|_| "1000".to_string())
+            .parse()
+            .unwrap_or(1000);
 
         let governance_repo =
             env::var("GOVERNANCE_REPO").unwrap_or_else(|_| "BTCDecoded/governance".to_string());
@@ -69,6 +196,11 @@ impl AppConfig {
             .unwrap_or_else(|_| "3000".to_string())
             .parse()?;
 
+        let shutdown_timeout_secs = env::var("SHUTDOWN_TIMEOUT_SECS")
+            .unwrap_or_else(|_| "30".to_string())
+            .parse()
+            .unwrap_or(30);
+
         let dry_run_mode = env::var("DRY_RUN_MODE")
             .unwrap_or_else(|_| "false".to_string())
             .parse()
@@ -84,13 +216,88 @@ impl AppConfig {
         let server_id = env::var("SERVER_ID")
             .unwrap_or_else(|_| "governance-01".to_string());
 
+        let admin_token = Secret::from_env("ADMIN_TOKEN")?;
+
+        let status_token = Secret::from_env("STATUS_TOKEN")?;
+
+        let record_signing_key_path = env::var("RECORD_SIGNING_KEY_PATH").ok();
+
+        let version_manifest_path = env::var("VERSION_MANIFEST_PATH")
+            .unwrap_or_else(|_| "governance/config/version-manifest.yml".to_string());
+
+        let governance_config_path = env::var("GOVERNANCE_CONFIG_PATH")
+            .unwrap_or_else(|_| "governance/config".to_string());
+
+        let economic_quorums = EconomicQuorums::load(Path::new(&governance_config_path));
+
+        // Commit of the governance repo the config was deployed from, recorded in exports
+        let governance_config_commit = env::var("GOVERNANCE_CONFIG_COMMIT").ok();
+
+        let github_file_cache_capacity = env::var("GITHUB_FILE_CACHE_CAPACITY")
+            .unwrap_or_else(|_| "512".to_string())
+            .parse()
+            .unwrap_or(512);
+
+        let content_hash_cache_capacity = env::var("CONTENT_HASH_CACHE_CAPACITY")
+            .unwrap_or_else(|_| "4096".to_string())
+            .parse()
+            .unwrap_or(4096);
+
+        // Without a harness, behavioral equivalence proofs fail
+        let equivalence_harness_path = env::var("EQUIVALENCE_HARNESS_PATH")
+            .ok()
+            .filter(|p| !p.is_empty());
+
+        let equivalence_harness_args = env::var("EQUIVALENCE_HARNESS_ARGS")
+            .unwrap_or_default()
+            .split_whitespace()
+            .map(|s| s.to_string())
+            .collect();
+
+        let github_check_runs = env::var("GITHUB_CHECK_RUNS")
+            .unwrap_or_else(|_| "false".to_string())
+            .parse()
+            .unwrap_or(false);
+
+        // Calendar days unless configured otherwise; holidays only matter for business days
+        let review_period_days = env::var("REVIEW_PERIOD_DAYS")
+            .unwrap_or_else(|_| "calendar".to_string())
+            .parse()?;
+
+        let review_period_holidays = env::var("REVIEW_PERIOD_HOLIDAYS")
+            .unwrap_or_default()
+            .split(',')
+            .map(|s| s.trim())
+            .filter(|s| !s.is_empty())
+            .map(|s| chrono::NaiveDate::parse_from_str(s, "%Y-%m-%d"))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        // Where merges that bypassed governance are reported, besides governance_events
+        let violation_sinks = env::var("VIOLATION_SINKS")
+            .unwrap_or_else(|_| "nostr,audit".to_string())
+            .split(',')
+            .map(|s| s.trim().to_string())
+            .filter(|s| !s.is_empty())
+            .collect();
+
         let nostr_enabled = env::var("NOSTR_ENABLED")
             .unwrap_or_else(|_| "false".to_string())
             .parse()
             .unwrap_or(false);
 
-        let nostr_server_nsec_path = env::var("NOSTR_SERVER_NSEC_PATH")
-            .unwrap_or_else(|_| "/etc/governance/server.nsec".to_string());
+        // Only read the key when it's needed; NOSTR_SERVER_NSEC_PATH is the
+        // older name for NOSTR_SERVER_NSEC_FILE
+        let nostr_server_nsec = if nostr_enabled {
+            match Secret::from_env("NOSTR_SERVER_NSEC")? {
+                Some(nsec) => Some(nsec),
+                None => Some(Secret::from_file(
+                    &env::var("NOSTR_SERVER_NSEC_PATH")
+                        .unwrap_or_else(|_| "/etc/governance/server.nsec".to_string()),
+                )?),
+            }
+        } else {
+            None
+        };
 
         let nostr_relays = env::var("NOSTR_RELAYS")
             .unwrap_or_else(|_| "wss://relay.damus.io,wss://nos.lol".to_string())
@@ -122,6 +329,37 @@ impl AppConfig {
         let ots_proofs_path = env::var("OTS_PROOFS_PATH")
             .unwrap_or_else(|_| "/var/lib/governance/ots-proofs".to_string());
 
+        let ots_config_paths = env::var("OTS_CONFIG_PATHS")
+            .unwrap_or_else(|_| "config.toml".to_string())
+            .split(',')
+            .map(|s| s.trim().to_string())
+            .filter(|s| !s.is_empty())
+            .collect();
+
+        let ots_refuse_on_mismatch = env::var("OTS_REFUSE_START_ON_CONFIG_MISMATCH")
+            .unwrap_or_else(|_| "false".to_string())
+            .parse()
+            .unwrap_or(false);
+
+        let ots_verification_calendars: Vec<String> = env::var("OTS_VERIFICATION_CALENDARS")
+            .unwrap_or_else(|_| {
+                "https://alice.btc.calendar.opentimestamps.org,https://bob.btc.calendar.opentimestamps.org,https://finney.calendar.eternitywall.com".to_string()
+            })
+            .split(',')
+            .map(|s| s.trim().to_string())
+            .filter(|s| !s.is_empty())
+            .collect();
+
+        let ots_verification_quorum = env::var("OTS_VERIFICATION_QUORUM")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(ots_verification_calendars.len() / 2 + 1);
+
+        let ots_upgrade_interval = env::var("OTS_UPGRADE_INTERVAL_SECS")
+            .unwrap_or_else(|_| "3600".to_string())
+            .parse()
+            .unwrap_or(3600);
+
         let audit_enabled = env::var("AUDIT_ENABLED")
             .unwrap_or_else(|_| "true".to_string())
             .parse()
@@ -135,21 +373,107 @@ impl AppConfig {
             .parse()
             .unwrap_or(30);
 
-        Ok(AppConfig {
+        let audit_max_file_size = env::var("AUDIT_MAX_FILE_SIZE_BYTES")
+            .ok()
+            .and_then(|v| v.parse().ok());
+
+        let reminders_enabled = env::var("REMINDERS_ENABLED")
+            .unwrap_or_else(|_| "false".to_string())
+            .parse()
+            .unwrap_or(false);
+
+        let reminders_check_interval = env::var("REMINDERS_CHECK_INTERVAL_SECS")
+            .unwrap_or_else(|_| "3600".to_string())
+            .parse()
+            .unwrap_or(3600);
+
+        let reminders_interval_hours = env::var("REMINDERS_INTERVAL_HOURS")
+            .unwrap_or_else(|_| "168".to_string())
+            .parse()
+            .unwrap_or(168);
+
+        let retention_enabled = env::var("RETENTION_ENABLED")
+            .unwrap_or_else(|_| "false".to_string())
+            .parse()
+            .unwrap_or(false);
+
+        let retention_events_days = env::var("RETENTION_EVENTS_DAYS")
+            .unwrap_or_else(|_| "365".to_string())
+            .parse()
+            .unwrap_or(365);
+
+        let retention_batch_size = env::var("RETENTION_BATCH_SIZE")
+            .unwrap_or_else(|_| "500".to_string())
+            .parse()
+            .unwrap_or(500);
+
+        let key_expiry_sweep_interval_secs = env::var("KEY_EXPIRY_SWEEP_INTERVAL_SECS")
+            .unwrap_or_else(|_| "3600".to_string())
+            .parse()
+            .unwrap_or(3600);
+
+        let reconcile_interval_secs = env::var("RECONCILE_INTERVAL_SECS")
+            .unwrap_or_else(|_| "900".to_string())
+            .parse()
+            .unwrap_or(900);
+
+        let retention_prune_interval = env::var("RETENTION_PRUNE_INTERVAL_SECS")
+            .unwrap_or_else(|_| "86400".to_string())
+            .parse()
+            .unwrap_or(86400);
+
+        let retention_retained_event_types = env::var("RETENTION_RETAINED_EVENT_TYPES")
+            .unwrap_or_else(|_| "emergency_activated,governance_fork_initiated,economic_node_veto,maintainer_added,maintainer_removed,tier_override,tier_override_cleared".to_string())
+            .split(',')
+            .map(|s| s.trim().to_string())
+            .filter(|s| !s.is_empty())
+            .collect();
+
+        let config = AppConfig {
             database_url,
+            database_connect_max_attempts,
+            database_connect_base_delay_ms,
+            slow_query_threshold_ms,
             github_app_id,
-            github_private_key_path,
+            github_private_key,
             github_webhook_secret,
+            github_max_retry_attempts,
+            github_api_url,
+            webhook_delivery_ttl_secs,
+            webhook_queue_capacity,
+            webhook_workers,
+            webhook_max_attempts,
+            webhook_retry_base_delay_ms,
             governance_repo,
             server_host,
             server_port,
+            shutdown_timeout_secs,
             dry_run_mode,
             log_enforcement_decisions,
             enforcement_log_path,
             server_id,
+            admin_token,
+            status_token,
+            record_signing_key_path,
+            version_manifest_path,
+            governance_config_path,
+            economic_quorums,
+            governance_config_commit,
+            github_file_cache_capacity,
+            content_hash_cache_capacity,
+            equivalence_harness_path,
+            equivalence_harness_args,
+            github_check_runs,
+            review_period_policy: ReviewPeriodPolicy {
+                counting: review_period_days,
+                holidays: review_period_holidays,
+            },
+            violation_sinks,
+            key_expiry_sweep_interval_secs,
+            reconcile_interval_secs,
             nostr: NostrConfig {
                 enabled: nostr_enabled,
-                server_nsec_path: nostr_server_nsec_path,
+                server_nsec: nostr_server_nsec,
                 relays: nostr_relays,
                 publish_interval_secs: nostr_publish_interval,
             },
@@ -159,12 +483,347 @@ impl AppConfig {
                 monthly_anchor_day: ots_monthly_anchor_day,
                 registry_path: ots_registry_path,
                 proofs_path: ots_proofs_path,
+                config_paths: ots_config_paths,
+                refuse_start_on_config_mismatch: ots_refuse_on_mismatch,
+                verification_calendars: ots_verification_calendars,
+                verification_quorum: ots_verification_quorum,
+                upgrade_interval_secs: ots_upgrade_interval,
             },
             audit: AuditConfig {
                 enabled: audit_enabled,
                 log_path: audit_log_path,
                 rotation_interval_days: audit_rotation_interval,
+                max_file_size_bytes: audit_max_file_size,
+            },
+            reminders: ReminderConfig {
+                enabled: reminders_enabled,
+                check_interval_secs: reminders_check_interval,
+                reminder_interval_hours: reminders_interval_hours,
+            },
+            retention: RetentionConfig {
+                enabled: retention_enabled,
+                events_retention_days: retention_events_days,
+                batch_size: retention_batch_size,
+                prune_interval_secs: retention_prune_interval,
+                retained_event_types: retention_retained_event_types,
             },
+        };
+        config.validate()?;
+        Ok(config)
+    }
+
+    /// Check invariants between fields that would otherwise only fail at runtime
+    pub fn validate(&self) -> Result<(), GovernanceError> {
+        if self.database_connect_max_attempts == 0 {
+            return Err(config_error(
+                "database_connect_max_attempts (DATABASE_CONNECT_MAX_ATTEMPTS) must be greater than 0",
+            ));
+        }
+        if self.webhook_queue_capacity == 0 {
+            return Err(config_error(
+                "webhook_queue_capacity (WEBHOOK_QUEUE_CAPACITY) must be greater than 0",
+            ));
+        }
+        if self.webhook_workers == 0 {
+            return Err(config_error("webhook_workers (WEBHOOK_WORKERS) must be greater than 0"));
+        }
+        if self.webhook_max_attempts == 0 {
+            return Err(config_error(
+                "webhook_max_attempts (WEBHOOK_MAX_ATTEMPTS) must be greater than 0",
+            ));
+        }
+        // Anyone who knows the placeholder could forge webhooks
+        let webhook_secret = self.github_webhook_secret.expose();
+        if webhook_secret.is_empty() || webhook_secret == PLACEHOLDER_WEBHOOK_SECRET {
+            return Err(config_error(
+                "github_webhook_secret (GITHUB_WEBHOOK_SECRET or GITHUB_WEBHOOK_SECRET_FILE) must be set to the secret configured on the GitHub App",
+            ));
+        }
+        if self.key_expiry_sweep_interval_secs == 0 {
+            return Err(config_error(
+                "key_expiry_sweep_interval_secs (KEY_EXPIRY_SWEEP_INTERVAL_SECS) must be greater than 0",
+            ));
+        }
+        if let Some(sink) = self
+            .violation_sinks
+            .iter()
+            .find(|s| !SINK_NAMES.contains(&s.as_str()))
+        {
+            return Err(config_error(format!(
+                "violation_sinks (VIOLATION_SINKS) contains '{}', expected one of: {}",
+                sink,
+                SINK_NAMES.join(", ")
+            )));
+        }
+        if self.nostr.enabled {
+            if self.nostr.relays.is_empty() {
+                return Err(config_error(
+                    "nostr.relays (NOSTR_RELAYS) must list at least one relay when Nostr is enabled",
+                ));
+            }
+            if let Some(relay) = self
+                .nostr
+                .relays
+                .iter()
+                .find(|r| !(r.starts_with("wss://") || r.starts_with("ws://")))
+            {
+                return Err(config_error(format!(
+                    "nostr.relays (NOSTR_RELAYS) contains '{}', expected a ws:// or wss:// URL",
+                    relay
+                )));
+            }
+            if self.nostr.server_nsec.is_none() {
+                return Err(config_error(
+                    "nostr.server_nsec (NOSTR_SERVER_NSEC or NOSTR_SERVER_NSEC_FILE) is required when Nostr is enabled",
+                ));
+            }
+            if self.nostr.publish_interval_secs == 0 {
+                return Err(config_error(
+                    "nostr.publish_interval_secs (NOSTR_PUBLISH_INTERVAL_SECS) must be greater than 0",
+                ));
+            }
+        }
+
+        // Days past the 28th don't occur every month, so anchors would be skipped
+        if !(1..=28).contains(&self.ots.monthly_anchor_day) {
+            return Err(config_error(format!(
+                "ots.monthly_anchor_day (OTS_MONTHLY_ANCHOR_DAY) must be between 1 and 28, got {}",
+                self.ots.monthly_anchor_day
+            )));
+        }
+        if self.ots.enabled {
+            let calendars = self.ots.verification_calendars.len();
+            if self.ots.verification_quorum == 0 || self.ots.verification_quorum > calendars {
+                return Err(config_error(format!(
+                    "ots.verification_quorum (OTS_VERIFICATION_QUORUM) must be between 1 and the {} configured verification calendars, got {}",
+                    calendars, self.ots.verification_quorum
+                )));
+            }
+            if self.ots.upgrade_interval_secs == 0 {
+                return Err(config_error(
+                    "ots.upgrade_interval_secs (OTS_UPGRADE_INTERVAL_SECS) must be greater than 0",
+                ));
+            }
+        }
+
+        if self.audit.enabled {
+            check_writable(Path::new(&self.audit.log_path)).map_err(|reason| {
+                config_error(format!(
+                    "audit.log_path (AUDIT_LOG_PATH) {} is not writable: {}",
+                    self.audit.log_path, reason
+                ))
+            })?;
+            if self.audit.rotation_interval_days == 0 {
+                return Err(config_error(
+                    "audit.rotation_interval_days (AUDIT_ROTATION_INTERVAL_DAYS) must be greater than 0",
+                ));
+            }
+        }
+
+        if self.reminders.enabled && self.reminders.check_interval_secs == 0 {
+            return Err(config_error(
+                "reminders.check_interval_secs (REMINDERS_CHECK_INTERVAL_SECS) must be greater than 0",
+            ));
+        }
+
+        if self.retention.enabled {
+            if self.retention.batch_size == 0 {
+                return Err(config_error(
+                    "retention.batch_size (RETENTION_BATCH_SIZE) must be greater than 0",
+                ));
+            }
+            if self.retention.prune_interval_secs == 0 {
+                return Err(config_error(
+                    "retention.prune_interval_secs (RETENTION_PRUNE_INTERVAL_SECS) must be greater than 0",
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Server key for signing exported records and rulesets
+    ///
+    /// `None` when no key is configured or the key file can't be read.
+    pub fn record_signing_key(&self) -> Option<secp256k1::SecretKey> {
+        self.record_signing_key_path.as_ref().and_then(|path| {
+            std::fs::read_to_string(path)
+                .ok()
+                .and_then(|hex| hex.trim().parse::<secp256k1::SecretKey>().ok())
         })
     }
 }
+
+fn config_error(message: impl Into<String>) -> GovernanceError {
+    GovernanceError::ConfigError(message.into())
+}
+
+/// Check a file can be appended to, or created if missing, without creating it
+fn check_writable(path: &Path) -> Result<(), String> {
+    if path.exists() {
+        return OpenOptions::new()
+            .append(true)
+            .open(path)
+            .map(|_| ())
+            .map_err(|e| e.to_string());
+    }
+
+    // The logger creates missing directories; check the nearest one that exists
+    let mut ancestor = path.parent();
+    while let Some(dir) = ancestor {
+        if dir.as_os_str().is_empty() {
+            break;
+        }
+        if dir.exists() {
+            let metadata = dir.metadata().map_err(|e| e.to_string())?;
+            if !metadata.is_dir() {
+                return Err(format!("{} is not a directory", dir.display()));
+            }
+            if metadata.permissions().readonly() {
+                return Err(format!("{} is read-only", dir.display()));
+            }
+            return Ok(());
+        }
+        ancestor = dir.parent();
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    fn valid_config() -> AppConfig {
+        let mut config = AppConfig::load().unwrap();
+        config.github_webhook_secret = Secret::new("a-real-webhook-secret");
+        config
+    }
+
+    fn error_text(config: &AppConfig) -> String {
+        config.validate().unwrap_err().to_string()
+    }
+
+    #[test]
+    fn test_default_config_is_valid() {
+        assert!(valid_config().validate().is_ok());
+    }
+
+    #[test]
+    fn test_placeholder_webhook_secret_is_rejected() {
+        let mut config = valid_config();
+        for secret in ["", PLACEHOLDER_WEBHOOK_SECRET] {
+            config.github_webhook_secret = Secret::new(secret);
+            assert!(error_text(&config).starts_with(
+                "Configuration error: github_webhook_secret (GITHUB_WEBHOOK_SECRET"
+            ));
+        }
+    }
+
+    #[test]
+    fn test_nostr_enabled_without_relays_is_rejected() {
+        let mut config = valid_config();
+        config.nostr.enabled = true;
+        config.nostr.relays.clear();
+        assert_eq!(
+            error_text(&config),
+            "Configuration error: nostr.relays (NOSTR_RELAYS) must list at least one relay when Nostr is enabled"
+        );
+
+        config.nostr.relays = vec!["https://relay.example.com".to_string()];
+        assert!(error_text(&config).contains("contains 'https://relay.example.com'"));
+    }
+
+    #[test]
+    fn test_unknown_violation_sink_is_rejected() {
+        let mut config = valid_config();
+        config.violation_sinks = vec!["audit".to_string(), "email".to_string()];
+        assert_eq!(
+            error_text(&config),
+            "Configuration error: violation_sinks (VIOLATION_SINKS) contains 'email', expected one of: nostr, audit"
+        );
+    }
+
+    #[test]
+    fn test_anchor_day_outside_every_month_is_rejected() {
+        let mut config = valid_config();
+        config.ots.monthly_anchor_day = 40;
+        assert_eq!(
+            error_text(&config),
+            "Configuration error: ots.monthly_anchor_day (OTS_MONTHLY_ANCHOR_DAY) must be between 1 and 28, got 40"
+        );
+
+        config.ots.monthly_anchor_day = 29;
+        assert!(error_text(&config).contains("got 29"));
+        config.ots.monthly_anchor_day = 0;
+        assert!(error_text(&config).contains("got 0"));
+        config.ots.monthly_anchor_day = 28;
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn test_unwritable_audit_log_is_rejected() {
+        let dir = tempdir().unwrap();
+        // A regular file where the log directory should be
+        let blocker = dir.path().join("not-a-dir");
+        std::fs::write(&blocker, "").unwrap();
+
+        let mut config = valid_config();
+        config.audit.log_path = blocker.join("audit-log.jsonl").to_string_lossy().to_string();
+        let error = error_text(&config);
+        assert!(error.starts_with("Configuration error: audit.log_path (AUDIT_LOG_PATH)"));
+        assert!(error.ends_with("is not a directory"));
+
+        // Disabling the audit log skips the check
+        config.audit.enabled = false;
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn test_zero_intervals_are_rejected() {
+        let mut config = valid_config();
+        config.ots.enabled = true;
+        config.ots.upgrade_interval_secs = 0;
+        assert_eq!(
+            error_text(&config),
+            "Configuration error: ots.upgrade_interval_secs (OTS_UPGRADE_INTERVAL_SECS) must be greater than 0"
+        );
+
+        let dir = tempdir().unwrap();
+        let mut config = valid_config();
+        config.audit.enabled = true;
+        config.audit.log_path = dir.path().join("audit.jsonl").to_string_lossy().to_string();
+        config.audit.rotation_interval_days = 0;
+        assert_eq!(
+            error_text(&config),
+            "Configuration error: audit.rotation_interval_days (AUDIT_ROTATION_INTERVAL_DAYS) must be greater than 0"
+        );
+
+        let mut config = valid_config();
+        config.reminders.enabled = true;
+        config.reminders.check_interval_secs = 0;
+        assert_eq!(
+            error_text(&config),
+            "Configuration error: reminders.check_interval_secs (REMINDERS_CHECK_INTERVAL_SECS) must be greater than 0"
+        );
+
+        let mut config = valid_config();
+        config.retention.enabled = true;
+        config.retention.prune_interval_secs = 0;
+        assert_eq!(
+            error_text(&config),
+            "Configuration error: retention.prune_interval_secs (RETENTION_PRUNE_INTERVAL_SECS) must be greater than 0"
+        );
+    }
+
+    #[test]
+    fn test_quorum_larger_than_calendars_is_rejected() {
+        let mut config = valid_config();
+        config.ots.enabled = true;
+        config.ots.verification_calendars = vec!["https://a.example".to_string()];
+        config.ots.verification_quorum = 2;
+        assert!(error_text(&config).contains(
+            "ots.verification_quorum (OTS_VERIFICATION_QUORUM) must be between 1 and the 1 configured verification calendars, got 2"
+        ));
+    }
+}
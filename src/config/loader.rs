@@ -4,8 +4,9 @@
 use serde::{Deserialize, Serialize};
 use std::fs;
 use std::path::{Path, PathBuf};
-use tracing::info;
+use tracing::{info, warn};
 use crate::error::GovernanceError;
+use crate::validation::threshold::ThresholdValidator;
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct ActionTiersConfig {
@@ -19,6 +20,10 @@ pub struct TierConfig {
     pub signatures_total: usize,
     pub review_period_days: i64,
     pub economic_veto_required: bool,
+    /// Minimum number of economic node signals (support or veto) required
+    /// before a PR in this tier counts as reviewed by economic nodes
+    #[serde(default)]
+    pub economic_quorum: Option<u32>,
     pub description: String,
 }
 
@@ -237,6 +242,27 @@ impl GovernanceConfigFiles {
         self.action_tiers.tiers.get(&tier_name)
     }
 
+    /// Get the economic node quorum for a tier, falling back to the built-in default
+    pub fn get_economic_quorum(&self, tier: u32) -> u32 {
+        self.get_tier_config(tier)
+            .and_then(|t| t.economic_quorum)
+            .unwrap_or_else(|| ThresholdValidator::get_tier_economic_quorum(tier))
+    }
+
+    /// Economic node quorums configured for each tier
+    pub fn economic_quorums(&self) -> EconomicQuorums {
+        let by_tier = self
+            .action_tiers
+            .tiers
+            .iter()
+            .filter_map(|(name, tier)| {
+                let number = name.strip_prefix("tier_")?.parse().ok()?;
+                Some((number, tier.economic_quorum?))
+            })
+            .collect();
+        EconomicQuorums { by_tier }
+    }
+
     /// Get layer configuration by layer number
     pub fn get_layer_config(&self, layer: i32) -> Option<&LayerConfig> {
         let layer_name = match layer {
@@ -260,6 +286,37 @@ impl GovernanceConfigFiles {
     }
 }
 
+/// Economic node quorum per tier, from action-tiers.yml
+///
+/// Tiers without a configured `economic_quorum` use the built-in default.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub struct EconomicQuorums {
+    by_tier: std::collections::HashMap<u32, u32>,
+}
+
+impl EconomicQuorums {
+    /// Read the quorums from a governance config directory
+    ///
+    /// A directory that can't be loaded leaves every tier at its default.
+    pub fn load(path: &Path) -> Self {
+        match GovernanceConfigFiles::load_from_directory(path) {
+            Ok(config) => config.economic_quorums(),
+            Err(e) => {
+                warn!("Failed to load governance config, using default economic quorums: {}", e);
+                Self::default()
+            }
+        }
+    }
+
+    /// Economic node signals required for a tier
+    pub fn for_tier(&self, tier: u32) -> u32 {
+        self.by_tier
+            .get(&tier)
+            .copied()
+            .unwrap_or_else(|| ThresholdValidator::get_tier_economic_quorum(tier))
+    }
+}
+
 /// Configuration cache for runtime updates
 pub struct ConfigCache {
     config: GovernanceConfigFiles,
@@ -340,6 +397,7 @@ mod tests {
             signatures_total: 5,
             review_period_days: 7,
             economic_veto_required: false,
+            economic_quorum: None,
             description: "Routine maintenance".to_string(),
         });
 
@@ -375,6 +433,7 @@ mod tests {
             signatures_total: 5,
             review_period_days: 7,
             economic_veto_required: false,
+            economic_quorum: None,
             description: "Routine maintenance".to_string(),
         });
 
@@ -404,6 +463,43 @@ mod tests {
         let tier_2 = config.get_tier_config(2);
         assert!(tier_2.is_none());
     }
+
+    #[test]
+    fn test_economic_quorums_fall_back_to_defaults() {
+        let mut tiers = HashMap::new();
+        tiers.insert("tier_3".to_string(), TierConfig {
+            name: "Consensus-Adjacent".to_string(),
+            signatures_required: 5,
+            signatures_total: 5,
+            review_period_days: 90,
+            economic_veto_required: true,
+            economic_quorum: Some(7),
+            description: "Consensus-adjacent changes".to_string(),
+        });
+
+        let config = GovernanceConfigFiles {
+            action_tiers: ActionTiersConfig { tiers },
+            repository_layers: RepositoryLayersConfig {
+                layers: HashMap::new(),
+            },
+            tier_classification: TierClassificationConfig {
+                classification_rules: HashMap::new(),
+                classification_config: ClassificationConfig {
+                    min_confidence: 0.6,
+                    file_pattern_weight: 0.7,
+                    keyword_weight: 0.3,
+                },
+            },
+        };
+
+        let quorums = config.economic_quorums();
+        assert_eq!(quorums.for_tier(3), 7);
+        assert_eq!(quorums.for_tier(5), ThresholdValidator::get_tier_economic_quorum(5));
+
+        let missing = EconomicQuorums::load(Path::new("/nonexistent/governance/config"));
+        assert_eq!(missing, EconomicQuorums::default());
+        assert_eq!(missing.for_tier(3), ThresholdValidator::get_tier_economic_quorum(3));
+    }
 }
 
 
@@ -0,0 +1,163 @@
+//! Secret configuration values
+//!
+//! Sensitive settings can be given directly in the environment or as a
+//! path in `<NAME>_FILE`, which is how container orchestrators mount
+//! secrets. Resolved values are never printed: `Debug` and `Serialize` both
+//! redact them.
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::env;
+use std::fmt;
+use std::fs;
+
+use crate::error::GovernanceError;
+
+const REDACTED: &str = "[REDACTED]";
+
+/// A sensitive configuration value
+#[derive(Clone, PartialEq, Eq)]
+pub struct Secret(String);
+
+impl Secret {
+    pub fn new(value: impl Into<String>) -> Self {
+        Self(value.into())
+    }
+
+    /// The secret value itself; don't log it
+    pub fn expose(&self) -> &str {
+        &self.0
+    }
+
+    /// Read a secret from a file, dropping trailing whitespace
+    pub fn from_file(path: &str) -> Result<Self, GovernanceError> {
+        let value = fs::read_to_string(path).map_err(|e| {
+            GovernanceError::ConfigError(format!("Failed to read secret file {}: {}", path, e))
+        })?;
+        Ok(Self(value.trim_end().to_string()))
+    }
+
+    /// Resolve `name` from the environment only
+    pub fn from_env(name: &str) -> Result<Option<Self>, GovernanceError> {
+        resolve_with(name, None, |var| env::var(var).ok())
+    }
+
+    /// Resolve `name`, highest precedence first:
+    ///
+    /// 1. the file at `<NAME>_FILE`
+    /// 2. the `<NAME>` environment variable
+    /// 3. `inline`
+    ///
+    /// Empty variables count as unset.
+    pub fn resolve(name: &str, inline: &str) -> Result<Self, GovernanceError> {
+        Ok(resolve_with(name, Some(inline), |var| env::var(var).ok())?
+            .unwrap_or_else(|| Self::new(inline)))
+    }
+}
+
+/// Resolve against an arbitrary variable lookup, so tests don't touch the process environment
+fn resolve_with(
+    name: &str,
+    inline: Option<&str>,
+    lookup: impl Fn(&str) -> Option<String>,
+) -> Result<Option<Secret>, GovernanceError> {
+    let file_var = format!("{}_FILE", name);
+    if let Some(path) = lookup(&file_var).filter(|p| !p.is_empty()) {
+        return Secret::from_file(&path)
+            .map(Some)
+            .map_err(|e| GovernanceError::ConfigError(format!("{}: {}", file_var, e)));
+    }
+    if let Some(value) = lookup(name).filter(|v| !v.is_empty()) {
+        return Ok(Some(Secret(value)));
+    }
+    Ok(inline.map(Secret::new))
+}
+
+impl fmt::Debug for Secret {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Secret({})", REDACTED)
+    }
+}
+
+impl Serialize for Secret {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(REDACTED)
+    }
+}
+
+impl<'de> Deserialize<'de> for Secret {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer).map(Secret)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+    use tempfile::NamedTempFile;
+
+    fn lookup(vars: &[(&str, &str)]) -> impl Fn(&str) -> Option<String> {
+        let vars: HashMap<String, String> = vars
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect();
+        move |name| vars.get(name).cloned()
+    }
+
+    fn secret_file(contents: &str) -> NamedTempFile {
+        let file = NamedTempFile::new().unwrap();
+        fs::write(file.path(), contents).unwrap();
+        file
+    }
+
+    #[test]
+    fn test_each_source() {
+        let resolved = resolve_with("TOKEN", Some("inline"), lookup(&[])).unwrap();
+        assert_eq!(resolved.unwrap().expose(), "inline");
+
+        let resolved = resolve_with("TOKEN", None, lookup(&[("TOKEN", "from-env")])).unwrap();
+        assert_eq!(resolved.unwrap().expose(), "from-env");
+
+        let file = secret_file("from-file\n");
+        let path = file.path().to_string_lossy().to_string();
+        let resolved = resolve_with("TOKEN", None, lookup(&[("TOKEN_FILE", &path)])).unwrap();
+        assert_eq!(resolved.unwrap().expose(), "from-file");
+
+        assert!(resolve_with("TOKEN", None, lookup(&[])).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_precedence_when_several_sources_set() {
+        let file = secret_file("from-file");
+        let path = file.path().to_string_lossy().to_string();
+
+        let all = lookup(&[("TOKEN_FILE", &path), ("TOKEN", "from-env")]);
+        assert_eq!(resolve_with("TOKEN", Some("inline"), all).unwrap().unwrap().expose(), "from-file");
+
+        let env_and_inline = lookup(&[("TOKEN", "from-env")]);
+        assert_eq!(
+            resolve_with("TOKEN", Some("inline"), env_and_inline).unwrap().unwrap().expose(),
+            "from-env"
+        );
+
+        // Empty variables fall through to the next source
+        let empty = lookup(&[("TOKEN_FILE", ""), ("TOKEN", "")]);
+        assert_eq!(resolve_with("TOKEN", Some("inline"), empty).unwrap().unwrap().expose(), "inline");
+    }
+
+    #[test]
+    fn test_missing_file_names_variable_not_value() {
+        let err = resolve_with("TOKEN", None, lookup(&[("TOKEN_FILE", "/nonexistent/token"), ("TOKEN", "hunter2")]))
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("TOKEN_FILE"));
+        assert!(!err.contains("hunter2"));
+    }
+
+    #[test]
+    fn test_secret_is_redacted() {
+        let secret = Secret::new("hunter2");
+        assert_eq!(format!("{:?}", secret), "Secret([REDACTED])");
+        assert_eq!(serde_json::to_string(&secret).unwrap(), "\"[REDACTED]\"");
+    }
+}
@@ -5,6 +5,7 @@
 
 use rand;
 use std::collections::HashMap;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
@@ -12,9 +13,13 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use sqlx::{Row, SqlitePool};
 use tokio::sync::RwLock;
-use tracing::info;
+use tracing::{debug, info, warn};
 
-use super::signatures::SignatureManager;
+use super::key_backup;
+use super::key_store::{key_store_from_config, KeyStore};
+use super::signatures::{build_key_rotation_message, SignatureManager};
+use crate::database::models::ExpiredKey;
+use crate::database::Database;
 use crate::error::GovernanceError;
 
 /// Key types supported by the system
@@ -162,20 +167,72 @@ pub struct KeyManagementConfig {
     pub rotation_policies: Vec<KeyRotationPolicy>,
 }
 
+/// A maintainer's signature over a rotation request
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RotationApproval {
+    pub signer: String,
+    pub signature: String,
+    pub approved_at: DateTime<Utc>,
+}
+
+/// A key rotation waiting for maintainer approval
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PendingRotation {
+    pub rotation_id: String,
+    pub key_id: String,
+    pub new_owner: Option<String>,
+    pub approval_threshold: u32,
+    pub approvals: Vec<RotationApproval>,
+    pub requested_at: DateTime<Utc>,
+}
+
+impl PendingRotation {
+    /// Message each approving maintainer signs
+    pub fn signing_message(&self) -> String {
+        build_key_rotation_message(
+            &self.rotation_id,
+            &self.key_id,
+            self.new_owner.as_deref(),
+            self.approval_threshold,
+        )
+    }
+}
+
+/// Result of requesting or approving a key rotation
+#[derive(Debug, Clone)]
+pub enum RotationOutcome {
+    /// The old key is revoked and this replacement is active
+    Completed(KeyMetadata),
+    /// More approvals are needed before the key is swapped
+    Pending(PendingRotation),
+}
+
 /// Production key manager
 pub struct KeyManager {
     pool: SqlitePool,
     signature_manager: SignatureManager,
+    key_store: Box<dyn KeyStore>,
     config: KeyManagementConfig,
     key_cache: Arc<RwLock<HashMap<String, KeyMetadata>>>,
 }
 
 impl KeyManager {
-    /// Create a new key manager
-    pub fn new(pool: SqlitePool, config: KeyManagementConfig) -> Self {
+    /// Create a new key manager using the key store selected by `config`
+    pub fn new(pool: SqlitePool, config: KeyManagementConfig) -> Result<Self, GovernanceError> {
+        let key_store = key_store_from_config(&config)?;
+        Ok(Self::with_key_store(pool, config, key_store))
+    }
+
+    /// Create a key manager that keeps private keys in `key_store`
+    pub fn with_key_store(
+        pool: SqlitePool,
+        config: KeyManagementConfig,
+        key_store: Box<dyn KeyStore>,
+    ) -> Self {
         Self {
             pool,
             signature_manager: SignatureManager::new(),
+            key_store,
             config,
             key_cache: Arc::new(RwLock::new(HashMap::new())),
         }
@@ -193,10 +250,9 @@ impl KeyManager {
             owner, key_type
         );
 
-        // Generate key pair using signature manager
-        let keypair = self.signature_manager.generate_keypair()?;
-        let public_key = keypair.public_key.to_string();
+        // Only the public key leaves the key store
         let key_id = self.generate_key_id(&key_type, owner)?;
+        let public_key = self.key_store.generate(&key_id)?;
 
         // Calculate expiration date
         let rotation_period = key_type.rotation_period();
@@ -332,8 +388,80 @@ impl KeyManager {
         }
     }
 
-    /// Update key usage statistics
+    /// Newest managed key with this public key, if key management knows it
+    pub async fn key_id_for_public_key(&self, public_key: &str) -> Result<Option<String>, GovernanceError> {
+        sqlx::query_scalar::<_, String>(
+            "SELECT key_id FROM key_metadata WHERE public_key = ? ORDER BY created_at DESC, id DESC LIMIT 1",
+        )
+        .bind(public_key)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| GovernanceError::DatabaseError(format!("Failed to look up key by public key: {}", e)))
+    }
+
+    /// Reject keys that are not active or are past their expiry
+    ///
+    /// An active key found past `expires_at` is marked `Expired` so it stays
+    /// unusable even if the clock check is skipped elsewhere.
+    pub async fn validate_key_usable(&self, key_id: &str) -> Result<(), GovernanceError> {
+        let metadata = self
+            .get_key_metadata(key_id)
+            .await?
+            .ok_or_else(|| GovernanceError::not_found("Key", key_id))?;
+
+        if metadata.status != KeyStatus::Active {
+            return Err(GovernanceError::CryptoError(format!(
+                "Key {} is {}",
+                key_id,
+                metadata.status.as_str()
+            )));
+        }
+
+        if metadata.expires_at <= Utc::now() {
+            warn!("Key {} expired at {}, marking expired", key_id, metadata.expires_at);
+            self.set_key_status(key_id, KeyStatus::Expired).await?;
+            return Err(GovernanceError::CryptoError(format!(
+                "Key {} expired at {}",
+                key_id, metadata.expires_at
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Sign with a managed key inside its key store, recording the use
+    pub async fn sign(&self, key_id: &str, message: &str) -> Result<String, GovernanceError> {
+        self.validate_key_usable(key_id).await?;
+        let signature = self.key_store.sign(key_id, message)?;
+        self.update_key_usage(key_id).await?;
+        Ok(signature)
+    }
+
+    /// Verify a signature made with a managed key, recording the use on success
+    pub async fn verify_key_signature(
+        &self,
+        key_id: &str,
+        message: &str,
+        signature: &str,
+    ) -> Result<bool, GovernanceError> {
+        self.validate_key_usable(key_id).await?;
+        let metadata = self
+            .get_key_metadata(key_id)
+            .await?
+            .ok_or_else(|| GovernanceError::not_found("Key", key_id))?;
+
+        let verified = self
+            .signature_manager
+            .verify_governance_signature(message, signature, &metadata.public_key)?;
+        if verified {
+            self.update_key_usage(key_id).await?;
+        }
+        Ok(verified)
+    }
+
+    /// Update key usage statistics; fails for keys that are no longer usable
     pub async fn update_key_usage(&self, key_id: &str) -> Result<(), GovernanceError> {
+        self.validate_key_usable(key_id).await?;
         let now = Utc::now();
 
         sqlx::query(
@@ -364,11 +492,15 @@ impl KeyManager {
     }
 
     /// Rotate a key
+    ///
+    /// When the rotation policy for the key type requires approval, this only
+    /// records a pending rotation; the key is swapped by `approve_rotation`
+    /// once `approval_threshold` maintainers have signed the request.
     pub async fn rotate_key(
         &self,
         key_id: &str,
         new_owner: Option<&str>,
-    ) -> Result<KeyMetadata, GovernanceError> {
+    ) -> Result<RotationOutcome, GovernanceError> {
         info!("Rotating key: {}", key_id);
 
         // Get current key metadata
@@ -384,26 +516,283 @@ impl KeyManager {
             ));
         }
 
-        // Generate new key pair
+        let approval_threshold = self
+            .rotation_policy(&current_metadata.key_type)
+            .filter(|policy| policy.require_approval)
+            .map(|policy| policy.approval_threshold)
+            .unwrap_or(0);
+        if approval_threshold == 0 {
+            let new_metadata = self.complete_rotation(&current_metadata, new_owner).await?;
+            return Ok(RotationOutcome::Completed(new_metadata));
+        }
+
+        if let Some(existing) = self.get_pending_rotation_for_key(key_id).await? {
+            return Err(GovernanceError::CryptoError(format!(
+                "Key {} already has pending rotation {}",
+                key_id, existing.rotation_id
+            )));
+        }
+
+        let rotation = PendingRotation {
+            rotation_id: format!("rotation_{}_{}", Utc::now().timestamp(), rand::random::<u32>()),
+            key_id: key_id.to_string(),
+            new_owner: new_owner.map(str::to_string),
+            approval_threshold,
+            approvals: Vec::new(),
+            requested_at: Utc::now(),
+        };
+        sqlx::query(
+            r#"
+            INSERT INTO key_rotations (rotation_id, key_id, new_owner, status, approval_threshold, approvals, requested_at)
+            VALUES (?, ?, ?, 'pending', ?, '[]', ?)
+            "#,
+        )
+        .bind(&rotation.rotation_id)
+        .bind(&rotation.key_id)
+        .bind(&rotation.new_owner)
+        .bind(rotation.approval_threshold as i64)
+        .bind(rotation.requested_at)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| GovernanceError::DatabaseError(format!("Failed to store key rotation: {}", e)))?;
+
+        info!(
+            "Rotation {} of key {} awaiting {} approvals",
+            rotation.rotation_id, key_id, approval_threshold
+        );
+        Ok(RotationOutcome::Pending(rotation))
+    }
+
+    /// Check that `signature` is an active maintainer's approval of `message`
+    async fn verify_rotation_approval(
+        &self,
+        message: &str,
+        rotation_id: &str,
+        signer: &str,
+        signature: &str,
+    ) -> Result<(), GovernanceError> {
+        let public_key = sqlx::query_scalar::<_, String>(
+            "SELECT public_key FROM maintainers WHERE github_username = ? AND active = true",
+        )
+        .bind(signer)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| GovernanceError::DatabaseError(format!("Failed to fetch maintainer: {}", e)))?
+        .ok_or_else(|| {
+            GovernanceError::CryptoError(format!("{} is not an active maintainer", signer))
+        })?;
+
+        if !self
+            .signature_manager
+            .verify_governance_signature(message, signature, &public_key)?
+        {
+            return Err(GovernanceError::CryptoError(format!(
+                "Invalid approval signature from {} for rotation {}",
+                signer, rotation_id
+            )));
+        }
+        Ok(())
+    }
+
+    /// Record a maintainer's signature over a pending rotation, swapping the key once the threshold is met
+    pub async fn approve_rotation(
+        &self,
+        rotation_id: &str,
+        signer: &str,
+        signature: &str,
+    ) -> Result<RotationOutcome, GovernanceError> {
+        let mut rotation = self.get_pending_rotation(rotation_id).await?.ok_or_else(|| {
+            GovernanceError::CryptoError(format!("No pending rotation {}", rotation_id))
+        })?;
+
+        if rotation.approvals.iter().any(|a| a.signer == signer) {
+            return Err(GovernanceError::CryptoError(format!(
+                "{} already approved rotation {}",
+                signer, rotation_id
+            )));
+        }
+
+        // Earlier approvals are checked again, so a rotation whose owner or
+        // threshold was changed after they were given can't complete
+        let message = rotation.signing_message();
+        for approval in &rotation.approvals {
+            self.verify_rotation_approval(&message, rotation_id, &approval.signer, &approval.signature)
+                .await?;
+        }
+        self.verify_rotation_approval(&message, rotation_id, signer, signature)
+            .await?;
+
+        let previous_approvals = serde_json::to_string(&rotation.approvals)?;
+        rotation.approvals.push(RotationApproval {
+            signer: signer.to_string(),
+            signature: signature.to_string(),
+            approved_at: Utc::now(),
+        });
+        let threshold_met = rotation.approvals.len() as u32 >= rotation.approval_threshold;
+
+        // Compare-and-swap on the stored approvals so concurrent approvals can't
+        // overwrite each other or complete the rotation twice
+        let updated = sqlx::query(
+            r#"
+            UPDATE key_rotations
+            SET approvals = ?, status = ?
+            WHERE rotation_id = ? AND status = 'pending' AND approvals = ?
+            "#,
+        )
+        .bind(serde_json::to_string(&rotation.approvals)?)
+        .bind(if threshold_met { "completed" } else { "pending" })
+        .bind(rotation_id)
+        .bind(&previous_approvals)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| GovernanceError::DatabaseError(format!("Failed to record approval: {}", e)))?
+        .rows_affected();
+        if updated == 0 {
+            return Err(GovernanceError::CryptoError(format!(
+                "Rotation {} changed while approving; retry",
+                rotation_id
+            )));
+        }
+
+        if !threshold_met {
+            info!(
+                "Rotation {} approved by {} ({}/{})",
+                rotation_id,
+                signer,
+                rotation.approvals.len(),
+                rotation.approval_threshold
+            );
+            return Ok(RotationOutcome::Pending(rotation));
+        }
+
+        let new_metadata = match self.get_key_metadata(&rotation.key_id).await? {
+            Some(current_metadata) if current_metadata.status == KeyStatus::Active => {
+                self.complete_rotation(&current_metadata, rotation.new_owner.as_deref())
+                    .await
+            }
+            Some(_) => Err(GovernanceError::CryptoError("Key is not active".to_string())),
+            None => Err(GovernanceError::CryptoError("Key not found".to_string())),
+        };
+        let new_metadata = match new_metadata {
+            Ok(new_metadata) => new_metadata,
+            Err(e) => {
+                // Leave the rotation pending, with the approval recorded, so it can be retried
+                sqlx::query("UPDATE key_rotations SET status = 'pending' WHERE rotation_id = ?")
+                    .bind(rotation_id)
+                    .execute(&self.pool)
+                    .await
+                    .map_err(|e| GovernanceError::DatabaseError(format!("Failed to reopen rotation: {}", e)))?;
+                return Err(e);
+            }
+        };
+
+        sqlx::query("UPDATE key_rotations SET new_key_id = ?, completed_at = ? WHERE rotation_id = ?")
+            .bind(&new_metadata.key_id)
+            .bind(Utc::now())
+            .bind(rotation_id)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| GovernanceError::DatabaseError(format!("Failed to complete rotation: {}", e)))?;
+
+        Ok(RotationOutcome::Completed(new_metadata))
+    }
+
+    /// Get a rotation that is still waiting for approvals
+    pub async fn get_pending_rotation(
+        &self,
+        rotation_id: &str,
+    ) -> Result<Option<PendingRotation>, GovernanceError> {
+        let row = sqlx::query(
+            r#"
+            SELECT rotation_id, key_id, new_owner, approval_threshold, approvals, requested_at
+            FROM key_rotations
+            WHERE rotation_id = ? AND status = 'pending'
+            "#,
+        )
+        .bind(rotation_id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| GovernanceError::DatabaseError(format!("Failed to fetch key rotation: {}", e)))?;
+
+        row.map(|row| row_to_pending_rotation(&row)).transpose()
+    }
+
+    async fn get_pending_rotation_for_key(
+        &self,
+        key_id: &str,
+    ) -> Result<Option<PendingRotation>, GovernanceError> {
+        let row = sqlx::query(
+            r#"
+            SELECT rotation_id, key_id, new_owner, approval_threshold, approvals, requested_at
+            FROM key_rotations
+            WHERE key_id = ? AND status = 'pending'
+            "#,
+        )
+        .bind(key_id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| GovernanceError::DatabaseError(format!("Failed to fetch key rotation: {}", e)))?;
+
+        row.map(|row| row_to_pending_rotation(&row)).transpose()
+    }
+
+    fn rotation_policy(&self, key_type: &KeyType) -> Option<&KeyRotationPolicy> {
+        self.config
+            .rotation_policies
+            .iter()
+            .find(|policy| &policy.key_type == key_type)
+    }
+
+    /// Generate the replacement key, activate it and revoke the old one
+    async fn complete_rotation(
+        &self,
+        current_metadata: &KeyMetadata,
+        new_owner: Option<&str>,
+    ) -> Result<KeyMetadata, GovernanceError> {
         let owner = new_owner.unwrap_or(&current_metadata.owner);
-        let new_metadata = self
+        let mut new_metadata = self
             .generate_key_pair(
                 current_metadata.key_type.clone(),
                 owner,
                 Some(current_metadata.metadata.clone()),
             )
             .await?;
+        self.activate_key(&new_metadata.key_id).await?;
+        new_metadata.status = KeyStatus::Active;
 
         // Mark old key as revoked
-        self.revoke_key(key_id, "Key rotated").await?;
+        self.revoke_key(&current_metadata.key_id, "Key rotated").await?;
 
         info!(
             "Key rotated successfully: {} -> {}",
-            key_id, new_metadata.key_id
+            current_metadata.key_id, new_metadata.key_id
         );
         Ok(new_metadata)
     }
 
+    /// Mark a pending key as active
+    pub async fn activate_key(&self, key_id: &str) -> Result<(), GovernanceError> {
+        self.set_key_status(key_id, KeyStatus::Active).await
+    }
+
+    async fn set_key_status(&self, key_id: &str, status: KeyStatus) -> Result<(), GovernanceError> {
+        sqlx::query("UPDATE key_metadata SET status = ? WHERE key_id = ?")
+            .bind(status.as_str())
+            .bind(key_id)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| GovernanceError::DatabaseError(format!("Failed to update key status: {}", e)))?;
+
+        {
+            let mut cache = self.key_cache.write().await;
+            if let Some(metadata) = cache.get_mut(key_id) {
+                metadata.status = status;
+            }
+        }
+
+        Ok(())
+    }
+
     /// Revoke a key
     pub async fn revoke_key(&self, key_id: &str, reason: &str) -> Result<(), GovernanceError> {
         info!("Revoking key: {} (reason: {})", key_id, reason);
@@ -527,6 +916,98 @@ impl KeyManager {
         Ok(keys)
     }
 
+    /// Write an encrypted backup of all key metadata to `backup_location`
+    ///
+    /// Private keys stay in the key store and are not part of the backup.
+    pub async fn backup_keys(&self, passphrase: &str) -> Result<PathBuf, GovernanceError> {
+        if !self.config.backup_enabled {
+            return Err(GovernanceError::ConfigError("Key backups are disabled".to_string()));
+        }
+        let location = self.config.backup_location.as_deref().ok_or_else(|| {
+            GovernanceError::ConfigError("backup_location must be set to back up keys".to_string())
+        })?;
+
+        let rows = sqlx::query(
+            r#"
+            SELECT id, key_id, key_type, owner, public_key, status, created_at, expires_at, last_used, usage_count, metadata
+            FROM key_metadata
+            ORDER BY key_id
+            "#,
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| GovernanceError::DatabaseError(format!("Failed to fetch keys for backup: {}", e)))?;
+        let backup = KeyBackup {
+            created_at: Utc::now(),
+            keys: rows.iter().map(row_to_key_metadata).collect::<Result<_, _>>()?,
+        };
+
+        let archive = key_backup::seal(&serde_json::to_vec(&backup)?, passphrase)?;
+        let path = Path::new(location).join(format!(
+            "key-backup-{}.json",
+            backup.created_at.format("%Y%m%dT%H%M%S%.3fZ")
+        ));
+        std::fs::create_dir_all(location)
+            .and_then(|_| std::fs::write(&path, archive))
+            .map_err(|e| {
+                GovernanceError::CryptoError(format!("Failed to write key backup {}: {}", path.display(), e))
+            })?;
+
+        info!("Backed up {} keys to {}", backup.keys.len(), path.display());
+        Ok(path)
+    }
+
+    /// Restore key metadata from an encrypted backup, replacing keys with the same ID
+    pub async fn restore_keys(&self, path: &Path, passphrase: &str) -> Result<usize, GovernanceError> {
+        let archive = std::fs::read(path).map_err(|e| {
+            GovernanceError::CryptoError(format!("Failed to read key backup {}: {}", path.display(), e))
+        })?;
+        let backup: KeyBackup = serde_json::from_slice(&key_backup::open(&archive, passphrase)?)?;
+
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| GovernanceError::DatabaseError(format!("Failed to start restore: {}", e)))?;
+        for key in &backup.keys {
+            sqlx::query(
+                r#"
+                INSERT INTO key_metadata
+                (key_id, key_type, owner, public_key, status, created_at, expires_at, last_used, usage_count, metadata)
+                VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+                ON CONFLICT(key_id) DO UPDATE SET
+                    key_type = excluded.key_type, owner = excluded.owner, public_key = excluded.public_key,
+                    status = excluded.status, created_at = excluded.created_at, expires_at = excluded.expires_at,
+                    last_used = excluded.last_used, usage_count = excluded.usage_count, metadata = excluded.metadata
+                "#,
+            )
+            .bind(&key.key_id)
+            .bind(key.key_type.as_str())
+            .bind(&key.owner)
+            .bind(&key.public_key)
+            .bind(key.status.as_str())
+            .bind(key.created_at)
+            .bind(key.expires_at)
+            .bind(key.last_used)
+            .bind(key.usage_count as i64)
+            .bind(serde_json::to_string(&key.metadata)?)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| GovernanceError::DatabaseError(format!("Failed to restore key {}: {}", key.key_id, e)))?;
+        }
+        tx.commit()
+            .await
+            .map_err(|e| GovernanceError::DatabaseError(format!("Failed to commit restore: {}", e)))?;
+
+        self.key_cache.write().await.clear();
+        info!(
+            "Restored {} keys from backup taken at {}",
+            backup.keys.len(),
+            backup.created_at
+        );
+        Ok(backup.keys.len())
+    }
+
     /// Get key statistics
     pub async fn get_key_statistics(&self) -> Result<KeyStatistics, GovernanceError> {
         let total_keys = sqlx::query_scalar::<_, i64>("SELECT COUNT(*) FROM key_metadata")
@@ -570,6 +1051,36 @@ impl KeyManager {
     }
 }
 
+/// Expire every active key past its `expires_at`, logging a governance event per key
+pub async fn sweep_expired_keys(
+    database: &Database,
+    now: DateTime<Utc>,
+) -> Result<Vec<ExpiredKey>, GovernanceError> {
+    let expired = database.expire_keys(now).await?;
+    for key in &expired {
+        database
+            .log_governance_event(
+                "key_expired",
+                None,
+                None,
+                Some(&key.owner),
+                &serde_json::json!({
+                    "key_id": key.key_id,
+                    "key_type": key.key_type,
+                    "expires_at": key.expires_at,
+                }),
+            )
+            .await?;
+    }
+
+    if expired.is_empty() {
+        debug!("Key expiry sweep found no expired keys");
+    } else {
+        info!("Key expiry sweep marked {} keys expired", expired.len());
+    }
+    Ok(expired)
+}
+
 /// Key statistics
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct KeyStatistics {
@@ -579,12 +1090,51 @@ pub struct KeyStatistics {
     pub revoked_keys: u64,
 }
 
+/// Plaintext contents of a key backup archive
+#[derive(Debug, Serialize, Deserialize)]
+struct KeyBackup {
+    created_at: DateTime<Utc>,
+    keys: Vec<KeyMetadata>,
+}
+
 // Helper functions for string conversion
 
+fn row_to_key_metadata(row: &sqlx::sqlite::SqliteRow) -> Result<KeyMetadata, GovernanceError> {
+    Ok(KeyMetadata {
+        id: Some(row.get("id")),
+        key_id: row.get::<String, _>("key_id"),
+        key_type: row
+            .get::<String, _>("key_type")
+            .parse()
+            .map_err(|e| GovernanceError::CryptoError(format!("Invalid key type: {}", e)))?,
+        owner: row.get::<String, _>("owner"),
+        public_key: row.get::<String, _>("public_key"),
+        status: row
+            .get::<String, _>("status")
+            .parse()
+            .map_err(|e| GovernanceError::CryptoError(format!("Invalid key status: {}", e)))?,
+        created_at: row.get::<DateTime<Utc>, _>("created_at"),
+        expires_at: row.get::<DateTime<Utc>, _>("expires_at"),
+        last_used: row.get::<Option<DateTime<Utc>>, _>("last_used"),
+        usage_count: row.get::<i64, _>("usage_count") as u64,
+        metadata: serde_json::from_str(&row.get::<String, _>("metadata"))?,
+    })
+}
+
+fn row_to_pending_rotation(row: &sqlx::sqlite::SqliteRow) -> Result<PendingRotation, GovernanceError> {
+    Ok(PendingRotation {
+        rotation_id: row.get::<String, _>("rotation_id"),
+        key_id: row.get::<String, _>("key_id"),
+        new_owner: row.get::<Option<String>, _>("new_owner"),
+        approval_threshold: row.get::<i64, _>("approval_threshold") as u32,
+        approvals: serde_json::from_str(&row.get::<String, _>("approvals"))?,
+        requested_at: row.get::<DateTime<Utc>, _>("requested_at"),
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::database::Database;
 
     #[tokio::test]
     #[ignore] // Skip database tests for now
@@ -599,7 +1149,7 @@ mod tests {
             encryption_enabled: false,
             rotation_policies: vec![],
         };
-        let key_manager = KeyManager::new(db.pool().unwrap().clone(), config);
+        let key_manager = KeyManager::new(db.pool().unwrap().clone(), config)?;
 
         let metadata = key_manager
             .generate_key_pair(KeyType::Maintainer, "test@example.com", None)
@@ -625,7 +1175,7 @@ mod tests {
             encryption_enabled: false,
             rotation_policies: vec![],
         };
-        let key_manager = KeyManager::new(db.pool().unwrap().clone(), config);
+        let key_manager = KeyManager::new(db.pool().unwrap().clone(), config)?;
 
         let metadata = key_manager
             .generate_key_pair(KeyType::Maintainer, "test@example.com", None)
@@ -637,4 +1187,429 @@ mod tests {
 
         Ok(())
     }
+
+    struct RotationFixture {
+        db: Database,
+        key_manager: KeyManager,
+        maintainers: Vec<(String, developer_sdk::governance::GovernanceKeypair)>,
+        key_id: String,
+    }
+
+    impl RotationFixture {
+        fn approval(&self, index: usize, rotation: &PendingRotation) -> (String, String) {
+            let (username, keypair) = &self.maintainers[index];
+            let signature = SignatureManager::new()
+                .create_governance_signature(&rotation.signing_message(), keypair)
+                .unwrap();
+            (username.clone(), signature)
+        }
+    }
+
+    async fn rotation_fixture(require_approval: bool) -> RotationFixture {
+        let db = Database::new_in_memory().await.unwrap();
+        let pool = db.pool().unwrap().clone();
+
+        let mut maintainers = Vec::new();
+        for username in ["alice", "bob", "carol", "dave"] {
+            let keypair = SignatureManager::new().generate_keypair().unwrap();
+            sqlx::query("INSERT INTO maintainers (github_username, public_key, layer) VALUES (?, ?, 3)")
+                .bind(username)
+                .bind(keypair.public_key.to_string())
+                .execute(&pool)
+                .await
+                .unwrap();
+            maintainers.push((username.to_string(), keypair));
+        }
+
+        let config = KeyManagementConfig {
+            hsm_enabled: false,
+            hsm_provider: None,
+            backup_enabled: false,
+            backup_location: None,
+            encryption_enabled: false,
+            rotation_policies: vec![KeyRotationPolicy {
+                key_type: KeyType::Maintainer,
+                rotation_period_days: 365,
+                grace_period_days: 30,
+                auto_rotation: false,
+                require_approval,
+                approval_threshold: 3,
+            }],
+        };
+        let key_manager = KeyManager::new(pool, config).unwrap();
+        let key = key_manager
+            .generate_key_pair(KeyType::Maintainer, "erin", None)
+            .await
+            .unwrap();
+        key_manager.activate_key(&key.key_id).await.unwrap();
+
+        RotationFixture {
+            db,
+            key_manager,
+            maintainers,
+            key_id: key.key_id,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_rotation_stays_pending_until_threshold() {
+        let fixture = rotation_fixture(true).await;
+        let manager = &fixture.key_manager;
+
+        let rotation = match manager.rotate_key(&fixture.key_id, None).await.unwrap() {
+            RotationOutcome::Pending(rotation) => rotation,
+            RotationOutcome::Completed(_) => panic!("rotation should wait for approval"),
+        };
+        assert_eq!(rotation.approval_threshold, 3);
+
+        for index in 0..2 {
+            let (signer, signature) = fixture.approval(index, &rotation);
+            match manager
+                .approve_rotation(&rotation.rotation_id, &signer, &signature)
+                .await
+                .unwrap()
+            {
+                RotationOutcome::Pending(pending) => assert_eq!(pending.approvals.len(), index + 1),
+                RotationOutcome::Completed(_) => panic!("completed after {} approvals", index + 1),
+            }
+            let old_key = manager.get_key_metadata(&fixture.key_id).await.unwrap().unwrap();
+            assert_eq!(old_key.status, KeyStatus::Active);
+        }
+
+        let (signer, signature) = fixture.approval(2, &rotation);
+        let new_key = match manager
+            .approve_rotation(&rotation.rotation_id, &signer, &signature)
+            .await
+            .unwrap()
+        {
+            RotationOutcome::Completed(new_key) => new_key,
+            RotationOutcome::Pending(_) => panic!("third approval should complete the rotation"),
+        };
+        assert_eq!(new_key.status, KeyStatus::Active);
+        assert_eq!(new_key.owner, "erin");
+        let old_key = manager.get_key_metadata(&fixture.key_id).await.unwrap().unwrap();
+        assert_eq!(old_key.status, KeyStatus::Revoked);
+        assert!(manager
+            .get_pending_rotation(&rotation.rotation_id)
+            .await
+            .unwrap()
+            .is_none());
+    }
+
+    #[tokio::test]
+    async fn test_rotation_rejects_duplicate_and_invalid_approvals() {
+        let fixture = rotation_fixture(true).await;
+        let manager = &fixture.key_manager;
+        let RotationOutcome::Pending(rotation) = manager.rotate_key(&fixture.key_id, None).await.unwrap() else {
+            panic!("rotation should wait for approval");
+        };
+
+        let (signer, signature) = fixture.approval(0, &rotation);
+        manager
+            .approve_rotation(&rotation.rotation_id, &signer, &signature)
+            .await
+            .unwrap();
+        assert!(manager
+            .approve_rotation(&rotation.rotation_id, &signer, &signature)
+            .await
+            .is_err());
+
+        // Bob's name with Carol's signature
+        let (_, carol_signature) = fixture.approval(2, &rotation);
+        assert!(manager
+            .approve_rotation(&rotation.rotation_id, "bob", &carol_signature)
+            .await
+            .is_err());
+        assert!(manager
+            .approve_rotation(&rotation.rotation_id, "mallory", &carol_signature)
+            .await
+            .is_err());
+
+        let pending = manager.get_pending_rotation(&rotation.rotation_id).await.unwrap().unwrap();
+        assert_eq!(pending.approvals.len(), 1);
+        assert!(manager.rotate_key(&fixture.key_id, None).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_rotation_approvals_bind_new_owner_and_threshold() {
+        let fixture = rotation_fixture(true).await;
+        let manager = &fixture.key_manager;
+        let RotationOutcome::Pending(rotation) =
+            manager.rotate_key(&fixture.key_id, Some("frank")).await.unwrap()
+        else {
+            panic!("rotation should wait for approval");
+        };
+        assert!(rotation.signing_message().contains(":frank:3"));
+
+        let (signer, signature) = fixture.approval(0, &rotation);
+        manager
+            .approve_rotation(&rotation.rotation_id, &signer, &signature)
+            .await
+            .unwrap();
+
+        // Redirecting the pending rotation invalidates the approval already given
+        sqlx::query("UPDATE key_rotations SET new_owner = 'mallory' WHERE rotation_id = ?")
+            .bind(&rotation.rotation_id)
+            .execute(fixture.db.pool().unwrap())
+            .await
+            .unwrap();
+        let tampered = manager.get_pending_rotation(&rotation.rotation_id).await.unwrap().unwrap();
+        let (signer, signature) = fixture.approval(1, &tampered);
+        assert!(manager
+            .approve_rotation(&rotation.rotation_id, &signer, &signature)
+            .await
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn test_rotation_without_approval_policy_completes_immediately() {
+        let fixture = rotation_fixture(false).await;
+        let outcome = fixture.key_manager.rotate_key(&fixture.key_id, Some("frank")).await.unwrap();
+        let RotationOutcome::Completed(new_key) = outcome else {
+            panic!("rotation should not need approval");
+        };
+        assert_eq!(new_key.owner, "frank");
+        let old_key = fixture.key_manager.get_key_metadata(&fixture.key_id).await.unwrap().unwrap();
+        assert_eq!(old_key.status, KeyStatus::Revoked);
+    }
+
+    async fn store_test_key(
+        manager: &KeyManager,
+        key_id: &str,
+        public_key: String,
+        expires_at: DateTime<Utc>,
+    ) {
+        manager
+            .store_key_metadata(&KeyMetadata {
+                id: None,
+                key_id: key_id.to_string(),
+                key_type: KeyType::Emergency,
+                owner: "alice".to_string(),
+                public_key,
+                status: KeyStatus::Active,
+                created_at: Utc::now() - chrono::Duration::try_days(200).unwrap(),
+                expires_at,
+                last_used: None,
+                usage_count: 0,
+                metadata: HashMap::new(),
+            })
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_active_key_is_usable() {
+        let fixture = rotation_fixture(false).await;
+        let manager = &fixture.key_manager;
+        let keypair = SignatureManager::new().generate_keypair().unwrap();
+        let expires_at = Utc::now() + chrono::Duration::try_days(30).unwrap();
+        store_test_key(manager, "emergency_alice", keypair.public_key.to_string(), expires_at).await;
+
+        let signature = SignatureManager::new()
+            .create_governance_signature("activate emergency tier 1", &keypair)
+            .unwrap();
+        assert!(manager
+            .verify_key_signature("emergency_alice", "activate emergency tier 1", &signature)
+            .await
+            .unwrap());
+        let key = manager.get_key_metadata("emergency_alice").await.unwrap().unwrap();
+        assert_eq!(key.usage_count, 1);
+    }
+
+    #[tokio::test]
+    async fn test_expired_key_is_rejected_and_marked_expired() {
+        let fixture = rotation_fixture(false).await;
+        let manager = &fixture.key_manager;
+        let keypair = SignatureManager::new().generate_keypair().unwrap();
+        let expires_at = Utc::now() - chrono::Duration::try_days(1).unwrap();
+        store_test_key(manager, "emergency_alice", keypair.public_key.to_string(), expires_at).await;
+
+        let signature = SignatureManager::new()
+            .create_governance_signature("activate emergency tier 1", &keypair)
+            .unwrap();
+        let error = manager
+            .verify_key_signature("emergency_alice", "activate emergency tier 1", &signature)
+            .await
+            .unwrap_err();
+        assert!(error.to_string().contains("expired"));
+
+        let key = manager.get_key_metadata("emergency_alice").await.unwrap().unwrap();
+        assert_eq!(key.status, KeyStatus::Expired);
+        assert_eq!(key.usage_count, 0);
+        assert!(manager.update_key_usage("emergency_alice").await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_revoked_key_is_rejected() {
+        let fixture = rotation_fixture(false).await;
+        let manager = &fixture.key_manager;
+        let expires_at = Utc::now() + chrono::Duration::try_days(30).unwrap();
+        store_test_key(manager, "emergency_alice", "02ab".to_string(), expires_at).await;
+        manager.revoke_key("emergency_alice", "compromised laptop").await.unwrap();
+
+        let error = manager.validate_key_usable("emergency_alice").await.unwrap_err();
+        assert!(error.to_string().contains("revoked"));
+        assert!(manager.update_key_usage("emergency_alice").await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_expiry_sweep_marks_keys_expired_and_logs_events() {
+        let fixture = rotation_fixture(false).await;
+        let manager = &fixture.key_manager;
+        let now = Utc::now();
+        store_test_key(manager, "emergency_old", "02ab".to_string(), now - chrono::Duration::try_days(1).unwrap()).await;
+        store_test_key(manager, "emergency_new", "02cd".to_string(), now + chrono::Duration::try_days(1).unwrap()).await;
+
+        let expired = sweep_expired_keys(&fixture.db, now).await.unwrap();
+        assert_eq!(expired.len(), 1);
+        assert_eq!(expired[0].key_id, "emergency_old");
+
+        let statistics = manager.get_key_statistics().await.unwrap();
+        assert_eq!(statistics.expired_keys, 1);
+        let status: String = sqlx::query_scalar("SELECT status FROM key_metadata WHERE key_id = 'emergency_new'")
+            .fetch_one(fixture.db.pool().unwrap())
+            .await
+            .unwrap();
+        assert_eq!(status, "active");
+
+        let events: Vec<String> = sqlx::query_scalar(
+            "SELECT details FROM governance_events WHERE event_type = 'key_expired'",
+        )
+        .fetch_all(fixture.db.pool().unwrap())
+        .await
+        .unwrap();
+        assert_eq!(events.len(), 1);
+        assert!(events[0].contains("emergency_old"));
+
+        // A second sweep has nothing left to do
+        assert!(sweep_expired_keys(&fixture.db, now).await.unwrap().is_empty());
+    }
+
+    /// Key store that records what it is asked to do and holds a recognizable fake secret
+    #[derive(Clone, Default)]
+    struct MockKeyStore {
+        calls: Arc<std::sync::Mutex<Vec<String>>>,
+    }
+
+    const MOCK_SECRET: &str = "mock-private-key-material";
+
+    impl KeyStore for MockKeyStore {
+        fn generate(&self, key_id: &str) -> Result<String, GovernanceError> {
+            self.calls.lock().unwrap().push(format!("generate {}", key_id));
+            Ok(format!("02{}", hex::encode(key_id)))
+        }
+
+        fn sign(&self, key_id: &str, message: &str) -> Result<String, GovernanceError> {
+            self.calls.lock().unwrap().push(format!("sign {} {}", key_id, message));
+            Ok(hex::encode(format!("{}:{}", MOCK_SECRET, message)))
+        }
+
+        fn public_key(&self, key_id: &str) -> Result<String, GovernanceError> {
+            Ok(format!("02{}", hex::encode(key_id)))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_hsm_key_store_handles_generation_and_signing() {
+        let db = Database::new_in_memory().await.unwrap();
+        let store = MockKeyStore::default();
+        let config = KeyManagementConfig {
+            hsm_enabled: true,
+            hsm_provider: Some("/usr/lib/softhsm/libsofthsm2.so".to_string()),
+            backup_enabled: false,
+            backup_location: None,
+            encryption_enabled: false,
+            rotation_policies: vec![],
+        };
+        let manager = KeyManager::with_key_store(db.pool().unwrap().clone(), config, Box::new(store.clone()));
+
+        let key = manager
+            .generate_key_pair(KeyType::Emergency, "alice", None)
+            .await
+            .unwrap();
+        assert_eq!(key.public_key, store.public_key(&key.key_id).unwrap());
+        manager.activate_key(&key.key_id).await.unwrap();
+
+        let signature = manager.sign(&key.key_id, "activate emergency tier 1").await.unwrap();
+        assert_eq!(signature, hex::encode(format!("{}:activate emergency tier 1", MOCK_SECRET)));
+        assert_eq!(
+            *store.calls.lock().unwrap(),
+            vec![
+                format!("generate {}", key.key_id),
+                format!("sign {} activate emergency tier 1", key.key_id),
+            ]
+        );
+
+        // Nothing but the public key reaches the database
+        let row: (String, String) = sqlx::query_as("SELECT public_key, metadata FROM key_metadata WHERE key_id = ?")
+            .bind(&key.key_id)
+            .fetch_one(db.pool().unwrap())
+            .await
+            .unwrap();
+        assert_eq!(row.0, key.public_key);
+        assert!(!row.1.contains(MOCK_SECRET) && !row.1.contains(&hex::encode(MOCK_SECRET)));
+    }
+
+    #[test]
+    fn test_hsm_requires_a_provider() {
+        let config = KeyManagementConfig {
+            hsm_enabled: true,
+            hsm_provider: None,
+            backup_enabled: false,
+            backup_location: None,
+            encryption_enabled: false,
+            rotation_policies: vec![],
+        };
+        assert!(matches!(
+            key_store_from_config(&config),
+            Err(GovernanceError::ConfigError(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_backup_and_restore_round_trip() {
+        let db = Database::new_in_memory().await.unwrap();
+        let backup_dir = tempfile::tempdir().unwrap();
+        let config = KeyManagementConfig {
+            hsm_enabled: false,
+            hsm_provider: None,
+            backup_enabled: true,
+            backup_location: Some(backup_dir.path().display().to_string()),
+            encryption_enabled: true,
+            rotation_policies: vec![],
+        };
+        let manager = KeyManager::new(db.pool().unwrap().clone(), config.clone()).unwrap();
+        let kept = manager
+            .generate_key_pair(KeyType::Maintainer, "alice", Some(HashMap::from([("team".to_string(), "core".to_string())])))
+            .await
+            .unwrap();
+        manager.activate_key(&kept.key_id).await.unwrap();
+        let revoked = manager.generate_key_pair(KeyType::Emergency, "bob", None).await.unwrap();
+        manager.revoke_key(&revoked.key_id, "left the project").await.unwrap();
+
+        let key_set = |db: Database| async move {
+            sqlx::query_as::<_, (String, String, String, String, String)>(
+                "SELECT key_id, key_type, owner, public_key, status FROM key_metadata ORDER BY key_id",
+            )
+            .fetch_all(db.pool().unwrap())
+            .await
+            .unwrap()
+        };
+        let before = key_set(db.clone()).await;
+        assert_eq!(before.len(), 2);
+
+        let path = manager.backup_keys("correct horse").await.unwrap();
+        let archive = std::fs::read_to_string(&path).unwrap();
+        assert!(!archive.contains(&kept.public_key));
+
+        sqlx::query("DELETE FROM key_metadata").execute(db.pool().unwrap()).await.unwrap();
+        let restorer = KeyManager::new(db.pool().unwrap().clone(), config).unwrap();
+        assert!(restorer.restore_keys(&path, "battery staple").await.is_err());
+        assert!(key_set(db.clone()).await.is_empty());
+
+        assert_eq!(restorer.restore_keys(&path, "correct horse").await.unwrap(), 2);
+        assert_eq!(key_set(db.clone()).await, before);
+        let restored = restorer.get_key_metadata(&revoked.key_id).await.unwrap().unwrap();
+        assert_eq!(restored.metadata["revocation_reason"], "left the project");
+    }
 }
@@ -0,0 +1,231 @@
+//! Key Stores
+//!
+//! Where private key material lives. `KeyManager` only ever sees public keys
+//! and signatures; generation and signing are delegated to a `KeyStore`, so
+//! with an HSM the private keys never leave the device.
+
+use std::collections::HashMap;
+use std::sync::RwLock;
+
+use developer_sdk::governance::GovernanceKeypair;
+
+use super::key_management::KeyManagementConfig;
+use super::signatures::SignatureManager;
+use crate::error::GovernanceError;
+
+/// Holds private keys and performs operations with them
+pub trait KeyStore: Send + Sync {
+    /// Create a key pair stored under `key_id`, returning the hex public key
+    fn generate(&self, key_id: &str) -> Result<String, GovernanceError>;
+
+    /// Sign `message` with the private key stored under `key_id`, returning a hex signature
+    fn sign(&self, key_id: &str, message: &str) -> Result<String, GovernanceError>;
+
+    /// Hex public key of the key pair stored under `key_id`
+    fn public_key(&self, key_id: &str) -> Result<String, GovernanceError>;
+}
+
+/// Select the key store described by the configuration
+pub fn key_store_from_config(config: &KeyManagementConfig) -> Result<Box<dyn KeyStore>, GovernanceError> {
+    if !config.hsm_enabled {
+        return Ok(Box::new(InMemoryKeyStore::new()));
+    }
+
+    let module = config.hsm_provider.as_deref().ok_or_else(|| {
+        GovernanceError::ConfigError("hsm_provider must name a PKCS#11 module when HSM is enabled".to_string())
+    })?;
+
+    #[cfg(feature = "hsm")]
+    {
+        Ok(Box::new(pkcs11::Pkcs11KeyStore::open(module)?))
+    }
+    #[cfg(not(feature = "hsm"))]
+    {
+        Err(GovernanceError::ConfigError(format!(
+            "HSM is enabled with module {} but this build lacks the `hsm` feature",
+            module
+        )))
+    }
+}
+
+/// Process-local key store; keys are lost on restart
+pub struct InMemoryKeyStore {
+    signature_manager: SignatureManager,
+    keys: RwLock<HashMap<String, GovernanceKeypair>>,
+}
+
+impl InMemoryKeyStore {
+    pub fn new() -> Self {
+        Self {
+            signature_manager: SignatureManager::new(),
+            keys: RwLock::new(HashMap::new()),
+        }
+    }
+}
+
+impl Default for InMemoryKeyStore {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl KeyStore for InMemoryKeyStore {
+    fn generate(&self, key_id: &str) -> Result<String, GovernanceError> {
+        let keypair = self.signature_manager.generate_keypair()?;
+        let public_key = keypair.public_key.to_string();
+        self.keys
+            .write()
+            .map_err(|_| GovernanceError::CryptoError("Key store lock poisoned".to_string()))?
+            .insert(key_id.to_string(), keypair);
+        Ok(public_key)
+    }
+
+    fn sign(&self, key_id: &str, message: &str) -> Result<String, GovernanceError> {
+        let keys = self
+            .keys
+            .read()
+            .map_err(|_| GovernanceError::CryptoError("Key store lock poisoned".to_string()))?;
+        let keypair = keys
+            .get(key_id)
+            .ok_or_else(|| GovernanceError::CryptoError(format!("No private key for {}", key_id)))?;
+        self.signature_manager.create_governance_signature(message, keypair)
+    }
+
+    fn public_key(&self, key_id: &str) -> Result<String, GovernanceError> {
+        self.keys
+            .read()
+            .map_err(|_| GovernanceError::CryptoError("Key store lock poisoned".to_string()))?
+            .get(key_id)
+            .map(|keypair| keypair.public_key.to_string())
+            .ok_or_else(|| GovernanceError::CryptoError(format!("No key pair for {}", key_id)))
+    }
+}
+
+#[cfg(feature = "hsm")]
+mod pkcs11 {
+    //! secp256k1 keys generated and used inside a PKCS#11 token
+
+    use cryptoki::context::{CInitializeArgs, Pkcs11};
+    use cryptoki::mechanism::Mechanism;
+    use cryptoki::object::{Attribute, AttributeType, ObjectClass, ObjectHandle};
+    use cryptoki::session::{Session, UserType};
+    use cryptoki::types::AuthPin;
+    use developer_sdk::governance::Signature as GovernanceSignature;
+    use sha2::{Digest, Sha256};
+    use std::sync::Mutex;
+
+    use super::KeyStore;
+    use crate::config::Secret;
+    use crate::error::GovernanceError;
+
+    /// DER-encoded OID 1.3.132.0.10 (secp256k1)
+    const SECP256K1_PARAMS: &[u8] = &[0x06, 0x05, 0x2b, 0x81, 0x04, 0x00, 0x0a];
+
+    fn hsm_err(e: cryptoki::error::Error) -> GovernanceError {
+        GovernanceError::CryptoError(format!("HSM error: {}", e))
+    }
+
+    /// Key store backed by the first slot with a token; the PIN comes from `HSM_PIN`/`HSM_PIN_FILE`
+    pub struct Pkcs11KeyStore {
+        session: Mutex<Session>,
+    }
+
+    impl Pkcs11KeyStore {
+        pub fn open(module: &str) -> Result<Self, GovernanceError> {
+            let pkcs11 = Pkcs11::new(module).map_err(hsm_err)?;
+            pkcs11.initialize(CInitializeArgs::OsThreads).map_err(hsm_err)?;
+            let slot = pkcs11
+                .get_slots_with_token()
+                .map_err(hsm_err)?
+                .into_iter()
+                .next()
+                .ok_or_else(|| GovernanceError::CryptoError("No HSM slot with a token".to_string()))?;
+            let session = pkcs11.open_rw_session(slot).map_err(hsm_err)?;
+            let pin = Secret::resolve("HSM_PIN", "")?;
+            session
+                .login(UserType::User, Some(&AuthPin::new(pin.expose().to_string())))
+                .map_err(hsm_err)?;
+            Ok(Self {
+                session: Mutex::new(session),
+            })
+        }
+
+        fn session(&self) -> Result<std::sync::MutexGuard<'_, Session>, GovernanceError> {
+            self.session
+                .lock()
+                .map_err(|_| GovernanceError::CryptoError("HSM session lock poisoned".to_string()))
+        }
+
+        fn find(session: &Session, key_id: &str, class: ObjectClass) -> Result<ObjectHandle, GovernanceError> {
+            session
+                .find_objects(&[Attribute::Class(class), Attribute::Label(key_id.as_bytes().to_vec())])
+                .map_err(hsm_err)?
+                .into_iter()
+                .next()
+                .ok_or_else(|| GovernanceError::CryptoError(format!("No HSM key for {}", key_id)))
+        }
+
+        fn read_public_key(session: &Session, handle: ObjectHandle) -> Result<String, GovernanceError> {
+            let attributes = session
+                .get_attributes(handle, &[AttributeType::EcPoint])
+                .map_err(hsm_err)?;
+            let Some(Attribute::EcPoint(point)) = attributes.into_iter().next() else {
+                return Err(GovernanceError::CryptoError("HSM key has no EC point".to_string()));
+            };
+            // CKA_EC_POINT is a DER OCTET STRING wrapping the uncompressed point
+            let point = point.get(point.len().saturating_sub(65)..).unwrap_or_default();
+            let public_key = secp256k1::PublicKey::from_slice(point)
+                .map_err(|e| GovernanceError::CryptoError(format!("Invalid HSM public key: {}", e)))?;
+            Ok(public_key.to_string())
+        }
+    }
+
+    impl KeyStore for Pkcs11KeyStore {
+        fn generate(&self, key_id: &str) -> Result<String, GovernanceError> {
+            let session = self.session()?;
+            let label = Attribute::Label(key_id.as_bytes().to_vec());
+            let (public, _private) = session
+                .generate_key_pair(
+                    &Mechanism::EccKeyPairGen,
+                    &[
+                        Attribute::Token(true),
+                        Attribute::Verify(true),
+                        Attribute::EcParams(SECP256K1_PARAMS.to_vec()),
+                        label.clone(),
+                    ],
+                    &[
+                        Attribute::Token(true),
+                        Attribute::Private(true),
+                        Attribute::Sensitive(true),
+                        Attribute::Extractable(false),
+                        Attribute::Sign(true),
+                        label,
+                    ],
+                )
+                .map_err(hsm_err)?;
+            Self::read_public_key(&session, public)
+        }
+
+        fn sign(&self, key_id: &str, message: &str) -> Result<String, GovernanceError> {
+            let session = self.session()?;
+            let private = Self::find(&session, key_id, ObjectClass::PRIVATE_KEY)?;
+            let digest = Sha256::digest(message.as_bytes());
+            let raw = session
+                .sign(&Mechanism::Ecdsa, private, &digest)
+                .map_err(hsm_err)?;
+            // Tokens return r || s; normalize to low-S like secp256k1 signers do
+            let mut signature = secp256k1::ecdsa::Signature::from_compact(&raw)
+                .map_err(|e| GovernanceError::CryptoError(format!("Invalid HSM signature: {}", e)))?;
+            signature.normalize_s();
+            let signature = GovernanceSignature::from_bytes(&signature.serialize_compact())
+                .map_err(|e| GovernanceError::CryptoError(format!("Invalid HSM signature: {}", e)))?;
+            Ok(signature.to_string())
+        }
+
+        fn public_key(&self, key_id: &str) -> Result<String, GovernanceError> {
+            let session = self.session()?;
+            let public = Self::find(&session, key_id, ObjectClass::PUBLIC_KEY)?;
+            Self::read_public_key(&session, public)
+        }
+    }
+}
@@ -1,3 +1,5 @@
+pub mod key_backup;
 pub mod key_management;
+pub mod key_store;
 pub mod multisig;
 pub mod signatures;
@@ -1,6 +1,6 @@
 use crate::crypto::signatures::SignatureManager;
 use crate::error::GovernanceError;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 pub struct MultisigManager {
     signature_manager: SignatureManager,
@@ -13,6 +13,10 @@ impl MultisigManager {
         }
     }
 
+    /// Check that enough distinct maintainers signed `message`
+    ///
+    /// Each signer counts once, however many signatures they submitted, and
+    /// signers without an entry in `public_keys` are ignored.
     pub fn verify_multisig(
         &self,
         message: &str,
@@ -21,41 +25,9 @@ impl MultisigManager {
         required_threshold: (usize, usize),    // (required, total)
     ) -> Result<bool, GovernanceError> {
         let (required, total) = required_threshold;
-        let mut valid_signatures = 0;
-        let mut verified_signers = Vec::new();
-
-        for (signer, signature) in signatures {
-            if let Some(public_key_str) = public_keys.get(signer) {
-                // Parse public key
-                let public_key = public_key_str
-                    .parse::<secp256k1::PublicKey>()
-                    .map_err(|e| {
-                        GovernanceError::CryptoError(format!(
-                            "Invalid public key for {}: {}",
-                            signer, e
-                        ))
-                    })?;
-
-                // Parse signature
-                let sig = signature
-                    .parse::<secp256k1::ecdsa::Signature>()
-                    .map_err(|e| {
-                        GovernanceError::CryptoError(format!(
-                            "Invalid signature from {}: {}",
-                            signer, e
-                        ))
-                    })?;
-
-                // Verify signature
-                if self
-                    .signature_manager
-                    .verify_signature(message, &sig, &public_key)?
-                {
-                    valid_signatures += 1;
-                    verified_signers.push(signer.clone());
-                }
-            }
-        }
+        let valid_signatures = self
+            .get_verified_signers(message, signatures, public_keys)?
+            .len();
 
         if valid_signatures >= required {
             Ok(true)
@@ -70,6 +42,7 @@ impl MultisigManager {
         }
     }
 
+    /// Distinct signers whose signature over `message` verifies against their listed key
     pub fn get_verified_signers(
         &self,
         message: &str,
@@ -77,33 +50,41 @@ impl MultisigManager {
         public_keys: &HashMap<String, String>,
     ) -> Result<Vec<String>, GovernanceError> {
         let mut verified_signers = Vec::new();
+        // Two usernames sharing a key are still one signer
+        let mut verified_keys = HashSet::new();
 
         for (signer, signature) in signatures {
-            if let Some(public_key_str) = public_keys.get(signer) {
-                let public_key = public_key_str
-                    .parse::<secp256k1::PublicKey>()
-                    .map_err(|e| {
-                        GovernanceError::CryptoError(format!(
-                            "Invalid public key for {}: {}",
-                            signer, e
-                        ))
-                    })?;
-
-                let sig = signature
-                    .parse::<secp256k1::ecdsa::Signature>()
-                    .map_err(|e| {
-                        GovernanceError::CryptoError(format!(
-                            "Invalid signature from {}: {}",
-                            signer, e
-                        ))
-                    })?;
-
-                if self
-                    .signature_manager
-                    .verify_signature(message, &sig, &public_key)?
-                {
-                    verified_signers.push(signer.clone());
-                }
+            let Some(public_key_str) = public_keys.get(signer) else {
+                continue;
+            };
+            if verified_keys.contains(public_key_str) {
+                continue;
+            }
+
+            let public_key = public_key_str
+                .parse::<secp256k1::PublicKey>()
+                .map_err(|e| {
+                    GovernanceError::CryptoError(format!(
+                        "Invalid public key for {}: {}",
+                        signer, e
+                    ))
+                })?;
+
+            let sig = signature
+                .parse::<secp256k1::ecdsa::Signature>()
+                .map_err(|e| {
+                    GovernanceError::CryptoError(format!(
+                        "Invalid signature from {}: {}",
+                        signer, e
+                    ))
+                })?;
+
+            if self
+                .signature_manager
+                .verify_signature(message, &sig, &public_key)?
+            {
+                verified_keys.insert(public_key_str);
+                verified_signers.push(signer.clone());
             }
         }
 
@@ -116,3 +97,64 @@ impl Default for MultisigManager {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MESSAGE: &str = "PR #42 in BTCDecoded/protocol-engine";
+
+    /// Three maintainers' public keys and each one's signature over `MESSAGE`
+    fn signed_by_three() -> (HashMap<String, String>, Vec<(String, String)>) {
+        let signature_manager = SignatureManager::new();
+        let mut public_keys = HashMap::new();
+        let mut signatures = Vec::new();
+        for signer in ["alice", "bob", "carol"] {
+            let keypair = signature_manager.generate_keypair().unwrap();
+            let signature = signature_manager
+                .create_signature(MESSAGE, &keypair.secret_key)
+                .unwrap();
+            public_keys.insert(signer.to_string(), keypair.public_key.to_string());
+            signatures.push((signer.to_string(), signature.to_string()));
+        }
+        (public_keys, signatures)
+    }
+
+    #[test]
+    fn test_duplicate_signer_counts_once() {
+        let (public_keys, signatures) = signed_by_three();
+        let doubled = vec![signatures[0].clone(), signatures[0].clone()];
+
+        let result =
+            MultisigManager::new().verify_multisig(MESSAGE, &doubled, &public_keys, (2, 3));
+        assert!(matches!(result, Err(GovernanceError::ThresholdError(_))));
+    }
+
+    #[test]
+    fn test_unknown_signer_is_ignored() {
+        let (mut public_keys, signatures) = signed_by_three();
+        public_keys.remove("carol");
+        let manager = MultisigManager::new();
+
+        let verified = manager
+            .get_verified_signers(MESSAGE, &signatures, &public_keys)
+            .unwrap();
+        assert_eq!(verified, vec!["alice".to_string(), "bob".to_string()]);
+        assert!(manager
+            .verify_multisig(MESSAGE, &signatures, &public_keys, (3, 3))
+            .is_err());
+    }
+
+    #[test]
+    fn test_exact_threshold_of_distinct_signers_passes() {
+        let (public_keys, signatures) = signed_by_three();
+        let manager = MultisigManager::new();
+
+        assert!(manager
+            .verify_multisig(MESSAGE, &signatures[..2], &public_keys, (2, 3))
+            .unwrap());
+        assert!(manager
+            .verify_multisig(MESSAGE, &signatures[..1], &public_keys, (2, 3))
+            .is_err());
+    }
+}
@@ -3,8 +3,104 @@ use developer_sdk::governance::{
     signatures::sign_message, verify_signature, GovernanceKeypair,
     PublicKey as GovernancePublicKey, Signature as GovernanceSignature,
 };
-use secp256k1::{ecdsa::Signature, PublicKey, Secp256k1, SecretKey};
+use secp256k1::{
+    ecdsa::Signature, schnorr, Keypair, PublicKey, Secp256k1, SecretKey, XOnlyPublicKey,
+};
+use serde::{Deserialize, Serialize};
 use sha2::{Digest, Sha256};
+use std::str::FromStr;
+
+/// Algorithm a governance signature was made with
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SignatureScheme {
+    /// ECDSA over secp256k1 in the developer-sdk encoding; what all existing signatures use
+    #[default]
+    EcdsaSecp256k1,
+    /// BIP340 Schnorr over SHA-256 of the message, as held by Taproot keys
+    SchnorrBip340,
+}
+
+impl SignatureScheme {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SignatureScheme::EcdsaSecp256k1 => "ecdsa_secp256k1",
+            SignatureScheme::SchnorrBip340 => "schnorr_bip340",
+        }
+    }
+}
+
+impl FromStr for SignatureScheme {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "ecdsa_secp256k1" | "ecdsa" => Ok(SignatureScheme::EcdsaSecp256k1),
+            "schnorr_bip340" | "schnorr" | "bip340" => Ok(SignatureScheme::SchnorrBip340),
+            _ => Err(format!("Unknown signature scheme: {}", s)),
+        }
+    }
+}
+
+/// Domain tag and format version opening every PR signing message
+pub const SIGNING_DOMAIN: &str = "btcdecoded-governance:v1";
+
+/// Canonical message a maintainer signs to approve a PR
+///
+/// `btcdecoded-governance:v1:{repo}:{pr}:{head_sha}:{tier}`. The domain tag
+/// keeps the signature from matching anything else the key signs, and the
+/// remaining fields bind it to one PR state: a signature for another PR, an
+/// older head or a different tier does not verify.
+pub fn build_signing_message(repo_name: &str, pr_number: i32, head_sha: &str, tier: u32) -> String {
+    format!("{}:{}:{}:{}:{}", SIGNING_DOMAIN, repo_name, pr_number, head_sha, tier)
+}
+
+/// Change to the maintainer set that existing maintainers approve
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MaintainerChange<'a> {
+    Add { github_username: &'a str, layer: i32, public_key: &'a str },
+    Remove { github_username: &'a str },
+}
+
+/// Canonical message a maintainer signs to approve a maintainer set change
+///
+/// `btcdecoded-governance:v1:maintainer-add:{sequence}:{username}:{layer}:{public_key}`
+/// or `...:maintainer-remove:{sequence}:{username}`. `sequence` counts the
+/// changes applied so far, so an approval is spent once its change lands.
+pub fn build_maintainer_change_message(sequence: i64, change: &MaintainerChange<'_>) -> String {
+    match change {
+        MaintainerChange::Add { github_username, layer, public_key } => format!(
+            "{}:maintainer-add:{}:{}:{}:{}",
+            SIGNING_DOMAIN, sequence, github_username, layer, public_key
+        ),
+        MaintainerChange::Remove { github_username } => format!(
+            "{}:maintainer-remove:{}:{}",
+            SIGNING_DOMAIN, sequence, github_username
+        ),
+    }
+}
+
+/// Canonical message a maintainer signs to approve a key rotation
+///
+/// `btcdecoded-governance:v1:key-rotation:{rotation_id}:{key_id}:{new_owner}:{threshold}`,
+/// with `new_owner` empty when the key keeps its owner. Covering the owner and
+/// threshold means neither can be changed on a pending rotation without
+/// invalidating the approvals already collected.
+pub fn build_key_rotation_message(
+    rotation_id: &str,
+    key_id: &str,
+    new_owner: Option<&str>,
+    approval_threshold: u32,
+) -> String {
+    format!(
+        "{}:key-rotation:{}:{}:{}:{}",
+        SIGNING_DOMAIN,
+        rotation_id,
+        key_id,
+        new_owner.unwrap_or(""),
+        approval_threshold
+    )
+}
 
 pub struct SignatureManager {
     secp: Secp256k1<secp256k1::All>,
@@ -59,12 +155,7 @@ impl SignatureManager {
             GovernanceError::CryptoError(format!("Invalid signature format: {}", e))
         })?;
 
-        // Parse public key from hex string
-        let public_key_bytes = hex::decode(public_key)
-            .map_err(|e| GovernanceError::CryptoError(format!("Invalid public key hex: {}", e)))?;
-        let public_key = GovernancePublicKey::from_bytes(&public_key_bytes).map_err(|e| {
-            GovernanceError::CryptoError(format!("Invalid public key format: {}", e))
-        })?;
+        let public_key = Self::parse_governance_public_key(public_key)?;
 
         // Use developer-sdk's verify_signature function
         verify_signature(&signature, message.as_bytes(), &public_key).map_err(|e| {
@@ -72,6 +163,25 @@ impl SignatureManager {
         })
     }
 
+    /// Parse a hex public key in the encoding `verify_governance_signature` expects
+    fn parse_governance_public_key(public_key: &str) -> Result<GovernancePublicKey, GovernanceError> {
+        let public_key_bytes = hex::decode(public_key)
+            .map_err(|e| GovernanceError::CryptoError(format!("Invalid public key hex: {}", e)))?;
+        GovernancePublicKey::from_bytes(&public_key_bytes).map_err(|e| {
+            GovernanceError::CryptoError(format!("Invalid public key format: {}", e))
+        })
+    }
+
+    /// Check that `public_key` is a key governance signatures can be verified against
+    pub fn validate_public_key(public_key: &str) -> Result<(), GovernanceError> {
+        Self::parse_governance_public_key(public_key)
+            .map(|_| ())
+            .map_err(|e| match e {
+                GovernanceError::CryptoError(message) => GovernanceError::ValidationError(message),
+                other => other,
+            })
+    }
+
     /// Create signature using developer-sdk governance primitives
     pub fn create_governance_signature(
         &self,
@@ -86,6 +196,72 @@ impl SignatureManager {
         Ok(signature.to_string())
     }
 
+    /// Sign `message` under `scheme`, returning the hex signature
+    pub fn sign_with_scheme(
+        &self,
+        message: &str,
+        secret_key: &SecretKey,
+        scheme: SignatureScheme,
+    ) -> Result<String, GovernanceError> {
+        match scheme {
+            SignatureScheme::EcdsaSecp256k1 => sign_message(secret_key, message.as_bytes())
+                .map(|signature| signature.to_string())
+                .map_err(|e| {
+                    GovernanceError::CryptoError(format!("Signature creation failed: {}", e))
+                }),
+            SignatureScheme::SchnorrBip340 => {
+                let keypair = Keypair::from_secret_key(&self.secp, secret_key);
+                let aux_rand: [u8; 32] = rand::random();
+                let signature = self.secp.sign_schnorr_with_aux_rand(
+                    &Self::message_digest(message)?,
+                    &keypair,
+                    &aux_rand,
+                );
+                Ok(hex::encode(signature.as_ref()))
+            }
+        }
+    }
+
+    /// Verify a hex signature made under `scheme`
+    ///
+    /// Schnorr public keys may be given x-only (32 bytes) or compressed (33 bytes).
+    pub fn verify_with_scheme(
+        &self,
+        message: &str,
+        signature: &str,
+        public_key: &str,
+        scheme: SignatureScheme,
+    ) -> Result<bool, GovernanceError> {
+        match scheme {
+            SignatureScheme::EcdsaSecp256k1 => {
+                self.verify_governance_signature(message, signature, public_key)
+            }
+            SignatureScheme::SchnorrBip340 => {
+                let signature_bytes = hex::decode(signature).map_err(|e| {
+                    GovernanceError::CryptoError(format!("Invalid signature hex: {}", e))
+                })?;
+                let signature = schnorr::Signature::from_slice(&signature_bytes).map_err(|e| {
+                    GovernanceError::CryptoError(format!("Invalid Schnorr signature: {}", e))
+                })?;
+                let public_key = match public_key.len() {
+                    64 => XOnlyPublicKey::from_str(public_key),
+                    _ => PublicKey::from_str(public_key).map(|key| key.x_only_public_key().0),
+                }
+                .map_err(|e| GovernanceError::CryptoError(format!("Invalid public key: {}", e)))?;
+
+                Ok(self
+                    .secp
+                    .verify_schnorr(&signature, &Self::message_digest(message)?, &public_key)
+                    .is_ok())
+            }
+        }
+    }
+
+    fn message_digest(message: &str) -> Result<secp256k1::Message, GovernanceError> {
+        secp256k1::Message::from_digest_slice(&Sha256::digest(message.as_bytes()))
+            .map_err(|e| GovernanceError::CryptoError(format!("Invalid message hash: {}", e)))
+    }
+
     pub fn public_key_from_secret(&self, secret_key: &SecretKey) -> PublicKey {
         PublicKey::from_secret_key(&self.secp, secret_key)
     }
@@ -108,3 +284,125 @@ impl Default for SignatureManager {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MESSAGE: &str = "PR #42 in BTCDecoded/protocol-engine";
+
+    #[test]
+    fn test_signing_message_is_stable() {
+        assert_eq!(
+            build_signing_message("BTCDecoded/protocol-engine", 42, "abc123", 3),
+            "btcdecoded-governance:v1:BTCDecoded/protocol-engine:42:abc123:3"
+        );
+    }
+
+    #[test]
+    fn test_signature_does_not_replay_across_prs() {
+        let manager = SignatureManager::new();
+        let keypair = manager.generate_keypair().unwrap();
+        let public_key = keypair.public_key.to_string();
+        let message = build_signing_message("BTCDecoded/protocol-engine", 42, "abc123", 3);
+
+        for scheme in [
+            SignatureScheme::EcdsaSecp256k1,
+            SignatureScheme::SchnorrBip340,
+        ] {
+            let signature = manager
+                .sign_with_scheme(&message, &keypair.secret_key, scheme)
+                .unwrap();
+            assert!(manager
+                .verify_with_scheme(&message, &signature, &public_key, scheme)
+                .unwrap());
+
+            for other in [
+                build_signing_message("BTCDecoded/protocol-engine", 43, "abc123", 3),
+                build_signing_message("BTCDecoded/consensus-proof", 42, "abc123", 3),
+                build_signing_message("BTCDecoded/protocol-engine", 42, "def456", 3),
+                build_signing_message("BTCDecoded/protocol-engine", 42, "abc123", 2),
+            ] {
+                assert!(!manager
+                    .verify_with_scheme(&other, &signature, &public_key, scheme)
+                    .unwrap());
+            }
+        }
+    }
+
+    #[test]
+    fn test_sign_and_verify_under_both_schemes() {
+        let manager = SignatureManager::new();
+        let keypair = manager.generate_keypair().unwrap();
+        let public_key = keypair.public_key.to_string();
+
+        for scheme in [
+            SignatureScheme::EcdsaSecp256k1,
+            SignatureScheme::SchnorrBip340,
+        ] {
+            let signature = manager
+                .sign_with_scheme(MESSAGE, &keypair.secret_key, scheme)
+                .unwrap();
+            assert!(manager
+                .verify_with_scheme(MESSAGE, &signature, &public_key, scheme)
+                .unwrap());
+            assert!(!manager
+                .verify_with_scheme(
+                    "PR #43 in BTCDecoded/protocol-engine",
+                    &signature,
+                    &public_key,
+                    scheme
+                )
+                .unwrap());
+        }
+
+        // Taproot-style x-only keys verify too
+        let x_only = keypair.public_key.x_only_public_key().0.to_string();
+        let signature = manager
+            .sign_with_scheme(MESSAGE, &keypair.secret_key, SignatureScheme::SchnorrBip340)
+            .unwrap();
+        assert!(manager
+            .verify_with_scheme(MESSAGE, &signature, &x_only, SignatureScheme::SchnorrBip340)
+            .unwrap());
+    }
+
+    #[test]
+    fn test_signature_does_not_verify_under_other_scheme() {
+        let manager = SignatureManager::new();
+        let keypair = manager.generate_keypair().unwrap();
+        let public_key = keypair.public_key.to_string();
+
+        let schnorr = manager
+            .sign_with_scheme(MESSAGE, &keypair.secret_key, SignatureScheme::SchnorrBip340)
+            .unwrap();
+        assert!(!manager
+            .verify_with_scheme(
+                MESSAGE,
+                &schnorr,
+                &public_key,
+                SignatureScheme::EcdsaSecp256k1
+            )
+            .unwrap_or(false));
+
+        let ecdsa = manager
+            .sign_with_scheme(
+                MESSAGE,
+                &keypair.secret_key,
+                SignatureScheme::EcdsaSecp256k1,
+            )
+            .unwrap();
+        assert!(!manager
+            .verify_with_scheme(MESSAGE, &ecdsa, &public_key, SignatureScheme::SchnorrBip340)
+            .unwrap_or(false));
+    }
+
+    #[test]
+    fn test_scheme_defaults_to_ecdsa() {
+        assert_eq!(SignatureScheme::default(), SignatureScheme::EcdsaSecp256k1);
+        assert_eq!(
+            "schnorr".parse::<SignatureScheme>().unwrap(),
+            SignatureScheme::SchnorrBip340
+        );
+        assert!("rsa".parse::<SignatureScheme>().is_err());
+    }
+}
@@ -0,0 +1,246 @@
+//! Governance Dashboard
+//!
+//! `GET /dashboard` gathers what operators check most often into one
+//! response: open and blocked PRs, active vetoes, keys due for rotation and
+//! the health of the database, Nostr relays and OpenTimestamps anchoring.
+//! Each section reports its own `status` of `ok`, `disabled` or `error`, so a
+//! subsystem that is switched off or failing doesn't take the rest down.
+
+use axum::{extract::State, http::HeaderMap, http::StatusCode, response::Json};
+use chrono::{DateTime, Utc};
+use serde_json::{json, Value};
+use std::collections::BTreeMap;
+use tracing::{error, warn};
+
+use crate::auth::require_bearer;
+use crate::config::{AppConfig, Secret};
+use crate::database::Database;
+use crate::enforcement::pr_status::PrStatus;
+use crate::metrics::Metrics;
+
+/// Active keys expiring within this many days are reported as due for rotation
+pub const KEY_ROTATION_WINDOW_DAYS: i64 = 30;
+
+/// A section with `status` set alongside its fields
+fn section(status: &str, mut fields: Value) -> Value {
+    fields["status"] = json!(status);
+    fields
+}
+
+fn error_section(subsystem: &str, e: impl std::fmt::Display) -> Value {
+    error!("Dashboard failed to load {}: {}", subsystem, e);
+    json!({"status": "error", "error": e.to_string()})
+}
+
+/// Handle `GET /dashboard`
+///
+/// Guarded by the status token like `/status`, and disabled without one.
+pub async fn dashboard_endpoint(
+    State((config, database, metrics)): State<(AppConfig, Database, Metrics)>,
+    headers: HeaderMap,
+) -> (StatusCode, Json<Value>) {
+    if let Err(e) = require_bearer(&headers, config.status_token.as_ref().map(Secret::expose)) {
+        warn!("Rejected dashboard request: {}", e);
+        return e.to_response();
+    }
+
+    let now = Utc::now();
+    let (pull_requests, vetoes) = pull_request_sections(&config, &database, now).await;
+    (
+        StatusCode::OK,
+        Json(json!({
+            "generated_at": now,
+            "pull_requests": pull_requests,
+            "vetoes": vetoes,
+            "keys": key_section(&database, now).await,
+            "database": database_section(&database).await,
+            "nostr": nostr_section(&config, &metrics),
+            "ots": ots_section(&config, &database).await,
+        })),
+    )
+}
+
+/// Open PRs by tier with blocked ones listed, and PRs under an active veto
+async fn pull_request_sections(config: &AppConfig, database: &Database, now: DateTime<Utc>) -> (Value, Value) {
+    let pending = match database.get_pending_pull_requests().await {
+        Ok(prs) => prs,
+        Err(e) => {
+            let e = e.to_string();
+            return (error_section("pull requests", &e), error_section("vetoes", &e));
+        }
+    };
+
+    let mut open_by_tier: BTreeMap<u32, usize> = BTreeMap::new();
+    let mut blocked = Vec::new();
+    let mut active_vetoes = Vec::new();
+    let mut failed = Vec::new();
+    for pr in &pending {
+        let status = match PrStatus::load(
+            database,
+            &pr.repo_name,
+            pr.pr_number,
+            now,
+            &config.review_period_policy,
+            &config.economic_quorums,
+        )
+        .await
+        {
+            Ok(Some(status)) => status,
+            Ok(None) => continue,
+            Err(e) => {
+                warn!("Dashboard failed to evaluate {}#{}: {}", pr.repo_name, pr.pr_number, e);
+                failed.push(format!("{}#{}", pr.repo_name, pr.pr_number));
+                continue;
+            }
+        };
+
+        *open_by_tier.entry(status.tier).or_default() += 1;
+        if status.decision.blocked {
+            blocked.push(json!({
+                "repo": status.repo_name,
+                "pr_number": status.pr_number,
+                "tier": status.tier,
+                "reasons": status.decision.reasons.iter().map(|r| r.to_string()).collect::<Vec<_>>(),
+            }));
+        }
+        if status.veto.active {
+            active_vetoes.push(json!({
+                "repo": status.repo_name,
+                "pr_number": status.pr_number,
+                "tier": status.tier,
+                "signal_count": status.veto.signal_count,
+            }));
+        }
+    }
+
+    // PRs that couldn't be evaluated are left out of the counts but named
+    let pr_status = if failed.is_empty() { "ok" } else { "error" };
+    let pull_requests = section(pr_status, json!({
+        "open": pending.len(),
+        "open_by_tier": open_by_tier,
+        "blocked": blocked,
+        "unevaluated": failed,
+    }));
+
+    // Veto breakdowns are only tracked on SQLite
+    let vetoes = if database.pool().is_none() {
+        section("disabled", json!({}))
+    } else {
+        section(pr_status, json!({"active": active_vetoes}))
+    };
+    (pull_requests, vetoes)
+}
+
+async fn key_section(database: &Database, now: DateTime<Utc>) -> Value {
+    let cutoff = now + chrono::Duration::days(KEY_ROTATION_WINDOW_DAYS);
+    match database.get_keys_expiring_before(cutoff).await {
+        Ok(keys) => section("ok", json!({
+            "rotation_window_days": KEY_ROTATION_WINDOW_DAYS,
+            "needing_rotation": keys,
+        })),
+        Err(e) => error_section("keys", e),
+    }
+}
+
+async fn database_section(database: &Database) -> Value {
+    match database.health_check().await {
+        Ok(health) => section(if health.integrity_ok { "ok" } else { "error" }, json!({
+            "integrity_ok": health.integrity_ok,
+            "journal_mode": health.journal_mode,
+            "active_connections": health.active_connections,
+            "idle_connections": health.idle_connections,
+            "size_bytes": health.database_size_bytes,
+        })),
+        Err(e) => error_section("database health", e),
+    }
+}
+
+fn nostr_section(config: &AppConfig, metrics: &Metrics) -> Value {
+    if !config.nostr.enabled {
+        return section("disabled", json!({}));
+    }
+    let (connected, configured) = metrics.nostr_relays();
+    // No relay reachable means nothing gets published
+    let status = if connected == 0 && configured > 0 { "error" } else { "ok" };
+    section(status, json!({"connected": connected, "configured": configured}))
+}
+
+async fn ots_section(config: &AppConfig, database: &Database) -> Value {
+    if !config.ots.enabled {
+        return section("disabled", json!({}));
+    }
+    match database.get_latest_registry_anchor().await {
+        Ok(anchor) => section("ok", json!({
+            "last_anchor_month": anchor.as_ref().map(|a| a.month_key.clone()),
+            "last_anchored_at": anchor.as_ref().and_then(|a| a.anchored_at),
+            "covers_until": anchor.as_ref().map(|a| a.covers_until),
+        })),
+        Err(e) => error_section("OTS anchors", e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::body::{to_bytes, Body};
+    use axum::http::Request;
+    use axum::routing::get;
+    use axum::Router;
+    use tower::ServiceExt;
+
+    async fn get_dashboard(config: AppConfig, database: Database, token: Option<&str>) -> (StatusCode, Value) {
+        let app = Router::new()
+            .route("/dashboard", get(dashboard_endpoint))
+            .with_state((config, database, Metrics::new()));
+        let mut request = Request::get("/dashboard");
+        if let Some(token) = token {
+            request = request.header("authorization", format!("Bearer {}", token));
+        }
+        let response = app.oneshot(request.body(Body::empty()).unwrap()).await.unwrap();
+        let status = response.status();
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        (status, serde_json::from_slice(&body).unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_dashboard_reports_every_section() {
+        let mut config = AppConfig::load().unwrap();
+        config.status_token = Some(Secret::new("s3cret"));
+        config.nostr.enabled = false;
+        config.ots.enabled = true;
+        let database = Database::new_in_memory().await.unwrap();
+        database.create_pull_request("BTCDecoded/sdk", 1, "abc", 5).await.unwrap();
+        sqlx::query(
+            "INSERT INTO key_metadata (key_id, key_type, owner, public_key, status, expires_at) VALUES ('maint_alice', 'maintainer', 'alice', '02ab', 'active', ?)",
+        )
+        .bind(Utc::now() + chrono::Duration::days(3))
+        .execute(database.pool().unwrap())
+        .await
+        .unwrap();
+
+        let (status, body) = get_dashboard(config.clone(), database.clone(), Some("s3cret")).await;
+        assert_eq!(status, StatusCode::OK);
+        for name in ["pull_requests", "vetoes", "keys", "database", "nostr", "ots"] {
+            assert!(body[name]["status"].is_string(), "missing section {}", name);
+        }
+        assert_eq!(body["nostr"]["status"], "disabled");
+
+        assert_eq!(body["pull_requests"]["status"], "ok");
+        assert_eq!(body["pull_requests"]["open"], 1);
+        // Just opened, so its review period is still running
+        let blocked = body["pull_requests"]["blocked"].as_array().unwrap();
+        assert_eq!(blocked.len(), 1);
+        assert_eq!(blocked[0]["pr_number"], 1);
+        assert!(!blocked[0]["reasons"].as_array().unwrap().is_empty());
+
+        assert_eq!(body["vetoes"]["status"], "ok");
+        assert_eq!(body["keys"]["needing_rotation"][0]["key_id"], "maint_alice");
+        assert_eq!(body["database"]["status"], "ok");
+        assert_eq!(body["ots"]["status"], "ok");
+        assert!(body["ots"]["last_anchored_at"].is_null());
+
+        let (status, body) = get_dashboard(config, database, None).await;
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+        assert!(body.get("pull_requests").is_none());
+    }
+}
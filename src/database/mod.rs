@@ -1,38 +1,199 @@
 pub mod models;
 pub mod queries;
+pub mod retention;
 pub mod schema;
 
-use sqlx::{SqlitePool, PgPool, sqlite::SqliteConnectOptions, sqlite::SqlitePoolOptions};
+use chrono::{DateTime, NaiveDateTime, Utc};
+use sqlx::{Row, SqlitePool, PgPool, sqlite::SqliteConnectOptions, sqlite::SqlitePoolOptions};
+use std::collections::BTreeMap;
 use std::str::FromStr;
+use tokio::sync::broadcast;
+use crate::crypto::signatures::{MaintainerChange, SignatureScheme};
+use crate::database::models::{
+    EconomicSignal, ExpiredKey, ExpiringKey, GovernanceEvent, Maintainer, PullRequest,
+    PostedMergeDecision, PullRequestUpsert, ServerRevocation, Signature,
+};
+use crate::enforcement::merge_block::MergeDecision;
 use crate::error::GovernanceError;
 
+/// Mean execution time above which a statement counts as slow
+pub const DEFAULT_SLOW_QUERY_THRESHOLD_MS: f64 = 100.0;
+
+/// How long a SQLite connection waits for another writer before reporting `database is locked`
+pub const SQLITE_BUSY_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(5);
+
+/// Attempts for a SQLite write that keeps finding the database locked
+const LOCKED_WRITE_ATTEMPTS: u32 = 5;
+/// Backoff before the first retry of a locked write; doubles per attempt, plus jitter
+const LOCKED_WRITE_BASE_DELAY_MS: u64 = 25;
+
+static SQLITE_MIGRATOR: sqlx::migrate::Migrator = sqlx::migrate!("./migrations");
+static POSTGRES_MIGRATOR: sqlx::migrate::Migrator = sqlx::migrate!("./migrations-postgres");
+const APPLIED_MIGRATIONS_QUERY: &str = "SELECT version, success FROM _sqlx_migrations ORDER BY version";
+
 #[derive(Clone)]
 pub enum DatabaseBackend {
     Sqlite(SqlitePool),
     Postgres(PgPool),
 }
 
+/// Logged governance events buffered per stream subscriber before it lags
+pub const GOVERNANCE_EVENT_CHANNEL_CAPACITY: usize = 256;
+
 #[derive(Clone)]
 pub struct Database {
     backend: DatabaseBackend,
+    /// Every event written by `log_governance_event`, for live subscribers
+    events: broadcast::Sender<GovernanceEvent>,
+}
+
+/// How the initial database connection is retried while the server comes up
+#[derive(Debug, Clone)]
+pub struct ConnectRetryPolicy {
+    /// Total attempts, including the first connect
+    pub max_attempts: u32,
+    /// Delay before the first retry; doubles on each further attempt
+    pub base_delay: std::time::Duration,
+}
+
+impl Default for ConnectRetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 5,
+            base_delay: std::time::Duration::from_millis(500),
+        }
+    }
+}
+
+impl ConnectRetryPolicy {
+    fn backoff(&self, attempt: u32) -> std::time::Duration {
+        let factor = 2u32.saturating_pow(attempt.saturating_sub(1));
+        self.base_delay.saturating_mul(factor)
+    }
+}
+
+/// Whether a connect error can clear up on its own, e.g. while Postgres is still starting
+///
+/// Authentication and configuration errors are permanent and fail immediately.
+fn is_transient_connect_error(error: &sqlx::Error) -> bool {
+    match error {
+        sqlx::Error::Io(e) => matches!(
+            e.kind(),
+            std::io::ErrorKind::ConnectionRefused
+                | std::io::ErrorKind::ConnectionReset
+                | std::io::ErrorKind::ConnectionAborted
+                | std::io::ErrorKind::TimedOut
+        ),
+        sqlx::Error::PoolTimedOut => true,
+        // 57P03 cannot_connect_now: the server is starting up or shutting down
+        sqlx::Error::Database(e) => e.code().as_deref() == Some("57P03"),
+        _ => false,
+    }
+}
+
+/// Run `connect` until it succeeds, fails permanently or runs out of attempts
+async fn connect_with_retry<T, F, Fut>(policy: &ConnectRetryPolicy, mut connect: F) -> Result<T, sqlx::Error>
+where
+    F: FnMut() -> Fut,
+    Fut: std::future::Future<Output = Result<T, sqlx::Error>>,
+{
+    let mut attempt = 1;
+    loop {
+        match connect().await {
+            Ok(pool) => return Ok(pool),
+            Err(e) if attempt < policy.max_attempts && is_transient_connect_error(&e) => {
+                let delay = policy.backoff(attempt);
+                tracing::warn!(
+                    "Database connection attempt {}/{} failed: {}; retrying in {:?}",
+                    attempt,
+                    policy.max_attempts,
+                    e,
+                    delay
+                );
+                tokio::time::sleep(delay).await;
+                attempt += 1;
+            }
+            Err(e) => return Err(e),
+        }
+    }
+}
+
+/// Whether SQLite refused a statement because another connection holds the lock
+///
+/// `busy_timeout` covers most contention, but SQLite reports `SQLITE_BUSY`
+/// immediately when waiting could deadlock, e.g. when a WAL reader tries to
+/// become a writer after another connection committed.
+fn is_sqlite_locked(error: &sqlx::Error) -> bool {
+    match error {
+        // SQLITE_BUSY (5) and SQLITE_LOCKED (6), including their extended codes
+        sqlx::Error::Database(e) => {
+            let primary = e.code().and_then(|c| c.parse::<i32>().ok()).map(|c| c & 0xff);
+            matches!(primary, Some(5) | Some(6)) || e.message().contains("database is locked")
+        }
+        _ => false,
+    }
+}
+
+/// Run a SQLite write, retrying with jittered backoff while the database is locked
+async fn sqlite_write<T, F, Fut>(mut write: F) -> Result<T, sqlx::Error>
+where
+    F: FnMut() -> Fut,
+    Fut: std::future::Future<Output = Result<T, sqlx::Error>>,
+{
+    let mut attempt = 1;
+    loop {
+        match write().await {
+            Err(e) if attempt < LOCKED_WRITE_ATTEMPTS && is_sqlite_locked(&e) => {
+                let backoff = LOCKED_WRITE_BASE_DELAY_MS.saturating_mul(1 << (attempt - 1));
+                let jitter = rand::random::<u64>() % LOCKED_WRITE_BASE_DELAY_MS;
+                let delay = std::time::Duration::from_millis(backoff + jitter);
+                tracing::debug!(
+                    "SQLite write found the database locked (attempt {}/{}); retrying in {:?}",
+                    attempt,
+                    LOCKED_WRITE_ATTEMPTS,
+                    delay
+                );
+                tokio::time::sleep(delay).await;
+                attempt += 1;
+            }
+            result => return result,
+        }
+    }
+}
+
+/// Connect options for a SQLite URL, with the busy timeout applied
+fn sqlite_options(database_url: &str) -> Result<SqliteConnectOptions, GovernanceError> {
+    Ok(SqliteConnectOptions::from_str(database_url)
+        .map_err(|e| GovernanceError::DatabaseError(e.to_string()))?
+        .busy_timeout(SQLITE_BUSY_TIMEOUT))
 }
 
 impl Database {
+    fn from_backend(backend: DatabaseBackend) -> Self {
+        let (events, _) = broadcast::channel(GOVERNANCE_EVENT_CHANNEL_CAPACITY);
+        Self { backend, events }
+    }
+
     pub async fn new(database_url: &str) -> Result<Self, GovernanceError> {
+        Self::new_with_retry(database_url, &ConnectRetryPolicy::default()).await
+    }
+
+    /// Connect, retrying refused and timed-out connections with exponential backoff
+    pub async fn new_with_retry(
+        database_url: &str,
+        policy: &ConnectRetryPolicy,
+    ) -> Result<Self, GovernanceError> {
         if database_url.starts_with("sqlite:") {
-            let pool = SqlitePool::connect(database_url)
+            let options = sqlite_options(database_url)?;
+            let pool = connect_with_retry(policy, || SqlitePool::connect_with(options.clone()))
                 .await
                 .map_err(|e| GovernanceError::DatabaseError(e.to_string()))?;
-            Ok(Self {
-                backend: DatabaseBackend::Sqlite(pool),
-            })
+            Ok(Self::from_backend(DatabaseBackend::Sqlite(pool)))
         } else if database_url.starts_with("postgres://") || database_url.starts_with("postgresql://") {
-            let pool = PgPool::connect(database_url)
+            let pool = connect_with_retry(policy, || PgPool::connect(database_url))
                 .await
                 .map_err(|e| GovernanceError::DatabaseError(e.to_string()))?;
-            Ok(Self {
-                backend: DatabaseBackend::Postgres(pool),
-            })
+            Ok(Self::from_backend(DatabaseBackend::Postgres(pool)))
         } else {
             Err(GovernanceError::DatabaseError(
                 "Unsupported database URL format. Use 'sqlite://' or 'postgresql://'".to_string()
@@ -46,18 +207,20 @@ impl Database {
             .await
             .map_err(|e| GovernanceError::DatabaseError(e.to_string()))?;
         
-        let db = Self {
-            backend: DatabaseBackend::Sqlite(pool),
-        };
+        let db = Self::from_backend(DatabaseBackend::Sqlite(pool));
         db.run_migrations().await?;
         Ok(db)
     }
 
+    /// Wrap an existing SQLite pool; migrations are assumed to have run
+    pub fn from_sqlite_pool(pool: SqlitePool) -> Self {
+        Self::from_backend(DatabaseBackend::Sqlite(pool))
+    }
+
     /// Create a new production database with optimized settings
     pub async fn new_production(database_url: &str) -> Result<Self, GovernanceError> {
         if database_url.starts_with("sqlite:") {
-            let options = SqliteConnectOptions::from_str(database_url)
-                .map_err(|e| GovernanceError::DatabaseError(e.to_string()))?
+            let options = sqlite_options(database_url)?
                 .journal_mode(sqlx::sqlite::SqliteJournalMode::Wal)
                 .synchronous(sqlx::sqlite::SqliteSynchronous::Normal)
                 .locking_mode(sqlx::sqlite::SqliteLockingMode::Normal)
@@ -74,18 +237,14 @@ impl Database {
                 .await
                 .map_err(|e| GovernanceError::DatabaseError(e.to_string()))?;
 
-            let db = Database {
-                backend: DatabaseBackend::Sqlite(pool),
-            };
+            let db = Self::from_backend(DatabaseBackend::Sqlite(pool));
             db.run_migrations().await?;
             Ok(db)
         } else if database_url.starts_with("postgres://") || database_url.starts_with("postgresql://") {
-            let pool = PgPool::connect(database_url)
+            let pool = connect_with_retry(&ConnectRetryPolicy::default(), || PgPool::connect(database_url))
                 .await
                 .map_err(|e| GovernanceError::DatabaseError(e.to_string()))?;
-            let db = Database {
-                backend: DatabaseBackend::Postgres(pool),
-            };
+            let db = Self::from_backend(DatabaseBackend::Postgres(pool));
             db.run_migrations().await?;
             Ok(db)
         } else {
@@ -96,86 +255,1560 @@ impl Database {
     }
 
 
-    pub async fn run_migrations(&self) -> Result<(), GovernanceError> {
+    pub async fn run_migrations(&self) -> Result<(), GovernanceError> {
+        match &self.backend {
+            DatabaseBackend::Sqlite(pool) => {
+                SQLITE_MIGRATOR
+                    .run(pool)
+                    .await
+                    .map_err(|e| GovernanceError::DatabaseError(e.to_string()))?;
+            }
+            DatabaseBackend::Postgres(pool) => {
+                POSTGRES_MIGRATOR
+                    .run(pool)
+                    .await
+                    .map_err(|e| GovernanceError::DatabaseError(e.to_string()))?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Check the migrated schema against `schema::SHARED_SCHEMA`
+    ///
+    /// SQLite and Postgres are migrated from separate directories, so a table or
+    /// column added to only one of them would otherwise surface as a runtime
+    /// query failure on the other backend.
+    pub async fn verify_schema(&self) -> Result<(), GovernanceError> {
+        let (backend, migrator, applied) = match &self.backend {
+            DatabaseBackend::Sqlite(pool) => (
+                "SQLite",
+                &SQLITE_MIGRATOR,
+                sqlx::query_as::<_, (i64, bool)>(APPLIED_MIGRATIONS_QUERY)
+                    .fetch_all(pool)
+                    .await
+                    .map_err(db_err)?,
+            ),
+            DatabaseBackend::Postgres(pool) => (
+                "Postgres",
+                &POSTGRES_MIGRATOR,
+                sqlx::query_as::<_, (i64, bool)>(APPLIED_MIGRATIONS_QUERY)
+                    .fetch_all(pool)
+                    .await
+                    .map_err(db_err)?,
+            ),
+        };
+
+        let expected: Vec<i64> = migrator.iter().map(|m| m.version).collect();
+        let applied_ok: Vec<i64> = applied.iter().filter(|(_, ok)| *ok).map(|(v, _)| *v).collect();
+        if applied_ok != expected {
+            return Err(GovernanceError::DatabaseError(format!(
+                "{} migrations out of sync: expected versions {:?}, applied {:?}",
+                backend, expected, applied_ok
+            )));
+        }
+
+        for (table, columns) in schema::SHARED_SCHEMA {
+            let actual = self.table_columns(table).await?;
+            if actual.is_empty() {
+                return Err(GovernanceError::DatabaseError(format!(
+                    "Table '{}' is missing on {}; add it to that backend's migrations",
+                    table, backend
+                )));
+            }
+            let expected: BTreeMap<String, String> = columns
+                .iter()
+                .map(|(name, ty)| (name.to_string(), ty.to_string()))
+                .collect();
+            if actual != expected {
+                let missing: Vec<&String> = expected
+                    .iter()
+                    .filter(|(name, ty)| actual.get(*name) != Some(*ty))
+                    .map(|(name, _)| name)
+                    .collect();
+                let unexpected: Vec<&String> = actual
+                    .iter()
+                    .filter(|(name, ty)| expected.get(*name) != Some(*ty))
+                    .map(|(name, _)| name)
+                    .collect();
+                return Err(GovernanceError::DatabaseError(format!(
+                    "Table '{}' on {} drifted from the shared schema: missing or mistyped {:?}, unexpected {:?}",
+                    table, backend, missing, unexpected
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// Columns of `table` mapped to their normalized type family; empty if the table doesn't exist
+    pub async fn table_columns(&self, table: &str) -> Result<BTreeMap<String, String>, GovernanceError> {
+        match &self.backend {
+            DatabaseBackend::Sqlite(pool) => {
+                let rows = sqlx::query("SELECT name, type FROM pragma_table_info(?)")
+                    .bind(table)
+                    .fetch_all(pool)
+                    .await
+                    .map_err(db_err)?;
+                rows.iter()
+                    .map(|row| {
+                        let ty: String = row.try_get("type").map_err(db_err)?;
+                        Ok((row.try_get("name").map_err(db_err)?, schema::normalize_type(&ty)))
+                    })
+                    .collect()
+            }
+            DatabaseBackend::Postgres(pool) => {
+                let rows = sqlx::query(
+                    "SELECT column_name, data_type FROM information_schema.columns WHERE table_schema = current_schema() AND table_name = $1",
+                )
+                .bind(table)
+                .fetch_all(pool)
+                .await
+                .map_err(db_err)?;
+                rows.iter()
+                    .map(|row| {
+                        let ty: String = row.try_get("data_type").map_err(db_err)?;
+                        Ok((row.try_get("column_name").map_err(db_err)?, schema::normalize_type(&ty)))
+                    })
+                    .collect()
+            }
+        }
+    }
+
+    pub fn get_sqlite_pool(&self) -> Option<&SqlitePool> {
+        match &self.backend {
+            DatabaseBackend::Sqlite(pool) => Some(pool),
+            _ => None,
+        }
+    }
+
+    pub fn get_postgres_pool(&self) -> Option<&PgPool> {
+        match &self.backend {
+            DatabaseBackend::Postgres(pool) => Some(pool),
+            _ => None,
+        }
+    }
+
+    pub fn is_sqlite(&self) -> bool {
+        matches!(self.backend, DatabaseBackend::Sqlite(_))
+    }
+
+    pub fn is_postgres(&self) -> bool {
+        matches!(self.backend, DatabaseBackend::Postgres(_))
+    }
+
+    /// Store a pull request, or refresh the head SHA and layer of a known one
+    ///
+    /// Insert-or-nothing first, so a redelivered or synchronize event never
+    /// moves `opened_at` and the caller learns which case it was. Signatures
+    /// cover a specific head SHA, so a push that moves the head drops them.
+    pub async fn create_pull_request(
+        &self,
+        repo_name: &str,
+        pr_number: i32,
+        head_sha: &str,
+        layer: i32,
+    ) -> Result<PullRequestUpsert, GovernanceError> {
+        let inserted = match &self.backend {
+            DatabaseBackend::Sqlite(pool) => sqlite_write(|| {
+                sqlx::query(
+                    r#"
+                    INSERT INTO pull_requests (repo_name, pr_number, opened_at, layer, head_sha)
+                    VALUES (?, ?, CURRENT_TIMESTAMP, ?, ?)
+                    ON CONFLICT (repo_name, pr_number) DO NOTHING
+                    "#,
+                )
+                .bind(repo_name)
+                .bind(pr_number)
+                .bind(layer)
+                .bind(head_sha)
+                .execute(pool)
+            })
+            .await
+            .map_err(|e| GovernanceError::DatabaseError(e.to_string()))?
+            .rows_affected(),
+            DatabaseBackend::Postgres(pool) => sqlx::query(
+                r#"
+                INSERT INTO pull_requests (repo_name, pr_number, opened_at, layer, head_sha)
+                VALUES ($1, $2, CURRENT_TIMESTAMP, $3, $4)
+                ON CONFLICT (repo_name, pr_number) DO NOTHING
+                "#,
+            )
+            .bind(repo_name)
+            .bind(pr_number)
+            .bind(layer)
+            .bind(head_sha)
+            .execute(pool)
+            .await
+            .map_err(|e| GovernanceError::DatabaseError(e.to_string()))?
+            .rows_affected(),
+        };
+        if inserted > 0 {
+            return Ok(PullRequestUpsert::Created);
+        }
+
+        match &self.backend {
+            DatabaseBackend::Sqlite(pool) => {
+                sqlite_write(|| {
+                    sqlx::query(
+                        r#"
+                        UPDATE pull_requests
+                        SET head_sha = ?, layer = ?, updated_at = CURRENT_TIMESTAMP,
+                            signatures = CASE WHEN head_sha = ? THEN signatures ELSE '[]' END
+                        WHERE repo_name = ? AND pr_number = ?
+                        "#,
+                    )
+                    .bind(head_sha)
+                    .bind(layer)
+                    .bind(head_sha)
+                    .bind(repo_name)
+                    .bind(pr_number)
+                    .execute(pool)
+                })
+                .await
+                .map_err(|e| GovernanceError::DatabaseError(e.to_string()))?;
+            }
+            DatabaseBackend::Postgres(pool) => {
+                sqlx::query(
+                    r#"
+                    UPDATE pull_requests
+                    SET head_sha = $1, layer = $2, updated_at = CURRENT_TIMESTAMP,
+                        signatures = CASE WHEN head_sha = $1 THEN signatures ELSE '[]' END
+                    WHERE repo_name = $3 AND pr_number = $4
+                    "#,
+                )
+                .bind(head_sha)
+                .bind(layer)
+                .bind(repo_name)
+                .bind(pr_number)
+                .execute(pool)
+                .await
+                .map_err(|e| GovernanceError::DatabaseError(e.to_string()))?;
+            }
+        }
+        Ok(PullRequestUpsert::Updated)
+    }
+
+    /// Record a reviewer's latest review state on a pull request
+    pub async fn update_review_status(
+        &self,
+        repo_name: &str,
+        pr_number: i32,
+        reviewer: &str,
+        state: &str,
+    ) -> Result<(), GovernanceError> {
+        let state = state.to_uppercase();
+        match &self.backend {
+            DatabaseBackend::Sqlite(pool) => {
+                sqlite_write(|| {
+                    sqlx::query(
+                        r#"
+                        INSERT INTO pr_reviews (repo_name, pr_number, reviewer, state, updated_at)
+                        VALUES (?, ?, ?, ?, CURRENT_TIMESTAMP)
+                        ON CONFLICT (repo_name, pr_number, reviewer) DO UPDATE SET
+                            state = EXCLUDED.state,
+                            updated_at = CURRENT_TIMESTAMP
+                        "#,
+                    )
+                    .bind(repo_name)
+                    .bind(pr_number)
+                    .bind(reviewer)
+                    .bind(&state)
+                    .execute(pool)
+                })
+                .await
+                .map_err(|e| GovernanceError::DatabaseError(e.to_string()))?;
+            }
+            DatabaseBackend::Postgres(pool) => {
+                sqlx::query(
+                    r#"
+                    INSERT INTO pr_reviews (repo_name, pr_number, reviewer, state, updated_at)
+                    VALUES ($1, $2, $3, $4, CURRENT_TIMESTAMP)
+                    ON CONFLICT (repo_name, pr_number, reviewer) DO UPDATE SET
+                        state = EXCLUDED.state,
+                        updated_at = CURRENT_TIMESTAMP
+                    "#,
+                )
+                .bind(repo_name)
+                .bind(pr_number)
+                .bind(reviewer)
+                .bind(&state)
+                .execute(pool)
+                .await
+                .map_err(|e| GovernanceError::DatabaseError(e.to_string()))?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Get the latest review state of every reviewer on a pull request
+    pub async fn get_reviews(
+        &self,
+        repo_name: &str,
+        pr_number: i32,
+    ) -> Result<Vec<(String, String)>, GovernanceError> {
+        let reviews = match &self.backend {
+            DatabaseBackend::Sqlite(pool) => sqlx::query_as::<_, (String, String)>(
+                "SELECT reviewer, state FROM pr_reviews WHERE repo_name = ? AND pr_number = ? ORDER BY reviewer",
+            )
+            .bind(repo_name)
+            .bind(pr_number)
+            .fetch_all(pool)
+            .await
+            .map_err(|e| GovernanceError::DatabaseError(e.to_string()))?,
+            DatabaseBackend::Postgres(pool) => sqlx::query_as::<_, (String, String)>(
+                "SELECT reviewer, state FROM pr_reviews WHERE repo_name = $1 AND pr_number = $2 ORDER BY reviewer",
+            )
+            .bind(repo_name)
+            .bind(pr_number)
+            .fetch_all(pool)
+            .await
+            .map_err(|e| GovernanceError::DatabaseError(e.to_string()))?,
+        };
+        Ok(reviews)
+    }
+
+    /// Record a maintainer signature on a pull request
+    ///
+    /// Signatures are de-duplicated by signer: signing again replaces the
+    /// signer's earlier entry rather than adding a second one.
+    pub async fn add_signature(
+        &self,
+        repo_name: &str,
+        pr_number: i32,
+        signer: &str,
+        signature: &str,
+    ) -> Result<(), GovernanceError> {
+        self.add_signature_with_scheme(repo_name, pr_number, signer, signature, SignatureScheme::default())
+            .await
+    }
+
+    /// Record a maintainer signature made under a specific signature scheme
+    pub async fn add_signature_with_scheme(
+        &self,
+        repo_name: &str,
+        pr_number: i32,
+        signer: &str,
+        signature: &str,
+        scheme: SignatureScheme,
+    ) -> Result<(), GovernanceError> {
+        let timestamp = Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Micros, true);
+        let result = match &self.backend {
+            DatabaseBackend::Sqlite(pool) => sqlite_write(|| {
+                sqlx::query(
+                    r#"
+                    UPDATE pull_requests SET
+                        signatures = (
+                            SELECT json_group_array(json(value)) FROM (
+                                SELECT value FROM json_each(COALESCE(pull_requests.signatures, '[]'))
+                                WHERE json_extract(value, '$.signer') != ?
+                                UNION ALL
+                                SELECT json_object('signer', ?, 'signature', ?, 'timestamp', ?, 'scheme', ?)
+                            )
+                        ),
+                        updated_at = CURRENT_TIMESTAMP
+                    WHERE repo_name = ? AND pr_number = ?
+                    "#,
+                )
+                .bind(signer)
+                .bind(signer)
+                .bind(signature)
+                .bind(&timestamp)
+                .bind(scheme.as_str())
+                .bind(repo_name)
+                .bind(pr_number)
+                .execute(pool)
+            })
+            .await
+            .map_err(|e| GovernanceError::DatabaseError(e.to_string()))?
+            .rows_affected(),
+            DatabaseBackend::Postgres(pool) => sqlx::query(
+                r#"
+                UPDATE pull_requests SET
+                    signatures = COALESCE(
+                        (SELECT jsonb_agg(elem)
+                         FROM jsonb_array_elements(COALESCE(signatures, '[]'::jsonb)) AS elem
+                         WHERE elem->>'signer' <> $1::text),
+                        '[]'::jsonb
+                    ) || jsonb_build_array(jsonb_build_object(
+                        'signer', $1::text, 'signature', $2::text, 'timestamp', $3::text,
+                        'scheme', $6::text
+                    )),
+                    updated_at = CURRENT_TIMESTAMP
+                WHERE repo_name = $4 AND pr_number = $5
+                "#,
+            )
+            .bind(signer)
+            .bind(signature)
+            .bind(&timestamp)
+            .bind(repo_name)
+            .bind(pr_number)
+            .bind(scheme.as_str())
+            .execute(pool)
+            .await
+            .map_err(|e| GovernanceError::DatabaseError(e.to_string()))?
+            .rows_affected(),
+        };
+
+        if result == 0 {
+            return Err(GovernanceError::DatabaseError(format!(
+                "Pull request {}#{} not found",
+                repo_name, pr_number
+            )));
+        }
+        Ok(())
+    }
+
+    /// Record a governance event and publish it to event stream subscribers
+    pub async fn log_governance_event(
+        &self,
+        event_type: &str,
+        repo_name: Option<&str>,
+        pr_number: Option<i32>,
+        maintainer: Option<&str>,
+        details: &serde_json::Value,
+    ) -> Result<(), GovernanceError> {
+        let event = match &self.backend {
+            DatabaseBackend::Sqlite(pool) => {
+                let row = sqlite_write(|| {
+                    sqlx::query(
+                        r#"
+                        INSERT INTO governance_events (event_type, repo_name, pr_number, maintainer, details)
+                        VALUES (?, ?, ?, ?, ?)
+                        RETURNING id, event_type, repo_name, pr_number, maintainer, details, timestamp
+                        "#,
+                    )
+                    .bind(event_type)
+                    .bind(repo_name)
+                    .bind(pr_number)
+                    .bind(maintainer)
+                    .bind(serde_json::to_string(details).unwrap_or_default())
+                    .fetch_one(pool)
+                })
+                .await
+                .map_err(|e| GovernanceError::DatabaseError(e.to_string()))?;
+                sqlite_row_to_governance_event(&row)?
+            }
+            DatabaseBackend::Postgres(pool) => {
+                let row = sqlx::query(
+                    r#"
+                    INSERT INTO governance_events (event_type, repo_name, pr_number, maintainer, details)
+                    VALUES ($1, $2, $3, $4, $5)
+                    RETURNING id, event_type, repo_name, pr_number, maintainer, details, timestamp
+                    "#,
+                )
+                .bind(event_type)
+                .bind(repo_name)
+                .bind(pr_number)
+                .bind(maintainer)
+                .bind(details)
+                .fetch_one(pool)
+                .await
+                .map_err(|e| GovernanceError::DatabaseError(e.to_string()))?;
+                postgres_row_to_governance_event(&row)?
+            }
+        };
+
+        // Never waits on subscribers; one that falls behind sees `Lagged` instead
+        let _ = self.events.send(event);
+        Ok(())
+    }
+
+    /// Receive every governance event logged from now on
+    pub fn subscribe_governance_events(&self) -> broadcast::Receiver<GovernanceEvent> {
+        self.events.subscribe()
+    }
+
+    /// Governance events with an id above `after_id`, oldest first, at most `limit`
+    pub async fn get_governance_events_after(
+        &self,
+        after_id: i64,
+        limit: i64,
+    ) -> Result<Vec<GovernanceEvent>, GovernanceError> {
+        match &self.backend {
+            DatabaseBackend::Sqlite(pool) => {
+                let rows = sqlx::query(
+                    r#"
+                    SELECT id, event_type, repo_name, pr_number, maintainer, details, timestamp
+                    FROM governance_events
+                    WHERE id > ?
+                    ORDER BY id ASC
+                    LIMIT ?
+                    "#,
+                )
+                .bind(after_id)
+                .bind(limit)
+                .fetch_all(pool)
+                .await
+                .map_err(db_err)?;
+
+                rows.iter().map(sqlite_row_to_governance_event).collect()
+            }
+            DatabaseBackend::Postgres(pool) => {
+                let rows = sqlx::query(
+                    r#"
+                    SELECT id, event_type, repo_name, pr_number, maintainer, details, timestamp
+                    FROM governance_events
+                    WHERE id > $1
+                    ORDER BY id ASC
+                    LIMIT $2
+                    "#,
+                )
+                .bind(after_id)
+                .bind(limit)
+                .fetch_all(pool)
+                .await
+                .map_err(db_err)?;
+
+                rows.iter().map(postgres_row_to_governance_event).collect()
+            }
+        }
+    }
+
+    pub async fn get_pull_request(
+        &self,
+        repo_name: &str,
+        pr_number: i32,
+    ) -> Result<Option<crate::database::models::PullRequest>, GovernanceError> {
+        match &self.backend {
+            DatabaseBackend::Sqlite(pool) => {
+                let row = sqlx::query(
+                    r#"
+                    SELECT id, repo_name, pr_number, opened_at, layer, head_sha, signatures,
+                           governance_status, linked_prs, emergency_mode, created_at, updated_at
+                    FROM pull_requests
+                    WHERE repo_name = ? AND pr_number = ?
+                    "#,
+                )
+                .bind(repo_name)
+                .bind(pr_number)
+                .fetch_optional(pool)
+                .await
+                .map_err(|e| GovernanceError::DatabaseError(e.to_string()))?;
+
+                row.as_ref().map(sqlite_row_to_pull_request).transpose()
+            }
+            DatabaseBackend::Postgres(pool) => {
+                let row = sqlx::query(
+                    r#"
+                    SELECT id, repo_name, pr_number, opened_at, layer, head_sha, signatures,
+                           governance_status, linked_prs, emergency_mode, created_at, updated_at
+                    FROM pull_requests
+                    WHERE repo_name = $1 AND pr_number = $2
+                    "#,
+                )
+                .bind(repo_name)
+                .bind(pr_number)
+                .fetch_optional(pool)
+                .await
+                .map_err(|e| GovernanceError::DatabaseError(e.to_string()))?;
+
+                row.as_ref().map(postgres_row_to_pull_request).transpose()
+            }
+        }
+    }
+
+    pub async fn get_governance_events(
+        &self,
+        _limit: i64,
+    ) -> Result<Vec<crate::database::models::GovernanceEvent>, GovernanceError> {
+        // This would retrieve governance events from the database
+        // For now, return empty vector as a placeholder
+        Ok(vec![])
+    }
+
+    /// Look up an active maintainer
+    pub async fn get_maintainer_by_username(
+        &self,
+        username: &str,
+    ) -> Result<Option<crate::database::models::Maintainer>, GovernanceError> {
+        self.get_maintainer(username, false).await
+    }
+
+    /// Look up a maintainer, optionally including suspended ones
+    pub async fn get_maintainer(
+        &self,
+        username: &str,
+        include_inactive: bool,
+    ) -> Result<Option<crate::database::models::Maintainer>, GovernanceError> {
+        match &self.backend {
+            DatabaseBackend::Sqlite(pool) => {
+                let row = sqlx::query(
+                    "SELECT id, github_username, public_key, layer, active, last_updated FROM maintainers WHERE github_username = ? AND (active = true OR ?)",
+                )
+                .bind(username)
+                .bind(include_inactive)
+                .fetch_optional(pool)
+                .await
+                .map_err(|e| GovernanceError::DatabaseError(e.to_string()))?;
+
+                row.as_ref().map(sqlite_row_to_maintainer).transpose()
+            }
+            DatabaseBackend::Postgres(pool) => {
+                let row = sqlx::query(
+                    "SELECT id, github_username, public_key, layer, active, last_updated FROM maintainers WHERE github_username = $1 AND (active = true OR $2)",
+                )
+                .bind(username)
+                .bind(include_inactive)
+                .fetch_optional(pool)
+                .await
+                .map_err(|e| GovernanceError::DatabaseError(e.to_string()))?;
+
+                row.as_ref().map(postgres_row_to_maintainer).transpose()
+            }
+        }
+    }
+
+    /// Get all pull requests still awaiting a governance decision
+    pub async fn get_pending_pull_requests(
+        &self,
+    ) -> Result<Vec<crate::database::models::PullRequest>, GovernanceError> {
+        match &self.backend {
+            DatabaseBackend::Sqlite(pool) => {
+                let rows = sqlx::query(
+                    r#"
+                    SELECT id, repo_name, pr_number, opened_at, layer, head_sha, signatures,
+                           governance_status, linked_prs, emergency_mode, created_at, updated_at
+                    FROM pull_requests
+                    WHERE governance_status = 'pending'
+                    ORDER BY opened_at ASC
+                    "#,
+                )
+                .fetch_all(pool)
+                .await
+                .map_err(|e| GovernanceError::DatabaseError(e.to_string()))?;
+
+                rows.iter().map(sqlite_row_to_pull_request).collect()
+            }
+            DatabaseBackend::Postgres(pool) => {
+                let rows = sqlx::query(
+                    r#"
+                    SELECT id, repo_name, pr_number, opened_at, layer, head_sha, signatures,
+                           governance_status, linked_prs, emergency_mode, created_at, updated_at
+                    FROM pull_requests
+                    WHERE governance_status = 'pending'
+                    ORDER BY opened_at ASC
+                    "#,
+                )
+                .fetch_all(pool)
+                .await
+                .map_err(|e| GovernanceError::DatabaseError(e.to_string()))?;
+
+                rows.iter().map(postgres_row_to_pull_request).collect()
+            }
+        }
+    }
+
+    /// List tracked pull requests, oldest first
+    ///
+    /// `stored_status` filters on the `governance_status` column; the
+    /// listing statuses derived from pending rows are worked out by
+    /// [`crate::enforcement::pr_status::list_pull_requests`].
+    pub async fn list_pull_requests(
+        &self,
+        repo_name: Option<&str>,
+        stored_status: Option<&str>,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<PullRequest>, GovernanceError> {
+        match &self.backend {
+            DatabaseBackend::Sqlite(pool) => {
+                let rows = sqlx::query(
+                    r#"
+                    SELECT id, repo_name, pr_number, opened_at, layer, head_sha, signatures,
+                           governance_status, linked_prs, emergency_mode, created_at, updated_at
+                    FROM pull_requests
+                    WHERE (? IS NULL OR repo_name = ?)
+                      AND (? IS NULL OR governance_status = ?)
+                    ORDER BY opened_at ASC, id ASC
+                    LIMIT ? OFFSET ?
+                    "#,
+                )
+                .bind(repo_name)
+                .bind(repo_name)
+                .bind(stored_status)
+                .bind(stored_status)
+                .bind(limit)
+                .bind(offset)
+                .fetch_all(pool)
+                .await
+                .map_err(db_err)?;
+
+                rows.iter().map(sqlite_row_to_pull_request).collect()
+            }
+            DatabaseBackend::Postgres(pool) => {
+                let rows = sqlx::query(
+                    r#"
+                    SELECT id, repo_name, pr_number, opened_at, layer, head_sha, signatures,
+                           governance_status, linked_prs, emergency_mode, created_at, updated_at
+                    FROM pull_requests
+                    WHERE ($1::TEXT IS NULL OR repo_name = $1)
+                      AND ($2::TEXT IS NULL OR governance_status = $2)
+                    ORDER BY opened_at ASC, id ASC
+                    LIMIT $3 OFFSET $4
+                    "#,
+                )
+                .bind(repo_name)
+                .bind(stored_status)
+                .bind(limit)
+                .bind(offset)
+                .fetch_all(pool)
+                .await
+                .map_err(db_err)?;
+
+                rows.iter().map(postgres_row_to_pull_request).collect()
+            }
+        }
+    }
+
+    /// Drop every signature collected on a pull request
+    ///
+    /// Signatures commit to the PR's tier, so they no longer count once the
+    /// tier changes.
+    pub async fn clear_signatures(&self, repo_name: &str, pr_number: i32) -> Result<(), GovernanceError> {
+        match &self.backend {
+            DatabaseBackend::Sqlite(pool) => {
+                sqlite_write(|| {
+                    sqlx::query(
+                        r#"
+                        UPDATE pull_requests
+                        SET signatures = '[]', updated_at = CURRENT_TIMESTAMP
+                        WHERE repo_name = ? AND pr_number = ?
+                        "#,
+                    )
+                    .bind(repo_name)
+                    .bind(pr_number)
+                    .execute(pool)
+                })
+                .await
+                .map_err(|e| GovernanceError::DatabaseError(e.to_string()))?;
+            }
+            DatabaseBackend::Postgres(pool) => {
+                sqlx::query(
+                    r#"
+                    UPDATE pull_requests
+                    SET signatures = '[]'::jsonb, updated_at = CURRENT_TIMESTAMP
+                    WHERE repo_name = $1 AND pr_number = $2
+                    "#,
+                )
+                .bind(repo_name)
+                .bind(pr_number)
+                .execute(pool)
+                .await
+                .map_err(|e| GovernanceError::DatabaseError(e.to_string()))?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Move a pending pull request to `to_status`, e.g. `merged` or `closed`
+    ///
+    /// Returns false if the PR is unknown or was no longer in `from_status`,
+    /// so a redelivered close event isn't recorded twice.
+    pub async fn transition_pull_request(
+        &self,
+        repo_name: &str,
+        pr_number: i32,
+        from_status: &str,
+        to_status: &str,
+    ) -> Result<bool, GovernanceError> {
+        let updated = match &self.backend {
+            DatabaseBackend::Sqlite(pool) => sqlite_write(|| {
+                sqlx::query(
+                    r#"
+                    UPDATE pull_requests
+                    SET governance_status = ?, updated_at = CURRENT_TIMESTAMP
+                    WHERE repo_name = ? AND pr_number = ? AND governance_status = ?
+                    "#,
+                )
+                .bind(to_status)
+                .bind(repo_name)
+                .bind(pr_number)
+                .bind(from_status)
+                .execute(pool)
+            })
+            .await
+            .map_err(|e| GovernanceError::DatabaseError(e.to_string()))?
+            .rows_affected(),
+            DatabaseBackend::Postgres(pool) => sqlx::query(
+                r#"
+                UPDATE pull_requests
+                SET governance_status = $1, updated_at = CURRENT_TIMESTAMP
+                WHERE repo_name = $2 AND pr_number = $3 AND governance_status = $4
+                "#,
+            )
+            .bind(to_status)
+            .bind(repo_name)
+            .bind(pr_number)
+            .bind(from_status)
+            .execute(pool)
+            .await
+            .map_err(|e| GovernanceError::DatabaseError(e.to_string()))?
+            .rows_affected(),
+        };
+        Ok(updated > 0)
+    }
+
+    /// Remember the merge decision posted for a PR's head, replacing the previous one
+    pub async fn record_merge_decision(
+        &self,
+        repo_name: &str,
+        pr_number: i32,
+        head_sha: &str,
+        tier: u32,
+        decision: &MergeDecision,
+    ) -> Result<(), GovernanceError> {
+        let reasons = serde_json::to_value(&decision.reasons)?;
+        match &self.backend {
+            DatabaseBackend::Sqlite(pool) => {
+                let reasons = reasons.to_string();
+                sqlite_write(|| {
+                    sqlx::query(
+                        r#"
+                        INSERT INTO merge_decisions (repo_name, pr_number, head_sha, tier, blocked, reasons, decided_at)
+                        VALUES (?, ?, ?, ?, ?, ?, CURRENT_TIMESTAMP)
+                        ON CONFLICT (repo_name, pr_number) DO UPDATE SET
+                            head_sha = EXCLUDED.head_sha,
+                            tier = EXCLUDED.tier,
+                            blocked = EXCLUDED.blocked,
+                            reasons = EXCLUDED.reasons,
+                            decided_at = CURRENT_TIMESTAMP
+                        "#,
+                    )
+                    .bind(repo_name)
+                    .bind(pr_number)
+                    .bind(head_sha)
+                    .bind(tier as i64)
+                    .bind(decision.blocked)
+                    .bind(&reasons)
+                    .execute(pool)
+                })
+                .await
+                .map_err(db_err)?;
+            }
+            DatabaseBackend::Postgres(pool) => {
+                sqlx::query(
+                    r#"
+                    INSERT INTO merge_decisions (repo_name, pr_number, head_sha, tier, blocked, reasons, decided_at)
+                    VALUES ($1, $2, $3, $4, $5, $6, CURRENT_TIMESTAMP)
+                    ON CONFLICT (repo_name, pr_number) DO UPDATE SET
+                        head_sha = EXCLUDED.head_sha,
+                        tier = EXCLUDED.tier,
+                        blocked = EXCLUDED.blocked,
+                        reasons = EXCLUDED.reasons,
+                        decided_at = CURRENT_TIMESTAMP
+                    "#,
+                )
+                .bind(repo_name)
+                .bind(pr_number)
+                .bind(head_sha)
+                .bind(tier as i32)
+                .bind(decision.blocked)
+                .bind(&reasons)
+                .execute(pool)
+                .await
+                .map_err(db_err)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Merge decision last posted for a PR, if any was
+    pub async fn get_merge_decision(
+        &self,
+        repo_name: &str,
+        pr_number: i32,
+    ) -> Result<Option<PostedMergeDecision>, GovernanceError> {
+        match &self.backend {
+            DatabaseBackend::Sqlite(pool) => {
+                let row = sqlx::query(
+                    r#"
+                    SELECT head_sha, tier, blocked, reasons, decided_at
+                    FROM merge_decisions
+                    WHERE repo_name = ? AND pr_number = ?
+                    "#,
+                )
+                .bind(repo_name)
+                .bind(pr_number)
+                .fetch_optional(pool)
+                .await
+                .map_err(db_err)?;
+
+                row.as_ref().map(sqlite_row_to_merge_decision).transpose()
+            }
+            DatabaseBackend::Postgres(pool) => {
+                let row = sqlx::query(
+                    r#"
+                    SELECT head_sha, tier, blocked, reasons, decided_at
+                    FROM merge_decisions
+                    WHERE repo_name = $1 AND pr_number = $2
+                    "#,
+                )
+                .bind(repo_name)
+                .bind(pr_number)
+                .fetch_optional(pool)
+                .await
+                .map_err(db_err)?;
+
+                row.as_ref().map(postgres_row_to_merge_decision).transpose()
+            }
+        }
+    }
+
+    /// Get the active maintainers whose signatures count towards a layer's threshold
+    pub async fn get_maintainers_for_layer(
+        &self,
+        layer: i32,
+    ) -> Result<Vec<crate::database::models::Maintainer>, GovernanceError> {
+        match &self.backend {
+            DatabaseBackend::Sqlite(pool) => {
+                let rows = sqlx::query(
+                    "SELECT id, github_username, public_key, layer, active, last_updated FROM maintainers WHERE layer = ? AND active = true ORDER BY github_username",
+                )
+                .bind(layer)
+                .fetch_all(pool)
+                .await
+                .map_err(|e| GovernanceError::DatabaseError(e.to_string()))?;
+
+                rows.iter().map(sqlite_row_to_maintainer).collect()
+            }
+            DatabaseBackend::Postgres(pool) => {
+                let rows = sqlx::query(
+                    "SELECT id, github_username, public_key, layer, active, last_updated FROM maintainers WHERE layer = $1 AND active = true ORDER BY github_username",
+                )
+                .bind(layer)
+                .fetch_all(pool)
+                .await
+                .map_err(|e| GovernanceError::DatabaseError(e.to_string()))?;
+
+                rows.iter().map(postgres_row_to_maintainer).collect()
+            }
+        }
+    }
+
+    /// Every maintainer, active or not, by layer then username
+    pub async fn list_maintainers(&self) -> Result<Vec<Maintainer>, GovernanceError> {
+        let query = "SELECT id, github_username, public_key, layer, active, last_updated FROM maintainers ORDER BY layer, github_username";
+        match &self.backend {
+            DatabaseBackend::Sqlite(pool) => {
+                let rows = sqlx::query(query).fetch_all(pool).await.map_err(db_err)?;
+                rows.iter().map(sqlite_row_to_maintainer).collect()
+            }
+            DatabaseBackend::Postgres(pool) => {
+                let rows = sqlx::query(query).fetch_all(pool).await.map_err(db_err)?;
+                rows.iter().map(postgres_row_to_maintainer).collect()
+            }
+        }
+    }
+
+    /// Add a maintainer, or reactivate one with a new key and layer
+    pub async fn upsert_maintainer(
+        &self,
+        github_username: &str,
+        public_key: &str,
+        layer: i32,
+    ) -> Result<Maintainer, GovernanceError> {
+        match &self.backend {
+            DatabaseBackend::Sqlite(pool) => {
+                let row = sqlite_write(|| {
+                    sqlx::query(
+                        r#"
+                        INSERT INTO maintainers (github_username, public_key, layer, active, last_updated)
+                        VALUES (?, ?, ?, TRUE, CURRENT_TIMESTAMP)
+                        ON CONFLICT (github_username) DO UPDATE SET
+                            public_key = EXCLUDED.public_key,
+                            layer = EXCLUDED.layer,
+                            active = TRUE,
+                            last_updated = CURRENT_TIMESTAMP
+                        RETURNING id, github_username, public_key, layer, active, last_updated
+                        "#,
+                    )
+                    .bind(github_username)
+                    .bind(public_key)
+                    .bind(layer)
+                    .fetch_one(pool)
+                })
+                .await
+                .map_err(db_err)?;
+                sqlite_row_to_maintainer(&row)
+            }
+            DatabaseBackend::Postgres(pool) => {
+                let row = sqlx::query(
+                    r#"
+                    INSERT INTO maintainers (github_username, public_key, layer, active, last_updated)
+                    VALUES ($1, $2, $3, TRUE, CURRENT_TIMESTAMP)
+                    ON CONFLICT (github_username) DO UPDATE SET
+                        public_key = EXCLUDED.public_key,
+                        layer = EXCLUDED.layer,
+                        active = TRUE,
+                        last_updated = CURRENT_TIMESTAMP
+                    RETURNING id, github_username, public_key, layer, active, last_updated
+                    "#,
+                )
+                .bind(github_username)
+                .bind(public_key)
+                .bind(layer)
+                .fetch_one(pool)
+                .await
+                .map_err(db_err)?;
+                postgres_row_to_maintainer(&row)
+            }
+        }
+    }
+
+    /// Mark an active maintainer inactive; returns whether one was found
+    ///
+    /// The row is kept so past signatures can still be attributed.
+    pub async fn deactivate_maintainer(&self, github_username: &str) -> Result<bool, GovernanceError> {
+        let updated = match &self.backend {
+            DatabaseBackend::Sqlite(pool) => sqlite_write(|| {
+                sqlx::query(
+                    "UPDATE maintainers SET active = FALSE, last_updated = CURRENT_TIMESTAMP WHERE github_username = ? AND active = TRUE",
+                )
+                .bind(github_username)
+                .execute(pool)
+            })
+            .await
+            .map_err(db_err)?
+            .rows_affected(),
+            DatabaseBackend::Postgres(pool) => sqlx::query(
+                "UPDATE maintainers SET active = FALSE, last_updated = CURRENT_TIMESTAMP WHERE github_username = $1 AND active = TRUE",
+            )
+            .bind(github_username)
+            .execute(pool)
+            .await
+            .map_err(db_err)?
+            .rows_affected(),
+        };
+        Ok(updated > 0)
+    }
+
+    /// Sequence number the next maintainer set change's approvals must sign
+    ///
+    /// Counts the changes applied so far; unlike the `maintainer_added` /
+    /// `maintainer_removed` events it is never pruned.
+    pub async fn maintainer_change_sequence(&self) -> Result<i64, GovernanceError> {
+        let query = "SELECT sequence FROM maintainer_change_sequence WHERE id = 1";
+        match &self.backend {
+            DatabaseBackend::Sqlite(pool) => sqlx::query_scalar::<_, i64>(query).fetch_one(pool).await.map_err(db_err),
+            DatabaseBackend::Postgres(pool) => sqlx::query_scalar::<_, i64>(query).fetch_one(pool).await.map_err(db_err),
+        }
+    }
+
+    /// Apply an approved maintainer set change at `sequence`
+    ///
+    /// Advances the change sequence, updates the maintainer and logs the
+    /// `maintainer_added` / `maintainer_removed` event with `details` in one
+    /// transaction. If another change landed since the approvals were
+    /// checked, nothing is applied and `Conflict` is returned: the approvals
+    /// were signed for a spent sequence. Adding returns the maintainer row.
+    pub async fn apply_maintainer_change(
+        &self,
+        sequence: i64,
+        change: &MaintainerChange<'_>,
+        details: &serde_json::Value,
+    ) -> Result<Option<Maintainer>, GovernanceError> {
+        let (event_type, github_username) = match change {
+            MaintainerChange::Add { github_username, .. } => ("maintainer_added", *github_username),
+            MaintainerChange::Remove { github_username } => ("maintainer_removed", *github_username),
+        };
+        let conflict = || {
+            GovernanceError::Conflict(format!(
+                "maintainer set changed since change {} was approved",
+                sequence
+            ))
+        };
+
+        let (maintainer, event) = match &self.backend {
+            DatabaseBackend::Sqlite(pool) => {
+                let details = serde_json::to_string(details).unwrap_or_default();
+                let details = details.as_str();
+                let applied = sqlite_write(|| async move {
+                    let mut tx = pool.begin().await?;
+                    let advanced = sqlx::query(
+                        "UPDATE maintainer_change_sequence SET sequence = sequence + 1 WHERE id = 1 AND sequence = ?",
+                    )
+                    .bind(sequence)
+                    .execute(&mut *tx)
+                    .await?
+                    .rows_affected();
+                    if advanced == 0 {
+                        return Ok(None);
+                    }
+                    let maintainer = match change {
+                        MaintainerChange::Add { github_username, layer, public_key } => Some(
+                            sqlx::query(
+                                r#"
+                                INSERT INTO maintainers (github_username, public_key, layer, active, last_updated)
+                                VALUES (?, ?, ?, TRUE, CURRENT_TIMESTAMP)
+                                ON CONFLICT (github_username) DO UPDATE SET
+                                    public_key = EXCLUDED.public_key,
+                                    layer = EXCLUDED.layer,
+                                    active = TRUE,
+                                    last_updated = CURRENT_TIMESTAMP
+                                RETURNING id, github_username, public_key, layer, active, last_updated
+                                "#,
+                            )
+                            .bind(*github_username)
+                            .bind(*public_key)
+                            .bind(*layer)
+                            .fetch_one(&mut *tx)
+                            .await?,
+                        ),
+                        MaintainerChange::Remove { github_username } => {
+                            let removed = sqlx::query(
+                                "UPDATE maintainers SET active = FALSE, last_updated = CURRENT_TIMESTAMP WHERE github_username = ? AND active = TRUE",
+                            )
+                            .bind(*github_username)
+                            .execute(&mut *tx)
+                            .await?
+                            .rows_affected();
+                            if removed == 0 {
+                                return Ok(None);
+                            }
+                            None
+                        }
+                    };
+                    let event = sqlx::query(
+                        r#"
+                        INSERT INTO governance_events (event_type, repo_name, pr_number, maintainer, details)
+                        VALUES (?, NULL, NULL, ?, ?)
+                        RETURNING id, event_type, repo_name, pr_number, maintainer, details, timestamp
+                        "#,
+                    )
+                    .bind(event_type)
+                    .bind(github_username)
+                    .bind(details)
+                    .fetch_one(&mut *tx)
+                    .await?;
+                    tx.commit().await?;
+                    Ok(Some((maintainer, event)))
+                })
+                .await
+                .map_err(db_err)?;
+                let (maintainer, event) = applied.ok_or_else(conflict)?;
+                (
+                    maintainer.as_ref().map(sqlite_row_to_maintainer).transpose()?,
+                    sqlite_row_to_governance_event(&event)?,
+                )
+            }
+            DatabaseBackend::Postgres(pool) => {
+                let mut tx = pool.begin().await.map_err(db_err)?;
+                let advanced = sqlx::query(
+                    "UPDATE maintainer_change_sequence SET sequence = sequence + 1 WHERE id = 1 AND sequence = $1",
+                )
+                .bind(sequence)
+                .execute(&mut *tx)
+                .await
+                .map_err(db_err)?
+                .rows_affected();
+                if advanced == 0 {
+                    return Err(conflict());
+                }
+                let maintainer = match change {
+                    MaintainerChange::Add { github_username, layer, public_key } => {
+                        let row = sqlx::query(
+                            r#"
+                            INSERT INTO maintainers (github_username, public_key, layer, active, last_updated)
+                            VALUES ($1, $2, $3, TRUE, CURRENT_TIMESTAMP)
+                            ON CONFLICT (github_username) DO UPDATE SET
+                                public_key = EXCLUDED.public_key,
+                                layer = EXCLUDED.layer,
+                                active = TRUE,
+                                last_updated = CURRENT_TIMESTAMP
+                            RETURNING id, github_username, public_key, layer, active, last_updated
+                            "#,
+                        )
+                        .bind(*github_username)
+                        .bind(*public_key)
+                        .bind(*layer)
+                        .fetch_one(&mut *tx)
+                        .await
+                        .map_err(db_err)?;
+                        Some(postgres_row_to_maintainer(&row)?)
+                    }
+                    MaintainerChange::Remove { github_username } => {
+                        let removed = sqlx::query(
+                            "UPDATE maintainers SET active = FALSE, last_updated = CURRENT_TIMESTAMP WHERE github_username = $1 AND active = TRUE",
+                        )
+                        .bind(*github_username)
+                        .execute(&mut *tx)
+                        .await
+                        .map_err(db_err)?
+                        .rows_affected();
+                        if removed == 0 {
+                            return Err(conflict());
+                        }
+                        None
+                    }
+                };
+                let event = sqlx::query(
+                    r#"
+                    INSERT INTO governance_events (event_type, repo_name, pr_number, maintainer, details)
+                    VALUES ($1, NULL, NULL, $2, $3)
+                    RETURNING id, event_type, repo_name, pr_number, maintainer, details, timestamp
+                    "#,
+                )
+                .bind(event_type)
+                .bind(github_username)
+                .bind(details)
+                .fetch_one(&mut *tx)
+                .await
+                .map_err(db_err)?;
+                tx.commit().await.map_err(db_err)?;
+                (maintainer, postgres_row_to_governance_event(&event)?)
+            }
+        };
+
+        let _ = self.events.send(event);
+        Ok(maintainer)
+    }
+
+    /// Check whether a user is an active emergency keyholder
+    pub async fn is_active_emergency_keyholder(&self, username: &str) -> Result<bool, GovernanceError> {
+        let count = match &self.backend {
+            DatabaseBackend::Sqlite(pool) => sqlx::query_scalar::<_, i64>(
+                "SELECT COUNT(*) FROM emergency_keyholders WHERE github_username = ? AND active = true",
+            )
+            .bind(username)
+            .fetch_one(pool)
+            .await
+            .map_err(db_err)?,
+            DatabaseBackend::Postgres(pool) => sqlx::query_scalar::<_, i64>(
+                "SELECT COUNT(*) FROM emergency_keyholders WHERE github_username = $1 AND active = true",
+            )
+            .bind(username)
+            .fetch_one(pool)
+            .await
+            .map_err(db_err)?,
+        };
+        Ok(count > 0)
+    }
+
+    /// Add a server to the revocation list
+    pub async fn record_server_revocation(
+        &self,
+        server_id: &str,
+        reason: &str,
+        signed_by: &[String],
+    ) -> Result<(), GovernanceError> {
+        let signed_by = serde_json::to_string(signed_by).unwrap_or_default();
+        match &self.backend {
+            DatabaseBackend::Sqlite(pool) => {
+                sqlx::query(
+                    "INSERT INTO revoked_servers (server_id, reason, signed_by, revoked_at) VALUES (?, ?, ?, CURRENT_TIMESTAMP)",
+                )
+                .bind(server_id)
+                .bind(reason)
+                .bind(&signed_by)
+                .execute(pool)
+                .await
+                .map_err(db_err)?;
+            }
+            DatabaseBackend::Postgres(pool) => {
+                sqlx::query(
+                    "INSERT INTO revoked_servers (server_id, reason, signed_by, revoked_at) VALUES ($1, $2, $3, CURRENT_TIMESTAMP)",
+                )
+                .bind(server_id)
+                .bind(reason)
+                .bind(&signed_by)
+                .execute(pool)
+                .await
+                .map_err(db_err)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Get the revocation for a server, if it has been revoked
+    pub async fn get_server_revocation(
+        &self,
+        server_id: &str,
+    ) -> Result<Option<ServerRevocation>, GovernanceError> {
+        let row = match &self.backend {
+            DatabaseBackend::Sqlite(pool) => sqlx::query_as::<_, (String, String, String, NaiveDateTime)>(
+                "SELECT server_id, reason, signed_by, revoked_at FROM revoked_servers WHERE server_id = ?",
+            )
+            .bind(server_id)
+            .fetch_optional(pool)
+            .await
+            .map_err(db_err)?,
+            DatabaseBackend::Postgres(pool) => sqlx::query_as::<_, (String, String, String, NaiveDateTime)>(
+                "SELECT server_id, reason, signed_by, revoked_at FROM revoked_servers WHERE server_id = $1",
+            )
+            .bind(server_id)
+            .fetch_optional(pool)
+            .await
+            .map_err(db_err)?,
+        };
+
+        Ok(row.map(|(server_id, reason, signed_by, revoked_at)| ServerRevocation {
+            server_id,
+            reason,
+            signed_by: serde_json::from_str(&signed_by).unwrap_or_default(),
+            revoked_at: revoked_at.and_utc(),
+        }))
+    }
+
+    /// Claim a webhook delivery for processing
+    ///
+    /// Returns `false` if the delivery was already claimed after `expired_before`.
+    /// The insert is a single statement, so of several concurrent redeliveries
+    /// exactly one wins the claim.
+    pub async fn claim_webhook_delivery(
+        &self,
+        delivery_id: &str,
+        event_name: &str,
+        received_at: DateTime<Utc>,
+        expired_before: DateTime<Utc>,
+    ) -> Result<bool, GovernanceError> {
+        let claimed = match &self.backend {
+            DatabaseBackend::Sqlite(pool) => sqlite_write(|| {
+                sqlx::query(
+                    r#"
+                    INSERT INTO webhook_deliveries (delivery_id, event_name, received_at)
+                    VALUES (?, ?, ?)
+                    ON CONFLICT (delivery_id) DO UPDATE SET
+                        event_name = EXCLUDED.event_name,
+                        received_at = EXCLUDED.received_at
+                    WHERE webhook_deliveries.received_at < ?
+                    "#,
+                )
+                .bind(delivery_id)
+                .bind(event_name)
+                .bind(received_at.naive_utc())
+                .bind(expired_before.naive_utc())
+                .execute(pool)
+            })
+            .await
+            .map_err(db_err)?
+            .rows_affected(),
+            DatabaseBackend::Postgres(pool) => sqlx::query(
+                r#"
+                INSERT INTO webhook_deliveries (delivery_id, event_name, received_at)
+                VALUES ($1, $2, $3)
+                ON CONFLICT (delivery_id) DO UPDATE SET
+                    event_name = EXCLUDED.event_name,
+                    received_at = EXCLUDED.received_at
+                WHERE webhook_deliveries.received_at < $4
+                "#,
+            )
+            .bind(delivery_id)
+            .bind(event_name)
+            .bind(received_at.naive_utc())
+            .bind(expired_before.naive_utc())
+            .execute(pool)
+            .await
+            .map_err(db_err)?
+            .rows_affected(),
+        };
+        Ok(claimed == 1)
+    }
+
+    /// Forget a claimed webhook delivery so a redelivery is processed again
+    pub async fn release_webhook_delivery(&self, delivery_id: &str) -> Result<(), GovernanceError> {
         match &self.backend {
             DatabaseBackend::Sqlite(pool) => {
-                sqlx::migrate!("./migrations")
-                    .run(pool)
-                    .await
-                    .map_err(|e| GovernanceError::DatabaseError(e.to_string()))?;
+                sqlite_write(|| {
+                    sqlx::query("DELETE FROM webhook_deliveries WHERE delivery_id = ?")
+                        .bind(delivery_id)
+                        .execute(pool)
+                })
+                .await
+                .map_err(db_err)?;
             }
             DatabaseBackend::Postgres(pool) => {
-                sqlx::migrate!("./migrations-postgres")
-                    .run(pool)
+                sqlx::query("DELETE FROM webhook_deliveries WHERE delivery_id = $1")
+                    .bind(delivery_id)
+                    .execute(pool)
                     .await
-                    .map_err(|e| GovernanceError::DatabaseError(e.to_string()))?;
+                    .map_err(db_err)?;
             }
         }
         Ok(())
     }
 
-    pub fn get_sqlite_pool(&self) -> Option<&SqlitePool> {
-        match &self.backend {
-            DatabaseBackend::Sqlite(pool) => Some(pool),
-            _ => None,
-        }
+    /// Delete webhook deliveries received before `cutoff`, returning how many were removed
+    pub async fn prune_webhook_deliveries(&self, cutoff: DateTime<Utc>) -> Result<u64, GovernanceError> {
+        let removed = match &self.backend {
+            DatabaseBackend::Sqlite(pool) => sqlx::query("DELETE FROM webhook_deliveries WHERE received_at < ?")
+                .bind(cutoff.naive_utc())
+                .execute(pool)
+                .await
+                .map_err(db_err)?
+                .rows_affected(),
+            DatabaseBackend::Postgres(pool) => sqlx::query("DELETE FROM webhook_deliveries WHERE received_at < $1")
+                .bind(cutoff.naive_utc())
+                .execute(pool)
+                .await
+                .map_err(db_err)?
+                .rows_affected(),
+        };
+        Ok(removed)
     }
 
-    pub fn get_postgres_pool(&self) -> Option<&PgPool> {
+    /// Mark active keys whose `expires_at` has passed as expired
+    pub async fn expire_keys(&self, now: DateTime<Utc>) -> Result<Vec<ExpiredKey>, GovernanceError> {
         match &self.backend {
-            DatabaseBackend::Postgres(pool) => Some(pool),
-            _ => None,
+            // KeyManager stores key timestamps as RFC 3339 text, so compare in the same encoding
+            DatabaseBackend::Sqlite(pool) => {
+                let rows = sqlx::query(
+                    "UPDATE key_metadata SET status = 'expired' WHERE status = 'active' AND expires_at <= ? RETURNING key_id, key_type, owner, expires_at",
+                )
+                .bind(now)
+                .fetch_all(pool)
+                .await
+                .map_err(db_err)?;
+                rows.iter()
+                    .map(|row| {
+                        Ok(ExpiredKey {
+                            key_id: row.try_get("key_id").map_err(db_err)?,
+                            key_type: row.try_get("key_type").map_err(db_err)?,
+                            owner: row.try_get("owner").map_err(db_err)?,
+                            expires_at: row.try_get("expires_at").map_err(db_err)?,
+                        })
+                    })
+                    .collect()
+            }
+            DatabaseBackend::Postgres(pool) => {
+                let rows = sqlx::query(
+                    "UPDATE key_metadata SET status = 'expired' WHERE status = 'active' AND expires_at <= $1 RETURNING key_id, key_type, owner, expires_at",
+                )
+                .bind(now.naive_utc())
+                .fetch_all(pool)
+                .await
+                .map_err(db_err)?;
+                rows.iter()
+                    .map(|row| {
+                        let expires_at: NaiveDateTime = row.try_get("expires_at").map_err(db_err)?;
+                        Ok(ExpiredKey {
+                            key_id: row.try_get("key_id").map_err(db_err)?,
+                            key_type: row.try_get("key_type").map_err(db_err)?,
+                            owner: row.try_get("owner").map_err(db_err)?,
+                            expires_at: expires_at.and_utc(),
+                        })
+                    })
+                    .collect()
+            }
         }
     }
 
-    pub fn is_sqlite(&self) -> bool {
-        matches!(self.backend, DatabaseBackend::Sqlite(_))
+    /// Active keys expiring before `cutoff`, soonest first
+    pub async fn get_keys_expiring_before(&self, cutoff: DateTime<Utc>) -> Result<Vec<ExpiringKey>, GovernanceError> {
+        match &self.backend {
+            // Same RFC 3339 text encoding as `expire_keys`
+            DatabaseBackend::Sqlite(pool) => {
+                let rows = sqlx::query(
+                    "SELECT key_id, key_type, owner, expires_at FROM key_metadata WHERE status = 'active' AND expires_at < ? ORDER BY expires_at",
+                )
+                .bind(cutoff)
+                .fetch_all(pool)
+                .await
+                .map_err(db_err)?;
+                rows.iter()
+                    .map(|row| {
+                        Ok(ExpiringKey {
+                            key_id: row.try_get("key_id").map_err(db_err)?,
+                            key_type: row.try_get("key_type").map_err(db_err)?,
+                            owner: row.try_get("owner").map_err(db_err)?,
+                            expires_at: row.try_get("expires_at").map_err(db_err)?,
+                        })
+                    })
+                    .collect()
+            }
+            DatabaseBackend::Postgres(pool) => {
+                let rows = sqlx::query(
+                    "SELECT key_id, key_type, owner, expires_at FROM key_metadata WHERE status = 'active' AND expires_at < $1 ORDER BY expires_at",
+                )
+                .bind(cutoff.naive_utc())
+                .fetch_all(pool)
+                .await
+                .map_err(db_err)?;
+                rows.iter()
+                    .map(|row| {
+                        let expires_at: NaiveDateTime = row.try_get("expires_at").map_err(db_err)?;
+                        Ok(ExpiringKey {
+                            key_id: row.try_get("key_id").map_err(db_err)?,
+                            key_type: row.try_get("key_type").map_err(db_err)?,
+                            owner: row.try_get("owner").map_err(db_err)?,
+                            expires_at: expires_at.and_utc(),
+                        })
+                    })
+                    .collect()
+            }
+        }
     }
 
-    pub fn is_postgres(&self) -> bool {
-        matches!(self.backend, DatabaseBackend::Postgres(_))
+    /// Get when a signature reminder was last posted on a PR
+    pub async fn get_last_reminder(
+        &self,
+        repo_name: &str,
+        pr_number: i32,
+    ) -> Result<Option<DateTime<Utc>>, GovernanceError> {
+        let last = match &self.backend {
+            DatabaseBackend::Sqlite(pool) => sqlx::query_scalar::<_, NaiveDateTime>(
+                "SELECT last_reminded_at FROM pr_reminders WHERE repo_name = ? AND pr_number = ?",
+            )
+            .bind(repo_name)
+            .bind(pr_number)
+            .fetch_optional(pool)
+            .await
+            .map_err(|e| GovernanceError::DatabaseError(e.to_string()))?,
+            DatabaseBackend::Postgres(pool) => sqlx::query_scalar::<_, NaiveDateTime>(
+                "SELECT last_reminded_at FROM pr_reminders WHERE repo_name = $1 AND pr_number = $2",
+            )
+            .bind(repo_name)
+            .bind(pr_number)
+            .fetch_optional(pool)
+            .await
+            .map_err(|e| GovernanceError::DatabaseError(e.to_string()))?,
+        };
+        Ok(last.map(|t| t.and_utc()))
     }
 
-    pub async fn create_pull_request(
+    /// Record that a signature reminder was posted on a PR
+    pub async fn record_reminder(
         &self,
         repo_name: &str,
         pr_number: i32,
-        head_sha: &str,
-        layer: i32,
+        comment_id: Option<i64>,
+        reminded_at: DateTime<Utc>,
     ) -> Result<(), GovernanceError> {
         match &self.backend {
             DatabaseBackend::Sqlite(pool) => {
-                sqlx::query(
-                    r#"
-                    INSERT INTO pull_requests (repo_name, pr_number, opened_at, layer, head_sha)
-                    VALUES (?, ?, CURRENT_TIMESTAMP, ?, ?)
-                    ON CONFLICT (repo_name, pr_number) DO UPDATE SET
-                        head_sha = EXCLUDED.head_sha,
-                        updated_at = CURRENT_TIMESTAMP
-                    "#,
-                )
-                .bind(repo_name)
-                .bind(pr_number)
-                .bind(layer)
-                .bind(head_sha)
-                .execute(pool)
+                sqlite_write(|| {
+                    sqlx::query(
+                        r#"
+                        INSERT INTO pr_reminders (repo_name, pr_number, comment_id, last_reminded_at)
+                        VALUES (?, ?, ?, ?)
+                        ON CONFLICT (repo_name, pr_number) DO UPDATE SET
+                            comment_id = COALESCE(EXCLUDED.comment_id, pr_reminders.comment_id),
+                            last_reminded_at = EXCLUDED.last_reminded_at,
+                            reminder_count = pr_reminders.reminder_count + 1
+                        "#,
+                    )
+                    .bind(repo_name)
+                    .bind(pr_number)
+                    .bind(comment_id)
+                    .bind(reminded_at.naive_utc())
+                    .execute(pool)
+                })
                 .await
                 .map_err(|e| GovernanceError::DatabaseError(e.to_string()))?;
             }
             DatabaseBackend::Postgres(pool) => {
                 sqlx::query(
                     r#"
-                    INSERT INTO pull_requests (repo_name, pr_number, opened_at, layer, head_sha)
-                    VALUES ($1, $2, CURRENT_TIMESTAMP, $3, $4)
+                    INSERT INTO pr_reminders (repo_name, pr_number, comment_id, last_reminded_at)
+                    VALUES ($1, $2, $3, $4)
                     ON CONFLICT (repo_name, pr_number) DO UPDATE SET
-                        head_sha = EXCLUDED.head_sha,
-                        updated_at = CURRENT_TIMESTAMP
+                        comment_id = COALESCE(EXCLUDED.comment_id, pr_reminders.comment_id),
+                        last_reminded_at = EXCLUDED.last_reminded_at,
+                        reminder_count = pr_reminders.reminder_count + 1
                     "#,
                 )
                 .bind(repo_name)
                 .bind(pr_number)
-                .bind(layer)
-                .bind(head_sha)
+                .bind(comment_id)
+                .bind(reminded_at.naive_utc())
                 .execute(pool)
                 .await
                 .map_err(|e| GovernanceError::DatabaseError(e.to_string()))?;
@@ -184,51 +1817,34 @@ impl Database {
         Ok(())
     }
 
-    pub async fn update_review_status(
-        &self,
-        _repo_name: &str,
-        _pr_number: i32,
-        _reviewer: &str,
-        _state: &str,
-    ) -> Result<(), GovernanceError> {
-        // This would update review status in the database
-        // Implementation depends on specific review tracking requirements
-        Ok(())
-    }
-
-    pub async fn add_signature(
-        &self,
-        _repo_name: &str,
-        _pr_number: i32,
-        _signer: &str,
-        _signature: &str,
-    ) -> Result<(), GovernanceError> {
-        // Add signature to the pull request
-        // This would involve updating the signatures JSONB field
-        Ok(())
-    }
-
-    pub async fn log_governance_event(
+    /// Record a registry that has been anchored via OpenTimestamps
+    pub async fn record_registry_anchor(
         &self,
-        event_type: &str,
-        repo_name: Option<&str>,
-        pr_number: Option<i32>,
-        maintainer: Option<&str>,
-        details: &serde_json::Value,
+        month_key: &str,
+        registry_hash: &str,
+        registry_path: &str,
+        proof_path: &str,
+        covers_until: DateTime<Utc>,
     ) -> Result<(), GovernanceError> {
         match &self.backend {
             DatabaseBackend::Sqlite(pool) => {
                 sqlx::query(
                     r#"
-                    INSERT INTO governance_events (event_type, repo_name, pr_number, maintainer, details)
+                    INSERT INTO registry_anchors (month_key, registry_hash, registry_path, proof_path, covers_until)
                     VALUES (?, ?, ?, ?, ?)
+                    ON CONFLICT (month_key) DO UPDATE SET
+                        registry_hash = EXCLUDED.registry_hash,
+                        registry_path = EXCLUDED.registry_path,
+                        proof_path = EXCLUDED.proof_path,
+                        covers_until = EXCLUDED.covers_until,
+                        anchored_at = CURRENT_TIMESTAMP
                     "#,
                 )
-                .bind(event_type)
-                .bind(repo_name)
-                .bind(pr_number)
-                .bind(maintainer)
-                .bind(serde_json::to_string(details).unwrap_or_default())
+                .bind(month_key)
+                .bind(registry_hash)
+                .bind(registry_path)
+                .bind(proof_path)
+                .bind(covers_until.naive_utc())
                 .execute(pool)
                 .await
                 .map_err(|e| GovernanceError::DatabaseError(e.to_string()))?;
@@ -236,15 +1852,21 @@ impl Database {
             DatabaseBackend::Postgres(pool) => {
                 sqlx::query(
                     r#"
-                    INSERT INTO governance_events (event_type, repo_name, pr_number, maintainer, details)
+                    INSERT INTO registry_anchors (month_key, registry_hash, registry_path, proof_path, covers_until)
                     VALUES ($1, $2, $3, $4, $5)
+                    ON CONFLICT (month_key) DO UPDATE SET
+                        registry_hash = EXCLUDED.registry_hash,
+                        registry_path = EXCLUDED.registry_path,
+                        proof_path = EXCLUDED.proof_path,
+                        covers_until = EXCLUDED.covers_until,
+                        anchored_at = CURRENT_TIMESTAMP
                     "#,
                 )
-                .bind(event_type)
-                .bind(repo_name)
-                .bind(pr_number)
-                .bind(maintainer)
-                .bind(details)
+                .bind(month_key)
+                .bind(registry_hash)
+                .bind(registry_path)
+                .bind(proof_path)
+                .bind(covers_until.naive_utc())
                 .execute(pool)
                 .await
                 .map_err(|e| GovernanceError::DatabaseError(e.to_string()))?;
@@ -253,55 +1875,276 @@ impl Database {
         Ok(())
     }
 
-    pub async fn get_pull_request(
+    /// Get the most recently anchored registry
+    pub async fn get_latest_registry_anchor(
         &self,
-        _repo_name: &str,
-        _pr_number: i32,
-    ) -> Result<Option<crate::database::models::PullRequest>, GovernanceError> {
-        // This would retrieve a pull request from the database
-        // For now, return None as a placeholder
-        Ok(None)
+    ) -> Result<Option<crate::database::models::RegistryAnchor>, GovernanceError> {
+        let row = match &self.backend {
+            DatabaseBackend::Sqlite(pool) => sqlx::query_as::<_, (String, String, String, String, NaiveDateTime, Option<NaiveDateTime>)>(
+                "SELECT month_key, registry_hash, registry_path, proof_path, covers_until, anchored_at FROM registry_anchors ORDER BY covers_until DESC LIMIT 1",
+            )
+            .fetch_optional(pool)
+            .await
+            .map_err(|e| GovernanceError::DatabaseError(e.to_string()))?,
+            DatabaseBackend::Postgres(pool) => sqlx::query_as::<_, (String, String, String, String, NaiveDateTime, Option<NaiveDateTime>)>(
+                "SELECT month_key, registry_hash, registry_path, proof_path, covers_until, anchored_at FROM registry_anchors ORDER BY covers_until DESC LIMIT 1",
+            )
+            .fetch_optional(pool)
+            .await
+            .map_err(|e| GovernanceError::DatabaseError(e.to_string()))?,
+        };
+
+        Ok(row.map(|(month_key, registry_hash, registry_path, proof_path, covers_until, anchored_at)| {
+            crate::database::models::RegistryAnchor {
+                month_key,
+                registry_hash,
+                registry_path,
+                proof_path,
+                covers_until: covers_until.and_utc(),
+                anchored_at: anchored_at.map(|t| t.and_utc()),
+            }
+        }))
     }
 
-    pub async fn get_governance_events(
+    /// Get the point in time up to which events are covered by an anchored registry
+    pub async fn get_anchored_until(&self) -> Result<Option<DateTime<Utc>>, GovernanceError> {
+        let covers_until = match &self.backend {
+            DatabaseBackend::Sqlite(pool) => sqlx::query_scalar::<_, Option<NaiveDateTime>>(
+                "SELECT MAX(covers_until) FROM registry_anchors",
+            )
+            .fetch_one(pool)
+            .await
+            .map_err(|e| GovernanceError::DatabaseError(e.to_string()))?,
+            DatabaseBackend::Postgres(pool) => sqlx::query_scalar::<_, Option<NaiveDateTime>>(
+                "SELECT MAX(covers_until) FROM registry_anchors",
+            )
+            .fetch_one(pool)
+            .await
+            .map_err(|e| GovernanceError::DatabaseError(e.to_string()))?,
+        };
+        Ok(covers_until.map(|t| t.and_utc()))
+    }
+
+    /// Count verified support and veto signals submitted by economic nodes for a PR
+    ///
+    /// Abstentions are excluded; they do not count towards the economic quorum.
+    pub async fn count_economic_signals(&self, pr_id: i32) -> Result<u32, GovernanceError> {
+        let count = match &self.backend {
+            DatabaseBackend::Sqlite(pool) => sqlx::query_scalar::<_, i64>(
+                r#"
+                SELECT COUNT(*) FROM veto_signals
+                WHERE pr_id = ? AND verified = TRUE AND signal_type IN ('veto', 'support')
+                "#,
+            )
+            .bind(pr_id)
+            .fetch_one(pool)
+            .await
+            .map_err(|e| GovernanceError::DatabaseError(e.to_string()))?,
+            DatabaseBackend::Postgres(pool) => sqlx::query_scalar::<_, i64>(
+                r#"
+                SELECT COUNT(*) FROM veto_signals
+                WHERE pr_id = $1 AND verified = TRUE AND signal_type IN ('veto', 'support')
+                "#,
+            )
+            .bind(pr_id)
+            .fetch_one(pool)
+            .await
+            .map_err(|e| GovernanceError::DatabaseError(e.to_string()))?,
+        };
+        Ok(count as u32)
+    }
+
+    /// Count every veto signal on record, grouped by signal type
+    pub async fn count_veto_signals_by_type(&self) -> Result<Vec<(String, i64)>, GovernanceError> {
+        let query = "SELECT signal_type, COUNT(*) FROM veto_signals GROUP BY signal_type ORDER BY signal_type";
+        let counts = match &self.backend {
+            DatabaseBackend::Sqlite(pool) => sqlx::query_as::<_, (String, i64)>(query)
+                .fetch_all(pool)
+                .await
+                .map_err(db_err)?,
+            DatabaseBackend::Postgres(pool) => sqlx::query_as::<_, (String, i64)>(query)
+                .fetch_all(pool)
+                .await
+                .map_err(db_err)?,
+        };
+        Ok(counts)
+    }
+
+    /// Active and idle connections in the pool
+    pub fn pool_connections(&self) -> (u32, u32) {
+        let (size, idle) = match &self.backend {
+            DatabaseBackend::Sqlite(pool) => (pool.size(), pool.num_idle() as u32),
+            DatabaseBackend::Postgres(pool) => (pool.size(), pool.num_idle() as u32),
+        };
+        (size.saturating_sub(idle), idle)
+    }
+
+    /// Get every governance event recorded for a PR, oldest first
+    pub async fn get_pr_governance_events(
         &self,
-        _limit: i64,
-    ) -> Result<Vec<crate::database::models::GovernanceEvent>, GovernanceError> {
-        // This would retrieve governance events from the database
-        // For now, return empty vector as a placeholder
-        Ok(vec![])
+        repo_name: &str,
+        pr_number: i32,
+    ) -> Result<Vec<GovernanceEvent>, GovernanceError> {
+        match &self.backend {
+            DatabaseBackend::Sqlite(pool) => {
+                let rows = sqlx::query(
+                    r#"
+                    SELECT id, event_type, repo_name, pr_number, maintainer, details, timestamp
+                    FROM governance_events
+                    WHERE repo_name = ? AND pr_number = ?
+                    ORDER BY timestamp ASC, id ASC
+                    "#,
+                )
+                .bind(repo_name)
+                .bind(pr_number)
+                .fetch_all(pool)
+                .await
+                .map_err(db_err)?;
+
+                rows.iter().map(sqlite_row_to_governance_event).collect()
+            }
+            DatabaseBackend::Postgres(pool) => {
+                let rows = sqlx::query(
+                    r#"
+                    SELECT id, event_type, repo_name, pr_number, maintainer, details, timestamp
+                    FROM governance_events
+                    WHERE repo_name = $1 AND pr_number = $2
+                    ORDER BY timestamp ASC, id ASC
+                    "#,
+                )
+                .bind(repo_name)
+                .bind(pr_number)
+                .fetch_all(pool)
+                .await
+                .map_err(db_err)?;
+
+                rows.iter().map(postgres_row_to_governance_event).collect()
+            }
+        }
     }
 
-    pub async fn get_maintainer_by_username(
+    /// Get every economic node signal submitted for a PR, oldest first
+    pub async fn get_pr_economic_signals(
         &self,
-        username: &str,
-    ) -> Result<Option<crate::database::models::Maintainer>, GovernanceError> {
+        pr_id: i32,
+    ) -> Result<Vec<EconomicSignal>, GovernanceError> {
         match &self.backend {
             DatabaseBackend::Sqlite(pool) => {
-                let maintainer = sqlx::query_as!(
-                    crate::database::models::Maintainer,
-                    "SELECT id, github_username, public_key, layer, active, last_updated FROM maintainers WHERE github_username = ? AND active = true",
-                    username
+                let rows = sqlx::query(
+                    r#"
+                    SELECT vs.node_id, en.entity_name, vs.signal_type, vs.weight, vs.rationale,
+                           vs.verified, vs.timestamp
+                    FROM veto_signals vs
+                    JOIN economic_nodes en ON vs.node_id = en.id
+                    WHERE vs.pr_id = ?
+                    ORDER BY vs.timestamp ASC
+                    "#,
                 )
-                .fetch_optional(pool)
+                .bind(pr_id)
+                .fetch_all(pool)
                 .await
-                .map_err(|e| GovernanceError::DatabaseError(e.to_string()))?;
-                Ok(maintainer)
+                .map_err(db_err)?;
+
+                rows.iter().map(sqlite_row_to_economic_signal).collect()
             }
             DatabaseBackend::Postgres(pool) => {
-                let maintainer = sqlx::query_as!(
-                    crate::database::models::Maintainer,
-                    "SELECT id, github_username, public_key, layer, active, last_updated FROM maintainers WHERE github_username = $1 AND active = true",
-                    username
+                let rows = sqlx::query(
+                    r#"
+                    SELECT vs.node_id, en.entity_name, vs.signal_type, vs.weight, vs.rationale,
+                           vs.verified, vs.timestamp
+                    FROM veto_signals vs
+                    JOIN economic_nodes en ON vs.node_id = en.id
+                    WHERE vs.pr_id = $1
+                    ORDER BY vs.timestamp ASC
+                    "#,
                 )
-                .fetch_optional(pool)
+                .bind(pr_id)
+                .fetch_all(pool)
                 .await
-                .map_err(|e| GovernanceError::DatabaseError(e.to_string()))?;
-                Ok(maintainer)
+                .map_err(db_err)?;
+
+                rows.iter().map(postgres_row_to_economic_signal).collect()
             }
         }
     }
 
+    /// Delete one batch of governance events older than `cutoff`
+    ///
+    /// Events flagged `retain_for_compliance`, events whose type is listed in
+    /// `retained_event_types` and events of still-pending PRs, whose tier is
+    /// resolved from them, are never deleted. Returns the number of rows removed.
+    pub async fn prune_governance_events(
+        &self,
+        cutoff: DateTime<Utc>,
+        retained_event_types: &[String],
+        batch_size: i64,
+    ) -> Result<u64, GovernanceError> {
+        let result = match &self.backend {
+            DatabaseBackend::Sqlite(pool) => {
+                let placeholders = vec!["?"; retained_event_types.len()].join(", ");
+                let sql = format!(
+                    r#"
+                    DELETE FROM governance_events WHERE id IN (
+                        SELECT id FROM governance_events
+                        WHERE timestamp < ?
+                          AND COALESCE(retain_for_compliance, false) = false
+                          AND NOT EXISTS (
+                              SELECT 1 FROM pull_requests p
+                              WHERE p.repo_name = governance_events.repo_name
+                                AND p.pr_number = governance_events.pr_number
+                                AND p.governance_status = 'pending'
+                          )
+                          {}
+                        ORDER BY id
+                        LIMIT ?
+                    )
+                    "#,
+                    if retained_event_types.is_empty() {
+                        String::new()
+                    } else {
+                        format!("AND event_type NOT IN ({})", placeholders)
+                    }
+                );
+                let mut query = sqlx::query(&sql).bind(cutoff.naive_utc());
+                for event_type in retained_event_types {
+                    query = query.bind(event_type);
+                }
+                query
+                    .bind(batch_size)
+                    .execute(pool)
+                    .await
+                    .map_err(|e| GovernanceError::DatabaseError(e.to_string()))?
+                    .rows_affected()
+            }
+            DatabaseBackend::Postgres(pool) => sqlx::query(
+                r#"
+                DELETE FROM governance_events WHERE id IN (
+                    SELECT id FROM governance_events
+                    WHERE timestamp < $1
+                      AND COALESCE(retain_for_compliance, false) = false
+                      AND NOT EXISTS (
+                          SELECT 1 FROM pull_requests p
+                          WHERE p.repo_name = governance_events.repo_name
+                            AND p.pr_number = governance_events.pr_number
+                            AND p.governance_status = 'pending'
+                      )
+                      AND NOT (event_type = ANY($2))
+                    ORDER BY id
+                    LIMIT $3
+                )
+                "#,
+            )
+            .bind(cutoff.naive_utc())
+            .bind(retained_event_types)
+            .bind(batch_size)
+            .execute(pool)
+            .await
+            .map_err(|e| GovernanceError::DatabaseError(e.to_string()))?
+            .rows_affected(),
+        };
+        Ok(result)
+    }
+
     /// Get the database pool for testing purposes (SQLite only)
     pub fn pool(&self) -> Option<&SqlitePool> {
         self.get_sqlite_pool()
@@ -378,6 +2221,15 @@ impl Database {
 
     /// Get performance statistics
     pub async fn get_performance_stats(&self) -> Result<PerformanceStats, GovernanceError> {
+        self.get_performance_stats_with_threshold(DEFAULT_SLOW_QUERY_THRESHOLD_MS)
+            .await
+    }
+
+    /// Get performance statistics, counting statements slower than `slow_query_threshold_ms`
+    pub async fn get_performance_stats_with_threshold(
+        &self,
+        slow_query_threshold_ms: f64,
+    ) -> Result<PerformanceStats, GovernanceError> {
         match &self.backend {
             DatabaseBackend::Sqlite(pool) => {
                 // Get cache size
@@ -402,16 +2254,50 @@ impl Database {
                     cache_size,
                     wal_checkpoint_threshold,
                     slow_queries_count: compile_options.len() as i64,
+                    degraded: false,
+                    degraded_reason: None,
                 })
             }
-            DatabaseBackend::Postgres(_pool) => {
-                // PostgreSQL-specific statistics would go here
-                // For now, return default values
-                Ok(PerformanceStats {
-                    cache_size: 0,
-                    wal_checkpoint_threshold: 0,
-                    slow_queries_count: 0,
-                })
+            DatabaseBackend::Postgres(pool) => {
+                // shared_buffers is reported in 8kB pages, max_wal_size in MB
+                let cache_size = sqlx::query_scalar::<_, i64>(
+                    "SELECT setting::bigint FROM pg_settings WHERE name = 'shared_buffers'",
+                )
+                .fetch_one(pool)
+                .await
+                .map_err(|e| GovernanceError::DatabaseError(e.to_string()))?;
+
+                let wal_checkpoint_threshold = sqlx::query_scalar::<_, i64>(
+                    "SELECT setting::bigint FROM pg_settings WHERE name = 'max_wal_size'",
+                )
+                .fetch_one(pool)
+                .await
+                .map_err(|e| GovernanceError::DatabaseError(e.to_string()))?;
+
+                // pg_stat_statements is an optional extension; report degraded stats without it
+                let slow_queries = sqlx::query_scalar::<_, i64>(
+                    "SELECT COUNT(*) FROM pg_stat_statements WHERE mean_exec_time > $1",
+                )
+                .bind(slow_query_threshold_ms)
+                .fetch_one(pool)
+                .await;
+
+                match slow_queries {
+                    Ok(slow_queries_count) => Ok(PerformanceStats {
+                        cache_size,
+                        wal_checkpoint_threshold,
+                        slow_queries_count,
+                        degraded: false,
+                        degraded_reason: None,
+                    }),
+                    Err(e) => Ok(PerformanceStats {
+                        cache_size,
+                        wal_checkpoint_threshold,
+                        slow_queries_count: 0,
+                        degraded: true,
+                        degraded_reason: Some(format!("pg_stat_statements unavailable: {}", e)),
+                    }),
+                }
             }
         }
     }
@@ -478,4 +2364,259 @@ pub struct PerformanceStats {
     pub cache_size: i64,
     pub wal_checkpoint_threshold: i64,
     pub slow_queries_count: i64,
-}
\ No newline at end of file
+    /// Set when some statistics could not be collected and are not meaningful
+    pub degraded: bool,
+    pub degraded_reason: Option<String>,
+}
+
+fn db_err(e: sqlx::Error) -> GovernanceError {
+    GovernanceError::DatabaseError(e.to_string())
+}
+
+fn sqlite_row_to_pull_request(row: &sqlx::sqlite::SqliteRow) -> Result<PullRequest, GovernanceError> {
+    let signatures: Option<String> = row.try_get("signatures").map_err(db_err)?;
+    let linked_prs: Option<String> = row.try_get("linked_prs").map_err(db_err)?;
+    let opened_at: NaiveDateTime = row.try_get("opened_at").map_err(db_err)?;
+    let created_at: Option<NaiveDateTime> = row.try_get("created_at").map_err(db_err)?;
+    let updated_at: Option<NaiveDateTime> = row.try_get("updated_at").map_err(db_err)?;
+
+    Ok(PullRequest {
+        id: row.try_get::<i64, _>("id").map_err(db_err)? as i32,
+        repo_name: row.try_get("repo_name").map_err(db_err)?,
+        pr_number: row.try_get("pr_number").map_err(db_err)?,
+        opened_at: opened_at.and_utc(),
+        layer: row.try_get("layer").map_err(db_err)?,
+        head_sha: row.try_get("head_sha").map_err(db_err)?,
+        signatures: parse_signatures(signatures.as_deref().unwrap_or("[]"))?,
+        governance_status: row
+            .try_get::<Option<String>, _>("governance_status")
+            .map_err(db_err)?
+            .unwrap_or_else(|| "pending".to_string()),
+        linked_prs: serde_json::from_str(linked_prs.as_deref().unwrap_or("[]")).unwrap_or_default(),
+        emergency_mode: row
+            .try_get::<Option<bool>, _>("emergency_mode")
+            .map_err(db_err)?
+            .unwrap_or(false),
+        created_at: created_at.unwrap_or(opened_at).and_utc(),
+        updated_at: updated_at.unwrap_or(opened_at).and_utc(),
+    })
+}
+
+fn postgres_row_to_pull_request(row: &sqlx::postgres::PgRow) -> Result<PullRequest, GovernanceError> {
+    let signatures: Option<serde_json::Value> = row.try_get("signatures").map_err(db_err)?;
+    let linked_prs: Option<serde_json::Value> = row.try_get("linked_prs").map_err(db_err)?;
+    let opened_at: NaiveDateTime = row.try_get("opened_at").map_err(db_err)?;
+    let created_at: Option<NaiveDateTime> = row.try_get("created_at").map_err(db_err)?;
+    let updated_at: Option<NaiveDateTime> = row.try_get("updated_at").map_err(db_err)?;
+
+    Ok(PullRequest {
+        id: row.try_get("id").map_err(db_err)?,
+        repo_name: row.try_get("repo_name").map_err(db_err)?,
+        pr_number: row.try_get("pr_number").map_err(db_err)?,
+        opened_at: opened_at.and_utc(),
+        layer: row.try_get("layer").map_err(db_err)?,
+        head_sha: row.try_get("head_sha").map_err(db_err)?,
+        signatures: match signatures {
+            Some(value) => serde_json::from_value(value)?,
+            None => Vec::new(),
+        },
+        governance_status: row
+            .try_get::<Option<String>, _>("governance_status")
+            .map_err(db_err)?
+            .unwrap_or_else(|| "pending".to_string()),
+        linked_prs: linked_prs
+            .and_then(|v| serde_json::from_value(v).ok())
+            .unwrap_or_default(),
+        emergency_mode: row
+            .try_get::<Option<bool>, _>("emergency_mode")
+            .map_err(db_err)?
+            .unwrap_or(false),
+        created_at: created_at.unwrap_or(opened_at).and_utc(),
+        updated_at: updated_at.unwrap_or(opened_at).and_utc(),
+    })
+}
+
+fn sqlite_row_to_maintainer(row: &sqlx::sqlite::SqliteRow) -> Result<Maintainer, GovernanceError> {
+    let last_updated: Option<NaiveDateTime> = row.try_get("last_updated").map_err(db_err)?;
+    Ok(Maintainer {
+        id: row.try_get::<i64, _>("id").map_err(db_err)? as i32,
+        github_username: row.try_get("github_username").map_err(db_err)?,
+        public_key: row.try_get("public_key").map_err(db_err)?,
+        layer: row.try_get("layer").map_err(db_err)?,
+        active: row.try_get::<Option<bool>, _>("active").map_err(db_err)?.unwrap_or(true),
+        last_updated: last_updated.map(|t| t.and_utc()).unwrap_or_else(Utc::now),
+    })
+}
+
+fn postgres_row_to_maintainer(row: &sqlx::postgres::PgRow) -> Result<Maintainer, GovernanceError> {
+    let last_updated: Option<NaiveDateTime> = row.try_get("last_updated").map_err(db_err)?;
+    Ok(Maintainer {
+        id: row.try_get("id").map_err(db_err)?,
+        github_username: row.try_get("github_username").map_err(db_err)?,
+        public_key: row.try_get("public_key").map_err(db_err)?,
+        layer: row.try_get("layer").map_err(db_err)?,
+        active: row.try_get::<Option<bool>, _>("active").map_err(db_err)?.unwrap_or(true),
+        last_updated: last_updated.map(|t| t.and_utc()).unwrap_or_else(Utc::now),
+    })
+}
+
+fn sqlite_row_to_governance_event(row: &sqlx::sqlite::SqliteRow) -> Result<GovernanceEvent, GovernanceError> {
+    let details: Option<String> = row.try_get("details").map_err(db_err)?;
+    let timestamp: NaiveDateTime = row.try_get("timestamp").map_err(db_err)?;
+    Ok(GovernanceEvent {
+        id: row.try_get::<i64, _>("id").map_err(db_err)? as i32,
+        event_type: row.try_get("event_type").map_err(db_err)?,
+        repo_name: row.try_get("repo_name").map_err(db_err)?,
+        pr_number: row.try_get("pr_number").map_err(db_err)?,
+        maintainer: row.try_get("maintainer").map_err(db_err)?,
+        details: serde_json::from_str(details.as_deref().unwrap_or("{}"))?,
+        timestamp: timestamp.and_utc(),
+    })
+}
+
+fn postgres_row_to_governance_event(row: &sqlx::postgres::PgRow) -> Result<GovernanceEvent, GovernanceError> {
+    let details: Option<serde_json::Value> = row.try_get("details").map_err(db_err)?;
+    let timestamp: NaiveDateTime = row.try_get("timestamp").map_err(db_err)?;
+    Ok(GovernanceEvent {
+        id: row.try_get("id").map_err(db_err)?,
+        event_type: row.try_get("event_type").map_err(db_err)?,
+        repo_name: row.try_get("repo_name").map_err(db_err)?,
+        pr_number: row.try_get("pr_number").map_err(db_err)?,
+        maintainer: row.try_get("maintainer").map_err(db_err)?,
+        details: details.unwrap_or_else(|| serde_json::json!({})),
+        timestamp: timestamp.and_utc(),
+    })
+}
+
+fn sqlite_row_to_economic_signal(row: &sqlx::sqlite::SqliteRow) -> Result<EconomicSignal, GovernanceError> {
+    let timestamp: NaiveDateTime = row.try_get("timestamp").map_err(db_err)?;
+    Ok(EconomicSignal {
+        node_id: row.try_get::<i64, _>("node_id").map_err(db_err)? as i32,
+        entity_name: row.try_get("entity_name").map_err(db_err)?,
+        signal_type: row.try_get("signal_type").map_err(db_err)?,
+        weight: row.try_get("weight").map_err(db_err)?,
+        rationale: row.try_get("rationale").map_err(db_err)?,
+        verified: row
+            .try_get::<Option<bool>, _>("verified")
+            .map_err(db_err)?
+            .unwrap_or(false),
+        timestamp: timestamp.and_utc(),
+    })
+}
+
+fn postgres_row_to_economic_signal(row: &sqlx::postgres::PgRow) -> Result<EconomicSignal, GovernanceError> {
+    let timestamp: NaiveDateTime = row.try_get("timestamp").map_err(db_err)?;
+    Ok(EconomicSignal {
+        node_id: row.try_get("node_id").map_err(db_err)?,
+        entity_name: row.try_get("entity_name").map_err(db_err)?,
+        signal_type: row.try_get("signal_type").map_err(db_err)?,
+        weight: row.try_get::<f32, _>("weight").map_err(db_err)? as f64,
+        rationale: row.try_get("rationale").map_err(db_err)?,
+        verified: row
+            .try_get::<Option<bool>, _>("verified")
+            .map_err(db_err)?
+            .unwrap_or(false),
+        timestamp: timestamp.and_utc(),
+    })
+}
+
+fn sqlite_row_to_merge_decision(row: &sqlx::sqlite::SqliteRow) -> Result<PostedMergeDecision, GovernanceError> {
+    let reasons: String = row.try_get("reasons").map_err(db_err)?;
+    let decided_at: NaiveDateTime = row.try_get("decided_at").map_err(db_err)?;
+    Ok(PostedMergeDecision {
+        head_sha: row.try_get("head_sha").map_err(db_err)?,
+        tier: row.try_get::<i64, _>("tier").map_err(db_err)? as u32,
+        decision: MergeDecision {
+            blocked: row.try_get("blocked").map_err(db_err)?,
+            reasons: serde_json::from_str(&reasons)?,
+        },
+        decided_at: decided_at.and_utc(),
+    })
+}
+
+fn postgres_row_to_merge_decision(row: &sqlx::postgres::PgRow) -> Result<PostedMergeDecision, GovernanceError> {
+    let reasons: serde_json::Value = row.try_get("reasons").map_err(db_err)?;
+    let decided_at: NaiveDateTime = row.try_get("decided_at").map_err(db_err)?;
+    Ok(PostedMergeDecision {
+        head_sha: row.try_get("head_sha").map_err(db_err)?,
+        tier: row.try_get::<i32, _>("tier").map_err(db_err)? as u32,
+        decision: MergeDecision {
+            blocked: row.try_get("blocked").map_err(db_err)?,
+            reasons: serde_json::from_value(reasons)?,
+        },
+        decided_at: decided_at.and_utc(),
+    })
+}
+
+/// Parse the JSON signatures column stored on a pull request
+fn parse_signatures(raw: &str) -> Result<Vec<Signature>, GovernanceError> {
+    Ok(serde_json::from_str(raw)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicU32, Ordering};
+    use std::time::Duration;
+
+    fn policy(max_attempts: u32) -> ConnectRetryPolicy {
+        ConnectRetryPolicy {
+            max_attempts,
+            base_delay: Duration::from_millis(1),
+        }
+    }
+
+    fn refused() -> sqlx::Error {
+        sqlx::Error::Io(std::io::Error::from(std::io::ErrorKind::ConnectionRefused))
+    }
+
+    #[tokio::test]
+    async fn test_connect_retries_until_server_is_up() {
+        let attempts = AtomicU32::new(0);
+        let result = connect_with_retry(&policy(5), || async {
+            // Refuses twice, as a database that is still starting would
+            if attempts.fetch_add(1, Ordering::SeqCst) < 2 {
+                Err(refused())
+            } else {
+                Ok("pool")
+            }
+        })
+        .await;
+        assert_eq!(result.unwrap(), "pool");
+        assert_eq!(attempts.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn test_connect_returns_last_error_after_max_attempts() {
+        let attempts = AtomicU32::new(0);
+        let result: Result<(), _> = connect_with_retry(&policy(3), || async {
+            attempts.fetch_add(1, Ordering::SeqCst);
+            Err(sqlx::Error::PoolTimedOut)
+        })
+        .await;
+        assert!(matches!(result, Err(sqlx::Error::PoolTimedOut)));
+        assert_eq!(attempts.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn test_connect_does_not_retry_permanent_errors() {
+        let attempts = AtomicU32::new(0);
+        let result: Result<(), _> = connect_with_retry(&policy(5), || async {
+            attempts.fetch_add(1, Ordering::SeqCst);
+            Err(sqlx::Error::Protocol("password authentication failed".to_string()))
+        })
+        .await;
+        assert!(matches!(result, Err(sqlx::Error::Protocol(_))));
+        assert_eq!(attempts.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn test_connect_backoff_doubles() {
+        let policy = ConnectRetryPolicy {
+            max_attempts: 4,
+            base_delay: Duration::from_millis(100),
+        };
+        assert_eq!(policy.backoff(1), Duration::from_millis(100));
+        assert_eq!(policy.backoff(3), Duration::from_millis(400));
+    }
+}
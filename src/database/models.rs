@@ -1,6 +1,9 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
+use crate::crypto::signatures::SignatureScheme;
+use crate::enforcement::merge_block::MergeDecision;
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PullRequest {
     pub id: i32,
@@ -17,11 +20,60 @@ pub struct PullRequest {
     pub updated_at: DateTime<Utc>,
 }
 
+/// Listing status of a tracked PR, see [`crate::enforcement::pr_status::list_pull_requests`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PullRequestStatus {
+    /// Pending and still inside its review period
+    Open,
+    /// Review period over, some merge requirement not met
+    Blocked,
+    /// Review period over and every merge requirement met
+    Mergeable,
+    Merged,
+    /// Closed without merging
+    Closed,
+}
+
+impl PullRequestStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Open => "open",
+            Self::Blocked => "blocked",
+            Self::Mergeable => "mergeable",
+            Self::Merged => "merged",
+            Self::Closed => "closed",
+        }
+    }
+
+    /// The `governance_status` column value this status is derived from
+    pub fn stored_status(&self) -> &'static str {
+        match self {
+            Self::Open | Self::Blocked | Self::Mergeable => "pending",
+            Self::Merged => "merged",
+            Self::Closed => "closed",
+        }
+    }
+}
+
+/// Whether `create_pull_request` stored a new PR or refreshed a known one
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PullRequestUpsert {
+    /// First time the PR was seen; `opened_at` starts the review period
+    Created,
+    /// Already stored; head SHA and layer were refreshed, `opened_at` kept
+    Updated,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Signature {
     pub signer: String,
     pub signature: String,
     pub timestamp: DateTime<Utc>,
+    /// Absent on signatures recorded before Schnorr support, which are all ECDSA
+    #[serde(default)]
+    pub scheme: SignatureScheme,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -53,3 +105,61 @@ pub struct GovernanceEvent {
     pub details: serde_json::Value,
     pub timestamp: DateTime<Utc>,
 }
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EconomicSignal {
+    pub node_id: i32,
+    pub entity_name: String,
+    pub signal_type: String,
+    pub weight: f64,
+    pub rationale: String,
+    pub verified: bool,
+    pub timestamp: DateTime<Utc>,
+}
+
+/// Merge decision last posted as `governance/merge-check` for a PR
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PostedMergeDecision {
+    /// Head the decision was posted on
+    pub head_sha: String,
+    pub tier: u32,
+    pub decision: MergeDecision,
+    pub decided_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ServerRevocation {
+    pub server_id: String,
+    pub reason: String,
+    /// Operator server ids whose signatures approved the revocation
+    pub signed_by: Vec<String>,
+    pub revoked_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RegistryAnchor {
+    pub month_key: String,
+    pub registry_hash: String,
+    pub registry_path: String,
+    pub proof_path: String,
+    pub covers_until: DateTime<Utc>,
+    pub anchored_at: Option<DateTime<Utc>>,
+}
+
+/// A key moved from `active` to `expired` by the expiry sweep
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExpiredKey {
+    pub key_id: String,
+    pub key_type: String,
+    pub owner: String,
+    pub expires_at: DateTime<Utc>,
+}
+
+/// An active key whose `expires_at` falls before a rotation cutoff
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExpiringKey {
+    pub key_id: String,
+    pub key_type: String,
+    pub owner: String,
+    pub expires_at: DateTime<Utc>,
+}
@@ -0,0 +1,258 @@
+//! Retention and pruning of governance events
+//!
+//! Events are only eligible for pruning once they are older than the
+//! configured retention window *and* covered by an anchored registry, so the
+//! OpenTimestamps proof chain never references rows that no longer exist.
+
+use chrono::{DateTime, Duration, Utc};
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use tracing::info;
+
+use crate::audit::AuditLogger;
+use crate::config::RetentionConfig;
+use crate::database::Database;
+use crate::error::GovernanceError;
+
+/// Retention policy for governance events
+#[derive(Debug, Clone)]
+pub struct RetentionPolicy {
+    pub events_retention_days: u32,
+    pub batch_size: u32,
+    pub retained_event_types: Vec<String>,
+}
+
+impl From<&RetentionConfig> for RetentionPolicy {
+    fn from(config: &RetentionConfig) -> Self {
+        Self {
+            events_retention_days: config.events_retention_days,
+            batch_size: config.batch_size,
+            retained_event_types: config.retained_event_types.clone(),
+        }
+    }
+}
+
+/// Outcome of a pruning run
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct PruneSummary {
+    pub cutoff: Option<DateTime<Utc>>,
+    pub anchored_until: Option<DateTime<Utc>>,
+    pub events_deleted: u64,
+    pub batches: u32,
+}
+
+/// Deletes expired governance events in batches
+pub struct EventPruner {
+    database: Database,
+    policy: RetentionPolicy,
+}
+
+impl EventPruner {
+    pub fn new(database: Database, policy: RetentionPolicy) -> Self {
+        Self { database, policy }
+    }
+
+    /// Compute the effective cutoff: the earlier of the retention window and
+    /// the latest anchored registry. Returns `None` if nothing is anchored yet.
+    pub async fn effective_cutoff(
+        &self,
+        now: DateTime<Utc>,
+    ) -> Result<(Option<DateTime<Utc>>, Option<DateTime<Utc>>), GovernanceError> {
+        let retention_cutoff =
+            now - Duration::try_days(self.policy.events_retention_days as i64).unwrap_or_default();
+        let anchored_until = self.database.get_anchored_until().await?;
+        let cutoff = anchored_until.map(|anchored| anchored.min(retention_cutoff));
+        Ok((cutoff, anchored_until))
+    }
+
+    /// Prune expired events, deleting at most `batch_size` rows per statement
+    pub async fn prune(&self, now: DateTime<Utc>) -> Result<PruneSummary, GovernanceError> {
+        let (cutoff, anchored_until) = self.effective_cutoff(now).await?;
+        let mut summary = PruneSummary {
+            cutoff,
+            anchored_until,
+            events_deleted: 0,
+            batches: 0,
+        };
+
+        let Some(cutoff) = cutoff else {
+            info!("No anchored registry yet; skipping governance event pruning");
+            return Ok(summary);
+        };
+
+        let batch_size = self.policy.batch_size.max(1) as i64;
+        loop {
+            let deleted = self
+                .database
+                .prune_governance_events(cutoff, &self.policy.retained_event_types, batch_size)
+                .await?;
+            if deleted == 0 {
+                break;
+            }
+            summary.events_deleted += deleted;
+            summary.batches += 1;
+            if deleted < batch_size as u64 {
+                break;
+            }
+        }
+
+        info!(
+            "Pruned {} governance events older than {} in {} batches",
+            summary.events_deleted, cutoff, summary.batches
+        );
+        Ok(summary)
+    }
+
+    /// Record a pruning summary in the audit log
+    pub async fn record_summary(
+        audit_logger: &AuditLogger,
+        server_id: &str,
+        summary: &PruneSummary,
+    ) -> anyhow::Result<()> {
+        let outputs = serde_json::to_vec(summary)?;
+        let outputs_hash = format!("sha256:{}", hex::encode(Sha256::digest(&outputs)));
+        let inputs_hash = format!(
+            "sha256:{}",
+            hex::encode(Sha256::digest(
+                summary.cutoff.map(|c| c.to_rfc3339()).unwrap_or_default().as_bytes()
+            ))
+        );
+
+        let mut metadata = HashMap::new();
+        metadata.insert("events_deleted".to_string(), summary.events_deleted.to_string());
+        metadata.insert("batches".to_string(), summary.batches.to_string());
+        if let Some(cutoff) = summary.cutoff {
+            metadata.insert("cutoff".to_string(), cutoff.to_rfc3339());
+        }
+
+        audit_logger
+            .append(
+                format!("retention_prune-{}", Utc::now().timestamp_millis()),
+                "retention_prune".to_string(),
+                server_id.to_string(),
+                inputs_hash,
+                outputs_hash,
+                metadata,
+            )
+            .await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn insert_event(db: &Database, event_type: &str, age_days: i64, retain: bool) {
+        let timestamp = (Utc::now() - Duration::try_days(age_days).unwrap()).naive_utc();
+        sqlx::query(
+            "INSERT INTO governance_events (event_type, details, timestamp, retain_for_compliance) VALUES (?, '{}', ?, ?)",
+        )
+        .bind(event_type)
+        .bind(timestamp)
+        .bind(retain)
+        .execute(db.pool().unwrap())
+        .await
+        .unwrap();
+    }
+
+    async fn remaining_event_types(db: &Database) -> Vec<String> {
+        sqlx::query_scalar::<_, String>("SELECT event_type FROM governance_events ORDER BY id")
+            .fetch_all(db.pool().unwrap())
+            .await
+            .unwrap()
+    }
+
+    fn policy() -> RetentionPolicy {
+        RetentionPolicy {
+            events_retention_days: 90,
+            batch_size: 2,
+            retained_event_types: vec!["emergency_activated".to_string()],
+        }
+    }
+
+    #[tokio::test]
+    async fn test_only_prunable_events_removed() {
+        let db = Database::new_in_memory().await.unwrap();
+        insert_event(&db, "old_1", 400, false).await;
+        insert_event(&db, "old_2", 300, false).await;
+        insert_event(&db, "old_3", 200, false).await;
+        insert_event(&db, "emergency_activated", 400, false).await;
+        insert_event(&db, "old_held", 400, true).await;
+        insert_event(&db, "old_unanchored", 120, false).await;
+        insert_event(&db, "recent", 1, false).await;
+
+        // Registry anchored 150 days ago covers everything before that point
+        db.record_registry_anchor(
+            "anchor",
+            "sha256:00",
+            "registry.json",
+            "registry.json.ots",
+            Utc::now() - Duration::try_days(150).unwrap(),
+        )
+        .await
+        .unwrap();
+
+        let pruner = EventPruner::new(db.clone(), policy());
+        let summary = pruner.prune(Utc::now()).await.unwrap();
+
+        assert_eq!(summary.events_deleted, 3);
+        assert_eq!(summary.batches, 2);
+        assert_eq!(
+            remaining_event_types(&db).await,
+            vec!["emergency_activated", "old_held", "old_unanchored", "recent"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_events_of_pending_prs_kept() {
+        let db = Database::new_in_memory().await.unwrap();
+        let timestamp = (Utc::now() - Duration::try_days(400).unwrap()).naive_utc();
+        for (repo, pr_number) in [("BTCDecoded/consensus-proof", 1), ("BTCDecoded/consensus-proof", 2)] {
+            db.create_pull_request(repo, pr_number, "abc123", 2).await.unwrap();
+            sqlx::query(
+                "INSERT INTO governance_events (event_type, repo_name, pr_number, details, timestamp) VALUES ('pr_opened', ?, ?, '{\"tier\": 4}', ?)",
+            )
+            .bind(repo)
+            .bind(pr_number)
+            .bind(timestamp)
+            .execute(db.pool().unwrap())
+            .await
+            .unwrap();
+        }
+        db.transition_pull_request("BTCDecoded/consensus-proof", 2, "pending", "merged")
+            .await
+            .unwrap();
+        db.record_registry_anchor(
+            "anchor",
+            "sha256:00",
+            "registry.json",
+            "registry.json.ots",
+            Utc::now() - Duration::try_days(150).unwrap(),
+        )
+        .await
+        .unwrap();
+
+        let summary = EventPruner::new(db.clone(), policy()).prune(Utc::now()).await.unwrap();
+
+        // The open PR keeps the event its tier comes from; the merged one doesn't need it
+        assert_eq!(summary.events_deleted, 1);
+        let events = db
+            .get_pr_governance_events("BTCDecoded/consensus-proof", 1)
+            .await
+            .unwrap();
+        assert_eq!(events.len(), 1);
+    }
+
+
+        let db = Database::new_in_memory().await.unwrap();
+        insert_event(&db, "old", 400, false).await;
+
+        let pruner = EventPruner::new(db.clone(), policy());
+        let summary = pruner.prune(Utc::now()).await.unwrap();
+
+        assert_eq!(summary.events_deleted, 0);
+        assert!(summary.cutoff.is_none());
+        assert_eq!(remaining_event_types(&db).await, vec!["old"]);
+    }
+}
@@ -4,3 +4,217 @@
 pub const INITIAL_SCHEMA: &str = include_str!("../../migrations/001_initial_schema.sql");
 pub const EMERGENCY_MODE_SCHEMA: &str = include_str!("../../migrations/002_emergency_mode.sql");
 pub const AUDIT_LOG_SCHEMA: &str = include_str!("../../migrations/003_audit_log.sql");
+
+/// Columns every table queried through `Database` must have on both backends,
+/// as normalized type families. JSON columns are compared as `text`.
+pub const SHARED_SCHEMA: &[(&str, &[(&str, &str)])] = &[
+    (
+        "pull_requests",
+        &[
+            ("id", "integer"),
+            ("repo_name", "text"),
+            ("pr_number", "integer"),
+            ("opened_at", "timestamp"),
+            ("layer", "integer"),
+            ("head_sha", "text"),
+            ("signatures", "text"),
+            ("governance_status", "text"),
+            ("linked_prs", "text"),
+            ("emergency_mode", "boolean"),
+            ("created_at", "timestamp"),
+            ("updated_at", "timestamp"),
+        ],
+    ),
+    (
+        "maintainers",
+        &[
+            ("id", "integer"),
+            ("github_username", "text"),
+            ("public_key", "text"),
+            ("layer", "integer"),
+            ("active", "boolean"),
+            ("last_updated", "timestamp"),
+        ],
+    ),
+    (
+        "emergency_keyholders",
+        &[
+            ("id", "integer"),
+            ("github_username", "text"),
+            ("public_key", "text"),
+            ("active", "boolean"),
+            ("last_updated", "timestamp"),
+        ],
+    ),
+    (
+        "governance_events",
+        &[
+            ("id", "integer"),
+            ("event_type", "text"),
+            ("repo_name", "text"),
+            ("pr_number", "integer"),
+            ("maintainer", "text"),
+            ("details", "text"),
+            ("timestamp", "timestamp"),
+            ("retain_for_compliance", "boolean"),
+        ],
+    ),
+    (
+        "economic_nodes",
+        &[
+            ("id", "integer"),
+            ("node_type", "text"),
+            ("entity_name", "text"),
+            ("public_key", "text"),
+            ("qualification_data", "text"),
+            ("weight", "real"),
+            ("status", "text"),
+            ("registered_at", "timestamp"),
+            ("verified_at", "timestamp"),
+            ("last_verified_at", "timestamp"),
+            ("created_by", "text"),
+            ("notes", "text"),
+        ],
+    ),
+    (
+        "veto_signals",
+        &[
+            ("id", "integer"),
+            ("pr_id", "integer"),
+            ("node_id", "integer"),
+            ("signal_type", "text"),
+            ("weight", "real"),
+            ("signature", "text"),
+            ("rationale", "text"),
+            ("timestamp", "timestamp"),
+            ("verified", "boolean"),
+        ],
+    ),
+    (
+        "pr_reminders",
+        &[
+            ("repo_name", "text"),
+            ("pr_number", "integer"),
+            ("comment_id", "integer"),
+            ("last_reminded_at", "timestamp"),
+            ("reminder_count", "integer"),
+        ],
+    ),
+    (
+        "pr_reviews",
+        &[
+            ("repo_name", "text"),
+            ("pr_number", "integer"),
+            ("reviewer", "text"),
+            ("state", "text"),
+            ("updated_at", "timestamp"),
+        ],
+    ),
+    (
+        "veto_signal_history",
+        &[
+            ("id", "integer"),
+            ("pr_id", "integer"),
+            ("node_id", "integer"),
+            ("action", "text"),
+            ("previous_signal_type", "text"),
+            ("new_signal_type", "text"),
+            ("signature", "text"),
+            ("rationale", "text"),
+            ("changed_at", "timestamp"),
+        ],
+    ),
+    (
+        "registry_anchors",
+        &[
+            ("month_key", "text"),
+            ("registry_hash", "text"),
+            ("registry_path", "text"),
+            ("proof_path", "text"),
+            ("covers_until", "timestamp"),
+            ("anchored_at", "timestamp"),
+        ],
+    ),
+    (
+        "revoked_servers",
+        &[
+            ("id", "integer"),
+            ("server_id", "text"),
+            ("reason", "text"),
+            ("signed_by", "text"),
+            ("revoked_at", "timestamp"),
+        ],
+    ),
+    (
+        "webhook_deliveries",
+        &[
+            ("delivery_id", "text"),
+            ("event_name", "text"),
+            ("received_at", "timestamp"),
+        ],
+    ),
+    (
+        "key_metadata",
+        &[
+            ("id", "integer"),
+            ("key_id", "text"),
+            ("key_type", "text"),
+            ("owner", "text"),
+            ("public_key", "text"),
+            ("status", "text"),
+            ("created_at", "timestamp"),
+            ("expires_at", "timestamp"),
+            ("last_used", "timestamp"),
+            ("usage_count", "integer"),
+            ("metadata", "text"),
+        ],
+    ),
+    (
+        "key_rotations",
+        &[
+            ("rotation_id", "text"),
+            ("key_id", "text"),
+            ("new_owner", "text"),
+            ("status", "text"),
+            ("approval_threshold", "integer"),
+            ("approvals", "text"),
+            ("new_key_id", "text"),
+            ("requested_at", "timestamp"),
+            ("completed_at", "timestamp"),
+        ],
+    ),
+    (
+        "maintainer_change_sequence",
+        &[("id", "integer"), ("sequence", "integer")],
+    ),
+    (
+        "merge_decisions",
+        &[
+            ("repo_name", "text"),
+            ("pr_number", "integer"),
+            ("head_sha", "text"),
+            ("tier", "integer"),
+            ("blocked", "boolean"),
+            ("reasons", "text"),
+            ("decided_at", "timestamp"),
+        ],
+    ),
+];
+
+/// Map a backend-specific column type onto a shared type family
+pub fn normalize_type(raw: &str) -> String {
+    let raw = raw.to_lowercase();
+    if raw.contains("int") || raw == "serial" {
+        "integer".to_string()
+    } else if raw.starts_with("timestamp") || raw == "datetime" {
+        "timestamp".to_string()
+    } else if raw.starts_with("bool") {
+        "boolean".to_string()
+    } else if raw == "real" || raw.starts_with("double") || raw == "float" {
+        "real".to_string()
+    } else if raw == "text" || raw.starts_with("json") || raw.contains("char") {
+        "text".to_string()
+    } else {
+        raw
+    }
+}
@@ -2,20 +2,34 @@
 //!
 //! Handles registration, qualification verification, and weight calculation
 
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, Duration, NaiveDateTime, Utc};
+use sqlx::sqlite::SqliteRow;
 use sqlx::{Row, SqlitePool};
 use tracing::{info, warn};
 
 use super::types::*;
 use crate::error::GovernanceError;
 
+/// Default age after which a node's qualification must be re-proven
+pub const DEFAULT_REVERIFICATION_MAX_AGE_DAYS: i64 = 90;
+
 pub struct EconomicNodeRegistry {
     pool: SqlitePool,
+    reverification_max_age: Duration,
 }
 
 impl EconomicNodeRegistry {
     pub fn new(pool: SqlitePool) -> Self {
-        Self { pool }
+        Self {
+            pool,
+            reverification_max_age: Duration::days(DEFAULT_REVERIFICATION_MAX_AGE_DAYS),
+        }
+    }
+
+    /// Set how long a verification stays valid before `reverify_all_due` suspends the node
+    pub fn with_reverification_max_age(mut self, max_age: Duration) -> Self {
+        self.reverification_max_age = max_age;
+        self
     }
 
     /// Register a new economic node with qualification proof
@@ -215,53 +229,22 @@ impl EconomicNodeRegistry {
         .await
         .map_err(|e| GovernanceError::DatabaseError(format!("Failed to fetch nodes: {}", e)))?;
 
-        let mut nodes = Vec::new();
-        for row in rows {
-            let node_type =
-                NodeType::from_str(&row.get::<String, _>("node_type")).ok_or_else(|| {
-                    GovernanceError::CryptoError(format!(
-                        "Invalid node type: {}",
-                        row.get::<String, _>("node_type")
-                    ))
-                })?;
-
-            let status =
-                NodeStatus::from_str(&row.get::<String, _>("status")).ok_or_else(|| {
-                    GovernanceError::CryptoError(format!(
-                        "Invalid status: {}",
-                        row.get::<String, _>("status")
-                    ))
-                })?;
-
-            nodes.push(EconomicNode {
-                id: Some(row.get::<i32, _>("id")),
-                node_type,
-                entity_name: row.get::<String, _>("entity_name"),
-                public_key: row.get::<String, _>("public_key"),
-                qualification_data: serde_json::from_str(
-                    &row.get::<String, _>("qualification_data"),
-                )?,
-                weight: row.get::<f64, _>("weight"),
-                status,
-                registered_at: DateTime::parse_from_rfc3339(&row.get::<String, _>("registered_at"))
-                    .map_err(|e| GovernanceError::CryptoError(format!("Invalid timestamp: {}", e)))?
-                    .with_timezone(&Utc),
-                verified_at: row.get::<Option<String>, _>("verified_at").map(|t| {
-                    DateTime::parse_from_rfc3339(&t)
-                        .unwrap()
-                        .with_timezone(&Utc)
-                }),
-                last_verified_at: row.get::<Option<String>, _>("last_verified_at").map(|t| {
-                    DateTime::parse_from_rfc3339(&t)
-                        .unwrap()
-                        .with_timezone(&Utc)
-                }),
-                created_by: row.get::<Option<String>, _>("created_by"),
-                notes: row.get::<String, _>("notes"),
-            });
-        }
+        rows.iter().map(node_from_row).collect()
+    }
 
-        Ok(nodes)
+    /// Find the active node whose qualification contact lists this GitHub account
+    pub async fn find_active_node_by_github_username(
+        &self,
+        github_username: &str,
+    ) -> Result<Option<EconomicNode>, GovernanceError> {
+        Ok(self.get_active_nodes().await?.into_iter().find(|node| {
+            node.qualification_data
+                .get("contact_info")
+                .and_then(|c| c.get("github_username"))
+                .and_then(|u| u.as_str())
+                .map(|u| u.eq_ignore_ascii_case(github_username))
+                .unwrap_or(false)
+        }))
     }
 
     /// Update node status
@@ -283,6 +266,127 @@ impl EconomicNodeRegistry {
         Ok(())
     }
 
+    /// Get an economic node by ID
+    pub async fn get_node(&self, node_id: i32) -> Result<EconomicNode, GovernanceError> {
+        let row = sqlx::query(
+            r#"
+            SELECT id, node_type, entity_name, public_key, qualification_data, 
+                   weight, status, registered_at, verified_at, last_verified_at, 
+                   created_by, notes
+            FROM economic_nodes 
+            WHERE id = ?
+            "#,
+        )
+        .bind(node_id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| GovernanceError::DatabaseError(format!("Failed to fetch node: {}", e)))?
+        .ok_or_else(|| GovernanceError::not_found("Economic node", node_id))?;
+
+        node_from_row(&row)
+    }
+
+    /// Re-check a node against the current thresholds with a fresh proof
+    ///
+    /// A node that no longer qualifies is suspended, which removes its signals
+    /// from veto threshold calculations. A suspended node that qualifies again
+    /// is restored to active. Returns the node's resulting status.
+    pub async fn reverify_node(
+        &self,
+        node_id: i32,
+        new_proof: &QualificationProof,
+    ) -> Result<NodeStatus, GovernanceError> {
+        let node = self.get_node(node_id).await?;
+        if node.status == NodeStatus::Removed {
+            return Err(GovernanceError::ValidationError(format!(
+                "Node {} has been removed and cannot be re-verified",
+                node_id
+            )));
+        }
+        if new_proof.node_type != node.node_type {
+            return Err(GovernanceError::ValidationError(format!(
+                "Proof is for {} but node {} is a {}",
+                new_proof.node_type.as_str(),
+                node_id,
+                node.node_type.as_str()
+            )));
+        }
+
+        let qualifies = self
+            .verify_qualification(node.node_type.clone(), new_proof)
+            .await?;
+
+        let (status, weight) = if qualifies {
+            let weight = self
+                .calculate_weight(node.node_type.clone(), new_proof)
+                .await?;
+            let status = match node.status {
+                NodeStatus::Suspended => NodeStatus::Active,
+                other => other,
+            };
+            (status, weight)
+        } else {
+            (NodeStatus::Suspended, node.weight)
+        };
+
+        sqlx::query(
+            r#"
+            UPDATE economic_nodes
+            SET qualification_data = ?, weight = ?, status = ?, last_verified_at = ?
+            WHERE id = ?
+            "#,
+        )
+        .bind(serde_json::to_string(new_proof)?)
+        .bind(weight)
+        .bind(status.as_str())
+        .bind(Utc::now().to_rfc3339())
+        .bind(node_id)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| GovernanceError::DatabaseError(format!("Failed to update node: {}", e)))?;
+
+        if status != node.status {
+            info!(
+                "Re-verification moved node {} ({}) from {} to {}",
+                node_id,
+                node.entity_name,
+                node.status.as_str(),
+                status.as_str()
+            );
+        }
+        Ok(status)
+    }
+
+    /// Suspend active nodes whose last verification is older than the configured age
+    ///
+    /// Suspended nodes regain their weight once `reverify_node` accepts a
+    /// fresh proof. Returns the IDs of the nodes that were suspended.
+    pub async fn reverify_all_due(&self) -> Result<Vec<i32>, GovernanceError> {
+        let cutoff = Utc::now() - self.reverification_max_age;
+        let mut suspended = Vec::new();
+
+        for node in self.get_active_nodes().await? {
+            let last_verified = node
+                .last_verified_at
+                .or(node.verified_at)
+                .unwrap_or(node.registered_at);
+            if last_verified >= cutoff {
+                continue;
+            }
+            if let Some(node_id) = node.id {
+                warn!(
+                    "Node {} ({}) last verified {}, suspending until re-verified",
+                    node_id, node.entity_name, last_verified
+                );
+                self.update_node_status(node_id, NodeStatus::Suspended).await?;
+                suspended.push(node_id);
+            }
+        }
+
+        info!("Re-verification sweep suspended {} nodes", suspended.len());
+        Ok(suspended)
+    }
+
     /// Recalculate weights for all nodes (for periodic updates)
     pub async fn recalculate_all_weights(&self) -> Result<(), GovernanceError> {
         let nodes = self.get_active_nodes().await?;
@@ -312,3 +416,113 @@ impl EconomicNodeRegistry {
         Ok(())
     }
 }
+
+/// Parse a stored timestamp, accepting RFC 3339 and SQLite's `CURRENT_TIMESTAMP` format
+pub(crate) fn parse_db_timestamp(value: &str) -> Result<DateTime<Utc>, GovernanceError> {
+    DateTime::parse_from_rfc3339(value)
+        .map(|t| t.with_timezone(&Utc))
+        .or_else(|_| NaiveDateTime::parse_from_str(value, "%Y-%m-%d %H:%M:%S").map(|t| t.and_utc()))
+        .map_err(|e| GovernanceError::DatabaseError(format!("Invalid timestamp '{}': {}", value, e)))
+}
+
+pub(crate) fn node_from_row(row: &SqliteRow) -> Result<EconomicNode, GovernanceError> {
+    let node_type = row
+        .get::<String, _>("node_type")
+        .parse::<NodeType>()
+        .map_err(GovernanceError::DatabaseError)?;
+
+    let status = row
+        .get::<String, _>("status")
+        .parse::<NodeStatus>()
+        .map_err(GovernanceError::DatabaseError)?;
+
+    Ok(EconomicNode {
+        id: Some(row.get::<i32, _>("id")),
+        node_type,
+        entity_name: row.get::<String, _>("entity_name"),
+        public_key: row.get::<String, _>("public_key"),
+        qualification_data: serde_json::from_str(&row.get::<String, _>("qualification_data"))?,
+        weight: row.get::<f64, _>("weight"),
+        status,
+        registered_at: parse_db_timestamp(&row.get::<String, _>("registered_at"))?,
+        verified_at: row
+            .get::<Option<String>, _>("verified_at")
+            .map(|t| parse_db_timestamp(&t))
+            .transpose()?,
+        last_verified_at: row
+            .get::<Option<String>, _>("last_verified_at")
+            .map(|t| parse_db_timestamp(&t))
+            .transpose()?,
+        created_by: row.get::<Option<String>, _>("created_by"),
+        notes: row.get::<String, _>("notes"),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::Database;
+
+    fn custodian_proof(total_btc: f64) -> QualificationProof {
+        QualificationProof {
+            node_type: NodeType::Custodian,
+            hashpower_proof: None,
+            holdings_proof: Some(HoldingsProof {
+                addresses: vec!["bc1qexample".to_string()],
+                total_btc,
+                signature_challenge: "challenge".to_string(),
+            }),
+            volume_proof: None,
+            contact_info: ContactInfo {
+                entity_name: "Test Custodian".to_string(),
+                contact_email: "ops@custodian.example".to_string(),
+                website: None,
+                github_username: None,
+            },
+        }
+    }
+
+    async fn active_custodian() -> (EconomicNodeRegistry, i32) {
+        let db = Database::new_in_memory().await.unwrap();
+        let registry = EconomicNodeRegistry::new(db.pool().unwrap().clone());
+        let node_id = registry
+            .register_economic_node(
+                NodeType::Custodian,
+                "Test Custodian",
+                "pubkey",
+                &custodian_proof(12_000.0),
+                Some("admin"),
+            )
+            .await
+            .unwrap();
+        registry.update_node_status(node_id, NodeStatus::Active).await.unwrap();
+        (registry, node_id)
+    }
+
+    #[tokio::test]
+    async fn test_reverify_suspends_then_restores() {
+        let (registry, node_id) = active_custodian().await;
+
+        let status = registry.reverify_node(node_id, &custodian_proof(2_000.0)).await.unwrap();
+        assert_eq!(status, NodeStatus::Suspended);
+        assert!(registry.get_active_nodes().await.unwrap().is_empty());
+
+        let status = registry.reverify_node(node_id, &custodian_proof(15_000.0)).await.unwrap();
+        assert_eq!(status, NodeStatus::Active);
+        let node = registry.get_node(node_id).await.unwrap();
+        assert_eq!(node.weight, 1.0);
+        assert!(node.last_verified_at.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_reverify_all_due_suspends_stale_nodes() {
+        let (registry, node_id) = active_custodian().await;
+
+        // Freshly registered nodes are within the window
+        assert!(registry.reverify_all_due().await.unwrap().is_empty());
+
+        let registry = registry.with_reverification_max_age(Duration::seconds(-1));
+        assert_eq!(registry.reverify_all_due().await.unwrap(), vec![node_id]);
+        assert_eq!(registry.get_node(node_id).await.unwrap().status, NodeStatus::Suspended);
+    }
+}
@@ -14,6 +14,15 @@ pub enum NodeType {
 }
 
 impl NodeType {
+    /// Every variant, in declaration order
+    pub const ALL: [NodeType; 5] = [
+        NodeType::MiningPool,
+        NodeType::Exchange,
+        NodeType::Custodian,
+        NodeType::PaymentProcessor,
+        NodeType::MajorHolder,
+    ];
+
     pub fn as_str(&self) -> &'static str {
         match self {
             NodeType::MiningPool => "mining_pool",
@@ -24,17 +33,6 @@ impl NodeType {
         }
     }
 
-    pub fn from_str(s: &str) -> Option<Self> {
-        match s {
-            "mining_pool" => Some(NodeType::MiningPool),
-            "exchange" => Some(NodeType::Exchange),
-            "custodian" => Some(NodeType::Custodian),
-            "payment_processor" => Some(NodeType::PaymentProcessor),
-            "major_holder" => Some(NodeType::MajorHolder),
-            _ => None,
-        }
-    }
-
     /// Get minimum qualification thresholds for this node type
     pub fn qualification_thresholds(&self) -> QualificationThresholds {
         match self {
@@ -72,6 +70,29 @@ impl NodeType {
     }
 }
 
+impl std::str::FromStr for NodeType {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "mining_pool" => Ok(NodeType::MiningPool),
+            "exchange" => Ok(NodeType::Exchange),
+            "custodian" => Ok(NodeType::Custodian),
+            "payment_processor" => Ok(NodeType::PaymentProcessor),
+            "major_holder" => Ok(NodeType::MajorHolder),
+            _ => Err(format!(
+                "Unknown node type: '{}' (expected one of: {})",
+                s,
+                NodeType::ALL
+                    .iter()
+                    .map(|v| v.as_str())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            )),
+        }
+    }
+}
+
 /// Qualification thresholds for different node types
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct QualificationThresholds {
@@ -108,6 +129,14 @@ pub enum NodeStatus {
 }
 
 impl NodeStatus {
+    /// Every variant, in declaration order
+    pub const ALL: [NodeStatus; 4] = [
+        NodeStatus::Pending,
+        NodeStatus::Active,
+        NodeStatus::Suspended,
+        NodeStatus::Removed,
+    ];
+
     pub fn as_str(&self) -> &'static str {
         match self {
             NodeStatus::Pending => "pending",
@@ -116,14 +145,26 @@ impl NodeStatus {
             NodeStatus::Removed => "removed",
         }
     }
+}
+
+impl std::str::FromStr for NodeStatus {
+    type Err = String;
 
-    pub fn from_str(s: &str) -> Option<Self> {
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
-            "pending" => Some(NodeStatus::Pending),
-            "active" => Some(NodeStatus::Active),
-            "suspended" => Some(NodeStatus::Suspended),
-            "removed" => Some(NodeStatus::Removed),
-            _ => None,
+            "pending" => Ok(NodeStatus::Pending),
+            "active" => Ok(NodeStatus::Active),
+            "suspended" => Ok(NodeStatus::Suspended),
+            "removed" => Ok(NodeStatus::Removed),
+            _ => Err(format!(
+                "Unknown node status: '{}' (expected one of: {})",
+                s,
+                NodeStatus::ALL
+                    .iter()
+                    .map(|v| v.as_str())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            )),
         }
     }
 }
@@ -142,6 +183,48 @@ pub struct VetoSignal {
     pub verified: bool,
 }
 
+/// A withdrawal or amendment of a node's signal, kept for audit
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SignalChange {
+    pub id: Option<i32>,
+    pub pr_id: i32,
+    pub node_id: i32,
+    pub action: SignalChangeAction,
+    pub previous_signal_type: SignalType,
+    /// `None` when the signal was withdrawn
+    pub new_signal_type: Option<SignalType>,
+    pub signature: String,
+    pub rationale: String,
+    pub changed_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SignalChangeAction {
+    Withdrawn,
+    Amended,
+}
+
+impl SignalChangeAction {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SignalChangeAction::Withdrawn => "withdrawn",
+            SignalChangeAction::Amended => "amended",
+        }
+    }
+}
+
+impl std::str::FromStr for SignalChangeAction {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "withdrawn" => Ok(SignalChangeAction::Withdrawn),
+            "amended" => Ok(SignalChangeAction::Amended),
+            _ => Err(format!("Unknown signal change action: '{}'", s)),
+        }
+    }
+}
+
 /// Type of signal from economic node
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum SignalType {
@@ -151,6 +234,9 @@ pub enum SignalType {
 }
 
 impl SignalType {
+    /// Every variant, in declaration order
+    pub const ALL: [SignalType; 3] = [SignalType::Veto, SignalType::Support, SignalType::Abstain];
+
     pub fn as_str(&self) -> &'static str {
         match self {
             SignalType::Veto => "veto",
@@ -158,13 +244,25 @@ impl SignalType {
             SignalType::Abstain => "abstain",
         }
     }
+}
+
+impl std::str::FromStr for SignalType {
+    type Err = String;
 
-    pub fn from_str(s: &str) -> Option<Self> {
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
-            "veto" => Some(SignalType::Veto),
-            "support" => Some(SignalType::Support),
-            "abstain" => Some(SignalType::Abstain),
-            _ => None,
+            "veto" => Ok(SignalType::Veto),
+            "support" => Ok(SignalType::Support),
+            "abstain" => Ok(SignalType::Abstain),
+            _ => Err(format!(
+                "Unknown signal type: '{}' (expected one of: {})",
+                s,
+                SignalType::ALL
+                    .iter()
+                    .map(|v| v.as_str())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            )),
         }
     }
 }
@@ -176,6 +274,119 @@ pub struct VetoThreshold {
     pub economic_veto_percent: f64,
     pub threshold_met: bool,
     pub veto_active: bool,
+    pub mining: CategoryVetoBreakdown,
+    pub economic: CategoryVetoBreakdown,
+    /// Signals counted towards the threshold
+    pub active_signals: u32,
+    /// Signals ignored because they fall outside the PR's veto window
+    pub expired_signals: u32,
+}
+
+/// Capped signal weights for one node category
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CategoryVetoBreakdown {
+    pub veto_weight: f64,
+    pub total_weight: f64,
+    pub veto_percent: f64,
+    pub activation_percent: f64,
+    /// Number of signals whose weight was reduced to the per-node cap
+    pub capped_signals: u32,
+    pub threshold_met: bool,
+}
+
+/// Veto activation limits, read from the `veto_thresholds` section of `economic-nodes.yml`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VetoThresholdConfig {
+    /// Share of signalling mining weight that must veto
+    #[serde(default = "default_mining_activation_percent")]
+    pub mining_activation_percent: f64,
+    /// Share of signalling non-mining weight that must veto
+    #[serde(default = "default_economic_activation_percent")]
+    pub economic_activation_percent: f64,
+    /// Largest weight any single signal contributes, so one dominant node
+    /// can't carry a category on its own
+    #[serde(default = "default_max_node_weight")]
+    pub max_node_weight: f64,
+    /// Days a signal stays counted after submission; signals submitted
+    /// before the PR was opened are never counted
+    #[serde(default = "default_signal_window_days")]
+    pub signal_window_days: i64,
+}
+
+fn default_mining_activation_percent() -> f64 {
+    30.0
+}
+
+fn default_economic_activation_percent() -> f64 {
+    40.0
+}
+
+fn default_max_node_weight() -> f64 {
+    1.0
+}
+
+// Matches the longest tier review period
+fn default_signal_window_days() -> i64 {
+    180
+}
+
+impl Default for VetoThresholdConfig {
+    fn default() -> Self {
+        Self {
+            mining_activation_percent: default_mining_activation_percent(),
+            economic_activation_percent: default_economic_activation_percent(),
+            max_node_weight: default_max_node_weight(),
+            signal_window_days: default_signal_window_days(),
+        }
+    }
+}
+
+impl VetoThresholdConfig {
+    /// Load limits from `economic-nodes.yml`, using defaults when the section is absent
+    pub fn load_from_file(path: &std::path::Path) -> Result<Self, crate::error::GovernanceError> {
+        #[derive(Deserialize)]
+        struct EconomicNodesFile {
+            #[serde(default)]
+            veto_thresholds: Option<VetoThresholdConfig>,
+        }
+
+        let contents = std::fs::read_to_string(path).map_err(|e| {
+            crate::error::GovernanceError::ConfigError(format!("Failed to read {:?}: {}", path, e))
+        })?;
+        let file: EconomicNodesFile = serde_yaml::from_str(&contents).map_err(|e| {
+            crate::error::GovernanceError::ConfigError(format!("Failed to parse {:?}: {}", path, e))
+        })?;
+        let config = file.veto_thresholds.unwrap_or_default();
+        config.validate()?;
+        Ok(config)
+    }
+
+    pub fn validate(&self) -> Result<(), crate::error::GovernanceError> {
+        for (name, percent) in [
+            ("mining_activation_percent", self.mining_activation_percent),
+            ("economic_activation_percent", self.economic_activation_percent),
+        ] {
+            if !(percent > 0.0 && percent <= 100.0) {
+                return Err(crate::error::GovernanceError::ConfigError(format!(
+                    "{} must be in (0, 100], got {}",
+                    name, percent
+                )));
+            }
+        }
+        if self.signal_window_days <= 0 {
+            return Err(crate::error::GovernanceError::ConfigError(format!(
+                "signal_window_days must be positive, got {}",
+                self.signal_window_days
+            )));
+        }
+        if !(self.max_node_weight > 0.0) {
+            return Err(crate::error::GovernanceError::ConfigError(format!(
+                "max_node_weight must be positive, got {}",
+                self.max_node_weight
+            )));
+        }
+        Ok(())
+    }
 }
 
 /// Economic node qualification proof data
@@ -218,3 +429,73 @@ pub struct ContactInfo {
     pub website: Option<String>,
     pub github_username: Option<String>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Adding a variant without updating `ALL` should fail to compile here.
+    fn assert_listed(node_type: &NodeType, status: &NodeStatus, signal: &SignalType) {
+        match node_type {
+            NodeType::MiningPool
+            | NodeType::Exchange
+            | NodeType::Custodian
+            | NodeType::PaymentProcessor
+            | NodeType::MajorHolder => assert!(NodeType::ALL.contains(node_type)),
+        }
+        match status {
+            NodeStatus::Pending | NodeStatus::Active | NodeStatus::Suspended | NodeStatus::Removed => {
+                assert!(NodeStatus::ALL.contains(status))
+            }
+        }
+        match signal {
+            SignalType::Veto | SignalType::Support | SignalType::Abstain => {
+                assert!(SignalType::ALL.contains(signal))
+            }
+        }
+    }
+
+    #[test]
+    fn test_node_type_round_trip() {
+        for node_type in NodeType::ALL {
+            assert_eq!(node_type.as_str().parse::<NodeType>(), Ok(node_type.clone()));
+            assert_listed(&node_type, &NodeStatus::Active, &SignalType::Veto);
+        }
+    }
+
+    #[test]
+    fn test_node_status_round_trip() {
+        for status in NodeStatus::ALL {
+            assert_eq!(status.as_str().parse::<NodeStatus>(), Ok(status.clone()));
+            assert_listed(&NodeType::MiningPool, &status, &SignalType::Veto);
+        }
+    }
+
+    #[test]
+    fn test_signal_type_round_trip() {
+        for signal in SignalType::ALL {
+            assert_eq!(signal.as_str().parse::<SignalType>(), Ok(signal.clone()));
+            assert_listed(&NodeType::MiningPool, &NodeStatus::Active, &signal);
+        }
+    }
+
+    #[test]
+    fn test_string_forms_are_unique() {
+        let node_types: std::collections::HashSet<_> = NodeType::ALL.iter().map(|v| v.as_str()).collect();
+        let statuses: std::collections::HashSet<_> = NodeStatus::ALL.iter().map(|v| v.as_str()).collect();
+        let signals: std::collections::HashSet<_> = SignalType::ALL.iter().map(|v| v.as_str()).collect();
+        assert_eq!(node_types.len(), NodeType::ALL.len());
+        assert_eq!(statuses.len(), NodeStatus::ALL.len());
+        assert_eq!(signals.len(), SignalType::ALL.len());
+    }
+
+    #[test]
+    fn test_unknown_value_is_descriptive_error() {
+        let err = "Veto".parse::<SignalType>().unwrap_err();
+        assert!(err.contains("Unknown signal type: 'Veto'"));
+        assert!(err.contains("veto, support, abstain"));
+
+        assert!("retired".parse::<NodeStatus>().is_err());
+        assert!("miner".parse::<NodeType>().is_err());
+    }
+}
@@ -2,10 +2,11 @@
 //!
 //! Handles collection, verification, and threshold calculation for economic node vetoes
 
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, Duration, Utc};
 use sqlx::{Row, SqlitePool};
 use tracing::info;
 
+use super::registry::{node_from_row, parse_db_timestamp};
 use super::types::*;
 use crate::crypto::signatures::SignatureManager;
 use crate::error::GovernanceError;
@@ -13,6 +14,7 @@ use crate::error::GovernanceError;
 pub struct VetoManager {
     pool: SqlitePool,
     signature_manager: SignatureManager,
+    threshold_config: VetoThresholdConfig,
 }
 
 impl VetoManager {
@@ -20,9 +22,16 @@ impl VetoManager {
         Self {
             pool,
             signature_manager: SignatureManager::new(),
+            threshold_config: VetoThresholdConfig::default(),
         }
     }
 
+    /// Use activation percentages and the per-node cap from `economic-nodes.yml`
+    pub fn with_threshold_config(mut self, config: VetoThresholdConfig) -> Self {
+        self.threshold_config = config;
+        self
+    }
+
     /// Collect a veto signal from an economic node
     pub async fn collect_veto_signal(
         &self,
@@ -42,17 +51,7 @@ impl VetoManager {
 
         // Verify signature
         let message = format!("PR #{} veto signal from {}", pr_id, node.entity_name);
-        let verified = self.signature_manager.verify_governance_signature(
-            &message,
-            signature,
-            &node.public_key,
-        )?;
-
-        if !verified {
-            return Err(GovernanceError::CryptoError(
-                "Invalid signature".to_string(),
-            ));
-        }
+        self.verify_node_signature(&node, &message, signature)?;
 
         // Check if node already submitted a signal for this PR
         let existing = sqlx::query("SELECT id FROM veto_signals WHERE pr_id = ? AND node_id = ?")
@@ -66,7 +65,7 @@ impl VetoManager {
 
         if existing.is_some() {
             return Err(GovernanceError::CryptoError(
-                "Node already submitted signal for this PR".to_string(),
+                "Node already submitted signal for this PR; amend or withdraw it instead".to_string(),
             ));
         }
 
@@ -101,15 +100,235 @@ impl VetoManager {
         Ok(signal_id)
     }
 
+    /// Withdraw a node's signal on a PR
+    ///
+    /// The node signs `PR #<id> withdraw signal from <entity>`. The withdrawn
+    /// signal is kept in the signal history and the node may submit a new one
+    /// afterwards. Returns the recomputed threshold.
+    pub async fn withdraw_signal(
+        &self,
+        pr_id: i32,
+        node_id: i32,
+        signature: &str,
+    ) -> Result<VetoThreshold, GovernanceError> {
+        let node = self.get_node_by_id(node_id).await?;
+        let message = format!("PR #{} withdraw signal from {}", pr_id, node.entity_name);
+        self.verify_node_signature(&node, &message, signature)?;
+
+        let (signal_id, previous_type, previous_rationale) =
+            self.get_current_signal(pr_id, node_id).await?;
+
+        let mut tx = self.pool.begin().await.map_err(|e| {
+            GovernanceError::DatabaseError(format!("Failed to start transaction: {}", e))
+        })?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO veto_signal_history
+            (pr_id, node_id, action, previous_signal_type, new_signal_type, signature, rationale, changed_at)
+            VALUES (?, ?, ?, ?, NULL, ?, ?, ?)
+            "#,
+        )
+        .bind(pr_id)
+        .bind(node_id)
+        .bind(SignalChangeAction::Withdrawn.as_str())
+        .bind(previous_type.as_str())
+        .bind(signature)
+        .bind(&previous_rationale)
+        .bind(Utc::now().to_rfc3339())
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| {
+            GovernanceError::DatabaseError(format!("Failed to record signal withdrawal: {}", e))
+        })?;
+
+        sqlx::query("DELETE FROM veto_signals WHERE id = ?")
+            .bind(signal_id)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| {
+                GovernanceError::DatabaseError(format!("Failed to withdraw veto signal: {}", e))
+            })?;
+
+        tx.commit().await.map_err(|e| {
+            GovernanceError::DatabaseError(format!("Failed to commit withdrawal: {}", e))
+        })?;
+
+        info!(
+            "Node {} withdrew {} signal for PR {}",
+            node.entity_name,
+            previous_type.as_str(),
+            pr_id
+        );
+        self.check_veto_threshold(pr_id).await
+    }
+
+    /// Change a node's existing signal on a PR to a different type
+    ///
+    /// The node signs `PR #<id> amend signal to <type> from <entity>`, so a
+    /// signature over the original signal can't be replayed as an amendment.
+    /// Returns the recomputed threshold.
+    pub async fn amend_signal(
+        &self,
+        pr_id: i32,
+        node_id: i32,
+        new_type: SignalType,
+        signature: &str,
+        rationale: &str,
+    ) -> Result<VetoThreshold, GovernanceError> {
+        let node = self.get_node_by_id(node_id).await?;
+        if node.status != NodeStatus::Active {
+            return Err(GovernanceError::CryptoError(
+                "Node is not active".to_string(),
+            ));
+        }
+
+        let message = format!(
+            "PR #{} amend signal to {} from {}",
+            pr_id,
+            new_type.as_str(),
+            node.entity_name
+        );
+        self.verify_node_signature(&node, &message, signature)?;
+
+        let (signal_id, previous_type, _) = self.get_current_signal(pr_id, node_id).await?;
+        if previous_type == new_type {
+            return Err(GovernanceError::ValidationError(format!(
+                "Node already has a {} signal for PR {}",
+                new_type.as_str(),
+                pr_id
+            )));
+        }
+
+        let mut tx = self.pool.begin().await.map_err(|e| {
+            GovernanceError::DatabaseError(format!("Failed to start transaction: {}", e))
+        })?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO veto_signal_history
+            (pr_id, node_id, action, previous_signal_type, new_signal_type, signature, rationale, changed_at)
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?)
+            "#,
+        )
+        .bind(pr_id)
+        .bind(node_id)
+        .bind(SignalChangeAction::Amended.as_str())
+        .bind(previous_type.as_str())
+        .bind(new_type.as_str())
+        .bind(signature)
+        .bind(rationale)
+        .bind(Utc::now().to_rfc3339())
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| {
+            GovernanceError::DatabaseError(format!("Failed to record signal amendment: {}", e))
+        })?;
+
+        sqlx::query(
+            r#"
+            UPDATE veto_signals
+            SET signal_type = ?, weight = ?, signature = ?, rationale = ?,
+                timestamp = CURRENT_TIMESTAMP, verified = TRUE
+            WHERE id = ?
+            "#,
+        )
+        .bind(new_type.as_str())
+        .bind(node.weight)
+        .bind(signature)
+        .bind(rationale)
+        .bind(signal_id)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| {
+            GovernanceError::DatabaseError(format!("Failed to amend veto signal: {}", e))
+        })?;
+
+        tx.commit().await.map_err(|e| {
+            GovernanceError::DatabaseError(format!("Failed to commit amendment: {}", e))
+        })?;
+
+        info!(
+            "Node {} amended signal for PR {} from {} to {}",
+            node.entity_name,
+            pr_id,
+            previous_type.as_str(),
+            new_type.as_str()
+        );
+        self.check_veto_threshold(pr_id).await
+    }
+
+    /// Get every withdrawal and amendment recorded for a PR, oldest first
+    pub async fn get_signal_history(&self, pr_id: i32) -> Result<Vec<SignalChange>, GovernanceError> {
+        let rows = sqlx::query(
+            r#"
+            SELECT id, pr_id, node_id, action, previous_signal_type, new_signal_type,
+                   signature, rationale, changed_at
+            FROM veto_signal_history
+            WHERE pr_id = ?
+            ORDER BY id ASC
+            "#,
+        )
+        .bind(pr_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| {
+            GovernanceError::DatabaseError(format!("Failed to fetch signal history: {}", e))
+        })?;
+
+        rows.iter()
+            .map(|row| {
+                Ok(SignalChange {
+                    id: Some(row.get::<i32, _>("id")),
+                    pr_id: row.get::<i32, _>("pr_id"),
+                    node_id: row.get::<i32, _>("node_id"),
+                    action: row
+                        .get::<String, _>("action")
+                        .parse()
+                        .map_err(GovernanceError::DatabaseError)?,
+                    previous_signal_type: row
+                        .get::<String, _>("previous_signal_type")
+                        .parse()
+                        .map_err(GovernanceError::DatabaseError)?,
+                    new_signal_type: row
+                        .get::<Option<String>, _>("new_signal_type")
+                        .map(|t| t.parse())
+                        .transpose()
+                        .map_err(GovernanceError::DatabaseError)?,
+                    signature: row.get::<String, _>("signature"),
+                    rationale: row.get::<String, _>("rationale"),
+                    changed_at: DateTime::parse_from_rfc3339(&row.get::<String, _>("changed_at"))
+                        .map_err(|e| {
+                            GovernanceError::DatabaseError(format!("Invalid timestamp: {}", e))
+                        })?
+                        .with_timezone(&Utc),
+                })
+            })
+            .collect()
+    }
+
     /// Check if veto threshold is met for a PR
     pub async fn check_veto_threshold(&self, pr_id: i32) -> Result<VetoThreshold, GovernanceError> {
+        self.check_veto_threshold_at(pr_id, Utc::now()).await
+    }
+
+    /// Check the veto threshold as of `now`
+    ///
+    /// Only signals submitted after the PR was opened and within the
+    /// configured signal window of `now` are counted.
+    pub async fn check_veto_threshold_at(
+        &self,
+        pr_id: i32,
+        now: DateTime<Utc>,
+    ) -> Result<VetoThreshold, GovernanceError> {
         // Get all veto signals for this PR
         let signals = sqlx::query(
             r#"
-            SELECT vs.signal_type, vs.weight, en.node_type
+            SELECT vs.signal_type, vs.weight, vs.timestamp, en.node_type, pr.opened_at
             FROM veto_signals vs
             JOIN economic_nodes en ON vs.node_id = en.id
-            WHERE vs.pr_id = ? AND vs.verified = TRUE
+            JOIN pull_requests pr ON vs.pr_id = pr.id
+            WHERE vs.pr_id = ? AND vs.verified = TRUE AND en.status = 'active'
             "#,
         )
         .bind(pr_id)
@@ -119,70 +338,33 @@ impl VetoManager {
             GovernanceError::DatabaseError(format!("Failed to fetch veto signals: {}", e))
         })?;
 
-        let mut mining_veto_weight = 0.0;
-        let mut economic_veto_weight = 0.0;
-        let mut total_mining_weight = 0.0;
-        let mut total_economic_weight = 0.0;
-
-        // Calculate weights by node type
+        let window_start = now - Duration::days(self.threshold_config.signal_window_days);
+        let mut expired_signals = 0;
+        let mut tallied = Vec::with_capacity(signals.len());
         for signal in signals {
-            let node_type =
-                NodeType::from_str(&signal.get::<String, _>("node_type")).ok_or_else(|| {
-                    GovernanceError::CryptoError(format!(
-                        "Invalid node type: {}",
-                        signal.get::<String, _>("node_type")
-                    ))
-                })?;
-
-            let signal_type = SignalType::from_str(&signal.get::<String, _>("signal_type"))
-                .ok_or_else(|| {
-                    GovernanceError::CryptoError(format!(
-                        "Invalid signal type: {}",
-                        signal.get::<String, _>("signal_type")
-                    ))
-                })?;
-
-            let weight = signal.get::<f64, _>("weight");
-
-            match node_type {
-                NodeType::MiningPool => {
-                    total_mining_weight += weight;
-                    if signal_type == SignalType::Veto {
-                        mining_veto_weight += weight;
-                    }
-                }
-                _ => {
-                    total_economic_weight += weight;
-                    if signal_type == SignalType::Veto {
-                        economic_veto_weight += weight;
-                    }
-                }
+            let node_type = signal
+                .get::<String, _>("node_type")
+                .parse::<NodeType>()
+                .map_err(GovernanceError::DatabaseError)?;
+
+            let signal_type = signal
+                .get::<String, _>("signal_type")
+                .parse::<SignalType>()
+                .map_err(GovernanceError::DatabaseError)?;
+
+            let submitted_at = parse_db_timestamp(&signal.get::<String, _>("timestamp"))?;
+            let opened_at = parse_db_timestamp(&signal.get::<String, _>("opened_at"))?;
+            if submitted_at < opened_at || submitted_at < window_start {
+                expired_signals += 1;
+                continue;
             }
-        }
 
-        // Calculate percentages
-        let mining_veto_percent = if total_mining_weight > 0.0 {
-            (mining_veto_weight / total_mining_weight) * 100.0
-        } else {
-            0.0
-        };
-
-        let economic_veto_percent = if total_economic_weight > 0.0 {
-            (economic_veto_weight / total_economic_weight) * 100.0
-        } else {
-            0.0
-        };
-
-        // Check thresholds (30% mining or 40% economic)
-        let threshold_met = mining_veto_percent >= 30.0 || economic_veto_percent >= 40.0;
-        let veto_active = threshold_met;
+            tallied.push((node_type, signal_type, signal.get::<f64, _>("weight")));
+        }
 
-        Ok(VetoThreshold {
-            mining_veto_percent,
-            economic_veto_percent,
-            threshold_met,
-            veto_active,
-        })
+        let mut threshold = tally_veto_signals(&tallied, &self.threshold_config);
+        threshold.expired_signals = expired_signals;
+        Ok(threshold)
     }
 
     /// Get all veto signals for a PR
@@ -210,13 +392,10 @@ impl VetoManager {
 
         let mut signals = Vec::new();
         for row in rows {
-            let signal_type = SignalType::from_str(&row.get::<String, _>("signal_type"))
-                .ok_or_else(|| {
-                    GovernanceError::CryptoError(format!(
-                        "Invalid signal type: {}",
-                        row.get::<String, _>("signal_type")
-                    ))
-                })?;
+            let signal_type = row
+                .get::<String, _>("signal_type")
+                .parse::<SignalType>()
+                .map_err(GovernanceError::DatabaseError)?;
 
             signals.push(VetoSignal {
                 id: Some(row.get::<i32, _>("id")),
@@ -226,9 +405,7 @@ impl VetoManager {
                 weight: row.get::<f64, _>("weight"),
                 signature: row.get::<String, _>("signature"),
                 rationale: row.get::<String, _>("rationale"),
-                timestamp: DateTime::parse_from_rfc3339(&row.get::<String, _>("timestamp"))
-                    .map_err(|e| GovernanceError::CryptoError(format!("Invalid timestamp: {}", e)))?
-                    .with_timezone(&Utc),
+                timestamp: parse_db_timestamp(&row.get::<String, _>("timestamp"))?,
                 verified: row.get::<i32, _>("verified") != 0,
             });
         }
@@ -236,6 +413,54 @@ impl VetoManager {
         Ok(signals)
     }
 
+    /// Get the ID, type and rationale of a node's live signal on a PR
+    async fn get_current_signal(
+        &self,
+        pr_id: i32,
+        node_id: i32,
+    ) -> Result<(i32, SignalType, String), GovernanceError> {
+        let row = sqlx::query(
+            "SELECT id, signal_type, rationale FROM veto_signals WHERE pr_id = ? AND node_id = ?",
+        )
+        .bind(pr_id)
+        .bind(node_id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| GovernanceError::DatabaseError(format!("Failed to fetch signal: {}", e)))?
+        .ok_or_else(|| {
+            GovernanceError::ValidationError(format!(
+                "Node {} has no signal for PR {}",
+                node_id, pr_id
+            ))
+        })?;
+
+        let signal_type = row
+            .get::<String, _>("signal_type")
+            .parse::<SignalType>()
+            .map_err(GovernanceError::DatabaseError)?;
+        Ok((row.get::<i32, _>("id"), signal_type, row.get::<String, _>("rationale")))
+    }
+
+    fn verify_node_signature(
+        &self,
+        node: &EconomicNode,
+        message: &str,
+        signature: &str,
+    ) -> Result<(), GovernanceError> {
+        let verified = self.signature_manager.verify_governance_signature(
+            message,
+            signature,
+            &node.public_key,
+        )?;
+
+        if !verified {
+            return Err(GovernanceError::CryptoError(
+                "Invalid signature".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
     /// Get economic node by ID
     async fn get_node_by_id(&self, node_id: i32) -> Result<EconomicNode, GovernanceError> {
         let row = sqlx::query(
@@ -253,45 +478,7 @@ impl VetoManager {
         .map_err(|e| GovernanceError::DatabaseError(format!("Failed to fetch node: {}", e)))?
         .ok_or_else(|| GovernanceError::CryptoError("Node not found".to_string()))?;
 
-        let node_type =
-            NodeType::from_str(&row.get::<String, _>("node_type")).ok_or_else(|| {
-                GovernanceError::CryptoError(format!(
-                    "Invalid node type: {}",
-                    row.get::<String, _>("node_type")
-                ))
-            })?;
-
-        let status = NodeStatus::from_str(&row.get::<String, _>("status")).ok_or_else(|| {
-            GovernanceError::CryptoError(format!(
-                "Invalid status: {}",
-                row.get::<String, _>("status")
-            ))
-        })?;
-
-        Ok(EconomicNode {
-            id: Some(row.get::<i32, _>("id")),
-            node_type,
-            entity_name: row.get::<String, _>("entity_name"),
-            public_key: row.get::<String, _>("public_key"),
-            qualification_data: serde_json::from_str(&row.get::<String, _>("qualification_data"))?,
-            weight: row.get::<f64, _>("weight"),
-            status,
-            registered_at: DateTime::parse_from_rfc3339(&row.get::<String, _>("registered_at"))
-                .map_err(|e| GovernanceError::CryptoError(format!("Invalid timestamp: {}", e)))?
-                .with_timezone(&Utc),
-            verified_at: row.get::<Option<String>, _>("verified_at").map(|t| {
-                DateTime::parse_from_rfc3339(&t)
-                    .unwrap()
-                    .with_timezone(&Utc)
-            }),
-            last_verified_at: row.get::<Option<String>, _>("last_verified_at").map(|t| {
-                DateTime::parse_from_rfc3339(&t)
-                    .unwrap()
-                    .with_timezone(&Utc)
-            }),
-            created_by: row.get::<Option<String>, _>("created_by"),
-            notes: row.get::<String, _>("notes"),
-        })
+        node_from_row(&row)
     }
 
     /// Get veto statistics for a PR
@@ -321,7 +508,11 @@ impl VetoManager {
                 "mining_veto_percent": threshold.mining_veto_percent,
                 "economic_veto_percent": threshold.economic_veto_percent,
                 "threshold_met": threshold.threshold_met,
-                "veto_active": threshold.veto_active
+                "veto_active": threshold.veto_active,
+                "mining": threshold.mining,
+                "economic": threshold.economic,
+                "active_signals": threshold.active_signals,
+                "expired_signals": threshold.expired_signals
             },
             "signals": {
                 "total": signals.len(),
@@ -334,3 +525,294 @@ impl VetoManager {
         }))
     }
 }
+
+/// Sum capped signal weights by category and compare against the activation limits
+///
+/// Mining pools form one category; every other node type counts as economic.
+pub fn tally_veto_signals(
+    signals: &[(NodeType, SignalType, f64)],
+    config: &VetoThresholdConfig,
+) -> VetoThreshold {
+    let mut mining = CategoryVetoBreakdown {
+        activation_percent: config.mining_activation_percent,
+        ..Default::default()
+    };
+    let mut economic = CategoryVetoBreakdown {
+        activation_percent: config.economic_activation_percent,
+        ..Default::default()
+    };
+
+    for (node_type, signal_type, weight) in signals {
+        let category = match node_type {
+            NodeType::MiningPool => &mut mining,
+            _ => &mut economic,
+        };
+
+        let weight = if *weight > config.max_node_weight {
+            category.capped_signals += 1;
+            config.max_node_weight
+        } else {
+            *weight
+        };
+
+        category.total_weight += weight;
+        if *signal_type == SignalType::Veto {
+            category.veto_weight += weight;
+        }
+    }
+
+    for category in [&mut mining, &mut economic] {
+        category.veto_percent = if category.total_weight > 0.0 {
+            (category.veto_weight / category.total_weight) * 100.0
+        } else {
+            0.0
+        };
+        category.threshold_met = category.veto_percent >= category.activation_percent;
+    }
+
+    let threshold_met = mining.threshold_met || economic.threshold_met;
+    VetoThreshold {
+        mining_veto_percent: mining.veto_percent,
+        economic_veto_percent: economic.veto_percent,
+        threshold_met,
+        veto_active: threshold_met,
+        mining,
+        economic,
+        active_signals: signals.len() as u32,
+        expired_signals: 0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::Database;
+    use crate::economic_nodes::registry::EconomicNodeRegistry;
+    use developer_sdk::governance::GovernanceKeypair;
+
+    struct Fixture {
+        _db: Database,
+        veto_manager: VetoManager,
+        keypair: GovernanceKeypair,
+        node_id: i32,
+        pr_id: i32,
+    }
+
+    impl Fixture {
+        fn sign(&self, message: &str) -> String {
+            SignatureManager::new()
+                .create_governance_signature(message, &self.keypair)
+                .unwrap()
+        }
+    }
+
+    async fn fixture() -> Fixture {
+        let db = Database::new_in_memory().await.unwrap();
+        db.create_pull_request("test-org/test-repo", 1, "abc123", 3)
+            .await
+            .unwrap();
+        let pool = db.pool().unwrap().clone();
+
+        let keypair = SignatureManager::new().generate_keypair().unwrap();
+        let registry = EconomicNodeRegistry::new(pool.clone());
+        let proof = QualificationProof {
+            node_type: NodeType::Custodian,
+            hashpower_proof: None,
+            holdings_proof: Some(HoldingsProof {
+                addresses: vec!["bc1qexample".to_string()],
+                total_btc: 12_000.0,
+                signature_challenge: "challenge".to_string(),
+            }),
+            volume_proof: None,
+            contact_info: ContactInfo {
+                entity_name: "Test Custodian".to_string(),
+                contact_email: "ops@custodian.example".to_string(),
+                website: None,
+                github_username: None,
+            },
+        };
+        let node_id = registry
+            .register_economic_node(
+                NodeType::Custodian,
+                "Test Custodian",
+                &keypair.public_key.to_string(),
+                &proof,
+                None,
+            )
+            .await
+            .unwrap();
+        registry.update_node_status(node_id, NodeStatus::Active).await.unwrap();
+
+        Fixture {
+            _db: db,
+            veto_manager: VetoManager::new(pool),
+            keypair,
+            node_id,
+            pr_id: 1,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_withdraw_then_resubmit() {
+        let f = fixture().await;
+        let veto_sig = f.sign("PR #1 veto signal from Test Custodian");
+
+        f.veto_manager
+            .collect_veto_signal(f.pr_id, f.node_id, SignalType::Veto, &veto_sig, "Unsafe")
+            .await
+            .unwrap();
+        assert!(f
+            .veto_manager
+            .collect_veto_signal(f.pr_id, f.node_id, SignalType::Veto, &veto_sig, "Unsafe")
+            .await
+            .is_err());
+
+        // A signature over some other message doesn't authorize a withdrawal
+        assert!(f
+            .veto_manager
+            .withdraw_signal(f.pr_id, f.node_id, &veto_sig)
+            .await
+            .is_err());
+
+        let withdraw_sig = f.sign("PR #1 withdraw signal from Test Custodian");
+        let threshold = f
+            .veto_manager
+            .withdraw_signal(f.pr_id, f.node_id, &withdraw_sig)
+            .await
+            .unwrap();
+        assert!(!threshold.veto_active);
+
+        f.veto_manager
+            .collect_veto_signal(f.pr_id, f.node_id, SignalType::Veto, &veto_sig, "Still unsafe")
+            .await
+            .unwrap();
+        assert!(f.veto_manager.check_veto_threshold(f.pr_id).await.unwrap().veto_active);
+
+        let history = f.veto_manager.get_signal_history(f.pr_id).await.unwrap();
+        assert_eq!(history.len(), 1);
+        assert_eq!(history[0].action, SignalChangeAction::Withdrawn);
+        assert_eq!(history[0].previous_signal_type, SignalType::Veto);
+        assert_eq!(history[0].new_signal_type, None);
+    }
+
+    #[tokio::test]
+    async fn test_amend_veto_to_support_updates_threshold() {
+        let f = fixture().await;
+        let veto_sig = f.sign("PR #1 veto signal from Test Custodian");
+        f.veto_manager
+            .collect_veto_signal(f.pr_id, f.node_id, SignalType::Veto, &veto_sig, "Unsafe")
+            .await
+            .unwrap();
+        let before = f.veto_manager.check_veto_threshold(f.pr_id).await.unwrap();
+        assert!(before.veto_active);
+        assert_eq!(before.economic_veto_percent, 100.0);
+
+        let amend_sig = f.sign("PR #1 amend signal to support from Test Custodian");
+        let after = f
+            .veto_manager
+            .amend_signal(f.pr_id, f.node_id, SignalType::Support, &amend_sig, "Concerns addressed")
+            .await
+            .unwrap();
+        assert!(!after.veto_active);
+        assert_eq!(after.economic_veto_percent, 0.0);
+
+        let history = f.veto_manager.get_signal_history(f.pr_id).await.unwrap();
+        assert_eq!(history.len(), 1);
+        assert_eq!(history[0].action, SignalChangeAction::Amended);
+        assert_eq!(history[0].new_signal_type, Some(SignalType::Support));
+
+        // Amending to the current type is rejected
+        assert!(f
+            .veto_manager
+            .amend_signal(f.pr_id, f.node_id, SignalType::Support, &amend_sig, "Again")
+            .await
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn test_signals_expire_after_window() {
+        let f = fixture().await;
+        let veto_sig = f.sign("PR #1 veto signal from Test Custodian");
+        f.veto_manager
+            .collect_veto_signal(f.pr_id, f.node_id, SignalType::Veto, &veto_sig, "Unsafe")
+            .await
+            .unwrap();
+
+        let now = Utc::now();
+        let current = f.veto_manager.check_veto_threshold_at(f.pr_id, now).await.unwrap();
+        assert!(current.veto_active);
+        assert_eq!((current.active_signals, current.expired_signals), (1, 0));
+
+        let window = VetoThresholdConfig::default().signal_window_days;
+        let later = now + Duration::days(window + 1);
+        let stale = f.veto_manager.check_veto_threshold_at(f.pr_id, later).await.unwrap();
+        assert!(!stale.veto_active);
+        assert_eq!((stale.active_signals, stale.expired_signals), (0, 1));
+    }
+
+    #[test]
+    fn test_oversized_node_is_capped() {
+        let signals = vec![
+            (NodeType::Exchange, SignalType::Veto, 1.0),
+            (NodeType::PaymentProcessor, SignalType::Support, 0.2),
+            (NodeType::MajorHolder, SignalType::Support, 0.2),
+            (NodeType::Custodian, SignalType::Support, 0.2),
+        ];
+
+        // Uncapped, the single exchange holds 62.5% of economic weight
+        let uncapped = tally_veto_signals(&signals, &VetoThresholdConfig::default());
+        assert!(uncapped.veto_active);
+
+        let config = VetoThresholdConfig {
+            max_node_weight: 0.3,
+            ..Default::default()
+        };
+        let capped = tally_veto_signals(&signals, &config);
+        assert!(!capped.veto_active);
+        assert_eq!(capped.economic.capped_signals, 1);
+        assert!((capped.economic.veto_weight - 0.3).abs() < 1e-9);
+        assert!((capped.economic.total_weight - 0.9).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_combined_capped_weights_cross_activation() {
+        let config = VetoThresholdConfig {
+            max_node_weight: 0.3,
+            ..Default::default()
+        };
+        let signals = vec![
+            (NodeType::Exchange, SignalType::Veto, 0.9),
+            (NodeType::Custodian, SignalType::Veto, 1.0),
+            (NodeType::PaymentProcessor, SignalType::Support, 0.3),
+            (NodeType::MajorHolder, SignalType::Support, 0.3),
+            (NodeType::MiningPool, SignalType::Support, 0.2),
+        ];
+
+        let threshold = tally_veto_signals(&signals, &config);
+        assert_eq!(threshold.economic.capped_signals, 2);
+        assert!((threshold.economic_veto_percent - 50.0).abs() < 1e-9);
+        assert!(threshold.economic.threshold_met);
+        assert!(!threshold.mining.threshold_met);
+        assert!(threshold.veto_active);
+    }
+
+    #[test]
+    fn test_threshold_config_from_economic_nodes_yml() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("economic-nodes.yml");
+        std::fs::write(
+            &path,
+            "nodes: []\nveto_thresholds:\n  economic_activation_percent: 50\n  max_node_weight: 0.25\n",
+        )
+        .unwrap();
+
+        let config = VetoThresholdConfig::load_from_file(&path).unwrap();
+        assert_eq!(config.mining_activation_percent, 30.0);
+        assert_eq!(config.economic_activation_percent, 50.0);
+        assert_eq!(config.max_node_weight, 0.25);
+
+        std::fs::write(&path, "nodes: []\n").unwrap();
+        let defaults = VetoThresholdConfig::load_from_file(&path).unwrap();
+        assert_eq!(defaults.max_node_weight, 1.0);
+    }
+}
@@ -1,81 +1,134 @@
+use crate::database::Database;
 use crate::error::GovernanceError;
 use crate::github::client::GitHubClient;
 use crate::enforcement::decision_log::DecisionLogger;
+use crate::enforcement::status_checks::{CheckRunSections, StatusCheckGenerator, CHECK_RUN_NAME};
+use serde::{Deserialize, Serialize};
+use std::fmt;
 use tracing::{info, warn};
 
+/// A single governance requirement that is keeping a PR from merging
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "reason", rename_all = "snake_case")]
+pub enum BlockReason {
+    ReviewPeriodNotMet,
+    InsufficientSignatures { have: usize, need: usize },
+    EconomicVetoActive,
+    EconomicQuorumNotMet,
+    CrossLayerUnsynced,
+}
+
+impl fmt::Display for BlockReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BlockReason::ReviewPeriodNotMet => write!(f, "Review period not met"),
+            BlockReason::InsufficientSignatures { have, need } => {
+                write!(f, "Insufficient signatures ({}/{})", have, need)
+            }
+            BlockReason::EconomicVetoActive => write!(f, "Economic node veto active"),
+            BlockReason::EconomicQuorumNotMet => write!(f, "Economic node quorum not met"),
+            BlockReason::CrossLayerUnsynced => write!(f, "Cross-layer changes not synchronized"),
+        }
+    }
+}
+
+/// Inputs to a merge decision for one PR
+#[derive(Debug, Clone)]
+pub struct MergeRequirements {
+    pub review_period_met: bool,
+    pub current_signatures: usize,
+    pub required_signatures: usize,
+    pub economic_veto_active: bool,
+    pub economic_quorum_met: bool,
+    pub cross_layer_synced: bool,
+    pub tier: u32,
+    pub emergency_mode: bool,
+}
+
+/// Outcome of evaluating a PR against its governance requirements
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MergeDecision {
+    pub blocked: bool,
+    pub reasons: Vec<BlockReason>,
+}
+
+impl MergeDecision {
+    /// Reasons joined into one line, for logs and short status descriptions
+    pub fn summary(&self) -> String {
+        if self.reasons.is_empty() {
+            "All governance requirements met".to_string()
+        } else {
+            self.reasons
+                .iter()
+                .map(|r| r.to_string())
+                .collect::<Vec<_>>()
+                .join(", ")
+        }
+    }
+}
+
 pub struct MergeBlocker {
     github_client: Option<GitHubClient>,
     decision_logger: DecisionLogger,
+    dry_run: bool,
+    database: Option<Database>,
 }
 
 impl MergeBlocker {
-    pub fn new(github_client: Option<GitHubClient>, decision_logger: DecisionLogger) -> Self {
+    /// Create a merge blocker; with `dry_run` set, statuses are recorded but never posted
+    pub fn new(github_client: Option<GitHubClient>, decision_logger: DecisionLogger, dry_run: bool) -> Self {
         Self { 
             github_client,
             decision_logger,
+            dry_run,
+            database: None,
         }
     }
 
-    /// Determine if merge should be blocked based on governance requirements
-    pub fn should_block_merge(
-        review_period_met: bool,
-        signatures_met: bool,
-        economic_veto_active: bool,
-        tier: u32,
-        emergency_mode: bool,
-    ) -> Result<bool, GovernanceError> {
-        // In emergency mode, only signature threshold matters
-        if emergency_mode {
-            Ok(!signatures_met)
-        } else {
-            // Normal mode: check all requirements
-            let basic_requirements_met = review_period_met && signatures_met;
+    /// Record every status decision in `governance_events`
+    pub fn with_database(mut self, database: Database) -> Self {
+        self.database = Some(database);
+        self
+    }
 
-            // For Tier 3+ PRs, also check economic node veto
-            if tier >= 3 && economic_veto_active {
-                Ok(true) // Block merge due to economic node veto
-            } else {
-                Ok(!basic_requirements_met)
-            }
-        }
+    pub fn is_dry_run(&self) -> bool {
+        self.dry_run
     }
 
-    /// Get detailed reason for merge blocking
-    pub fn get_block_reason(
-        review_period_met: bool,
-        signatures_met: bool,
-        economic_veto_active: bool,
-        tier: u32,
-        emergency_mode: bool,
-    ) -> String {
-        if emergency_mode {
-            if !signatures_met {
-                "Emergency mode: Signature threshold not met".to_string()
-            } else {
-                "Emergency mode: All requirements met".to_string()
-            }
-        } else {
-            let mut reasons = Vec::new();
+    /// Evaluate every requirement and collect the ones that are not met
+    ///
+    /// In emergency mode only the signature threshold applies. Economic node
+    /// veto and quorum only apply to Tier 3+ PRs.
+    pub fn evaluate_merge(requirements: &MergeRequirements) -> MergeDecision {
+        let mut reasons = Vec::new();
 
-            if !review_period_met {
-                reasons.push("Review period requirement not met");
-            }
+        if !requirements.emergency_mode && !requirements.review_period_met {
+            reasons.push(BlockReason::ReviewPeriodNotMet);
+        }
 
-            if !signatures_met {
-                reasons.push("Signature threshold requirement not met");
-            }
+        if requirements.current_signatures < requirements.required_signatures {
+            reasons.push(BlockReason::InsufficientSignatures {
+                have: requirements.current_signatures,
+                need: requirements.required_signatures,
+            });
+        }
 
-            if tier >= 3 && economic_veto_active {
-                reasons
-                    .push("Economic node veto active (30%+ hashpower or 40%+ economic activity)");
+        if !requirements.emergency_mode {
+            if requirements.tier >= 3 && requirements.economic_veto_active {
+                reasons.push(BlockReason::EconomicVetoActive);
             }
-
-            if reasons.is_empty() {
-                "All governance requirements met".to_string()
-            } else {
-                format!("Governance requirements not met: {}", reasons.join(", "))
+            if requirements.tier >= 3 && !requirements.economic_quorum_met {
+                reasons.push(BlockReason::EconomicQuorumNotMet);
+            }
+            if !requirements.cross_layer_synced {
+                reasons.push(BlockReason::CrossLayerUnsynced);
             }
         }
+
+        MergeDecision {
+            blocked: !reasons.is_empty(),
+            reasons,
+        }
     }
 
     /// Post status check to GitHub for merge blocking
@@ -94,13 +147,6 @@ impl MergeBlocker {
             "✅ Governance requirements met - merge allowed".to_string()
         };
 
-        // Add dry-run prefix if in dry-run mode
-        let final_description = if self.decision_logger.dry_run_mode {
-            format!("[DRY-RUN] {}", description)
-        } else {
-            description
-        };
-
         // Log the decision
         self.decision_logger.log_merge_decision(
             sha.parse().unwrap_or(0),
@@ -108,59 +154,165 @@ impl MergeBlocker {
             reason,
         );
 
+        self.post_status(owner, repo, sha, state, &description, "governance/merge-check")
+            .await
+    }
+
+    /// Post the merge-check status for an evaluated decision
+    pub async fn post_merge_decision(
+        &self,
+        owner: &str,
+        repo: &str,
+        sha: &str,
+        decision: &MergeDecision,
+    ) -> Result<(), GovernanceError> {
+        let state = if decision.blocked { "failure" } else { "success" };
+        let description = StatusCheckGenerator::generate_merge_decision_status(decision);
+
+        self.decision_logger.log_merge_decision(
+            sha.parse().unwrap_or(0),
+            decision.blocked,
+            &decision.summary(),
+        );
+
+        self.post_status(owner, repo, sha, state, &description, "governance/merge-check")
+            .await
+    }
+
+    /// Post a commit status to GitHub, or only record it in dry-run mode
+    ///
+    /// Every status is recorded as a governance event when a database is
+    /// attached; dry-run events use the `status_check_dry_run` type so they
+    /// can't be mistaken for statuses GitHub actually received.
+    pub async fn post_status(
+        &self,
+        owner: &str,
+        repo: &str,
+        sha: &str,
+        state: &str,
+        description: &str,
+        context: &str,
+    ) -> Result<(), GovernanceError> {
+        let description = if self.dry_run {
+            format!("[DRY-RUN] {}", description)
+        } else {
+            description.to_string()
+        };
+
+        if let Some(database) = &self.database {
+            let event_type = if self.dry_run {
+                "status_check_dry_run"
+            } else {
+                "status_check_posted"
+            };
+            database
+                .log_governance_event(
+                    event_type,
+                    Some(&format!("{}/{}", owner, repo)),
+                    None,
+                    None,
+                    &serde_json::json!({
+                        "sha": sha,
+                        "context": context,
+                        "state": state,
+                        "description": description,
+                        "dry_run": self.dry_run,
+                    }),
+                )
+                .await?;
+        }
+
+        if self.dry_run {
+            info!(
+                "[DRY-RUN] Not posting {} for {}/{}@{}: {} - {}",
+                context, owner, repo, sha, state, description
+            );
+            return Ok(());
+        }
+
         if let Some(client) = &self.github_client {
             client
-                .post_status_check(
-                    owner,
-                    repo,
-                    sha,
-                    state,
-                    &final_description,
-                    "governance/merge-check",
-                )
+                .post_status_check(owner, repo, sha, state, &description, context)
                 .await?;
 
             info!(
-                "Posted merge status for {}/{}@{}: {} - {}",
-                owner, repo, sha, state, final_description
+                "Posted {} for {}/{}@{}: {} - {}",
+                context, owner, repo, sha, state, description
             );
         } else {
-            warn!("No GitHub client available, cannot post merge status");
+            warn!("No GitHub client available, cannot post {}", context);
         }
 
         Ok(())
     }
 
-    /// Update merge status when requirements change
-    pub async fn update_merge_status(
+    /// Create a check run for an evaluated decision, with a section per requirement
+    ///
+    /// Recorded and skipped in dry-run mode, like commit statuses.
+    pub async fn post_check_run(
         &self,
         owner: &str,
         repo: &str,
         sha: &str,
-        review_period_met: bool,
-        signatures_met: bool,
-        economic_veto_active: bool,
-        tier: u32,
-        emergency_mode: bool,
+        decision: &MergeDecision,
+        sections: &CheckRunSections,
     ) -> Result<(), GovernanceError> {
-        let should_block = Self::should_block_merge(
-            review_period_met,
-            signatures_met,
-            economic_veto_active,
-            tier,
-            emergency_mode,
-        )?;
-
-        let reason = Self::get_block_reason(
-            review_period_met,
-            signatures_met,
-            economic_veto_active,
-            tier,
-            emergency_mode,
-        );
+        let conclusion = if decision.blocked { "failure" } else { "success" };
+        let output = StatusCheckGenerator::generate_check_run_output(decision, sections);
 
-        self.post_merge_status(owner, repo, sha, should_block, &reason)
-            .await
+        if let Some(database) = &self.database {
+            let event_type = if self.dry_run {
+                "check_run_dry_run"
+            } else {
+                "check_run_posted"
+            };
+            database
+                .log_governance_event(
+                    event_type,
+                    Some(&format!("{}/{}", owner, repo)),
+                    None,
+                    None,
+                    &serde_json::json!({
+                        "sha": sha,
+                        "name": CHECK_RUN_NAME,
+                        "conclusion": conclusion,
+                        "title": output.title,
+                        "dry_run": self.dry_run,
+                    }),
+                )
+                .await?;
+        }
+
+        if self.dry_run {
+            info!(
+                "[DRY-RUN] Not creating check run for {}/{}@{}: {}",
+                owner, repo, sha, conclusion
+            );
+            return Ok(());
+        }
+
+        match &self.github_client {
+            Some(client) => {
+                client
+                    .create_check_run(owner, repo, sha, CHECK_RUN_NAME, conclusion, &output)
+                    .await?;
+            }
+            None => warn!("No GitHub client available, cannot create check run"),
+        }
+        Ok(())
+    }
+
+    /// Update merge status when requirements change
+    pub async fn update_merge_status(
+        &self,
+        owner: &str,
+        repo: &str,
+        sha: &str,
+        requirements: &MergeRequirements,
+    ) -> Result<MergeDecision, GovernanceError> {
+        let decision = Self::evaluate_merge(requirements);
+        self.post_merge_decision(owner, repo, sha, &decision).await?;
+        Ok(decision)
     }
 
     /// Check if PR can be merged (GitHub API integration)
@@ -205,72 +357,4 @@ impl MergeBlocker {
 
         Ok(())
     }
-
-    /// Generate comprehensive merge status message
-    pub fn generate_merge_status_message(
-        review_period_met: bool,
-        signatures_met: bool,
-        economic_veto_active: bool,
-        tier: u32,
-        tier_name: &str,
-        emergency_mode: bool,
-        review_period_days: i64,
-        elapsed_days: i64,
-        current_signatures: usize,
-        required_signatures: usize,
-    ) -> String {
-        let mut message = format!("🏛️ Governance Status for Tier {}: {}\n\n", tier, tier_name);
-
-        // Review period status
-        if review_period_met {
-            message.push_str("✅ Review Period: Met\n");
-        } else {
-            message.push_str(&format!(
-                "❌ Review Period: {} days elapsed, {} days required\n",
-                elapsed_days, review_period_days
-            ));
-        }
-
-        // Signature status
-        if signatures_met {
-            message.push_str("✅ Signatures: Complete\n");
-        } else {
-            message.push_str(&format!(
-                "❌ Signatures: {}/{} required\n",
-                current_signatures, required_signatures
-            ));
-        }
-
-        // Economic node veto status (Tier 3+)
-        if tier >= 3 {
-            if economic_veto_active {
-                message.push_str("⚠️ Economic Node Veto: Active\n");
-            } else {
-                message.push_str("✅ Economic Node Veto: Not Active\n");
-            }
-        }
-
-        // Emergency mode indicator
-        if emergency_mode {
-            message.push_str("\n🚨 Emergency Mode Active\n");
-        }
-
-        // Overall status
-        let should_block = Self::should_block_merge(
-            review_period_met,
-            signatures_met,
-            economic_veto_active,
-            tier,
-            emergency_mode,
-        )
-        .unwrap_or(true);
-
-        if should_block {
-            message.push_str("\n❌ Merge Blocked: Governance requirements not met");
-        } else {
-            message.push_str("\n✅ Merge Allowed: All governance requirements met");
-        }
-
-        message
-    }
 }
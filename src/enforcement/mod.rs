@@ -1,3 +1,7 @@
 pub mod decision_log;
 pub mod merge_block;
+pub mod pr_record;
+pub mod pr_status;
+pub mod reminders;
 pub mod status_checks;
+pub mod violations;
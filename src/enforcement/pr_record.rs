@@ -0,0 +1,512 @@
+//! PR Governance Record
+//!
+//! Assembles everything the app knows about a PR's governance into a single
+//! auditable bundle, so maintainers can review the full history when a
+//! contributor appeals a block.
+
+use chrono::{DateTime, Utc};
+use secp256k1::SecretKey;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use tracing::info;
+
+use crate::crypto::signatures::{build_signing_message, SignatureManager, SignatureScheme};
+use crate::database::models::{EconomicSignal, GovernanceEvent, PullRequest};
+use crate::database::Database;
+use crate::error::GovernanceError;
+use crate::validation::review_period::ReviewPeriodValidator;
+use crate::validation::threshold::ThresholdValidator;
+
+/// Event types that carry a tier classification in their details
+const CLASSIFICATION_EVENTS: [&str; 3] = ["pr_opened", "pr_synchronized", "pr_reclassified"];
+
+/// Event types that set or clear a manual tier override
+const OVERRIDE_EVENTS: [&str; 2] = ["tier_override", "tier_override_cleared"];
+
+/// Verification status of a stored maintainer signature
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SignatureVerification {
+    Verified,
+    Invalid,
+    UnknownSigner,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SignatureRecord {
+    pub signer: String,
+    pub signature: String,
+    pub timestamp: DateTime<Utc>,
+    pub scheme: SignatureScheme,
+    pub verification: SignatureVerification,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ClassificationRecord {
+    pub tier: u32,
+    pub confidence: Option<f64>,
+    pub rationale: String,
+    pub classified_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReviewTimeline {
+    pub opened_at: DateTime<Utc>,
+    pub review_period_days: i64,
+    pub earliest_merge: DateTime<Utc>,
+    pub review_period_met: bool,
+    pub signatures_required: usize,
+    pub signatures_total: usize,
+    pub signatures_verified: usize,
+    pub threshold_met: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EmergencyRecord {
+    pub emergency_mode: bool,
+    pub events: Vec<GovernanceEvent>,
+}
+
+/// Complete governance record for a single PR
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PrGovernanceRecord {
+    pub repo_name: String,
+    pub pr_number: i32,
+    pub layer: i32,
+    pub head_sha: String,
+    pub governance_status: String,
+    pub generated_at: DateTime<Utc>,
+    pub classification: ClassificationRecord,
+    pub signatures: Vec<SignatureRecord>,
+    pub review: ReviewTimeline,
+    pub economic_signals: Vec<EconomicSignal>,
+    pub emergency: EmergencyRecord,
+    pub decisions: Vec<GovernanceEvent>,
+}
+
+/// Server attestation over a record's hash
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecordAttestation {
+    pub server_id: String,
+    pub record_hash: String,
+    pub public_key: Option<String>,
+    pub signature: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SignedPrRecord {
+    pub record: PrGovernanceRecord,
+    pub attestation: RecordAttestation,
+}
+
+impl PrGovernanceRecord {
+    /// Build a record from stored PR state
+    ///
+    /// `maintainer_keys` maps GitHub usernames to public keys and is used to
+    /// re-verify every stored signature against the PR's current head and tier.
+    pub fn build(
+        pr: &PullRequest,
+        events: Vec<GovernanceEvent>,
+        economic_signals: Vec<EconomicSignal>,
+        maintainer_keys: &HashMap<String, String>,
+        now: DateTime<Utc>,
+    ) -> Self {
+        let classification = Self::classification_from_events(&events);
+
+        let signature_manager = SignatureManager::new();
+        let message = build_signing_message(&pr.repo_name, pr.pr_number, &pr.head_sha, classification.tier);
+        let signatures: Vec<SignatureRecord> = pr
+            .signatures
+            .iter()
+            .map(|s| {
+                let verification = match maintainer_keys.get(&s.signer) {
+                    None => SignatureVerification::UnknownSigner,
+                    Some(key) => {
+                        match signature_manager.verify_with_scheme(&message, &s.signature, key, s.scheme) {
+                            Ok(true) => SignatureVerification::Verified,
+                            _ => SignatureVerification::Invalid,
+                        }
+                    }
+                };
+                SignatureRecord {
+                    signer: s.signer.clone(),
+                    signature: s.signature.clone(),
+                    timestamp: s.timestamp,
+                    scheme: s.scheme,
+                    verification,
+                }
+            })
+            .collect();
+
+        let (signatures_required, signatures_total, review_period_days) =
+            ThresholdValidator::get_combined_requirements(pr.layer, classification.tier);
+        let signatures_verified = signatures
+            .iter()
+            .filter(|s| s.verification == SignatureVerification::Verified)
+            .count();
+        let earliest_merge = ReviewPeriodValidator::get_earliest_merge_date(
+            pr.opened_at,
+            review_period_days,
+            pr.emergency_mode,
+        );
+        let review = ReviewTimeline {
+            opened_at: pr.opened_at,
+            review_period_days,
+            earliest_merge,
+            review_period_met: now >= earliest_merge,
+            signatures_required,
+            signatures_total,
+            signatures_verified,
+            threshold_met: signatures_verified >= signatures_required,
+        };
+
+        let emergency = EmergencyRecord {
+            emergency_mode: pr.emergency_mode,
+            events: events
+                .iter()
+                .filter(|e| e.event_type.starts_with("emergency"))
+                .cloned()
+                .collect(),
+        };
+
+        Self {
+            repo_name: pr.repo_name.clone(),
+            pr_number: pr.pr_number,
+            layer: pr.layer,
+            head_sha: pr.head_sha.clone(),
+            governance_status: pr.governance_status.clone(),
+            generated_at: now,
+            classification,
+            signatures,
+            review,
+            economic_signals,
+            emergency,
+            decisions: events,
+        }
+    }
+
+    /// Load and assemble the record for a PR, if the PR is known
+    pub async fn load(
+        database: &Database,
+        repo_name: &str,
+        pr_number: i32,
+    ) -> Result<Option<Self>, GovernanceError> {
+        let pr = match database.get_pull_request(repo_name, pr_number).await? {
+            Some(pr) => pr,
+            None => return Ok(None),
+        };
+
+        let events = database.get_pr_governance_events(repo_name, pr_number).await?;
+        let economic_signals = database.get_pr_economic_signals(pr.id).await?;
+
+        let mut maintainer_keys = HashMap::new();
+        for signature in &pr.signatures {
+            if let Some(maintainer) = database.get_maintainer_by_username(&signature.signer).await? {
+                maintainer_keys.insert(maintainer.github_username, maintainer.public_key);
+            }
+        }
+
+        Ok(Some(Self::build(
+            &pr,
+            events,
+            economic_signals,
+            &maintainer_keys,
+            Utc::now(),
+        )))
+    }
+
+    /// Calculate the `sha256:<hex>` hash of the serialized record
+    pub fn record_hash(&self) -> Result<String, GovernanceError> {
+        let bytes = serde_json::to_vec(self)?;
+        Ok(format!("sha256:{}", hex::encode(Sha256::digest(&bytes))))
+    }
+
+    /// Wrap the record with a server attestation, signing it when a key is given
+    pub fn attest(
+        self,
+        server_id: &str,
+        signing_key: Option<&SecretKey>,
+    ) -> Result<SignedPrRecord, GovernanceError> {
+        let record_hash = self.record_hash()?;
+        let (public_key, signature) = match signing_key {
+            Some(key) => {
+                let signature_manager = SignatureManager::new();
+                let signature = signature_manager.create_signature(&record_hash, key)?;
+                let public_key = signature_manager.public_key_from_secret(key);
+                (
+                    Some(hex::encode(public_key.serialize())),
+                    Some(hex::encode(signature.serialize_compact())),
+                )
+            }
+            None => (None, None),
+        };
+
+        Ok(SignedPrRecord {
+            record: self,
+            attestation: RecordAttestation {
+                server_id: server_id.to_string(),
+                record_hash,
+                public_key,
+                signature,
+            },
+        })
+    }
+
+    /// Log an event that may change a PR's tier
+    ///
+    /// Signatures commit to the tier, so when the event moves the PR to a
+    /// different tier the signatures collected for the old one are dropped.
+    pub(crate) async fn log_tier_event(
+        database: &Database,
+        event_type: &str,
+        repo_name: &str,
+        pr_number: i32,
+        maintainer: Option<&str>,
+        details: &serde_json::Value,
+    ) -> Result<(), GovernanceError> {
+        let before = Self::classification_from_events(
+            &database.get_pr_governance_events(repo_name, pr_number).await?,
+        );
+        database
+            .log_governance_event(event_type, Some(repo_name), Some(pr_number), maintainer, details)
+            .await?;
+        let after = Self::classification_from_events(
+            &database.get_pr_governance_events(repo_name, pr_number).await?,
+        );
+        if after.tier != before.tier {
+            database.clear_signatures(repo_name, pr_number).await?;
+            info!(
+                "Tier of {}#{} changed from {} to {}; signatures cleared",
+                repo_name, pr_number, before.tier, after.tier
+            );
+        }
+        Ok(())
+    }
+
+    /// Take the classification recorded in the event history, falling back to Tier 1
+    pub(crate) fn classification_from_events(events: &[GovernanceEvent]) -> ClassificationRecord {
+        Self::recorded_classification(events).unwrap_or_else(|| ClassificationRecord {
+            tier: 1,
+            confidence: None,
+            rationale: "No classification recorded, using fallback Tier 1".to_string(),
+            classified_at: None,
+        })
+    }
+
+    /// The tier every governance decision on a PR uses
+    ///
+    /// A manual override applies until a later `tier_override_cleared`;
+    /// otherwise the PR's most recent open, synchronize or reclassification
+    /// counts. `None` when no tier was ever recorded.
+    pub(crate) fn recorded_classification(events: &[GovernanceEvent]) -> Option<ClassificationRecord> {
+        Self::active_override(events)
+            .and_then(Self::classification_record)
+            .or_else(|| Self::auto_classification(events))
+    }
+
+    /// The `tier_override` event in effect, unless a later one cleared it
+    ///
+    /// Overrides live only in the event log, so this is the one place that
+    /// decides whether a PR is manually classified.
+    pub(crate) fn active_override(events: &[GovernanceEvent]) -> Option<&GovernanceEvent> {
+        events
+            .iter()
+            .rev()
+            .find(|e| OVERRIDE_EVENTS.contains(&e.event_type.as_str()))
+            .filter(|e| e.event_type == "tier_override")
+    }
+
+    /// The most recent automatic classification, ignoring manual overrides
+    pub(crate) fn auto_classification(events: &[GovernanceEvent]) -> Option<ClassificationRecord> {
+        events
+            .iter()
+            .rev()
+            .filter(|e| CLASSIFICATION_EVENTS.contains(&e.event_type.as_str()))
+            .find_map(Self::classification_record)
+    }
+
+    fn classification_record(e: &GovernanceEvent) -> Option<ClassificationRecord> {
+        let tier = e.details.get("tier")?.as_u64()? as u32;
+        Some(ClassificationRecord {
+            tier,
+            confidence: e
+                .details
+                .get("classification_confidence")
+                .and_then(|c| c.as_f64()),
+            rationale: e
+                .details
+                .get("classification_rationale")
+                .or_else(|| e.details.get("rationale"))
+                .and_then(|r| r.as_str())
+                .unwrap_or("No rationale recorded")
+                .to_string(),
+            classified_at: Some(e.timestamp),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::models::Signature;
+    use chrono::Duration;
+
+    fn event(id: i32, event_type: &str, details: serde_json::Value, at: DateTime<Utc>) -> GovernanceEvent {
+        GovernanceEvent {
+            id,
+            event_type: event_type.to_string(),
+            repo_name: Some("BTCDecoded/protocol-engine".to_string()),
+            pr_number: Some(42),
+            maintainer: None,
+            details,
+            timestamp: at,
+        }
+    }
+
+    fn mixed_state_record() -> PrGovernanceRecord {
+        let now = Utc::now();
+        let opened_at = now - Duration::days(100);
+        let pr = PullRequest {
+            id: 7,
+            repo_name: "BTCDecoded/protocol-engine".to_string(),
+            pr_number: 42,
+            opened_at,
+            layer: 3,
+            head_sha: "abc123".to_string(),
+            signatures: vec![
+                Signature {
+                    signer: "alice".to_string(),
+                    signature: "deadbeef".to_string(),
+                    timestamp: opened_at + Duration::days(1),
+                    scheme: SignatureScheme::default(),
+                },
+                Signature {
+                    signer: "mallory".to_string(),
+                    signature: "cafebabe".to_string(),
+                    timestamp: opened_at + Duration::days(2),
+                    scheme: SignatureScheme::default(),
+                },
+            ],
+            governance_status: "pending".to_string(),
+            linked_prs: vec![],
+            emergency_mode: true,
+            created_at: opened_at,
+            updated_at: now,
+        };
+
+        let events = vec![
+            event(
+                1,
+                "pr_opened",
+                serde_json::json!({"tier": 3, "classification_confidence": 0.95, "classification_rationale": "Matched Consensus-Adjacent rule"}),
+                opened_at,
+            ),
+            event(2, "signature_collected", serde_json::json!({"verified": true}), opened_at + Duration::days(1)),
+            event(3, "emergency_activated", serde_json::json!({"tier": 1}), opened_at + Duration::days(3)),
+            event(4, "merge_blocked", serde_json::json!({"reason": "Signature threshold requirement not met"}), opened_at + Duration::days(4)),
+        ];
+
+        let economic_signals = vec![EconomicSignal {
+            node_id: 1,
+            entity_name: "Example Pool".to_string(),
+            signal_type: "veto".to_string(),
+            weight: 0.3,
+            rationale: "Insufficient testing".to_string(),
+            verified: true,
+            timestamp: opened_at + Duration::days(5),
+        }];
+
+        let mut keys = HashMap::new();
+        keys.insert("alice".to_string(), "02ffff".to_string());
+
+        PrGovernanceRecord::build(&pr, events, economic_signals, &keys, now)
+    }
+
+    #[test]
+    fn test_record_contains_all_sections() {
+        let record = mixed_state_record();
+
+        assert_eq!(record.classification.tier, 3);
+        assert_eq!(record.classification.rationale, "Matched Consensus-Adjacent rule");
+        assert_eq!(record.signatures.len(), 2);
+        assert_eq!(record.economic_signals.len(), 1);
+        assert_eq!(record.economic_signals[0].rationale, "Insufficient testing");
+        assert!(record.emergency.emergency_mode);
+        assert_eq!(record.emergency.events.len(), 1);
+        assert_eq!(record.decisions.len(), 4);
+
+        let json = serde_json::to_value(&record).unwrap();
+        for section in ["classification", "signatures", "review", "economic_signals", "emergency", "decisions"] {
+            assert!(json.get(section).is_some(), "missing section {}", section);
+        }
+    }
+
+    #[test]
+    fn test_record_is_internally_consistent() {
+        let record = mixed_state_record();
+
+        // Unknown signers and bad signatures are reported, never counted
+        assert_eq!(record.signatures[0].verification, SignatureVerification::Invalid);
+        assert_eq!(record.signatures[1].verification, SignatureVerification::UnknownSigner);
+        assert_eq!(record.review.signatures_verified, 0);
+        assert!(!record.review.threshold_met);
+
+        // Requirements follow the recorded classification and layer
+        let (required, total, days) = ThresholdValidator::get_combined_requirements(3, 3);
+        assert_eq!(record.review.signatures_required, required);
+        assert_eq!(record.review.signatures_total, total);
+        assert_eq!(record.review.review_period_days, days);
+        assert_eq!(
+            record.review.review_period_met,
+            record.generated_at >= record.review.earliest_merge
+        );
+
+        // Decision history is chronological and covers the emergency events
+        assert!(record.decisions.windows(2).all(|w| w[0].timestamp <= w[1].timestamp));
+        assert!(record
+            .emergency
+            .events
+            .iter()
+            .all(|e| record.decisions.iter().any(|d| d.id == e.id)));
+    }
+
+    #[test]
+    fn test_classification_follows_overrides_and_reclassification() {
+        let at = Utc::now() - Duration::days(10);
+        let mut events = vec![event(1, "pr_opened", serde_json::json!({"tier": 2}), at)];
+        events.push(event(2, "pr_reclassified", serde_json::json!({"tier": 3}), at + Duration::days(1)));
+        assert_eq!(PrGovernanceRecord::classification_from_events(&events).tier, 3);
+
+        // An override wins over later synchronizes until it's cleared
+        events.push(event(3, "tier_override", serde_json::json!({"tier": 5, "rationale": "governance change"}), at + Duration::days(2)));
+        events.push(event(4, "pr_synchronized", serde_json::json!({"tier": 2}), at + Duration::days(3)));
+        let classification = PrGovernanceRecord::classification_from_events(&events);
+        assert_eq!(classification.tier, 5);
+        assert_eq!(classification.rationale, "governance change");
+        assert_eq!(PrGovernanceRecord::auto_classification(&events).unwrap().tier, 2);
+
+        events.push(event(5, "tier_override_cleared", serde_json::json!({"rationale": "mistake"}), at + Duration::days(4)));
+        assert_eq!(PrGovernanceRecord::classification_from_events(&events).tier, 2);
+        assert!(PrGovernanceRecord::active_override(&events).is_none());
+
+        assert!(PrGovernanceRecord::recorded_classification(&[]).is_none());
+        assert_eq!(PrGovernanceRecord::classification_from_events(&[]).tier, 1);
+    }
+
+    #[test]
+    fn test_attestation_hash_and_signature() {
+        let record = mixed_state_record();
+        let expected_hash = record.record_hash().unwrap();
+
+        let unsigned = record.clone().attest("governance-01", None).unwrap();
+        assert_eq!(unsigned.attestation.record_hash, expected_hash);
+        assert!(unsigned.attestation.signature.is_none());
+
+        let key = SecretKey::from_slice(&[7u8; 32]).unwrap();
+        let signed = record.attest("governance-01", Some(&key)).unwrap();
+        assert_eq!(signed.attestation.record_hash, expected_hash);
+        assert!(signed.attestation.signature.is_some());
+        assert!(signed.attestation.public_key.is_some());
+    }
+}
@@ -0,0 +1,233 @@
+//! PR Governance Status
+//!
+//! Where a PR currently stands against its governance requirements, for
+//! block explorers, watchdogs and other dashboards. Unlike the governance
+//! record this is a point-in-time view and carries no event history.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use crate::config::loader::EconomicQuorums;
+use crate::database::models::{PullRequest, PullRequestStatus};
+use crate::database::Database;
+use crate::economic_nodes::types::VetoThreshold;
+use crate::economic_nodes::veto::VetoManager;
+use crate::enforcement::merge_block::{MergeBlocker, MergeDecision, MergeRequirements};
+use crate::enforcement::pr_record::PrGovernanceRecord;
+use crate::error::GovernanceError;
+use crate::validation::review_period::{ReviewPeriodPolicy, ReviewPeriodValidator};
+use crate::validation::threshold::ThresholdValidator;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SignatureProgress {
+    pub required: usize,
+    pub total: usize,
+    pub collected: usize,
+    /// Maintainers of the PR's layer who have signed
+    pub signers: Vec<String>,
+    /// Maintainers of the PR's layer who have not signed yet
+    pub pending: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReviewProgress {
+    pub opened_at: DateTime<Utc>,
+    pub required_days: i64,
+    pub elapsed_days: i64,
+    pub earliest_merge: DateTime<Utc>,
+    pub met: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VetoStatus {
+    pub active: bool,
+    pub quorum: u32,
+    pub signal_count: u32,
+    pub quorum_met: bool,
+    /// Per-category breakdown; absent where veto signals aren't tracked (PostgreSQL)
+    pub breakdown: Option<VetoThreshold>,
+}
+
+/// Current governance state of a single PR
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PrStatus {
+    pub repo_name: String,
+    pub pr_number: i32,
+    pub layer: i32,
+    pub head_sha: String,
+    pub tier: u32,
+    pub emergency_mode: bool,
+    pub signatures: SignatureProgress,
+    pub review: ReviewProgress,
+    pub veto: VetoStatus,
+    pub decision: MergeDecision,
+}
+
+impl PrStatus {
+    /// Evaluate the PR's current status, if the PR is known
+    pub async fn load(
+        database: &Database,
+        repo_name: &str,
+        pr_number: i32,
+        now: DateTime<Utc>,
+        review_period_policy: &ReviewPeriodPolicy,
+        economic_quorums: &EconomicQuorums,
+    ) -> Result<Option<Self>, GovernanceError> {
+        match database.get_pull_request(repo_name, pr_number).await? {
+            Some(pr) => Self::evaluate(database, pr, now, review_period_policy, economic_quorums)
+                .await
+                .map(Some),
+            None => Ok(None),
+        }
+    }
+
+    /// Evaluate the status of a PR row already read from the database
+    pub async fn evaluate(
+        database: &Database,
+        pr: PullRequest,
+        now: DateTime<Utc>,
+        review_period_policy: &ReviewPeriodPolicy,
+        economic_quorums: &EconomicQuorums,
+    ) -> Result<Self, GovernanceError> {
+        let events = database.get_pr_governance_events(&pr.repo_name, pr.pr_number).await?;
+        let tier = PrGovernanceRecord::classification_from_events(&events).tier;
+        let (required, total, required_days) =
+            ThresholdValidator::get_combined_requirements(pr.layer, tier);
+
+        // Only active maintainers of the PR's layer count, as for the status checks
+        let layer_maintainers: Vec<String> = database
+            .get_maintainers_for_layer(pr.layer)
+            .await?
+            .into_iter()
+            .map(|m| m.github_username)
+            .collect();
+        let signers: Vec<String> = pr
+            .signatures
+            .iter()
+            .map(|s| s.signer.clone())
+            .filter(|signer| layer_maintainers.contains(signer))
+            .collect();
+        let pending = layer_maintainers
+            .into_iter()
+            .filter(|username| !signers.contains(username))
+            .collect();
+        let signatures = SignatureProgress {
+            required,
+            total,
+            collected: signers.len(),
+            signers,
+            pending,
+        };
+
+        let earliest_merge = ReviewPeriodValidator::get_earliest_merge_date_with_policy(
+            pr.opened_at,
+            required_days,
+            pr.emergency_mode,
+            review_period_policy,
+        );
+        let review = ReviewProgress {
+            opened_at: pr.opened_at,
+            required_days,
+            elapsed_days: (now - pr.opened_at).num_days(),
+            earliest_merge,
+            met: now >= earliest_merge,
+        };
+
+        let breakdown = match database.pool() {
+            Some(pool) => Some(VetoManager::new(pool.clone()).check_veto_threshold_at(pr.id, now).await?),
+            None => None,
+        };
+        let quorum = economic_quorums.for_tier(tier);
+        let signal_count = database.count_economic_signals(pr.id).await?;
+        let veto = VetoStatus {
+            active: breakdown.as_ref().map(|b| b.veto_active).unwrap_or(false),
+            quorum,
+            signal_count,
+            quorum_met: ThresholdValidator::economic_quorum_met(tier, signal_count, quorum),
+            breakdown,
+        };
+
+        let decision = MergeBlocker::evaluate_merge(&MergeRequirements {
+            review_period_met: review.met,
+            current_signatures: signatures.collected,
+            required_signatures: required,
+            economic_veto_active: veto.active,
+            economic_quorum_met: veto.quorum_met,
+            cross_layer_synced: true,
+            tier,
+            emergency_mode: pr.emergency_mode,
+        });
+
+        Ok(Self {
+            repo_name: pr.repo_name,
+            pr_number: pr.pr_number,
+            layer: pr.layer,
+            head_sha: pr.head_sha,
+            tier,
+            emergency_mode: pr.emergency_mode,
+            signatures,
+            review,
+            veto,
+            decision,
+        })
+    }
+
+    /// Where a pending PR stands in `GET /prs` listings
+    pub fn listing_status(&self) -> PullRequestStatus {
+        if !self.review.met {
+            PullRequestStatus::Open
+        } else if self.decision.blocked {
+            PullRequestStatus::Blocked
+        } else {
+            PullRequestStatus::Mergeable
+        }
+    }
+}
+
+/// List tracked PRs with their listing status, oldest first
+///
+/// Pending PRs are evaluated as for `/prs/:repo/:number/status`, so a PR is
+/// only listed `mergeable` when its merge check would pass. Those statuses
+/// can't be told apart in SQL, so when filtering on one every pending row is
+/// evaluated and the page is taken afterwards.
+#[allow(clippy::too_many_arguments)]
+pub async fn list_pull_requests(
+    database: &Database,
+    repo_name: Option<&str>,
+    status: Option<PullRequestStatus>,
+    limit: i64,
+    offset: i64,
+    now: DateTime<Utc>,
+    review_period_policy: &ReviewPeriodPolicy,
+    economic_quorums: &EconomicQuorums,
+) -> Result<Vec<(PullRequest, PullRequestStatus)>, GovernanceError> {
+    let stored_status = status.map(|s| s.stored_status());
+    let derive = stored_status == Some("pending");
+    let (sql_limit, sql_offset) = if derive { (i64::MAX, 0) } else { (limit, offset) };
+
+    let mut listed = Vec::new();
+    for pr in database
+        .list_pull_requests(repo_name, stored_status, sql_limit, sql_offset)
+        .await?
+    {
+        let pr_status = match pr.governance_status.as_str() {
+            "pending" => {
+                PrStatus::evaluate(database, pr.clone(), now, review_period_policy, economic_quorums)
+                    .await?
+                    .listing_status()
+            }
+            "merged" => PullRequestStatus::Merged,
+            _ => PullRequestStatus::Closed,
+        };
+        listed.push((pr, pr_status));
+    }
+
+    if !derive {
+        return Ok(listed);
+    }
+    Ok(listed
+        .into_iter()
+        .filter(|(_, pr_status)| Some(*pr_status) == status)
+        .skip(offset.max(0) as usize)
+        .take(limit.max(0) as usize)
+        .collect())
+}
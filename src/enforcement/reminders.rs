@@ -0,0 +1,313 @@
+use chrono::{DateTime, Duration, Utc};
+use tracing::{info, warn};
+
+use crate::database::Database;
+use crate::enforcement::pr_record::PrGovernanceRecord;
+use crate::error::GovernanceError;
+use crate::github::client::GitHubClient;
+use crate::validation::review_period::{ReviewPeriodPolicy, ReviewPeriodValidator};
+use crate::validation::threshold::ThresholdValidator;
+
+/// Hidden marker identifying the app's reminder comment on a PR
+pub const REMINDER_MARKER: &str = "<!-- governance-app:signature-reminder -->";
+
+/// A reminder to be posted on a PR that is short of its signature threshold
+#[derive(Debug, Clone)]
+pub struct SignatureReminder {
+    pub repo_name: String,
+    pub pr_number: i32,
+    pub layer: i32,
+    pub tier: u32,
+    pub current_signatures: usize,
+    pub required_signatures: usize,
+    pub missing_signers: Vec<String>,
+    pub days_remaining: i64,
+}
+
+impl SignatureReminder {
+    /// Render the reminder as a PR comment body
+    pub fn render_comment(&self) -> String {
+        let mut body = format!(
+            "{}\n⏳ **Governance: Signatures Needed**\n\n\
+            This PR has {} of {} required signatures. The review period ends in {} days.\n",
+            REMINDER_MARKER,
+            self.current_signatures,
+            self.required_signatures,
+            self.days_remaining
+        );
+
+        if !self.missing_signers.is_empty() {
+            body.push_str("\nMaintainers who have not yet signed:\n");
+            for signer in &self.missing_signers {
+                body.push_str(&format!("- @{}\n", signer));
+            }
+        }
+
+        body.push_str("\nSign with `/governance-sign <signature>`.");
+        body
+    }
+}
+
+/// Finds PRs with progressing review periods that lack signatures and
+/// posts rate-limited reminder comments on them
+pub struct ReminderScheduler {
+    database: Database,
+    reminder_interval: Duration,
+    review_period_policy: ReviewPeriodPolicy,
+}
+
+impl ReminderScheduler {
+    pub fn new(database: Database, reminder_interval_hours: u64) -> Self {
+        Self {
+            database,
+            reminder_interval: Duration::try_hours(reminder_interval_hours as i64)
+                .unwrap_or_default(),
+            review_period_policy: ReviewPeriodPolicy::default(),
+        }
+    }
+
+    /// Measure review periods under `policy` when deciding whether a PR still needs reminding
+    pub fn with_review_period_policy(mut self, policy: ReviewPeriodPolicy) -> Self {
+        self.review_period_policy = policy;
+        self
+    }
+
+    /// Collect reminders due at `now`, skipping PRs reminded within the interval
+    pub async fn pending_reminders(
+        &self,
+        now: DateTime<Utc>,
+    ) -> Result<Vec<SignatureReminder>, GovernanceError> {
+        let mut reminders = Vec::new();
+
+        for pr in self.database.get_pending_pull_requests().await? {
+            let events = self
+                .database
+                .get_pr_governance_events(&pr.repo_name, pr.pr_number)
+                .await?;
+            let tier = PrGovernanceRecord::classification_from_events(&events).tier;
+            let (required, _total, required_days) =
+                ThresholdValidator::get_combined_requirements(pr.layer, tier);
+
+            // Only active maintainers of the PR's layer count, as for the status checks
+            let layer_maintainers: Vec<String> = self
+                .database
+                .get_maintainers_for_layer(pr.layer)
+                .await?
+                .into_iter()
+                .map(|m| m.github_username)
+                .collect();
+            let current_signatures = pr
+                .signatures
+                .iter()
+                .filter(|s| layer_maintainers.contains(&s.signer))
+                .count();
+            if current_signatures >= required {
+                continue;
+            }
+
+            let earliest_merge = ReviewPeriodValidator::get_earliest_merge_date_with_policy(
+                pr.opened_at,
+                required_days,
+                pr.emergency_mode,
+                &self.review_period_policy,
+            );
+            let days_remaining = (earliest_merge - now).num_days();
+            if days_remaining <= 0 {
+                continue;
+            }
+
+            if let Some(last) = self
+                .database
+                .get_last_reminder(&pr.repo_name, pr.pr_number)
+                .await?
+            {
+                if now - last < self.reminder_interval {
+                    continue;
+                }
+            }
+
+            let missing_signers = layer_maintainers
+                .into_iter()
+                .filter(|username| !pr.signatures.iter().any(|s| &s.signer == username))
+                .collect();
+
+            reminders.push(SignatureReminder {
+                repo_name: pr.repo_name,
+                pr_number: pr.pr_number,
+                layer: pr.layer,
+                tier,
+                current_signatures,
+                required_signatures: required,
+                missing_signers,
+                days_remaining,
+            });
+        }
+
+        Ok(reminders)
+    }
+
+    /// Record that a reminder was posted so it is not repeated within the interval
+    pub async fn mark_reminded(
+        &self,
+        reminder: &SignatureReminder,
+        comment_id: Option<u64>,
+        now: DateTime<Utc>,
+    ) -> Result<(), GovernanceError> {
+        self.database
+            .record_reminder(
+                &reminder.repo_name,
+                reminder.pr_number,
+                comment_id.map(|id| id as i64),
+                now,
+            )
+            .await
+    }
+
+    /// Post all due reminders via the GitHub client
+    pub async fn run_once(&self, github_client: &GitHubClient) -> Result<usize, GovernanceError> {
+        let now = Utc::now();
+        let reminders = self.pending_reminders(now).await?;
+        let mut posted = 0;
+
+        for reminder in &reminders {
+            let (owner, repo) = match reminder.repo_name.split_once('/') {
+                Some(parts) => parts,
+                None => {
+                    warn!("Skipping reminder for malformed repo name: {}", reminder.repo_name);
+                    continue;
+                }
+            };
+
+            match github_client
+                .upsert_pr_comment(
+                    owner,
+                    repo,
+                    reminder.pr_number as u64,
+                    REMINDER_MARKER,
+                    &reminder.render_comment(),
+                )
+                .await
+            {
+                Ok(comment_id) => {
+                    self.mark_reminded(reminder, Some(comment_id), now).await?;
+                    let _ = self
+                        .database
+                        .log_governance_event(
+                            "signature_reminder_sent",
+                            Some(&reminder.repo_name),
+                            Some(reminder.pr_number),
+                            None,
+                            &serde_json::json!({
+                                "tier": reminder.tier,
+                                "current_signatures": reminder.current_signatures,
+                                "required_signatures": reminder.required_signatures,
+                                "missing_signers": reminder.missing_signers,
+                                "days_remaining": reminder.days_remaining
+                            }),
+                        )
+                        .await;
+                    posted += 1;
+                }
+                Err(e) => {
+                    warn!(
+                        "Failed to post reminder on {}#{}: {}",
+                        reminder.repo_name, reminder.pr_number, e
+                    );
+                }
+            }
+        }
+
+        info!("Posted {} signature reminders", posted);
+        Ok(posted)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn setup() -> Database {
+        let db = Database::new_in_memory().await.unwrap();
+        let pool = db.pool().unwrap();
+        for name in ["alice", "bob", "carol", "dave", "erin"] {
+            sqlx::query("INSERT INTO maintainers (github_username, public_key, layer) VALUES (?, ?, 3)")
+                .bind(name)
+                .bind(format!("{}_key", name))
+                .execute(pool)
+                .await
+                .unwrap();
+        }
+        db.create_pull_request("BTCDecoded/protocol-engine", 42, "abc123", 3)
+            .await
+            .unwrap();
+        db
+    }
+
+    #[tokio::test]
+    async fn test_reminder_generated_for_pr_missing_signatures() {
+        let db = setup().await;
+        let scheduler = ReminderScheduler::new(db, 24);
+
+        let reminders = scheduler.pending_reminders(Utc::now()).await.unwrap();
+        assert_eq!(reminders.len(), 1);
+
+        let reminder = &reminders[0];
+        assert_eq!(reminder.pr_number, 42);
+        assert_eq!(reminder.current_signatures, 0);
+        assert_eq!(reminder.required_signatures, 4);
+        assert_eq!(reminder.missing_signers.len(), 5);
+        assert!(reminder.days_remaining > 0);
+
+        let body = reminder.render_comment();
+        assert!(body.contains(REMINDER_MARKER));
+        assert!(body.contains("@alice"));
+    }
+
+    #[tokio::test]
+    async fn test_reminder_not_reposted_within_interval() {
+        let db = setup().await;
+        let scheduler = ReminderScheduler::new(db, 24);
+        let now = Utc::now();
+
+        let reminders = scheduler.pending_reminders(now).await.unwrap();
+        assert_eq!(reminders.len(), 1);
+        scheduler.mark_reminded(&reminders[0], Some(1), now).await.unwrap();
+
+        let later = now + Duration::try_hours(1).unwrap();
+        assert!(scheduler.pending_reminders(later).await.unwrap().is_empty());
+
+        let after_interval = now + Duration::try_hours(25).unwrap();
+        assert_eq!(scheduler.pending_reminders(after_interval).await.unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_tier_3_reminder_uses_combined_requirements() {
+        let db = setup().await;
+        db.log_governance_event(
+            "pr_opened",
+            Some("BTCDecoded/protocol-engine"),
+            Some(42),
+            None,
+            &serde_json::json!({"tier": 3}),
+        )
+        .await
+        .unwrap();
+        // Four layer signatures meet the layer's 4-of-5 but not Tier 3's 5-of-5;
+        // a signer from outside the layer doesn't count
+        for signer in ["alice", "bob", "carol", "dave", "mallory"] {
+            db.add_signature("BTCDecoded/protocol-engine", 42, signer, "sig")
+                .await
+                .unwrap();
+        }
+        let scheduler = ReminderScheduler::new(db, 24);
+
+        let reminders = scheduler.pending_reminders(Utc::now()).await.unwrap();
+        assert_eq!(reminders.len(), 1);
+        let reminder = &reminders[0];
+        assert_eq!(reminder.tier, 3);
+        assert_eq!(reminder.current_signatures, 4);
+        assert_eq!(reminder.required_signatures, 5);
+        assert_eq!(reminder.missing_signers, vec!["erin".to_string()]);
+        assert!(reminder.days_remaining >= 89);
+    }
+}
@@ -1,8 +1,39 @@
+use crate::economic_nodes::types::VetoThreshold;
+use crate::enforcement::merge_block::{BlockReason, MergeDecision};
+use crate::github::types::{CheckRunAnnotation, CheckRunOutput};
 use crate::validation::emergency::{ActiveEmergency, EmergencyTier};
-use crate::validation::review_period::ReviewPeriodValidator;
+use crate::validation::review_period::{ReviewPeriodPolicy, ReviewPeriodValidator};
 use crate::validation::threshold::ThresholdValidator;
+use crate::validation::tier_classification::TierClassificationResult;
 use chrono::{DateTime, Utc};
 
+/// Marks the tier classification comment so it is edited rather than duplicated
+pub const TIER_COMMENT_MARKER: &str = "<!-- governance-app:tier-classification -->";
+
+/// Name of the check run carrying the merge decision
+pub const CHECK_RUN_NAME: &str = "governance";
+
+/// File governance annotations are attached to
+///
+/// GitHub requires every annotation to point at a file, but governance
+/// requirements belong to the PR as a whole.
+pub const CHECK_RUN_ANNOTATION_PATH: &str = "README.md";
+
+/// Per-requirement status texts shown in the check run's details
+#[derive(Debug, Clone)]
+pub struct CheckRunSections {
+    pub signatures: String,
+    pub review_period: String,
+    /// `None` below Tier 3, where no economic node veto applies
+    pub economic_veto: Option<String>,
+}
+
+/// GitHub rejects commit status descriptions longer than this
+const STATUS_DESCRIPTION_LIMIT: usize = 140;
+
+/// Matched signals listed in the tier comment before the rest are summarised
+const COMMENT_SIGNAL_LIMIT: usize = 10;
+
 pub struct StatusCheckGenerator;
 
 impl StatusCheckGenerator {
@@ -20,22 +51,38 @@ impl StatusCheckGenerator {
         emergency_mode: bool,
         dry_run: bool,
     ) -> String {
-        let remaining_days =
-            ReviewPeriodValidator::get_remaining_days(opened_at, required_days, emergency_mode);
+        Self::generate_review_period_status_with_policy(
+            opened_at,
+            required_days,
+            emergency_mode,
+            dry_run,
+            &ReviewPeriodPolicy::default(),
+        )
+    }
 
-        let prefix = if dry_run { "[DRY-RUN] " } else { "" };
+    /// Review period status with the end date computed under `policy`
+    pub fn generate_review_period_status_with_policy(
+        opened_at: DateTime<Utc>,
+        required_days: i64,
+        emergency_mode: bool,
+        dry_run: bool,
+        policy: &ReviewPeriodPolicy,
+    ) -> String {
+        let earliest_merge = ReviewPeriodValidator::get_earliest_merge_date_with_policy(
+            opened_at,
+            required_days,
+            emergency_mode,
+            policy,
+        );
 
-        if remaining_days > 0 {
-            let earliest_merge = ReviewPeriodValidator::get_earliest_merge_date(
-                opened_at,
-                required_days,
-                emergency_mode,
-            );
+        let prefix = if dry_run { "[DRY-RUN] " } else { "" };
 
+        if Utc::now() < earliest_merge {
             format!(
-                "{}❌ Governance: Review Period Not Met\nRequired: {} days | Elapsed: {} days\nEarliest merge: {}",
+                "{}❌ Governance: Review Period Not Met\nRequired: {} {} | Elapsed: {} days\nEarliest merge: {}",
                 prefix,
                 required_days,
+                policy.unit(),
                 (Utc::now() - opened_at).num_days(),
                 earliest_merge.format("%Y-%m-%d")
             )
@@ -101,8 +148,215 @@ impl StatusCheckGenerator {
         }
     }
 
+    /// Render a merge decision as a GitHub status body, one line per failed requirement
+    pub fn generate_merge_decision_status(decision: &MergeDecision) -> String {
+        if !decision.blocked {
+            return "✅ Governance: All Requirements Met - Ready to Merge".to_string();
+        }
+
+        let mut status = "❌ Governance: Merge Blocked".to_string();
+        for reason in &decision.reasons {
+            status.push_str(&format!("\n- {}", Self::block_reason_detail(reason)));
+        }
+        status
+    }
+
+    /// One-line explanation of a failed requirement
+    fn block_reason_detail(reason: &BlockReason) -> String {
+        match reason {
+            BlockReason::ReviewPeriodNotMet => "Review period has not elapsed".to_string(),
+            BlockReason::InsufficientSignatures { have, need } => format!(
+                "Signatures: {}/{} required ({} more needed)",
+                have,
+                need,
+                need.saturating_sub(*have)
+            ),
+            BlockReason::EconomicVetoActive => {
+                "Economic node veto active (30%+ hashpower or 40%+ economic activity)".to_string()
+            }
+            BlockReason::EconomicQuorumNotMet => {
+                "Economic node quorum not met (insufficient economic node signals)".to_string()
+            }
+            BlockReason::CrossLayerUnsynced => {
+                "Orange Paper and Consensus Proof changes are not synchronized".to_string()
+            }
+        }
+    }
+
+    /// Render a merge decision as check run output
+    ///
+    /// The per-requirement status texts become sections of the run's details,
+    /// and each reason the merge is blocked becomes a failure annotation.
+    pub fn generate_check_run_output(
+        decision: &MergeDecision,
+        sections: &CheckRunSections,
+    ) -> CheckRunOutput {
+        let title = if decision.blocked {
+            format!("Merge blocked: {}", decision.summary())
+        } else {
+            "All governance requirements met".to_string()
+        };
+
+        let cross_layer = if decision.reasons.contains(&BlockReason::CrossLayerUnsynced) {
+            format!("❌ {}", Self::block_reason_detail(&BlockReason::CrossLayerUnsynced))
+        } else {
+            "✅ No unsynchronized cross-layer changes".to_string()
+        };
+        let text = format!(
+            "## Signatures\n\n{}\n\n## Review Period\n\n{}\n\n## Economic Node Veto\n\n{}\n\n## Cross-Layer Sync\n\n{}",
+            sections.signatures,
+            sections.review_period,
+            sections
+                .economic_veto
+                .as_deref()
+                .unwrap_or("Not required below Tier 3"),
+            cross_layer
+        );
+
+        let annotations = decision
+            .reasons
+            .iter()
+            .map(|reason| CheckRunAnnotation {
+                path: CHECK_RUN_ANNOTATION_PATH.to_string(),
+                start_line: 1,
+                end_line: 1,
+                annotation_level: "failure".to_string(),
+                title: Some(reason.to_string()),
+                message: Self::block_reason_detail(reason),
+            })
+            .collect();
+
+        CheckRunOutput {
+            title,
+            summary: Self::generate_merge_decision_status(decision),
+            text: Some(text),
+            annotations,
+        }
+    }
+
+    /// Human-readable name of a tier
+    pub fn tier_name(tier: u32) -> &'static str {
+        match tier {
+            1 => "Routine Maintenance",
+            2 => "Feature Changes",
+            3 => "Consensus-Adjacent",
+            4 => "Emergency Actions",
+            5 => "Governance Changes",
+            _ => "Unknown",
+        }
+    }
+
+    /// One-line status description explaining a PR's tier classification
+    ///
+    /// Lists as many matched signals as fit in a commit status description,
+    /// then counts the rest.
+    pub fn generate_tier_status(result: &TierClassificationResult) -> String {
+        let mut status = format!(
+            "Tier {}: {} ({:.0}% confidence)",
+            result.tier,
+            Self::tier_name(result.tier),
+            result.confidence * 100.0
+        );
+
+        let signals = Self::tier_signals(result);
+        if signals.is_empty() {
+            return status;
+        }
+
+        status.push_str(" - ");
+        let mut shown = 0;
+        for (i, signal) in signals.iter().enumerate() {
+            let separator = if i == 0 { "" } else { ", " };
+            let remaining = signals.len() - i - 1;
+            let more = if remaining > 0 {
+                format!(" +{} more", remaining)
+            } else {
+                String::new()
+            };
+            let fits = status.chars().count() + separator.len() + signal.chars().count() + more.len()
+                <= STATUS_DESCRIPTION_LIMIT;
+            if !fits {
+                break;
+            }
+            status.push_str(separator);
+            status.push_str(signal);
+            shown += 1;
+        }
+        if shown == 0 {
+            // Not even one signal fits; fall back to a count
+            status.truncate(status.len() - " - ".len());
+            status.push_str(&format!(" - {} matched signals", signals.len()));
+        } else if shown < signals.len() {
+            status.push_str(&format!(" +{} more", signals.len() - shown));
+        }
+        status
+    }
+
+    /// PR comment explaining a tier classification and how to contest it
+    pub fn generate_tier_comment(result: &TierClassificationResult) -> String {
+        let mut comment = format!(
+            "{}\n### Governance tier: Tier {} ({})\n\n**Confidence:** {:.0}%\n\n",
+            TIER_COMMENT_MARKER,
+            result.tier,
+            Self::tier_name(result.tier),
+            result.confidence * 100.0
+        );
+
+        let signals = Self::tier_signals(result);
+        if signals.is_empty() {
+            comment.push_str("No file patterns or keywords matched; this is the default tier.\n");
+        } else {
+            comment.push_str("**Matched signals:**\n");
+            for signal in signals.iter().take(COMMENT_SIGNAL_LIMIT) {
+                comment.push_str(&format!("- {}\n", signal));
+            }
+            if signals.len() > COMMENT_SIGNAL_LIMIT {
+                comment.push_str(&format!(
+                    "- ...and {} more\n",
+                    signals.len() - COMMENT_SIGNAL_LIMIT
+                ));
+            }
+        }
+
+        if !result.rationale.is_empty() {
+            comment.push_str(&format!("\n**Rationale:** {}\n", result.rationale));
+        }
+        comment.push_str(
+            "\nIf this tier looks wrong, ask a maintainer to apply a manual tier override \
+             with a rationale, or push changes and request reclassification.\n",
+        );
+        comment
+    }
+
+    /// Matched patterns and keywords in a readable form
+    ///
+    /// Patterns are recorded as `pattern:file` and keywords as `field:keyword`.
+    fn tier_signals(result: &TierClassificationResult) -> Vec<String> {
+        let files = result
+            .matched_patterns
+            .iter()
+            .map(|m| match m.split_once(':') {
+                Some((_, file)) => file.to_string(),
+                None => m.clone(),
+            });
+        let keywords = result
+            .matched_keywords
+            .iter()
+            .map(|m| match m.split_once(':') {
+                Some((field, keyword)) => format!("\"{}\" in {}", keyword, field),
+                None => format!("\"{}\"", m),
+            });
+        let mut signals: Vec<String> = Vec::new();
+        for signal in files.chain(keywords) {
+            if !signals.contains(&signal) {
+                signals.push(signal);
+            }
+        }
+        signals
+    }
+
     /// Generate status check with tier classification and economic node veto status
-    pub fn generate_tier_status(
+    pub fn generate_tier_requirements_status(
         tier: u32,
         tier_name: &str,
         review_period_met: bool,
@@ -170,6 +424,42 @@ impl StatusCheckGenerator {
         }
     }
 
+    /// Generate economic node quorum status
+    pub fn generate_economic_quorum_status(signal_count: u32, quorum: u32) -> String {
+        if signal_count >= quorum {
+            format!(
+                "✅ Economic Node Quorum: Met\n\
+                Signals: {}/{} required (support or veto)",
+                signal_count, quorum
+            )
+        } else {
+            format!(
+                "❌ Economic Node Quorum: Not Met\n\
+                Signals: {}/{} required (support or veto)",
+                signal_count, quorum
+            )
+        }
+    }
+
+    /// Generate economic node veto status
+    pub fn generate_economic_veto_status(threshold: &VetoThreshold) -> String {
+        if threshold.veto_active {
+            format!(
+                "❌ Economic Node Veto: Active\n\
+                Mining: {:.1}% of hashpower, Economic: {:.1}% of economic activity",
+                threshold.mining_veto_percent, threshold.economic_veto_percent
+            )
+        } else if threshold.active_signals == 0 {
+            "✅ Economic Node Veto: No veto signals received".to_string()
+        } else {
+            format!(
+                "✅ Economic Node Veto: Below threshold\n\
+                Mining: {:.1}% of hashpower, Economic: {:.1}% of economic activity",
+                threshold.mining_veto_percent, threshold.economic_veto_percent
+            )
+        }
+    }
+
     /// Generate detailed status with all governance requirements
     pub fn generate_detailed_status(
         tier: u32,
@@ -182,7 +472,7 @@ impl StatusCheckGenerator {
         economic_veto_status: &str,
         documentation_link: Option<&str>,
     ) -> String {
-        let mut status = Self::generate_tier_status(
+        let mut status = Self::generate_tier_requirements_status(
             tier,
             tier_name,
             review_period_met,
@@ -386,3 +676,86 @@ impl StatusCheckGenerator {
         status
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn classified(tier: u32, patterns: &[&str], keywords: &[&str]) -> TierClassificationResult {
+        TierClassificationResult {
+            tier,
+            confidence: 0.85,
+            matched_patterns: patterns.iter().map(|p| p.to_string()).collect(),
+            matched_keywords: keywords.iter().map(|k| k.to_string()).collect(),
+            rationale: "Matched consensus-adjacent rules".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_tier_status_names_tier_confidence_and_signal() {
+        let result = classified(3, &["src/consensus/**:src/consensus/block.rs"], &["title:consensus"]);
+        let status = StatusCheckGenerator::generate_tier_status(&result);
+        assert!(status.starts_with("Tier 3: Consensus-Adjacent (85% confidence)"), "{}", status);
+        assert!(status.contains("src/consensus/block.rs"), "{}", status);
+        assert!(status.contains("\"consensus\" in title"), "{}", status);
+    }
+
+    #[test]
+    fn test_tier_status_truncates_long_match_lists() {
+        let patterns: Vec<String> = (0..30)
+            .map(|i| format!("src/consensus/**:src/consensus/module_{}.rs", i))
+            .collect();
+        let patterns: Vec<&str> = patterns.iter().map(String::as_str).collect();
+        let status = StatusCheckGenerator::generate_tier_status(&classified(3, &patterns, &[]));
+        assert!(status.chars().count() <= STATUS_DESCRIPTION_LIMIT, "{}", status);
+        assert!(status.contains("src/consensus/module_0.rs"), "{}", status);
+        assert!(status.contains("more"), "{}", status);
+    }
+
+    #[test]
+    fn test_economic_veto_status_reports_active_veto() {
+        let mut threshold = VetoThreshold {
+            mining_veto_percent: 35.0,
+            economic_veto_percent: 10.0,
+            threshold_met: true,
+            veto_active: true,
+            mining: Default::default(),
+            economic: Default::default(),
+            active_signals: 4,
+            expired_signals: 0,
+        };
+        let status = StatusCheckGenerator::generate_economic_veto_status(&threshold);
+        assert!(status.starts_with("❌ Economic Node Veto: Active"), "{}", status);
+        assert!(status.contains("35.0% of hashpower"), "{}", status);
+
+        threshold.veto_active = false;
+        threshold.active_signals = 0;
+        let status = StatusCheckGenerator::generate_economic_veto_status(&threshold);
+        assert!(status.contains("No veto signals received"), "{}", status);
+    }
+
+    #[test]
+    fn test_review_status_shows_business_day_end_date() {
+        let opened_at = Utc::now();
+        let policy = ReviewPeriodPolicy::business_days(vec![]);
+        let status = StatusCheckGenerator::generate_review_period_status_with_policy(
+            opened_at, 7, false, false, &policy,
+        );
+        let end = ReviewPeriodValidator::get_earliest_merge_date_with_policy(opened_at, 7, false, &policy);
+        assert!(status.contains("Required: 7 business days"), "{}", status);
+        assert!(status.contains(&format!("Earliest merge: {}", end.format("%Y-%m-%d"))), "{}", status);
+    }
+
+    #[test]
+    fn test_tier_comment_explains_classification() {
+        let patterns: Vec<String> = (0..15).map(|i| format!("docs/**:docs/page_{}.md", i)).collect();
+        let patterns: Vec<&str> = patterns.iter().map(String::as_str).collect();
+        let comment = StatusCheckGenerator::generate_tier_comment(&classified(1, &patterns, &["body:typo"]));
+        assert!(comment.starts_with(TIER_COMMENT_MARKER));
+        assert!(comment.contains("Tier 1 (Routine Maintenance)"));
+        assert!(comment.contains("**Confidence:** 85%"));
+        assert!(comment.contains("- docs/page_0.md"));
+        assert!(comment.contains("...and 6 more"));
+        assert!(comment.contains("manual tier override"));
+    }
+}
@@ -0,0 +1,201 @@
+//! Governance Violation Alerts
+//!
+//! A repo admin can merge a PR on GitHub while governance still blocks it.
+//! When the merge arrives as a `closed` webhook the violation is recorded in
+//! `governance_events` and then reported to every configured sink, so it is
+//! visible outside this server's database too.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::sync::Arc;
+use tracing::{error, info, warn};
+
+use crate::audit::AuditLogger;
+use crate::database::models::PostedMergeDecision;
+use crate::database::Database;
+use crate::enforcement::merge_block::BlockReason;
+use crate::error::GovernanceError;
+use crate::nostr::client::{NostrClient, PublishError};
+use crate::nostr::events::violation_event;
+
+/// Event type used for violations in `governance_events` and the audit log
+pub const VIOLATION_EVENT_TYPE: &str = "governance_violation";
+
+/// Sink names accepted in `VIOLATION_SINKS`
+pub const SINK_NAMES: [&str; 2] = ["nostr", "audit"];
+
+/// A merge that went through while governance blocked it
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GovernanceViolation {
+    pub severity: String,
+    pub violation: String,
+    pub repo_name: String,
+    pub pr_number: i32,
+    pub merged_by: Option<String>,
+    pub merge_commit_sha: Option<String>,
+    /// Head the blocking decision was made for
+    pub head_sha: String,
+    pub tier: u32,
+    pub reasons: Vec<BlockReason>,
+    pub detected_at: DateTime<Utc>,
+}
+
+impl GovernanceViolation {
+    pub fn merged_while_blocked(
+        repo_name: &str,
+        pr_number: i32,
+        posted: &PostedMergeDecision,
+        merged_by: Option<&str>,
+        merge_commit_sha: Option<&str>,
+    ) -> Self {
+        Self {
+            severity: "high".to_string(),
+            violation: "merged_while_blocked".to_string(),
+            repo_name: repo_name.to_string(),
+            pr_number,
+            merged_by: merged_by.map(str::to_string),
+            merge_commit_sha: merge_commit_sha.map(str::to_string),
+            head_sha: posted.head_sha.clone(),
+            tier: posted.tier,
+            reasons: posted.decision.reasons.clone(),
+            detected_at: Utc::now(),
+        }
+    }
+
+    pub fn summary(&self) -> String {
+        format!(
+            "{}#{} merged by {} while governance blocked it ({} unmet requirement{})",
+            self.repo_name,
+            self.pr_number,
+            self.merged_by.as_deref().unwrap_or("unknown"),
+            self.reasons.len(),
+            if self.reasons.len() == 1 { "" } else { "s" }
+        )
+    }
+}
+
+/// Somewhere a violation is reported besides `governance_events`
+#[derive(Clone)]
+pub enum ViolationSink {
+    /// Publish a signed alert event to the server's relays
+    Nostr(Arc<NostrClient>),
+    /// Append a hash-chained entry to the audit log
+    Audit(AuditLogger),
+}
+
+impl ViolationSink {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Nostr(_) => "nostr",
+            Self::Audit(_) => "audit",
+        }
+    }
+
+    async fn notify(&self, server_id: &str, violation: &GovernanceViolation) -> Result<(), GovernanceError> {
+        match self {
+            Self::Nostr(client) => {
+                let event = violation_event(violation, server_id, &client.keys)
+                    .map_err(|e| GovernanceError::CryptoError(e.to_string()))?;
+                match client.publish_event(event).await {
+                    Ok(_) => Ok(()),
+                    Err(e @ PublishError::PartialFailure(_)) => {
+                        warn!("Published violation alert with relay failures: {}", e);
+                        Ok(())
+                    }
+                    Err(e) => Err(GovernanceError::ConfigError(format!(
+                        "Failed to publish violation alert: {}",
+                        e
+                    ))),
+                }
+            }
+            Self::Audit(logger) => {
+                let inputs_hash = format!(
+                    "sha256:{}",
+                    hex::encode(Sha256::digest(violation.head_sha.as_bytes()))
+                );
+                let outputs_hash = format!(
+                    "sha256:{}",
+                    hex::encode(Sha256::digest(serde_json::to_vec(violation)?))
+                );
+
+                let mut metadata = HashMap::new();
+                metadata.insert("repo_name".to_string(), violation.repo_name.clone());
+                metadata.insert("pr_number".to_string(), violation.pr_number.to_string());
+                metadata.insert("severity".to_string(), violation.severity.clone());
+                metadata.insert("violation".to_string(), violation.violation.clone());
+                if let Some(sha) = &violation.merge_commit_sha {
+                    metadata.insert("merge_commit_sha".to_string(), sha.clone());
+                }
+
+                logger
+                    .append(
+                        format!("{}-{}#{}", VIOLATION_EVENT_TYPE, violation.repo_name, violation.pr_number),
+                        VIOLATION_EVENT_TYPE.to_string(),
+                        server_id.to_string(),
+                        inputs_hash,
+                        outputs_hash,
+                        metadata,
+                    )
+                    .await
+                    .map_err(|e| GovernanceError::DatabaseError(format!("Failed to write audit entry: {}", e)))?;
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Records violations and fans them out to the configured sinks
+#[derive(Clone, Default)]
+pub struct ViolationNotifier {
+    server_id: String,
+    sinks: Vec<ViolationSink>,
+}
+
+impl ViolationNotifier {
+    pub fn new(server_id: &str) -> Self {
+        Self {
+            server_id: server_id.to_string(),
+            sinks: Vec::new(),
+        }
+    }
+
+    pub fn with_sink(mut self, sink: ViolationSink) -> Self {
+        self.sinks.push(sink);
+        self
+    }
+
+    pub fn sink_names(&self) -> Vec<&'static str> {
+        self.sinks.iter().map(ViolationSink::name).collect()
+    }
+
+    /// Record the violation, then notify each sink
+    ///
+    /// Only the `governance_events` write can fail the call; a sink that is
+    /// down is logged so one unreachable relay doesn't hide the others.
+    pub async fn report(
+        &self,
+        database: &Database,
+        violation: &GovernanceViolation,
+    ) -> Result<(), GovernanceError> {
+        error!("Governance violation: {}", violation.summary());
+        database
+            .log_governance_event(
+                VIOLATION_EVENT_TYPE,
+                Some(&violation.repo_name),
+                Some(violation.pr_number),
+                violation.merged_by.as_deref(),
+                &serde_json::to_value(violation)?,
+            )
+            .await?;
+
+        for sink in &self.sinks {
+            match sink.notify(&self.server_id, violation).await {
+                Ok(()) => info!("Reported violation on {}#{} to {}", violation.repo_name, violation.pr_number, sink.name()),
+                Err(e) => warn!("Failed to report violation to {}: {}", sink.name(), e),
+            }
+        }
+        Ok(())
+    }
+}
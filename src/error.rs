@@ -1,3 +1,6 @@
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Json, Response};
+use serde_json::Value;
 use thiserror::Error;
 
 impl From<serde_json::Error> for GovernanceError {
@@ -8,7 +11,16 @@ impl From<serde_json::Error> for GovernanceError {
 
 impl From<sqlx::Error> for GovernanceError {
     fn from(err: sqlx::Error) -> Self {
-        Self::DatabaseError(format!("Database error: {}", err))
+        match &err {
+            sqlx::Error::RowNotFound => Self::NotFound {
+                entity: "row".to_string(),
+                id: "query result".to_string(),
+            },
+            sqlx::Error::Database(db_err) if db_err.is_unique_violation() => {
+                Self::Conflict(db_err.message().to_string())
+            }
+            _ => Self::DatabaseError(format!("Database error: {}", err)),
+        }
     }
 }
 
@@ -40,6 +52,88 @@ pub enum GovernanceError {
 
     #[error("Threshold not satisfied: {0}")]
     ThresholdError(String),
+
+    #[error("{entity} not found: {id}")]
+    NotFound { entity: String, id: String },
+
+    #[error("Unauthorized: {0}")]
+    Unauthorized(String),
+
+    /// An upstream API refused the request; `retry_after` is in seconds when known
+    #[error("Rate limited: {message}")]
+    RateLimited {
+        message: String,
+        retry_after: Option<u64>,
+    },
+
+    #[error("Conflict: {0}")]
+    Conflict(String),
+}
+
+impl GovernanceError {
+    pub fn not_found(entity: &str, id: impl std::fmt::Display) -> Self {
+        Self::NotFound {
+            entity: entity.to_string(),
+            id: id.to_string(),
+        }
+    }
+
+    /// HTTP status a handler should answer with when a request fails with this error
+    pub fn status_code(&self) -> StatusCode {
+        match self {
+            Self::NotFound { .. } => StatusCode::NOT_FOUND,
+            Self::Unauthorized(_) => StatusCode::UNAUTHORIZED,
+            Self::RateLimited { .. } => StatusCode::TOO_MANY_REQUESTS,
+            Self::Conflict(_) => StatusCode::CONFLICT,
+            Self::ValidationError(_) | Self::WebhookError(_) | Self::SignatureError(_) => {
+                StatusCode::BAD_REQUEST
+            }
+            Self::ReviewPeriodError(_) | Self::ThresholdError(_) => StatusCode::UNPROCESSABLE_ENTITY,
+            Self::GitHubError(_) => StatusCode::BAD_GATEWAY,
+            // Database failures are usually a locked or unreachable store; worth retrying
+            Self::ConfigError(_) | Self::DatabaseError(_) => StatusCode::SERVICE_UNAVAILABLE,
+            Self::CryptoError(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+
+    /// Machine-readable code for the `code` field of an error body
+    pub fn error_code(&self) -> &'static str {
+        match self {
+            Self::NotFound { .. } => "NOT_FOUND",
+            Self::Unauthorized(_) => "AUTHENTICATION_ERROR",
+            Self::RateLimited { .. } => "RATE_LIMITED",
+            Self::Conflict(_) => "CONFLICT",
+            Self::ValidationError(_) | Self::WebhookError(_) | Self::SignatureError(_) => {
+                "VALIDATION_ERROR"
+            }
+            Self::ReviewPeriodError(_) | Self::ThresholdError(_) => "REQUIREMENT_NOT_MET",
+            Self::GitHubError(_) => "UPSTREAM_ERROR",
+            Self::ConfigError(_) | Self::DatabaseError(_) => "SERVICE_UNAVAILABLE",
+            Self::CryptoError(_) => "INTERNAL_ERROR",
+        }
+    }
+
+    /// Status and `{"error", "code"}` body for handlers that answer with a tuple
+    pub fn to_response(&self) -> (StatusCode, Json<Value>) {
+        let mut body = serde_json::json!({
+            "error": self.to_string(),
+            "code": self.error_code(),
+        });
+        if let Self::RateLimited {
+            retry_after: Some(seconds),
+            ..
+        } = self
+        {
+            body["retry_after"] = serde_json::json!(seconds);
+        }
+        (self.status_code(), Json(body))
+    }
+}
+
+impl IntoResponse for GovernanceError {
+    fn into_response(self) -> Response {
+        self.to_response().into_response()
+    }
 }
 
 // Type alias for compatibility with emergency module
@@ -129,3 +223,67 @@ pub struct MaxExtensionsReachedArgs {
     pub current: u32,
     pub max: u32,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_status_code_mapping() {
+        assert_eq!(GovernanceError::not_found("pull request", "BTCDecoded/governance#7").status_code(), StatusCode::NOT_FOUND);
+        assert_eq!(GovernanceError::Unauthorized("bad token".to_string()).status_code(), StatusCode::UNAUTHORIZED);
+        assert_eq!(
+            GovernanceError::RateLimited { message: "secondary rate limit".to_string(), retry_after: Some(30) }.status_code(),
+            StatusCode::TOO_MANY_REQUESTS
+        );
+        assert_eq!(GovernanceError::Conflict("duplicate signature".to_string()).status_code(), StatusCode::CONFLICT);
+        assert_eq!(GovernanceError::WebhookError("missing repository".to_string()).status_code(), StatusCode::BAD_REQUEST);
+        assert_eq!(GovernanceError::GitHubError("HTTP 502".to_string()).status_code(), StatusCode::BAD_GATEWAY);
+        assert_eq!(GovernanceError::DatabaseError("database is locked".to_string()).status_code(), StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(GovernanceError::CryptoError("bad key".to_string()).status_code(), StatusCode::INTERNAL_SERVER_ERROR);
+    }
+
+    #[test]
+    fn test_response_body_carries_code() {
+        let (status, body) = GovernanceError::RateLimited {
+            message: "secondary rate limit".to_string(),
+            retry_after: Some(30),
+        }
+        .to_response();
+        assert_eq!(status, StatusCode::TOO_MANY_REQUESTS);
+        assert_eq!(body.0["code"], "RATE_LIMITED");
+        assert_eq!(body.0["error"], "Rate limited: secondary rate limit");
+        assert_eq!(body.0["retry_after"], 30);
+
+        let response = GovernanceError::WebhookError("missing pull_request.number".to_string()).into_response();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[test]
+    fn test_not_found_message_names_entity() {
+        let err = GovernanceError::not_found("Economic node", 42);
+        assert_eq!(err.to_string(), "Economic node not found: 42");
+    }
+
+    #[tokio::test]
+    async fn test_sqlx_errors_keep_their_kind() {
+        let db = crate::database::Database::new_in_memory().await.unwrap();
+        let pool = db.pool().unwrap();
+
+        let missing: GovernanceError = sqlx::query("SELECT 1 WHERE 0")
+            .fetch_one(pool)
+            .await
+            .unwrap_err()
+            .into();
+        assert!(matches!(missing, GovernanceError::NotFound { .. }));
+
+        sqlx::query("CREATE TABLE t (k TEXT PRIMARY KEY)").execute(pool).await.unwrap();
+        sqlx::query("INSERT INTO t VALUES ('a')").execute(pool).await.unwrap();
+        let duplicate: GovernanceError = sqlx::query("INSERT INTO t VALUES ('a')")
+            .execute(pool)
+            .await
+            .unwrap_err()
+            .into();
+        assert_eq!(duplicate.status_code(), StatusCode::CONFLICT);
+    }
+}
@@ -0,0 +1,217 @@
+//! Governance Event Stream
+//!
+//! `GET /events/stream` pushes governance events to transparency UIs as
+//! Server-Sent Events the moment they are logged. Each event carries its id
+//! as the SSE id, so a client reconnecting with `Last-Event-ID` (or `?since=`)
+//! first gets what it missed from the database, then the live feed.
+//!
+//! The logging path never waits on subscribers. A client that reads too
+//! slowly to keep up is sent a `lagged` notice and disconnected, and picks up
+//! from its last event id when it reconnects.
+
+use axum::{
+    extract::{Query, State},
+    http::HeaderMap,
+    response::sse::{Event, KeepAlive, Sse},
+};
+use futures::stream::{self, Stream};
+use serde::Deserialize;
+use std::collections::VecDeque;
+use tokio::sync::broadcast::{self, error::RecvError};
+use tracing::{error, warn};
+
+use crate::config::AppConfig;
+use crate::database::models::GovernanceEvent;
+use crate::database::Database;
+use crate::metrics::Metrics;
+
+/// Events fetched from the database per replay page
+const REPLAY_PAGE_SIZE: i64 = 200;
+
+#[derive(Debug, Deserialize)]
+pub struct EventStreamQuery {
+    /// Replay events with an id above this before going live
+    pub since: Option<i64>,
+}
+
+struct StreamState {
+    database: Database,
+    receiver: broadcast::Receiver<GovernanceEvent>,
+    backlog: VecDeque<GovernanceEvent>,
+    /// Id of the last event sent; live events at or below it were replayed already
+    last_id: i64,
+    replaying: bool,
+    done: bool,
+}
+
+fn governance_event(event: &GovernanceEvent) -> Result<Event, axum::Error> {
+    Event::default()
+        .id(event.id.to_string())
+        .event("governance_event")
+        .json_data(event)
+}
+
+/// Handle `GET /events/stream?since=`
+pub async fn event_stream_endpoint(
+    State((_config, database, _metrics)): State<(AppConfig, Database, Metrics)>,
+    Query(query): Query<EventStreamQuery>,
+    headers: HeaderMap,
+) -> Sse<impl Stream<Item = Result<Event, axum::Error>>> {
+    let since = query.since.or_else(|| {
+        headers
+            .get("last-event-id")
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| v.parse().ok())
+    });
+
+    // Subscribe before replaying so nothing logged in between is missed
+    let state = StreamState {
+        receiver: database.subscribe_governance_events(),
+        database,
+        backlog: VecDeque::new(),
+        last_id: since.unwrap_or(0),
+        replaying: since.is_some(),
+        done: false,
+    };
+    Sse::new(stream::unfold(state, next_event)).keep_alive(KeepAlive::default())
+}
+
+async fn next_event(mut state: StreamState) -> Option<(Result<Event, axum::Error>, StreamState)> {
+    if state.done {
+        return None;
+    }
+
+    while state.replaying && state.backlog.is_empty() {
+        match state.database.get_governance_events_after(state.last_id, REPLAY_PAGE_SIZE).await {
+            Ok(page) if page.is_empty() => state.replaying = false,
+            Ok(page) => state.backlog.extend(page),
+            Err(e) => {
+                error!("Failed to replay governance events after {}: {}", state.last_id, e);
+                return None;
+            }
+        }
+    }
+    if let Some(event) = state.backlog.pop_front() {
+        state.last_id = event.id as i64;
+        return Some((governance_event(&event), state));
+    }
+
+    loop {
+        match state.receiver.recv().await {
+            Ok(event) if (event.id as i64) <= state.last_id => continue,
+            Ok(event) => {
+                state.last_id = event.id as i64;
+                return Some((governance_event(&event), state));
+            }
+            Err(RecvError::Lagged(skipped)) => {
+                warn!("Dropping governance event stream client that lagged by {} events", skipped);
+                state.done = true;
+                let notice = Event::default().event("lagged").json_data(serde_json::json!({
+                    "skipped": skipped,
+                    "last_event_id": state.last_id,
+                }));
+                return Some((notice, state));
+            }
+            Err(RecvError::Closed) => return None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::GOVERNANCE_EVENT_CHANNEL_CAPACITY;
+    use axum::body::Body;
+    use axum::http::Request;
+    use axum::routing::get;
+    use axum::Router;
+    use futures::StreamExt;
+    use std::time::Duration;
+    use tower::ServiceExt;
+
+    async fn open_stream(database: &Database, uri: &str) -> axum::body::BodyDataStream {
+        let app = Router::new()
+            .route("/events/stream", get(event_stream_endpoint))
+            .with_state((AppConfig::load().unwrap(), database.clone(), Metrics::new()));
+        let response = app
+            .oneshot(Request::get(uri).body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        assert_eq!(response.headers()["content-type"], "text/event-stream");
+        response.into_body().into_data_stream()
+    }
+
+    /// Read from the stream until `needle` shows up
+    async fn read_until(body: &mut axum::body::BodyDataStream, needle: &str) -> String {
+        let mut received = String::new();
+        tokio::time::timeout(Duration::from_secs(5), async {
+            while !received.contains(needle) {
+                let chunk = body.next().await.expect("stream ended").unwrap();
+                received.push_str(&String::from_utf8_lossy(&chunk));
+            }
+        })
+        .await
+        .unwrap_or_else(|_| panic!("{:?} not received, got {:?}", needle, received));
+        received
+    }
+
+    #[tokio::test]
+    async fn test_logged_event_is_streamed() {
+        let database = Database::new_in_memory().await.unwrap();
+        let mut body = open_stream(&database, "/events/stream").await;
+
+        database
+            .log_governance_event("pr_opened", Some("BTCDecoded/sdk"), Some(7), None, &serde_json::json!({}))
+            .await
+            .unwrap();
+
+        let received = read_until(&mut body, "pr_opened").await;
+        assert!(received.contains("event: governance_event"));
+        assert!(received.contains("id: 1"));
+        assert!(received.contains("\"pr_number\":7"));
+    }
+
+    #[tokio::test]
+    async fn test_since_replays_missed_events() {
+        let database = Database::new_in_memory().await.unwrap();
+        for event_type in ["first_event", "second_event"] {
+            database
+                .log_governance_event(event_type, None, None, None, &serde_json::json!({}))
+                .await
+                .unwrap();
+        }
+
+        let mut body = open_stream(&database, "/events/stream?since=1").await;
+        database
+            .log_governance_event("live_event", None, None, None, &serde_json::json!({}))
+            .await
+            .unwrap();
+
+        let received = read_until(&mut body, "live_event").await;
+        assert!(!received.contains("first_event"));
+        assert!(received.find("second_event").unwrap() < received.find("live_event").unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_slow_client_is_dropped_without_stalling_writer() {
+        let database = Database::new_in_memory().await.unwrap();
+        let mut body = open_stream(&database, "/events/stream").await;
+
+        // Nobody reads the stream while the channel overflows
+        let overflow = GOVERNANCE_EVENT_CHANNEL_CAPACITY + 10;
+        tokio::time::timeout(Duration::from_secs(10), async {
+            for _ in 0..overflow {
+                database
+                    .log_governance_event("signature_added", None, None, None, &serde_json::json!({}))
+                    .await
+                    .unwrap();
+            }
+        })
+        .await
+        .expect("writer stalled on a slow subscriber");
+
+        let received = read_until(&mut body, "event: lagged").await;
+        assert!(received.contains("\"skipped\":10"));
+        assert!(body.next().await.is_none());
+    }
+}
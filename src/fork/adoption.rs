@@ -9,6 +9,12 @@ use tracing::info;
 use super::types::*;
 use crate::error::GovernanceError;
 
+/// Decisions accepted by `record_fork_decision`
+pub const FORK_DECISIONS: [&str; 4] = ["adopt", "support", "reject", "abandon"];
+
+/// Decisions that count a node's weight towards the ruleset
+const ADOPTING_DECISIONS: [&str; 2] = ["adopt", "support"];
+
 #[derive(Clone)]
 pub struct AdoptionTracker {
     pool: SqlitePool,
@@ -19,6 +25,99 @@ impl AdoptionTracker {
         Self { pool }
     }
 
+    /// Register a ruleset so decisions can reference it
+    pub async fn register_ruleset(&self, ruleset: &Ruleset) -> Result<(), GovernanceError> {
+        sqlx::query(
+            r#"
+            INSERT OR IGNORE INTO governance_rulesets
+            (id, name, version_major, version_minor, version_patch,
+             version_pre_release, version_build_metadata, hash, config, description)
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+            "#,
+        )
+        .bind(&ruleset.id)
+        .bind(&ruleset.name)
+        .bind(ruleset.version.major as i64)
+        .bind(ruleset.version.minor as i64)
+        .bind(ruleset.version.patch as i64)
+        .bind(&ruleset.version.pre_release)
+        .bind(&ruleset.version.build_metadata)
+        .bind(&ruleset.hash)
+        .bind(serde_json::to_string(&ruleset.config)?)
+        .bind(&ruleset.description)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| GovernanceError::DatabaseError(format!("Failed to register ruleset: {}", e)))?;
+
+        Ok(())
+    }
+
+    /// Record an economic node's decision on a ruleset
+    ///
+    /// The node's type and current weight are read from the registry. Only a
+    /// node's most recent decision counts towards adoption, so a node switching
+    /// rulesets moves its weight rather than counting twice.
+    pub async fn record_fork_decision(
+        &self,
+        node_id: i32,
+        ruleset_id: &str,
+        decision: &str,
+        signature: &str,
+        reason: Option<&str>,
+    ) -> Result<(), GovernanceError> {
+        if !FORK_DECISIONS.contains(&decision) {
+            return Err(GovernanceError::ValidationError(format!(
+                "Unknown fork decision '{}' (expected one of: {})",
+                decision,
+                FORK_DECISIONS.join(", ")
+            )));
+        }
+
+        let node = sqlx::query("SELECT node_type, weight, status FROM economic_nodes WHERE id = ?")
+            .bind(node_id)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| GovernanceError::DatabaseError(format!("Failed to fetch node: {}", e)))?
+            .ok_or_else(|| {
+                GovernanceError::not_found("Economic node", node_id)
+            })?;
+        if node.get::<String, _>("status") != "active" {
+            return Err(GovernanceError::ValidationError(format!(
+                "Economic node {} is not active",
+                node_id
+            )));
+        }
+
+        self.insert_decision(
+            ruleset_id,
+            &node_id.to_string(),
+            &node.get::<String, _>("node_type"),
+            node.get::<f64, _>("weight"),
+            decision,
+            reason.unwrap_or(""),
+            signature,
+        )
+        .await
+    }
+
+    /// Record that the app switched to a new ruleset
+    pub async fn record_governance_fork(
+        &self,
+        from_ruleset: Option<&str>,
+        to_ruleset: &str,
+    ) -> Result<(), GovernanceError> {
+        self.log_fork_event(
+            ForkEventType::GovernanceFork,
+            to_ruleset,
+            "governance-app",
+            &serde_json::json!({
+                "from_ruleset": from_ruleset,
+                "to_ruleset": to_ruleset
+            }),
+        )
+        .await
+    }
+
     /// Track adoption of a ruleset by a node
     pub async fn track_adoption(
         &self,
@@ -29,18 +128,54 @@ impl AdoptionTracker {
         decision_reason: &str,
         signature: &str,
     ) -> Result<(), GovernanceError> {
+        self.insert_decision(
+            ruleset_id,
+            node_id,
+            node_type,
+            weight,
+            "adopt",
+            decision_reason,
+            signature,
+        )
+        .await
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    async fn insert_decision(
+        &self,
+        ruleset_id: &str,
+        node_id: &str,
+        node_type: &str,
+        weight: f64,
+        decision: &str,
+        decision_reason: &str,
+        signature: &str,
+    ) -> Result<(), GovernanceError> {
+        let known = sqlx::query("SELECT 1 FROM governance_rulesets WHERE id = ?")
+            .bind(ruleset_id)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| GovernanceError::DatabaseError(format!("Failed to fetch ruleset: {}", e)))?;
+        if known.is_none() {
+            return Err(GovernanceError::ValidationError(format!(
+                "Unknown ruleset: {}",
+                ruleset_id
+            )));
+        }
+
         // Record the adoption decision
         sqlx::query(
             r#"
             INSERT INTO fork_decisions 
-            (ruleset_id, node_id, node_type, weight, decision_reason, signature, timestamp)
-            VALUES (?, ?, ?, ?, ?, ?, ?)
+            (ruleset_id, node_id, node_type, weight, decision, decision_reason, signature, timestamp)
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?)
             "#,
         )
         .bind(ruleset_id)
         .bind(node_id)
         .bind(node_type)
         .bind(weight)
+        .bind(decision)
         .bind(decision_reason)
         .bind(signature)
         .bind(Utc::now())
@@ -49,21 +184,27 @@ impl AdoptionTracker {
         .map_err(|e| GovernanceError::DatabaseError(format!("Failed to track adoption: {}", e)))?;
 
         // Log adoption event
+        let event_type = if ADOPTING_DECISIONS.contains(&decision) {
+            ForkEventType::RulesetAdopted
+        } else {
+            ForkEventType::RulesetAbandoned
+        };
         self.log_fork_event(
-            ForkEventType::RulesetAdopted,
+            event_type,
             ruleset_id,
             node_id,
             &serde_json::json!({
                 "node_type": node_type,
                 "weight": weight,
+                "decision": decision,
                 "decision_reason": decision_reason
             }),
         )
         .await?;
 
         info!(
-            "Tracked adoption of ruleset {} by node {} (weight: {})",
-            ruleset_id, node_id, weight
+            "Recorded {} of ruleset {} by node {} (weight: {})",
+            decision, ruleset_id, node_id, weight
         );
         Ok(())
     }
@@ -73,106 +214,89 @@ impl AdoptionTracker {
         &self,
         ruleset_id: &str,
     ) -> Result<AdoptionMetrics, GovernanceError> {
-        // Get all adoption decisions for this ruleset
-        let decisions = sqlx::query(
+        let stats = self.get_adoption_statistics().await?;
+        Ok(stats
+            .rulesets
+            .into_iter()
+            .find(|m| m.ruleset_id == ruleset_id)
+            .unwrap_or_else(|| AdoptionMetrics {
+                ruleset_id: ruleset_id.to_string(),
+                node_count: 0,
+                hashpower_percentage: 0.0,
+                economic_activity_percentage: 0.0,
+                total_weight: 0.0,
+                last_updated: Utc::now(),
+            }))
+    }
+
+    /// Get comprehensive adoption statistics
+    ///
+    /// Each node counts once, for the ruleset of its latest adopting decision.
+    /// Hashpower and economic activity percentages are that ruleset's share of
+    /// all decided mining and non-mining weight respectively.
+    pub async fn get_adoption_statistics(&self) -> Result<AdoptionStatistics, GovernanceError> {
+        let rows = sqlx::query(
             r#"
-            SELECT node_type, weight, timestamp
-            FROM fork_decisions 
-            WHERE ruleset_id = ?
-            ORDER BY timestamp DESC
+            WITH latest AS (
+                SELECT fd.ruleset_id, fd.node_type, fd.weight, fd.decision
+                FROM fork_decisions fd
+                WHERE fd.id = (SELECT MAX(id) FROM fork_decisions WHERE node_id = fd.node_id)
+            )
+            SELECT ruleset_id,
+                   COUNT(*) AS node_count,
+                   SUM(weight) AS total_weight,
+                   SUM(CASE WHEN node_type = 'mining_pool' THEN weight ELSE 0 END) AS mining_weight,
+                   SUM(CASE WHEN node_type != 'mining_pool' THEN weight ELSE 0 END) AS economic_weight
+            FROM latest
+            WHERE decision IN ('adopt', 'support')
+            GROUP BY ruleset_id
+            ORDER BY ruleset_id
             "#,
         )
-        .bind(ruleset_id)
         .fetch_all(&self.pool)
         .await
         .map_err(|e| {
-            GovernanceError::DatabaseError(format!("Failed to fetch adoption decisions: {}", e))
+            GovernanceError::DatabaseError(format!("Failed to aggregate adoption decisions: {}", e))
         })?;
 
-        let mut node_count = 0;
-        let mut hashpower_weight = 0.0;
-        let mut economic_weight = 0.0;
-        let mut total_weight = 0.0;
-
-        for decision in decisions {
-            let node_type = decision.get::<String, _>("node_type");
-            let weight = decision.get::<f64, _>("weight");
-
-            node_count += 1;
-            total_weight += weight;
-
-            match node_type.as_str() {
-                "mining_pool" => hashpower_weight += weight,
-                _ => economic_weight += weight,
-            }
-        }
-
-        // Calculate percentages (assuming total network metrics are known)
-        // For now, use placeholder calculations
-        let hashpower_percentage = if total_weight > 0.0 {
-            (hashpower_weight / total_weight) * 100.0
-        } else {
-            0.0
-        };
-
-        let economic_activity_percentage = if total_weight > 0.0 {
-            (economic_weight / total_weight) * 100.0
-        } else {
-            0.0
-        };
-
-        Ok(AdoptionMetrics {
-            ruleset_id: ruleset_id.to_string(),
-            node_count: node_count as u32,
-            hashpower_percentage,
-            economic_activity_percentage,
-            total_weight,
-            last_updated: Utc::now(),
-        })
-    }
-
-    /// Get comprehensive adoption statistics
-    pub async fn get_adoption_statistics(&self) -> Result<AdoptionStatistics, GovernanceError> {
-        // Get all unique rulesets
-        let rulesets = sqlx::query("SELECT DISTINCT ruleset_id FROM fork_decisions")
-            .fetch_all(&self.pool)
+        let total_nodes = sqlx::query("SELECT COUNT(DISTINCT node_id) AS count FROM fork_decisions")
+            .fetch_one(&self.pool)
             .await
             .map_err(|e| {
-                GovernanceError::DatabaseError(format!("Failed to fetch rulesets: {}", e))
-            })?;
-
-        let mut adoption_metrics = Vec::new();
-        let mut total_nodes = 0;
-        let mut total_hashpower = 0.0;
-        let mut total_economic_activity = 0.0;
-
-        for ruleset in rulesets {
-            let ruleset_id = ruleset.get::<String, _>("ruleset_id");
-            let metrics = self.calculate_adoption_metrics(&ruleset_id).await?;
-
-            total_nodes += metrics.node_count;
-            total_hashpower += metrics.hashpower_percentage;
-            total_economic_activity += metrics.economic_activity_percentage;
-
-            adoption_metrics.push(metrics);
-        }
-
-        // Find winning ruleset (highest adoption)
-        let winning_ruleset = adoption_metrics
+                GovernanceError::DatabaseError(format!("Failed to count deciding nodes: {}", e))
+            })?
+            .get::<i64, _>("count") as u32;
+
+        let total_hashpower: f64 = rows.iter().map(|r| r.get::<f64, _>("mining_weight")).sum();
+        let total_economic_activity: f64 =
+            rows.iter().map(|r| r.get::<f64, _>("economic_weight")).sum();
+        let total_weight: f64 = rows.iter().map(|r| r.get::<f64, _>("total_weight")).sum();
+
+        let share = |part: f64, whole: f64| if whole > 0.0 { (part / whole) * 100.0 } else { 0.0 };
+        let now = Utc::now();
+        let adoption_metrics: Vec<AdoptionMetrics> = rows
             .iter()
-            .max_by(|a, b| {
-                a.total_weight
-                    .partial_cmp(&b.total_weight)
-                    .unwrap_or(std::cmp::Ordering::Equal)
+            .map(|row| AdoptionMetrics {
+                ruleset_id: row.get::<String, _>("ruleset_id"),
+                node_count: row.get::<i64, _>("node_count") as u32,
+                hashpower_percentage: share(row.get::<f64, _>("mining_weight"), total_hashpower),
+                economic_activity_percentage: share(
+                    row.get::<f64, _>("economic_weight"),
+                    total_economic_activity,
+                ),
+                total_weight: row.get::<f64, _>("total_weight"),
+                last_updated: now,
             })
-            .map(|m| m.ruleset_id.clone());
+            .collect();
 
-        // Calculate overall adoption percentage
-        let adoption_percentage = if total_nodes > 0 {
-            (adoption_metrics.len() as f64 / total_nodes as f64) * 100.0
-        } else {
-            0.0
-        };
+        // Find winning ruleset (highest adoption)
+        let winner = adoption_metrics.iter().max_by(|a, b| {
+            a.total_weight
+                .partial_cmp(&b.total_weight)
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+        let winning_ruleset = winner.map(|m| m.ruleset_id.clone());
+        let adoption_percentage = winner.map(|m| share(m.total_weight, total_weight)).unwrap_or(0.0);
 
         Ok(AdoptionStatistics {
             total_nodes,
@@ -181,7 +305,7 @@ impl AdoptionTracker {
             rulesets: adoption_metrics,
             winning_ruleset,
             adoption_percentage,
-            last_updated: Utc::now(),
+            last_updated: now,
         })
     }
 
@@ -239,7 +363,13 @@ impl AdoptionTracker {
         node_id: &str,
         details: &serde_json::Value,
     ) -> Result<(), GovernanceError> {
-        let event_id = format!("{}_{}_{}", event_type.as_str(), ruleset_id, node_id);
+        let event_id = format!(
+            "{}_{}_{}_{}",
+            event_type.as_str(),
+            ruleset_id,
+            node_id,
+            uuid::Uuid::new_v4()
+        );
 
         sqlx::query(
             r#"
@@ -261,3 +391,91 @@ impl AdoptionTracker {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::Database;
+
+    fn ruleset(id: &str) -> Ruleset {
+        Ruleset {
+            id: id.to_string(),
+            name: format!("Ruleset {}", id),
+            version: RulesetVersion::new(1, 0, 0),
+            hash: format!("hash-{}", id),
+            created_at: Utc::now(),
+            config: serde_json::json!({}),
+            description: None,
+        }
+    }
+
+    async fn add_node(pool: &SqlitePool, node_type: &str, weight: f64) -> i32 {
+        sqlx::query(
+            r#"
+            INSERT INTO economic_nodes (node_type, entity_name, public_key, qualification_data, weight, status)
+            VALUES (?, ?, 'pubkey', '{}', ?, 'active')
+            "#,
+        )
+        .bind(node_type)
+        .bind(format!("{} node", node_type))
+        .bind(weight)
+        .execute(pool)
+        .await
+        .unwrap()
+        .last_insert_rowid() as i32
+    }
+
+    #[tokio::test]
+    async fn test_per_ruleset_counts_and_weights() {
+        let db = Database::new_in_memory().await.unwrap();
+        let pool = db.pool().unwrap().clone();
+        let tracker = AdoptionTracker::new(pool.clone());
+        tracker.register_ruleset(&ruleset("v1")).await.unwrap();
+        tracker.register_ruleset(&ruleset("v2")).await.unwrap();
+
+        let pool_node = add_node(&pool, "mining_pool", 0.4).await;
+        let exchange = add_node(&pool, "exchange", 0.6).await;
+        let custodian = add_node(&pool, "custodian", 1.0).await;
+
+        tracker.record_fork_decision(pool_node, "v1", "adopt", "sig1", None).await.unwrap();
+        tracker.record_fork_decision(exchange, "v1", "support", "sig2", Some("Agree")).await.unwrap();
+        tracker.record_fork_decision(custodian, "v2", "adopt", "sig3", None).await.unwrap();
+
+        let v1 = tracker.calculate_adoption_metrics("v1").await.unwrap();
+        assert_eq!(v1.node_count, 2);
+        assert!((v1.total_weight - 1.0).abs() < 1e-9);
+        assert_eq!(v1.hashpower_percentage, 100.0);
+        assert!((v1.economic_activity_percentage - 37.5).abs() < 1e-9);
+
+        let v2 = tracker.calculate_adoption_metrics("v2").await.unwrap();
+        assert_eq!(v2.node_count, 1);
+        assert!((v2.total_weight - 1.0).abs() < 1e-9);
+
+        // Switching rulesets moves the node's weight
+        tracker.record_fork_decision(exchange, "v2", "adopt", "sig4", None).await.unwrap();
+        let stats = tracker.get_adoption_statistics().await.unwrap();
+        assert_eq!(stats.total_nodes, 3);
+        assert_eq!(stats.winning_ruleset.as_deref(), Some("v2"));
+        let v1 = stats.rulesets.iter().find(|m| m.ruleset_id == "v1").unwrap();
+        assert_eq!(v1.node_count, 1);
+        let v2 = stats.rulesets.iter().find(|m| m.ruleset_id == "v2").unwrap();
+        assert_eq!(v2.node_count, 2);
+        assert!((v2.total_weight - 1.6).abs() < 1e-9);
+    }
+
+    #[tokio::test]
+    async fn test_rejects_unknown_ruleset_and_decision() {
+        let db = Database::new_in_memory().await.unwrap();
+        let pool = db.pool().unwrap().clone();
+        let tracker = AdoptionTracker::new(pool.clone());
+        tracker.register_ruleset(&ruleset("v1")).await.unwrap();
+        let node = add_node(&pool, "custodian", 1.0).await;
+
+        assert!(tracker.record_fork_decision(node, "v9", "adopt", "sig", None).await.is_err());
+        assert!(tracker.record_fork_decision(node, "v1", "maybe", "sig", None).await.is_err());
+
+        // Rejections are recorded but don't count as adoption
+        tracker.record_fork_decision(node, "v1", "reject", "sig", None).await.unwrap();
+        assert_eq!(tracker.calculate_adoption_metrics("v1").await.unwrap().node_count, 0);
+    }
+}
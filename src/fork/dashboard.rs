@@ -3,7 +3,10 @@
 //! Provides HTTP endpoints for viewing governance adoption metrics
 
 use axum::{extract::State, http::StatusCode, response::Json, routing::get, Router};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
 use serde_json::Value;
+use std::fmt::Write;
 
 // use crate::error::GovernanceError;
 use super::adoption::AdoptionTracker;
@@ -12,17 +15,120 @@ use super::types::*;
 #[derive(Clone)]
 pub struct AdoptionDashboard {
     adoption_tracker: AdoptionTracker,
+    fork_thresholds: ForkThresholds,
+}
+
+/// One ruleset's row in the adoption summary
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RulesetAdoptionSummary {
+    pub ruleset_id: String,
+    pub node_count: u32,
+    pub hashpower_percentage: f64,
+    pub economic_activity_percentage: f64,
+    pub total_weight: f64,
+    pub crosses_fork_thresholds: bool,
+}
+
+/// Adoption across all rulesets, heaviest first
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AdoptionSummary {
+    pub total_nodes: u32,
+    pub winning_ruleset: Option<String>,
+    pub adoption_percentage: f64,
+    pub last_updated: DateTime<Utc>,
+    pub rulesets: Vec<RulesetAdoptionSummary>,
+}
+
+impl AdoptionSummary {
+    pub fn to_json(&self) -> Result<Value, serde_json::Error> {
+        serde_json::to_value(self)
+    }
+
+    /// Render as a fixed-width table for terminals
+    pub fn render_text(&self) -> String {
+        let mut out = String::new();
+        let _ = writeln!(
+            out,
+            "Governance adoption as of {}",
+            self.last_updated.format("%Y-%m-%d %H:%M:%S UTC")
+        );
+        let _ = writeln!(
+            out,
+            "Nodes: {} | Winning ruleset: {} ({:.1}%)",
+            self.total_nodes,
+            self.winning_ruleset.as_deref().unwrap_or("none"),
+            self.adoption_percentage
+        );
+        let _ = writeln!(out);
+        let _ = writeln!(
+            out,
+            "{:<24} {:>6} {:>10} {:>10} {:>8}  {}",
+            "RULESET", "NODES", "HASHPOWER", "ECONOMIC", "WEIGHT", "FORK THRESHOLD"
+        );
+        for ruleset in &self.rulesets {
+            let _ = writeln!(
+                out,
+                "{:<24} {:>6} {:>9.1}% {:>9.1}% {:>8.1}  {}",
+                ruleset.ruleset_id,
+                ruleset.node_count,
+                ruleset.hashpower_percentage,
+                ruleset.economic_activity_percentage,
+                ruleset.total_weight,
+                if ruleset.crosses_fork_thresholds {
+                    "met"
+                } else {
+                    "not met"
+                }
+            );
+        }
+        out
+    }
 }
 
 impl AdoptionDashboard {
     pub fn new(adoption_tracker: AdoptionTracker) -> Self {
-        Self { adoption_tracker }
+        Self {
+            adoption_tracker,
+            fork_thresholds: ForkThresholds::default(),
+        }
+    }
+
+    /// Judge rulesets against these thresholds instead of the defaults
+    pub fn with_thresholds(mut self, fork_thresholds: ForkThresholds) -> Self {
+        self.fork_thresholds = fork_thresholds;
+        self
+    }
+
+    /// Summarize adoption statistics per ruleset, sorted by total weight descending
+    pub fn summarize(&self, statistics: &AdoptionStatistics) -> AdoptionSummary {
+        let mut rulesets: Vec<RulesetAdoptionSummary> = statistics
+            .rulesets
+            .iter()
+            .map(|metrics| RulesetAdoptionSummary {
+                ruleset_id: metrics.ruleset_id.clone(),
+                node_count: metrics.node_count,
+                hashpower_percentage: metrics.hashpower_percentage,
+                economic_activity_percentage: metrics.economic_activity_percentage,
+                total_weight: metrics.total_weight,
+                crosses_fork_thresholds: self.fork_thresholds.met_by(metrics),
+            })
+            .collect();
+        rulesets.sort_by(|a, b| b.total_weight.total_cmp(&a.total_weight));
+
+        AdoptionSummary {
+            total_nodes: statistics.total_nodes,
+            winning_ruleset: statistics.winning_ruleset.clone(),
+            adoption_percentage: statistics.adoption_percentage,
+            last_updated: statistics.last_updated,
+            rulesets,
+        }
     }
 
     /// Create the dashboard router
     pub fn router(self) -> Router {
         Router::new()
             .route("/adoption-metrics", get(get_adoption_metrics))
+            .route("/adoption-summary", get(get_adoption_summary))
             .route("/ruleset/:ruleset_id/metrics", get(get_ruleset_metrics))
             .route("/ruleset/:ruleset_id/history", get(get_ruleset_history))
             .route("/health", get(health_check))
@@ -57,6 +163,28 @@ pub async fn get_adoption_metrics(
     }
 }
 
+/// Get per-ruleset adoption, heaviest first, with fork threshold flags
+pub async fn get_adoption_summary(
+    State(dashboard): State<AdoptionDashboard>,
+) -> Result<Json<Value>, StatusCode> {
+    match dashboard.adoption_tracker.get_adoption_statistics().await {
+        Ok(stats) => {
+            let summary = dashboard.summarize(&stats).to_json().map_err(|e| {
+                tracing::error!("Failed to serialize adoption summary: {}", e);
+                StatusCode::INTERNAL_SERVER_ERROR
+            })?;
+            Ok(Json(serde_json::json!({
+                "status": "success",
+                "data": summary
+            })))
+        }
+        Err(e) => {
+            tracing::error!("Failed to get adoption summary: {}", e);
+            Err(StatusCode::INTERNAL_SERVER_ERROR)
+        }
+    }
+}
+
 /// Get metrics for a specific ruleset
 pub async fn get_ruleset_metrics(
     axum::extract::Path(ruleset_id): axum::extract::Path<String>,
@@ -218,3 +346,97 @@ pub fn create_dashboard_html(statistics: &AdoptionStatistics) -> String {
             .join("")
     )
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn metrics(
+        ruleset_id: &str,
+        node_count: u32,
+        hashpower: f64,
+        economic: f64,
+        weight: f64,
+    ) -> AdoptionMetrics {
+        AdoptionMetrics {
+            ruleset_id: ruleset_id.to_string(),
+            node_count,
+            hashpower_percentage: hashpower,
+            economic_activity_percentage: economic,
+            total_weight: weight,
+            last_updated: Utc::now(),
+        }
+    }
+
+    fn statistics() -> AdoptionStatistics {
+        AdoptionStatistics {
+            total_nodes: 45,
+            total_hashpower: 100.0,
+            total_economic_activity: 100.0,
+            rulesets: vec![
+                metrics("minority", 5, 10.0, 15.0, 12.5),
+                metrics("mainline", 30, 60.0, 55.0, 57.5),
+                // Heavy, but too few nodes to cross the thresholds
+                metrics("concentrated", 4, 30.0, 30.0, 30.0),
+                metrics("empty", 0, 0.0, 0.0, 0.0),
+            ],
+            winning_ruleset: Some("mainline".to_string()),
+            adoption_percentage: 57.5,
+            last_updated: Utc::now(),
+        }
+    }
+
+    async fn dashboard() -> AdoptionDashboard {
+        let db = crate::database::Database::new_in_memory().await.unwrap();
+        AdoptionDashboard::new(AdoptionTracker::new(db.pool().unwrap().clone()))
+    }
+
+    #[tokio::test]
+    async fn test_summary_sorted_by_weight_with_threshold_flags() {
+        let summary = dashboard().await.summarize(&statistics());
+
+        let order: Vec<&str> = summary
+            .rulesets
+            .iter()
+            .map(|r| r.ruleset_id.as_str())
+            .collect();
+        assert_eq!(order, vec!["mainline", "concentrated", "minority", "empty"]);
+
+        let crossing: Vec<&str> = summary
+            .rulesets
+            .iter()
+            .filter(|r| r.crosses_fork_thresholds)
+            .map(|r| r.ruleset_id.as_str())
+            .collect();
+        assert_eq!(crossing, vec!["mainline"]);
+
+        let json = summary.to_json().unwrap();
+        assert_eq!(json["rulesets"][0]["ruleset_id"], "mainline");
+        assert_eq!(json["rulesets"][0]["crosses_fork_thresholds"], true);
+        assert_eq!(json["rulesets"][1]["crosses_fork_thresholds"], false);
+        assert_eq!(json["winning_ruleset"], "mainline");
+    }
+
+    #[tokio::test]
+    async fn test_custom_thresholds_and_text_rendering() {
+        let thresholds = ForkThresholds {
+            minimum_node_count: 4,
+            minimum_hashpower_percentage: 25.0,
+            minimum_economic_activity_percentage: 25.0,
+            minimum_adoption_percentage: 25.0,
+            ..ForkThresholds::default()
+        };
+        let summary = dashboard()
+            .await
+            .with_thresholds(thresholds)
+            .summarize(&statistics());
+        assert!(summary.rulesets[1].crosses_fork_thresholds);
+
+        let text = summary.render_text();
+        let lines: Vec<&str> = text.lines().collect();
+        assert!(lines[1].contains("Winning ruleset: mainline (57.5%)"));
+        assert!(lines[3].starts_with("RULESET"));
+        assert!(lines[4].starts_with("mainline") && !lines[4].ends_with("not met"));
+        assert!(lines[7].starts_with("empty") && lines[7].ends_with("not met"));
+    }
+}
@@ -32,11 +32,14 @@ pub struct ForkDetectionEvent {
 }
 
 /// Types of fork triggers
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum ForkTriggerType {
     AdoptionThreshold,
+    /// Adoption is split between competing rulesets with no clear winner
+    MaintainerSchism,
     TimeBased,
     Manual,
+    /// Significant hashpower has moved to a ruleset other than the winner
     Emergency,
     Consensus,
 }
@@ -74,12 +77,8 @@ impl ForkDetector {
         // Get current adoption statistics
         let adoption_stats = self.adoption_tracker.get_adoption_statistics().await?;
         
-        // Check each ruleset for fork conditions
-        for metrics in &adoption_stats.rulesets {
-            if let Some(detection) = self.analyze_ruleset_for_fork(metrics).await? {
-                new_detections.push(detection);
-            }
-        }
+        // Check adoption, schism and emergency split triggers
+        new_detections.extend(self.evaluate(&adoption_stats));
         
         // Check for time-based triggers
         if let Some(time_detection) = self.check_time_based_triggers().await? {
@@ -102,57 +101,107 @@ impl ForkDetector {
         Ok(new_detections)
     }
 
-    /// Analyze a specific ruleset for fork conditions
-    async fn analyze_ruleset_for_fork(
-        &self,
-        metrics: &AdoptionMetrics,
-    ) -> Result<Option<ForkDetectionEvent>, GovernanceError> {
-        debug!("Analyzing ruleset {} for fork conditions", metrics.ruleset_id);
-        
-        let threshold_met = self.check_adoption_thresholds(metrics);
-        
-        if threshold_met {
-            info!("Fork threshold met for ruleset: {}", metrics.ruleset_id);
-            
-            let detection = ForkDetectionEvent {
-                event_id: uuid::Uuid::new_v4().to_string(),
-                detected_at: Utc::now(),
-                ruleset_id: metrics.ruleset_id.clone(),
-                trigger_type: ForkTriggerType::AdoptionThreshold,
-                metrics: metrics.clone(),
-                threshold_met: true,
-                action_taken: Some(ForkAction::ForkExecuted),
-            };
-            
-            return Ok(Some(detection));
+    /// Evaluate adoption statistics against every metric-based trigger
+    ///
+    /// Each event carries the recommended action: execute the fork when a
+    /// ruleset meets all adoption thresholds, increase monitoring when one is
+    /// approaching them, alert on a schism, and schedule a fork on an
+    /// emergency split.
+    pub fn evaluate(&self, stats: &AdoptionStatistics) -> Vec<ForkDetectionEvent> {
+        let mut events = Vec::new();
+
+        for metrics in &stats.rulesets {
+            debug!("Analyzing ruleset {} for fork conditions", metrics.ruleset_id);
+
+            if self.check_adoption_thresholds(metrics) {
+                info!("Fork threshold met for ruleset: {}", metrics.ruleset_id);
+                events.push(Self::event(
+                    metrics,
+                    ForkTriggerType::AdoptionThreshold,
+                    true,
+                    ForkAction::ForkExecuted,
+                ));
+            } else if self.is_approaching_threshold(metrics) {
+                warn!("Ruleset {} approaching fork threshold", metrics.ruleset_id);
+                events.push(Self::event(
+                    metrics,
+                    ForkTriggerType::AdoptionThreshold,
+                    false,
+                    ForkAction::MonitoringIncreased,
+                ));
+            }
         }
-        
-        // Check if approaching threshold (for early warning)
-        if self.is_approaching_threshold(metrics) {
-            warn!("Ruleset {} approaching fork threshold", metrics.ruleset_id);
-            
-            let detection = ForkDetectionEvent {
-                event_id: uuid::Uuid::new_v4().to_string(),
-                detected_at: Utc::now(),
-                ruleset_id: metrics.ruleset_id.clone(),
-                trigger_type: ForkTriggerType::AdoptionThreshold,
-                metrics: metrics.clone(),
-                threshold_met: false,
-                action_taken: Some(ForkAction::MonitoringIncreased),
-            };
-            
-            return Ok(Some(detection));
+
+        let total_weight: f64 = stats.rulesets.iter().map(|m| m.total_weight).sum();
+        if total_weight > 0.0 {
+            let competing: Vec<&AdoptionMetrics> = stats
+                .rulesets
+                .iter()
+                .filter(|m| {
+                    (m.total_weight / total_weight) * 100.0
+                        >= self.fork_thresholds.schism_minimum_share_percentage
+                })
+                .collect();
+
+            if competing.len() >= 2 {
+                warn!(
+                    "Maintainer schism detected across {} rulesets",
+                    competing.len()
+                );
+                for metrics in competing {
+                    events.push(Self::event(
+                        metrics,
+                        ForkTriggerType::MaintainerSchism,
+                        true,
+                        ForkAction::AlertSent,
+                    ));
+                }
+            }
+        }
+
+        for metrics in &stats.rulesets {
+            if stats.winning_ruleset.as_ref() == Some(&metrics.ruleset_id) {
+                continue;
+            }
+            if metrics.hashpower_percentage
+                >= self.fork_thresholds.emergency_split_hashpower_percentage
+            {
+                warn!(
+                    "Emergency split: {:.1}% hashpower on non-winning ruleset {}",
+                    metrics.hashpower_percentage, metrics.ruleset_id
+                );
+                events.push(Self::event(
+                    metrics,
+                    ForkTriggerType::Emergency,
+                    true,
+                    ForkAction::ForkScheduled,
+                ));
+            }
+        }
+
+        events
+    }
+
+    fn event(
+        metrics: &AdoptionMetrics,
+        trigger_type: ForkTriggerType,
+        threshold_met: bool,
+        action: ForkAction,
+    ) -> ForkDetectionEvent {
+        ForkDetectionEvent {
+            event_id: uuid::Uuid::new_v4().to_string(),
+            detected_at: Utc::now(),
+            ruleset_id: metrics.ruleset_id.clone(),
+            trigger_type,
+            metrics: metrics.clone(),
+            threshold_met,
+            action_taken: Some(action),
         }
-        
-        Ok(None)
     }
 
     /// Check if adoption thresholds are met
     fn check_adoption_thresholds(&self, metrics: &AdoptionMetrics) -> bool {
-        metrics.node_count >= self.fork_thresholds.minimum_node_count &&
-        metrics.hashpower_percentage >= self.fork_thresholds.minimum_hashpower_percentage &&
-        metrics.economic_activity_percentage >= self.fork_thresholds.minimum_economic_activity_percentage &&
-        metrics.total_weight >= self.fork_thresholds.minimum_adoption_percentage
+        self.fork_thresholds.met_by(metrics)
     }
 
     /// Check if approaching threshold (for early warning)
@@ -311,12 +360,11 @@ pub struct ForkDetectionStats {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use tempfile::tempdir;
 
     #[tokio::test]
     async fn test_fork_detection() {
-        let temp_dir = tempdir().unwrap();
-        let adoption_tracker = AdoptionTracker::new().unwrap();
+        let db = crate::database::Database::new_in_memory().await.unwrap();
+        let adoption_tracker = AdoptionTracker::new(db.pool().unwrap().clone());
         let mut detector = ForkDetector::new(adoption_tracker, None);
         
         // Test with empty adoption stats
@@ -324,10 +372,10 @@ mod tests {
         assert_eq!(detections.len(), 0);
     }
 
-    #[test]
-    fn test_threshold_checking() {
-        let temp_dir = tempdir().unwrap();
-        let adoption_tracker = AdoptionTracker::new().unwrap();
+    #[tokio::test]
+    async fn test_threshold_checking() {
+        let db = crate::database::Database::new_in_memory().await.unwrap();
+        let adoption_tracker = AdoptionTracker::new(db.pool().unwrap().clone());
         let detector = ForkDetector::new(adoption_tracker, None);
         
         let metrics = AdoptionMetrics {
@@ -341,4 +389,108 @@ mod tests {
         
         assert!(detector.check_adoption_thresholds(&metrics));
     }
+
+    fn metrics(ruleset_id: &str, hashpower: f64, economic: f64, weight: f64) -> AdoptionMetrics {
+        AdoptionMetrics {
+            ruleset_id: ruleset_id.to_string(),
+            node_count: 20,
+            hashpower_percentage: hashpower,
+            economic_activity_percentage: economic,
+            total_weight: weight,
+            last_updated: Utc::now(),
+        }
+    }
+
+    fn stats(rulesets: Vec<AdoptionMetrics>, winner: &str) -> AdoptionStatistics {
+        AdoptionStatistics {
+            total_nodes: rulesets.iter().map(|m| m.node_count).sum(),
+            total_hashpower: 0.0,
+            total_economic_activity: 0.0,
+            rulesets,
+            winning_ruleset: Some(winner.to_string()),
+            adoption_percentage: 0.0,
+            last_updated: Utc::now(),
+        }
+    }
+
+    fn triggered(events: &[ForkDetectionEvent], trigger: ForkTriggerType) -> Vec<&ForkDetectionEvent> {
+        events.iter().filter(|e| e.trigger_type == trigger).collect()
+    }
+
+    async fn detector() -> ForkDetector {
+        let db = crate::database::Database::new_in_memory().await.unwrap();
+        ForkDetector::new(AdoptionTracker::new(db.pool().unwrap().clone()), None)
+    }
+
+    #[tokio::test]
+    async fn test_adoption_threshold_boundary() {
+        let detector = detector().await;
+        let t = ForkThresholds::default();
+
+        // Exactly at every threshold fires a fork
+        let at = metrics(
+            "v2",
+            t.minimum_hashpower_percentage,
+            t.minimum_economic_activity_percentage,
+            t.minimum_adoption_percentage,
+        );
+        let events = detector.evaluate(&stats(vec![at], "v2"));
+        let adoption = triggered(&events, ForkTriggerType::AdoptionThreshold);
+        assert_eq!(adoption.len(), 1);
+        assert_eq!(adoption[0].action_taken, Some(ForkAction::ForkExecuted));
+
+        // Just below one threshold only raises monitoring
+        let below = metrics(
+            "v2",
+            t.minimum_hashpower_percentage,
+            t.minimum_economic_activity_percentage,
+            t.minimum_adoption_percentage - 0.01,
+        );
+        let events = detector.evaluate(&stats(vec![below], "v2"));
+        let adoption = triggered(&events, ForkTriggerType::AdoptionThreshold);
+        assert_eq!(adoption.len(), 1);
+        assert!(!adoption[0].threshold_met);
+        assert_eq!(adoption[0].action_taken, Some(ForkAction::MonitoringIncreased));
+    }
+
+    #[tokio::test]
+    async fn test_maintainer_schism_boundary() {
+        let detector = detector().await;
+
+        // 35/65 split: the smaller side holds exactly the schism share
+        let events = detector.evaluate(&stats(
+            vec![metrics("v1", 0.0, 0.0, 65.0), metrics("v2", 0.0, 0.0, 35.0)],
+            "v1",
+        ));
+        let schism = triggered(&events, ForkTriggerType::MaintainerSchism);
+        assert_eq!(schism.len(), 2);
+        assert!(schism.iter().all(|e| e.action_taken == Some(ForkAction::AlertSent)));
+
+        let events = detector.evaluate(&stats(
+            vec![metrics("v1", 0.0, 0.0, 65.1), metrics("v2", 0.0, 0.0, 34.9)],
+            "v1",
+        ));
+        assert!(triggered(&events, ForkTriggerType::MaintainerSchism).is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_emergency_split_boundary() {
+        let detector = detector().await;
+        let limit = ForkThresholds::default().emergency_split_hashpower_percentage;
+
+        let events = detector.evaluate(&stats(
+            vec![metrics("v1", 60.0, 0.0, 1.0), metrics("v2", limit, 0.0, 0.0)],
+            "v1",
+        ));
+        let emergency = triggered(&events, ForkTriggerType::Emergency);
+        assert_eq!(emergency.len(), 1);
+        assert_eq!(emergency[0].ruleset_id, "v2");
+        assert_eq!(emergency[0].action_taken, Some(ForkAction::ForkScheduled));
+
+        let events = detector.evaluate(&stats(
+            vec![metrics("v1", 60.0, 0.0, 1.0), metrics("v2", limit - 0.01, 0.0, 0.0)],
+            "v1",
+        ));
+        assert!(triggered(&events, ForkTriggerType::Emergency).is_empty());
+    }
 }
@@ -0,0 +1,260 @@
+//! Ruleset Diff
+//!
+//! Field-level comparison of two governance rulesets: per-tier threshold
+//! changes and maintainer membership, rather than whole-section equality.
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Write;
+
+/// Tier fields that decide when a PR can merge
+const TIER_FIELDS: [&str; 3] = ["signatures_required", "signatures_total", "review_period_days"];
+
+/// Sections compared only for equality
+const OTHER_SECTIONS: [&str; 2] = ["economic_nodes", "repositories"];
+
+/// One field's value in each ruleset; `None` where the tier or field is absent
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FieldChange {
+    pub field: String,
+    pub old: Option<i64>,
+    pub new: Option<i64>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TierDiff {
+    pub tier: String,
+    pub changes: Vec<FieldChange>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct MaintainerDiff {
+    pub added: Vec<String>,
+    pub removed: Vec<String>,
+}
+
+/// Differences between two rulesets, from `from` to `to`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RulesetDiff {
+    pub from: String,
+    pub to: String,
+    pub tiers: Vec<TierDiff>,
+    pub maintainers: MaintainerDiff,
+    /// Other sections that differ at all
+    pub changed_sections: Vec<String>,
+}
+
+impl RulesetDiff {
+    /// Compare two ruleset exports
+    pub fn between(from: &Value, to: &Value) -> Self {
+        let old_tiers = tiers_by_name(&from["action_tiers"]);
+        let new_tiers = tiers_by_name(&to["action_tiers"]);
+        let names: BTreeSet<&String> = old_tiers.keys().chain(new_tiers.keys()).collect();
+
+        let tiers = names
+            .into_iter()
+            .filter_map(|name| {
+                let changes: Vec<FieldChange> = TIER_FIELDS
+                    .iter()
+                    .map(|field| FieldChange {
+                        field: field.to_string(),
+                        old: old_tiers.get(name).and_then(|t| t[*field].as_i64()),
+                        new: new_tiers.get(name).and_then(|t| t[*field].as_i64()),
+                    })
+                    .filter(|change| change.old != change.new)
+                    .collect();
+                (!changes.is_empty()).then(|| TierDiff {
+                    tier: name.clone(),
+                    changes,
+                })
+            })
+            .collect();
+
+        let old_maintainers = maintainer_usernames(&from["maintainers"]);
+        let new_maintainers = maintainer_usernames(&to["maintainers"]);
+        let maintainers = MaintainerDiff {
+            added: new_maintainers.difference(&old_maintainers).cloned().collect(),
+            removed: old_maintainers.difference(&new_maintainers).cloned().collect(),
+        };
+
+        let changed_sections = OTHER_SECTIONS
+            .iter()
+            .filter(|section| from[**section] != to[**section])
+            .map(|section| section.to_string())
+            .collect();
+
+        Self {
+            from: from["ruleset_id"].as_str().unwrap_or("unknown").to_string(),
+            to: to["ruleset_id"].as_str().unwrap_or("unknown").to_string(),
+            tiers,
+            maintainers,
+            changed_sections,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.tiers.is_empty()
+            && self.maintainers.added.is_empty()
+            && self.maintainers.removed.is_empty()
+            && self.changed_sections.is_empty()
+    }
+
+    pub fn to_json(&self) -> Result<Value, serde_json::Error> {
+        serde_json::to_value(self)
+    }
+
+    pub fn render_text(&self) -> String {
+        let mut out = String::new();
+        let _ = writeln!(out, "{} -> {}", self.from, self.to);
+        if self.is_empty() {
+            let _ = writeln!(out, "  No differences");
+            return out;
+        }
+
+        if !self.tiers.is_empty() {
+            let _ = writeln!(out, "Action tiers:");
+            for tier in &self.tiers {
+                let _ = writeln!(out, "  {}:", tier.tier);
+                for change in &tier.changes {
+                    let _ = writeln!(
+                        out,
+                        "    {}: {} -> {}",
+                        change.field,
+                        display(change.old),
+                        display(change.new)
+                    );
+                }
+            }
+        }
+
+        if !self.maintainers.added.is_empty() || !self.maintainers.removed.is_empty() {
+            let _ = writeln!(out, "Maintainers:");
+            for username in &self.maintainers.added {
+                let _ = writeln!(out, "  + {}", username);
+            }
+            for username in &self.maintainers.removed {
+                let _ = writeln!(out, "  - {}", username);
+            }
+        }
+
+        for section in &self.changed_sections {
+            let _ = writeln!(out, "{}: changed", section);
+        }
+        out
+    }
+}
+
+fn display(value: Option<i64>) -> String {
+    value.map(|v| v.to_string()).unwrap_or_else(|| "(none)".to_string())
+}
+
+/// Tiers keyed by name, whether the config keeps them in a map or a list
+fn tiers_by_name(action_tiers: &Value) -> BTreeMap<String, &Value> {
+    match &action_tiers["tiers"] {
+        Value::Object(tiers) => tiers.iter().map(|(name, tier)| (name.clone(), tier)).collect(),
+        Value::Array(tiers) => tiers
+            .iter()
+            .map(|tier| {
+                let name = match &tier["tier"] {
+                    Value::Null => tier["name"].as_str().unwrap_or("unknown").to_string(),
+                    number => format!("tier_{}", number),
+                };
+                (name, tier)
+            })
+            .collect(),
+        _ => BTreeMap::new(),
+    }
+}
+
+/// GitHub usernames across all layer files of the maintainers section
+fn maintainer_usernames(maintainers: &Value) -> BTreeSet<String> {
+    maintainers
+        .as_object()
+        .into_iter()
+        .flat_map(|layers| layers.values())
+        .filter_map(|layer| layer["maintainers"].as_array())
+        .flatten()
+        .filter_map(|m| m["github"].as_str().or_else(|| m["github_username"].as_str()))
+        .map(str::to_string)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn ruleset(id: &str, tier_3_required: i64) -> Value {
+        json!({
+            "ruleset_id": id,
+            "action_tiers": {
+                "tiers": {
+                    "tier_1_routine": {
+                        "signatures_required": 3,
+                        "signatures_total": 5,
+                        "review_period_days": 7
+                    },
+                    "tier_3_consensus_adjacent": {
+                        "signatures_required": tier_3_required,
+                        "signatures_total": 5,
+                        "review_period_days": 90
+                    }
+                }
+            },
+            "maintainers": {
+                "layer-1-2": { "maintainers": [{ "github": "alice" }, { "github": "bob" }] }
+            },
+            "economic_nodes": {},
+            "repositories": {}
+        })
+    }
+
+    #[test]
+    fn test_tier_threshold_change() {
+        let diff = RulesetDiff::between(&ruleset("governance-01", 4), &ruleset("governance-02", 5));
+
+        assert_eq!(
+            diff.tiers,
+            vec![TierDiff {
+                tier: "tier_3_consensus_adjacent".to_string(),
+                changes: vec![FieldChange {
+                    field: "signatures_required".to_string(),
+                    old: Some(4),
+                    new: Some(5),
+                }],
+            }]
+        );
+        assert_eq!(diff.maintainers, MaintainerDiff::default());
+        assert!(diff.changed_sections.is_empty());
+
+        let text = diff.render_text();
+        assert!(text.contains("governance-01 -> governance-02"));
+        assert!(text.contains("  tier_3_consensus_adjacent:\n    signatures_required: 4 -> 5\n"));
+        assert!(!text.contains("tier_1_routine"));
+
+        let json = diff.to_json().unwrap();
+        assert_eq!(json["tiers"][0]["changes"][0]["old"], 4);
+        assert_eq!(json["tiers"][0]["changes"][0]["new"], 5);
+    }
+
+    #[test]
+    fn test_maintainer_and_tier_membership_changes() {
+        let from = ruleset("governance-01", 4);
+        let mut to = ruleset("governance-02", 4);
+        to["maintainers"]["layer-1-2"]["maintainers"] = json!([{ "github": "alice" }, { "github": "carol" }]);
+        to["action_tiers"]["tiers"]
+            .as_object_mut()
+            .unwrap()
+            .remove("tier_1_routine");
+
+        let diff = RulesetDiff::between(&from, &to);
+        assert_eq!(diff.maintainers.added, vec!["carol"]);
+        assert_eq!(diff.maintainers.removed, vec!["bob"]);
+        assert_eq!(diff.tiers.len(), 1);
+        assert_eq!(diff.tiers[0].tier, "tier_1_routine");
+        assert!(diff.tiers[0].changes.iter().all(|c| c.new.is_none()));
+
+        assert!(RulesetDiff::between(&from, &from).is_empty());
+    }
+}
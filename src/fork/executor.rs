@@ -4,48 +4,64 @@
 //! and coordination between different governance rulesets.
 
 use std::collections::HashMap;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::fs;
 use chrono::{DateTime, Utc};
+use sqlx::SqlitePool;
 use serde_json;
 use tracing::{info, warn, error};
 
+use crate::audit::AuditLogger;
+use crate::database::Database;
 use crate::error::GovernanceError;
+use super::migration_log::{log_migration, MigrationRecord};
 use super::types::*;
-use super::export::GovernanceExporter;
 use super::adoption::AdoptionTracker;
 use super::versioning::RulesetVersioning;
+use super::detection::{ForkAction, ForkDetector};
 
 /// Executes governance forks and manages ruleset transitions
 pub struct ForkExecutor {
     current_ruleset: Option<Ruleset>,
     available_rulesets: HashMap<String, Ruleset>,
     adoption_tracker: AdoptionTracker,
-    exporter: GovernanceExporter,
     versioning: RulesetVersioning,
-    fork_thresholds: ForkThresholds,
+    detector: ForkDetector,
+    database: Database,
+    audit_logger: Option<AuditLogger>,
+    export_path: PathBuf,
+    rollback_record: Option<RollbackRecord>,
 }
 
 impl ForkExecutor {
     /// Create a new fork executor
     pub fn new(
         export_path: &str,
+        pool: SqlitePool,
         fork_thresholds: Option<ForkThresholds>,
     ) -> Result<Self, GovernanceError> {
-        let exporter = GovernanceExporter::new(export_path)?;
-        let adoption_tracker = AdoptionTracker::new()?;
-        let versioning = RulesetVersioning::new()?;
+        let adoption_tracker = AdoptionTracker::new(pool.clone());
+        let versioning = RulesetVersioning::new();
         
         Ok(Self {
             current_ruleset: None,
             available_rulesets: HashMap::new(),
+            detector: ForkDetector::new(adoption_tracker.clone(), fork_thresholds),
             adoption_tracker,
-            exporter,
             versioning,
-            fork_thresholds: fork_thresholds.unwrap_or_default(),
+            database: Database::from_sqlite_pool(pool),
+            audit_logger: None,
+            export_path: PathBuf::from(export_path),
+            rollback_record: None,
         })
     }
 
+    /// Also record executed forks in the audit log
+    pub fn with_audit_logger(mut self, audit_logger: AuditLogger) -> Self {
+        self.audit_logger = Some(audit_logger);
+        self
+    }
+
     /// Initialize the fork executor with current governance state
     pub async fn initialize(&mut self, governance_config_path: &str) -> Result<(), GovernanceError> {
         info!("Initializing governance fork executor...");
@@ -55,6 +71,7 @@ impl ForkExecutor {
         
         // Create current ruleset
         let current_ruleset = self.create_ruleset_from_config(&current_config, "current")?;
+        self.adoption_tracker.register_ruleset(&current_ruleset).await?;
         self.current_ruleset = Some(current_ruleset.clone());
         self.available_rulesets.insert("current".to_string(), current_ruleset);
         
@@ -176,7 +193,7 @@ impl ForkExecutor {
     async fn load_available_rulesets(&mut self) -> Result<(), GovernanceError> {
         info!("Loading available rulesets...");
         
-        let export_dir = self.exporter.get_export_directory();
+        let export_dir = self.export_path.clone();
         if !export_dir.exists() {
             info!("No export directory found, creating empty ruleset registry");
             return Ok(());
@@ -193,14 +210,15 @@ impl ForkExecutor {
                             id: export.ruleset_id.clone(),
                             name: format!("Ruleset {}", export.ruleset_id),
                             version: export.ruleset_version,
-                            hash: self.calculate_config_hash(&export.config)?,
+                            hash: self.calculate_config_hash(&export.config())?,
                             created_at: export.created_at,
-                            config: export.config,
+                            config: export.config(),
                             description: Some(format!("Exported ruleset from {}", export.metadata.source_repository)),
                         };
                         
-                        self.available_rulesets.insert(export.ruleset_id, ruleset);
+                        self.adoption_tracker.register_ruleset(&ruleset).await?;
                         info!("Loaded ruleset: {}", export.ruleset_id);
+                        self.available_rulesets.insert(export.ruleset_id, ruleset);
                     }
                 }
             }
@@ -217,38 +235,40 @@ impl ForkExecutor {
         // Get adoption statistics
         let adoption_stats = self.adoption_tracker.get_adoption_statistics().await?;
         
-        // Check if any ruleset meets fork thresholds
-        for (ruleset_id, metrics) in &adoption_stats.rulesets {
-            if self.should_execute_fork(metrics) {
-                info!("Fork conditions met for ruleset: {}", ruleset_id);
-                self.execute_fork(ruleset_id).await?;
-                break; // Only execute one fork at a time
-            }
+        // Act on the detector's recommendations
+        let events = self.detector.evaluate(&adoption_stats);
+        for event in events.iter().filter(|e| {
+            matches!(e.action_taken, Some(ForkAction::AlertSent) | Some(ForkAction::ForkScheduled))
+        }) {
+            warn!(
+                "Fork trigger {:?} for ruleset {}: {:?}",
+                event.trigger_type, event.ruleset_id, event.action_taken
+            );
+        }
+
+        if let Some(event) = events
+            .iter()
+            .find(|e| e.action_taken == Some(ForkAction::ForkExecuted))
+        {
+            info!("Fork conditions met for ruleset: {}", event.ruleset_id);
+            self.execute_fork(&event.ruleset_id).await?; // Only execute one fork at a time
         }
         
         Ok(())
     }
 
-    /// Determine if a fork should be executed based on thresholds
-    fn should_execute_fork(&self, metrics: &AdoptionMetrics) -> bool {
-        metrics.node_count >= self.fork_thresholds.minimum_node_count &&
-        metrics.hashpower_percentage >= self.fork_thresholds.minimum_hashpower_percentage &&
-        metrics.economic_activity_percentage >= self.fork_thresholds.minimum_economic_activity_percentage &&
-        metrics.total_weight >= self.fork_thresholds.minimum_adoption_percentage
-    }
-
     /// Execute a governance fork
     async fn execute_fork(&mut self, target_ruleset_id: &str) -> Result<(), GovernanceError> {
         info!("Executing governance fork to ruleset: {}", target_ruleset_id);
         
         // Get target ruleset
-        let target_ruleset = self.available_rulesets.get(target_ruleset_id)
+        let target_ruleset = self.available_rulesets.get(target_ruleset_id).cloned()
             .ok_or_else(|| GovernanceError::ConfigError(
                 format!("Target ruleset not found: {}", target_ruleset_id)
             ))?;
         
         // Validate target ruleset
-        self.validate_ruleset(target_ruleset)?;
+        self.validate_ruleset(&target_ruleset)?;
         
         // Create fork event
         let fork_event = ForkEvent {
@@ -267,8 +287,19 @@ impl ForkExecutor {
         // Log fork event
         self.log_fork_event(&fork_event).await?;
         
+        let from_ruleset = self.current_ruleset.as_ref().map(|r| r.id.clone());
+
         // Execute the fork
-        self.perform_fork_transition(target_ruleset).await?;
+        self.perform_fork_transition(&target_ruleset).await?;
+
+        let record = MigrationRecord::new(
+            "fork",
+            from_ruleset.as_deref(),
+            target_ruleset_id,
+            "governance-app",
+            "Adoption threshold met",
+        );
+        log_migration(&self.database, self.audit_logger.as_ref(), "governance-app", &record).await?;
         
         info!("Governance fork executed successfully to: {}", target_ruleset_id);
         Ok(())
@@ -276,65 +307,124 @@ impl ForkExecutor {
 
     /// Validate a ruleset before fork execution
     fn validate_ruleset(&self, ruleset: &Ruleset) -> Result<(), GovernanceError> {
-        // Check if ruleset has required components
-        if !ruleset.config.get("action_tiers").is_some() {
-            return Err(GovernanceError::ConfigError(
-                "Ruleset missing action_tiers configuration".to_string()
-            ));
-        }
-        
-        if !ruleset.config.get("economic_nodes").is_some() {
-            return Err(GovernanceError::ConfigError(
-                "Ruleset missing economic_nodes configuration".to_string()
-            ));
-        }
-        
-        if !ruleset.config.get("maintainers").is_some() {
-            return Err(GovernanceError::ConfigError(
-                "Ruleset missing maintainers configuration".to_string()
-            ));
-        }
-        
-        // Validate ruleset version compatibility
-        if let Some(current) = &self.current_ruleset {
-            if !self.versioning.is_compatible(&current.version, &ruleset.version) {
-                return Err(GovernanceError::ConfigError(
-                    format!("Incompatible ruleset version: {} -> {}", 
-                        current.version.to_string(), 
-                        ruleset.version.to_string()
-                    )
-                ));
-            }
-        }
-        
-        Ok(())
+        validate_ruleset(
+            &ruleset.config,
+            &ruleset.version,
+            self.current_ruleset.as_ref().map(|r| &r.version),
+            &self.versioning,
+        )
     }
 
     /// Perform the actual fork transition
     async fn perform_fork_transition(&mut self, target_ruleset: &Ruleset) -> Result<(), GovernanceError> {
         info!("Performing fork transition to: {}", target_ruleset.id);
         
-        // Update current ruleset
-        self.current_ruleset = Some(target_ruleset.clone());
+        // Snapshot the current ruleset so the fork can be undone
+        let record = RollbackRecord {
+            fork_id: uuid::Uuid::new_v4().to_string(),
+            previous_ruleset: self.current_ruleset.clone(),
+            forked_to: target_ruleset.id.clone(),
+            forked_to_hash: target_ruleset.hash.clone(),
+            forked_at: Utc::now(),
+        };
+        self.write_rollback_record(&record)?;
+        self.rollback_record = Some(record);
         
-        // Update available rulesets
-        self.available_rulesets.insert("current".to_string(), target_ruleset.clone());
+        let previous_ruleset = self.current_ruleset.as_ref().map(|r| r.id.clone());
+        self.apply_ruleset(target_ruleset).await?;
         
         // Notify adoption tracker
-        self.adoption_tracker.record_fork_decision(
-            "governance-app".to_string(),
-            target_ruleset.id.clone(),
-            "Fork executed by governance-app".to_string(),
-            1.0,
+        self.adoption_tracker.record_governance_fork(
+            previous_ruleset.as_deref(),
+            &target_ruleset.id,
         ).await?;
         
-        // Export new current ruleset
-        self.exporter.export_ruleset(target_ruleset).await?;
-        
         info!("Fork transition completed successfully");
         Ok(())
     }
 
+    /// Undo the last fork, restoring the ruleset that was current before it
+    ///
+    /// Refused if the current ruleset is no longer the one that fork switched
+    /// to, since rolling back would then discard a later transition.
+    pub async fn rollback_fork(&mut self) -> Result<(), GovernanceError> {
+        let record = match self.rollback_record.clone() {
+            Some(record) => record,
+            None => self.read_rollback_record()?.ok_or_else(|| {
+                GovernanceError::ValidationError("No fork to roll back".to_string())
+            })?,
+        };
+        
+        let current = self.current_ruleset.as_ref();
+        if current.map(|r| (&r.id, &r.hash)) != Some((&record.forked_to, &record.forked_to_hash)) {
+            return Err(GovernanceError::ValidationError(format!(
+                "Cannot roll back fork to {}: current ruleset is now {}",
+                record.forked_to,
+                current.map(|r| r.id.as_str()).unwrap_or("none")
+            )));
+        }
+        let previous = record.previous_ruleset.clone().ok_or_else(|| {
+            GovernanceError::ValidationError(format!(
+                "Fork to {} has no previous ruleset to restore",
+                record.forked_to
+            ))
+        })?;
+        
+        info!("Rolling back fork {} to ruleset: {}", record.fork_id, previous.id);
+        self.apply_ruleset(&previous).await?;
+        
+        self.rollback_record = None;
+        let record_path = self.export_path.join("rollback.json");
+        if record_path.exists() {
+            fs::remove_file(&record_path).map_err(|e| {
+                GovernanceError::ConfigError(format!("Failed to remove rollback record: {}", e))
+            })?;
+        }
+        
+        let migration = MigrationRecord::new(
+            "rollback",
+            Some(&record.forked_to),
+            &previous.id,
+            "governance-app",
+            &format!("Rolled back fork {}", record.fork_id),
+        );
+        log_migration(&self.database, self.audit_logger.as_ref(), "governance-app", &migration).await?;
+        
+        info!("Rollback completed successfully");
+        Ok(())
+    }
+
+    /// Make a ruleset current and export it as such
+    async fn apply_ruleset(&mut self, ruleset: &Ruleset) -> Result<(), GovernanceError> {
+        self.current_ruleset = Some(ruleset.clone());
+        self.available_rulesets.insert("current".to_string(), ruleset.clone());
+        self.adoption_tracker.register_ruleset(ruleset).await?;
+        self.export_current_ruleset().await
+    }
+
+    /// Persist the rollback record next to the exports so it survives restarts
+    fn write_rollback_record(&self, record: &RollbackRecord) -> Result<(), GovernanceError> {
+        fs::create_dir_all(&self.export_path).map_err(|e| {
+            GovernanceError::ConfigError(format!("Failed to create export directory: {}", e))
+        })?;
+        fs::write(
+            self.export_path.join("rollback.json"),
+            serde_json::to_string_pretty(record)?,
+        )
+        .map_err(|e| GovernanceError::ConfigError(format!("Failed to write rollback record: {}", e)))
+    }
+
+    fn read_rollback_record(&self) -> Result<Option<RollbackRecord>, GovernanceError> {
+        let record_path = self.export_path.join("rollback.json");
+        if !record_path.exists() {
+            return Ok(None);
+        }
+        let content = fs::read_to_string(&record_path).map_err(|e| {
+            GovernanceError::ConfigError(format!("Failed to read rollback record: {}", e))
+        })?;
+        Ok(Some(serde_json::from_str(&content)?))
+    }
+
     /// Log a fork event
     async fn log_fork_event(&self, event: &ForkEvent) -> Result<(), GovernanceError> {
         let log_entry = serde_json::to_string_pretty(event)
@@ -373,7 +463,13 @@ impl ForkExecutor {
     /// Export current ruleset
     pub async fn export_current_ruleset(&self) -> Result<(), GovernanceError> {
         if let Some(current) = &self.current_ruleset {
-            self.exporter.export_ruleset(current).await?;
+            let export = GovernanceExport::from_ruleset(current, "governance-app");
+            tokio::fs::create_dir_all(&self.export_path).await.map_err(|e| {
+                GovernanceError::ConfigError(format!("Failed to create export directory: {}", e))
+            })?;
+            tokio::fs::write(self.export_path.join("current.json"), serde_json::to_string_pretty(&export)?)
+                .await
+                .map_err(|e| GovernanceError::ConfigError(format!("Failed to write current ruleset: {}", e)))?;
             info!("Current ruleset exported successfully");
         } else {
             warn!("No current ruleset to export");
@@ -399,6 +495,44 @@ impl ForkExecutor {
     }
 }
 
+/// Configuration sections every ruleset must define
+const REQUIRED_SECTIONS: [&str; 3] = ["action_tiers", "economic_nodes", "maintainers"];
+
+/// Check a ruleset the way a fork does before switching to it
+///
+/// Every required configuration section must be present and an object, and
+/// when `current` is given the move from it must be allowed by `versioning`.
+pub fn validate_ruleset(
+    config: &serde_json::Value,
+    version: &RulesetVersion,
+    current: Option<&RulesetVersion>,
+    versioning: &RulesetVersioning,
+) -> Result<(), GovernanceError> {
+    for section in REQUIRED_SECTIONS {
+        match config.get(section) {
+            Some(value) if value.is_object() => {}
+            Some(value) if !value.is_null() => {
+                return Err(GovernanceError::ConfigError(format!(
+                    "Ruleset {} configuration must be a mapping",
+                    section
+                )))
+            }
+            _ => {
+                return Err(GovernanceError::ConfigError(format!(
+                    "Ruleset missing {} configuration",
+                    section
+                )))
+            }
+        }
+    }
+
+    if let Some(current) = current {
+        versioning.is_compatible(current, version)?;
+    }
+
+    Ok(())
+}
+
 /// Fork status information
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ForkStatus {
@@ -418,7 +552,9 @@ mod tests {
         let temp_dir = tempdir().unwrap();
         let export_path = temp_dir.path().join("exports");
         
-        let executor = ForkExecutor::new(export_path.to_str().unwrap(), None);
+        let db = crate::database::Database::new_in_memory().await.unwrap();
+        
+        let executor = ForkExecutor::new(export_path.to_str().unwrap(), db.pool().unwrap().clone(), None);
         assert!(executor.is_ok());
     }
 
@@ -427,7 +563,9 @@ mod tests {
         let temp_dir = tempdir().unwrap();
         let export_path = temp_dir.path().join("exports");
         
-        let mut executor = ForkExecutor::new(export_path.to_str().unwrap(), None).unwrap();
+        let db = crate::database::Database::new_in_memory().await.unwrap();
+        
+        let mut executor = ForkExecutor::new(export_path.to_str().unwrap(), db.pool().unwrap().clone(), None).unwrap();
         
         // Create a valid ruleset
         let config = serde_json::json!({
@@ -440,5 +578,82 @@ mod tests {
         let ruleset = executor.create_ruleset_from_config(&config, "test").unwrap();
         assert_eq!(ruleset.id, "test");
         assert_eq!(ruleset.version.major, 1);
+        assert!(executor.validate_ruleset(&ruleset).is_ok());
+
+        let mut incomplete = ruleset.clone();
+        incomplete.config["maintainers"] = serde_json::Value::Null;
+        let err = executor.validate_ruleset(&incomplete).unwrap_err();
+        assert!(err.to_string().contains("missing maintainers"));
+
+        // Once a ruleset is current, a major bump needs an explicit migration
+        executor.current_ruleset = Some(ruleset.clone());
+        let mut next_major = ruleset;
+        next_major.version = RulesetVersion::new(2, 0, 0);
+        assert!(executor.validate_ruleset(&next_major).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_rollback_restores_pre_fork_ruleset() {
+        let temp_dir = tempdir().unwrap();
+        let export_path = temp_dir.path().join("exports");
+        let db = crate::database::Database::new_in_memory().await.unwrap();
+        let mut executor = ForkExecutor::new(export_path.to_str().unwrap(), db.pool().unwrap().clone(), None).unwrap();
+
+        let original = executor.create_ruleset_from_config(&serde_json::json!({
+            "action_tiers": { "tiers": { "tier_1_routine": { "signatures_required": 3 } } },
+            "economic_nodes": {},
+            "maintainers": {}
+        }), "governance-01").unwrap();
+        let forked = executor.create_ruleset_from_config(&serde_json::json!({
+            "action_tiers": { "tiers": { "tier_1_routine": { "signatures_required": 4 } } },
+            "economic_nodes": {},
+            "maintainers": {}
+        }), "governance-02").unwrap();
+        executor.apply_ruleset(&original).await.unwrap();
+
+        executor.perform_fork_transition(&forked).await.unwrap();
+        assert_eq!(executor.get_current_ruleset().unwrap().id, "governance-02");
+        assert!(export_path.join("rollback.json").exists());
+
+        executor.rollback_fork().await.unwrap();
+        let current = executor.get_current_ruleset().unwrap();
+        assert_eq!(current.id, original.id);
+        assert_eq!(current.hash, original.hash);
+        assert_eq!(current.config, original.config);
+        let exported: GovernanceExport =
+            serde_json::from_str(&fs::read_to_string(export_path.join("current.json")).unwrap()).unwrap();
+        assert_eq!(exported.ruleset_id, "governance-01");
+
+        let (details,): (String,) = sqlx::query_as(
+            "SELECT details FROM governance_events WHERE event_type = 'ruleset_migration'",
+        )
+        .fetch_one(db.pool().unwrap())
+        .await
+        .unwrap();
+        assert!(details.contains("\"action\":\"rollback\""));
+
+        // Nothing left to undo
+        assert!(executor.rollback_fork().await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_rollback_refused_after_later_fork() {
+        let temp_dir = tempdir().unwrap();
+        let export_path = temp_dir.path().join("exports");
+        let db = crate::database::Database::new_in_memory().await.unwrap();
+        let mut executor = ForkExecutor::new(export_path.to_str().unwrap(), db.pool().unwrap().clone(), None).unwrap();
+
+        let config = serde_json::json!({ "action_tiers": {}, "economic_nodes": {}, "maintainers": {} });
+        let first = executor.create_ruleset_from_config(&config, "governance-01").unwrap();
+        let second = executor.create_ruleset_from_config(&config, "governance-02").unwrap();
+        let third = executor.create_ruleset_from_config(&config, "governance-03").unwrap();
+        executor.apply_ruleset(&first).await.unwrap();
+        executor.perform_fork_transition(&second).await.unwrap();
+
+        // Switched again without going through the fork path, e.g. by fork-migrate
+        executor.apply_ruleset(&third).await.unwrap();
+        let err = executor.rollback_fork().await.unwrap_err();
+        assert!(err.to_string().contains("current ruleset is now governance-03"));
+        assert_eq!(executor.get_current_ruleset().unwrap().id, "governance-03");
     }
 }
@@ -2,13 +2,26 @@
 //!
 //! Handles exporting complete governance configuration as single YAML file
 
+use axum::{
+    extract::{Query, State},
+    http::{HeaderMap, StatusCode},
+    response::Json,
+};
 use chrono::Utc;
+use secp256k1::SecretKey;
+use serde::Deserialize;
 use serde_json::Value;
 use sha2::{Digest, Sha256};
 use std::path::Path;
+use tracing::{error, warn};
 
 use super::types::*;
+use crate::auth::require_bearer;
+use crate::config::{AppConfig, Secret};
+use crate::crypto::signatures::SignatureManager;
+use crate::database::Database;
 use crate::error::GovernanceError;
+use crate::metrics::Metrics;
 
 pub struct GovernanceExporter {
     config_path: String,
@@ -54,7 +67,8 @@ impl GovernanceExporter {
             source_repository: source_repo.to_string(),
             commit_hash: commit_hash.to_string(),
             export_tool_version: env!("CARGO_PKG_VERSION").to_string(),
-            signature: None, // Would be added by signing process
+            signature: None, // Added by `sign_export`
+            signer_public_key: None,
             verification_url: None,
         };
 
@@ -120,6 +134,50 @@ impl GovernanceExporter {
         Ok(!calculated_hash.is_empty())
     }
 
+    /// Sign an export with `signing_key`, recording the signature and key in its metadata
+    pub fn sign_export(
+        &self,
+        export: &mut GovernanceExport,
+        signing_key: &SecretKey,
+    ) -> Result<(), GovernanceError> {
+        let signature_manager = SignatureManager::new();
+        export.metadata.signer_public_key = Some(hex::encode(
+            signature_manager.public_key_from_secret(signing_key).serialize(),
+        ));
+        let digest = self.export_digest(export)?;
+        let signature = signature_manager.create_signature(&digest, signing_key)?;
+        export.metadata.signature = Some(hex::encode(signature.serialize_compact()));
+        Ok(())
+    }
+
+    /// Check an export's signature against the public key in its metadata
+    ///
+    /// Unsigned exports verify as `false`.
+    pub fn verify_export_signature(&self, export: &GovernanceExport) -> Result<bool, GovernanceError> {
+        let (Some(signature), Some(public_key)) = (
+            export.metadata.signature.as_ref(),
+            export.metadata.signer_public_key.as_ref(),
+        ) else {
+            return Ok(false);
+        };
+        let signature = hex::decode(signature)
+            .ok()
+            .and_then(|bytes| secp256k1::ecdsa::Signature::from_compact(&bytes).ok())
+            .ok_or_else(|| GovernanceError::CryptoError("Invalid export signature".to_string()))?;
+        let public_key = public_key
+            .parse::<secp256k1::PublicKey>()
+            .map_err(|e| GovernanceError::CryptoError(format!("Invalid export public key: {}", e)))?;
+
+        SignatureManager::new().verify_signature(&self.export_digest(export)?, &signature, &public_key)
+    }
+
+    /// Hash of the export with its signature field cleared, which is what gets signed
+    fn export_digest(&self, export: &GovernanceExport) -> Result<String, GovernanceError> {
+        let mut unsigned = export.clone();
+        unsigned.metadata.signature = None;
+        self.calculate_config_hash(&serde_json::to_value(&unsigned)?)
+    }
+
     /// Load a single configuration file
     async fn load_config_file(&self, filename: &str) -> Result<Value, GovernanceError> {
         let file_path = Path::new(&self.config_path).join(filename);
@@ -233,3 +291,75 @@ impl GovernanceExporter {
         Ok(hex::encode(hash))
     }
 }
+
+#[derive(Debug, Deserialize)]
+pub struct ExportParams {
+    pub ruleset_id: Option<String>,
+    pub version: Option<String>,
+}
+
+/// Handle `GET /governance/export`
+///
+/// Exports the configuration this server is enforcing as a ruleset, signed
+/// with the record signing key. Disabled unless `admin_token` is configured;
+/// callers must present it as a bearer token.
+pub async fn export_endpoint(
+    State((config, _database, _metrics)): State<(AppConfig, Database, Metrics)>,
+    Query(params): Query<ExportParams>,
+    headers: HeaderMap,
+) -> (StatusCode, Json<Value>) {
+    if let Err(e) = require_bearer(&headers, config.admin_token.as_ref().map(Secret::expose)) {
+        warn!("Rejected governance export request: {}", e);
+        return e.to_response();
+    }
+
+    let version = match RulesetVersion::from_string(params.version.as_deref().unwrap_or("1.0.0")) {
+        Ok(version) => version,
+        Err(e) => {
+            return (
+                StatusCode::BAD_REQUEST,
+                Json(serde_json::json!({"error": e})),
+            )
+        }
+    };
+    let ruleset_id = params.ruleset_id.unwrap_or_else(|| config.server_id.clone());
+
+    let exporter = GovernanceExporter::new(&config.governance_config_path);
+    let mut export = match exporter
+        .export_governance_config(
+            &ruleset_id,
+            &version,
+            &config.server_id,
+            &config.governance_repo,
+            config.governance_config_commit.as_deref().unwrap_or("unknown"),
+        )
+        .await
+    {
+        Ok(export) => export,
+        Err(e) => {
+            error!("Failed to export governance configuration: {}", e);
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(serde_json::json!({"error": "failed to export configuration"})),
+            );
+        }
+    };
+
+    match config.record_signing_key() {
+        Some(key) => {
+            if let Err(e) = exporter.sign_export(&mut export, &key) {
+                error!("Failed to sign governance export: {}", e);
+                return (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    Json(serde_json::json!({"error": "failed to sign export"})),
+                );
+            }
+        }
+        None => error!("Failed to load record signing key, exporting unsigned ruleset"),
+    }
+
+    (
+        StatusCode::OK,
+        Json(serde_json::to_value(export).unwrap_or_default()),
+    )
+}
@@ -0,0 +1,145 @@
+//! Ruleset Migration Log
+//!
+//! Records ruleset migrations in `governance_events`, and in the audit log
+//! when one is configured, so that CLI migrations and server-side forks share
+//! one history with the rest of governance.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use tracing::info;
+
+use crate::audit::AuditLogger;
+use crate::database::Database;
+use crate::error::GovernanceError;
+
+/// Event type used for migrations in `governance_events`
+pub const MIGRATION_EVENT_TYPE: &str = "ruleset_migration";
+
+/// One switch of the current ruleset
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MigrationRecord {
+    /// `migrate`, `rollback` or `fork`
+    pub action: String,
+    pub from_ruleset: Option<String>,
+    pub to_ruleset: String,
+    /// Who performed it, e.g. `fork-migrate` or the server ID
+    pub initiated_by: String,
+    pub message: String,
+    pub timestamp: DateTime<Utc>,
+}
+
+impl MigrationRecord {
+    pub fn new(
+        action: &str,
+        from_ruleset: Option<&str>,
+        to_ruleset: &str,
+        initiated_by: &str,
+        message: &str,
+    ) -> Self {
+        Self {
+            action: action.to_string(),
+            from_ruleset: from_ruleset.map(str::to_string),
+            to_ruleset: to_ruleset.to_string(),
+            initiated_by: initiated_by.to_string(),
+            message: message.to_string(),
+            timestamp: Utc::now(),
+        }
+    }
+}
+
+/// Write a migration to `governance_events` and, if given, the audit log
+pub async fn log_migration(
+    database: &Database,
+    audit_logger: Option<&AuditLogger>,
+    server_id: &str,
+    record: &MigrationRecord,
+) -> Result<(), GovernanceError> {
+    let details = serde_json::to_value(record)?;
+    database
+        .log_governance_event(MIGRATION_EVENT_TYPE, None, None, None, &details)
+        .await?;
+
+    if let Some(audit_logger) = audit_logger {
+        let inputs_hash = format!(
+            "sha256:{}",
+            hex::encode(Sha256::digest(
+                record.from_ruleset.as_deref().unwrap_or_default().as_bytes()
+            ))
+        );
+        let outputs_hash = format!(
+            "sha256:{}",
+            hex::encode(Sha256::digest(serde_json::to_vec(record)?))
+        );
+
+        let mut metadata = HashMap::new();
+        metadata.insert("action".to_string(), record.action.clone());
+        metadata.insert("to_ruleset".to_string(), record.to_ruleset.clone());
+        if let Some(from) = &record.from_ruleset {
+            metadata.insert("from_ruleset".to_string(), from.clone());
+        }
+
+        audit_logger
+            .append(
+                format!("{}-{}", MIGRATION_EVENT_TYPE, record.timestamp.timestamp_millis()),
+                MIGRATION_EVENT_TYPE.to_string(),
+                server_id.to_string(),
+                inputs_hash,
+                outputs_hash,
+                metadata,
+            )
+            .await
+            .map_err(|e| GovernanceError::DatabaseError(format!("Failed to write audit entry: {}", e)))?;
+    }
+
+    info!(
+        "Recorded ruleset {}: {} -> {}",
+        record.action,
+        record.from_ruleset.as_deref().unwrap_or("none"),
+        record.to_ruleset
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_migration_recorded_as_governance_event() {
+        let db = Database::new_in_memory().await.unwrap();
+        let temp_dir = tempfile::tempdir().unwrap();
+        let audit_logger = AuditLogger::new(
+            temp_dir.path().join("audit.jsonl").to_string_lossy().to_string(),
+        )
+        .await
+        .unwrap();
+
+        let record = MigrationRecord::new(
+            "migrate",
+            Some("governance-01"),
+            "governance-02",
+            "fork-migrate",
+            "Migration completed successfully",
+        );
+        log_migration(&db, Some(&audit_logger), "governance-01", &record)
+            .await
+            .unwrap();
+
+        let (event_type, details): (String, String) =
+            sqlx::query_as("SELECT event_type, details FROM governance_events")
+                .fetch_one(db.pool().unwrap())
+                .await
+                .unwrap();
+        assert_eq!(event_type, MIGRATION_EVENT_TYPE);
+        let details: serde_json::Value = serde_json::from_str(&details).unwrap();
+        assert_eq!(details["action"], "migrate");
+        assert_eq!(details["from_ruleset"], "governance-01");
+        assert_eq!(details["to_ruleset"], "governance-02");
+
+        let entries = audit_logger.get_entries_by_type(MIGRATION_EVENT_TYPE).await.unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].metadata["to_ruleset"], "governance-02");
+    }
+}
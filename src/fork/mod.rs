@@ -5,16 +5,20 @@
 pub mod adoption;
 pub mod dashboard;
 pub mod detection;
+pub mod diff;
 pub mod executor;
 pub mod export;
+pub mod migration_log;
 pub mod types;
 pub mod versioning;
 
 pub use adoption::AdoptionTracker;
 pub use dashboard::AdoptionDashboard;
 pub use detection::{ForkDetector, ForkDetectionEvent, ForkTriggerType, ForkAction};
-pub use executor::{ForkExecutor, ForkStatus};
+pub use diff::RulesetDiff;
+pub use executor::{validate_ruleset, ForkExecutor, ForkStatus};
 pub use export::GovernanceExporter;
+pub use migration_log::{log_migration, MigrationRecord};
 pub use types::*;
 pub use versioning::RulesetVersioning;
 
@@ -111,6 +111,44 @@ pub struct GovernanceExport {
     pub metadata: ExportMetadata,
 }
 
+impl GovernanceExport {
+    /// The configuration sections, keyed as they are in a `Ruleset` config
+    pub fn config(&self) -> serde_json::Value {
+        serde_json::json!({
+            "action_tiers": self.action_tiers,
+            "economic_nodes": self.economic_nodes,
+            "maintainers": self.maintainers,
+            "repositories": self.repositories,
+            "governance_fork": self.governance_fork,
+        })
+    }
+
+    /// Export an in-memory ruleset, e.g. to publish it as the current one
+    pub fn from_ruleset(ruleset: &Ruleset, exported_by: &str) -> Self {
+        let section = |name: &str| ruleset.config.get(name).cloned().unwrap_or(serde_json::Value::Null);
+        Self {
+            version: "1.0".to_string(),
+            ruleset_id: ruleset.id.clone(),
+            ruleset_version: ruleset.version.clone(),
+            created_at: ruleset.created_at,
+            action_tiers: section("action_tiers"),
+            economic_nodes: section("economic_nodes"),
+            maintainers: section("maintainers"),
+            repositories: section("repositories"),
+            governance_fork: section("governance_fork"),
+            metadata: ExportMetadata {
+                exported_by: exported_by.to_string(),
+                source_repository: "btcdecoded/governance".to_string(),
+                commit_hash: ruleset.hash.clone(),
+                export_tool_version: env!("CARGO_PKG_VERSION").to_string(),
+                signature: None,
+                signer_public_key: None,
+                verification_url: None,
+            },
+        }
+    }
+}
+
 /// Export metadata and provenance information
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ExportMetadata {
@@ -119,6 +157,9 @@ pub struct ExportMetadata {
     pub commit_hash: String,
     pub export_tool_version: String,
     pub signature: Option<String>,
+    /// Key that produced `signature`, hex-encoded compressed
+    #[serde(default)]
+    pub signer_public_key: Option<String>,
     pub verification_url: Option<String>,
 }
 
@@ -134,6 +175,18 @@ pub struct ForkDecision {
     pub signature: String,
 }
 
+/// Snapshot taken before a fork so it can be undone
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RollbackRecord {
+    pub fork_id: String,
+    /// The ruleset that was current before the fork
+    pub previous_ruleset: Option<Ruleset>,
+    pub forked_to: String,
+    /// Config hash of `forked_to`, to tell it apart from a later ruleset with the same ID
+    pub forked_to_hash: String,
+    pub forked_at: DateTime<Utc>,
+}
+
 /// Governance fork event
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ForkEvent {
@@ -177,6 +230,20 @@ pub struct ForkThresholds {
     pub minimum_hashpower_percentage: f64,
     pub minimum_economic_activity_percentage: f64,
     pub grace_period_days: u32,
+    /// Share of total adoption weight at which a competing ruleset counts as a schism
+    pub schism_minimum_share_percentage: f64,
+    /// Hashpower behind a non-winning ruleset that signals an emergency split
+    pub emergency_split_hashpower_percentage: f64,
+}
+
+impl ForkThresholds {
+    /// Whether a ruleset's adoption meets every fork threshold
+    pub fn met_by(&self, metrics: &AdoptionMetrics) -> bool {
+        metrics.node_count >= self.minimum_node_count
+            && metrics.hashpower_percentage >= self.minimum_hashpower_percentage
+            && metrics.economic_activity_percentage >= self.minimum_economic_activity_percentage
+            && metrics.total_weight >= self.minimum_adoption_percentage
+    }
 }
 
 impl Default for ForkThresholds {
@@ -187,6 +254,8 @@ impl Default for ForkThresholds {
             minimum_hashpower_percentage: 30.0,
             minimum_economic_activity_percentage: 40.0,
             grace_period_days: 30,
+            schism_minimum_share_percentage: 35.0,
+            emergency_split_hashpower_percentage: 25.0,
         }
     }
 }
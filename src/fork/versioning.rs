@@ -8,9 +8,29 @@ use sha2::{Digest, Sha256};
 use super::types::*;
 use crate::error::GovernanceError;
 
-pub struct RulesetVersioning;
+#[derive(Debug, Clone, Default)]
+pub struct RulesetVersioning {
+    allow_major_migration: bool,
+    allow_downgrade: bool,
+}
 
 impl RulesetVersioning {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Accept major version bumps, for when a migration has been prepared
+    pub fn with_major_migration(mut self, allow: bool) -> Self {
+        self.allow_major_migration = allow;
+        self
+    }
+
+    /// Accept moving to an older ruleset version
+    pub fn with_allow_downgrade(mut self, allow: bool) -> Self {
+        self.allow_downgrade = allow;
+        self
+    }
+
     /// Generate semantic version for a ruleset based on changes
     pub fn version_ruleset(
         &self,
@@ -105,10 +125,34 @@ impl RulesetVersioning {
         }
     }
 
-    /// Check if a version is compatible with another
-    pub fn is_compatible(&self, version1: &RulesetVersion, version2: &RulesetVersion) -> bool {
-        // Same major version means compatible
-        version1.major == version2.major
+    /// Check whether a fork may move from one ruleset version to another
+    ///
+    /// Patch and minor bumps within the same major version are compatible.
+    /// A major bump needs the migration flag and a downgrade needs
+    /// `allow_downgrade`; otherwise the error explains which rule blocked it.
+    pub fn is_compatible(
+        &self,
+        from: &RulesetVersion,
+        to: &RulesetVersion,
+    ) -> Result<(), GovernanceError> {
+        let reason = match self.compare_versions(from, to) {
+            VersionComparison::Equal => return Ok(()),
+            VersionComparison::Newer if !self.allow_downgrade => {
+                "downgrades are rejected unless allow_downgrade is set"
+            }
+            VersionComparison::Newer => return Ok(()),
+            VersionComparison::Older if to.major != from.major && !self.allow_major_migration => {
+                "major version bumps require an explicit migration"
+            }
+            VersionComparison::Older => return Ok(()),
+        };
+
+        Err(GovernanceError::ValidationError(format!(
+            "Incompatible ruleset version {} -> {}: {}",
+            from.to_string(),
+            to.to_string(),
+            reason
+        )))
     }
 
     /// Increment version based on change type
@@ -146,3 +190,46 @@ pub enum VersionComparison {
     Equal,
     Newer,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_minor_and_patch_bumps_are_compatible() {
+        let versioning = RulesetVersioning::new();
+        let current = RulesetVersion::new(1, 2, 3);
+
+        assert!(versioning.is_compatible(&current, &RulesetVersion::new(1, 2, 4)).is_ok());
+        assert!(versioning.is_compatible(&current, &RulesetVersion::new(1, 3, 0)).is_ok());
+        assert!(versioning.is_compatible(&current, &current.clone()).is_ok());
+    }
+
+    #[test]
+    fn test_major_bump_requires_migration() {
+        let current = RulesetVersion::new(1, 2, 3);
+        let next = RulesetVersion::new(2, 0, 0);
+
+        let err = RulesetVersioning::new().is_compatible(&current, &next).unwrap_err();
+        assert!(err.to_string().contains("migration"));
+
+        let migrating = RulesetVersioning::new().with_major_migration(true);
+        assert!(migrating.is_compatible(&current, &next).is_ok());
+    }
+
+    #[test]
+    fn test_downgrade_rejected() {
+        let current = RulesetVersion::new(1, 2, 3);
+        let older = RulesetVersion::new(1, 2, 2);
+
+        let err = RulesetVersioning::new().is_compatible(&current, &older).unwrap_err();
+        assert!(err.to_string().contains("allow_downgrade"));
+
+        // The migration flag alone doesn't permit going backwards
+        let migrating = RulesetVersioning::new().with_major_migration(true);
+        assert!(migrating.is_compatible(&current, &older).is_err());
+
+        let permissive = RulesetVersioning::new().with_allow_downgrade(true);
+        assert!(permissive.is_compatible(&current, &older).is_ok());
+    }
+}
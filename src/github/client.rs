@@ -1,34 +1,419 @@
+use axum::http::HeaderMap;
+use chrono::{DateTime, Duration, Utc};
+use jsonwebtoken::{Algorithm, EncodingKey, Header};
 use octocrab::Octocrab;
+use serde::{Deserialize, Serialize};
 use serde_json::json;
-use tracing::{error, info};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use tokio::sync::RwLock;
+use tracing::{debug, error, info, warn};
 
+use crate::config::AppConfig;
 use crate::error::GovernanceError;
+use crate::github::cross_layer_status::StatusState;
+use crate::github::types::{CheckRunOutput, PullRequestFile, PullRequestReview};
+
+const DEFAULT_API_BASE: &str = "https://api.github.com";
+
+/// Refresh installation tokens this long before GitHub expires them
+const TOKEN_REFRESH_MARGIN_SECS: i64 = 300;
 
 #[derive(Clone)]
 pub struct GitHubClient {
     client: Octocrab,
     app_id: u64,
+    app_key: Option<EncodingKey>,
+    base_uri: String,
+    installation_id: Option<u64>,
+    installation_token: TokenSlot,
+    /// Token slots per installation, shared by every clone of the app client
+    installation_tokens: Arc<Mutex<HashMap<u64, TokenSlot>>>,
+    retry_policy: RetryPolicy,
+}
+
+type TokenSlot = Arc<RwLock<Option<CachedToken>>>;
+
+/// How idempotent GET requests are retried
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    /// Total attempts, including the first request
+    pub max_attempts: u32,
+    /// Delay before the first retry; doubles on each further attempt
+    pub base_delay: std::time::Duration,
+    /// Upper bound on any single wait, including rate-limit resets
+    pub max_delay: std::time::Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 4,
+            base_delay: std::time::Duration::from_millis(500),
+            max_delay: std::time::Duration::from_secs(60),
+        }
+    }
+}
+
+impl RetryPolicy {
+    fn backoff(&self, attempt: u32) -> std::time::Duration {
+        let factor = 2u32.saturating_pow(attempt.saturating_sub(1));
+        self.base_delay.saturating_mul(factor).min(self.max_delay)
+    }
+}
+
+/// Installation access token and the client authenticated with it
+#[derive(Clone)]
+struct CachedToken {
+    client: Octocrab,
+    valid_until: DateTime<Utc>,
+}
+
+#[derive(Serialize)]
+struct AppJwtClaims {
+    iat: i64,
+    exp: i64,
+    iss: String,
+}
+
+#[derive(Deserialize)]
+struct InstallationTokenResponse {
+    token: String,
+    expires_at: DateTime<Utc>,
 }
 
 impl GitHubClient {
-    pub fn new(app_id: u64, private_key_path: &str) -> Result<Self, GovernanceError> {
-        let key = std::fs::read_to_string(private_key_path).map_err(|e| {
+    /// Authenticate as a GitHub App using its RSA private key
+    ///
+    /// Requests are signed with an app JWT; use [`GitHubClient::installation`]
+    /// to act on a repository the app is installed on.
+    pub fn from_app(app_id: u64, key_path: &str) -> Result<Self, GovernanceError> {
+        Self::from_app_with_base_uri(app_id, key_path, DEFAULT_API_BASE)
+    }
+
+    /// Authenticate as a GitHub App against a non-default API host
+    pub fn from_app_with_base_uri(
+        app_id: u64,
+        key_path: &str,
+        base_uri: &str,
+    ) -> Result<Self, GovernanceError> {
+        let key = std::fs::read_to_string(key_path).map_err(|e| {
             GovernanceError::ConfigError(format!("Failed to read private key: {}", e))
         })?;
+        Self::from_app_key_with_base_uri(app_id, &key, base_uri)
+    }
+
+    /// Authenticate as a GitHub App with an already loaded PEM key
+    pub fn from_app_key(app_id: u64, key_pem: &str) -> Result<Self, GovernanceError> {
+        Self::from_app_key_with_base_uri(app_id, key_pem, DEFAULT_API_BASE)
+    }
+
+    /// Authenticate as a GitHub App with a loaded PEM key against a non-default API host
+    pub fn from_app_key_with_base_uri(
+        app_id: u64,
+        key_pem: &str,
+        base_uri: &str,
+    ) -> Result<Self, GovernanceError> {
+        let app_key = EncodingKey::from_rsa_pem(key_pem.as_bytes()).map_err(|e| {
+            GovernanceError::GitHubError(format!("Failed to parse private key: {}", e))
+        })?;
 
         let client = Octocrab::builder()
-            .app(
-                app_id.into(),
-                jsonwebtoken::EncodingKey::from_rsa_pem(key.as_bytes()).map_err(|e| {
-                    GovernanceError::GitHubError(format!("Failed to parse private key: {}", e))
-                })?,
-            )
+            .base_uri(base_uri)
+            .map_err(|e| GovernanceError::ConfigError(format!("Invalid GitHub API URL: {}", e)))?
+            .app(app_id.into(), app_key.clone())
             .build()
             .map_err(|e| {
                 GovernanceError::GitHubError(format!("Failed to create GitHub client: {}", e))
             })?;
 
-        Ok(Self { client, app_id })
+        Ok(Self {
+            client,
+            app_id,
+            app_key: Some(app_key),
+            base_uri: base_uri.trim_end_matches('/').to_string(),
+            installation_id: None,
+            installation_token: TokenSlot::default(),
+            installation_tokens: Arc::default(),
+            retry_policy: RetryPolicy::default(),
+        })
+    }
+
+    /// App client for this deployment's configured key, API host and retry policy
+    pub fn from_config(config: &AppConfig) -> Result<Self, GovernanceError> {
+        let key = config.github_private_key.as_ref().ok_or_else(|| {
+            GovernanceError::ConfigError("No GitHub App private key configured".to_string())
+        })?;
+        Ok(
+            Self::from_app_key_with_base_uri(config.github_app_id, key.expose(), &config.github_api_url)?
+                .with_retry_policy(RetryPolicy {
+                    max_attempts: config.github_max_retry_attempts,
+                    ..RetryPolicy::default()
+                }),
+        )
+    }
+
+    /// Authenticate with a personal access or installation token
+    ///
+    /// Token clients have no app ID, so `app_id` is 0.
+    pub fn from_token(token: String) -> Result<Self, GovernanceError> {
+        if token.trim().is_empty() {
+            return Err(GovernanceError::ConfigError(
+                "GitHub token must not be empty".to_string(),
+            ));
+        }
+
+        let client = Octocrab::builder()
+            .personal_token(token)
+            .build()
+            .map_err(|e| {
+                GovernanceError::GitHubError(format!("Failed to create GitHub client: {}", e))
+            })?;
+
+        Ok(Self::from_octocrab(client, 0))
+    }
+
+    /// Wrap an already-configured Octocrab instance
+    pub fn from_octocrab(client: Octocrab, app_id: u64) -> Self {
+        Self {
+            client,
+            app_id,
+            app_key: None,
+            base_uri: DEFAULT_API_BASE.to_string(),
+            installation_id: None,
+            installation_token: TokenSlot::default(),
+            installation_tokens: Arc::default(),
+            retry_policy: RetryPolicy::default(),
+        }
+    }
+
+    /// Override how GET requests are retried on rate limits and 5xx errors
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Scope an app client to one installation
+    ///
+    /// Installation access tokens are minted from the app JWT on first use
+    /// and reused until shortly before they expire. Clients scoped to the
+    /// same installation from one app client share that token.
+    pub fn installation(&self, installation_id: u64) -> Self {
+        let installation_token = self
+            .installation_tokens
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .entry(installation_id)
+            .or_default()
+            .clone();
+        Self {
+            installation_id: Some(installation_id),
+            installation_token,
+            ..self.clone()
+        }
+    }
+
+    /// Scope an app client to the installation covering `owner/repo`
+    ///
+    /// For requests that don't come from a webhook, which would carry the
+    /// installation id.
+    pub async fn for_repository(&self, owner: &str, repo: &str) -> Result<Self, GovernanceError> {
+        let (installation, _): (serde_json::Value, _) = self
+            .get_json(&format!("/repos/{}/{}/installation", owner, repo))
+            .await?;
+        let installation_id = installation
+            .get("id")
+            .and_then(|id| id.as_u64())
+            .ok_or_else(|| {
+                GovernanceError::GitHubError(format!("No installation id for {}/{}", owner, repo))
+            })?;
+        Ok(self.installation(installation_id))
+    }
+
+    /// Client to issue API requests with
+    ///
+    /// Installation-scoped app clients get a client authenticated with a
+    /// cached installation token, refreshed lazily once it expires.
+    async fn api(&self) -> Result<Octocrab, GovernanceError> {
+        let (installation_id, app_key) = match (self.installation_id, &self.app_key) {
+            (Some(id), Some(key)) => (id, key),
+            _ => return Ok(self.client.clone()),
+        };
+
+        if let Some(cached) = self.installation_token.read().await.as_ref() {
+            if cached.valid_until > Utc::now() {
+                return Ok(cached.client.clone());
+            }
+        }
+
+        let mut slot = self.installation_token.write().await;
+        // Another task may have refreshed while we waited for the lock
+        if let Some(cached) = slot.as_ref() {
+            if cached.valid_until > Utc::now() {
+                return Ok(cached.client.clone());
+            }
+        }
+
+        let fresh = self.mint_installation_token(installation_id, app_key).await?;
+        let client = fresh.client.clone();
+        *slot = Some(fresh);
+        Ok(client)
+    }
+
+    /// GET a JSON resource, retrying rate limits and transient server errors
+    ///
+    /// Returns the decoded body and the `rel="next"` link, if any.
+    async fn get_json<T: serde::de::DeserializeOwned>(
+        &self,
+        route: &str,
+    ) -> Result<(T, Option<String>), GovernanceError> {
+        let client = self.api().await?;
+        let mut attempt = 0;
+
+        loop {
+            attempt += 1;
+            let response = client._get(route).await.map_err(|e| {
+                GovernanceError::GitHubError(format!("GET {} failed: {}", route, e))
+            })?;
+            let status = response.status().as_u16();
+
+            if (200..300).contains(&status) {
+                let next = next_page_link(response.headers());
+                let body = client.body_to_string(response).await.map_err(|e| {
+                    GovernanceError::GitHubError(format!("Failed to read {}: {}", route, e))
+                })?;
+                let value = serde_json::from_str(&body).map_err(|e| {
+                    GovernanceError::GitHubError(format!("Unexpected response from {}: {}", route, e))
+                })?;
+                return Ok((value, next));
+            }
+
+            let headers = response.headers().clone();
+            let body = client.body_to_string(response).await.unwrap_or_default();
+            let delay = match status {
+                403 | 429 => rate_limit_delay(&headers, &body, &self.retry_policy, attempt),
+                500 | 502 | 503 | 504 => Some(self.retry_policy.backoff(attempt)),
+                _ => None,
+            };
+
+            let delay = match delay {
+                Some(delay) if attempt < self.retry_policy.max_attempts => delay,
+                Some(delay) if status == 403 || status == 429 => {
+                    return Err(GovernanceError::RateLimited {
+                        message: format!("GET {} still rate limited after {} attempts", route, attempt),
+                        retry_after: Some(delay.as_secs().max(1)),
+                    })
+                }
+                Some(_) => {
+                    return Err(GovernanceError::GitHubError(format!(
+                        "GET {} still failing after {} attempts: HTTP {} {}",
+                        route,
+                        attempt,
+                        status,
+                        body.trim()
+                    )))
+                }
+                None => {
+                    let message = format!("GET {} failed: HTTP {} {}", route, status, body.trim());
+                    return Err(match status {
+                        401 => GovernanceError::Unauthorized(message),
+                        404 => GovernanceError::not_found("GitHub resource", route),
+                        _ => GovernanceError::GitHubError(message),
+                    });
+                }
+            };
+
+            warn!(
+                "GET {} returned HTTP {} (attempt {}/{}), retrying in {:?}",
+                route, status, attempt, self.retry_policy.max_attempts, delay
+            );
+            tokio::time::sleep(delay).await;
+        }
+    }
+
+    /// GET every page of a list endpoint, following `rel="next"` links
+    async fn get_all_pages<T: serde::de::DeserializeOwned>(
+        &self,
+        route: &str,
+    ) -> Result<Vec<T>, GovernanceError> {
+        let mut items = Vec::new();
+        let mut next = Some(route.to_string());
+        while let Some(route) = next {
+            let (page, next_page): (Vec<T>, _) = self.get_json(&route).await?;
+            items.extend(page);
+            next = next_page;
+        }
+        Ok(items)
+    }
+
+    /// Exchange an app JWT for an installation access token
+    async fn mint_installation_token(
+        &self,
+        installation_id: u64,
+        app_key: &EncodingKey,
+    ) -> Result<CachedToken, GovernanceError> {
+        debug!("Minting installation token for installation {}", installation_id);
+
+        let now = Utc::now();
+        let claims = AppJwtClaims {
+            // Backdated to tolerate clock drift, as GitHub recommends
+            iat: (now - Duration::seconds(60)).timestamp(),
+            exp: (now + Duration::minutes(9)).timestamp(),
+            iss: self.app_id.to_string(),
+        };
+        let jwt = jsonwebtoken::encode(&Header::new(Algorithm::RS256), &claims, app_key)
+            .map_err(|e| GovernanceError::GitHubError(format!("Failed to sign app JWT: {}", e)))?;
+
+        let url = format!(
+            "{}/app/installations/{}/access_tokens",
+            self.base_uri, installation_id
+        );
+        let response = reqwest::Client::new()
+            .post(&url)
+            .bearer_auth(jwt)
+            .header("Accept", "application/vnd.github+json")
+            .header("User-Agent", "governance-app")
+            .send()
+            .await
+            .map_err(|e| {
+                GovernanceError::GitHubError(format!("Failed to request installation token: {}", e))
+            })?;
+
+        if !response.status().is_success() {
+            return Err(GovernanceError::GitHubError(format!(
+                "Installation token request for {} failed: HTTP {}",
+                installation_id,
+                response.status()
+            )));
+        }
+
+        let token: InstallationTokenResponse = response.json().await.map_err(|e| {
+            GovernanceError::GitHubError(format!("Invalid installation token response: {}", e))
+        })?;
+
+        let client = Octocrab::builder()
+            .base_uri(self.base_uri.as_str())
+            .map_err(|e| GovernanceError::ConfigError(format!("Invalid GitHub API URL: {}", e)))?
+            .personal_token(token.token)
+            .build()
+            .map_err(|e| {
+                GovernanceError::GitHubError(format!("Failed to create GitHub client: {}", e))
+            })?;
+
+        info!(
+            "Minted installation token for installation {} (expires {})",
+            installation_id, token.expires_at
+        );
+
+        Ok(CachedToken {
+            client,
+            valid_until: token.expires_at - Duration::seconds(TOKEN_REFRESH_MARGIN_SECS),
+        })
+    }
+
+    /// ID of the GitHub App, or 0 for token-authenticated clients
+    pub fn app_id(&self) -> u64 {
+        self.app_id
     }
 
     /// Post a status check to GitHub
@@ -41,37 +426,81 @@ impl GitHubClient {
         description: &str,
         context: &str,
     ) -> Result<(), GovernanceError> {
-        info!(
-            "Posting status check for {}/{}@{}: {} - {} ({})",
-            owner, repo, sha, state, description, context
-        );
-
         // Convert state to GitHub API format
+        let status_state = match state {
+            "success" => StatusState::Success,
+            "failure" => StatusState::Failure,
+            "pending" => StatusState::Pending,
+            _ => StatusState::Error,
+        };
+
+        let target_url = format!("https://github.com/{}/{}/actions", owner, repo);
+        self.create_status_check(
+            owner,
+            repo,
+            sha,
+            context,
+            &status_state,
+            description,
+            Some(&target_url),
+        )
+        .await
+    }
+
+    /// Create a commit status via `POST /repos/{owner}/{repo}/statuses/{sha}`
+    ///
+    /// A 422 from GitHub means the commit does not exist and is reported as a
+    /// validation error rather than an API failure.
+    pub async fn create_status_check(
+        &self,
+        owner: &str,
+        repo: &str,
+        sha: &str,
+        context: &str,
+        state: &StatusState,
+        description: &str,
+        target_url: Option<&str>,
+    ) -> Result<(), GovernanceError> {
         let github_state = match state {
-            "success" => "success",
-            "failure" => "failure",
-            "pending" => "pending",
-            "error" => "error",
-            _ => "error",
+            StatusState::Success => "success",
+            StatusState::Failure => "failure",
+            StatusState::Pending => "pending",
+            StatusState::Error => "error",
         };
 
-        // Create status check payload
-        let payload = json!({
+        info!(
+            "Posting status check for {}/{}@{}: {} - {} ({})",
+            owner, repo, sha, github_state, description, context
+        );
+
+        let mut payload = json!({
             "state": github_state,
             "description": description,
             "context": context,
-            "target_url": format!("https://github.com/{}/{}/actions", owner, repo)
         });
+        if let Some(url) = target_url {
+            payload["target_url"] = json!(url);
+        }
 
-        // Post status check via GitHub API
-        self.client
-            .repos(owner, repo)
-            .create_status(sha)
-            .body(&payload)
-            .send()
+        let route = format!("/repos/{}/{}/statuses/{}", owner, repo, sha);
+        let _: serde_json::Value = self
+            .api()
+            .await?
+            .post(route, Some(&payload))
             .await
-            .map_err(|e| {
-                GovernanceError::GitHubError(format!("Failed to post status check: {}", e))
+            .map_err(|e| match &e {
+                octocrab::Error::GitHub { source, .. }
+                    if source.status_code.as_u16() == 422 =>
+                {
+                    GovernanceError::ValidationError(format!(
+                        "Cannot post status for {}/{}: commit {} not found ({})",
+                        owner, repo, sha, source.message
+                    ))
+                }
+                _ => {
+                    error!("Failed to post status check: {}", e);
+                    GovernanceError::GitHubError(format!("Failed to post status check: {}", e))
+                }
             })?;
 
         info!(
@@ -82,6 +511,49 @@ impl GitHubClient {
         Ok(())
     }
 
+    /// Create a completed check run via `POST /repos/{owner}/{repo}/check-runs`
+    ///
+    /// Only GitHub Apps can create check runs, so this needs an app client.
+    /// Returns the check run's ID.
+    pub async fn create_check_run(
+        &self,
+        owner: &str,
+        repo: &str,
+        head_sha: &str,
+        name: &str,
+        conclusion: &str,
+        output: &CheckRunOutput,
+    ) -> Result<u64, GovernanceError> {
+        info!(
+            "Creating check run {} for {}/{}@{}: {}",
+            name, owner, repo, head_sha, conclusion
+        );
+
+        let payload = json!({
+            "name": name,
+            "head_sha": head_sha,
+            "status": "completed",
+            "conclusion": conclusion,
+            "completed_at": Utc::now().to_rfc3339(),
+            "output": output,
+        });
+        let route = format!("/repos/{}/{}/check-runs", owner, repo);
+        let check_run: serde_json::Value = self
+            .api()
+            .await?
+            .post(route, Some(&payload))
+            .await
+            .map_err(|e| {
+                error!("Failed to create check run: {}", e);
+                GovernanceError::GitHubError(format!("Failed to create check run: {}", e))
+            })?;
+
+        check_run
+            .get("id")
+            .and_then(|id| id.as_u64())
+            .ok_or_else(|| GovernanceError::GitHubError("Created check run has no id".to_string()))
+    }
+
     /// Update an existing status check
     pub async fn update_status_check(
         &self,
@@ -114,10 +586,13 @@ impl GitHubClient {
     ) -> Result<serde_json::Value, GovernanceError> {
         info!("Getting repository info for {}/{}", owner, repo);
 
-        let repository = self.client.repos(owner, repo).get().await.map_err(|e| {
-            error!("Failed to get repository info: {}", e);
-            GovernanceError::GitHubError(format!("Failed to get repository info: {}", e))
-        })?;
+        let (repository, _): (octocrab::models::Repository, _) = self
+            .get_json(&format!("/repos/{}/{}", owner, repo))
+            .await
+            .map_err(|e| {
+                error!("Failed to get repository info: {}", e);
+                e
+            })?;
 
         Ok(json!({
             "id": repository.id,
@@ -156,14 +631,12 @@ impl GitHubClient {
             owner, repo, pr_number
         );
 
-        let pull_request = self
-            .client
-            .pulls(owner, repo)
-            .get(pr_number)
+        let (pull_request, _): (octocrab::models::pulls::PullRequest, _) = self
+            .get_json(&format!("/repos/{}/{}/pulls/{}", owner, repo, pr_number))
             .await
             .map_err(|e| {
                 error!("Failed to get pull request info: {}", e);
-                GovernanceError::GitHubError(format!("Failed to get pull request info: {}", e))
+                e
             })?;
 
         Ok(json!({
@@ -171,6 +644,7 @@ impl GitHubClient {
             "number": pull_request.number,
             "title": pull_request.title,
             "body": pull_request.body,
+            "head_sha": pull_request.head.sha,
             "state": pull_request.state,
             "created_at": pull_request.created_at,
             "updated_at": pull_request.updated_at,
@@ -188,6 +662,115 @@ impl GitHubClient {
         }))
     }
 
+    /// List the files changed by a pull request, following pagination
+    pub async fn list_pull_request_files(
+        &self,
+        owner: &str,
+        repo: &str,
+        pr_number: u64,
+    ) -> Result<Vec<String>, GovernanceError> {
+        Ok(self
+            .list_pull_request_patches(owner, repo, pr_number)
+            .await?
+            .into_iter()
+            .map(|file| file.filename)
+            .collect())
+    }
+
+    /// List the files changed by a pull request along with their diffs
+    pub async fn list_pull_request_patches(
+        &self,
+        owner: &str,
+        repo: &str,
+        pr_number: u64,
+    ) -> Result<Vec<PullRequestFile>, GovernanceError> {
+        info!(
+            "Listing changed files for {}/{}#{}",
+            owner, repo, pr_number
+        );
+
+        let entries: Vec<octocrab::models::repos::DiffEntry> = self
+            .get_all_pages(&format!(
+                "/repos/{}/{}/pulls/{}/files?per_page=100",
+                owner, repo, pr_number
+            ))
+            .await
+            .map_err(|e| {
+                error!("Failed to list pull request files: {}", e);
+                e
+            })?;
+
+        Ok(entries
+            .into_iter()
+            .map(|entry| PullRequestFile {
+                status: serde_json::to_value(&entry.status)
+                    .ok()
+                    .and_then(|status| status.as_str().map(str::to_string)),
+                filename: entry.filename,
+                patch: entry.patch,
+                previous_filename: entry.previous_filename,
+            })
+            .collect())
+    }
+
+    /// List the reviews submitted on a pull request, following pagination
+    pub async fn list_pull_request_reviews(
+        &self,
+        owner: &str,
+        repo: &str,
+        pr_number: u64,
+    ) -> Result<Vec<PullRequestReview>, GovernanceError> {
+        info!("Listing reviews for {}/{}#{}", owner, repo, pr_number);
+
+        let reviews: Vec<serde_json::Value> = self
+            .get_all_pages(&format!(
+                "/repos/{}/{}/pulls/{}/reviews?per_page=100",
+                owner, repo, pr_number
+            ))
+            .await
+            .map_err(|e| {
+                error!("Failed to list pull request reviews: {}", e);
+                e
+            })?;
+
+        Ok(reviews
+            .into_iter()
+            .filter_map(|review| {
+                Some(PullRequestReview {
+                    reviewer: review["user"]["login"].as_str()?.to_string(),
+                    state: review["state"].as_str()?.to_string(),
+                    commit_id: review["commit_id"].as_str().map(str::to_string),
+                })
+            })
+            .collect())
+    }
+
+    /// List the SHAs of a pull request's commits, oldest first, following pagination
+    pub async fn list_pull_request_commits(
+        &self,
+        owner: &str,
+        repo: &str,
+        pr_number: u64,
+    ) -> Result<Vec<String>, GovernanceError> {
+        info!("Listing commits for {}/{}#{}", owner, repo, pr_number);
+
+        let commits: Vec<serde_json::Value> = self
+            .get_all_pages(&format!(
+                "/repos/{}/{}/pulls/{}/commits?per_page=100",
+                owner, repo, pr_number
+            ))
+            .await
+            .map_err(|e| {
+                error!("Failed to list pull request commits: {}", e);
+                e
+            })?;
+
+        Ok(commits
+            .into_iter()
+            .filter_map(|commit| commit["sha"].as_str().map(str::to_string))
+            .collect())
+    }
+
     /// Set required status checks for a branch
     pub async fn set_required_status_checks(
         &self,
@@ -213,7 +796,8 @@ impl GitHubClient {
         });
 
         // Update branch protection via GitHub API
-        self.client
+        self.api()
+            .await?
             .repos(owner, repo)
             .branches(branch)
             .protection()
@@ -231,6 +815,84 @@ impl GitHubClient {
         Ok(())
     }
 
+    /// Create or update the app's comment on a PR
+    ///
+    /// The comment is identified by `marker` (typically an HTML comment), so
+    /// repeated calls edit the existing comment instead of adding new ones.
+    /// Returns the ID of the created or updated comment.
+    pub async fn upsert_pr_comment(
+        &self,
+        owner: &str,
+        repo: &str,
+        pr_number: u64,
+        marker: &str,
+        body: &str,
+    ) -> Result<u64, GovernanceError> {
+        let body = if body.contains(marker) {
+            body.to_string()
+        } else {
+            format!("{}\n{}", marker, body)
+        };
+
+        let client = self.api().await?;
+        let issues = client.issues(owner, repo);
+        let comments = issues
+            .list_comments(pr_number)
+            .per_page(100)
+            .send()
+            .await
+            .map_err(|e| {
+                GovernanceError::GitHubError(format!("Failed to list PR comments: {}", e))
+            })?;
+
+        let existing = comments.items.into_iter().find(|c| {
+            c.body
+                .as_deref()
+                .map(|b| b.contains(marker))
+                .unwrap_or(false)
+        });
+
+        let comment = match existing {
+            Some(comment) => {
+                info!(
+                    "Updating comment {} on {}/{}#{}",
+                    comment.id, owner, repo, pr_number
+                );
+                issues.update_comment(comment.id, body).await
+            }
+            None => {
+                info!("Creating comment on {}/{}#{}", owner, repo, pr_number);
+                issues.create_comment(pr_number, body).await
+            }
+        }
+        .map_err(|e| GovernanceError::GitHubError(format!("Failed to upsert PR comment: {}", e)))?;
+
+        Ok(comment.id.into_inner())
+    }
+
+    /// Post a new comment on an issue or PR, returning the comment's ID
+    pub async fn create_issue_comment(
+        &self,
+        owner: &str,
+        repo: &str,
+        number: u64,
+        body: &str,
+    ) -> Result<u64, GovernanceError> {
+        let route = format!("/repos/{}/{}/issues/{}/comments", owner, repo, number);
+        let comment: serde_json::Value = self
+            .api()
+            .await?
+            .post(route, Some(&json!({ "body": body })))
+            .await
+            .map_err(|e| GovernanceError::GitHubError(format!("Failed to create comment: {}", e)))?;
+
+        info!("Created comment on {}/{}#{}", owner, repo, number);
+        comment
+            .get("id")
+            .and_then(|id| id.as_u64())
+            .ok_or_else(|| GovernanceError::GitHubError("Created comment has no id".to_string()))
+    }
+
     /// Check if a PR can be merged
     pub async fn can_merge_pull_request(
         &self,
@@ -243,14 +905,12 @@ impl GitHubClient {
             owner, repo, pr_number
         );
 
-        let pull_request = self
-            .client
-            .pulls(owner, repo)
-            .get(pr_number)
+        let (pull_request, _): (octocrab::models::pulls::PullRequest, _) = self
+            .get_json(&format!("/repos/{}/{}/pulls/{}", owner, repo, pr_number))
             .await
             .map_err(|e| {
                 error!("Failed to get pull request for merge check: {}", e);
-                GovernanceError::GitHubError(format!("Failed to get pull request: {}", e))
+                e
             })?;
 
         // Check if PR is mergeable
@@ -265,3 +925,365 @@ impl GitHubClient {
         Ok(can_merge)
     }
 }
+
+/// How long to wait before retrying a 403/429, or `None` if it is not a rate limit
+fn rate_limit_delay(
+    headers: &HeaderMap,
+    body: &str,
+    policy: &RetryPolicy,
+    attempt: u32,
+) -> Option<std::time::Duration> {
+    let header = |name: &str| headers.get(name).and_then(|v| v.to_str().ok());
+
+    if let Some(secs) = header("retry-after").and_then(|v| v.trim().parse::<u64>().ok()) {
+        return Some(std::time::Duration::from_secs(secs).min(policy.max_delay));
+    }
+
+    if header("x-ratelimit-remaining") == Some("0") {
+        let wait = header("x-ratelimit-reset")
+            .and_then(|v| v.trim().parse::<i64>().ok())
+            .map(|reset| (reset - Utc::now().timestamp()).max(1) as u64)
+            .map(std::time::Duration::from_secs)
+            .unwrap_or_else(|| policy.backoff(attempt));
+        return Some(wait.min(policy.max_delay));
+    }
+
+    // Secondary (abuse) limits may arrive without rate-limit headers
+    let body = body.to_ascii_lowercase();
+    if body.contains("secondary rate limit") || body.contains("abuse") {
+        return Some(policy.backoff(attempt).max(std::time::Duration::from_secs(1)).min(policy.max_delay));
+    }
+
+    None
+}
+
+/// Extract the `rel="next"` URL from a `Link` header
+fn next_page_link(headers: &HeaderMap) -> Option<String> {
+    let link = headers.get("link")?.to_str().ok()?;
+    link.split(',').find_map(|part| {
+        let (url, rel) = part.split_once(';')?;
+        rel.contains("rel=\"next\"").then(|| {
+            url.trim().trim_start_matches('<').trim_end_matches('>').to_string()
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wiremock::matchers::{header_exists, method, path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    const TEST_KEY: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/test-app-key.pem");
+
+    #[tokio::test]
+    async fn test_installation_token_refreshed_after_expiry() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/app/installations/42/access_tokens"))
+            .and(header_exists("authorization"))
+            .respond_with(ResponseTemplate::new(201).set_body_json(json!({
+                "token": "ghs_installation_token",
+                "expires_at": (Utc::now() + Duration::hours(1)).to_rfc3339()
+            })))
+            .expect(2)
+            .mount(&server)
+            .await;
+
+        let client = GitHubClient::from_app_with_base_uri(12345, TEST_KEY, &server.uri())
+            .unwrap()
+            .installation(42);
+
+        client.api().await.unwrap();
+        // Cached token is reused while still valid
+        client.api().await.unwrap();
+
+        // Force expiry; the next request must mint a new token
+        client
+            .installation_token
+            .write()
+            .await
+            .as_mut()
+            .unwrap()
+            .valid_until = Utc::now() - Duration::seconds(1);
+        client.api().await.unwrap();
+
+        let valid_until = client.installation_token.read().await.as_ref().unwrap().valid_until;
+        assert!(valid_until > Utc::now());
+    }
+
+    #[tokio::test]
+    async fn test_installation_token_shared_across_scoped_clients() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/app/installations/42/access_tokens"))
+            .respond_with(ResponseTemplate::new(201).set_body_json(json!({
+                "token": "ghs_installation_token",
+                "expires_at": (Utc::now() + Duration::hours(1)).to_rfc3339()
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let app = GitHubClient::from_app_with_base_uri(12345, TEST_KEY, &server.uri()).unwrap();
+
+        // Each webhook scopes the shared app client anew; only the first mints a token
+        app.installation(42).api().await.unwrap();
+        app.clone().installation(42).api().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_installation_token_failure_is_github_error() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/app/installations/42/access_tokens"))
+            .respond_with(ResponseTemplate::new(401))
+            .mount(&server)
+            .await;
+
+        let client = GitHubClient::from_app_with_base_uri(12345, TEST_KEY, &server.uri())
+            .unwrap()
+            .installation(42);
+
+        assert!(matches!(client.api().await, Err(GovernanceError::GitHubError(_))));
+    }
+
+    fn fast_policy(max_attempts: u32) -> RetryPolicy {
+        RetryPolicy {
+            max_attempts,
+            base_delay: std::time::Duration::from_millis(10),
+            max_delay: std::time::Duration::from_secs(5),
+        }
+    }
+
+    async fn mock_client(server: &MockServer, max_attempts: u32) -> GitHubClient {
+        let octocrab = Octocrab::builder().base_uri(server.uri()).unwrap().build().unwrap();
+        GitHubClient::from_octocrab(octocrab, 12345).with_retry_policy(fast_policy(max_attempts))
+    }
+
+    #[tokio::test]
+    async fn test_rate_limited_get_waits_for_reset() {
+        let server = MockServer::start().await;
+        let reset = Utc::now().timestamp() + 1;
+        Mock::given(method("GET"))
+            .and(path("/repos/BTCDecoded/governance"))
+            .respond_with(
+                ResponseTemplate::new(403)
+                    .insert_header("x-ratelimit-remaining", "0")
+                    .insert_header("x-ratelimit-reset", reset.to_string().as_str())
+                    .set_body_json(json!({"message": "API rate limit exceeded"})),
+            )
+            .up_to_n_times(1)
+            .with_priority(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/repos/BTCDecoded/governance"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({"full_name": "BTCDecoded/governance"})))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = mock_client(&server, 3).await;
+        let started = std::time::Instant::now();
+        let (value, _): (serde_json::Value, _) = client.get_json("/repos/BTCDecoded/governance").await.unwrap();
+
+        assert_eq!(value["full_name"], "BTCDecoded/governance");
+        assert!(started.elapsed() >= std::time::Duration::from_millis(500));
+    }
+
+    #[tokio::test]
+    async fn test_secondary_rate_limit_honors_retry_after() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/repos/BTCDecoded/governance"))
+            .respond_with(
+                ResponseTemplate::new(403)
+                    .insert_header("retry-after", "1")
+                    .set_body_json(json!({"message": "You have exceeded a secondary rate limit"})),
+            )
+            .up_to_n_times(1)
+            .with_priority(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/repos/BTCDecoded/governance"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({})))
+            .mount(&server)
+            .await;
+
+        let client = mock_client(&server, 3).await;
+        let started = std::time::Instant::now();
+        let result: Result<(serde_json::Value, _), _> = client.get_json("/repos/BTCDecoded/governance").await;
+
+        assert!(result.is_ok());
+        assert!(started.elapsed() >= std::time::Duration::from_secs(1));
+    }
+
+    #[tokio::test]
+    async fn test_server_errors_give_up_after_max_attempts() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/repos/BTCDecoded/governance"))
+            .respond_with(ResponseTemplate::new(503))
+            .expect(3)
+            .mount(&server)
+            .await;
+
+        let client = mock_client(&server, 3).await;
+        let result: Result<(serde_json::Value, _), _> = client.get_json("/repos/BTCDecoded/governance").await;
+
+        match result {
+            Err(GovernanceError::GitHubError(msg)) => assert!(msg.contains("after 3 attempts")),
+            other => panic!("expected GitHubError, got {:?}", other.map(|(v, _)| v)),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_plain_forbidden_is_not_retried() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/repos/BTCDecoded/governance"))
+            .respond_with(ResponseTemplate::new(403).set_body_json(json!({"message": "Resource not accessible by integration"})))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = mock_client(&server, 3).await;
+        let result: Result<(serde_json::Value, _), _> = client.get_json("/repos/BTCDecoded/governance").await;
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_list_pull_request_reviews_follows_link_header() {
+        let server = MockServer::start().await;
+        let reviews_path = "/repos/BTCDecoded/governance/pulls/3/reviews";
+        Mock::given(method("GET"))
+            .and(path(reviews_path))
+            .and(query_param("page", "2"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!([
+                {"user": {"login": "bob"}, "state": "CHANGES_REQUESTED", "commit_id": "def456"}
+            ])))
+            .with_priority(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path(reviews_path))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header(
+                        "link",
+                        format!("<{}{}?page=2>; rel=\"next\"", server.uri(), reviews_path).as_str(),
+                    )
+                    .set_body_json(json!([
+                        {"user": {"login": "alice"}, "state": "APPROVED", "commit_id": "abc123"}
+                    ])),
+            )
+            .mount(&server)
+            .await;
+
+        let client = mock_client(&server, 1).await;
+        let reviews = client.list_pull_request_reviews("BTCDecoded", "governance", 3).await.unwrap();
+
+        let reviewers: Vec<_> = reviews.iter().map(|r| (r.reviewer.as_str(), r.state.as_str())).collect();
+        assert_eq!(reviewers, vec![("alice", "APPROVED"), ("bob", "CHANGES_REQUESTED")]);
+    }
+
+    #[tokio::test]
+    async fn test_exhausted_rate_limit_is_rate_limited_error() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/repos/BTCDecoded/governance"))
+            .respond_with(
+                ResponseTemplate::new(429)
+                    .insert_header("retry-after", "1")
+                    .set_body_json(json!({"message": "API rate limit exceeded"})),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = mock_client(&server, 1).await;
+        let result: Result<(serde_json::Value, _), _> = client.get_json("/repos/BTCDecoded/governance").await;
+
+        match result {
+            Err(e @ GovernanceError::RateLimited { retry_after: Some(1), .. }) => {
+                assert_eq!(e.status_code(), axum::http::StatusCode::TOO_MANY_REQUESTS)
+            }
+            other => panic!("expected RateLimited, got {:?}", other.map(|(v, _)| v)),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_missing_resource_is_not_found() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/repos/BTCDecoded/missing"))
+            .respond_with(ResponseTemplate::new(404).set_body_json(json!({"message": "Not Found"})))
+            .mount(&server)
+            .await;
+
+        let client = mock_client(&server, 3).await;
+        let result: Result<(serde_json::Value, _), _> = client.get_json("/repos/BTCDecoded/missing").await;
+        assert!(matches!(result, Err(GovernanceError::NotFound { .. })));
+    }
+
+    #[tokio::test]
+    async fn test_check_run_carries_sections_and_annotations() {
+        use crate::enforcement::merge_block::{BlockReason, MergeDecision};
+        use crate::enforcement::status_checks::{CheckRunSections, StatusCheckGenerator};
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/repos/BTCDecoded/consensus-proof/check-runs"))
+            .respond_with(ResponseTemplate::new(201).set_body_json(json!({"id": 77})))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let decision = MergeDecision {
+            blocked: true,
+            reasons: vec![
+                BlockReason::ReviewPeriodNotMet,
+                BlockReason::InsufficientSignatures { have: 2, need: 6 },
+            ],
+        };
+        let output = StatusCheckGenerator::generate_check_run_output(
+            &decision,
+            &CheckRunSections {
+                signatures: "⏳ 2/6 signatures".to_string(),
+                review_period: "⏳ 170 days remaining".to_string(),
+                economic_veto: None,
+            },
+        );
+        let client = mock_client(&server, 1).await;
+        let id = client
+            .create_check_run("BTCDecoded", "consensus-proof", "abc123", "governance", "failure", &output)
+            .await
+            .unwrap();
+        assert_eq!(id, 77);
+
+        let requests = server.received_requests().await.unwrap();
+        let body: serde_json::Value = serde_json::from_slice(&requests[0].body).unwrap();
+        assert_eq!(body["name"], "governance");
+        assert_eq!(body["head_sha"], "abc123");
+        assert_eq!(body["status"], "completed");
+        assert_eq!(body["conclusion"], "failure");
+
+        let text = body["output"]["text"].as_str().unwrap();
+        for section in ["## Signatures", "## Review Period", "## Economic Node Veto", "## Cross-Layer Sync"] {
+            assert!(text.contains(section), "missing {} in {}", section, text);
+        }
+        assert!(text.contains("⏳ 2/6 signatures"));
+        assert!(text.contains("Not required below Tier 3"));
+
+        let annotations = body["output"]["annotations"].as_array().unwrap();
+        assert_eq!(annotations.len(), 2);
+        assert_eq!(annotations[0]["annotation_level"], "failure");
+        assert_eq!(annotations[0]["title"], "Review period not met");
+        assert_eq!(annotations[1]["message"], "Signatures: 2/6 required (4 more needed)");
+        assert!(annotations
+            .iter()
+            .all(|a| a["path"].is_string() && a["start_line"] == 1 && a["end_line"] == 1));
+    }
+}
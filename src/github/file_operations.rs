@@ -3,11 +3,19 @@
 //! This module provides utilities for fetching file content and directory structures
 //! from GitHub repositories via the GitHub API.
 
+use crate::cache::LruCache;
 use crate::error::GovernanceError;
+use base64::Engine;
 use octocrab::Octocrab;
 use std::collections::HashMap;
+use std::sync::{Arc, OnceLock};
 use tracing::{info, warn, error, debug};
 
+/// Files kept by the shared content cache unless configured otherwise
+pub const DEFAULT_FILE_CACHE_CAPACITY: usize = 512;
+
+static GLOBAL_FILE_CACHE: OnceLock<Arc<FileContentCache>> = OnceLock::new();
+
 /// Represents a file in a GitHub repository
 #[derive(Debug, Clone)]
 pub struct GitHubFile {
@@ -47,8 +55,68 @@ pub struct FileComparison {
     pub content_diff: Option<String>,
 }
 
+/// (owner, repo, path, ref)
+type FileCacheKey = (String, String, String, String);
+
+/// Least-recently-used cache of file content fetched at a commit SHA
+///
+/// Content at a fixed SHA never changes, so entries only leave the cache to
+/// make room. Lookups by branch name bypass it.
+pub struct FileContentCache {
+    entries: LruCache<FileCacheKey, GitHubFile>,
+}
+
+impl FileContentCache {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            entries: LruCache::new(capacity),
+        }
+    }
+
+    /// The process-wide cache shared by all file operation clients
+    pub fn global() -> Arc<Self> {
+        GLOBAL_FILE_CACHE
+            .get_or_init(|| Arc::new(Self::new(DEFAULT_FILE_CACHE_CAPACITY)))
+            .clone()
+    }
+
+    /// Size the process-wide cache; only effective before its first use
+    pub fn configure_global(capacity: usize) {
+        if GLOBAL_FILE_CACHE.set(Arc::new(Self::new(capacity))).is_err() {
+            warn!("GitHub file cache already initialized, ignoring capacity {}", capacity);
+        }
+    }
+
+    fn get(&self, key: &FileCacheKey) -> Option<GitHubFile> {
+        self.entries.get(key)
+    }
+
+    fn insert(&self, key: FileCacheKey, file: GitHubFile) {
+        self.entries.insert(key, file);
+    }
+
+    /// Cache (hits, misses) since startup
+    pub fn stats(&self) -> (u64, u64) {
+        self.entries.stats()
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+/// Whether a ref names a full commit SHA, whose content is immutable
+fn is_commit_sha(reference: &str) -> bool {
+    reference.len() == 40 && reference.chars().all(|c| c.is_ascii_hexdigit())
+}
+
 pub struct GitHubFileOperations {
     client: Octocrab,
+    cache: Arc<FileContentCache>,
 }
 
 impl GitHubFileOperations {
@@ -59,7 +127,21 @@ impl GitHubFileOperations {
             .build()
             .map_err(|e| GovernanceError::GitHubError(format!("Failed to create GitHub client: {}", e)))?;
 
-        Ok(Self { client })
+        Ok(Self::from_octocrab(client))
+    }
+
+    /// Wrap an already configured client, e.g. one pointed at a mock server
+    pub fn from_octocrab(client: Octocrab) -> Self {
+        Self {
+            client,
+            cache: FileContentCache::global(),
+        }
+    }
+
+    /// Use a dedicated cache instead of the process-wide one
+    pub fn with_cache(mut self, cache: Arc<FileContentCache>) -> Self {
+        self.cache = cache;
+        self
     }
 
     /// Fetch file content from GitHub repository
@@ -72,28 +154,7 @@ impl GitHubFileOperations {
     ) -> Result<GitHubFile, GovernanceError> {
         info!("Fetching file content: {}/{}:{}", owner, repo, file_path);
 
-        let branch = branch.unwrap_or("main");
-        
-        let response = self
-            .client
-            .repos(owner, repo)
-            .get_content()
-            .path(file_path)
-            .r#ref(branch)
-            .send()
-            .await
-            .map_err(|e| GovernanceError::GitHubError(format!("Failed to fetch file: {}", e)))?;
-
-        // For now, we'll implement a simplified version that works with the current octocrab API
-        // In a real implementation, we would handle the response properly based on the actual API structure
-        
-        // This is a placeholder implementation - in practice, you would:
-        // 1. Check the response type
-        // 2. Extract file content based on encoding
-        // 3. Return the appropriate GitHubFile struct
-        
-        // For now, return an error indicating this needs proper implementation
-        Err(GovernanceError::GitHubError("File content fetching not fully implemented - requires proper octocrab API integration".to_string()))
+        Self::fetch_file_content_static(&self.client, &self.cache, owner, repo, file_path, branch).await
     }
 
     /// Fetch directory tree from GitHub repository
@@ -233,13 +294,14 @@ impl GitHubFileOperations {
 
         for file_path in file_paths {
             let client = self.client.clone();
+            let cache = self.cache.clone();
             let owner = owner.to_string();
             let repo = repo.to_string();
             let file_path = file_path.clone();
             let branch = branch.map(|s| s.to_string());
 
             let task = tokio::spawn(async move {
-                match Self::fetch_file_content_static(&client, &owner, &repo, &file_path, branch.as_deref()).await {
+                match Self::fetch_file_content_static(&client, &cache, &owner, &repo, &file_path, branch.as_deref()).await {
                     Ok(file) => Some((file_path, file)),
                     Err(e) => {
                         error!("Failed to fetch file {}: {}", file_path, e);
@@ -262,29 +324,74 @@ impl GitHubFileOperations {
     }
 
     /// Static method for fetching file content (used in async tasks)
+    ///
+    /// Files requested at a commit SHA are served from and stored in `cache`.
     async fn fetch_file_content_static(
         client: &Octocrab,
+        cache: &FileContentCache,
         owner: &str,
         repo: &str,
         file_path: &str,
         branch: Option<&str>,
     ) -> Result<GitHubFile, GovernanceError> {
         let branch = branch.unwrap_or("main");
-        
+        let cache_key = is_commit_sha(branch).then(|| {
+            (owner.to_string(), repo.to_string(), file_path.to_string(), branch.to_string())
+        });
+        if let Some(key) = &cache_key {
+            if let Some(file) = cache.get(key) {
+                debug!("Using cached content for {}/{}:{}@{}", owner, repo, file_path, branch);
+                return Ok(file);
+            }
+        }
+
+        let route = format!("/repos/{}/{}/contents/{}?ref={}", owner, repo, file_path, branch);
         let response = client
-            .repos(owner, repo)
-            .get_content()
-            .path(file_path)
-            .r#ref(branch)
-            .send()
+            ._get(route.as_str())
             .await
             .map_err(|e| GovernanceError::GitHubError(format!("Failed to fetch file: {}", e)))?;
+        let status = response.status();
+        let body = client
+            .body_to_string(response)
+            .await
+            .map_err(|e| GovernanceError::GitHubError(format!("Failed to read file: {}", e)))?;
+        if !status.is_success() {
+            return Err(GovernanceError::GitHubError(format!(
+                "Failed to fetch {}/{}:{}: HTTP {}",
+                owner, repo, file_path, status
+            )));
+        }
 
-        // For now, we'll implement a simplified version
-        // In a real implementation, we would handle the response properly
-        
-        // This is a placeholder implementation
-        Err(GovernanceError::GitHubError("File content fetching not fully implemented - requires proper octocrab API integration".to_string()))
+        let item: serde_json::Value = serde_json::from_str(&body)
+            .map_err(|e| GovernanceError::GitHubError(format!("Unexpected contents response: {}", e)))?;
+        if item["type"].as_str() != Some("file") {
+            return Err(GovernanceError::GitHubError(format!(
+                "{}/{}:{} is not a file",
+                owner, repo, file_path
+            )));
+        }
+        // GitHub wraps the base64 payload at 60 columns
+        let encoded: String = item["content"]
+            .as_str()
+            .unwrap_or_default()
+            .chars()
+            .filter(|c| !c.is_whitespace())
+            .collect();
+        let content = base64::engine::general_purpose::STANDARD
+            .decode(encoded)
+            .map_err(|e| GovernanceError::GitHubError(format!("Invalid file encoding: {}", e)))?;
+
+        let file = GitHubFile {
+            path: item["path"].as_str().unwrap_or(file_path).to_string(),
+            size: item["size"].as_u64().unwrap_or(content.len() as u64),
+            sha: item["sha"].as_str().unwrap_or_default().to_string(),
+            download_url: item["download_url"].as_str().map(str::to_string),
+            content,
+        };
+        if let Some(key) = cache_key {
+            cache.insert(key, file.clone());
+        }
+        Ok(file)
     }
 }
 
@@ -333,4 +440,99 @@ mod tests {
         assert_eq!(repo.default_branch, "main");
         assert_eq!(repo.last_commit_sha, "abc123def456");
     }
+
+    fn contents_response(content: &str) -> serde_json::Value {
+        serde_json::json!({
+            "type": "file",
+            "path": "src/consensus/block.rs",
+            "sha": "3d21ec53a331a6f037a91c368710b99387d012c1",
+            "size": content.len(),
+            "encoding": "base64",
+            "content": base64::engine::general_purpose::STANDARD.encode(content),
+            "download_url": null
+        })
+    }
+
+    #[tokio::test]
+    async fn test_pinned_file_served_from_cache() {
+        use wiremock::matchers::{method, path, query_param};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        let sha = "6dcb09b5b57875f334f61aebed695e2e4193db5e";
+        Mock::given(method("GET"))
+            .and(path("/repos/BTCDecoded/consensus-proof/contents/src/consensus/block.rs"))
+            .and(query_param("ref", sha))
+            .respond_with(ResponseTemplate::new(200).set_body_json(contents_response("fn check_block() {}\n")))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let octocrab = Octocrab::builder().base_uri(server.uri()).unwrap().build().unwrap();
+        let cache = Arc::new(FileContentCache::new(8));
+        let file_ops = GitHubFileOperations::from_octocrab(octocrab).with_cache(cache.clone());
+
+        let first = file_ops
+            .fetch_file_content("BTCDecoded", "consensus-proof", "src/consensus/block.rs", Some(sha))
+            .await
+            .unwrap();
+        // The mock allows a single request; this one must not reach it
+        let second = file_ops
+            .fetch_file_content("BTCDecoded", "consensus-proof", "src/consensus/block.rs", Some(sha))
+            .await
+            .unwrap();
+
+        assert_eq!(first.content, b"fn check_block() {}\n");
+        assert_eq!(second.content, first.content);
+        assert_eq!(second.sha, first.sha);
+        assert_eq!(cache.stats(), (1, 1));
+    }
+
+    #[tokio::test]
+    async fn test_branch_refs_bypass_cache() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/repos/BTCDecoded/consensus-proof/contents/src/consensus/block.rs"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(contents_response("fn check_block() {}\n")))
+            .expect(2)
+            .mount(&server)
+            .await;
+
+        let octocrab = Octocrab::builder().base_uri(server.uri()).unwrap().build().unwrap();
+        let cache = Arc::new(FileContentCache::new(8));
+        let file_ops = GitHubFileOperations::from_octocrab(octocrab).with_cache(cache.clone());
+        for _ in 0..2 {
+            file_ops
+                .fetch_file_content("BTCDecoded", "consensus-proof", "src/consensus/block.rs", Some("main"))
+                .await
+                .unwrap();
+        }
+        assert!(cache.is_empty());
+    }
+
+    #[test]
+    fn test_file_cache_evicts_least_recently_used() {
+        let cache = FileContentCache::new(2);
+        let key = |path: &str| ("o".to_string(), "r".to_string(), path.to_string(), "a".repeat(40));
+        let file = |path: &str| GitHubFile {
+            path: path.to_string(),
+            content: Vec::new(),
+            sha: String::new(),
+            size: 0,
+            download_url: None,
+        };
+
+        cache.insert(key("a"), file("a"));
+        cache.insert(key("b"), file("b"));
+        assert!(cache.get(&key("a")).is_some());
+        cache.insert(key("c"), file("c"));
+
+        assert!(cache.get(&key("b")).is_none());
+        assert!(cache.get(&key("a")).is_some());
+        assert!(cache.get(&key("c")).is_some());
+        assert_eq!(cache.len(), 2);
+    }
 }
@@ -12,6 +12,30 @@ pub struct PullRequest {
     pub updated_at: String,
 }
 
+/// A file changed by a pull request, with its unified diff
+///
+/// GitHub omits `patch` for binary files, very large diffs and pure renames.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PullRequestFile {
+    pub filename: String,
+    pub patch: Option<String>,
+    /// `added`, `removed`, `modified`, `renamed`, `copied`, `changed` or `unchanged`
+    #[serde(default)]
+    pub status: Option<String>,
+    /// Path before a rename or copy
+    #[serde(default)]
+    pub previous_filename: Option<String>,
+}
+
+/// A submitted review on a pull request
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PullRequestReview {
+    pub reviewer: String,
+    /// `APPROVED`, `CHANGES_REQUESTED`, `COMMENTED` or `DISMISSED`
+    pub state: String,
+    pub commit_id: Option<String>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CommitRef {
     pub sha: String,
@@ -61,3 +85,25 @@ pub struct Review {
     pub user: User,
     pub body: Option<String>,
 }
+
+/// `output` of a check run; `summary` and `text` are rendered as Markdown
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CheckRunOutput {
+    pub title: String,
+    pub summary: String,
+    pub text: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub annotations: Vec<CheckRunAnnotation>,
+}
+
+/// A note attached to a check run, listed under the run and on the file it points at
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CheckRunAnnotation {
+    pub path: String,
+    pub start_line: u32,
+    pub end_line: u32,
+    /// `notice`, `warning` or `failure`
+    pub annotation_level: String,
+    pub title: Option<String>,
+    pub message: String,
+}
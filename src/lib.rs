@@ -1,11 +1,23 @@
+pub mod audit;
+pub mod auth;
+pub mod authorization;
+pub mod cache;
 pub mod config;
 pub mod crypto;
+pub mod dashboard;
 pub mod database;
 pub mod economic_nodes;
 pub mod enforcement;
 pub mod error;
+pub mod events;
 pub mod fork;
 pub mod github;
+pub mod maintainers;
+pub mod metrics;
+pub mod nostr;
+pub mod ots;
+pub mod shutdown;
+pub mod status;
 pub mod validation;
 pub mod webhooks;
 
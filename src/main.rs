@@ -1,23 +1,32 @@
 use axum::{
-    extract::State,
-    response::Json,
-    routing::{get, post},
+    routing::{delete, get, post},
     Router,
 };
 use chrono::Datelike;
 use std::net::SocketAddr;
+use std::sync::Arc;
 use tokio::time::Duration;
 use tower::ServiceBuilder;
 use tower_http::trace::TraceLayer;
-use tracing::{error, info};
+use tracing::{error, info, info_span, warn, Instrument};
 use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
 
+mod auth;
+mod cache;
 mod config;
 mod crypto;
+mod dashboard;
 mod database;
+mod economic_nodes;
 mod enforcement;
 mod error;
+mod events;
+mod fork;
 mod github;
+mod maintainers;
+mod metrics;
+mod shutdown;
+mod status;
 mod validation;
 mod webhooks;
 mod nostr;
@@ -26,7 +35,8 @@ mod audit;
 mod authorization;
 
 use config::AppConfig;
-use database::Database;
+use database::{ConnectRetryPolicy, Database};
+use metrics::Metrics;
 use nostr::{NostrClient, StatusPublisher};
 use ots::{OtsClient, RegistryAnchorer};
 use audit::AuditLogger;
@@ -47,18 +57,43 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     // Load configuration
     let config = AppConfig::load()?;
     info!("Configuration loaded");
+    github::file_operations::FileContentCache::configure_global(config.github_file_cache_capacity);
+    validation::content_hash::ContentHashCache::configure_global(config.content_hash_cache_capacity);
 
     // Initialize database
-    let database = Database::new(&config.database_url).await?;
+    let database = Database::new_with_retry(
+        config.database_url.expose(),
+        &ConnectRetryPolicy {
+            max_attempts: config.database_connect_max_attempts,
+            base_delay: std::time::Duration::from_millis(config.database_connect_base_delay_ms),
+        },
+    )
+    .await?;
     info!("Database connected");
 
     // Run migrations
     database.run_migrations().await?;
     info!("Database migrations completed");
 
+    // Refuse to start on a schema that drifted from the other backend
+    database.verify_schema().await?;
+
+    // Verify loaded config matches the last anchored registry
+    ots::integrity::run_startup_self_test(
+        &database,
+        &config.ots.config_paths,
+        config.ots.refuse_start_on_config_mismatch,
+    )
+    .await
+    .map_err(|e| e.to_string())?;
+
     // Initialize audit logger
-    let mut audit_logger = if config.audit.enabled {
-        Some(AuditLogger::new(config.audit.log_path.clone())?)
+    let audit_logger = if config.audit.enabled {
+        let logger = AuditLogger::new(config.audit.log_path.clone()).await?;
+        Some(match config.audit.max_file_size_bytes {
+            Some(max_file_size) => logger.with_max_file_size(max_file_size),
+            None => logger,
+        })
     } else {
         None
     };
@@ -66,17 +101,39 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     // Initialize Nostr client and status publisher
     let nostr_client = if config.nostr.enabled {
-        let nsec = std::fs::read_to_string(&config.nostr.server_nsec_path)
-            .map_err(|e| format!("Failed to read Nostr key: {}", e))?;
+        let nsec = config
+            .nostr
+            .server_nsec
+            .as_ref()
+            .ok_or("Nostr is enabled but no server nsec is configured")?
+            .expose()
+            .to_string();
         
         let client = NostrClient::new(nsec, config.nostr.relays.clone()).await
             .map_err(|e| format!("Failed to create Nostr client: {}", e))?;
         
-        Some(client)
+        Some(Arc::new(client))
     } else {
         None
     };
 
+    // Merges that bypassed governance go to whichever configured sinks are enabled
+    let mut violation_notifier = enforcement::violations::ViolationNotifier::new(&config.server_id);
+    for sink in &config.violation_sinks {
+        match (sink.as_str(), &nostr_client, &audit_logger) {
+            ("nostr", Some(client), _) => {
+                violation_notifier = violation_notifier
+                    .with_sink(enforcement::violations::ViolationSink::Nostr(client.clone()));
+            }
+            ("audit", _, Some(logger)) => {
+                violation_notifier = violation_notifier
+                    .with_sink(enforcement::violations::ViolationSink::Audit(logger.clone()));
+            }
+            _ => warn!("Violation sink '{}' is configured but not enabled", sink),
+        }
+    }
+    info!("Violation sinks: {:?}", violation_notifier.sink_names());
+
     let status_publisher = if let Some(client) = nostr_client {
         Some(StatusPublisher::new(
             client,
@@ -102,118 +159,400 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             database.clone(),
             config.ots.registry_path.clone(),
             config.ots.proofs_path.clone(),
-        ))
+        )
+        .with_config_paths(config.ots.config_paths.clone()))
     } else {
         None
     };
 
+    let metrics = Metrics::new();
+
     // Start background tasks
     let config_clone = config.clone();
     let database_clone = database.clone();
-        // TODO: Implement audit logger cloning or use Arc
 
     // Nostr status publisher task
     if let Some(publisher) = status_publisher {
         let publish_interval = Duration::from_secs(config.nostr.publish_interval_secs);
-        tokio::spawn(async move {
-            let mut interval = tokio::time::interval(publish_interval);
-            loop {
-                interval.tick().await;
-                if let Err(e) = publisher.publish_status().await {
-                    error!("Failed to publish Nostr status: {}", e);
+        let publish_metrics = metrics.clone();
+        tokio::spawn(
+            async move {
+                let mut interval = tokio::time::interval(publish_interval);
+                loop {
+                    interval.tick().await;
+                    let result = publisher.publish_status().await;
+                    publish_metrics.record_nostr_publish(result.is_ok());
+                    let (connected, total) = publisher.relay_counts().await;
+                    publish_metrics.set_nostr_relays(connected, total);
+                    if let Err(e) = result {
+                        error!("Failed to publish Nostr status: {}", e);
+                    }
                 }
             }
-        });
+            .instrument(info_span!("background_task", task = "nostr_status_publisher")),
+        );
         info!("Nostr status publisher started");
     }
 
     // OTS monthly anchoring task
     if let Some(anchorer) = registry_anchorer {
-        tokio::spawn(async move {
-            let mut interval = tokio::time::interval(Duration::from_secs(86400)); // Check daily
-            loop {
-                interval.tick().await;
-                let now = chrono::Utc::now();
-                if now.day() == config_clone.ots.monthly_anchor_day as u32 {
-                    if let Err(e) = anchorer.anchor_registry().await {
-                        error!("Failed to anchor registry: {}", e);
+        let anchor_metrics = metrics.clone();
+        tokio::spawn(
+            async move {
+                let mut interval = tokio::time::interval(Duration::from_secs(86400)); // Check daily
+                loop {
+                    interval.tick().await;
+                    let now = chrono::Utc::now();
+                    if now.day() == config_clone.ots.monthly_anchor_day as u32 {
+                        anchor_metrics.record_ots_anchor_attempt();
+                        let result = anchorer.anchor_registry().await;
+                        anchor_metrics.record_ots_anchor_result(result.is_ok());
+                        if let Err(e) = result {
+                            error!("Failed to anchor registry: {}", e);
+                        }
                     }
                 }
             }
-        });
+            .instrument(info_span!("background_task", task = "ots_registry_anchor")),
+        );
         info!("OTS registry anchorer started");
     }
 
+    // Pending OTS proof upgrade task
+    if config.ots.enabled {
+        let upgrader = ots::ProofUpgrader::new(
+            OtsClient::new(config.ots.aggregator_url.clone()),
+            config.ots.proofs_path.clone(),
+        );
+        let upgrade_interval = Duration::from_secs(config.ots.upgrade_interval_secs);
+        let upgrade_metrics = metrics.clone();
+        tokio::spawn(
+            async move {
+                let mut interval = tokio::time::interval(upgrade_interval);
+                loop {
+                    interval.tick().await;
+                    match upgrader.upgrade_pending().await {
+                        Ok(summary) => {
+                            upgrade_metrics.set_ots_pending_proofs(summary.still_pending.len() + summary.failed.len());
+                            if !summary.upgraded.is_empty() || !summary.failed.is_empty() {
+                                info!(
+                                    "OTS proof upgrade: {} upgraded, {} still pending, {} failed",
+                                    summary.upgraded.len(),
+                                    summary.still_pending.len(),
+                                    summary.failed.len()
+                                );
+                            }
+                            for (path, reason) in &summary.failed {
+                                error!("Failed to upgrade OTS proof {}: {}", path.display(), reason);
+                            }
+                        }
+                        Err(e) => error!("Failed to scan OTS proofs: {}", e),
+                    }
+                }
+            }
+            .instrument(info_span!("background_task", task = "ots_proof_upgrade")),
+        );
+        info!("OTS proof upgrader started");
+    }
+
+    // Monthly audit log Merkle root anchoring
+    if config.ots.enabled && config.audit.enabled {
+        let anchorer = ots::AuditAnchorer::new(
+            OtsClient::new(config.ots.aggregator_url.clone()),
+            config.audit.log_path.clone(),
+        );
+        let anchor_day = config.ots.monthly_anchor_day as u32;
+        let anchor_metrics = metrics.clone();
+        tokio::spawn(
+            async move {
+                let mut interval = tokio::time::interval(Duration::from_secs(86400)); // Check daily
+                loop {
+                    interval.tick().await;
+                    if chrono::Utc::now().day() == anchor_day {
+                        anchor_metrics.record_ots_anchor_attempt();
+                        let result = anchorer.anchor_audit_log().await;
+                        anchor_metrics.record_ots_anchor_result(result.is_ok());
+                        match result {
+                            Ok(proof) => info!("Anchored audit log, proof at {}", proof.display()),
+                            Err(e) => error!("Failed to anchor audit log: {}", e),
+                        }
+                    }
+                }
+            }
+            .instrument(info_span!("background_task", task = "audit_log_anchor")),
+        );
+        info!("OTS audit log anchorer started");
+    }
+
     // Audit log rotation task
-    if audit_logger.is_some() {
+    if let Some(logger) = audit_logger.clone() {
         let rotation_interval = Duration::from_secs(config.audit.rotation_interval_days as u64 * 86400);
-        tokio::spawn(async move {
-            let mut interval = tokio::time::interval(rotation_interval);
-            loop {
+        let server_id = config.server_id.clone();
+        tokio::spawn(
+            async move {
+                let mut interval = tokio::time::interval(rotation_interval);
+                // The first tick fires immediately; skip it so startup doesn't rotate
                 interval.tick().await;
-                // Rotate audit log (implement rotation logic)
-                info!("Audit log rotation triggered");
+                loop {
+                    interval.tick().await;
+                    match logger.rotate(&server_id).await {
+                        Ok(rotated) => info!("Audit log rotated to {}", rotated),
+                        Err(e) => error!("Failed to rotate audit log: {}", e),
+                    }
+                }
             }
-        });
+            .instrument(info_span!("background_task", task = "audit_log_rotation")),
+        );
         info!("Audit log rotation started");
     }
 
+    // One app client for the whole process, so installation tokens are minted once and reused
+    let github_client = match github::client::GitHubClient::from_config(&config) {
+        Ok(client) => Some(client),
+        Err(e) => {
+            warn!("GitHub client unavailable: {}", e);
+            None
+        }
+    };
+
+    // Stale-PR signature reminder task
+    if config.reminders.enabled {
+        let github_client = github_client
+            .clone()
+            .ok_or("Reminders need a GitHub App private key (GITHUB_PRIVATE_KEY or GITHUB_PRIVATE_KEY_FILE)")?;
+        let scheduler = enforcement::reminders::ReminderScheduler::new(
+            database.clone(),
+            config.reminders.reminder_interval_hours,
+        )
+        .with_review_period_policy(config.review_period_policy.clone());
+        let check_interval = Duration::from_secs(config.reminders.check_interval_secs);
+        tokio::spawn(
+            async move {
+                let mut interval = tokio::time::interval(check_interval);
+                loop {
+                    interval.tick().await;
+                    if let Err(e) = scheduler.run_once(&github_client).await {
+                        error!("Failed to post signature reminders: {}", e);
+                    }
+                }
+            }
+            .instrument(info_span!("background_task", task = "signature_reminders")),
+        );
+        info!("Signature reminder task started");
+    }
+
+    // Catch up on PR events missed while the server was down, then keep checking
+    if config.reconcile_interval_secs > 0 {
+        match github_client.clone() {
+            Some(github_client) => {
+                let reconciler = webhooks::reconcile::PrReconciler::new(
+                    github_client,
+                    database.clone(),
+                    enforcement::decision_log::DecisionLogger::new(
+                        config.dry_run_mode,
+                        config.log_enforcement_decisions,
+                        config.enforcement_log_path.clone(),
+                    ),
+                )
+                .with_review_period_policy(config.review_period_policy.clone())
+                .with_economic_quorums(config.economic_quorums.clone())
+                .with_violation_notifier(violation_notifier.clone())
+                .with_check_runs(config.github_check_runs);
+                let reconcile_interval = Duration::from_secs(config.reconcile_interval_secs);
+                tokio::spawn(
+                    async move {
+                        let mut interval = tokio::time::interval(reconcile_interval);
+                        loop {
+                            interval.tick().await;
+                            if let Err(e) = reconciler.run_once().await {
+                                error!("Failed to reconcile pull requests: {}", e);
+                            }
+                        }
+                    }
+                    .instrument(info_span!("background_task", task = "pr_reconciliation")),
+                );
+                info!("PR reconciliation task started");
+            }
+            None => warn!("PR reconciliation disabled: no GitHub App private key configured"),
+        }
+    }
+
+    // Governance event retention task
+    if config.retention.enabled {
+        let pruner = database::retention::EventPruner::new(
+            database.clone(),
+            database::retention::RetentionPolicy::from(&config.retention),
+        );
+        let pruning_audit_logger = audit_logger.clone();
+        let server_id = config.server_id.clone();
+        let prune_interval = Duration::from_secs(config.retention.prune_interval_secs);
+        tokio::spawn(
+            async move {
+                let mut interval = tokio::time::interval(prune_interval);
+                loop {
+                    interval.tick().await;
+                    match pruner.prune(chrono::Utc::now()).await {
+                        Ok(summary) => {
+                            if let Some(logger) = &pruning_audit_logger {
+                                if let Err(e) = database::retention::EventPruner::record_summary(
+                                    logger, &server_id, &summary,
+                                )
+                                .await
+                                {
+                                    error!("Failed to record pruning summary: {}", e);
+                                }
+                            }
+                        }
+                        Err(e) => error!("Failed to prune governance events: {}", e),
+                    }
+                }
+            }
+            .instrument(info_span!("background_task", task = "event_retention")),
+        );
+        info!("Governance event retention task started");
+    }
+
+    // Keys past expires_at stay active until something transitions them
+    {
+        let database = database.clone();
+        let sweep_interval = Duration::from_secs(config.key_expiry_sweep_interval_secs);
+        tokio::spawn(
+            async move {
+                let mut interval = tokio::time::interval(sweep_interval);
+                loop {
+                    interval.tick().await;
+                    if let Err(e) = crypto::key_management::sweep_expired_keys(&database, chrono::Utc::now()).await {
+                        error!("Failed to sweep expired keys: {}", e);
+                    }
+                }
+            }
+            .instrument(info_span!("background_task", task = "key_expiry_sweep")),
+        );
+        info!("Key expiry sweep task started");
+    }
+
+    // Expired webhook delivery ids only need clearing out occasionally
+    {
+        let database = database.clone();
+        let ttl = chrono::Duration::seconds(config.webhook_delivery_ttl_secs as i64);
+        tokio::spawn(
+            async move {
+                let mut interval = tokio::time::interval(Duration::from_secs(3600));
+                loop {
+                    interval.tick().await;
+                    if let Err(e) = database.prune_webhook_deliveries(chrono::Utc::now() - ttl).await {
+                        error!("Failed to prune webhook deliveries: {}", e);
+                    }
+                }
+            }
+            .instrument(info_span!("background_task", task = "webhook_delivery_prune")),
+        );
+    }
+
+    // Webhooks are acknowledged immediately and processed by a worker pool
+    let (webhook_queue, webhook_receiver) =
+        webhooks::queue::WebhookQueue::bounded(config.webhook_queue_capacity, metrics.clone());
+    let webhook_workers = webhook_receiver
+        .with_violation_notifier(violation_notifier)
+        .with_github_client(github_client.clone())
+        .spawn_workers(config.clone(), database.clone(), config.webhook_workers);
+    let webhook_routes = Router::new()
+        .route("/webhooks/github", post(webhooks::github::handle_webhook))
+        .with_state((config.clone(), database.clone(), webhook_queue));
+    let reclassify_routes = Router::new()
+        .route(
+            "/prs/:repo/:number/reclassify",
+            post(webhooks::reclassify::handle_reclassify),
+        )
+        .with_state((config.clone(), database.clone(), metrics.clone(), github_client));
+    info!(
+        "Webhook queue started ({} workers, capacity {})",
+        config.webhook_workers, config.webhook_queue_capacity
+    );
+
     // Build application
     let app = Router::new()
-        .route("/health", get(health_check))
-        .route("/webhooks/github", post(webhooks::github::handle_webhook))
-        .route("/status", get(status_endpoint))
+        .route("/health", get(status::health_check))
+        .route("/status", get(status::status_endpoint))
+        .route("/dashboard", get(dashboard::dashboard_endpoint))
+        .route("/events/stream", get(events::event_stream_endpoint))
+        .route("/metrics", get(metrics::metrics_endpoint))
+        .route("/prs", get(status::list_prs_endpoint))
+        .route("/prs/:repo/:number/record", get(status::pr_record_endpoint))
+        .route("/prs/:repo/:number/status", get(status::pr_status_endpoint))
+        .route("/governance/export", get(fork::export::export_endpoint))
+        .route(
+            "/maintainers",
+            get(maintainers::list_maintainers_endpoint).post(maintainers::add_maintainer_endpoint),
+        )
+        .route(
+            "/maintainers/:username",
+            delete(maintainers::remove_maintainer_endpoint),
+        )
+        .route(
+            "/admin/simulate-webhook",
+            post(webhooks::simulate::handle_simulate_webhook),
+        )
+        .merge(webhook_routes)
+        .merge(reclassify_routes)
         .layer(
             ServiceBuilder::new()
                 .layer(TraceLayer::new_for_http())
                 .into_inner(),
         )
-        .with_state((config, database));
+        .with_state((config.clone(), database.clone(), metrics));
 
     // Start server
     let addr = SocketAddr::from(([0, 0, 0, 0], 3000));
     info!("Server listening on {}", addr);
 
     let listener = tokio::net::TcpListener::bind(addr).await?;
-    axum::serve(listener, app).await?;
-
-    Ok(())
-}
+    let summary = shutdown::serve_with_graceful_shutdown(
+        listener,
+        app,
+        shutdown::shutdown_signal(),
+        Duration::from_secs(config.shutdown_timeout_secs),
+    )
+    .await?;
 
-async fn health_check() -> Json<serde_json::Value> {
-    Json(serde_json::json!({
-        "status": "healthy",
-        "service": "governance-app",
-        "timestamp": chrono::Utc::now()
-    }))
-}
+    // The router owned the queue's senders, so the workers stop once it is empty
+    let queue_status = match tokio::time::timeout(
+        Duration::from_secs(config.shutdown_timeout_secs),
+        webhook_workers.join(),
+    )
+    .await
+    {
+        Ok(()) => "drained",
+        Err(_) => "abandoned",
+    };
 
-async fn status_endpoint(State((config, database)): State<(AppConfig, Database)>) -> Json<serde_json::Value> {
-    let mut status = serde_json::json!({
-        "status": "healthy",
-        "service": "governance-app",
-        "timestamp": chrono::Utc::now(),
-        "server_id": config.server_id,
-        "features": {
-            "nostr": config.nostr.enabled,
-            "ots": config.ots.enabled,
-            "audit": config.audit.enabled,
-            "dry_run": config.dry_run_mode
+    // Persist what the drained handlers wrote before exiting
+    let audit_status = match &audit_logger {
+        Some(logger) => match logger.flush().await {
+            Ok(()) => "flushed",
+            Err(e) => {
+                error!("Failed to flush audit log: {}", e);
+                "flush failed"
+            }
+        },
+        None => "disabled",
+    };
+    let wal_status = match database.checkpoint_wal().await {
+        Ok(()) => "checkpointed",
+        Err(e) => {
+            error!("Failed to checkpoint database WAL: {}", e);
+            "checkpoint failed"
         }
-    });
-
-    // Add database status
-    if let Ok(stats) = database.get_performance_stats().await {
-        status["database"] = serde_json::json!({
-            "status": "healthy",
-            "cache_size": stats.cache_size,
-            "slow_queries": stats.slow_queries_count
-        });
-    } else {
-        status["database"] = serde_json::json!({
-            "status": "error"
-        });
-    }
+    };
 
-    Json(status)
+    info!(
+        "Shutdown complete: in-flight requests {} after {}ms, webhook queue {}, audit log {}, WAL {}",
+        if summary.drained { "drained" } else { "abandoned" },
+        summary.drain_time.as_millis(),
+        queue_status,
+        audit_status,
+        wal_status
+    );
+
+    Ok(())
 }
@@ -0,0 +1,435 @@
+//! Maintainer Set API
+//!
+//! `GET /maintainers` lists the maintainer set, which is public like the
+//! signatures made with it. Adding or removing a maintainer is a Tier 5
+//! governance action: besides the admin token, the request must carry
+//! approvals from the Tier 5 threshold of active maintainers, each signing
+//! the message built by `build_maintainer_change_message`. Applied changes
+//! are recorded as `maintainer_added` / `maintainer_removed` events.
+
+use axum::{
+    extract::{Path, State},
+    http::{HeaderMap, StatusCode},
+    response::Json,
+};
+use serde::Deserialize;
+use serde_json::Value;
+use std::collections::HashSet;
+use tracing::{error, info, warn};
+
+use crate::auth::require_bearer;
+use crate::config::{AppConfig, Secret};
+use crate::crypto::signatures::{build_maintainer_change_message, MaintainerChange, SignatureManager};
+use crate::database::Database;
+use crate::error::GovernanceError;
+use crate::metrics::Metrics;
+use crate::validation::threshold::ThresholdValidator;
+
+/// Governance tier of a maintainer set change
+const MAINTAINER_CHANGE_TIER: u32 = 5;
+
+/// An existing maintainer's signature over the change message
+#[derive(Debug, Clone, Deserialize)]
+pub struct MaintainerApproval {
+    pub signer: String,
+    pub signature: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct AddMaintainerRequest {
+    pub github_username: String,
+    pub public_key: String,
+    pub layer: i32,
+    pub approvals: Vec<MaintainerApproval>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RemoveMaintainerRequest {
+    pub approvals: Vec<MaintainerApproval>,
+}
+
+/// Handle `GET /maintainers`
+///
+/// Also returns the `change_sequence` the next change's approvals must sign.
+pub async fn list_maintainers_endpoint(
+    State((_config, database, _metrics)): State<(AppConfig, Database, Metrics)>,
+) -> (StatusCode, Json<Value>) {
+    let listing = async {
+        let maintainers = database.list_maintainers().await?;
+        let change_sequence = database.maintainer_change_sequence().await?;
+        Ok::<_, GovernanceError>((maintainers, change_sequence))
+    };
+    match listing.await {
+        Ok((maintainers, change_sequence)) => (
+            StatusCode::OK,
+            Json(serde_json::json!({
+                "maintainers": maintainers,
+                "change_sequence": change_sequence,
+                "required_approvals": ThresholdValidator::get_tier_threshold(MAINTAINER_CHANGE_TIER).0,
+            })),
+        ),
+        Err(e) => {
+            error!("Failed to list maintainers: {}", e);
+            e.to_response()
+        }
+    }
+}
+
+/// Handle `POST /maintainers`
+pub async fn add_maintainer_endpoint(
+    State((config, database, _metrics)): State<(AppConfig, Database, Metrics)>,
+    headers: HeaderMap,
+    Json(request): Json<AddMaintainerRequest>,
+) -> (StatusCode, Json<Value>) {
+    if let Err(e) = require_bearer(&headers, config.admin_token.as_ref().map(Secret::expose)) {
+        warn!("Rejected maintainer change request: {}", e);
+        return e.to_response();
+    }
+    match add_maintainer(&database, &request).await {
+        Ok(maintainer) => (StatusCode::CREATED, Json(serde_json::json!(maintainer))),
+        Err(e) => {
+            warn!("Rejected adding maintainer {}: {}", request.github_username, e);
+            e.to_response()
+        }
+    }
+}
+
+/// Handle `DELETE /maintainers/:username`
+pub async fn remove_maintainer_endpoint(
+    State((config, database, _metrics)): State<(AppConfig, Database, Metrics)>,
+    Path(username): Path<String>,
+    headers: HeaderMap,
+    Json(request): Json<RemoveMaintainerRequest>,
+) -> (StatusCode, Json<Value>) {
+    if let Err(e) = require_bearer(&headers, config.admin_token.as_ref().map(Secret::expose)) {
+        warn!("Rejected maintainer change request: {}", e);
+        return e.to_response();
+    }
+    match remove_maintainer(&database, &username, &request.approvals).await {
+        Ok(()) => (
+            StatusCode::OK,
+            Json(serde_json::json!({"github_username": username, "active": false})),
+        ),
+        Err(e) => {
+            warn!("Rejected removing maintainer {}: {}", username, e);
+            e.to_response()
+        }
+    }
+}
+
+async fn add_maintainer(
+    database: &Database,
+    request: &AddMaintainerRequest,
+) -> Result<crate::database::models::Maintainer, GovernanceError> {
+    if request.github_username.trim().is_empty() {
+        return Err(GovernanceError::ValidationError("github_username is required".to_string()));
+    }
+    if !(1..=5).contains(&request.layer) {
+        return Err(GovernanceError::ValidationError(format!(
+            "Invalid layer {}; expected 1-5",
+            request.layer
+        )));
+    }
+    SignatureManager::validate_public_key(&request.public_key)?;
+
+    let sequence = database.maintainer_change_sequence().await?;
+    let change = MaintainerChange::Add {
+        github_username: &request.github_username,
+        layer: request.layer,
+        public_key: &request.public_key,
+    };
+    let message = build_maintainer_change_message(sequence, &change);
+    let approvers = verify_approvals(database, &message, &request.approvals).await?;
+
+    let maintainer = database
+        .apply_maintainer_change(
+            sequence,
+            &change,
+            &serde_json::json!({
+                "layer": request.layer,
+                "public_key": request.public_key,
+                "approvers": approvers,
+                "change_sequence": sequence,
+            }),
+        )
+        .await?
+        .ok_or_else(|| GovernanceError::DatabaseError("added maintainer not returned".to_string()))?;
+    info!("Added maintainer {} at layer {}", request.github_username, request.layer);
+    Ok(maintainer)
+}
+
+async fn remove_maintainer(
+    database: &Database,
+    username: &str,
+    approvals: &[MaintainerApproval],
+) -> Result<(), GovernanceError> {
+    if database.get_maintainer_by_username(username).await?.is_none() {
+        return Err(GovernanceError::NotFound {
+            entity: "maintainer".to_string(),
+            id: username.to_string(),
+        });
+    }
+
+    let sequence = database.maintainer_change_sequence().await?;
+    let change = MaintainerChange::Remove { github_username: username };
+    let message = build_maintainer_change_message(sequence, &change);
+    let approvers = verify_approvals(database, &message, approvals).await?;
+
+    database
+        .apply_maintainer_change(
+            sequence,
+            &change,
+            &serde_json::json!({
+                "approvers": approvers,
+                "change_sequence": sequence,
+            }),
+        )
+        .await?;
+    info!("Removed maintainer {}", username);
+    Ok(())
+}
+
+/// Check every approval and that enough distinct active maintainers signed
+///
+/// Any invalid approval rejects the request rather than being skipped, so a
+/// bad submission is reported instead of silently counting short.
+async fn verify_approvals(
+    database: &Database,
+    message: &str,
+    approvals: &[MaintainerApproval],
+) -> Result<Vec<String>, GovernanceError> {
+    let signature_manager = SignatureManager::new();
+    let mut approvers = Vec::new();
+    let mut seen = HashSet::new();
+    for approval in approvals {
+        if !seen.insert(approval.signer.as_str()) {
+            return Err(GovernanceError::SignatureError(format!(
+                "Duplicate approval from {}",
+                approval.signer
+            )));
+        }
+        let maintainer = database
+            .get_maintainer_by_username(&approval.signer)
+            .await?
+            .ok_or_else(|| {
+                GovernanceError::SignatureError(format!("{} is not an active maintainer", approval.signer))
+            })?;
+        let valid = signature_manager
+            .verify_governance_signature(message, &approval.signature, &maintainer.public_key)
+            .unwrap_or(false);
+        if !valid {
+            return Err(GovernanceError::SignatureError(format!(
+                "Invalid approval signature from {}",
+                approval.signer
+            )));
+        }
+        approvers.push(approval.signer.clone());
+    }
+
+    let (required, _) = ThresholdValidator::get_tier_threshold(MAINTAINER_CHANGE_TIER);
+    if approvers.len() < required {
+        return Err(GovernanceError::ThresholdError(format!(
+            "{} of {} required maintainer approvals",
+            approvers.len(),
+            required
+        )));
+    }
+    Ok(approvers)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::body::{to_bytes, Body};
+    use axum::http::Request;
+    use axum::routing::{delete, get};
+    use axum::Router;
+    use developer_sdk::governance::GovernanceKeypair;
+    use tower::ServiceExt;
+
+    /// Database with five active maintainers, enough for a Tier 5 approval
+    async fn fixture() -> (Database, Vec<(String, GovernanceKeypair)>) {
+        let database = Database::new_in_memory().await.unwrap();
+        let mut maintainers = Vec::new();
+        for (i, name) in ["alice", "bob", "carol", "dave", "erin"].iter().enumerate() {
+            let keypair = SignatureManager::new().generate_keypair().unwrap();
+            database
+                .upsert_maintainer(name, &keypair.public_key.to_string(), (i % 2) as i32 + 3)
+                .await
+                .unwrap();
+            maintainers.push((name.to_string(), keypair));
+        }
+        (database, maintainers)
+    }
+
+    fn approvals(message: &str, signers: &[(String, GovernanceKeypair)]) -> Vec<Value> {
+        signers
+            .iter()
+            .map(|(name, keypair)| {
+                serde_json::json!({
+                    "signer": name,
+                    "signature": SignatureManager::new().create_governance_signature(message, keypair).unwrap()
+                })
+            })
+            .collect()
+    }
+
+    async fn send(database: &Database, method: &str, uri: &str, body: Option<Value>) -> (StatusCode, Value) {
+        let mut config = AppConfig::load().unwrap();
+        config.admin_token = Some(Secret::new("admin-secret"));
+        let app = Router::new()
+            .route("/maintainers", get(list_maintainers_endpoint).post(add_maintainer_endpoint))
+            .route("/maintainers/:username", delete(remove_maintainer_endpoint))
+            .with_state((config, database.clone(), Metrics::new()));
+        let mut request = Request::builder()
+            .method(method)
+            .uri(uri)
+            .header("authorization", "Bearer admin-secret");
+        let body = match body {
+            Some(body) => {
+                request = request.header("content-type", "application/json");
+                Body::from(body.to_string())
+            }
+            None => Body::empty(),
+        };
+        let response = app.oneshot(request.body(body).unwrap()).await.unwrap();
+        let status = response.status();
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        (status, serde_json::from_slice(&body).unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_list_maintainers() {
+        let (database, _) = fixture().await;
+        database.deactivate_maintainer("erin").await.unwrap();
+
+        let (status, body) = send(&database, "GET", "/maintainers", None).await;
+        assert_eq!(status, StatusCode::OK);
+        let maintainers = body["maintainers"].as_array().unwrap();
+        assert_eq!(maintainers.len(), 5);
+        assert_eq!(maintainers[0]["github_username"], "alice");
+        assert_eq!(maintainers[0]["layer"], 3);
+        let erin = maintainers.iter().find(|m| m["github_username"] == "erin").unwrap();
+        assert_eq!(erin["active"], false);
+        assert_eq!(body["change_sequence"], 0);
+        assert_eq!(body["required_approvals"], 5);
+    }
+
+    #[tokio::test]
+    async fn test_add_and_remove_with_tier_5_approvals() {
+        let (database, maintainers) = fixture().await;
+        let newcomer = SignatureManager::new().generate_keypair().unwrap().public_key.to_string();
+        let message = build_maintainer_change_message(
+            0,
+            &MaintainerChange::Add { github_username: "frank", layer: 4, public_key: &newcomer },
+        );
+
+        let add = serde_json::json!({
+            "github_username": "frank",
+            "public_key": newcomer,
+            "layer": 4,
+            "approvals": approvals(&message, &maintainers),
+        });
+        let (status, body) = send(&database, "POST", "/maintainers", Some(add.clone())).await;
+        assert_eq!(status, StatusCode::CREATED, "{}", body);
+        assert_eq!(body["github_username"], "frank");
+        assert!(database.get_maintainer_by_username("frank").await.unwrap().is_some());
+        let events = database.get_governance_events_after(0, 10).await.unwrap();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].event_type, "maintainer_added");
+        assert_eq!(events[0].maintainer.as_deref(), Some("frank"));
+        assert_eq!(events[0].details["approvers"].as_array().unwrap().len(), 5);
+
+        // Approvals for the add are spent once it lands; replaying the request fails
+        let (status, _) = send(&database, "POST", "/maintainers", Some(add)).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(database.maintainer_change_sequence().await.unwrap(), 1);
+
+        let message = build_maintainer_change_message(1, &MaintainerChange::Remove { github_username: "frank" });
+        let (status, _) = send(
+            &database,
+            "DELETE",
+            "/maintainers/frank",
+            Some(serde_json::json!({"approvals": approvals(&message, &maintainers)})),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        assert!(database.get_maintainer_by_username("frank").await.unwrap().is_none());
+        assert_eq!(database.maintainer_change_sequence().await.unwrap(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_change_sequence_survives_event_pruning() {
+        let (database, maintainers) = fixture().await;
+        let message = build_maintainer_change_message(0, &MaintainerChange::Remove { github_username: "erin" });
+        let (status, _) = send(
+            &database,
+            "DELETE",
+            "/maintainers/erin",
+            Some(serde_json::json!({"approvals": approvals(&message, &maintainers)})),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+
+        sqlx::query("DELETE FROM governance_events")
+            .execute(database.pool().unwrap())
+            .await
+            .unwrap();
+        assert_eq!(database.maintainer_change_sequence().await.unwrap(), 1);
+
+        // A change approved at a sequence another change already spent is not applied
+        let result = database
+            .apply_maintainer_change(
+                0,
+                &MaintainerChange::Remove { github_username: "dave" },
+                &serde_json::json!({}),
+            )
+            .await;
+        assert!(matches!(result, Err(GovernanceError::Conflict(_))));
+        assert!(database.get_maintainer_by_username("dave").await.unwrap().is_some());
+        assert_eq!(database.maintainer_change_sequence().await.unwrap(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_add_without_quorum_is_rejected() {
+        let (database, maintainers) = fixture().await;
+        let newcomer = SignatureManager::new().generate_keypair().unwrap().public_key.to_string();
+        let message = build_maintainer_change_message(
+            0,
+            &MaintainerChange::Add { github_username: "frank", layer: 4, public_key: &newcomer },
+        );
+
+        let (status, body) = send(
+            &database,
+            "POST",
+            "/maintainers",
+            Some(serde_json::json!({
+                "github_username": "frank",
+                "public_key": newcomer,
+                "layer": 4,
+                "approvals": approvals(&message, &maintainers[..3]),
+            })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert!(body["error"].as_str().unwrap().contains("3 of 5"));
+        assert_eq!(body["code"], "REQUIREMENT_NOT_MET");
+        assert!(database.get_maintainer_by_username("frank").await.unwrap().is_none());
+        assert_eq!(database.maintainer_change_sequence().await.unwrap(), 0);
+
+        // A malformed key is refused before any approval is checked
+        let (status, _) = send(
+            &database,
+            "POST",
+            "/maintainers",
+            Some(serde_json::json!({
+                "github_username": "frank",
+                "public_key": "not-a-key",
+                "layer": 4,
+                "approvals": [],
+            })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+    }
+}
@@ -0,0 +1,300 @@
+//! Prometheus Metrics
+//!
+//! Counters are kept in memory and shared through the app state; veto signal
+//! counts and pool usage are read at scrape time so they stay accurate when
+//! signals are submitted by the CLI tools rather than this process.
+
+use axum::{extract::State, http::header, http::StatusCode, response::IntoResponse};
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use tracing::warn;
+
+use crate::config::AppConfig;
+use crate::database::Database;
+use crate::github::file_operations::FileContentCache;
+use crate::validation::content_hash::ContentHashCache;
+
+const CONTENT_TYPE: &str = "text/plain; version=0.0.4";
+
+#[derive(Default)]
+struct MetricsInner {
+    webhooks_received: Mutex<BTreeMap<String, u64>>,
+    webhooks_rejected: AtomicU64,
+    prs_classified: Mutex<BTreeMap<u32, u64>>,
+    merge_blocks: AtomicU64,
+    nostr_publish_success: AtomicU64,
+    nostr_publish_failure: AtomicU64,
+    ots_anchor_attempts: AtomicU64,
+    nostr_relays_connected: AtomicU64,
+    nostr_relays_total: AtomicU64,
+    subsystems: Mutex<SubsystemHealth>,
+}
+
+/// When the Nostr and OTS background tasks last succeeded and failed
+///
+/// A subsystem is degraded while its latest attempt is a failure.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct SubsystemHealth {
+    pub nostr_last_publish: Option<DateTime<Utc>>,
+    pub nostr_last_publish_failure: Option<DateTime<Utc>>,
+    pub ots_last_anchor: Option<DateTime<Utc>>,
+    pub ots_last_anchor_failure: Option<DateTime<Utc>>,
+    /// Proofs awaiting a Bitcoin attestation as of the last upgrade scan
+    pub ots_pending_proofs: Option<usize>,
+}
+
+/// Whether the latest recorded attempt failed
+fn failed_last(success: Option<DateTime<Utc>>, failure: Option<DateTime<Utc>>) -> bool {
+    match (success, failure) {
+        (_, None) => false,
+        (None, Some(_)) => true,
+        (Some(success), Some(failure)) => failure >= success,
+    }
+}
+
+impl SubsystemHealth {
+    pub fn nostr_degraded(&self) -> bool {
+        failed_last(self.nostr_last_publish, self.nostr_last_publish_failure)
+    }
+
+    pub fn ots_degraded(&self) -> bool {
+        failed_last(self.ots_last_anchor, self.ots_last_anchor_failure)
+    }
+}
+
+/// Process-wide metrics registry, cheap to clone
+#[derive(Clone, Default)]
+pub struct Metrics {
+    inner: Arc<MetricsInner>,
+}
+
+impl Metrics {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn record_webhook(&self, event_type: &str) {
+        *self
+            .inner
+            .webhooks_received
+            .lock()
+            .unwrap()
+            .entry(event_type.to_string())
+            .or_insert(0) += 1;
+    }
+
+    /// A webhook turned away because the processing queue was full
+    pub fn record_webhook_rejected(&self) {
+        self.inner.webhooks_rejected.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn record_pr_classified(&self, tier: u32) {
+        *self.inner.prs_classified.lock().unwrap().entry(tier).or_insert(0) += 1;
+    }
+
+    pub fn record_merge_block(&self) {
+        self.inner.merge_blocks.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn record_nostr_publish(&self, success: bool) {
+        let counter = if success {
+            &self.inner.nostr_publish_success
+        } else {
+            &self.inner.nostr_publish_failure
+        };
+        counter.fetch_add(1, Ordering::Relaxed);
+
+        let mut subsystems = self.inner.subsystems.lock().unwrap();
+        if success {
+            subsystems.nostr_last_publish = Some(Utc::now());
+        } else {
+            subsystems.nostr_last_publish_failure = Some(Utc::now());
+        }
+    }
+
+    pub fn set_nostr_relays(&self, connected: usize, total: usize) {
+        self.inner.nostr_relays_connected.store(connected as u64, Ordering::Relaxed);
+        self.inner.nostr_relays_total.store(total as u64, Ordering::Relaxed);
+    }
+
+    /// Relays connected and configured as of the last publish
+    pub fn nostr_relays(&self) -> (u64, u64) {
+        (
+            self.inner.nostr_relays_connected.load(Ordering::Relaxed),
+            self.inner.nostr_relays_total.load(Ordering::Relaxed),
+        )
+    }
+
+    pub fn record_ots_anchor_attempt(&self) {
+        self.inner.ots_anchor_attempts.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn record_ots_anchor_result(&self, success: bool) {
+        let mut subsystems = self.inner.subsystems.lock().unwrap();
+        if success {
+            subsystems.ots_last_anchor = Some(Utc::now());
+        } else {
+            subsystems.ots_last_anchor_failure = Some(Utc::now());
+        }
+    }
+
+    pub fn set_ots_pending_proofs(&self, pending: usize) {
+        self.inner.subsystems.lock().unwrap().ots_pending_proofs = Some(pending);
+    }
+
+    pub fn subsystem_health(&self) -> SubsystemHealth {
+        self.inner.subsystems.lock().unwrap().clone()
+    }
+
+    /// Render all metrics in the Prometheus text exposition format
+    pub async fn render(&self, database: &Database) -> String {
+        let mut out = String::new();
+
+        write_header(&mut out, "governance_webhooks_received_total", "Webhooks received, by event type", "counter");
+        for (event, count) in self.inner.webhooks_received.lock().unwrap().iter() {
+            let _ = writeln!(out, "governance_webhooks_received_total{{event=\"{}\"}} {}", escape(event), count);
+        }
+
+        write_header(&mut out, "governance_webhooks_rejected_total", "Webhooks rejected because the queue was full", "counter");
+        let _ = writeln!(
+            out,
+            "governance_webhooks_rejected_total {}",
+            self.inner.webhooks_rejected.load(Ordering::Relaxed)
+        );
+
+        write_header(&mut out, "governance_prs_classified_total", "Pull requests classified, by tier", "counter");
+        for (tier, count) in self.inner.prs_classified.lock().unwrap().iter() {
+            let _ = writeln!(out, "governance_prs_classified_total{{tier=\"{}\"}} {}", tier, count);
+        }
+
+        write_header(&mut out, "governance_merge_blocks_total", "Blocking merge-check statuses issued", "counter");
+        let _ = writeln!(out, "governance_merge_blocks_total {}", self.inner.merge_blocks.load(Ordering::Relaxed));
+
+        write_header(&mut out, "governance_nostr_publish_total", "Nostr status publications, by result", "counter");
+        let _ = writeln!(
+            out,
+            "governance_nostr_publish_total{{result=\"success\"}} {}",
+            self.inner.nostr_publish_success.load(Ordering::Relaxed)
+        );
+        let _ = writeln!(
+            out,
+            "governance_nostr_publish_total{{result=\"failure\"}} {}",
+            self.inner.nostr_publish_failure.load(Ordering::Relaxed)
+        );
+
+        let (connected, total) = self.nostr_relays();
+        write_header(&mut out, "governance_nostr_relays", "Nostr relays, by connection state", "gauge");
+        let _ = writeln!(out, "governance_nostr_relays{{state=\"connected\"}} {}", connected);
+        let _ = writeln!(out, "governance_nostr_relays{{state=\"configured\"}} {}", total);
+
+        write_header(&mut out, "governance_ots_anchor_attempts_total", "OpenTimestamps anchoring attempts", "counter");
+        let _ = writeln!(
+            out,
+            "governance_ots_anchor_attempts_total {}",
+            self.inner.ots_anchor_attempts.load(Ordering::Relaxed)
+        );
+
+        let (hits, misses) = FileContentCache::global().stats();
+        write_header(&mut out, "governance_github_file_cache_total", "GitHub file content lookups at a commit SHA, by result", "counter");
+        let _ = writeln!(out, "governance_github_file_cache_total{{result=\"hit\"}} {}", hits);
+        let _ = writeln!(out, "governance_github_file_cache_total{{result=\"miss\"}} {}", misses);
+
+        let (hits, misses) = ContentHashCache::global().stats();
+        write_header(&mut out, "governance_content_hash_cache_total", "Content hash lookups by Git blob SHA, by result", "counter");
+        let _ = writeln!(out, "governance_content_hash_cache_total{{result=\"hit\"}} {}", hits);
+        let _ = writeln!(out, "governance_content_hash_cache_total{{result=\"miss\"}} {}", misses);
+
+        write_header(&mut out, "governance_veto_signals", "Economic node signals on record, by type", "gauge");
+        match database.count_veto_signals_by_type().await {
+            Ok(counts) => {
+                for (signal_type, count) in counts {
+                    let _ = writeln!(out, "governance_veto_signals{{type=\"{}\"}} {}", escape(&signal_type), count);
+                }
+            }
+            Err(e) => warn!("Failed to count veto signals for metrics: {}", e),
+        }
+
+        let (active, idle) = database.pool_connections();
+        write_header(&mut out, "governance_db_connections", "Database pool connections, by state", "gauge");
+        let _ = writeln!(out, "governance_db_connections{{state=\"active\"}} {}", active);
+        let _ = writeln!(out, "governance_db_connections{{state=\"idle\"}} {}", idle);
+
+        out
+    }
+}
+
+fn write_header(out: &mut String, name: &str, help: &str, kind: &str) {
+    let _ = writeln!(out, "# HELP {} {}", name, help);
+    let _ = writeln!(out, "# TYPE {} {}", name, kind);
+}
+
+fn escape(label: &str) -> String {
+    label.replace('\\', "\\\\").replace('"', "\\\"").replace('\n', "\\n")
+}
+
+/// Handle `GET /metrics`
+pub async fn metrics_endpoint(
+    State((_config, database, metrics)): State<(AppConfig, Database, Metrics)>,
+) -> impl IntoResponse {
+    (
+        StatusCode::OK,
+        [(header::CONTENT_TYPE, CONTENT_TYPE)],
+        metrics.render(&database).await,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::body::{to_bytes, Body};
+    use axum::http::Request;
+    use axum::routing::get;
+    use axum::Router;
+    use tower::ServiceExt;
+
+    #[tokio::test]
+    async fn test_metrics_endpoint_exports_prometheus_text() {
+        let database = Database::new_in_memory().await.unwrap();
+        let metrics = Metrics::new();
+        metrics.record_webhook("pull_request");
+        metrics.record_webhook("pull_request");
+        metrics.record_pr_classified(3);
+        metrics.record_merge_block();
+        metrics.record_nostr_publish(false);
+
+        let app = Router::new()
+            .route("/metrics", get(metrics_endpoint))
+            .with_state((AppConfig::load().unwrap(), database, metrics));
+        let response = app
+            .oneshot(Request::get("/metrics").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.headers()[header::CONTENT_TYPE], CONTENT_TYPE);
+
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let body = String::from_utf8(body.to_vec()).unwrap();
+        for name in [
+            "governance_webhooks_received_total",
+            "governance_prs_classified_total",
+            "governance_merge_blocks_total",
+            "governance_nostr_publish_total",
+            "governance_ots_anchor_attempts_total",
+            "governance_github_file_cache_total",
+            "governance_content_hash_cache_total",
+            "governance_veto_signals",
+            "governance_db_connections",
+        ] {
+            assert!(body.contains(&format!("# HELP {} ", name)), "missing HELP for {}", name);
+            assert!(body.contains(&format!("# TYPE {} ", name)), "missing TYPE for {}", name);
+        }
+        assert!(body.contains("governance_webhooks_received_total{event=\"pull_request\"} 2"));
+        assert!(body.contains("governance_prs_classified_total{tier=\"3\"} 1"));
+        assert!(body.contains("governance_merge_blocks_total 1"));
+        assert!(body.contains("governance_nostr_publish_total{result=\"failure\"} 1"));
+    }
+}
@@ -6,15 +6,119 @@
 use anyhow::{anyhow, Result};
 use nostr_sdk::prelude::*;
 use std::collections::HashMap;
+use std::fmt;
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 use tokio::sync::Mutex;
+use tokio::task::JoinSet;
 use tracing::{debug, error, info, warn};
 
+/// How long to wait for a relay's OK message by default
+pub const DEFAULT_ACK_TIMEOUT: Duration = Duration::from_secs(10);
+
+const INITIAL_RECONNECT_BACKOFF: Duration = Duration::from_secs(1);
+const MAX_RECONNECT_BACKOFF: Duration = Duration::from_secs(300);
+
+/// Connection health of a single relay
+#[derive(Debug, Clone, Default)]
+pub struct RelayHealth {
+    pub connected: bool,
+    pub consecutive_failures: u32,
+    pub last_error: Option<String>,
+    next_reconnect: Option<Instant>,
+}
+
+impl RelayHealth {
+    fn record_success(&mut self) {
+        self.connected = true;
+        self.consecutive_failures = 0;
+        self.last_error = None;
+        self.next_reconnect = None;
+    }
+
+    /// Record a failure and push the next reconnect out exponentially
+    fn record_failure(&mut self, error: String) {
+        self.connected = false;
+        self.consecutive_failures += 1;
+        self.last_error = Some(error);
+        self.next_reconnect = Some(Instant::now() + reconnect_backoff(self.consecutive_failures));
+    }
+
+    fn reconnect_due(&self) -> bool {
+        self.next_reconnect.map_or(true, |at| Instant::now() >= at)
+    }
+}
+
+/// Delay before the next reconnect after `failures` consecutive failures
+pub fn reconnect_backoff(failures: u32) -> Duration {
+    let exponent = failures.saturating_sub(1).min(16);
+    INITIAL_RECONNECT_BACKOFF
+        .saturating_mul(1 << exponent)
+        .min(MAX_RECONNECT_BACKOFF)
+}
+
+/// Per-relay outcome of publishing one event
+#[derive(Debug, Clone, Default)]
+pub struct PublishReport {
+    /// Relays that acknowledged the event with an accepting OK
+    pub accepted: Vec<String>,
+    /// Relays that rejected, timed out, or were unreachable, with the reason
+    pub failed: Vec<(String, String)>,
+}
+
+impl PublishReport {
+    fn failure_summary(&self) -> String {
+        self.failed
+            .iter()
+            .map(|(relay, reason)| format!("{} ({})", relay, reason))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+}
+
+/// Why an event was not acknowledged by every relay
+#[derive(Debug)]
+pub enum PublishError {
+    /// No relay acknowledged the event
+    NotAcknowledged(PublishReport),
+    /// Some relays acknowledged the event but others failed
+    PartialFailure(PublishReport),
+}
+
+impl PublishError {
+    pub fn report(&self) -> &PublishReport {
+        match self {
+            PublishError::NotAcknowledged(report) | PublishError::PartialFailure(report) => report,
+        }
+    }
+}
+
+impl fmt::Display for PublishError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PublishError::NotAcknowledged(report) => write!(
+                f,
+                "No relay acknowledged the event; failed relays: {}",
+                report.failure_summary()
+            ),
+            PublishError::PartialFailure(report) => write!(
+                f,
+                "Event acknowledged by {} relays; failed relays: {}",
+                report.accepted.len(),
+                report.failure_summary()
+            ),
+        }
+    }
+}
+
+impl std::error::Error for PublishError {}
+
 /// Nostr client managing multiple relay connections
 pub struct NostrClient {
     client: Client,
     pub keys: Keys,
-    relay_status: Arc<Mutex<HashMap<String, bool>>>,
+    relay_status: Arc<Mutex<HashMap<String, RelayHealth>>>,
+    ack_timeout: Duration,
 }
 
 impl NostrClient {
@@ -46,55 +150,148 @@ impl NostrClient {
             client,
             keys,
             relay_status,
+            ack_timeout: DEFAULT_ACK_TIMEOUT,
         })
     }
 
-    /// Publish event to all connected relays
-    pub async fn publish_event(&self, event: Event) -> Result<()> {
-        let mut successful_relays = 0;
-        let mut failed_relays = Vec::new();
+    /// Wait at most `ack_timeout` for each relay's OK message
+    pub fn with_ack_timeout(mut self, ack_timeout: Duration) -> Self {
+        self.ack_timeout = ack_timeout;
+        self
+    }
 
-        // Get list of connected relays
+    /// Reconnect relays that have dropped, once their backoff has elapsed
+    pub async fn reconnect_disconnected(&self) {
         let relays = self.client.relays().await;
-
         for (relay_url, relay) in &relays {
-            match relay.send_event(event.clone(), RelaySendOptions::new()).await {
-                Ok(_) => {
-                    debug!("Published event to relay: {}", relay_url);
-                    successful_relays += 1;
-                    
-                    // Update relay status
-                    let mut status = self.relay_status.lock().await;
-                    status.insert(relay_url.to_string(), true);
-                }
-                Err(e) => {
-                    error!("Failed to publish to relay {}: {}", relay_url, e);
-                    failed_relays.push(relay_url.to_string());
-                    
-                    // Update relay status
-                    let mut status = self.relay_status.lock().await;
-                    status.insert(relay_url.to_string(), false);
-                }
+            if relay.is_connected().await {
+                continue;
+            }
+            let url = relay_url.to_string();
+            let due = self
+                .relay_status
+                .lock()
+                .await
+                .get(&url)
+                .map_or(true, RelayHealth::reconnect_due);
+            if !due {
+                continue;
+            }
+
+            info!("Reconnecting to relay {}", url);
+            if let Err(e) = self.client.connect_relay(relay_url.clone()).await {
+                warn!("Failed to reconnect to relay {}: {}", url, e);
+                self.relay_status
+                    .lock()
+                    .await
+                    .entry(url)
+                    .or_default()
+                    .record_failure(e.to_string());
             }
         }
+    }
 
-        if successful_relays == 0 {
-            return Err(anyhow!("Failed to publish to any relay"));
+    /// Publish an event and wait for each relay to acknowledge it
+    ///
+    /// Succeeds only if every relay sent an accepting OK within the ack
+    /// timeout. If at least one did, the event is out and the error is
+    /// `PartialFailure`; callers can treat that as published.
+    pub async fn publish_event(&self, event: Event) -> std::result::Result<PublishReport, PublishError> {
+        self.reconnect_disconnected().await;
+
+        let relays = self.client.relays().await;
+        let options = RelaySendOptions::new()
+            .skip_disconnected(false)
+            .skip_send_confirmation(false)
+            .timeout(Some(self.ack_timeout));
+
+        let mut sends = JoinSet::new();
+        for (relay_url, relay) in relays {
+            let event = event.clone();
+            let options = options.clone();
+            sends.spawn(async move {
+                let outcome = if relay.is_connected().await {
+                    relay
+                        .send_event(event, options)
+                        .await
+                        .map(|_| ())
+                        .map_err(|e| e.to_string())
+                } else {
+                    Err("not connected".to_string())
+                };
+                (relay_url.to_string(), outcome)
+            });
         }
 
-        if !failed_relays.is_empty() {
-            warn!("Failed to publish to {} relays: {:?}", failed_relays.len(), failed_relays);
+        let mut report = PublishReport::default();
+        while let Some(joined) = sends.join_next().await {
+            let (relay_url, outcome) = match joined {
+                Ok(result) => result,
+                Err(e) => {
+                    error!("Relay publish task failed: {}", e);
+                    continue;
+                }
+            };
+
+            let mut status = self.relay_status.lock().await;
+            let health = status.entry(relay_url.clone()).or_default();
+            match outcome {
+                Ok(()) => {
+                    debug!("Relay {} acknowledged event {}", relay_url, event.id);
+                    health.record_success();
+                    report.accepted.push(relay_url);
+                }
+                Err(reason) => {
+                    error!("Relay {} did not accept event {}: {}", relay_url, event.id, reason);
+                    health.record_failure(reason.clone());
+                    report.failed.push((relay_url, reason));
+                }
+            }
         }
+        report.accepted.sort();
+        report.failed.sort();
 
-        info!("Published event to {}/{} relays", successful_relays, relays.len());
-        Ok(())
+        if report.accepted.is_empty() {
+            return Err(PublishError::NotAcknowledged(report));
+        }
+        info!(
+            "Published event to {}/{} relays",
+            report.accepted.len(),
+            report.accepted.len() + report.failed.len()
+        );
+        if !report.failed.is_empty() {
+            return Err(PublishError::PartialFailure(report));
+        }
+        Ok(report)
     }
 
     /// Get current relay status
     pub async fn get_relay_status(&self) -> HashMap<String, bool> {
+        self.relay_status
+            .lock()
+            .await
+            .iter()
+            .map(|(url, health)| (url.clone(), health.connected))
+            .collect()
+    }
+
+    /// Get the full health record of every relay that has been published to
+    pub async fn get_relay_health(&self) -> HashMap<String, RelayHealth> {
         self.relay_status.lock().await.clone()
     }
 
+    /// Number of relays with a live connection, and the number configured
+    pub async fn connected_relay_count(&self) -> (usize, usize) {
+        let relays = self.client.relays().await;
+        let mut connected = 0;
+        for relay in relays.values() {
+            if relay.is_connected().await {
+                connected += 1;
+            }
+        }
+        (connected, relays.len())
+    }
+
     /// Close all relay connections
     pub async fn close(&self) -> Result<()> {
         self.client.disconnect().await?;
@@ -108,6 +305,59 @@ impl NostrClient {
     }
 }
 
+/// In-process relay for tests that publish events
+#[cfg(test)]
+pub(crate) mod mock_relay {
+    use axum::extract::ws::{Message, WebSocket, WebSocketUpgrade};
+    use axum::routing::get;
+    use serde_json::Value;
+    use std::sync::{Arc, Mutex};
+
+    /// Events a mock relay has received, in arrival order
+    pub(crate) type Received = Arc<Mutex<Vec<Value>>>;
+
+    /// Start a relay that records every EVENT and answers with an OK of `accept`
+    pub(crate) async fn spawn(accept: bool) -> (String, Received) {
+        async fn serve(mut socket: WebSocket, accept: bool, received: Received) {
+            while let Some(Ok(Message::Text(text))) = socket.recv().await {
+                let message: Value = match serde_json::from_str(&text) {
+                    Ok(message) => message,
+                    Err(_) => continue,
+                };
+                if message[0] == "EVENT" {
+                    received.lock().unwrap().push(message[1].clone());
+                    let reply = serde_json::json!([
+                        "OK",
+                        message[1]["id"],
+                        accept,
+                        if accept { "" } else { "blocked: not on allowlist" }
+                    ]);
+                    if socket.send(Message::Text(reply.to_string())).await.is_err() {
+                        break;
+                    }
+                }
+            }
+        }
+
+        let received = Received::default();
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let app = {
+            let received = received.clone();
+            axum::Router::new().route(
+                "/",
+                get(move |ws: WebSocketUpgrade| async move {
+                    ws.on_upgrade(move |socket| serve(socket, accept, received))
+                }),
+            )
+        };
+        tokio::spawn(async move {
+            axum::serve(listener, app).await.unwrap();
+        });
+        (format!("ws://{}", addr), received)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -130,4 +380,58 @@ mod tests {
         let result = NostrClient::new("invalid_key".to_string(), vec![]).await;
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_reconnect_backoff_doubles_up_to_cap() {
+        assert_eq!(reconnect_backoff(1), Duration::from_secs(1));
+        assert_eq!(reconnect_backoff(2), Duration::from_secs(2));
+        assert_eq!(reconnect_backoff(5), Duration::from_secs(16));
+        assert_eq!(reconnect_backoff(20), MAX_RECONNECT_BACKOFF);
+    }
+
+    #[tokio::test]
+    async fn test_partial_failure_names_rejecting_relay() {
+        let (accepting, _) = mock_relay::spawn(true).await;
+        let (rejecting, _) = mock_relay::spawn(false).await;
+
+        let keys = Keys::generate();
+        let client = NostrClient::new(
+            keys.secret_key().unwrap().to_secret_hex(),
+            vec![accepting.clone(), rejecting.clone()],
+        )
+        .await
+        .unwrap()
+        .with_ack_timeout(Duration::from_secs(2));
+
+        for _ in 0..50 {
+            if client.connected_relay_count().await == (2, 2) {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(50)).await;
+        }
+        assert_eq!(client.connected_relay_count().await, (2, 2));
+
+        let event = EventBuilder::new_text_note("governance status", [])
+            .to_event(&client.keys)
+            .unwrap();
+        let err = client.publish_event(event).await.unwrap_err();
+
+        let report = match &err {
+            PublishError::PartialFailure(report) => report,
+            other => panic!("expected partial failure, got {}", other),
+        };
+        let rejecting_host = rejecting.trim_start_matches("ws://");
+        let accepting_host = accepting.trim_start_matches("ws://");
+        assert_eq!(report.accepted.len(), 1);
+        assert!(report.accepted[0].contains(accepting_host));
+        assert_eq!(report.failed.len(), 1);
+        assert!(report.failed[0].0.contains(rejecting_host));
+        assert!(err.to_string().contains(rejecting_host));
+        assert!(!err.to_string().contains(accepting_host));
+
+        let health = client.get_relay_health().await;
+        let rejected = health.iter().find(|(url, _)| url.contains(rejecting_host)).unwrap().1;
+        assert_eq!(rejected.consecutive_failures, 1);
+        assert!(rejected.last_error.is_some());
+    }
 }
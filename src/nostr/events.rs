@@ -2,8 +2,20 @@
 //!
 //! Defines the structure of governance status events published to Nostr.
 
+use anyhow::{anyhow, Result};
 use chrono::{DateTime, Utc};
+use nostr_sdk::prelude::*;
 use serde::{Deserialize, Serialize};
+use tracing::warn;
+
+use crate::authorization::AuthorizedServer;
+use crate::enforcement::violations::GovernanceViolation;
+
+/// Parameterized replaceable event kind used for governance status
+pub const GOVERNANCE_STATUS_KIND: u64 = 30078;
+
+/// Regular event kind used for governance violation alerts, so each one is kept
+pub const GOVERNANCE_VIOLATION_KIND: u64 = 1078;
 
 /// Governance status event published to Nostr
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -74,6 +86,35 @@ impl GovernanceStatus {
         serde_json::to_string(self)
     }
 
+    /// Build the status event, signed with the server's Nostr key
+    ///
+    /// The signer's npub is repeated in a `server_npub` tag alongside
+    /// `server`, so readers can look the pair up in the authorized server
+    /// registry before trusting the content.
+    pub fn to_signed_event(&self, keys: &Keys) -> Result<Event> {
+        let content = self
+            .to_json()
+            .map_err(|e| anyhow!("Failed to serialize status: {}", e))?;
+        let npub = keys
+            .public_key()
+            .to_bech32()
+            .map_err(|e| anyhow!("Failed to encode server npub: {}", e))?;
+        let current_month = Utc::now().format("%Y-%m").to_string();
+
+        let tags = vec![
+            Tag::Generic(TagKind::Custom("d".into()), vec!["governance-status".to_string()]),
+            Tag::Generic(TagKind::Custom("server".into()), vec![self.server_id.clone()]),
+            Tag::Generic(TagKind::Custom("server_npub".into()), vec![npub]),
+            Tag::Generic(TagKind::Custom("authorized_by".into()), vec![format!("registry-{}", current_month)]),
+            Tag::Generic(TagKind::Custom("btcdecoded".into()), vec!["governance-infrastructure".to_string()]),
+            Tag::Generic(TagKind::Custom("t".into()), vec!["bitcoin".to_string(), "governance".to_string()]),
+        ];
+
+        EventBuilder::new(Kind::Custom(GOVERNANCE_STATUS_KIND), content, tags)
+            .to_event(keys)
+            .map_err(|e| anyhow!("Failed to create Nostr event: {}", e))
+    }
+
     /// Get a human-readable summary
     pub fn summary(&self) -> String {
         format!(
@@ -86,6 +127,77 @@ impl GovernanceStatus {
     }
 }
 
+/// Build a violation alert signed with the server's Nostr key
+///
+/// Tagged like status events, plus the repository and PR, so relay
+/// subscribers can filter alerts for the repos they watch.
+pub fn violation_event(violation: &GovernanceViolation, server_id: &str, keys: &Keys) -> Result<Event> {
+    let content = serde_json::to_string(violation)
+        .map_err(|e| anyhow!("Failed to serialize violation: {}", e))?;
+    let npub = keys
+        .public_key()
+        .to_bech32()
+        .map_err(|e| anyhow!("Failed to encode server npub: {}", e))?;
+
+    let tags = vec![
+        Tag::Generic(TagKind::Custom("server".into()), vec![server_id.to_string()]),
+        Tag::Generic(TagKind::Custom("server_npub".into()), vec![npub]),
+        Tag::Generic(TagKind::Custom("repo".into()), vec![violation.repo_name.clone()]),
+        Tag::Generic(TagKind::Custom("pr".into()), vec![violation.pr_number.to_string()]),
+        Tag::Generic(TagKind::Custom("severity".into()), vec![violation.severity.clone()]),
+        Tag::Generic(TagKind::Custom("btcdecoded".into()), vec!["governance-violation".to_string()]),
+        Tag::Generic(TagKind::Custom("t".into()), vec!["bitcoin".to_string(), "governance".to_string()]),
+    ];
+
+    EventBuilder::new(Kind::Custom(GOVERNANCE_VIOLATION_KIND), content, tags)
+        .to_event(keys)
+        .map_err(|e| anyhow!("Failed to create Nostr event: {}", e))
+}
+
+/// Check that a status event was signed by an active authorized server
+///
+/// The event signature must be valid, its pubkey must belong to an
+/// authorized server whose status is active, and the `server` and
+/// `server_npub` tags must name that same server.
+pub fn verify_status_event(event: &Event, authorized_servers: &[AuthorizedServer]) -> bool {
+    if event.kind != Kind::Custom(GOVERNANCE_STATUS_KIND) {
+        return false;
+    }
+    if let Err(e) = event.verify() {
+        warn!("Rejected status event {} with invalid signature: {}", event.id, e);
+        return false;
+    }
+
+    let signer_hex = event.pubkey.to_string();
+    let signer_npub = match event.pubkey.to_bech32() {
+        Ok(npub) => npub,
+        Err(_) => return false,
+    };
+    let server = match authorized_servers
+        .iter()
+        .find(|s| s.keys.nostr_npub == signer_npub || s.keys.nostr_npub == signer_hex)
+    {
+        Some(server) => server,
+        None => {
+            warn!("Rejected status event {} from unknown key {}", event.id, signer_npub);
+            return false;
+        }
+    };
+    if !server.is_authorized() {
+        warn!("Rejected status event {} from {} server {}", event.id, server.status, server.server_id);
+        return false;
+    }
+
+    let tag_value = |name: &str| {
+        event.tags.iter().find_map(|tag| match tag {
+            Tag::Generic(TagKind::Custom(kind), values) if kind == name => values.first().cloned(),
+            _ => None,
+        })
+    };
+    tag_value("server").as_deref() == Some(server.server_id.as_str())
+        && tag_value("server_npub").as_deref() == Some(signer_npub.as_str())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -136,6 +248,69 @@ mod tests {
         assert!(json.contains("sha256:test"));
     }
 
+    fn status(server_id: &str) -> GovernanceStatus {
+        GovernanceStatus::new(
+            server_id.to_string(),
+            "sha256:abc".to_string(),
+            "sha256:def".to_string(),
+            1,
+            None,
+            None,
+            0,
+            Utc::now(),
+            HashMap::new(),
+            None,
+            None,
+        )
+    }
+
+    fn authorized(server_id: &str, keys: &Keys) -> AuthorizedServer {
+        use crate::authorization::{InfrastructureInfo, OperatorInfo, ServerKeys};
+
+        AuthorizedServer::new(
+            server_id.to_string(),
+            OperatorInfo {
+                name: "Test Operator".to_string(),
+                jurisdiction: "US".to_string(),
+                contact: None,
+            },
+            ServerKeys {
+                nostr_npub: keys.public_key().to_bech32().unwrap(),
+                ssh_fingerprint: "SHA256:test".to_string(),
+            },
+            InfrastructureInfo {
+                vpn_ip: None,
+                github_runner: false,
+                ots_enabled: true,
+            },
+        )
+    }
+
+    #[test]
+    fn test_signed_status_event_verifies_against_registry() {
+        let keys = Keys::generate();
+        let servers = vec![authorized("governance-01", &keys)];
+
+        let event = status("governance-01").to_signed_event(&keys).unwrap();
+        assert!(verify_status_event(&event, &servers));
+
+        // A server that has been marked compromised is no longer trusted
+        let mut compromised = servers.clone();
+        compromised[0].status = crate::authorization::ServerStatus::Compromised;
+        assert!(!verify_status_event(&event, &compromised));
+
+        // Claiming another server's id with a valid key is rejected
+        let event = status("governance-02").to_signed_event(&keys).unwrap();
+        assert!(!verify_status_event(&event, &servers));
+    }
+
+    #[test]
+    fn test_status_event_from_unknown_key_is_rejected() {
+        let servers = vec![authorized("governance-01", &Keys::generate())];
+        let event = status("governance-01").to_signed_event(&Keys::generate()).unwrap();
+        assert!(!verify_status_event(&event, &servers));
+    }
+
     #[test]
     fn test_summary() {
         let relay_status = HashMap::new();
@@ -7,6 +7,6 @@ pub mod client;
 pub mod publisher;
 pub mod events;
 
-pub use client::NostrClient;
+pub use client::{NostrClient, PublishError, PublishReport, RelayHealth};
 pub use publisher::StatusPublisher;
-pub use events::{GovernanceStatus, ServerHealth, Hashes};
+pub use events::{verify_status_event, violation_event, GovernanceStatus, ServerHealth, Hashes};
@@ -10,15 +10,16 @@ use nostr_sdk::prelude::*;
 use sha2::{Digest, Sha256};
 use std::collections::HashMap;
 use std::fs;
+use std::sync::Arc;
 use tracing::{debug, error, info, warn};
 
 use crate::database::Database;
-use crate::nostr::client::NostrClient;
+use crate::nostr::client::{NostrClient, PublishError};
 use crate::nostr::events::{GovernanceStatus, Hashes, ServerHealth};
 
 /// Status publisher for governance infrastructure
 pub struct StatusPublisher {
-    client: NostrClient,
+    client: Arc<NostrClient>,
     database: Database,
     server_id: String,
     binary_path: String,
@@ -29,7 +30,7 @@ pub struct StatusPublisher {
 impl StatusPublisher {
     /// Create new status publisher
     pub fn new(
-        client: NostrClient,
+        client: Arc<NostrClient>,
         database: Database,
         server_id: String,
         binary_path: String,
@@ -80,13 +81,22 @@ impl StatusPublisher {
         // Create Nostr event
         let event = self.create_nostr_event(status)?;
 
-        // Publish to relays
-        self.client.publish_event(event).await?;
-
-        info!("Successfully published governance status");
+        // Publish to relays; one acknowledgement is enough for the status to be public
+        match self.client.publish_event(event).await {
+            Ok(_) => info!("Successfully published governance status"),
+            Err(e @ PublishError::PartialFailure(_)) => {
+                warn!("Published governance status with relay failures: {}", e);
+            }
+            Err(e) => return Err(anyhow!("Failed to publish governance status: {}", e)),
+        }
         Ok(())
     }
 
+    /// Relays currently connected, and the number configured
+    pub async fn relay_counts(&self) -> (usize, usize) {
+        self.client.connected_relay_count().await
+    }
+
     /// Calculate SHA256 hash of a file
     fn calculate_file_hash(&self, file_path: &str) -> Result<String> {
         let content = fs::read(file_path)
@@ -110,8 +120,7 @@ impl StatusPublisher {
         let last_merge_time = None;
         let merges_today = 0;
 
-        // TODO: Implement relay status tracking
-        let relay_status = HashMap::new();
+        let relay_status = self.client.get_relay_status().await;
 
         Ok(ServerHealth {
             uptime_hours,
@@ -142,29 +151,9 @@ impl StatusPublisher {
             .with_minute(0).unwrap().with_second(0).unwrap()
     }
 
-    /// Create Nostr event from governance status
+    /// Create Nostr event from governance status, signed with the server key
     fn create_nostr_event(&self, status: GovernanceStatus) -> Result<Event> {
-        let content = status.to_json()
-            .map_err(|e| anyhow!("Failed to serialize status: {}", e))?;
-
-        let current_month = Utc::now().format("%Y-%m").to_string();
-
-        let tags = vec![
-            Tag::Generic(TagKind::Custom("d".into()), vec!["governance-status".to_string()]),
-            Tag::Generic(TagKind::Custom("server".into()), vec![self.server_id.clone()]),
-            Tag::Generic(TagKind::Custom("authorized_by".into()), vec![format!("registry-{}", current_month)]),
-            Tag::Generic(TagKind::Custom("btcdecoded".into()), vec!["governance-infrastructure".to_string()]),
-            Tag::Generic(TagKind::Custom("t".into()), vec!["bitcoin".to_string(), "governance".to_string()]),
-        ];
-
-        let event = EventBuilder::new(
-            Kind::Custom(30078),
-            content,
-            tags,
-        ).to_event(&self.client.keys)
-            .map_err(|e| anyhow!("Failed to create Nostr event: {}", e))?;
-
-        Ok(event)
+        status.to_signed_event(&self.client.keys)
     }
 }
 
@@ -181,7 +170,7 @@ mod tests {
         fs::write(&test_file, "test content").unwrap();
 
         let publisher = StatusPublisher {
-            client: NostrClient::new("test".to_string(), vec![]).await.unwrap(),
+            client: Arc::new(NostrClient::new("test".to_string(), vec![]).await.unwrap()),
             database: Database::new_sqlite(":memory:".to_string()).await.unwrap(),
             server_id: "test".to_string(),
             binary_path: test_file.to_string_lossy().to_string(),
@@ -197,7 +186,7 @@ mod tests {
     #[test]
     fn test_next_ots_anchor_calculation() {
         let publisher = StatusPublisher {
-            client: NostrClient::new("test".to_string(), vec![]).await.unwrap(),
+            client: Arc::new(NostrClient::new("test".to_string(), vec![]).await.unwrap()),
             database: Database::new_sqlite(":memory:".to_string()).await.unwrap(),
             server_id: "test".to_string(),
             binary_path: "".to_string(),
@@ -6,12 +6,14 @@
 use anyhow::{anyhow, Result};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
-use sha2::{Digest, Sha256};
 use std::collections::HashMap;
 use std::fs;
 use std::path::{Path, PathBuf};
 use tracing::{info, warn};
 
+use crate::audit::merkle::{
+    build_merkle_tree_from_hashes, generate_merkle_proof_from_hashes, MerkleProof,
+};
 use crate::database::Database;
 use crate::ots::client::{OtsClient, VerificationResult};
 
@@ -21,6 +23,7 @@ pub struct RegistryAnchorer {
     database: Database,
     registry_path: PathBuf,
     proofs_path: PathBuf,
+    config_paths: Vec<PathBuf>,
 }
 
 /// Governance registry structure
@@ -32,6 +35,9 @@ pub struct GovernanceRegistry {
     pub maintainers: Vec<Maintainer>,
     pub authorized_servers: Vec<AuthorizedServer>,
     pub audit_logs: HashMap<String, AuditLogSummary>,
+    /// SHA256 hashes of the server's configuration files, keyed by path
+    #[serde(default)]
+    pub config_hashes: HashMap<String, String>,
     pub multisig_config: MultisigConfig,
 }
 
@@ -108,10 +114,20 @@ impl RegistryAnchorer {
             database,
             registry_path: PathBuf::from(registry_path),
             proofs_path: PathBuf::from(proofs_path),
+            config_paths: Vec::new(),
         }
     }
 
+    /// Record hashes of these configuration files in each anchored registry
+    pub fn with_config_paths(mut self, config_paths: Vec<String>) -> Self {
+        self.config_paths = config_paths.into_iter().map(PathBuf::from).collect();
+        self
+    }
+
     /// Generate and anchor monthly registry
+    ///
+    /// The registry and the configured config files are anchored together
+    /// under a single Merkle root; see `anchor_batch`.
     pub async fn anchor_registry(&self) -> Result<()> {
         let now = Utc::now();
         let month_key = now.format("%Y-%m").to_string();
@@ -125,18 +141,15 @@ impl RegistryAnchorer {
         let registry_file = self.registry_path.join(format!("{}.json", month_key));
         self.save_registry(&registry, &registry_file).await?;
 
-        // Create OTS timestamp
-        let registry_data = serde_json::to_vec(&registry)
-            .map_err(|e| anyhow!("Failed to serialize registry: {}", e))?;
-
-        let proof_data = self.ots_client.stamp(&registry_data).await?;
-
-        // Save OTS proof
-        let proof_file = self.proofs_path.join(format!("{}.json.ots", month_key));
-        self.save_proof(&proof_data, &proof_file).await?;
+        // Anchor the registry alongside the config files it records
+        let mut files = vec![registry_file.clone()];
+        files.extend(self.config_paths.iter().filter(|p| p.exists()).cloned());
+        let batch = anchor_batch(&self.ots_client, &files, &self.proofs_path, &month_key).await?;
 
         // Store in database
-        self.store_registry_info(&month_key, &registry_file, &proof_file).await?;
+        let registry_hash = crate::ots::integrity::hash_config_file(&registry_file)?;
+        self.store_registry_info(&month_key, &registry_hash, &registry_file, &batch.proof_path, now)
+            .await?;
 
         info!("Successfully anchored registry for {} to Bitcoin", month_key);
         Ok(())
@@ -159,6 +172,9 @@ impl RegistryAnchorer {
         // Get audit log summaries
         let audit_logs = self.get_audit_log_summaries().await?;
 
+        // Get configuration file hashes
+        let config_hashes = self.get_config_hashes()?;
+
         // Get multisig configuration
         let multisig_config = self.get_multisig_config().await?;
 
@@ -169,6 +185,7 @@ impl RegistryAnchorer {
             maintainers,
             authorized_servers,
             audit_logs,
+            config_hashes,
             multisig_config,
         })
     }
@@ -201,6 +218,23 @@ impl RegistryAnchorer {
         Ok(HashMap::new())
     }
 
+    /// Hash each configured config file
+    ///
+    /// Missing files are left out, which the startup self-test reads as
+    /// "still missing", so an absent optional file doesn't stop anchoring.
+    fn get_config_hashes(&self) -> Result<HashMap<String, String>> {
+        let mut hashes = HashMap::new();
+        for path in &self.config_paths {
+            if !path.exists() {
+                warn!("Config file {} not found, leaving it out of the registry", path.display());
+                continue;
+            }
+            let hash = crate::ots::integrity::hash_config_file(path)?;
+            hashes.insert(path.to_string_lossy().to_string(), hash);
+        }
+        Ok(hashes)
+    }
+
     /// Get multisig configuration
     async fn get_multisig_config(&self) -> Result<MultisigConfig> {
         Ok(MultisigConfig {
@@ -227,42 +261,152 @@ impl RegistryAnchorer {
         Ok(())
     }
 
-    /// Save OTS proof to file
-    async fn save_proof(&self, proof: &[u8], path: &Path) -> Result<()> {
-        // Ensure directory exists
-        if let Some(parent) = path.parent() {
-            fs::create_dir_all(parent)
-                .map_err(|e| anyhow!("Failed to create directory: {}", e))?;
-        }
-
-        fs::write(path, proof)
-            .map_err(|e| anyhow!("Failed to write proof file: {}", e))?;
+    /// Store registry information in database
+    async fn store_registry_info(
+        &self,
+        month_key: &str,
+        registry_hash: &str,
+        registry_file: &Path,
+        proof_file: &Path,
+        covers_until: DateTime<Utc>,
+    ) -> Result<()> {
+        self.database
+            .record_registry_anchor(
+                month_key,
+                registry_hash,
+                &registry_file.to_string_lossy(),
+                &proof_file.to_string_lossy(),
+                covers_until,
+            )
+            .await
+            .map_err(|e| anyhow!("Failed to store registry info: {}", e))?;
 
-        info!("Saved OTS proof to: {}", path.display());
+        info!("Stored registry info for {}: {} -> {}", month_key, registry_file.display(), proof_file.display());
         Ok(())
     }
 
-    /// Store registry information in database
-    async fn store_registry_info(&self, month_key: &str, registry_file: &Path, proof_file: &Path) -> Result<()> {
-        // This would store the registry info in the database
-        // For now, just log
-        info!("Stored registry info for {}: {} -> {}", month_key, registry_file.display(), proof_file.display());
-        Ok(())
+    /// Verify a registry against its inclusion proof and the batch's OTS proof
+    pub async fn verify_registry(&self, registry_file: &Path, inclusion_file: &Path) -> Result<VerificationResult> {
+        verify_file_inclusion(&self.ots_client, registry_file, inclusion_file).await
+    }
+}
+
+/// Proof that one file is covered by a batch's anchored Merkle root
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InclusionProof {
+    pub file_name: String,
+    pub merkle_proof: MerkleProof,
+    /// OTS proof of the batch's Merkle root
+    pub batch_proof: PathBuf,
+}
+
+/// Result of anchoring a batch of files
+#[derive(Debug, Clone)]
+pub struct AnchoredBatch {
+    pub merkle_root: String,
+    /// OTS proof of the Merkle root, `<proofs>/<batch_key>.ots`
+    pub proof_path: PathBuf,
+    /// Per-file inclusion proofs, in the order the files were given
+    pub inclusion_paths: Vec<PathBuf>,
+}
+
+/// Anchor several files with a single timestamp
+///
+/// Builds a Merkle tree over the files' SHA256 hashes and stamps only the
+/// root. Each file gets `<proofs>/<batch_key>/<file name>.inclusion.json`
+/// so it can later be verified on its own with `verify_file_inclusion`.
+pub async fn anchor_batch(
+    ots_client: &OtsClient,
+    files: &[PathBuf],
+    proofs_path: &Path,
+    batch_key: &str,
+) -> Result<AnchoredBatch> {
+    let mut file_names = Vec::with_capacity(files.len());
+    for file in files {
+        let name = file
+            .file_name()
+            .ok_or_else(|| anyhow!("Cannot anchor {}: not a file", file.display()))?
+            .to_string_lossy()
+            .to_string();
+        if file_names.contains(&name) {
+            return Err(anyhow!("Cannot anchor two files named {} in one batch", name));
+        }
+        file_names.push(name);
     }
 
-    /// Verify a registry against its OTS proof
-    pub async fn verify_registry(&self, registry_file: &Path, proof_file: &Path) -> Result<VerificationResult> {
-        // Load registry data
-        let registry_data = fs::read(registry_file)
-            .map_err(|e| anyhow!("Failed to read registry file: {}", e))?;
+    let leaves = files
+        .iter()
+        .map(|file| crate::ots::integrity::hash_config_file(file))
+        .collect::<Result<Vec<_>>>()?;
+    let merkle_root = build_merkle_tree_from_hashes(&leaves)?.hash;
+
+    let proof = ots_client.stamp(merkle_root.as_bytes()).await?;
+    let proof_path = proofs_path.join(format!("{}.ots", batch_key));
+    let inclusion_dir = proofs_path.join(batch_key);
+    fs::create_dir_all(&inclusion_dir)
+        .map_err(|e| anyhow!("Failed to create directory: {}", e))?;
+    fs::write(&proof_path, &proof)
+        .map_err(|e| anyhow!("Failed to write proof file: {}", e))?;
+
+    let mut inclusion_paths = Vec::with_capacity(files.len());
+    for (index, file_name) in file_names.into_iter().enumerate() {
+        let inclusion = InclusionProof {
+            merkle_proof: generate_merkle_proof_from_hashes(&leaves, index)?,
+            batch_proof: proof_path.clone(),
+            file_name,
+        };
+        let path = inclusion_dir.join(format!("{}.inclusion.json", inclusion.file_name));
+        fs::write(&path, serde_json::to_string_pretty(&inclusion)?)
+            .map_err(|e| anyhow!("Failed to write inclusion proof: {}", e))?;
+        inclusion_paths.push(path);
+    }
 
-        // Load OTS proof
-        let proof_data = fs::read(proof_file)
-            .map_err(|e| anyhow!("Failed to read proof file: {}", e))?;
+    info!(
+        "Anchored {} files under root {} ({})",
+        files.len(),
+        merkle_root,
+        proof_path.display()
+    );
+    Ok(AnchoredBatch {
+        merkle_root,
+        proof_path,
+        inclusion_paths,
+    })
+}
 
-        // Verify timestamp
-        self.ots_client.verify(&registry_data, &proof_data).await
+/// Verify a single file from an anchored batch
+///
+/// Checks the file's hash against its inclusion proof, the proof against
+/// the batch root, and the root against the batch's OTS proof.
+pub async fn verify_file_inclusion(
+    ots_client: &OtsClient,
+    file: &Path,
+    inclusion_file: &Path,
+) -> Result<VerificationResult> {
+    let inclusion: InclusionProof = serde_json::from_str(
+        &fs::read_to_string(inclusion_file)
+            .map_err(|e| anyhow!("Failed to read inclusion proof {}: {}", inclusion_file.display(), e))?,
+    )
+    .map_err(|e| anyhow!("Failed to parse inclusion proof: {}", e))?;
+
+    let leaf_hash = crate::ots::integrity::hash_config_file(file)?;
+    if leaf_hash != inclusion.merkle_proof.leaf_hash {
+        return Err(anyhow!(
+            "{} hash {} does not match anchored hash {}",
+            file.display(),
+            leaf_hash,
+            inclusion.merkle_proof.leaf_hash
+        ));
+    }
+    if !inclusion.merkle_proof.verify() {
+        return Err(anyhow!("Inclusion proof for {} does not lead to the batch root", file.display()));
     }
+
+    let proof = fs::read(&inclusion.batch_proof)
+        .map_err(|e| anyhow!("Failed to read proof file: {}", e))?;
+    ots_client
+        .verify(inclusion.merkle_proof.root_hash.as_bytes(), &proof)
+        .await
 }
 
 #[cfg(test)]
@@ -287,6 +431,29 @@ mod tests {
         assert!(anchorer.proofs_path.exists() || anchorer.proofs_path.parent().unwrap().exists());
     }
 
+    #[tokio::test]
+    async fn test_missing_config_file_left_out_of_registry() {
+        let temp_dir = tempdir().unwrap();
+        let present = temp_dir.path().join("governance.yml");
+        fs::write(&present, "tiers: 5").unwrap();
+        let missing = temp_dir.path().join("config.toml");
+
+        let anchorer = RegistryAnchorer::new(
+            OtsClient::new("https://alice.btc.calendar.opentimestamps.org".to_string()),
+            Database::new_in_memory().await.unwrap(),
+            temp_dir.path().join("registries").to_string_lossy().to_string(),
+            temp_dir.path().join("proofs").to_string_lossy().to_string(),
+        )
+        .with_config_paths(vec![
+            present.to_string_lossy().to_string(),
+            missing.to_string_lossy().to_string(),
+        ]);
+
+        let hashes = anchorer.get_config_hashes().unwrap();
+        assert_eq!(hashes.len(), 1);
+        assert!(hashes.contains_key(&present.to_string_lossy().to_string()));
+    }
+
     #[test]
     fn test_governance_registry_creation() {
         let registry = GovernanceRegistry {
@@ -296,6 +463,7 @@ mod tests {
             maintainers: vec![],
             authorized_servers: vec![],
             audit_logs: HashMap::new(),
+            config_hashes: HashMap::new(),
             multisig_config: MultisigConfig {
                 required_signatures: 3,
                 total_maintainers: 5,
@@ -305,4 +473,49 @@ mod tests {
         assert_eq!(registry.version, "2025-01");
         assert_eq!(registry.multisig_config.required_signatures, 3);
     }
+
+    #[tokio::test]
+    async fn test_batch_anchor_proves_each_file_against_single_root() {
+        use sha2::{Digest, Sha256};
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/digest"))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(vec![0x00]))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let temp_dir = tempdir().unwrap();
+        let files: Vec<PathBuf> = ["2025-01.json", "config.toml", "version-manifest.yml"]
+            .iter()
+            .enumerate()
+            .map(|(i, name)| {
+                let file = temp_dir.path().join(name);
+                fs::write(&file, format!("contents {}", i)).unwrap();
+                file
+            })
+            .collect();
+        let proofs_path = temp_dir.path().join("proofs");
+
+        let ots_client = OtsClient::new(server.uri());
+        let batch = anchor_batch(&ots_client, &files, &proofs_path, "2025-01").await.unwrap();
+
+        // One calendar submission, of the root
+        let requests = server.received_requests().await.unwrap();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].body, Sha256::digest(batch.merkle_root.as_bytes()).to_vec());
+
+        assert_eq!(batch.inclusion_paths.len(), 3);
+        for (file, inclusion) in files.iter().zip(&batch.inclusion_paths) {
+            let result = verify_file_inclusion(&ots_client, file, inclusion).await.unwrap();
+            assert!(!result.is_confirmed());
+        }
+
+        // A modified file no longer matches its inclusion proof
+        fs::write(&files[1], "tampered").unwrap();
+        assert!(verify_file_inclusion(&ots_client, &files[1], &batch.inclusion_paths[1]).await.is_err());
+    }
 }
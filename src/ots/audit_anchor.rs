@@ -0,0 +1,204 @@
+//! Audit Log Anchoring
+//!
+//! Timestamps the Merkle root of the audit log so that the log's contents
+//! up to a given entry can later be proven to have existed at that time.
+
+use anyhow::{anyhow, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+use tracing::info;
+
+use crate::audit::{get_merkle_root, load_audit_log_from_file};
+use crate::ots::client::{OtsClient, VerificationResult};
+
+/// Record written next to each audit anchor proof
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuditAnchorRecord {
+    pub merkle_root: String,
+    /// Number of leading log entries covered by the root
+    pub entry_count: usize,
+    pub head_hash: String,
+    pub anchored_at: DateTime<Utc>,
+}
+
+/// Anchors audit log Merkle roots with OpenTimestamps
+pub struct AuditAnchorer {
+    ots_client: OtsClient,
+    log_path: PathBuf,
+}
+
+impl AuditAnchorer {
+    /// Create new anchorer for the audit log at `log_path`
+    pub fn new(ots_client: OtsClient, log_path: String) -> Self {
+        Self {
+            ots_client,
+            log_path: PathBuf::from(log_path),
+        }
+    }
+
+    /// Stamp the current Merkle root of the audit log
+    ///
+    /// Writes `<log>.<YYYY-MM>.ots` with the proof and `<log>.<YYYY-MM>.json`
+    /// with the anchored root and entry count. Returns the proof path.
+    pub async fn anchor_audit_log(&self) -> Result<PathBuf> {
+        let log_path = self.log_path.to_string_lossy();
+        let entries = load_audit_log_from_file(&log_path)?;
+        let merkle_root = get_merkle_root(&entries)?;
+        let head_hash = entries
+            .last()
+            .map(|e| e.this_log_hash.clone())
+            .unwrap_or_default();
+
+        let proof = self.ots_client.stamp(merkle_root.as_bytes()).await?;
+
+        let now = Utc::now();
+        let proof_path = PathBuf::from(format!("{}.{}.ots", log_path, now.format("%Y-%m")));
+        let record = AuditAnchorRecord {
+            merkle_root: merkle_root.clone(),
+            entry_count: entries.len(),
+            head_hash,
+            anchored_at: now,
+        };
+
+        fs::write(&proof_path, &proof)
+            .map_err(|e| anyhow!("Failed to write audit anchor proof: {}", e))?;
+        fs::write(
+            proof_path.with_extension("json"),
+            serde_json::to_string_pretty(&record)?,
+        )
+        .map_err(|e| anyhow!("Failed to write audit anchor record: {}", e))?;
+
+        info!(
+            "Anchored audit log {} ({} entries, root {})",
+            log_path,
+            entries.len(),
+            merkle_root
+        );
+        Ok(proof_path)
+    }
+}
+
+/// Recompute an audit log's anchored Merkle root and check its OTS proof
+///
+/// Only the entries covered by the anchor are hashed, so entries appended
+/// after anchoring don't invalidate the proof.
+pub async fn verify_audit_anchor(log_path: &str, proof_path: &str) -> Result<VerificationResult> {
+    let record_path = Path::new(proof_path).with_extension("json");
+    let record: AuditAnchorRecord = serde_json::from_str(
+        &fs::read_to_string(&record_path)
+            .map_err(|e| anyhow!("Failed to read anchor record {}: {}", record_path.display(), e))?,
+    )
+    .map_err(|e| anyhow!("Failed to parse anchor record: {}", e))?;
+
+    let entries = load_audit_log_from_file(log_path)?;
+    if entries.len() < record.entry_count {
+        return Err(anyhow!(
+            "Audit log has {} entries but the anchor covers {}",
+            entries.len(),
+            record.entry_count
+        ));
+    }
+
+    let merkle_root = get_merkle_root(&entries[..record.entry_count])?;
+    if merkle_root != record.merkle_root {
+        return Err(anyhow!(
+            "Audit log Merkle root mismatch: anchored {}, recomputed {}",
+            record.merkle_root,
+            merkle_root
+        ));
+    }
+
+    let proof = fs::read(proof_path).map_err(|e| anyhow!("Failed to read proof file: {}", e))?;
+    let ots_client = OtsClient::new("https://alice.btc.calendar.opentimestamps.org".to_string());
+    ots_client.verify(merkle_root.as_bytes(), &proof).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::audit::{build_merkle_tree, AuditLogger};
+    use sha2::{Digest, Sha256};
+    use std::collections::HashMap;
+    use tempfile::tempdir;
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    #[tokio::test]
+    async fn test_anchor_submits_audit_merkle_root() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/digest"))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(vec![0x00, 0x01]))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let temp_dir = tempdir().unwrap();
+        let log_path = temp_dir.path().join("audit.jsonl").to_string_lossy().to_string();
+        let logger = AuditLogger::new(log_path.clone()).await.unwrap();
+        for i in 0..4 {
+            logger
+                .append(
+                    format!("job-{}", i),
+                    "test_type".to_string(),
+                    "governance-01".to_string(),
+                    "sha256:input".to_string(),
+                    "sha256:output".to_string(),
+                    HashMap::new(),
+                )
+                .await
+                .unwrap();
+        }
+
+        let anchorer = AuditAnchorer::new(OtsClient::new(server.uri()), log_path.clone());
+        let proof_path = anchorer.anchor_audit_log().await.unwrap();
+
+        let entries = load_audit_log_from_file(&log_path).unwrap();
+        let expected_root = build_merkle_tree(&entries).unwrap().hash;
+        let requests = server.received_requests().await.unwrap();
+        assert_eq!(requests[0].body, Sha256::digest(expected_root.as_bytes()).to_vec());
+
+        // Appending after the anchor leaves the anchored prefix verifiable
+        logger
+            .append(
+                "job-late".to_string(),
+                "test_type".to_string(),
+                "governance-01".to_string(),
+                "sha256:input".to_string(),
+                "sha256:output".to_string(),
+                HashMap::new(),
+            )
+            .await
+            .unwrap();
+        let status = verify_audit_anchor(&log_path, &proof_path.to_string_lossy())
+            .await
+            .unwrap();
+        assert!(!status.is_confirmed());
+    }
+
+    #[tokio::test]
+    async fn test_tampered_log_fails_anchor_verification() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/digest"))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(vec![0x00]))
+            .mount(&server)
+            .await;
+
+        let temp_dir = tempdir().unwrap();
+        let log_path = temp_dir.path().join("audit.jsonl").to_string_lossy().to_string();
+        AuditLogger::new(log_path.clone()).await.unwrap();
+
+        let anchorer = AuditAnchorer::new(OtsClient::new(server.uri()), log_path.clone());
+        let proof_path = anchorer.anchor_audit_log().await.unwrap();
+
+        let mut entries = load_audit_log_from_file(&log_path).unwrap();
+        entries[0].job_id = "rewritten".to_string();
+        entries[0].this_log_hash = entries[0].calculate_hash();
+        fs::write(&log_path, serde_json::to_string(&entries[0]).unwrap() + "\n").unwrap();
+
+        assert!(verify_audit_anchor(&log_path, &proof_path.to_string_lossy()).await.is_err());
+    }
+}
@@ -7,6 +7,7 @@ use anyhow::{anyhow, Result};
 use reqwest::Client;
 use sha2::{Digest, Sha256};
 use std::collections::HashMap;
+use std::time::Duration;
 use tracing::{debug, info, warn};
 
 /// OpenTimestamps client for creating and verifying timestamps
@@ -27,50 +28,214 @@ impl OtsClient {
     }
 
     /// Submit data for timestamping
+    ///
+    /// The SHA256 digest of `data` is posted to the aggregator's `/digest`
+    /// endpoint and the returned pending timestamp is wrapped in a detached
+    /// `.ots` file.
     pub async fn stamp(&self, data: &[u8]) -> Result<Vec<u8>> {
         info!("Submitting {} bytes for timestamping", data.len());
 
-        // Calculate SHA256 hash
-        let mut hasher = Sha256::new();
-        hasher.update(data);
-        let hash = hasher.finalize();
-
-        // For now, return a mock proof
-        // In a real implementation, this would submit to OpenTimestamps
-        let mock_proof = format!("MOCK_OTS_PROOF:{}", hex::encode(hash)).into_bytes();
-        
-        info!("Created mock OTS proof for {} bytes", data.len());
-        Ok(mock_proof)
+        let digest = Sha256::digest(data);
+        let url = format!("{}/digest", self.aggregator_url.trim_end_matches('/'));
+
+        let response = self
+            .http_client
+            .post(&url)
+            .header("Accept", "application/vnd.opentimestamps.v1")
+            .body(digest.to_vec())
+            .send()
+            .await
+            .map_err(|e| anyhow!("Failed to submit digest to {}: {}", url, e))?;
+
+        if !response.status().is_success() {
+            return Err(anyhow!(
+                "Calendar {} rejected digest: HTTP {}",
+                url,
+                response.status()
+            ));
+        }
+
+        let timestamp = response
+            .bytes()
+            .await
+            .map_err(|e| anyhow!("Failed to read calendar response: {}", e))?;
+
+        let mut proof = Vec::with_capacity(OTS_HEADER_MAGIC.len() + 2 + digest.len() + timestamp.len());
+        proof.extend_from_slice(OTS_HEADER_MAGIC);
+        proof.push(OTS_VERSION);
+        proof.push(OP_SHA256);
+        proof.extend_from_slice(&digest);
+        proof.extend_from_slice(&timestamp);
+
+        info!("Created pending OTS proof for {} bytes via {}", data.len(), self.aggregator_url);
+        Ok(proof)
     }
 
     /// Verify a timestamp against Bitcoin blockchain
+    ///
+    /// Checks that the proof commits to `data` and reports whether it carries
+    /// a Bitcoin block attestation yet.
     pub async fn verify(&self, data: &[u8], proof: &[u8]) -> Result<VerificationResult> {
         debug!("Verifying timestamp proof ({} bytes)", proof.len());
 
-        // Calculate data hash
-        let mut hasher = Sha256::new();
-        hasher.update(data);
-        let data_hash = hasher.finalize();
-
-        // For now, return a mock verification
-        // In a real implementation, this would verify against OpenTimestamps
-        if proof.starts_with(b"MOCK_OTS_PROOF:") {
-            info!("Mock timestamp verified");
-            Ok(VerificationResult::Confirmed(12345)) // Mock block height
-        } else {
-            Err(anyhow!("Invalid proof format"))
+        let (digest, timestamp) = parse_detached_proof(proof)?;
+        if digest != Sha256::digest(data).as_slice() {
+            return Err(anyhow!("Proof does not commit to the supplied data"));
+        }
+
+        match bitcoin_attestation_height(timestamp) {
+            Some(height) => {
+                info!("Timestamp confirmed at block {}", height);
+                Ok(VerificationResult::Confirmed(height))
+            }
+            None => {
+                info!("Timestamp pending confirmation");
+                Ok(VerificationResult::Pending)
+            }
         }
     }
 
-    /// Upgrade a pending timestamp to confirmed
-    pub async fn upgrade(&self, proof: &[u8]) -> Result<Vec<u8>> {
-        debug!("Upgrading pending timestamp");
+    /// Fetch the calendar's current timestamp for a commitment
+    ///
+    /// Returns `None` when the calendar doesn't know the commitment (HTTP 404).
+    pub async fn fetch_timestamp(&self, commitment: &[u8]) -> Result<Option<Vec<u8>>> {
+        let url = format!(
+            "{}/timestamp/{}",
+            self.aggregator_url.trim_end_matches('/'),
+            hex::encode(commitment)
+        );
+
+        let response = self
+            .http_client
+            .get(&url)
+            .header("Accept", "application/vnd.opentimestamps.v1")
+            .timeout(CALENDAR_TIMEOUT)
+            .send()
+            .await
+            .map_err(|e| anyhow!("Failed to query calendar {}: {}", url, e))?;
+
+        if response.status() == reqwest::StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
+        if !response.status().is_success() {
+            return Err(anyhow!("Calendar {} returned HTTP {}", url, response.status()));
+        }
 
-        // For now, return the same proof
-        // In a real implementation, this would upgrade from OpenTimestamps
-        Ok(proof.to_vec())
+        let timestamp = response
+            .bytes()
+            .await
+            .map_err(|e| anyhow!("Failed to read calendar response: {}", e))?;
+        Ok(Some(timestamp.to_vec()))
     }
 
+    /// Calendar URL this client talks to
+    pub fn aggregator_url(&self) -> &str {
+        &self.aggregator_url
+    }
+
+    /// Upgrade a pending proof with the calendar's Bitcoin attestation
+    ///
+    /// Returns `UpgradedProof::Pending` while the calendar hasn't committed
+    /// the digest to a block yet; errors mean the calendar couldn't be
+    /// queried or the proof is malformed. Proofs that are already confirmed
+    /// are returned unchanged.
+    pub async fn upgrade_proof(&self, proof: &[u8]) -> Result<UpgradedProof> {
+        let (digest, timestamp) = parse_detached_proof(proof)?;
+        if let Some(block_height) = bitcoin_attestation_height(timestamp) {
+            return Ok(UpgradedProof::Complete {
+                proof: proof.to_vec(),
+                block_height,
+            });
+        }
+
+        debug!("Fetching attestation for pending proof from {}", self.aggregator_url);
+        let upgraded = match self.fetch_timestamp(digest).await? {
+            Some(upgraded) => upgraded,
+            None => return Ok(UpgradedProof::Pending),
+        };
+        let block_height = match bitcoin_attestation_height(&upgraded) {
+            Some(height) => height,
+            None => return Ok(UpgradedProof::Pending),
+        };
+
+        let header_len = proof.len() - timestamp.len();
+        let mut complete = Vec::with_capacity(header_len + upgraded.len());
+        complete.extend_from_slice(&proof[..header_len]);
+        complete.extend_from_slice(&upgraded);
+
+        info!("Upgraded proof to Bitcoin attestation at block {}", block_height);
+        Ok(UpgradedProof::Complete {
+            proof: complete,
+            block_height,
+        })
+    }
+}
+
+/// Magic bytes at the start of every detached `.ots` file
+pub const OTS_HEADER_MAGIC: &[u8] =
+    b"\x00OpenTimestamps\x00\x00Proof\x00\xbf\x89\xe2\xe8\x84\xe8\x92\x94";
+const OTS_VERSION: u8 = 0x01;
+const OP_SHA256: u8 = 0x08;
+const BITCOIN_ATTESTATION_TAG: [u8; 8] = [0x05, 0x88, 0x96, 0x0d, 0x73, 0xd7, 0x19, 0x01];
+const CALENDAR_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Split a detached `.ots` file into the file digest and its timestamp
+pub fn parse_detached_proof(proof: &[u8]) -> Result<(&[u8], &[u8])> {
+    let rest = proof
+        .strip_prefix(OTS_HEADER_MAGIC)
+        .ok_or_else(|| anyhow!("Invalid proof format: missing OpenTimestamps header"))?;
+    match rest {
+        [OTS_VERSION, OP_SHA256, tail @ ..] if tail.len() >= 32 => Ok(tail.split_at(32)),
+        [OTS_VERSION, ..] => Err(anyhow!("Invalid proof format: expected SHA256 file digest")),
+        _ => Err(anyhow!("Invalid proof format: unsupported version")),
+    }
+}
+
+/// Find the block height of the first Bitcoin attestation in a timestamp
+pub fn bitcoin_attestation_height(timestamp: &[u8]) -> Option<u32> {
+    bitcoin_attestation(timestamp).map(|(height, _)| height)
+}
+
+/// Find the first Bitcoin attestation in a timestamp
+///
+/// Returns the block height along with the operations leading up to the
+/// attestation, i.e. the merkle path from the digest to the block.
+pub fn bitcoin_attestation(timestamp: &[u8]) -> Option<(u32, &[u8])> {
+    let position = timestamp
+        .windows(BITCOIN_ATTESTATION_TAG.len())
+        .position(|w| w == BITCOIN_ATTESTATION_TAG)?;
+    let mut payload = &timestamp[position + BITCOIN_ATTESTATION_TAG.len()..];
+    // Attestation payload is length-prefixed and holds the height as a varuint
+    read_varuint(&mut payload)?;
+    let height = read_varuint(&mut payload).and_then(|h| u32::try_from(h).ok())?;
+    // The attestation marker byte precedes the tag
+    Some((height, &timestamp[..position.saturating_sub(1)]))
+}
+
+fn read_varuint(bytes: &mut &[u8]) -> Option<u64> {
+    let mut value = 0u64;
+    let mut shift = 0;
+    loop {
+        let (&byte, rest) = bytes.split_first()?;
+        *bytes = rest;
+        value |= u64::from(byte & 0x7f) << shift;
+        if byte & 0x80 == 0 {
+            return Some(value);
+        }
+        shift += 7;
+        if shift > 63 {
+            return None;
+        }
+    }
+}
+
+/// Outcome of upgrading a pending proof
+#[derive(Debug, Clone)]
+pub enum UpgradedProof {
+    /// Calendar hasn't included the digest in a Bitcoin block yet
+    Pending,
+    /// Proof now carries a Bitcoin attestation
+    Complete { proof: Vec<u8>, block_height: u32 },
 }
 
 /// Result of timestamp verification
@@ -118,4 +283,39 @@ mod tests {
         assert_eq!(client.aggregator_url, "https://alice.btc.calendar.opentimestamps.org");
         assert!(!client.calendars.is_empty());
     }
+
+    #[tokio::test]
+    async fn test_stamp_submits_digest_and_verifies_pending() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        // Pending attestation pointing back at the calendar
+        let pending: Vec<u8> = [0x00, 0x83, 0xdf, 0xe3, 0x0d, 0x2e, 0xf9, 0x0c, 0x8e, 0x01, 0x00].to_vec();
+        Mock::given(method("POST"))
+            .and(path("/digest"))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(pending.clone()))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = OtsClient::new(server.uri());
+        let proof = client.stamp(b"governance data").await.unwrap();
+        assert!(proof.starts_with(OTS_HEADER_MAGIC));
+        assert!(proof.ends_with(&pending));
+
+        let result = client.verify(b"governance data", &proof).await.unwrap();
+        assert!(!result.is_confirmed());
+        assert!(client.verify(b"other data", &proof).await.is_err());
+    }
+
+    #[test]
+    fn test_bitcoin_attestation_height() {
+        let mut timestamp = vec![0x00];
+        timestamp.extend_from_slice(&BITCOIN_ATTESTATION_TAG);
+        // length 3, height 800000 as varuint
+        timestamp.extend_from_slice(&[0x03, 0x80, 0xea, 0x30]);
+        assert_eq!(bitcoin_attestation_height(&timestamp), Some(800000));
+        assert_eq!(bitcoin_attestation_height(&[0x00, 0x01]), None);
+    }
 }
@@ -0,0 +1,163 @@
+//! Equivalence Proof Anchoring
+//!
+//! Timestamps verified equivalence proofs so there is a durable record
+//! that a test vector passed at a given point in time.
+
+use anyhow::{anyhow, Result};
+use sha2::{Digest, Sha256};
+use std::fs;
+use std::path::{Path, PathBuf};
+use tracing::info;
+
+use crate::ots::client::{OtsClient, VerificationResult as OtsVerificationResult};
+use crate::ots::verify::verify_registry;
+use crate::validation::equivalence_proof::{VerificationResult, VerificationStatus};
+
+/// Anchors equivalence proof verification results with OpenTimestamps
+pub struct EquivalenceAnchorer {
+    ots_client: OtsClient,
+    proofs_path: PathBuf,
+}
+
+impl EquivalenceAnchorer {
+    /// Create new anchorer storing results and proofs under `proofs_path`
+    pub fn new(ots_client: OtsClient, proofs_path: String) -> Self {
+        Self {
+            ots_client,
+            proofs_path: PathBuf::from(proofs_path),
+        }
+    }
+
+    /// Timestamp a verified result, returning its SHA256 hash
+    ///
+    /// The canonical result is written to `<test_id>.json` and the OTS proof
+    /// to `<test_id>.json.ots`.
+    pub async fn anchor_verification(&self, result: &VerificationResult) -> Result<String> {
+        if result.overall_status != VerificationStatus::Verified {
+            return Err(anyhow!(
+                "Refusing to anchor equivalence proof {}: status is {:?}",
+                result.test_id,
+                result.overall_status
+            ));
+        }
+
+        let data = canonical_result_bytes(result)?;
+        let proof = self.ots_client.stamp(&data).await?;
+
+        fs::create_dir_all(&self.proofs_path)
+            .map_err(|e| anyhow!("Failed to create proofs directory: {}", e))?;
+        let (result_file, proof_file) = self.paths_for(&result.test_id)?;
+        fs::write(&result_file, &data)
+            .map_err(|e| anyhow!("Failed to write verification result: {}", e))?;
+        fs::write(&proof_file, &proof)
+            .map_err(|e| anyhow!("Failed to write OTS proof: {}", e))?;
+
+        let hash = format!("sha256:{}", hex::encode(Sha256::digest(&data)));
+        info!(
+            "Anchored equivalence proof {} ({}) to {}",
+            result.test_id,
+            hash,
+            proof_file.display()
+        );
+        Ok(hash)
+    }
+
+    /// Re-validate the stored OTS proof for a test vector
+    pub async fn verify_proof_timestamp(&self, test_id: &str) -> Result<OtsVerificationResult> {
+        let (result_file, proof_file) = self.paths_for(test_id)?;
+        if !proof_file.exists() {
+            return Err(anyhow!("No OTS proof stored for equivalence proof {}", test_id));
+        }
+
+        verify_registry(
+            &result_file.to_string_lossy(),
+            &proof_file.to_string_lossy(),
+        )
+        .await
+    }
+
+    fn paths_for(&self, test_id: &str) -> Result<(PathBuf, PathBuf)> {
+        if test_id.is_empty() || Path::new(test_id).components().count() != 1 || test_id.contains("..") {
+            return Err(anyhow!("Invalid test id for proof storage: {}", test_id));
+        }
+        Ok((
+            self.proofs_path.join(format!("{}.json", test_id)),
+            self.proofs_path.join(format!("{}.json.ots", test_id)),
+        ))
+    }
+}
+
+/// Serialize a verification result with sorted keys so its hash is stable
+pub fn canonical_result_bytes(result: &VerificationResult) -> Result<Vec<u8>> {
+    // serde_json::Value keeps object keys in sorted order
+    let value = serde_json::to_value(result)
+        .map_err(|e| anyhow!("Failed to serialize verification result: {}", e))?;
+    serde_json::to_vec(&value).map_err(|e| anyhow!("Failed to serialize verification result: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::GovernanceError;
+    use crate::validation::equivalence_proof::{EquivalenceExecutor, EquivalenceProofValidator};
+    use std::collections::HashMap;
+    use tempfile::tempdir;
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    struct FixedExecutor(String);
+
+    impl EquivalenceExecutor for FixedExecutor {
+        fn run(&self, _impl_ref: &str, _test_data: &HashMap<String, String>) -> Result<String, GovernanceError> {
+            Ok(self.0.clone())
+        }
+    }
+
+    fn verified_result() -> VerificationResult {
+        let vectors = EquivalenceProofValidator::generate_consensus_test_vectors();
+        let expected = vectors[0].expected_result.clone();
+        let mut validator = EquivalenceProofValidator::new().with_executor(Box::new(FixedExecutor(expected)));
+        validator.load_test_vectors(vectors.clone());
+        validator.verify_equivalence_proof(&vectors[0].test_id).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_anchor_and_verify_equivalence_proof() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/digest"))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(vec![0x00, 0x01]))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let temp_dir = tempdir().unwrap();
+        let anchorer = EquivalenceAnchorer::new(
+            OtsClient::new(server.uri()),
+            temp_dir.path().to_string_lossy().to_string(),
+        );
+
+        let result = verified_result();
+        let hash = anchorer.anchor_verification(&result).await.unwrap();
+        assert!(hash.starts_with("sha256:"));
+        assert!(temp_dir.path().join("block_validation_001.json.ots").exists());
+
+        let status = anchorer.verify_proof_timestamp("block_validation_001").await.unwrap();
+        assert!(!status.is_confirmed());
+
+        assert!(anchorer.verify_proof_timestamp("block_validation_002").await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_failed_results_are_not_anchored() {
+        let temp_dir = tempdir().unwrap();
+        let anchorer = EquivalenceAnchorer::new(
+            OtsClient::new("http://127.0.0.1:9".to_string()),
+            temp_dir.path().to_string_lossy().to_string(),
+        );
+
+        let mut result = verified_result();
+        result.overall_status = VerificationStatus::Failed;
+        assert!(anchorer.anchor_verification(&result).await.is_err());
+    }
+}
@@ -0,0 +1,305 @@
+//! Startup Integrity Self-Test
+//!
+//! Compares the hashes of the configuration files currently on disk with the
+//! hashes recorded in the most recent OTS-anchored registry, so unauthorized
+//! configuration edits are surfaced as soon as the server boots.
+
+use anyhow::{anyhow, Result};
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+use tracing::{error, info, warn};
+
+use crate::database::Database;
+use crate::ots::anchor::GovernanceRegistry;
+
+/// Outcome of the startup integrity self-test
+#[derive(Debug, Clone, PartialEq)]
+pub enum IntegrityStatus {
+    /// All configuration hashes match the last anchored registry
+    Match { registry: String },
+    /// One or more configuration files differ from the last anchored registry
+    Mismatch {
+        registry: String,
+        mismatched: Vec<ConfigHashMismatch>,
+    },
+    /// The registry file no longer hashes to the value recorded when it was
+    /// anchored, so its config hashes can't be trusted
+    RegistryTampered {
+        registry: String,
+        anchored_hash: String,
+        current_hash: String,
+    },
+    /// No registry has been anchored yet, so there is nothing to compare against
+    NoAnchor,
+}
+
+/// A configuration file whose hash differs from the anchored value
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConfigHashMismatch {
+    pub path: String,
+    pub anchored_hash: Option<String>,
+    pub current_hash: Option<String>,
+}
+
+/// Calculate the `sha256:<hex>` hash of a configuration file
+pub fn hash_config_file(path: &Path) -> Result<String> {
+    let content = fs::read(path)
+        .map_err(|e| anyhow!("Failed to read config file {}: {}", path.display(), e))?;
+    Ok(format!("sha256:{}", hex::encode(Sha256::digest(&content))))
+}
+
+/// Compare current config file hashes with those recorded in a registry
+pub fn compare_config_hashes(
+    registry: &GovernanceRegistry,
+    config_paths: &[String],
+) -> Vec<ConfigHashMismatch> {
+    let current: HashMap<&String, Option<String>> = config_paths
+        .iter()
+        .map(|path| (path, hash_config_file(Path::new(path)).ok()))
+        .collect();
+
+    let mut mismatched = Vec::new();
+    for path in config_paths {
+        let anchored_hash = registry.config_hashes.get(path).cloned();
+        let current_hash = current.get(path).cloned().flatten();
+        if anchored_hash != current_hash {
+            mismatched.push(ConfigHashMismatch {
+                path: path.clone(),
+                anchored_hash,
+                current_hash,
+            });
+        }
+    }
+    mismatched
+}
+
+/// Run the integrity self-test against the latest anchored registry
+pub async fn check_config_integrity(
+    database: &Database,
+    config_paths: &[String],
+) -> Result<IntegrityStatus> {
+    let anchor = match database.get_latest_registry_anchor().await? {
+        Some(anchor) => anchor,
+        None => return Ok(IntegrityStatus::NoAnchor),
+    };
+
+    // The registry sits next to the config, so check it against the hash
+    // recorded at anchoring before trusting the config hashes inside it
+    let current_hash = hash_config_file(Path::new(&anchor.registry_path))?;
+    if current_hash != anchor.registry_hash {
+        return Ok(IntegrityStatus::RegistryTampered {
+            registry: anchor.month_key,
+            anchored_hash: anchor.registry_hash,
+            current_hash,
+        });
+    }
+
+    let content = fs::read_to_string(&anchor.registry_path)
+        .map_err(|e| anyhow!("Failed to read anchored registry {}: {}", anchor.registry_path, e))?;
+    let registry: GovernanceRegistry = serde_json::from_str(&content)
+        .map_err(|e| anyhow!("Failed to parse anchored registry: {}", e))?;
+
+    let mismatched = compare_config_hashes(&registry, config_paths);
+    if mismatched.is_empty() {
+        Ok(IntegrityStatus::Match {
+            registry: anchor.month_key,
+        })
+    } else {
+        Ok(IntegrityStatus::Mismatch {
+            registry: anchor.month_key,
+            mismatched,
+        })
+    }
+}
+
+/// Run the self-test and enforce its result
+///
+/// A self-test that can't run at all, e.g. because the anchored registry is
+/// unreadable, only refuses startup when `refuse_on_mismatch` is set.
+pub async fn run_startup_self_test(
+    database: &Database,
+    config_paths: &[String],
+    refuse_on_mismatch: bool,
+) -> Result<()> {
+    match check_config_integrity(database, config_paths).await {
+        Ok(status) => enforce_integrity(&status, refuse_on_mismatch),
+        Err(e) if refuse_on_mismatch => {
+            error!("Refusing to start: config integrity self-test failed: {}", e);
+            Err(anyhow!("Config integrity self-test failed: {}", e))
+        }
+        Err(e) => {
+            warn!("Config integrity self-test could not run: {}", e);
+            Ok(())
+        }
+    }
+}
+
+/// Log the self-test result, returning an error if startup should be refused
+pub fn enforce_integrity(status: &IntegrityStatus, refuse_on_mismatch: bool) -> Result<()> {
+    match status {
+        IntegrityStatus::Match { registry } => {
+            info!("Config integrity self-test passed against registry {}", registry);
+            Ok(())
+        }
+        IntegrityStatus::NoAnchor => {
+            info!("Config integrity self-test skipped: no anchored registry yet");
+            Ok(())
+        }
+        IntegrityStatus::Mismatch { registry, mismatched } => {
+            for m in mismatched {
+                warn!(
+                    "!!! CONFIG INTEGRITY MISMATCH !!! {} differs from anchored registry {} (anchored: {}, current: {})",
+                    m.path,
+                    registry,
+                    m.anchored_hash.as_deref().unwrap_or("<none>"),
+                    m.current_hash.as_deref().unwrap_or("<missing>")
+                );
+            }
+            if refuse_on_mismatch {
+                error!("Refusing to start: configuration does not match anchored registry {}", registry);
+                Err(anyhow!(
+                    "Configuration does not match anchored registry {} ({} files differ)",
+                    registry,
+                    mismatched.len()
+                ))
+            } else {
+                Ok(())
+            }
+        }
+        IntegrityStatus::RegistryTampered {
+            registry,
+            anchored_hash,
+            current_hash,
+        } => {
+            warn!(
+                "!!! CONFIG INTEGRITY MISMATCH !!! anchored registry {} was modified (anchored: {}, current: {})",
+                registry, anchored_hash, current_hash
+            );
+            if refuse_on_mismatch {
+                error!("Refusing to start: anchored registry {} was modified", registry);
+                Err(anyhow!("Anchored registry {} was modified since anchoring", registry))
+            } else {
+                Ok(())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ots::anchor::MultisigConfig;
+    use chrono::Utc;
+    use tempfile::tempdir;
+
+    async fn setup(anchored_content: &str, current_content: &str) -> (Database, String, tempfile::TempDir) {
+        let temp_dir = tempdir().unwrap();
+        let config_path = temp_dir.path().join("config.toml");
+        fs::write(&config_path, anchored_content).unwrap();
+        let config_path = config_path.to_string_lossy().to_string();
+
+        let mut config_hashes = HashMap::new();
+        config_hashes.insert(
+            config_path.clone(),
+            hash_config_file(Path::new(&config_path)).unwrap(),
+        );
+        let registry = GovernanceRegistry {
+            version: "2025-01".to_string(),
+            timestamp: Utc::now(),
+            previous_registry_hash: "sha256:0000000000000000000000000000000000000000000000000000000000000000".to_string(),
+            maintainers: vec![],
+            authorized_servers: vec![],
+            audit_logs: HashMap::new(),
+            config_hashes,
+            multisig_config: MultisigConfig {
+                required_signatures: 3,
+                total_maintainers: 5,
+            },
+        };
+        let registry_path = temp_dir.path().join("2025-01.json");
+        fs::write(&registry_path, serde_json::to_string(&registry).unwrap()).unwrap();
+
+        let database = Database::new_in_memory().await.unwrap();
+        database
+            .record_registry_anchor(
+                "2025-01",
+                &hash_config_file(&registry_path).unwrap(),
+                &registry_path.to_string_lossy(),
+                "2025-01.json.ots",
+                Utc::now(),
+            )
+            .await
+            .unwrap();
+
+        fs::write(&config_path, current_content).unwrap();
+        (database, config_path, temp_dir)
+    }
+
+    #[tokio::test]
+    async fn test_matching_config_passes() {
+        let (database, config_path, _dir) = setup("port = 8080", "port = 8080").await;
+        let status = check_config_integrity(&database, &[config_path]).await.unwrap();
+
+        assert!(matches!(status, IntegrityStatus::Match { .. }));
+        assert!(enforce_integrity(&status, true).is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_mismatched_config_warns_or_refuses() {
+        let (database, config_path, _dir) = setup("port = 8080", "port = 9090").await;
+        let status = check_config_integrity(&database, &[config_path.clone()]).await.unwrap();
+
+        match &status {
+            IntegrityStatus::Mismatch { mismatched, .. } => {
+                assert_eq!(mismatched.len(), 1);
+                assert_eq!(mismatched[0].path, config_path);
+            }
+            other => panic!("expected mismatch, got {:?}", other),
+        }
+        assert!(enforce_integrity(&status, false).is_ok());
+        assert!(enforce_integrity(&status, true).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_rewritten_registry_fails_integrity() {
+        let (database, config_path, dir) = setup("port = 8080", "port = 9090").await;
+
+        // Whoever edited the config also rewrites the registry's hash for it
+        let registry_path = dir.path().join("2025-01.json");
+        let mut registry: GovernanceRegistry =
+            serde_json::from_str(&fs::read_to_string(&registry_path).unwrap()).unwrap();
+        registry.config_hashes.insert(
+            config_path.clone(),
+            hash_config_file(Path::new(&config_path)).unwrap(),
+        );
+        fs::write(&registry_path, serde_json::to_string(&registry).unwrap()).unwrap();
+
+        let status = check_config_integrity(&database, &[config_path]).await.unwrap();
+        assert!(matches!(status, IntegrityStatus::RegistryTampered { .. }), "{:?}", status);
+        assert!(enforce_integrity(&status, false).is_ok());
+        assert!(enforce_integrity(&status, true).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_unreadable_registry_only_refuses_when_configured() {
+        let (database, config_path, dir) = setup("port = 8080", "port = 8080").await;
+        fs::remove_file(dir.path().join("2025-01.json")).unwrap();
+
+        assert!(check_config_integrity(&database, &[config_path.clone()]).await.is_err());
+        assert!(run_startup_self_test(&database, &[config_path.clone()], false).await.is_ok());
+        assert!(run_startup_self_test(&database, &[config_path], true).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_no_anchor_skips_check() {
+        let database = Database::new_in_memory().await.unwrap();
+        let status = check_config_integrity(&database, &["config.toml".to_string()])
+            .await
+            .unwrap();
+
+        assert_eq!(status, IntegrityStatus::NoAnchor);
+        assert!(enforce_integrity(&status, true).is_ok());
+    }
+}
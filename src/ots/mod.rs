@@ -5,8 +5,15 @@
 
 pub mod client;
 pub mod anchor;
+pub mod audit_anchor;
+pub mod equivalence;
+pub mod integrity;
+pub mod upgrade;
 pub mod verify;
 
 pub use client::OtsClient;
 pub use anchor::RegistryAnchorer;
-pub use verify::verify_registry;
+pub use audit_anchor::{verify_audit_anchor, AuditAnchorer};
+pub use equivalence::EquivalenceAnchorer;
+pub use upgrade::ProofUpgrader;
+pub use verify::{verify_registry, verify_registry_with_quorum, QuorumVerification};
@@ -0,0 +1,201 @@
+//! Pending Proof Upgrading
+//!
+//! Freshly stamped proofs only carry a pending calendar attestation. This
+//! periodically asks the calendar for the Bitcoin attestation and rewrites
+//! each `.ots` file under the proofs directory once it's available.
+
+use anyhow::{anyhow, Result};
+use std::fs;
+use std::path::{Path, PathBuf};
+use tracing::{debug, info, warn};
+
+use crate::ots::client::{
+    bitcoin_attestation_height, parse_detached_proof, OtsClient, UpgradedProof,
+};
+
+/// What one pass over the proofs directory did
+#[derive(Debug, Clone, Default)]
+pub struct UpgradeSummary {
+    /// Proofs rewritten with a Bitcoin attestation
+    pub upgraded: Vec<PathBuf>,
+    /// Proofs the calendar hasn't confirmed yet
+    pub still_pending: Vec<PathBuf>,
+    /// Proofs that couldn't be upgraded, with the reason
+    pub failed: Vec<(PathBuf, String)>,
+}
+
+/// Upgrades pending OTS proofs stored under a directory
+pub struct ProofUpgrader {
+    ots_client: OtsClient,
+    proofs_path: PathBuf,
+}
+
+impl ProofUpgrader {
+    /// Create new upgrader for the `.ots` files under `proofs_path`
+    pub fn new(ots_client: OtsClient, proofs_path: String) -> Self {
+        Self {
+            ots_client,
+            proofs_path: PathBuf::from(proofs_path),
+        }
+    }
+
+    /// Try to upgrade every pending proof once
+    ///
+    /// Already confirmed proofs are skipped without contacting the calendar.
+    pub async fn upgrade_pending(&self) -> Result<UpgradeSummary> {
+        let mut summary = UpgradeSummary::default();
+        if !self.proofs_path.exists() {
+            return Ok(summary);
+        }
+
+        for path in find_proofs(&self.proofs_path)? {
+            let proof = match fs::read(&path) {
+                Ok(proof) => proof,
+                Err(e) => {
+                    summary.failed.push((path, format!("Failed to read proof: {}", e)));
+                    continue;
+                }
+            };
+            match parse_detached_proof(&proof) {
+                Ok((_, timestamp)) if bitcoin_attestation_height(timestamp).is_some() => continue,
+                Ok(_) => {}
+                Err(e) => {
+                    summary.failed.push((path, e.to_string()));
+                    continue;
+                }
+            }
+
+            match self.ots_client.upgrade_proof(&proof).await {
+                Ok(UpgradedProof::Complete { proof, block_height }) => match write_in_place(&path, &proof) {
+                    Ok(()) => {
+                        info!("Upgraded {} (block {})", path.display(), block_height);
+                        summary.upgraded.push(path);
+                    }
+                    Err(e) => summary.failed.push((path, e.to_string())),
+                },
+                Ok(UpgradedProof::Pending) => {
+                    debug!("{} is still pending", path.display());
+                    summary.still_pending.push(path);
+                }
+                Err(e) => {
+                    warn!("Failed to upgrade {}: {}", path.display(), e);
+                    summary.failed.push((path, e.to_string()));
+                }
+            }
+        }
+
+        Ok(summary)
+    }
+}
+
+fn find_proofs(dir: &Path) -> Result<Vec<PathBuf>> {
+    let mut proofs = Vec::new();
+    let entries = fs::read_dir(dir)
+        .map_err(|e| anyhow!("Failed to read proofs directory {}: {}", dir.display(), e))?;
+    for entry in entries {
+        let path = entry
+            .map_err(|e| anyhow!("Failed to read proofs directory {}: {}", dir.display(), e))?
+            .path();
+        if path.is_dir() {
+            proofs.extend(find_proofs(&path)?);
+        } else if path.extension().is_some_and(|ext| ext == "ots") {
+            proofs.push(path);
+        }
+    }
+    proofs.sort();
+    Ok(proofs)
+}
+
+/// Replace a proof atomically so a crash never leaves a truncated file
+fn write_in_place(path: &Path, proof: &[u8]) -> Result<()> {
+    let tmp = path.with_extension("ots.tmp");
+    fs::write(&tmp, proof).map_err(|e| anyhow!("Failed to write upgraded proof: {}", e))?;
+    fs::rename(&tmp, path).map_err(|e| anyhow!("Failed to replace proof: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+    use wiremock::matchers::{method, path, path_regex};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    #[tokio::test]
+    async fn test_pending_proof_upgraded_once_calendar_confirms() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/digest"))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(vec![0x00, 0x83, 0xdf, 0xe3, 0x0d]))
+            .mount(&server)
+            .await;
+        // Not in a block on the first poll, confirmed on the next
+        Mock::given(method("GET"))
+            .and(path_regex("^/timestamp/[0-9a-f]{64}$"))
+            .respond_with(ResponseTemplate::new(404))
+            .up_to_n_times(1)
+            .with_priority(1)
+            .mount(&server)
+            .await;
+        let mut attested = vec![0xf0, 0x01, 0xaa, 0x08, 0x00, 0x05, 0x88, 0x96, 0x0d, 0x73, 0xd7, 0x19, 0x01];
+        attested.extend_from_slice(&[0x03, 0x80, 0xea, 0x30]);
+        Mock::given(method("GET"))
+            .and(path_regex("^/timestamp/[0-9a-f]{64}$"))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(attested))
+            .with_priority(2)
+            .mount(&server)
+            .await;
+
+        let temp_dir = tempdir().unwrap();
+        let proofs_dir = temp_dir.path().join("registries");
+        fs::create_dir_all(&proofs_dir).unwrap();
+        let proof_file = proofs_dir.join("2025-01.json.ots");
+        let client = OtsClient::new(server.uri());
+        fs::write(&proof_file, client.stamp(b"registry").await.unwrap()).unwrap();
+
+        let upgrader = ProofUpgrader::new(
+            OtsClient::new(server.uri()),
+            temp_dir.path().to_string_lossy().to_string(),
+        );
+
+        let summary = upgrader.upgrade_pending().await.unwrap();
+        assert_eq!(summary.still_pending, vec![proof_file.clone()]);
+        assert!(summary.upgraded.is_empty() && summary.failed.is_empty());
+        assert!(!client.verify(b"registry", &fs::read(&proof_file).unwrap()).await.unwrap().is_confirmed());
+
+        let summary = upgrader.upgrade_pending().await.unwrap();
+        assert_eq!(summary.upgraded, vec![proof_file.clone()]);
+        let result = client.verify(b"registry", &fs::read(&proof_file).unwrap()).await.unwrap();
+        assert_eq!(result.block_height(), Some(800000));
+
+        // Confirmed proofs are left alone
+        let summary = upgrader.upgrade_pending().await.unwrap();
+        assert!(summary.upgraded.is_empty() && summary.still_pending.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_unreachable_calendar_reported_as_failure() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/digest"))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(vec![0x00]))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(503))
+            .mount(&server)
+            .await;
+
+        let temp_dir = tempdir().unwrap();
+        let proof_file = temp_dir.path().join("audit.ots");
+        fs::write(&proof_file, OtsClient::new(server.uri()).stamp(b"log").await.unwrap()).unwrap();
+
+        let upgrader = ProofUpgrader::new(
+            OtsClient::new(server.uri()),
+            temp_dir.path().to_string_lossy().to_string(),
+        );
+        let summary = upgrader.upgrade_pending().await.unwrap();
+        assert!(summary.still_pending.is_empty());
+        assert_eq!(summary.failed.len(), 1);
+        assert_eq!(summary.failed[0].0, proof_file);
+    }
+}
@@ -3,12 +3,18 @@
 //! Provides public utilities for verifying OTS proofs and registries.
 
 use anyhow::{anyhow, Result};
-use sha2::Digest;
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::fmt;
 use std::fs;
-use tracing::{debug, info};
+use tokio::task::JoinSet;
+use tracing::{debug, info, warn};
 
 use crate::ots::anchor::GovernanceRegistry;
-use crate::ots::client::{OtsClient, VerificationResult};
+use crate::ots::client::{
+    bitcoin_attestation, bitcoin_attestation_height, parse_detached_proof, OtsClient,
+    VerificationResult,
+};
 
 /// Verify a governance registry against its OTS proof
 pub async fn verify_registry(
@@ -43,6 +49,171 @@ pub async fn verify_registry(
     Ok(result)
 }
 
+/// What a single calendar reported for a proof
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CalendarResult {
+    /// Calendar has a Bitcoin attestation; `merkle_path` is the hex encoded
+    /// operations from the digest to the block
+    Attested { block_height: u32, merkle_path: String },
+    /// Calendar knows the commitment but it isn't in a block yet
+    Pending,
+    /// Calendar couldn't be queried or returned an unusable response
+    Unavailable(String),
+}
+
+impl fmt::Display for CalendarResult {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CalendarResult::Attested { block_height, .. } => write!(f, "attested at block {}", block_height),
+            CalendarResult::Pending => write!(f, "pending"),
+            CalendarResult::Unavailable(reason) => write!(f, "unavailable ({})", reason),
+        }
+    }
+}
+
+/// Per-calendar outcome of a quorum verification
+#[derive(Debug, Clone)]
+pub struct CalendarVerification {
+    pub calendar: String,
+    pub result: CalendarResult,
+}
+
+/// Attestation agreed on by a quorum of calendars
+#[derive(Debug, Clone)]
+pub struct QuorumVerification {
+    pub block_height: u32,
+    /// Number of calendars reporting this exact attestation
+    pub agreeing: usize,
+    /// Every calendar's result, including those that disagreed
+    pub results: Vec<CalendarVerification>,
+}
+
+/// Verify a proof by asking several calendars for its Bitcoin attestation
+///
+/// The proof must commit to `data`. It's accepted only when at least
+/// `quorum` calendars report the same block height and merkle path; any
+/// other outcome is an inconclusive error listing each calendar's result.
+pub async fn verify_with_quorum(
+    data: &[u8],
+    proof: &[u8],
+    calendars: &[String],
+    quorum: usize,
+) -> Result<QuorumVerification> {
+    if quorum == 0 || quorum > calendars.len() {
+        return Err(anyhow!(
+            "Quorum of {} is not satisfiable with {} calendars",
+            quorum,
+            calendars.len()
+        ));
+    }
+
+    let (digest, _) = parse_detached_proof(proof)?;
+    if digest != Sha256::digest(data).as_slice() {
+        return Err(anyhow!("Proof does not commit to the supplied data"));
+    }
+
+    let mut queries = JoinSet::new();
+    for (index, calendar) in calendars.iter().enumerate() {
+        let client = OtsClient::new(calendar.clone());
+        let digest = digest.to_vec();
+        queries.spawn(async move { (index, query_calendar(&client, &digest).await) });
+    }
+
+    let mut results: Vec<CalendarVerification> = calendars
+        .iter()
+        .map(|calendar| CalendarVerification {
+            calendar: calendar.clone(),
+            result: CalendarResult::Unavailable("no response".to_string()),
+        })
+        .collect();
+    while let Some(joined) = queries.join_next().await {
+        if let Ok((index, result)) = joined {
+            debug!("Calendar {} reported {}", calendars[index], result);
+            results[index].result = result;
+        }
+    }
+
+    let mut tally: HashMap<(u32, &str), usize> = HashMap::new();
+    for verification in &results {
+        if let CalendarResult::Attested { block_height, merkle_path } = &verification.result {
+            *tally.entry((*block_height, merkle_path.as_str())).or_insert(0) += 1;
+        }
+    }
+    let best = tally
+        .into_iter()
+        .max_by_key(|(_, count)| *count)
+        .map(|((height, _), count)| (height, count));
+
+    match best {
+        Some((block_height, agreeing)) if agreeing >= quorum => {
+            for verification in &results {
+                if !matches!(&verification.result, CalendarResult::Attested { block_height: h, .. } if *h == block_height) {
+                    warn!("Calendar {} disagrees with quorum: {}", verification.calendar, verification.result);
+                }
+            }
+            info!(
+                "{} of {} calendars agree on attestation at block {}",
+                agreeing,
+                calendars.len(),
+                block_height
+            );
+            Ok(QuorumVerification {
+                block_height,
+                agreeing,
+                results,
+            })
+        }
+        best => Err(anyhow!(
+            "Inconclusive: {} of {} calendars agree, {} required ({})",
+            best.map(|(_, count)| count).unwrap_or(0),
+            calendars.len(),
+            quorum,
+            results
+                .iter()
+                .map(|v| format!("{}: {}", v.calendar, v.result))
+                .collect::<Vec<_>>()
+                .join("; ")
+        )),
+    }
+}
+
+async fn query_calendar(client: &OtsClient, digest: &[u8]) -> CalendarResult {
+    match client.fetch_timestamp(digest).await {
+        Ok(Some(timestamp)) => match bitcoin_attestation(&timestamp) {
+            Some((block_height, path)) => CalendarResult::Attested {
+                block_height,
+                merkle_path: hex::encode(path),
+            },
+            None => CalendarResult::Pending,
+        },
+        Ok(None) => CalendarResult::Unavailable(format!("{} has no timestamp for digest", client.aggregator_url())),
+        Err(e) => CalendarResult::Unavailable(e.to_string()),
+    }
+}
+
+/// Verify a governance registry by calendar quorum
+pub async fn verify_registry_with_quorum(
+    registry_path: &str,
+    proof_path: &str,
+    calendars: &[String],
+    quorum: usize,
+) -> Result<QuorumVerification> {
+    info!(
+        "Verifying registry: {} with proof: {} against {} calendars (quorum {})",
+        registry_path,
+        proof_path,
+        calendars.len(),
+        quorum
+    );
+
+    let registry_data = fs::read(registry_path)
+        .map_err(|e| anyhow!("Failed to read registry file: {}", e))?;
+    let proof_data = fs::read(proof_path)
+        .map_err(|e| anyhow!("Failed to read proof file: {}", e))?;
+
+    verify_with_quorum(&registry_data, &proof_data, calendars, quorum).await
+}
+
 /// Verify registry JSON structure
 pub fn verify_registry_structure(registry_path: &str) -> Result<GovernanceRegistry> {
     debug!("Verifying registry structure: {}", registry_path);
@@ -77,13 +248,9 @@ pub fn verify_proof_format(proof_path: &str) -> Result<()> {
     let proof_data = fs::read(proof_path)
         .map_err(|e| anyhow!("Failed to read proof file: {}", e))?;
 
-    // For now, just check if it's a mock proof
-    if proof_data.starts_with(b"MOCK_OTS_PROOF:") {
-        info!("OTS proof format is valid (mock)");
-        Ok(())
-    } else {
-        Err(anyhow!("Invalid OTS proof format"))
-    }
+    parse_detached_proof(&proof_data)?;
+    info!("OTS proof format is valid");
+    Ok(())
 }
 
 /// Get Bitcoin block height from confirmed proof
@@ -93,12 +260,8 @@ pub async fn get_bitcoin_block_height(proof_path: &str) -> Result<Option<u32>> {
     let proof_data = fs::read(proof_path)
         .map_err(|e| anyhow!("Failed to read proof file: {}", e))?;
 
-    // For mock proofs, return a mock block height
-    if proof_data.starts_with(b"MOCK_OTS_PROOF:") {
-        Ok(Some(12345)) // Mock block height
-    } else {
-        Ok(None)
-    }
+    let (_, timestamp) = parse_detached_proof(&proof_data)?;
+    Ok(bitcoin_attestation_height(timestamp))
 }
 
 /// Verify complete registry chain
@@ -190,6 +353,7 @@ mod tests {
             }],
             authorized_servers: vec![],
             audit_logs: std::collections::HashMap::new(),
+            config_hashes: std::collections::HashMap::new(),
             multisig_config: crate::ots::anchor::MultisigConfig {
                 required_signatures: 3,
                 total_maintainers: 5,
@@ -215,4 +379,104 @@ mod tests {
         let result = verify_proof_format(proof_file.to_str().unwrap());
         assert!(result.is_err());
     }
+
+    fn detached_proof(data: &[u8]) -> Vec<u8> {
+        let mut proof = crate::ots::client::OTS_HEADER_MAGIC.to_vec();
+        proof.extend_from_slice(&[0x01, 0x08]);
+        proof.extend_from_slice(&Sha256::digest(data));
+        proof
+    }
+
+    /// Calendar timestamp: append op and sha256, then a Bitcoin attestation
+    fn attested_timestamp(height_varuint: &[u8], nonce: u8) -> Vec<u8> {
+        let mut timestamp = vec![0xf0, 0x01, nonce, 0x08, 0x00];
+        timestamp.extend_from_slice(&[0x05, 0x88, 0x96, 0x0d, 0x73, 0xd7, 0x19, 0x01]);
+        timestamp.push(height_varuint.len() as u8);
+        timestamp.extend_from_slice(height_varuint);
+        timestamp
+    }
+
+    async fn calendar(response: wiremock::ResponseTemplate) -> wiremock::MockServer {
+        use wiremock::matchers::{method, path_regex};
+        use wiremock::{Mock, MockServer};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path_regex("^/timestamp/[0-9a-f]{64}$"))
+            .respond_with(response)
+            .mount(&server)
+            .await;
+        server
+    }
+
+    // 800000 as a varuint
+    const HEIGHT_800000: &[u8] = &[0x80, 0xea, 0x30];
+
+    #[tokio::test]
+    async fn test_quorum_all_calendars_agree() {
+        use wiremock::ResponseTemplate;
+
+        let body = attested_timestamp(HEIGHT_800000, 0xaa);
+        let servers = vec![
+            calendar(ResponseTemplate::new(200).set_body_bytes(body.clone())).await,
+            calendar(ResponseTemplate::new(200).set_body_bytes(body.clone())).await,
+            calendar(ResponseTemplate::new(200).set_body_bytes(body)).await,
+        ];
+        let calendars: Vec<String> = servers.iter().map(|s| s.uri()).collect();
+
+        let data = b"registry";
+        let result = verify_with_quorum(data, &detached_proof(data), &calendars, 2).await.unwrap();
+        assert_eq!(result.block_height, 800000);
+        assert_eq!(result.agreeing, 3);
+        assert_eq!(result.results.len(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_quorum_tolerates_single_disagreeing_calendar() {
+        use wiremock::ResponseTemplate;
+
+        let agreed = attested_timestamp(HEIGHT_800000, 0xaa);
+        // Same height but a different merkle path counts as disagreement
+        let forked = attested_timestamp(HEIGHT_800000, 0xbb);
+        let servers = vec![
+            calendar(ResponseTemplate::new(200).set_body_bytes(agreed.clone())).await,
+            calendar(ResponseTemplate::new(200).set_body_bytes(agreed)).await,
+            calendar(ResponseTemplate::new(200).set_body_bytes(forked)).await,
+        ];
+        let calendars: Vec<String> = servers.iter().map(|s| s.uri()).collect();
+
+        let data = b"registry";
+        let result = verify_with_quorum(data, &detached_proof(data), &calendars, 2).await.unwrap();
+        assert_eq!(result.block_height, 800000);
+        assert_eq!(result.agreeing, 2);
+        let paths: std::collections::HashSet<_> = result
+            .results
+            .iter()
+            .filter_map(|v| match &v.result {
+                CalendarResult::Attested { merkle_path, .. } => Some(merkle_path.clone()),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(paths.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_quorum_inconclusive_when_majority_unavailable() {
+        use wiremock::ResponseTemplate;
+
+        let servers = vec![
+            calendar(ResponseTemplate::new(200).set_body_bytes(attested_timestamp(HEIGHT_800000, 0xaa))).await,
+            calendar(ResponseTemplate::new(503)).await,
+            calendar(ResponseTemplate::new(503)).await,
+        ];
+        let calendars: Vec<String> = servers.iter().map(|s| s.uri()).collect();
+
+        let data = b"registry";
+        let err = verify_with_quorum(data, &detached_proof(data), &calendars, 2)
+            .await
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("Inconclusive"));
+        assert!(err.contains(&calendars[1]));
+    }
 }
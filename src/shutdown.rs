@@ -0,0 +1,182 @@
+//! Graceful Shutdown
+//!
+//! Stops accepting connections on SIGTERM/SIGINT and gives in-flight
+//! handlers a bounded window to finish, so webhook processing isn't cut off
+//! halfway through a DB write or audit append.
+
+use axum::Router;
+use std::future::{Future, IntoFuture};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::net::TcpListener;
+use tokio::sync::Notify;
+use tracing::{info, warn};
+
+/// How the server came down
+#[derive(Debug, Clone)]
+pub struct ShutdownSummary {
+    /// Whether every in-flight request finished before the timeout
+    pub drained: bool,
+    /// Time spent draining after the signal
+    pub drain_time: Duration,
+}
+
+/// Resolve on the first SIGTERM or SIGINT
+pub async fn shutdown_signal() {
+    let ctrl_c = async {
+        if let Err(e) = tokio::signal::ctrl_c().await {
+            warn!("Failed to listen for SIGINT: {}", e);
+            std::future::pending::<()>().await;
+        }
+    };
+
+    #[cfg(unix)]
+    let terminate = async {
+        match tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate()) {
+            Ok(mut signal) => {
+                signal.recv().await;
+            }
+            Err(e) => {
+                warn!("Failed to listen for SIGTERM: {}", e);
+                std::future::pending::<()>().await;
+            }
+        }
+    };
+
+    #[cfg(not(unix))]
+    let terminate = std::future::pending::<()>();
+
+    tokio::select! {
+        _ = ctrl_c => info!("Received SIGINT, shutting down"),
+        _ = terminate => info!("Received SIGTERM, shutting down"),
+    }
+}
+
+/// Serve `app` until `signal` resolves, then drain for at most `drain_timeout`
+///
+/// New connections are refused as soon as the signal fires. Requests still
+/// running when the timeout elapses are abandoned and reported via
+/// `ShutdownSummary::drained`.
+pub async fn serve_with_graceful_shutdown<F>(
+    listener: TcpListener,
+    app: Router,
+    signal: F,
+    drain_timeout: Duration,
+) -> std::io::Result<ShutdownSummary>
+where
+    F: Future<Output = ()> + Send + 'static,
+{
+    let signalled = Arc::new(Notify::new());
+    let notify = signalled.clone();
+    let server = axum::serve(listener, app)
+        .with_graceful_shutdown(async move {
+            signal.await;
+            notify.notify_one();
+        })
+        .into_future();
+    tokio::pin!(server);
+
+    tokio::select! {
+        result = &mut server => {
+            result?;
+            return Ok(ShutdownSummary { drained: true, drain_time: Duration::ZERO });
+        }
+        _ = signalled.notified() => {}
+    }
+
+    info!(
+        "Stopped accepting connections, draining in-flight requests (timeout {}s)",
+        drain_timeout.as_secs()
+    );
+    let started = Instant::now();
+    match tokio::time::timeout(drain_timeout, server).await {
+        Ok(result) => {
+            result?;
+            Ok(ShutdownSummary {
+                drained: true,
+                drain_time: started.elapsed(),
+            })
+        }
+        Err(_) => {
+            warn!(
+                "In-flight requests still running after {}s, abandoning them",
+                drain_timeout.as_secs()
+            );
+            Ok(ShutdownSummary {
+                drained: false,
+                drain_time: started.elapsed(),
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::routing::get;
+    use tokio::sync::oneshot;
+
+    async fn slow_handler() -> &'static str {
+        tokio::time::sleep(Duration::from_millis(300)).await;
+        "done"
+    }
+
+    #[tokio::test]
+    async fn test_in_flight_request_completes_after_signal() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let app = Router::new().route("/slow", get(slow_handler));
+
+        let (tx, rx) = oneshot::channel::<()>();
+        let server = tokio::spawn(serve_with_graceful_shutdown(
+            listener,
+            app,
+            async move {
+                let _ = rx.await;
+            },
+            Duration::from_secs(5),
+        ));
+
+        let request = tokio::spawn(async move {
+            reqwest::get(format!("http://{}/slow", addr))
+                .await?
+                .text()
+                .await
+        });
+
+        // Signal while the handler is still sleeping
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        tx.send(()).unwrap();
+
+        assert_eq!(request.await.unwrap().unwrap(), "done");
+        let summary = server.await.unwrap().unwrap();
+        assert!(summary.drained);
+
+        // The listener is closed once draining finishes
+        assert!(reqwest::get(format!("http://{}/slow", addr)).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_drain_timeout_abandons_stuck_requests() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let app = Router::new().route("/slow", get(slow_handler));
+
+        let (tx, rx) = oneshot::channel::<()>();
+        let server = tokio::spawn(serve_with_graceful_shutdown(
+            listener,
+            app,
+            async move {
+                let _ = rx.await;
+            },
+            Duration::from_millis(50),
+        ));
+
+        let _request = tokio::spawn(reqwest::get(format!("http://{}/slow", addr)));
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        tx.send(()).unwrap();
+
+        let summary = server.await.unwrap().unwrap();
+        assert!(!summary.drained);
+    }
+}
@@ -0,0 +1,355 @@
+//! Health and Status Endpoints
+//!
+//! `/health` is a public liveness probe and reveals nothing about the
+//! deployment. `/status` reports server identity, feature flags, Nostr and
+//! OTS liveness and database health, so it requires the configured status
+//! token. Per-PR governance
+//! status and the listing of tracked PRs are public, like the PRs themselves.
+
+use axum::{
+    extract::{Path, Query, State},
+    http::HeaderMap,
+    http::StatusCode,
+    response::Json,
+};
+use serde::Deserialize;
+use tracing::{error, warn};
+
+use crate::auth::require_bearer;
+use crate::config::{AppConfig, Secret};
+use crate::database::models::PullRequestStatus;
+use crate::database::Database;
+use crate::enforcement::pr_record::PrGovernanceRecord;
+use crate::enforcement::pr_status::{self, PrStatus};
+use crate::error::GovernanceError;
+use crate::metrics::Metrics;
+
+/// Handle `GET /health`
+pub async fn health_check() -> Json<serde_json::Value> {
+    Json(serde_json::json!({
+        "status": "healthy",
+        "service": "governance-app",
+        "timestamp": chrono::Utc::now()
+    }))
+}
+
+/// Handle `GET /status`
+///
+/// Disabled unless `status_token` is configured; callers must present it as
+/// a bearer token.
+pub async fn status_endpoint(
+    State((config, database, metrics)): State<(AppConfig, Database, Metrics)>,
+    headers: HeaderMap,
+) -> (StatusCode, Json<serde_json::Value>) {
+    if let Err(e) = require_bearer(&headers, config.status_token.as_ref().map(Secret::expose)) {
+        warn!("Rejected status request: {}", e);
+        return e.to_response();
+    }
+
+    let mut status = serde_json::json!({
+        "status": "healthy",
+        "service": "governance-app",
+        "timestamp": chrono::Utc::now(),
+        "server_id": config.server_id,
+        "features": {
+            "nostr": config.nostr.enabled,
+            "ots": config.ots.enabled,
+            "audit": config.audit.enabled,
+            "dry_run": config.dry_run_mode
+        }
+    });
+
+    // Liveness as last seen by the background tasks, not just what is configured
+    let health = metrics.subsystem_health();
+    let nostr = if config.nostr.enabled {
+        let (connected, total) = metrics.nostr_relays();
+        let degraded = health.nostr_degraded() || (total > 0 && connected == 0);
+        serde_json::json!({
+            "status": if degraded { "degraded" } else { "healthy" },
+            "connected_relays": connected,
+            "configured_relays": total,
+            "last_publish": health.nostr_last_publish,
+            "last_publish_failure": health.nostr_last_publish_failure
+        })
+    } else {
+        serde_json::json!({"status": "disabled"})
+    };
+    let ots = if config.ots.enabled {
+        serde_json::json!({
+            "status": if health.ots_degraded() { "degraded" } else { "healthy" },
+            "last_anchor": health.ots_last_anchor,
+            "last_anchor_failure": health.ots_last_anchor_failure,
+            "pending_proofs": health.ots_pending_proofs
+        })
+    } else {
+        serde_json::json!({"status": "disabled"})
+    };
+    if nostr["status"] == "degraded" || ots["status"] == "degraded" {
+        status["status"] = serde_json::json!("degraded");
+    }
+    status["subsystems"] = serde_json::json!({"nostr": nostr, "ots": ots});
+
+    // Add database status
+    if let Ok(stats) = database
+        .get_performance_stats_with_threshold(config.slow_query_threshold_ms)
+        .await
+    {
+        status["database"] = serde_json::json!({
+            "status": if stats.degraded { "degraded" } else { "healthy" },
+            "cache_size": stats.cache_size,
+            "slow_queries": stats.slow_queries_count,
+            "slow_query_threshold_ms": config.slow_query_threshold_ms,
+            "degraded_reason": stats.degraded_reason
+        });
+    } else {
+        status["database"] = serde_json::json!({
+            "status": "error"
+        });
+    }
+
+    (StatusCode::OK, Json(status))
+}
+
+/// Handle `GET /prs/:repo/:number/status`; `repo` is the URL-encoded full repository name
+pub async fn pr_status_endpoint(
+    State((config, database, _metrics)): State<(AppConfig, Database, Metrics)>,
+    Path((repo, number)): Path<(String, i32)>,
+) -> (StatusCode, Json<serde_json::Value>) {
+    match PrStatus::load(
+        &database,
+        &repo,
+        number,
+        chrono::Utc::now(),
+        &config.review_period_policy,
+        &config.economic_quorums,
+    )
+    .await
+    {
+        Ok(Some(status)) => (
+            StatusCode::OK,
+            Json(serde_json::to_value(status).unwrap_or_default()),
+        ),
+        Ok(None) => {
+            GovernanceError::not_found("Pull request", format!("{}#{}", repo, number)).to_response()
+        }
+        Err(e) => {
+            error!("Failed to load governance status for {}#{}: {}", repo, number, e);
+            e.to_response()
+        }
+    }
+}
+
+/// Handle `GET /prs/:repo/:number/record`; `repo` is the URL-encoded full repository name
+///
+/// Exports the PR's full governance record, signed when a record signing
+/// key is configured.
+pub async fn pr_record_endpoint(
+    State((config, database, _metrics)): State<(AppConfig, Database, Metrics)>,
+    Path((repo, number)): Path<(String, i32)>,
+) -> (StatusCode, Json<serde_json::Value>) {
+    let record = match PrGovernanceRecord::load(&database, &repo, number).await {
+        Ok(Some(record)) => record,
+        Ok(None) => {
+            return GovernanceError::not_found("Pull request", format!("{}#{}", repo, number))
+                .to_response()
+        }
+        Err(e) => {
+            error!("Failed to load governance record for {}#{}: {}", repo, number, e);
+            return e.to_response();
+        }
+    };
+
+    let signing_key = config.record_signing_key();
+    if config.record_signing_key_path.is_some() && signing_key.is_none() {
+        error!("Failed to load record signing key, exporting unsigned record");
+    }
+
+    match record.attest(&config.server_id, signing_key.as_ref()) {
+        Ok(signed) => (
+            StatusCode::OK,
+            Json(serde_json::to_value(signed).unwrap_or_default()),
+        ),
+        Err(e) => {
+            error!("Failed to attest governance record: {}", e);
+            e.to_response()
+        }
+    }
+}
+
+/// Page size for `GET /prs` when `limit` is omitted
+const DEFAULT_PR_LIST_LIMIT: i64 = 50;
+/// Largest page `GET /prs` returns
+const MAX_PR_LIST_LIMIT: i64 = 200;
+
+#[derive(Debug, Deserialize)]
+pub struct PrListQuery {
+    pub repo: Option<String>,
+    pub status: Option<PullRequestStatus>,
+    pub limit: Option<i64>,
+    pub offset: Option<i64>,
+}
+
+/// Handle `GET /prs?repo=&status=&limit=&offset=`
+pub async fn list_prs_endpoint(
+    State((config, database, _metrics)): State<(AppConfig, Database, Metrics)>,
+    Query(query): Query<PrListQuery>,
+) -> (StatusCode, Json<serde_json::Value>) {
+    let limit = query.limit.unwrap_or(DEFAULT_PR_LIST_LIMIT).clamp(1, MAX_PR_LIST_LIMIT);
+    let offset = query.offset.unwrap_or(0).max(0);
+    let prs = match pr_status::list_pull_requests(
+        &database,
+        query.repo.as_deref(),
+        query.status,
+        limit,
+        offset,
+        chrono::Utc::now(),
+        &config.review_period_policy,
+        &config.economic_quorums,
+    )
+    .await
+    {
+        Ok(prs) => prs,
+        Err(e) => {
+            error!("Failed to list pull requests: {}", e);
+            return e.to_response();
+        }
+    };
+
+    let pull_requests: Vec<serde_json::Value> = prs
+        .iter()
+        .map(|(pr, status)| {
+            let mut value = serde_json::to_value(pr).unwrap_or_default();
+            value["status"] = serde_json::json!(status);
+            value
+        })
+        .collect();
+    (
+        StatusCode::OK,
+        Json(serde_json::json!({
+            "pull_requests": pull_requests,
+            "limit": limit,
+            "offset": offset
+        })),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::body::{to_bytes, Body};
+    use axum::http::Request;
+    use axum::routing::get;
+    use axum::Router;
+    use tower::ServiceExt;
+
+    async fn app(status_token: Option<&str>) -> Router {
+        let mut config = AppConfig::load().unwrap();
+        config.status_token = status_token.map(Secret::new);
+        let database = Database::new_in_memory().await.unwrap();
+        Router::new()
+            .route("/health", get(health_check))
+            .route("/status", get(status_endpoint))
+            .route("/prs", get(list_prs_endpoint))
+            .with_state((config, database, Metrics::new()))
+    }
+
+    async fn get_json(app: Router, uri: &str, token: Option<&str>) -> (StatusCode, serde_json::Value) {
+        let mut request = Request::get(uri);
+        if let Some(token) = token {
+            request = request.header("authorization", format!("Bearer {}", token));
+        }
+        let response = app.oneshot(request.body(Body::empty()).unwrap()).await.unwrap();
+        let status = response.status();
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        (status, serde_json::from_slice(&body).unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_status_requires_token() {
+        let (status, body) = get_json(app(Some("s3cret")).await, "/status", Some("s3cret")).await;
+        assert_eq!(status, StatusCode::OK);
+        assert!(body.get("server_id").is_some());
+        assert!(body.get("database").is_some());
+
+        let (status, body) = get_json(app(Some("s3cret")).await, "/status", None).await;
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+        assert!(body.get("server_id").is_none());
+
+        let (status, _) = get_json(app(Some("s3cret")).await, "/status", Some("guess")).await;
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+
+        let (status, _) = get_json(app(None).await, "/status", Some("s3cret")).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn test_status_reports_failed_nostr_publish() {
+        let mut config = AppConfig::load().unwrap();
+        config.status_token = Some(Secret::new("s3cret"));
+        config.nostr.enabled = true;
+        config.ots.enabled = false;
+        let metrics = Metrics::new();
+        let app = Router::new()
+            .route("/status", get(status_endpoint))
+            .with_state((config, Database::new_in_memory().await.unwrap(), metrics.clone()));
+
+        metrics.record_nostr_publish(true);
+        metrics.set_nostr_relays(2, 2);
+        let (_, body) = get_json(app.clone(), "/status", Some("s3cret")).await;
+        assert_eq!(body["status"], "healthy");
+        assert_eq!(body["subsystems"]["nostr"]["status"], "healthy");
+        assert!(body["subsystems"]["nostr"]["last_publish"].is_string());
+        assert_eq!(body["subsystems"]["ots"]["status"], "disabled");
+
+        // The relays dropped and the next publish failed
+        metrics.record_nostr_publish(false);
+        metrics.set_nostr_relays(0, 2);
+        let (_, body) = get_json(app, "/status", Some("s3cret")).await;
+        assert_eq!(body["status"], "degraded");
+        let nostr = &body["subsystems"]["nostr"];
+        assert_eq!(nostr["status"], "degraded");
+        assert_eq!(nostr["connected_relays"], 0);
+        assert_eq!(nostr["configured_relays"], 2);
+        assert!(nostr["last_publish_failure"].is_string());
+    }
+
+    #[tokio::test]
+    async fn test_health_is_public_and_minimal() {
+        let (status, body) = get_json(app(Some("s3cret")).await, "/health", None).await;
+        assert_eq!(status, StatusCode::OK);
+        let mut keys: Vec<_> = body.as_object().unwrap().keys().cloned().collect();
+        keys.sort();
+        assert_eq!(keys, ["service", "status", "timestamp"]);
+    }
+
+    #[tokio::test]
+    async fn test_list_prs_reports_derived_status() {
+        let mut config = AppConfig::load().unwrap();
+        config.review_period_policy = Default::default();
+        let database = Database::new_in_memory().await.unwrap();
+        database.create_pull_request("BTCDecoded/sdk", 1, "abc", 5).await.unwrap();
+        database.create_pull_request("BTCDecoded/sdk", 2, "def", 5).await.unwrap();
+        database
+            .transition_pull_request("BTCDecoded/sdk", 2, "pending", "merged")
+            .await
+            .unwrap();
+        let app = Router::new()
+            .route("/prs", get(list_prs_endpoint))
+            .with_state((config, database, Metrics::new()));
+
+        let (status, body) = get_json(app.clone(), "/prs?repo=BTCDecoded%2Fsdk", None).await;
+        assert_eq!(status, StatusCode::OK);
+        let prs = body["pull_requests"].as_array().unwrap();
+        assert_eq!(prs.len(), 2);
+        assert_eq!(prs[0]["status"], "open");
+        assert_eq!(prs[1]["status"], "merged");
+
+        let (_, body) = get_json(app.clone(), "/prs?status=merged", None).await;
+        assert_eq!(body["pull_requests"].as_array().unwrap().len(), 1);
+        assert_eq!(body["pull_requests"][0]["pr_number"], 2);
+
+        let request = Request::get("/prs?status=stale").body(Body::empty()).unwrap();
+        let response = app.oneshot(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+}
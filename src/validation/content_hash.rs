@@ -4,16 +4,26 @@
 //! between Orange Paper (Layer 1) and Consensus Proof (Layer 2) repositories.
 //! It ensures that changes to one repository have corresponding changes in the other.
 
+use crate::audit::merkle::{self, MerkleProof};
+use crate::cache::LruCache;
 use crate::error::GovernanceError;
 use sha2::{Digest, Sha256};
 use std::collections::HashMap;
+use std::sync::{Arc, OnceLock};
 use tracing::{info, warn, error};
 
+/// Hashes kept by the shared content hash cache unless configured otherwise
+pub const DEFAULT_CONTENT_HASH_CACHE_CAPACITY: usize = 4096;
+
+static GLOBAL_HASH_CACHE: OnceLock<Arc<ContentHashCache>> = OnceLock::new();
+
 /// Represents a file correspondence mapping between repositories
 #[derive(Debug, Clone)]
 pub struct FileCorrespondence {
     pub orange_paper_file: String,
-    pub consensus_proof_file: String,
+    /// Proof files implementing the spec file; more than one for `OneToMany`.
+    /// For `ManyToOne`, each source has its own mapping naming the shared target.
+    pub consensus_proof_files: Vec<String>,
     pub correspondence_type: CorrespondenceType,
 }
 
@@ -75,8 +85,77 @@ pub enum SyncStatus {
     SyncFailure,
 }
 
+/// Marker a Consensus Proof file uses to record the spec hash it implements
+pub const SPEC_HASH_MARKER: &str = "@spec-hash:";
+
+/// What a cached hash was computed over
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum HashCacheKey {
+    /// `compute_file_hash` of a blob
+    File(String),
+    /// `leaf_hash` of a blob at a path (path, blob SHA)
+    Leaf(String, String),
+}
+
+/// Least-recently-used cache of content hashes keyed by Git blob SHA
+///
+/// A blob SHA identifies its content, so a hash computed once for it holds
+/// for every later PR that touches the same blob.
+pub struct ContentHashCache {
+    entries: LruCache<HashCacheKey, String>,
+}
+
+impl ContentHashCache {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            entries: LruCache::new(capacity),
+        }
+    }
+
+    /// The process-wide cache shared by all validators
+    pub fn global() -> Arc<Self> {
+        GLOBAL_HASH_CACHE
+            .get_or_init(|| Arc::new(Self::new(DEFAULT_CONTENT_HASH_CACHE_CAPACITY)))
+            .clone()
+    }
+
+    /// Size the process-wide cache; only effective before its first use
+    pub fn configure_global(capacity: usize) {
+        if GLOBAL_HASH_CACHE.set(Arc::new(Self::new(capacity))).is_err() {
+            warn!("Content hash cache already initialized, ignoring capacity {}", capacity);
+        }
+    }
+
+    /// Cached hash for `key`, computing and storing it with `compute` on a miss
+    fn get_or_compute(&self, key: HashCacheKey, compute: impl FnOnce() -> String) -> String {
+        if let Some(hash) = self.entries.get(&key) {
+            return hash;
+        }
+        // Hash outside the lock; a concurrent miss on the same key computes the same value
+        let hash = compute();
+        self.entries.insert(key, hash.clone());
+        hash
+    }
+
+    /// Cache (hits, misses) since startup
+    pub fn stats(&self) -> (u64, u64) {
+        self.entries.stats()
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
 pub struct ContentHashValidator {
     pub correspondence_mappings: HashMap<String, FileCorrespondence>,
+    /// Git blob SHA of each source file, when known, so its hash can be cached
+    pub blob_shas: HashMap<String, String>,
+    hash_cache: Arc<ContentHashCache>,
 }
 
 impl ContentHashValidator {
@@ -84,9 +163,17 @@ impl ContentHashValidator {
     pub fn new() -> Self {
         Self {
             correspondence_mappings: HashMap::new(),
+            blob_shas: HashMap::new(),
+            hash_cache: ContentHashCache::global(),
         }
     }
 
+    /// Cache hashes in `cache` instead of the process-wide cache
+    pub fn with_hash_cache(mut self, cache: Arc<ContentHashCache>) -> Self {
+        self.hash_cache = cache;
+        self
+    }
+
     /// Load correspondence mappings from configuration
     pub fn load_correspondence_mappings(&mut self, mappings: Vec<FileCorrespondence>) {
         for mapping in mappings {
@@ -98,6 +185,13 @@ impl ContentHashValidator {
         info!("Loaded {} correspondence mappings", self.correspondence_mappings.len());
     }
 
+    /// Record the Git blob SHA of source files by path
+    ///
+    /// Files with a known blob SHA have their hashes served from the cache.
+    pub fn load_blob_shas(&mut self, shas: impl IntoIterator<Item = (String, String)>) {
+        self.blob_shas.extend(shas);
+    }
+
     /// Compute SHA256 hash of file content
     pub fn compute_file_hash(&self, content: &[u8]) -> String {
         let mut hasher = Sha256::new();
@@ -105,77 +199,100 @@ impl ContentHashValidator {
         format!("sha256:{}", hex::encode(hasher.finalize()))
     }
 
+    /// `compute_file_hash` of a blob, reusing the hash computed for `blob_sha` before
+    pub fn compute_file_hash_cached(&self, blob_sha: &str, content: &[u8]) -> String {
+        self.hash_cache
+            .get_or_compute(HashCacheKey::File(blob_sha.to_string()), || {
+                self.compute_file_hash(content)
+            })
+    }
+
+    /// Cache statistics for the cache this validator uses
+    pub fn hash_cache_stats(&self) -> (u64, u64) {
+        self.hash_cache.stats()
+    }
+
     /// Compute Merkle tree hash of directory contents
     pub fn compute_directory_hash(&self, files: &[(String, Vec<u8>)]) -> DirectoryHashResult {
-        if files.is_empty() {
+        let leaves: Vec<(&str, &[u8], String)> = files
+            .iter()
+            .map(|(path, content)| (path.as_str(), content.as_slice(), Self::leaf_hash(path, content)))
+            .collect();
+        self.directory_hash_from_leaves(leaves)
+    }
+
+    /// `compute_directory_hash` over `(path, blob SHA, content)` entries
+    ///
+    /// Leaf hashes are cached per (path, blob SHA), so only files whose blob
+    /// changed since an earlier check are rehashed.
+    pub fn compute_directory_hash_cached(&self, files: &[(String, String, Vec<u8>)]) -> DirectoryHashResult {
+        let leaves: Vec<(&str, &[u8], String)> = files
+            .iter()
+            .map(|(path, blob_sha, content)| {
+                let hash = self.hash_cache.get_or_compute(
+                    HashCacheKey::Leaf(path.clone(), blob_sha.clone()),
+                    || Self::leaf_hash(path, content),
+                );
+                (path.as_str(), content.as_slice(), hash)
+            })
+            .collect();
+        self.directory_hash_from_leaves(leaves)
+    }
+
+    /// Directory hash from (path, content, leaf hash) entries in any order
+    ///
+    /// The tree is built by `audit::merkle` over the path-sorted leaves, so
+    /// directory roots and audit log roots follow one convention.
+    fn directory_hash_from_leaves(&self, mut leaves: Vec<(&str, &[u8], String)>) -> DirectoryHashResult {
+        // Sort files by path for consistent hashing
+        leaves.sort_by(|a, b| a.0.cmp(b.0));
+
+        let file_hashes: Vec<String> = leaves.iter().map(|(_, _, hash)| hash.clone()).collect();
+        let Ok(root) = merkle::build_merkle_tree_from_hashes(&file_hashes) else {
             return DirectoryHashResult {
                 directory_path: "empty".to_string(),
                 merkle_root: "sha256:e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855".to_string(),
                 file_count: 0,
                 total_size: 0,
             };
-        }
-
-        // Sort files by path for consistent hashing
-        let mut sorted_files = files.to_vec();
-        sorted_files.sort_by(|a, b| a.0.cmp(&b.0));
-
-        // Compute individual file hashes
-        let file_hashes: Vec<String> = sorted_files
-            .iter()
-            .map(|(path, content)| {
-                let mut hasher = Sha256::new();
-                hasher.update(path.as_bytes());
-                hasher.update(b"\0");
-                hasher.update(content);
-                hex::encode(hasher.finalize())
-            })
-            .collect();
-
-        // Build Merkle tree
-        let merkle_root = self.build_merkle_tree(&file_hashes);
-        let total_size: u64 = sorted_files.iter().map(|(_, content)| content.len() as u64).sum();
+        };
+        let total_size: u64 = leaves.iter().map(|(_, content, _)| content.len() as u64).sum();
 
         DirectoryHashResult {
             directory_path: "directory".to_string(),
-            merkle_root: format!("sha256:{}", merkle_root),
-            file_count: sorted_files.len(),
+            merkle_root: root.hash,
+            file_count: leaves.len(),
             total_size,
         }
     }
 
-    /// Build Merkle tree from file hashes
-    fn build_merkle_tree(&self, hashes: &[String]) -> String {
-        if hashes.is_empty() {
-            return "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855".to_string();
-        }
-
-        if hashes.len() == 1 {
-            return hashes[0].clone();
-        }
+    /// Leaf hash of a file in the directory tree: `sha256:` + `sha256(path || 0x00 || content)`
+    pub fn leaf_hash(path: &str, content: &[u8]) -> String {
+        let mut hasher = Sha256::new();
+        hasher.update(path.as_bytes());
+        hasher.update(b"\0");
+        hasher.update(content);
+        format!("sha256:{}", hex::encode(hasher.finalize()))
+    }
 
-        let mut current_level = hashes.to_vec();
-        
-        while current_level.len() > 1 {
-            let mut next_level = Vec::new();
-            
-            for i in (0..current_level.len()).step_by(2) {
-                if i + 1 < current_level.len() {
-                    // Combine two hashes
-                    let combined = format!("{}{}", current_level[i], current_level[i + 1]);
-                    let mut hasher = Sha256::new();
-                    hasher.update(combined.as_bytes());
-                    next_level.push(hex::encode(hasher.finalize()));
-                } else {
-                    // Odd number of hashes, promote the last one
-                    next_level.push(current_level[i].clone());
-                }
-            }
-            
-            current_level = next_level;
-        }
+    /// Build a proof that `target_path` is included in the directory hash of `files`
+    ///
+    /// The proof's root is the `merkle_root` of `compute_directory_hash` and
+    /// verifies with `MerkleProof::verify`.
+    pub fn generate_inclusion_proof(
+        &self,
+        files: &[(String, Vec<u8>)],
+        target_path: &str,
+    ) -> Option<MerkleProof> {
+        let mut sorted_files: Vec<&(String, Vec<u8>)> = files.iter().collect();
+        sorted_files.sort_by(|a, b| a.0.cmp(&b.0));
 
-        current_level[0].clone()
+        let leaf_index = sorted_files.iter().position(|(path, _)| path == target_path)?;
+        let leaves: Vec<String> = sorted_files
+            .iter()
+            .map(|(path, content)| Self::leaf_hash(path, content))
+            .collect();
+        merkle::generate_merkle_proof_from_hashes(&leaves, leaf_index).ok()
     }
 
     /// Verify file correspondence between repositories
@@ -185,7 +302,10 @@ impl ContentHashValidator {
         source_content: &[u8],
         target_repo_files: &HashMap<String, Vec<u8>>,
     ) -> Result<HashVerificationResult, GovernanceError> {
-        let source_hash = self.compute_file_hash(source_content);
+        let source_hash = match self.blob_shas.get(source_file) {
+            Some(blob_sha) => self.compute_file_hash_cached(blob_sha, source_content),
+            None => self.compute_file_hash(source_content),
+        };
         
         // Find correspondence mapping
         let mapping = self.correspondence_mappings.get(source_file)
@@ -193,27 +313,71 @@ impl ContentHashValidator {
                 format!("No correspondence mapping found for file: {}", source_file)
             ))?;
 
-        // Check if target file exists
-        let target_content = target_repo_files.get(&mapping.consensus_proof_file)
-            .ok_or_else(|| GovernanceError::ValidationError(
-                format!("Corresponding file not found: {}", mapping.consensus_proof_file)
-            ))?;
+        // Every target file must exist
+        let missing: Vec<&str> = mapping.consensus_proof_files.iter()
+            .filter(|target| !target_repo_files.contains_key(*target))
+            .map(|target| target.as_str())
+            .collect();
+        if !missing.is_empty() {
+            return Err(GovernanceError::ValidationError(
+                format!("Corresponding file not found: {}", missing.join(", "))
+            ));
+        }
 
-        let target_hash = self.compute_file_hash(target_content);
-        
-        // For now, we just verify the file exists and has content
-        // In a real implementation, we would verify the content matches the specification
-        let is_valid = !target_content.is_empty();
-        
+        // Each proof file records which spec revisions it implements. A
+        // ManyToOne target carries one marker per source, so a source is
+        // satisfied when any marker in the target matches its hash.
+        let mut expected_hash = Some(source_hash.clone());
+        let mut errors = Vec::new();
+        for target in &mapping.consensus_proof_files {
+            let recorded = Self::extract_spec_hashes(&target_repo_files[target]);
+            if recorded.is_empty() {
+                errors.push(format!(
+                    "{} has no {} marker for {}",
+                    target, SPEC_HASH_MARKER, source_file
+                ));
+                expected_hash = None;
+            } else if !recorded.contains(&source_hash) {
+                errors.push(format!(
+                    "{} is stale: {} records {} but {} hashes to {}",
+                    target, SPEC_HASH_MARKER, recorded.join(", "), source_file, source_hash
+                ));
+                if expected_hash.as_deref() == Some(source_hash.as_str()) {
+                    expected_hash = Some(recorded[0].clone());
+                }
+            }
+        }
+
+        let error_message = if errors.is_empty() { None } else { Some(errors.join("; ")) };
         Ok(HashVerificationResult {
             file_path: source_file.to_string(),
             computed_hash: source_hash,
-            expected_hash: Some(target_hash),
-            is_valid,
-            error_message: if is_valid { None } else { Some("Target file is empty".to_string()) },
+            expected_hash,
+            is_valid: error_message.is_none(),
+            error_message,
         })
     }
 
+    /// Extract every `@spec-hash: sha256:...` marker from a proof file
+    pub fn extract_spec_hashes(content: &[u8]) -> Vec<String> {
+        let content = String::from_utf8_lossy(content);
+        content.lines().filter_map(|line| {
+            let (_, rest) = line.split_once(SPEC_HASH_MARKER)?;
+            let hash = rest.trim().split_whitespace().next()?;
+            let hex_part = hash.strip_prefix("sha256:")?;
+            if hex_part.len() == 64 && hex_part.chars().all(|c| c.is_ascii_hexdigit()) {
+                Some(hash.to_lowercase())
+            } else {
+                None
+            }
+        }).collect()
+    }
+
+    /// Extract the first `@spec-hash: sha256:...` marker from a proof file, if present
+    pub fn extract_spec_hash(content: &[u8]) -> Option<String> {
+        Self::extract_spec_hashes(content).into_iter().next()
+    }
+
     /// Check bidirectional synchronization between Orange Paper and Consensus Proof
     pub fn check_bidirectional_sync(
         &self,
@@ -225,7 +389,7 @@ impl ContentHashValidator {
 
         let mut verification_results = Vec::new();
         let mut missing_files = Vec::new();
-        let outdated_files = Vec::new();
+        let mut outdated_files = Vec::new();
 
         // Check each changed file for correspondence
         for changed_file in changed_files {
@@ -234,6 +398,9 @@ impl ContentHashValidator {
                     Ok(result) => {
                         if result.is_valid {
                             verification_results.push(result);
+                        } else if result.expected_hash.is_some() {
+                            // Marker present but pointing at an older spec revision
+                            outdated_files.push(changed_file.clone());
                         } else {
                             missing_files.push(changed_file.clone());
                         }
@@ -272,37 +439,40 @@ impl ContentHashValidator {
         vec![
             FileCorrespondence {
                 orange_paper_file: "consensus-rules/block-validation.md".to_string(),
-                consensus_proof_file: "proofs/block-validation.rs".to_string(),
+                consensus_proof_files: vec!["proofs/block-validation.rs".to_string()],
                 correspondence_type: CorrespondenceType::Direct,
             },
             FileCorrespondence {
                 orange_paper_file: "consensus-rules/transaction-validation.md".to_string(),
-                consensus_proof_file: "proofs/transaction-validation.rs".to_string(),
-                correspondence_type: CorrespondenceType::Direct,
+                consensus_proof_files: vec![
+                    "proofs/transaction-validation.rs".to_string(),
+                    "proofs/transaction-signatures.rs".to_string(),
+                ],
+                correspondence_type: CorrespondenceType::OneToMany,
             },
             FileCorrespondence {
                 orange_paper_file: "consensus-rules/utxo-validation.md".to_string(),
-                consensus_proof_file: "proofs/utxo-validation.rs".to_string(),
+                consensus_proof_files: vec!["proofs/utxo-validation.rs".to_string()],
                 correspondence_type: CorrespondenceType::Direct,
             },
             FileCorrespondence {
                 orange_paper_file: "consensus-rules/script-validation.md".to_string(),
-                consensus_proof_file: "proofs/script-validation.rs".to_string(),
+                consensus_proof_files: vec!["proofs/script-validation.rs".to_string()],
                 correspondence_type: CorrespondenceType::Direct,
             },
             FileCorrespondence {
                 orange_paper_file: "consensus-rules/economic-model.md".to_string(),
-                consensus_proof_file: "proofs/economic-model.rs".to_string(),
+                consensus_proof_files: vec!["proofs/economic-model.rs".to_string()],
                 correspondence_type: CorrespondenceType::Direct,
             },
             FileCorrespondence {
                 orange_paper_file: "consensus-rules/segwit-validation.md".to_string(),
-                consensus_proof_file: "proofs/segwit-validation.rs".to_string(),
+                consensus_proof_files: vec!["proofs/segwit-validation.rs".to_string()],
                 correspondence_type: CorrespondenceType::Direct,
             },
             FileCorrespondence {
                 orange_paper_file: "consensus-rules/taproot-validation.md".to_string(),
-                consensus_proof_file: "proofs/taproot-validation.rs".to_string(),
+                consensus_proof_files: vec!["proofs/taproot-validation.rs".to_string()],
                 correspondence_type: CorrespondenceType::Direct,
             },
         ]
@@ -359,6 +529,43 @@ mod tests {
         assert_eq!(hash.len(), 71); // "sha256:" + 64 hex chars
     }
 
+    #[test]
+    fn test_repeated_blob_hash_served_from_cache() {
+        let cache = Arc::new(ContentHashCache::new(16));
+        let validator = ContentHashValidator::new().with_hash_cache(cache.clone());
+        let sha = "3b18e512dba79e4c8300dd08aeb37f8e728b8dad";
+
+        let first = validator.compute_file_hash_cached(sha, b"test content");
+        let second = validator.compute_file_hash_cached(sha, b"test content");
+
+        assert_eq!(first, second);
+        assert_eq!(first, validator.compute_file_hash(b"test content"));
+        assert_eq!(cache.stats(), (1, 1));
+        assert_eq!(cache.len(), 1);
+    }
+
+    #[test]
+    fn test_cached_directory_hash_matches_uncached() {
+        let cache = Arc::new(ContentHashCache::new(16));
+        let validator = ContentHashValidator::new().with_hash_cache(cache.clone());
+        let files = vec![
+            ("file2.txt".to_string(), "b1".to_string(), b"content2".to_vec()),
+            ("file1.txt".to_string(), "a1".to_string(), b"content1".to_vec()),
+        ];
+        let uncached: Vec<(String, Vec<u8>)> = files
+            .iter()
+            .map(|(path, _, content)| (path.clone(), content.clone()))
+            .collect();
+
+        let first = validator.compute_directory_hash_cached(&files);
+        let second = validator.compute_directory_hash_cached(&files);
+
+        assert_eq!(first.merkle_root, validator.compute_directory_hash(&uncached).merkle_root);
+        assert_eq!(second.merkle_root, first.merkle_root);
+        assert_eq!(second.total_size, 16);
+        assert_eq!(cache.stats(), (2, 2));
+    }
+
     #[test]
     fn test_compute_directory_hash() {
         let validator = ContentHashValidator::new();
@@ -392,7 +599,8 @@ mod tests {
         
         assert!(!mappings.is_empty());
         assert!(mappings.iter().any(|m| m.orange_paper_file == "consensus-rules/block-validation.md"));
-        assert!(mappings.iter().any(|m| m.consensus_proof_file == "proofs/block-validation.rs"));
+        assert!(mappings.iter().any(|m| m.consensus_proof_files == vec!["proofs/block-validation.rs".to_string()]));
+        assert!(mappings.iter().any(|m| m.correspondence_type == CorrespondenceType::OneToMany && m.consensus_proof_files.len() == 2));
     }
 
     #[test]
@@ -404,8 +612,12 @@ mod tests {
         let source_file = "consensus-rules/block-validation.md";
         let source_content = b"block validation rules";
         
+        let source_hash = validator.compute_file_hash(source_content);
         let mut target_files = HashMap::new();
-        target_files.insert("proofs/block-validation.rs".to_string(), b"proof implementation".to_vec());
+        target_files.insert(
+            "proofs/block-validation.rs".to_string(),
+            format!("// @spec-hash: {}\nproof implementation", source_hash).into_bytes(),
+        );
 
         let result = validator.verify_correspondence(source_file, source_content, &target_files);
         
@@ -413,6 +625,52 @@ mod tests {
         let result = result.unwrap();
         assert!(result.is_valid);
         assert_eq!(result.file_path, source_file);
+        assert_eq!(result.expected_hash, Some(source_hash));
+    }
+
+    #[test]
+    fn test_verify_correspondence_missing_marker() {
+        let mut validator = ContentHashValidator::new();
+        validator.load_correspondence_mappings(ContentHashValidator::generate_correspondence_map());
+
+        let mut target_files = HashMap::new();
+        target_files.insert("proofs/block-validation.rs".to_string(), b"proof implementation".to_vec());
+
+        let result = validator
+            .verify_correspondence("consensus-rules/block-validation.md", b"block validation rules", &target_files)
+            .unwrap();
+        assert!(!result.is_valid);
+        assert!(result.expected_hash.is_none());
+        assert!(result.error_message.unwrap().contains("no @spec-hash: marker"));
+    }
+
+    #[test]
+    fn test_verify_correspondence_stale_marker() {
+        let mut validator = ContentHashValidator::new();
+        validator.load_correspondence_mappings(ContentHashValidator::generate_correspondence_map());
+
+        let old_hash = validator.compute_file_hash(b"old block validation rules");
+        let mut target_files = HashMap::new();
+        target_files.insert(
+            "proofs/block-validation.rs".to_string(),
+            format!("/// @spec-hash: {}\nproof implementation", old_hash).into_bytes(),
+        );
+
+        let result = validator
+            .verify_correspondence("consensus-rules/block-validation.md", b"block validation rules", &target_files)
+            .unwrap();
+        assert!(!result.is_valid);
+        assert_eq!(result.expected_hash, Some(old_hash));
+        assert!(result.error_message.unwrap().contains("is stale"));
+
+        // The sync report classifies the stale proof as outdated rather than missing
+        let mut orange_files = HashMap::new();
+        orange_files.insert("consensus-rules/block-validation.md".to_string(), b"block validation rules".to_vec());
+        let report = validator
+            .check_bidirectional_sync(&orange_files, &target_files, &["consensus-rules/block-validation.md".to_string()])
+            .unwrap();
+        assert_eq!(report.sync_status, SyncStatus::OutdatedVersions);
+        assert_eq!(report.outdated_files.len(), 1);
     }
 
     #[test]
@@ -430,4 +688,116 @@ mod tests {
         assert!(result.is_err());
         assert!(result.unwrap_err().to_string().contains("Corresponding file not found"));
     }
+
+    #[test]
+    fn test_one_to_many_requires_every_target() {
+        let mut validator = ContentHashValidator::new();
+        validator.load_correspondence_mappings(ContentHashValidator::generate_correspondence_map());
+
+        let source_file = "consensus-rules/transaction-validation.md";
+        let source_content = b"transaction validation rules";
+        let marker = format!("// @spec-hash: {}\n", validator.compute_file_hash(source_content));
+
+        let mut target_files = HashMap::new();
+        target_files.insert("proofs/transaction-validation.rs".to_string(), marker.clone().into_bytes());
+
+        // Partially-missing target set is an error naming the missing file
+        let err = validator
+            .verify_correspondence(source_file, source_content, &target_files)
+            .unwrap_err();
+        assert!(err.to_string().contains("proofs/transaction-signatures.rs"));
+
+        // Second target present but without a marker
+        target_files.insert("proofs/transaction-signatures.rs".to_string(), b"fn verify() {}".to_vec());
+        let result = validator.verify_correspondence(source_file, source_content, &target_files).unwrap();
+        assert!(!result.is_valid);
+        assert!(result.error_message.unwrap().contains("proofs/transaction-signatures.rs"));
+
+        target_files.insert("proofs/transaction-signatures.rs".to_string(), marker.into_bytes());
+        let result = validator.verify_correspondence(source_file, source_content, &target_files).unwrap();
+        assert!(result.is_valid);
+    }
+
+    #[test]
+    fn test_many_to_one_aggregates_sources() {
+        let mut validator = ContentHashValidator::new();
+        validator.load_correspondence_mappings(vec![
+            FileCorrespondence {
+                orange_paper_file: "consensus-rules/difficulty.md".to_string(),
+                consensus_proof_files: vec!["proofs/chain-params.rs".to_string()],
+                correspondence_type: CorrespondenceType::ManyToOne,
+            },
+            FileCorrespondence {
+                orange_paper_file: "consensus-rules/subsidy.md".to_string(),
+                consensus_proof_files: vec!["proofs/chain-params.rs".to_string()],
+                correspondence_type: CorrespondenceType::ManyToOne,
+            },
+        ]);
+
+        let mut orange_files = HashMap::new();
+        orange_files.insert("consensus-rules/difficulty.md".to_string(), b"difficulty rules".to_vec());
+        orange_files.insert("consensus-rules/subsidy.md".to_string(), b"subsidy rules".to_vec());
+
+        let target = format!(
+            "// @spec-hash: {}\n// @spec-hash: {}\n",
+            validator.compute_file_hash(b"difficulty rules"),
+            validator.compute_file_hash(b"subsidy rules"),
+        );
+        let mut target_files = HashMap::new();
+        target_files.insert("proofs/chain-params.rs".to_string(), target.into_bytes());
+
+        let results = validator.validate_all_correspondences(&orange_files, &target_files).unwrap();
+        assert_eq!(results.len(), 2);
+        assert!(results.iter().all(|r| r.is_valid));
+
+        // Updating one source without touching the shared target flags only that source
+        orange_files.insert("consensus-rules/subsidy.md".to_string(), b"new subsidy rules".to_vec());
+        let results = validator.validate_all_correspondences(&orange_files, &target_files).unwrap();
+        let invalid: Vec<_> = results.iter().filter(|r| !r.is_valid).collect();
+        assert_eq!(invalid.len(), 1);
+        assert_eq!(invalid[0].file_path, "consensus-rules/subsidy.md");
+    }
+
+    fn five_files() -> Vec<(String, Vec<u8>)> {
+        (1..=5)
+            .map(|i| (format!("consensus-rules/file{}.md", i), format!("content {}", i).into_bytes()))
+            .collect()
+    }
+
+    #[test]
+    fn test_inclusion_proof_round_trip() {
+        let validator = ContentHashValidator::new();
+        let files = five_files();
+        let root = validator.compute_directory_hash(&files).merkle_root;
+
+        let proof = validator
+            .generate_inclusion_proof(&files, "consensus-rules/file3.md")
+            .unwrap();
+        assert_eq!(proof.leaf_hash, ContentHashValidator::leaf_hash("consensus-rules/file3.md", b"content 3"));
+        assert_eq!(proof.root_hash, root);
+        assert!(proof.verify());
+
+        // The unpaired fifth leaf is hashed with itself, as in the audit log tree
+        let last = validator
+            .generate_inclusion_proof(&files, "consensus-rules/file5.md")
+            .unwrap();
+        assert_eq!(last.proof_hashes[0], last.leaf_hash);
+        assert_eq!(last.root_hash, root);
+        assert!(last.verify());
+
+        assert!(validator.generate_inclusion_proof(&files, "consensus-rules/missing.md").is_none());
+    }
+
+    #[test]
+    fn test_inclusion_proof_rejects_tampered_leaf() {
+        let validator = ContentHashValidator::new();
+        let files = five_files();
+        let root = validator.compute_directory_hash(&files).merkle_root;
+        let proof = validator
+            .generate_inclusion_proof(&files, "consensus-rules/file3.md")
+            .unwrap();
+
+        let tampered = ContentHashValidator::leaf_hash("consensus-rules/file3.md", b"content 3 (edited)");
+        assert!(!merkle::verify_merkle_proof(&proof, &tampered, &root));
+    }
 }
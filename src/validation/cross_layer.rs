@@ -1,11 +1,19 @@
+use crate::config::AppConfig;
+use crate::database::Database;
 use crate::error::GovernanceError;
 use crate::validation::content_hash::{ContentHashValidator, SyncReport, SyncStatus};
-use crate::validation::version_pinning::{VersionPinningValidator, VersionPinningConfig, VersionManifest};
+use crate::validation::version_pinning::{self, VersionPinningValidator, VersionPinningConfig, VersionManifest};
 use crate::validation::equivalence_proof::{EquivalenceProofValidator, EquivalenceTestVector};
+use crate::validation::tier_classification;
+use crate::github::client::GitHubClient;
 use crate::github::file_operations::GitHubFileOperations;
+use crate::github::types::PullRequestFile;
 use crate::github::cross_layer_status::{CrossLayerStatusChecker, CrossLayerStatusCheck, StatusState};
+use regex::Regex;
 use serde_json::Value;
 use std::collections::HashMap;
+use std::path::Path;
+use std::sync::OnceLock;
 use tracing::{info, warn};
 
 pub struct CrossLayerValidator;
@@ -15,14 +23,92 @@ impl CrossLayerValidator {
         repo_name: &str,
         changed_files: &[String],
         cross_layer_rules: &[Value],
+        version_manifest_path: &Path,
+        trusted_keys: &HashMap<String, String>,
     ) -> Result<(), GovernanceError> {
+        // Without diffs, any consensus change fails closed
+        let changed_files: Vec<PullRequestFile> = changed_files
+            .iter()
+            .map(|filename| PullRequestFile {
+                filename: filename.clone(),
+                patch: None,
+                status: None,
+                previous_filename: None,
+            })
+            .collect();
+        Self::validate_cross_layer_changes(
+            repo_name,
+            &changed_files,
+            cross_layer_rules,
+            version_manifest_path,
+            trusted_keys,
+        )
+    }
+
+    /// Validate a pull request's cross-layer dependencies, fetching its diffs from GitHub
+    ///
+    /// Version references are checked against the manifest at
+    /// `config.version_manifest_path`, signed by the active maintainers' keys.
+    pub async fn validate_pull_request(
+        client: &GitHubClient,
+        database: &Database,
+        config: &AppConfig,
+        repo_name: &str,
+        pr_number: u64,
+        cross_layer_rules: &[Value],
+    ) -> Result<(), GovernanceError> {
+        let (owner, repo) = repo_name.split_once('/').ok_or_else(|| {
+            GovernanceError::ValidationError(format!("Invalid repository name: {}", repo_name))
+        })?;
+        let changed_files = client.list_pull_request_patches(owner, repo, pr_number).await?;
+        let trusted_keys = Self::trusted_maintainer_keys(database).await?;
+        Self::validate_cross_layer_changes(
+            repo_name,
+            &changed_files,
+            cross_layer_rules,
+            Path::new(&config.version_manifest_path),
+            &trusted_keys,
+        )
+    }
+
+    /// Keys of the active maintainers, the only ones trusted to sign manifest versions
+    ///
+    /// Maps GitHub username to hex SEC1 public key. Keys that don't parse as
+    /// SEC1 can't sign manifest entries and are left out.
+    pub async fn trusted_maintainer_keys(
+        database: &Database,
+    ) -> Result<HashMap<String, String>, GovernanceError> {
+        Ok(database
+            .list_maintainers()
+            .await?
+            .into_iter()
+            .filter(|m| m.active)
+            .filter(|m| version_pinning::parse_public_key(&m.public_key).is_ok())
+            .map(|m| (m.github_username, m.public_key))
+            .collect())
+    }
+
+    /// Validate cross-layer dependencies for changed files and their diffs
+    pub fn validate_cross_layer_changes(
+        repo_name: &str,
+        changed_files: &[PullRequestFile],
+        cross_layer_rules: &[Value],
+        version_manifest_path: &Path,
+        trusted_keys: &HashMap<String, String>,
+    ) -> Result<(), GovernanceError> {
+        // A rename matches rules on its old path too, so moving a file out doesn't escape them
+        let filenames: Vec<String> = changed_files
+            .iter()
+            .flat_map(|f| std::iter::once(&f.filename).chain(f.previous_filename.as_ref()))
+            .cloned()
+            .collect();
         for rule in cross_layer_rules {
             if let Some(source_repo) = rule.get("source_repo").and_then(|v| v.as_str()) {
                 if source_repo == repo_name {
                     if let Some(source_pattern) =
                         rule.get("source_pattern").and_then(|v| v.as_str())
                     {
-                        if Self::matches_pattern(changed_files, source_pattern) {
+                        if Self::matches_pattern(&filenames, source_pattern) {
                             if let Some(target_repo) =
                                 rule.get("target_repo").and_then(|v| v.as_str())
                             {
@@ -33,6 +119,9 @@ impl CrossLayerValidator {
                                         target_repo,
                                         validation_type,
                                         rule,
+                                        changed_files,
+                                        version_manifest_path,
+                                        trusted_keys,
                                     );
                                 }
                             }
@@ -45,35 +134,26 @@ impl CrossLayerValidator {
     }
 
     fn matches_pattern(files: &[String], pattern: &str) -> bool {
-        // Simple glob pattern matching
-        // In a real implementation, this would use a proper glob library
-        files.iter().any(|file| {
-            if pattern.contains("**") {
-                let prefix = pattern.split("**").next().unwrap_or("");
-                file.starts_with(prefix)
-            } else if pattern.contains("*") {
-                let prefix = pattern.split("*").next().unwrap_or("");
-                file.starts_with(prefix)
-            } else {
-                file == pattern
-            }
-        })
+        files.iter().any(|file| tier_classification::matches_pattern(file, pattern))
     }
 
     fn validate_dependency(
         target_repo: &str,
         validation_type: &str,
         rule: &Value,
+        changed_files: &[PullRequestFile],
+        version_manifest_path: &Path,
+        trusted_keys: &HashMap<String, String>,
     ) -> Result<(), GovernanceError> {
         match validation_type {
             "corresponding_file_exists" => {
                 Self::verify_file_correspondence(target_repo, rule)
             }
             "references_latest_version" => {
-                Self::verify_version_references(target_repo, rule)
+                Self::verify_version_references(target_repo, rule, version_manifest_path, trusted_keys)
             }
             "no_consensus_modifications" => {
-                Self::verify_no_consensus_modifications(target_repo, rule)
+                Self::verify_no_consensus_modifications(target_repo, rule, changed_files)
             }
             _ => Err(GovernanceError::ValidationError(format!(
                 "Unknown validation type: {}",
@@ -111,7 +191,14 @@ impl CrossLayerValidator {
     }
 
     /// Verify version references are up to date
-    fn verify_version_references(target_repo: &str, rule: &Value) -> Result<(), GovernanceError> {
+    ///
+    /// Manifest versions count only when signed by one of `trusted_keys`.
+    fn verify_version_references(
+        target_repo: &str,
+        rule: &Value,
+        version_manifest_path: &Path,
+        trusted_keys: &HashMap<String, String>,
+    ) -> Result<(), GovernanceError> {
         info!("Verifying version references for target repo: {}", target_repo);
         
         // Extract rule parameters
@@ -130,10 +217,14 @@ impl CrossLayerValidator {
             enforce_latest_version: true,
         };
         
-        let mut validator = VersionPinningValidator::new(config);
+        let mut validator = VersionPinningValidator::new(config).with_trusted_keys(
+            trusted_keys
+                .iter()
+                .map(|(maintainer_id, public_key)| (maintainer_id.clone(), public_key.clone())),
+        )?;
         
-        // Load version manifest (in a real implementation, this would be loaded from file)
-        let manifest = Self::load_version_manifest()?;
+        // Load the signed release history and check its integrity
+        let manifest = Self::load_version_manifest(version_manifest_path)?;
         validator.load_version_manifest(manifest)?;
         
         // In a real implementation, this would:
@@ -148,63 +239,75 @@ impl CrossLayerValidator {
     }
 
     /// Load version manifest from configuration
-    fn load_version_manifest() -> Result<VersionManifest, GovernanceError> {
-        // In a real implementation, this would load from the YAML file
-        // For now, we'll create a mock manifest
-        
-        use chrono::Utc;
-        use crate::validation::version_pinning::{VersionManifestEntry, VersionSignature};
-        
-        let manifest = VersionManifest {
-            repository: "orange-paper".to_string(),
-            created_at: Utc::now(),
-            versions: vec![
-                VersionManifestEntry {
-                    version: "v1.0.0".to_string(),
-                    commit_sha: "a1b2c3d4e5f6789012345678901234567890abcd".to_string(),
-                    content_hash: "sha256:1234567890abcdef1234567890abcdef1234567890abcdef1234567890abcdef".to_string(),
-                    created_at: Utc::now() - chrono::Duration::days(1),
-                    signatures: vec![
-                        VersionSignature {
-                            maintainer_id: "maintainer1".to_string(),
-                            signature: "test_signature_1".to_string(),
-                            public_key: "test_public_key_1".to_string(),
-                            signed_at: Utc::now() - chrono::Duration::days(1),
-                        },
-                        // Add more signatures as needed
-                    ],
-                    ots_timestamp: Some("bitcoin:test_timestamp".to_string()),
-                    is_stable: true,
-                    is_latest: true,
-                }
-            ],
-            latest_version: "v1.0.0".to_string(),
-            manifest_hash: "sha256:test_manifest_hash".to_string(),
-        };
-        
-        Ok(manifest)
+    fn load_version_manifest(path: &Path) -> Result<VersionManifest, GovernanceError> {
+        VersionPinningValidator::load_manifest_from_file(path)
     }
 
     /// Verify no consensus modifications are made
-    fn verify_no_consensus_modifications(target_repo: &str, rule: &Value) -> Result<(), GovernanceError> {
+    ///
+    /// Consensus paths are the rule's `consensus_paths` globs, or its
+    /// `source_pattern` when none are listed. A file is checked when either
+    /// its new or, for a rename, its old path is a consensus path, and
+    /// renaming such a file is itself a modification. With
+    /// `allowed_imports_only`, changed lines in those files may only touch
+    /// `use` declarations or dependency versions; otherwise they may not
+    /// change at all.
+    fn verify_no_consensus_modifications(
+        target_repo: &str,
+        rule: &Value,
+        changed_files: &[PullRequestFile],
+    ) -> Result<(), GovernanceError> {
         info!("Verifying no consensus modifications for target repo: {}", target_repo);
-        
+
         // Extract rule parameters
         let allowed_imports_only = rule.get("allowed_imports_only")
             .and_then(|v| v.as_bool())
             .unwrap_or(false);
-        
+        let consensus_paths: Vec<&str> = match rule.get("consensus_paths").and_then(|v| v.as_array()) {
+            Some(paths) => paths.iter().filter_map(|p| p.as_str()).collect(),
+            None => rule.get("source_pattern").and_then(|v| v.as_str()).into_iter().collect(),
+        };
+
         info!("Checking consensus modifications - imports only: {}", allowed_imports_only);
-        
-        // For now, we'll just log the check and return success
-        // In a real implementation, this would:
-        // 1. Analyze file changes for consensus-related modifications
-        // 2. Check that only allowed import changes are made
-        // 3. Verify no core consensus logic is modified
-        // 4. Block any unauthorized consensus changes
-        
-        warn!("Consensus modification verification not fully implemented - using placeholder");
-        
+
+        let is_consensus_path = |path: &str| {
+            consensus_paths
+                .iter()
+                .any(|pattern| tier_classification::matches_pattern(path, pattern))
+        };
+        for file in changed_files {
+            let previous = file.previous_filename.as_deref().filter(|p| *p != file.filename);
+            if !is_consensus_path(&file.filename) && !previous.is_some_and(is_consensus_path) {
+                continue;
+            }
+            if let Some(previous) = previous {
+                return Err(GovernanceError::ValidationError(format!(
+                    "{} is renamed to {}, which modifies {} consensus code",
+                    previous, file.filename, target_repo
+                )));
+            }
+            let patch = file.patch.as_deref().ok_or_else(|| {
+                GovernanceError::ValidationError(format!(
+                    "No diff available for consensus file {} (binary or too large), so changes to {} code can't be verified",
+                    file.filename, target_repo
+                ))
+            })?;
+            if let Some(change) = find_consensus_change(patch, allowed_imports_only) {
+                return Err(GovernanceError::ValidationError(format!(
+                    "{} line {} modifies {} consensus code{}: {}",
+                    file.filename,
+                    change.line,
+                    target_repo,
+                    if allowed_imports_only {
+                        " beyond imports and dependency versions"
+                    } else {
+                        ""
+                    },
+                    change.text.trim()
+                )));
+            }
+        }
+
         Ok(())
     }
 
@@ -235,28 +338,37 @@ impl CrossLayerValidator {
 
         // Convert to the format expected by the validator
         let mut orange_files_map = HashMap::new();
+        let mut orange_blob_shas = HashMap::new();
         for (path, file) in orange_paper_files {
+            orange_blob_shas.insert(path.clone(), file.sha);
             orange_files_map.insert(path, file.content);
         }
+        validator.load_blob_shas(orange_blob_shas);
 
         // Fetch corresponding Consensus Proof files
         let mut consensus_proof_files = HashMap::new();
         for mapping in validator.correspondence_mappings.values() {
-            if changed_files.contains(&mapping.orange_paper_file) {
+            if !changed_files.contains(&mapping.orange_paper_file) {
+                continue;
+            }
+            for proof_file in &mapping.consensus_proof_files {
+                if consensus_proof_files.contains_key(proof_file) {
+                    continue;
+                }
                 match file_ops
                     .fetch_file_content(
                         consensus_proof_owner,
                         consensus_proof_repo,
-                        &mapping.consensus_proof_file,
+                        proof_file,
                         None,
                     )
                     .await
                 {
                     Ok(file) => {
-                        consensus_proof_files.insert(mapping.consensus_proof_file.clone(), file.content);
+                        consensus_proof_files.insert(proof_file.clone(), file.content);
                     }
                     Err(e) => {
-                        warn!("Failed to fetch Consensus Proof file {}: {}", mapping.consensus_proof_file, e);
+                        warn!("Failed to fetch Consensus Proof file {}: {}", proof_file, e);
                     }
                 }
             }
@@ -302,6 +414,7 @@ impl CrossLayerValidator {
 
         /// Generate comprehensive cross-layer status check for GitHub PR
         pub async fn generate_github_status_check(
+            config: &AppConfig,
             github_token: &str,
             owner: &str,
             repo: &str,
@@ -311,10 +424,10 @@ impl CrossLayerValidator {
             info!("Generating GitHub status check for {}/{} PR #{}", owner, repo, pr_number);
 
             // Create GitHub client
-            let github_client = crate::github::client::GitHubClient::new(github_token.to_string());
+            let github_client = crate::github::client::GitHubClient::from_token(github_token.to_string())?;
             
             // Create status checker
-            let mut status_checker = CrossLayerStatusChecker::new(github_client);
+            let mut status_checker = CrossLayerStatusChecker::from_config(github_client, config);
             
             // Generate comprehensive status check
             status_checker.generate_cross_layer_status(owner, repo, pr_number, changed_files).await
@@ -322,16 +435,19 @@ impl CrossLayerValidator {
 
         /// Post cross-layer status check to GitHub
         pub async fn post_cross_layer_status_check(
+            config: &AppConfig,
             github_token: &str,
             owner: &str,
             repo: &str,
             pr_number: u64,
+            head_sha: &str,
             changed_files: &[String],
         ) -> Result<(), GovernanceError> {
-            info!("Posting cross-layer status check for {}/{} PR #{}", owner, repo, pr_number);
+            info!("Posting cross-layer status check for {}/{} PR #{} ({})", owner, repo, pr_number, head_sha);
 
             // Generate status check
             let status_check = Self::generate_github_status_check(
+                config,
                 github_token,
                 owner,
                 repo,
@@ -340,13 +456,13 @@ impl CrossLayerValidator {
             ).await?;
 
             // Create GitHub client
-            let github_client = crate::github::client::GitHubClient::new(github_token.to_string());
+            let github_client = crate::github::client::GitHubClient::from_token(github_token.to_string())?;
 
             // Post status check to GitHub
             github_client.create_status_check(
                 owner,
                 repo,
-                pr_number,
+                head_sha,
                 &status_check.context,
                 &status_check.state,
                 &status_check.description,
@@ -357,3 +473,338 @@ impl CrossLayerValidator {
             Ok(())
         }
 }
+
+/// A changed line in a patch that isn't allowed
+#[derive(Debug, PartialEq)]
+struct PatchChange {
+    /// Line in the new file for additions, in the old file for removals
+    line: usize,
+    text: String,
+}
+
+/// Find the first changed line in a unified diff that isn't permitted
+///
+/// With `imports_only`, blank lines, comments, `use`/`extern crate`
+/// declarations (including the body of a multi-line `use`) and Cargo
+/// dependency version lines are permitted.
+fn find_consensus_change(patch: &str, imports_only: bool) -> Option<PatchChange> {
+    let mut old_line = 0;
+    let mut new_line = 0;
+    let mut in_use_block = false;
+
+    for raw in patch.lines() {
+        if let Some(header) = raw.strip_prefix("@@") {
+            let (old_start, new_start) = parse_hunk_header(header);
+            old_line = old_start;
+            new_line = new_start;
+            in_use_block = false;
+            continue;
+        }
+        if raw.starts_with('\\') {
+            // "\ No newline at end of file"
+            continue;
+        }
+
+        let (marker, text) = raw.split_at(raw.len().min(1));
+        let line = match marker {
+            "+" => new_line,
+            "-" => old_line,
+            _ => 0,
+        };
+        let permitted = imports_only && (in_use_block || is_import_or_version_line(text));
+        in_use_block = use_block_continues(text, in_use_block);
+
+        match marker {
+            "+" => new_line += 1,
+            "-" => old_line += 1,
+            _ => {
+                old_line += 1;
+                new_line += 1;
+                continue;
+            }
+        }
+        if !permitted {
+            return Some(PatchChange {
+                line,
+                text: text.to_string(),
+            });
+        }
+    }
+    None
+}
+
+/// Starting line numbers of the old and new side from `-a,b +c,d @@`
+fn parse_hunk_header(header: &str) -> (usize, usize) {
+    let mut old_start = 0;
+    let mut new_start = 0;
+    for range in header.split_whitespace() {
+        let start = |r: &str| r.split(',').next().and_then(|n| n.parse().ok()).unwrap_or(0);
+        if let Some(r) = range.strip_prefix('-') {
+            old_start = start(r);
+        } else if let Some(r) = range.strip_prefix('+') {
+            new_start = start(r);
+        }
+    }
+    (old_start, new_start)
+}
+
+fn is_import_or_version_line(line: &str) -> bool {
+    static DEPENDENCY_VERSION: OnceLock<Regex> = OnceLock::new();
+    let dependency_version = DEPENDENCY_VERSION.get_or_init(|| {
+        Regex::new(r#"^[A-Za-z0-9_-]+\s*=\s*("[^"]*"|\{.*\bversion\s*=\s*"[^"]*".*\})$"#).unwrap()
+    });
+
+    let line = line.trim();
+    line.is_empty()
+        || line.starts_with("//")
+        || line.starts_with("use ")
+        || line.starts_with("pub use ")
+        || line.starts_with("pub(crate) use ")
+        || line.starts_with("extern crate ")
+        || line.starts_with("version = ")
+        || dependency_version.is_match(line)
+}
+
+/// Whether the following line is still inside a multi-line `use` declaration
+///
+/// A `use` only spans lines when it leaves a `{` open; trailing comments
+/// are ignored.
+fn use_block_continues(line: &str, in_use_block: bool) -> bool {
+    let line = line.split("//").next().unwrap_or("").trim();
+    if in_use_block {
+        return !line.ends_with(';');
+    }
+    let starts_use = line.starts_with("use ") || line.starts_with("pub use ") || line.starts_with("pub(crate) use ");
+    starts_use && line.matches('{').count() > line.matches('}').count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::crypto::signatures::SignatureManager;
+    use crate::validation::version_pinning::{VersionManifestEntry, VersionSignature};
+
+    fn rule(allowed_imports_only: bool) -> Value {
+        serde_json::json!({
+            "source_repo": "BTCDecoded/reference-node",
+            "source_pattern": "src/consensus/**",
+            "target_repo": "BTCDecoded/consensus-proof",
+            "validation_type": "no_consensus_modifications",
+            "allowed_imports_only": allowed_imports_only
+        })
+    }
+
+    fn changed(filename: &str, patch: &str) -> Vec<PullRequestFile> {
+        vec![PullRequestFile {
+            filename: filename.to_string(),
+            patch: Some(patch.to_string()),
+            status: Some("modified".to_string()),
+            previous_filename: None,
+        }]
+    }
+
+    fn validate(files: &[PullRequestFile], rule: &Value) -> Result<(), GovernanceError> {
+        CrossLayerValidator::validate_cross_layer_changes(
+            "BTCDecoded/reference-node",
+            files,
+            std::slice::from_ref(rule),
+            Path::new("unused-manifest.toml"),
+            &HashMap::new(),
+        )
+    }
+
+    const IMPORT_ONLY_PATCH: &str = "\
+@@ -1,6 +1,7 @@
+-use bitcoin::hashes::sha256d;
++use bitcoin::hashes::{sha256d, Hash};
+ use crate::types::{
+-    Block, BlockHeader,
++    Block, BlockHeader, Transaction,
+ };
++// Re-export for downstream callers
+ 
+ pub fn check_block(block: &Block) -> bool {
+";
+
+    const LOGIC_PATCH: &str = "\
+@@ -40,7 +40,7 @@ pub fn get_block_subsidy(height: u64) -> u64 {
+     let halvings = height / 210_000;
+     if halvings >= 64 {
+         return 0;
+     }
+-    (50 * COIN) >> halvings
++    (51 * COIN) >> halvings
+ }
+";
+
+    #[test]
+    fn test_import_only_change_is_allowed() {
+        let files = changed("src/consensus/block.rs", IMPORT_ONLY_PATCH);
+        assert!(validate(&files, &rule(true)).is_ok());
+
+        let manifest = changed(
+            "src/consensus/Cargo.toml",
+            "@@ -5,2 +5,2 @@ [dependencies]\n-bitcoin = \"0.31\"\n+bitcoin = { version = \"0.32\", default-features = false }\n",
+        );
+        assert!(validate(&manifest, &rule(true)).is_ok());
+    }
+
+    #[test]
+    fn test_logic_change_in_consensus_function_is_rejected() {
+        let files = changed("src/consensus/subsidy.rs", LOGIC_PATCH);
+        let err = validate(&files, &rule(true)).unwrap_err().to_string();
+        assert!(err.contains("src/consensus/subsidy.rs line 44"), "{}", err);
+        assert!(err.contains("(50 * COIN) >> halvings"), "{}", err);
+
+        // Without allowed_imports_only even import changes are rejected
+        let files = changed("src/consensus/block.rs", IMPORT_ONLY_PATCH);
+        let err = validate(&files, &rule(false)).unwrap_err().to_string();
+        assert!(err.contains("src/consensus/block.rs line 1"), "{}", err);
+    }
+
+    #[test]
+    fn test_use_line_with_trailing_comment_ends_declaration() {
+        let patch = "\
+@@ -1,2 +1,3 @@
++use crate::params::COIN; // subsidy units
++pub const MAX_MONEY: u64 = 21_000_001 * COIN;
+ use crate::types::Block;
+";
+        let files = changed("src/consensus/block.rs", patch);
+        let err = validate(&files, &rule(true)).unwrap_err().to_string();
+        assert!(err.contains("src/consensus/block.rs line 2"), "{}", err);
+    }
+
+    #[test]
+    fn test_renaming_consensus_file_out_is_rejected() {
+        let files = vec![PullRequestFile {
+            filename: "src/legacy/subsidy.rs".to_string(),
+            patch: None,
+            status: Some("renamed".to_string()),
+            previous_filename: Some("src/consensus/subsidy.rs".to_string()),
+        }];
+        let err = validate(&files, &rule(true)).unwrap_err().to_string();
+        assert!(err.contains("src/consensus/subsidy.rs is renamed to src/legacy/subsidy.rs"), "{}", err);
+    }
+
+    #[test]
+    fn test_missing_diff_fails_closed() {
+        let files = vec![PullRequestFile {
+            filename: "src/consensus/block.rs".to_string(),
+            patch: None,
+            status: Some("modified".to_string()),
+            previous_filename: None,
+        }];
+        assert!(validate(&files, &rule(true)).is_err());
+
+        // Files outside the consensus paths aren't inspected
+        let files = changed("src/network/peer.rs", LOGIC_PATCH);
+        assert!(validate(&files, &rule(true)).is_ok());
+    }
+
+    const MAINTAINERS: [&str; 6] = ["alice", "bob", "carol", "dave", "erin", "frank"];
+
+    fn maintainer_key(index: usize) -> secp256k1::SecretKey {
+        secp256k1::SecretKey::from_slice(&[0x42 + index as u8; 32]).unwrap()
+    }
+
+    fn public_key_hex(secret_key: &secp256k1::SecretKey) -> String {
+        hex::encode(SignatureManager::new().public_key_from_secret(secret_key).serialize())
+    }
+
+    /// Write a manifest whose single version is signed by every maintainer
+    fn write_signed_manifest(dir: &Path) -> std::path::PathBuf {
+        let mut entry = VersionManifestEntry {
+            version: "v1.1.0".to_string(),
+            commit_sha: "b2c3d4e5f6a7890123456789012345678901bcde".to_string(),
+            content_hash: "sha256:fedcba0987654321".to_string(),
+            created_at: chrono::Utc::now(),
+            signatures: vec![],
+            ots_timestamp: None,
+            is_stable: true,
+            is_latest: true,
+        };
+        let message = VersionPinningValidator::version_signing_message(&entry);
+        entry.signatures = MAINTAINERS
+            .iter()
+            .enumerate()
+            .map(|(i, name)| {
+                let signature = SignatureManager::new()
+                    .create_signature(&message, &maintainer_key(i))
+                    .unwrap();
+                VersionSignature {
+                    maintainer_id: name.to_string(),
+                    signature: hex::encode(signature.serialize_compact()),
+                    public_key: public_key_hex(&maintainer_key(i)),
+                    signed_at: chrono::Utc::now(),
+                }
+            })
+            .collect();
+        let mut manifest = VersionManifest {
+            repository: "orange-paper".to_string(),
+            created_at: chrono::Utc::now(),
+            versions: vec![entry],
+            latest_version: "v1.1.0".to_string(),
+            manifest_hash: String::new(),
+        };
+        manifest.manifest_hash = VersionPinningValidator::manifest_hash(&manifest);
+
+        let path = dir.join("version-manifest.yml");
+        std::fs::write(&path, serde_yaml::to_string(&manifest).unwrap()).unwrap();
+        path
+    }
+
+    #[tokio::test]
+    async fn test_version_references_checked_against_maintainer_keys() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let manifest_path = write_signed_manifest(temp_dir.path());
+
+        let database = Database::new_in_memory().await.unwrap();
+        let pool = database.pool().unwrap();
+        for (i, name) in MAINTAINERS.iter().enumerate() {
+            sqlx::query("INSERT INTO maintainers (github_username, public_key, layer) VALUES (?, ?, 1)")
+                .bind(name)
+                .bind(public_key_hex(&maintainer_key(i)))
+                .execute(pool)
+                .await
+                .unwrap();
+        }
+        let trusted_keys = CrossLayerValidator::trusted_maintainer_keys(&database)
+            .await
+            .unwrap();
+        assert_eq!(trusted_keys.len(), MAINTAINERS.len());
+
+        let rule = serde_json::json!({
+            "source_repo": "BTCDecoded/consensus-proof",
+            "source_pattern": "proofs/**",
+            "target_repo": "BTCDecoded/orange-paper",
+            "validation_type": "references_latest_version"
+        });
+        let files = changed("proofs/block.rs", "@@ -1 +1 @@\n-// orange-paper@v1.0.0\n+// orange-paper@v1.1.0\n");
+        let check = |keys: &HashMap<String, String>| {
+            CrossLayerValidator::validate_cross_layer_changes(
+                "BTCDecoded/consensus-proof",
+                &files,
+                std::slice::from_ref(&rule),
+                &manifest_path,
+                keys,
+            )
+        };
+
+        assert!(check(&trusted_keys).is_ok());
+
+        // Without the maintainers' keys no manifest signature is trusted
+        assert!(check(&HashMap::new()).is_err());
+
+        // A deactivated maintainer's key no longer counts towards the six signatures
+        sqlx::query("UPDATE maintainers SET active = 0 WHERE github_username = 'frank'")
+            .execute(pool)
+            .await
+            .unwrap();
+        let trusted_keys = CrossLayerValidator::trusted_maintainer_keys(&database)
+            .await
+            .unwrap();
+        assert!(!trusted_keys.contains_key("frank"));
+        assert!(check(&trusted_keys).is_err());
+    }
+}
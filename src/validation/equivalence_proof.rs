@@ -4,9 +4,13 @@
 //! It provides cryptographic verification that Consensus Proof implementations
 //! are equivalent to Orange Paper specifications.
 
+use crate::config::AppConfig;
 use crate::error::GovernanceError;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
 use tracing::{info, warn, error};
 use sha2::{Digest, Sha256};
 use hex;
@@ -55,10 +59,87 @@ pub enum VerificationStatus {
     Expired,
 }
 
+/// Runs a Consensus Proof implementation against a test vector's inputs
+pub trait EquivalenceExecutor: Send + Sync {
+    /// Execute `impl_ref` with `test_data` and return its output
+    fn run(&self, impl_ref: &str, test_data: &HashMap<String, String>) -> Result<String, GovernanceError>;
+}
+
+/// Executor that shells out to a harness binary
+///
+/// The harness receives `{"impl": ..., "test_data": {...}}` as JSON on stdin
+/// and must print the result on stdout, exiting zero on success.
+#[derive(Debug, Clone)]
+pub struct CommandExecutor {
+    pub program: PathBuf,
+    pub args: Vec<String>,
+}
+
+impl CommandExecutor {
+    pub fn new(program: impl Into<PathBuf>) -> Self {
+        Self {
+            program: program.into(),
+            args: Vec::new(),
+        }
+    }
+
+    pub fn with_args(mut self, args: Vec<String>) -> Self {
+        self.args = args;
+        self
+    }
+
+    /// The harness named by `EQUIVALENCE_HARNESS_PATH`, if one is configured
+    pub fn from_config(config: &AppConfig) -> Option<Self> {
+        config
+            .equivalence_harness_path
+            .as_ref()
+            .map(|path| Self::new(path).with_args(config.equivalence_harness_args.clone()))
+    }
+}
+
+impl EquivalenceExecutor for CommandExecutor {
+    fn run(&self, impl_ref: &str, test_data: &HashMap<String, String>) -> Result<String, GovernanceError> {
+        let input = serde_json::to_vec(&serde_json::json!({
+            "impl": impl_ref,
+            "test_data": test_data,
+        }))?;
+
+        let mut child = Command::new(&self.program)
+            .args(&self.args)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(|e| GovernanceError::ValidationError(format!(
+                "Failed to start equivalence harness {}: {}", self.program.display(), e
+            )))?;
+
+        if let Some(mut stdin) = child.stdin.take() {
+            stdin.write_all(&input).map_err(|e| GovernanceError::ValidationError(format!(
+                "Failed to write test vector to harness: {}", e
+            )))?;
+        }
+
+        let output = child.wait_with_output().map_err(|e| GovernanceError::ValidationError(format!(
+            "Equivalence harness failed: {}", e
+        )))?;
+        if !output.status.success() {
+            return Err(GovernanceError::ValidationError(format!(
+                "Equivalence harness exited with {}: {}",
+                output.status,
+                String::from_utf8_lossy(&output.stderr).trim()
+            )));
+        }
+
+        Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+    }
+}
+
 /// Equivalence proof validator
 pub struct EquivalenceProofValidator {
     test_vectors: HashMap<String, EquivalenceTestVector>,
     verification_rules: VerificationRules,
+    executor: Option<Box<dyn EquivalenceExecutor>>,
 }
 
 /// Rules for proof verification
@@ -92,9 +173,16 @@ impl EquivalenceProofValidator {
         Self {
             test_vectors: HashMap::new(),
             verification_rules: VerificationRules::default(),
+            executor: None,
         }
     }
 
+    /// Use `executor` to run implementations during behavioral verification
+    pub fn with_executor(mut self, executor: Box<dyn EquivalenceExecutor>) -> Self {
+        self.executor = Some(executor);
+        self
+    }
+
     pub fn load_test_vectors(&mut self, vectors: Vec<EquivalenceTestVector>) {
         for vector in vectors {
             self.test_vectors.insert(vector.test_id.clone(), vector);
@@ -102,6 +190,44 @@ impl EquivalenceProofValidator {
         info!("Loaded {} equivalence test vectors", self.test_vectors.len());
     }
 
+    /// Load test vectors from a JSON array on disk
+    ///
+    /// Every vector's `proof_hash` is recomputed; if any stored hash does not
+    /// match, nothing from the file is loaded.
+    pub fn load_test_vectors_from_file(&mut self, path: &Path) -> Result<(), GovernanceError> {
+        let content = std::fs::read_to_string(path).map_err(|e| {
+            GovernanceError::ConfigError(format!(
+                "Failed to read test vectors from {}: {}",
+                path.display(),
+                e
+            ))
+        })?;
+
+        let vectors: Vec<EquivalenceTestVector> = serde_json::from_str(&content).map_err(|e| {
+            GovernanceError::ConfigError(format!(
+                "Failed to parse test vectors in {}: {}",
+                path.display(),
+                e
+            ))
+        })?;
+
+        for vector in &vectors {
+            let computed_hash = Self::compute_proof_hash(vector);
+            if computed_hash != vector.proof_metadata.proof_hash {
+                return Err(GovernanceError::ValidationError(format!(
+                    "Proof hash mismatch for test vector {} in {}: stored {}, computed {}",
+                    vector.test_id,
+                    path.display(),
+                    vector.proof_metadata.proof_hash,
+                    computed_hash
+                )));
+            }
+        }
+
+        self.load_test_vectors(vectors);
+        Ok(())
+    }
+
     /// Generate test vectors for common consensus operations
     pub fn generate_consensus_test_vectors() -> Vec<EquivalenceTestVector> {
         let mut vectors = Vec::new();
@@ -301,22 +427,36 @@ impl EquivalenceProofValidator {
     }
 
     /// Verify behavioral equivalence between spec and implementation
+    ///
+    /// Runs the implementation through the configured executor and compares
+    /// its output with the vector's `expected_result`. Without an executor
+    /// nothing was run, so the proof fails rather than passing unchecked.
     fn verify_behavioral_equivalence(&self, vector: &EquivalenceTestVector) -> Result<(), GovernanceError> {
-        // In a real implementation, this would:
-        // 1. Parse the Orange Paper specification
-        // 2. Execute the Consensus Proof implementation with test data
-        // 3. Compare outputs to ensure they match expected behavior
-        // 4. Verify edge cases and error conditions
-
         info!("Verifying behavioral equivalence for test: {}", vector.test_id);
         
-        // For now, we'll simulate the verification
-        // In practice, this would involve actual code execution and comparison
         if vector.expected_result.is_empty() {
             return Err(GovernanceError::ValidationError("Expected result is empty".to_string()));
         }
 
-        // Simulate behavioral verification
+        let executor = match &self.executor {
+            Some(executor) => executor,
+            None => {
+                return Err(GovernanceError::ValidationError(format!(
+                    "No equivalence executor configured; test {} was not executed",
+                    vector.test_id
+                )));
+            }
+        };
+
+        let actual = executor.run(&vector.consensus_proof_impl, &vector.test_data)?;
+        if actual.trim() != vector.expected_result.trim() {
+            return Err(GovernanceError::ValidationError(format!(
+                "Implementation returned '{}' but specification expects '{}'",
+                actual.trim(),
+                vector.expected_result.trim()
+            )));
+        }
+
         Ok(())
     }
 
@@ -417,7 +557,7 @@ impl EquivalenceProofValidator {
 }
 
 /// Result of equivalence proof verification
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct VerificationResult {
     pub test_id: String,
     pub overall_status: VerificationStatus,
@@ -427,7 +567,7 @@ pub struct VerificationResult {
 }
 
 /// Individual verification step
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct VerificationStep {
     pub step: String,
     pub status: VerificationStatus,
@@ -448,10 +588,33 @@ pub struct EquivalenceProofReport {
 mod tests {
     use super::*;
 
+    /// Answers each implementation with its vector's expected result
+    struct SpecExecutor(HashMap<String, String>);
+
+    impl SpecExecutor {
+        fn for_vectors(vectors: &[EquivalenceTestVector]) -> Box<Self> {
+            Box::new(Self(
+                vectors
+                    .iter()
+                    .map(|v| (v.consensus_proof_impl.clone(), v.expected_result.clone()))
+                    .collect(),
+            ))
+        }
+    }
+
+    impl EquivalenceExecutor for SpecExecutor {
+        fn run(&self, impl_ref: &str, _test_data: &HashMap<String, String>) -> Result<String, GovernanceError> {
+            self.0.get(impl_ref).cloned().ok_or_else(|| {
+                GovernanceError::ValidationError(format!("Unknown implementation {}", impl_ref))
+            })
+        }
+    }
+
     #[test]
     fn test_equivalence_proof_validation() {
-        let mut validator = EquivalenceProofValidator::new();
         let test_vectors = EquivalenceProofValidator::generate_consensus_test_vectors();
+        let mut validator =
+            EquivalenceProofValidator::new().with_executor(SpecExecutor::for_vectors(&test_vectors));
         validator.load_test_vectors(test_vectors);
 
         // Test block validation
@@ -497,6 +660,90 @@ mod tests {
         assert_eq!(hash1, hash2);
         assert!(hash1.starts_with("sha256:"));
     }
-}
 
+    #[test]
+    fn test_load_test_vectors_from_file_round_trip() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("vectors.json");
+        let vectors = EquivalenceProofValidator::generate_consensus_test_vectors();
+        std::fs::write(&path, serde_json::to_string_pretty(&vectors).unwrap()).unwrap();
+
+        let mut validator =
+            EquivalenceProofValidator::new().with_executor(SpecExecutor::for_vectors(&vectors));
+        validator.load_test_vectors_from_file(&path).unwrap();
+        let result = validator.verify_equivalence_proof("block_validation_001").unwrap();
+        assert_eq!(result.overall_status, VerificationStatus::Verified);
+    }
+
+    #[test]
+    fn test_behavioral_equivalence_fails_closed_without_executor() {
+        let mut validator = EquivalenceProofValidator::new();
+        validator.load_test_vectors(EquivalenceProofValidator::generate_consensus_test_vectors());
+
+        let result = validator.verify_equivalence_proof("block_validation_001").unwrap();
+        assert_eq!(result.overall_status, VerificationStatus::Failed);
+        assert!(result.errors.iter().any(|e| e.contains("No equivalence executor configured")));
+    }
+
+    #[test]
+    fn test_load_test_vectors_from_file_rejects_tampered_vector() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("vectors.json");
+        let mut vectors = EquivalenceProofValidator::generate_consensus_test_vectors();
+        vectors[0].expected_result = "tampered".to_string();
+        std::fs::write(&path, serde_json::to_string(&vectors).unwrap()).unwrap();
+
+        let mut validator = EquivalenceProofValidator::new();
+        let err = validator.load_test_vectors_from_file(&path).unwrap_err();
+        assert!(err.to_string().contains("Proof hash mismatch"));
+        assert!(validator.verify_equivalence_proof(&vectors[1].test_id).is_err());
+    }
+
+    struct FixedExecutor(&'static str);
+
+    impl EquivalenceExecutor for FixedExecutor {
+        fn run(&self, _impl_ref: &str, _test_data: &HashMap<String, String>) -> Result<String, GovernanceError> {
+            Ok(self.0.to_string())
+        }
+    }
+
+    #[test]
+    fn test_behavioral_equivalence_uses_executor() {
+        let vectors = EquivalenceProofValidator::generate_consensus_test_vectors();
+        let expected = vectors
+            .iter()
+            .find(|v| v.test_id == "block_validation_001")
+            .unwrap()
+            .expected_result
+            .clone();
+        let matching: &'static str = Box::leak(expected.into_boxed_str());
+
+        let mut validator = EquivalenceProofValidator::new().with_executor(Box::new(FixedExecutor(matching)));
+        validator.load_test_vectors(vectors.clone());
+        let result = validator.verify_equivalence_proof("block_validation_001").unwrap();
+        assert_eq!(result.overall_status, VerificationStatus::Verified);
+
+        let mut validator = EquivalenceProofValidator::new()
+            .with_executor(Box::new(FixedExecutor("definitely-not-the-expected-output")));
+        validator.load_test_vectors(vectors);
+        let result = validator.verify_equivalence_proof("block_validation_001").unwrap();
+        assert_eq!(result.overall_status, VerificationStatus::Failed);
+        assert!(result.errors.iter().any(|e| e.contains("Behavioral equivalence failed")));
+    }
 
+    #[cfg(unix)]
+    #[test]
+    fn test_command_executor_reads_stdin() {
+        // `cat` echoes the JSON request back, proving it was written to stdin
+        let executor = CommandExecutor::new("cat");
+        let mut test_data = HashMap::new();
+        test_data.insert("nonce".to_string(), "42".to_string());
+
+        let output = executor.run("validate_block_header", &test_data).unwrap();
+        let parsed: serde_json::Value = serde_json::from_str(&output).unwrap();
+        assert_eq!(parsed["impl"], "validate_block_header");
+        assert_eq!(parsed["test_data"]["nonce"], "42");
+
+        assert!(CommandExecutor::new("/nonexistent/harness").run("x", &test_data).is_err());
+    }
+}
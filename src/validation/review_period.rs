@@ -1,5 +1,88 @@
 use crate::error::GovernanceError;
-use chrono::{DateTime, Duration, Utc};
+use chrono::{DateTime, Datelike, Duration, NaiveDate, Utc, Weekday};
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+/// How the days of a review period are counted
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DayCounting {
+    /// Every day counts
+    #[default]
+    Calendar,
+    /// Weekends and listed holidays don't count
+    Business,
+}
+
+impl FromStr for DayCounting {
+    type Err = GovernanceError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "calendar" => Ok(Self::Calendar),
+            "business" => Ok(Self::Business),
+            other => Err(GovernanceError::ConfigError(format!(
+                "Unknown review period day counting '{}', expected 'calendar' or 'business'",
+                other
+            ))),
+        }
+    }
+}
+
+/// Which days count toward a review period
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReviewPeriodPolicy {
+    pub counting: DayCounting,
+    /// Dates (UTC) skipped under business-day counting
+    pub holidays: Vec<NaiveDate>,
+}
+
+impl ReviewPeriodPolicy {
+    pub fn business_days(holidays: Vec<NaiveDate>) -> Self {
+        Self {
+            counting: DayCounting::Business,
+            holidays,
+        }
+    }
+
+    fn counts(&self, date: NaiveDate) -> bool {
+        match self.counting {
+            DayCounting::Calendar => true,
+            DayCounting::Business => {
+                !matches!(date.weekday(), Weekday::Sat | Weekday::Sun)
+                    && !self.holidays.contains(&date)
+            }
+        }
+    }
+
+    /// When `days` counted days have passed since `start`
+    ///
+    /// Under business-day counting each day is a step of 24 hours that only
+    /// counts if it lands on a business day, so a PR opened Friday afternoon
+    /// with one business day required is mergeable Monday afternoon.
+    pub fn end_of_period(&self, start: DateTime<Utc>, days: i64) -> DateTime<Utc> {
+        if self.counting == DayCounting::Calendar {
+            return start + Duration::try_days(days).unwrap_or_default();
+        }
+        let mut end = start;
+        let mut counted = 0;
+        while counted < days {
+            end += Duration::days(1);
+            if self.counts(end.date_naive()) {
+                counted += 1;
+            }
+        }
+        end
+    }
+
+    /// Unit for status text: "days" or "business days"
+    pub fn unit(&self) -> &'static str {
+        match self.counting {
+            DayCounting::Calendar => "days",
+            DayCounting::Business => "business days",
+        }
+    }
+}
 
 pub struct ReviewPeriodValidator;
 
@@ -8,24 +91,34 @@ impl ReviewPeriodValidator {
         opened_at: DateTime<Utc>,
         required_days: i64,
         emergency_mode: bool,
+    ) -> Result<bool, GovernanceError> {
+        Self::validate_review_period_with_policy(
+            opened_at,
+            required_days,
+            emergency_mode,
+            &ReviewPeriodPolicy::default(),
+        )
+    }
+
+    pub fn validate_review_period_with_policy(
+        opened_at: DateTime<Utc>,
+        required_days: i64,
+        emergency_mode: bool,
+        policy: &ReviewPeriodPolicy,
     ) -> Result<bool, GovernanceError> {
         let now = Utc::now();
         let elapsed = now - opened_at;
+        let earliest_merge =
+            Self::get_earliest_merge_date_with_policy(opened_at, required_days, emergency_mode, policy);
 
-        // Emergency mode reduces review period to 30 days
-        let required_duration = if emergency_mode {
-            Duration::try_days(30).unwrap_or_default()
-        } else {
-            Duration::try_days(required_days).unwrap_or_default()
-        };
-
-        if elapsed >= required_duration {
+        if now >= earliest_merge {
             Ok(true)
         } else {
-            let remaining = required_duration - elapsed;
+            let remaining = earliest_merge - now;
             Err(GovernanceError::ReviewPeriodError(format!(
-                "Review period not met. Required: {} days, Elapsed: {} days, Remaining: {} days",
+                "Review period not met. Required: {} {}, Elapsed: {} days, Remaining: {} days",
                 required_days,
+                policy.unit(),
                 elapsed.num_days(),
                 remaining.num_days()
             )))
@@ -37,13 +130,23 @@ impl ReviewPeriodValidator {
         required_days: i64,
         emergency_mode: bool,
     ) -> DateTime<Utc> {
-        let required_duration = if emergency_mode {
-            Duration::try_days(30).unwrap_or_default()
-        } else {
-            Duration::try_days(required_days).unwrap_or_default()
-        };
+        Self::get_earliest_merge_date_with_policy(
+            opened_at,
+            required_days,
+            emergency_mode,
+            &ReviewPeriodPolicy::default(),
+        )
+    }
 
-        opened_at + required_duration
+    pub fn get_earliest_merge_date_with_policy(
+        opened_at: DateTime<Utc>,
+        required_days: i64,
+        emergency_mode: bool,
+        policy: &ReviewPeriodPolicy,
+    ) -> DateTime<Utc> {
+        // Emergency mode reduces review period to 30 days
+        let required_days = if emergency_mode { 30 } else { required_days };
+        policy.end_of_period(opened_at, required_days)
     }
 
     pub fn get_remaining_days(
@@ -51,16 +154,73 @@ impl ReviewPeriodValidator {
         required_days: i64,
         emergency_mode: bool,
     ) -> i64 {
-        let now = Utc::now();
-        let elapsed = now - opened_at;
+        Self::get_remaining_days_with_policy(
+            opened_at,
+            required_days,
+            emergency_mode,
+            &ReviewPeriodPolicy::default(),
+        )
+    }
 
-        let required_duration = if emergency_mode {
-            Duration::try_days(30).unwrap_or_default()
-        } else {
-            Duration::try_days(required_days).unwrap_or_default()
-        };
+    /// Calendar days left until the period ends, however its days are counted
+    pub fn get_remaining_days_with_policy(
+        opened_at: DateTime<Utc>,
+        required_days: i64,
+        emergency_mode: bool,
+        policy: &ReviewPeriodPolicy,
+    ) -> i64 {
+        let earliest_merge =
+            Self::get_earliest_merge_date_with_policy(opened_at, required_days, emergency_mode, policy);
+        (earliest_merge - Utc::now()).num_days().max(0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    /// Friday 2024-03-01, midday UTC
+    fn friday() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2024, 3, 1, 12, 0, 0).unwrap()
+    }
+
+    #[test]
+    fn test_friday_pr_under_calendar_and_business_days() {
+        let calendar = ReviewPeriodValidator::get_earliest_merge_date(friday(), 7, false);
+        assert_eq!(calendar, Utc.with_ymd_and_hms(2024, 3, 8, 12, 0, 0).unwrap());
+
+        // Both weekends are skipped: Mon 4th .. Fri 8th, Mon 11th, Tue 12th
+        let business = ReviewPeriodValidator::get_earliest_merge_date_with_policy(
+            friday(),
+            7,
+            false,
+            &ReviewPeriodPolicy::business_days(vec![]),
+        );
+        assert_eq!(business, Utc.with_ymd_and_hms(2024, 3, 12, 12, 0, 0).unwrap());
+
+        let with_holiday = ReviewPeriodValidator::get_earliest_merge_date_with_policy(
+            friday(),
+            7,
+            false,
+            &ReviewPeriodPolicy::business_days(vec![NaiveDate::from_ymd_opt(2024, 3, 4).unwrap()]),
+        );
+        assert_eq!(with_holiday, Utc.with_ymd_and_hms(2024, 3, 13, 12, 0, 0).unwrap());
+    }
+
+    #[test]
+    fn test_business_days_can_still_be_pending_when_calendar_days_are_met() {
+        // Opened 8 calendar days ago, which always spans a weekend
+        let opened_at = Utc::now() - Duration::days(8);
+        assert!(ReviewPeriodValidator::validate_review_period(opened_at, 7, false).is_ok());
 
-        let remaining = required_duration - elapsed;
-        remaining.num_days().max(0)
+        let err = ReviewPeriodValidator::validate_review_period_with_policy(
+            opened_at,
+            7,
+            false,
+            &ReviewPeriodPolicy::business_days(vec![]),
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("Required: 7 business days"), "{}", err);
     }
 }
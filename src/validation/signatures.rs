@@ -40,8 +40,12 @@ impl SignatureValidator {
         }
     }
 
+    /// Count signatures over `message` from known maintainers against a threshold
+    ///
+    /// `message` is normally the PR's `build_signing_message`.
     pub fn verify_multisig_threshold(
         &self,
+        message: &str,
         signatures: &[(String, String)],    // (signer, signature)
         required_threshold: (usize, usize), // (required, total)
         maintainer_keys: &std::collections::HashMap<String, String>, // username -> public_key
@@ -51,10 +55,7 @@ impl SignatureValidator {
 
         for (signer, signature) in signatures {
             if let Some(public_key) = maintainer_keys.get(signer) {
-                // Create message for signature verification
-                let message = format!("governance-signature:{}", signer);
-
-                if self.verify_signature(&message, signature, public_key)? {
+                if self.verify_signature(message, signature, public_key)? {
                     valid_signatures += 1;
                 }
             }
@@ -145,6 +145,22 @@ impl ThresholdValidator {
         }
     }
 
+    /// Get the default economic node quorum for a tier
+    ///
+    /// Overridden by `economic_quorum` in action-tiers.yml when present.
+    pub fn get_tier_economic_quorum(tier: u32) -> u32 {
+        match tier {
+            3 => 3, // Tier 3: Consensus-adjacent
+            5 => 5, // Tier 5: Governance
+            _ => 0, // No economic input required
+        }
+    }
+
+    /// Check whether enough economic nodes have weighed in on a PR
+    pub fn economic_quorum_met(tier: u32, signal_count: u32, quorum: u32) -> bool {
+        tier < 3 || signal_count >= quorum
+    }
+
     /// Check if economic veto is required for the given layer and tier
     pub fn requires_economic_veto(_layer: i32, tier: u32) -> bool {
         // Economic veto is required for Tier 3+ regardless of layer
@@ -4,11 +4,18 @@
 //! specific Orange Paper versions. It ensures version references are cryptographically verified
 //! and prevents outdated version references.
 
+use crate::crypto::signatures::SignatureManager;
 use crate::error::GovernanceError;
+use secp256k1::{ecdsa::Signature as EcdsaSignature, PublicKey};
 use serde::{Deserialize, Serialize};
 use sha2::{Digest, Sha256};
-use std::collections::HashMap;
+use regex::Regex;
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
 use std::str::FromStr;
+#[cfg(test)]
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::OnceLock;
 use tracing::{info, warn, error, debug};
 
 /// Version reference found in code
@@ -113,9 +120,50 @@ impl Default for VersionPinningConfig {
     }
 }
 
+/// Compiled patterns for the `@orange-paper-*` reference comments
+struct VersionReferencePatterns {
+    version: Regex,
+    commit: Regex,
+    content_hash: Regex,
+}
+
+static VERSION_REFERENCE_PATTERNS: OnceLock<Result<VersionReferencePatterns, String>> =
+    OnceLock::new();
+
+/// Number of times the reference patterns have been compiled (expected: once)
+#[cfg(test)]
+static PATTERN_COMPILATIONS: AtomicUsize = AtomicUsize::new(0);
+
+/// Parse a hex-encoded SEC1 public key
+pub(crate) fn parse_public_key(public_key: &str) -> Result<PublicKey, String> {
+    let bytes = hex::decode(public_key).map_err(|e| format!("invalid hex: {}", e))?;
+    PublicKey::from_slice(&bytes).map_err(|e| e.to_string())
+}
+
+/// Get the reference patterns, compiling them on first use
+fn version_reference_patterns() -> Result<&'static VersionReferencePatterns, GovernanceError> {
+    VERSION_REFERENCE_PATTERNS
+        .get_or_init(|| {
+            #[cfg(test)]
+            PATTERN_COMPILATIONS.fetch_add(1, Ordering::Relaxed);
+            let compile = |pattern: &str| Regex::new(pattern).map_err(|e| e.to_string());
+            Ok(VersionReferencePatterns {
+                version: compile(r"@orange-paper-version:\s*(v?\d+\.\d+\.\d+)")?,
+                commit: compile(r"@orange-paper-commit:\s*([a-f0-9]{40})")?,
+                content_hash: compile(r"@orange-paper-hash:\s*(sha256:[a-f0-9]{64})")?,
+            })
+        })
+        .as_ref()
+        .map_err(|e| {
+            GovernanceError::ConfigError(format!("Invalid version reference pattern: {}", e))
+        })
+}
+
 pub struct VersionPinningValidator {
     config: VersionPinningConfig,
     version_manifest: Option<VersionManifest>,
+    /// Registered maintainer keys by maintainer id; only these can sign versions
+    trusted_keys: HashMap<String, PublicKey>,
 }
 
 impl VersionPinningValidator {
@@ -124,9 +172,30 @@ impl VersionPinningValidator {
         Self {
             config,
             version_manifest: None,
+            trusted_keys: HashMap::new(),
         }
     }
 
+    /// Trust these maintainer keys (maintainer id to hex SEC1 public key)
+    ///
+    /// Manifest signatures are verified against these rather than the key
+    /// embedded next to each signature. Without any, no manifest can load.
+    pub fn with_trusted_keys<I>(mut self, keys: I) -> Result<Self, GovernanceError>
+    where
+        I: IntoIterator<Item = (String, String)>,
+    {
+        for (maintainer_id, public_key) in keys {
+            let public_key = parse_public_key(&public_key).map_err(|e| {
+                GovernanceError::CryptoError(format!(
+                    "Invalid public key for maintainer {}: {}",
+                    maintainer_id, e
+                ))
+            })?;
+            self.trusted_keys.insert(maintainer_id, public_key);
+        }
+        Ok(self)
+    }
+
     /// Load version manifest from configuration
     pub fn load_version_manifest(&mut self, manifest: VersionManifest) -> Result<(), GovernanceError> {
         // Verify manifest integrity
@@ -145,12 +214,14 @@ impl VersionPinningValidator {
         file_path: &str,
         content: &str,
     ) -> Result<Vec<VersionReference>, GovernanceError> {
+        let patterns = version_reference_patterns()?;
         let mut references = Vec::new();
-        let lines: Vec<&str> = content.lines().collect();
 
-        for (line_num, line) in lines.iter().enumerate() {
+        for (line_num, line) in content.lines().enumerate() {
             // Look for version reference patterns
-            if let Some(reference) = self.extract_version_reference(file_path, line_num + 1, line) {
+            if let Some(reference) =
+                self.extract_version_reference(patterns, file_path, line_num + 1, line)
+            {
                 references.push(reference);
             }
         }
@@ -162,17 +233,20 @@ impl VersionPinningValidator {
     /// Extract version reference from a single line
     fn extract_version_reference(
         &self,
+        patterns: &VersionReferencePatterns,
         file_path: &str,
         line_number: usize,
         line: &str,
     ) -> Option<VersionReference> {
         let trimmed = line.trim();
+
+        // Cheap pre-filter: every reference form carries this marker
+        if !trimmed.contains("@orange-paper") {
+            return None;
+        }
         
         // Pattern: @orange-paper-version: v1.2.3
-        if let Some(captures) = regex::Regex::new(r"@orange-paper-version:\s*(v?\d+\.\d+\.\d+)")
-            .unwrap()
-            .captures(trimmed)
-        {
+        if let Some(captures) = patterns.version.captures(trimmed) {
             return Some(VersionReference {
                 file_path: file_path.to_string(),
                 line_number,
@@ -185,10 +259,7 @@ impl VersionPinningValidator {
         }
 
         // Pattern: @orange-paper-commit: abc123def456
-        if let Some(captures) = regex::Regex::new(r"@orange-paper-commit:\s*([a-f0-9]{40})")
-            .unwrap()
-            .captures(trimmed)
-        {
+        if let Some(captures) = patterns.commit.captures(trimmed) {
             return Some(VersionReference {
                 file_path: file_path.to_string(),
                 line_number,
@@ -201,10 +272,7 @@ impl VersionPinningValidator {
         }
 
         // Pattern: @orange-paper-hash: sha256:fedcba...
-        if let Some(captures) = regex::Regex::new(r"@orange-paper-hash:\s*(sha256:[a-f0-9]{64})")
-            .unwrap()
-            .captures(trimmed)
-        {
+        if let Some(captures) = patterns.content_hash.captures(trimmed) {
             return Some(VersionReference {
                 file_path: file_path.to_string(),
                 line_number,
@@ -232,30 +300,55 @@ impl VersionPinningValidator {
         None
     }
 
-    /// Verify version signature
+    /// Canonical message a maintainer signs for a manifest entry
+    pub fn version_signing_message(entry: &VersionManifestEntry) -> String {
+        format!(
+            "orange-paper-version:{}\ncommit:{}\ncontent-hash:{}",
+            entry.version, entry.commit_sha, entry.content_hash
+        )
+    }
+
+    /// Verify a maintainer's secp256k1 signature over a manifest entry
+    ///
+    /// The signature is checked against the trusted key registered for
+    /// `signature.maintainer_id`; the `public_key` carried in the manifest is
+    /// not trusted. `signature.signature` is a hex-encoded compact (64-byte)
+    /// ECDSA signature. A malformed signature is an error; one from an
+    /// unregistered maintainer, or that doesn't match, returns `Ok(false)`.
     pub fn verify_version_signature(
         &self,
+        entry: &VersionManifestEntry,
         signature: &VersionSignature,
-        public_key: &str,
     ) -> Result<bool, GovernanceError> {
-        // In a real implementation, this would:
-        // 1. Parse the public key
-        // 2. Verify the signature against the version data
-        // 3. Check signature format and validity
-        
-        // For now, we'll implement a basic validation
-        if signature.signature.is_empty() || public_key.is_empty() {
-            return Ok(false);
-        }
-
-        // Basic format validation
-        if signature.signature.len() < 64 || public_key.len() < 64 {
-            return Ok(false);
-        }
+        let public_key = match self.trusted_keys.get(&signature.maintainer_id) {
+            Some(public_key) => public_key,
+            None => {
+                warn!(
+                    "Version {} signed by unregistered maintainer {}",
+                    entry.version, signature.maintainer_id
+                );
+                return Ok(false);
+            }
+        };
 
-        // In a real implementation, we would use secp256k1 to verify the signature
-        // For now, we'll just return true for valid-looking signatures
-        Ok(true)
+        let signature_bytes = hex::decode(&signature.signature).map_err(|e| {
+            GovernanceError::CryptoError(format!(
+                "Invalid signature hex from maintainer {}: {}",
+                signature.maintainer_id, e
+            ))
+        })?;
+        let ecdsa_signature = EcdsaSignature::from_compact(&signature_bytes).map_err(|e| {
+            GovernanceError::CryptoError(format!(
+                "Invalid signature from maintainer {}: {}",
+                signature.maintainer_id, e
+            ))
+        })?;
+
+        SignatureManager::new().verify_signature(
+            &Self::version_signing_message(entry),
+            &ecdsa_signature,
+            public_key,
+        )
     }
 
     /// Check version compatibility
@@ -427,23 +520,24 @@ impl VersionPinningValidator {
             ));
         }
 
-        // Verify signatures for each version
+        // Verify signatures for each version, counting each maintainer once
         for version in &manifest.versions {
-            if version.signatures.len() < self.config.minimum_signatures {
-                return Err(GovernanceError::ValidationError(format!(
-                    "Version {} has insufficient signatures: {} < {}",
-                    version.version, version.signatures.len(), self.config.minimum_signatures
-                )));
-            }
-
-            // Verify each signature
+            let mut signers = HashSet::new();
             for signature in &version.signatures {
-                if !self.verify_version_signature(signature, &signature.public_key)? {
+                if !self.verify_version_signature(version, signature)? {
                     return Err(GovernanceError::ValidationError(format!(
                         "Invalid signature for version {} by maintainer {}",
                         version.version, signature.maintainer_id
                     )));
                 }
+                signers.insert(signature.maintainer_id.as_str());
+            }
+
+            if signers.len() < self.config.minimum_signatures {
+                return Err(GovernanceError::ValidationError(format!(
+                    "Version {} has insufficient signatures: {} distinct signers < {}",
+                    version.version, signers.len(), self.config.minimum_signatures
+                )));
             }
         }
 
@@ -452,15 +546,69 @@ impl VersionPinningValidator {
 
     /// Compute manifest hash
     fn compute_manifest_hash(&self, manifest: &VersionManifest) -> String {
+        Self::manifest_hash(manifest)
+    }
+
+    /// Hash of the manifest's JSON serialization with `manifest_hash` blanked
+    pub fn manifest_hash(manifest: &VersionManifest) -> String {
         let mut hasher = Sha256::new();
         
         // Hash the manifest data (excluding the hash field itself)
-        let manifest_data = serde_json::to_string(&manifest).unwrap_or_default();
+        let mut unhashed = manifest.clone();
+        unhashed.manifest_hash = String::new();
+        let manifest_data = serde_json::to_string(&unhashed).unwrap_or_default();
         hasher.update(manifest_data.as_bytes());
         
         format!("sha256:{}", hex::encode(hasher.finalize()))
     }
 
+    /// Read a `version-manifest.yml` from disk and check its `manifest_hash`
+    ///
+    /// Signatures are checked separately by `load_version_manifest`.
+    pub fn load_manifest_from_file(path: &Path) -> Result<VersionManifest, GovernanceError> {
+        let content = match std::fs::read_to_string(path) {
+            Ok(content) => content,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+                return Err(GovernanceError::ConfigError(format!(
+                    "Version manifest not found at {}",
+                    path.display()
+                )))
+            }
+            Err(e) => {
+                return Err(GovernanceError::ConfigError(format!(
+                    "Failed to read version manifest {}: {}",
+                    path.display(),
+                    e
+                )))
+            }
+        };
+
+        let manifest: VersionManifest = serde_yaml::from_str(&content).map_err(|e| {
+            GovernanceError::ConfigError(format!(
+                "Failed to parse version manifest {}: {}",
+                path.display(),
+                e
+            ))
+        })?;
+
+        let computed = Self::manifest_hash(&manifest);
+        if computed != manifest.manifest_hash {
+            return Err(GovernanceError::ValidationError(format!(
+                "Version manifest hash mismatch in {}: recorded {}, computed {}",
+                path.display(),
+                manifest.manifest_hash,
+                computed
+            )));
+        }
+
+        info!(
+            "Loaded version manifest for {} with {} versions",
+            manifest.repository,
+            manifest.versions.len()
+        );
+        Ok(manifest)
+    }
+
     /// Generate version reference format
     pub fn generate_reference_format(
         &self,
@@ -555,6 +703,178 @@ mod tests {
         let status = validator.check_version_compatibility("v1.0.0", &manifest).unwrap();
         assert_eq!(status, ValidationStatus::Invalid);
     }
-}
 
+    fn public_key_hex(secret_key: &secp256k1::SecretKey) -> String {
+        hex::encode(SignatureManager::new().public_key_from_secret(secret_key).serialize())
+    }
+
+    fn trusting(keys: &[(&str, &secp256k1::SecretKey)]) -> VersionPinningValidator {
+        VersionPinningValidator::default()
+            .with_trusted_keys(keys.iter().map(|(id, key)| (id.to_string(), public_key_hex(key))))
+            .unwrap()
+    }
+
+    fn sign_as(
+        maintainer_id: &str,
+        entry: &VersionManifestEntry,
+        secret_key: &secp256k1::SecretKey,
+    ) -> VersionSignature {
+        let signature = SignatureManager::new()
+            .create_signature(&VersionPinningValidator::version_signing_message(entry), secret_key)
+            .unwrap();
+        VersionSignature {
+            maintainer_id: maintainer_id.to_string(),
+            signature: hex::encode(signature.serialize_compact()),
+            public_key: public_key_hex(secret_key),
+            signed_at: chrono::Utc::now(),
+        }
+    }
+
+    fn signed_entry(secret_key: &secp256k1::SecretKey) -> (VersionManifestEntry, VersionSignature) {
+        let entry = VersionManifestEntry {
+            version: "v1.2.3".to_string(),
+            commit_sha: "abc123def456789".to_string(),
+            content_hash: "sha256:fedcba123456".to_string(),
+            created_at: chrono::Utc::now(),
+            signatures: vec![],
+            ots_timestamp: None,
+            is_stable: true,
+            is_latest: true,
+        };
+        let version_signature = sign_as("alice", &entry, secret_key);
+        (entry, version_signature)
+    }
+
+    #[test]
+    fn test_verify_version_signature() {
+        let secret_key = secp256k1::SecretKey::from_slice(&[0x42; 32]).unwrap();
+        let validator = trusting(&[("alice", &secret_key)]);
+        let (entry, signature) = signed_entry(&secret_key);
+
+        assert!(validator.verify_version_signature(&entry, &signature).unwrap());
+
+        // Signature doesn't cover a different commit
+        let mut moved = entry.clone();
+        moved.commit_sha = "0000000000000000".to_string();
+        assert!(!validator.verify_version_signature(&moved, &signature).unwrap());
+    }
+
+    #[test]
+    fn test_tampered_version_signature_rejected() {
+        let secret_key = secp256k1::SecretKey::from_slice(&[0x42; 32]).unwrap();
+        let validator = trusting(&[("alice", &secret_key)]);
+        let (entry, mut signature) = signed_entry(&secret_key);
+
+        let mut bytes = hex::decode(&signature.signature).unwrap();
+        bytes[10] ^= 0x01;
+        signature.signature = hex::encode(bytes);
+        assert!(!validator.verify_version_signature(&entry, &signature).unwrap_or(false));
+
+        // Malformed inputs are errors rather than silent passes
+        let bad_key = VersionPinningValidator::default()
+            .with_trusted_keys([("alice".to_string(), "02deadbeef".to_string())]);
+        assert!(bad_key.is_err());
+
+        let (_, mut bad_sig) = signed_entry(&secret_key);
+        bad_sig.signature = "not-hex".to_string();
+        assert!(validator.verify_version_signature(&entry, &bad_sig).is_err());
+    }
+
+    #[test]
+    fn test_manifest_signatures_checked_against_trusted_keys() {
+        let alice = secp256k1::SecretKey::from_slice(&[0x42; 32]).unwrap();
+        let bob = secp256k1::SecretKey::from_slice(&[0x43; 32]).unwrap();
+        let mallory = secp256k1::SecretKey::from_slice(&[0x44; 32]).unwrap();
+        let (entry, alice_signature) = signed_entry(&alice);
+
+        let manifest_with = |signatures: Vec<VersionSignature>| {
+            let mut manifest = VersionManifest {
+                repository: "orange-paper".to_string(),
+                created_at: chrono::Utc::now(),
+                versions: vec![VersionManifestEntry { signatures, ..entry.clone() }],
+                latest_version: entry.version.clone(),
+                manifest_hash: String::new(),
+            };
+            manifest.manifest_hash = VersionPinningValidator::manifest_hash(&manifest);
+            manifest
+        };
+        let validator = || {
+            let mut validator = trusting(&[("alice", &alice), ("bob", &bob)]);
+            validator.config.minimum_signatures = 2;
+            validator
+        };
 
+        // Repeating one maintainer's signature doesn't reach the threshold
+        let repeated = manifest_with(vec![alice_signature.clone(), alice_signature.clone()]);
+        assert!(validator().load_version_manifest(repeated).is_err());
+
+        // A self-consistent signature under a key bob never registered is rejected
+        let forged = manifest_with(vec![alice_signature.clone(), sign_as("bob", &entry, &mallory)]);
+        assert!(validator().load_version_manifest(forged).is_err());
+
+        let signed = manifest_with(vec![alice_signature, sign_as("bob", &entry, &bob)]);
+        assert!(validator().load_version_manifest(signed).is_ok());
+    }
+
+    #[test]
+    fn test_reference_patterns_compiled_once() {
+        let validator = VersionPinningValidator::default();
+        let commit = "a".repeat(40);
+        let hash = format!("sha256:{}", "b".repeat(64));
+
+        let mut content = String::new();
+        for i in 0..5_000 {
+            match i % 500 {
+                0 => content.push_str("// @orange-paper-version: v1.2.3\n"),
+                1 => content.push_str(&format!("// @orange-paper-commit: {}\n", commit)),
+                2 => content.push_str(&format!("// @orange-paper-hash: {}\n", hash)),
+                _ => content.push_str(&format!("let value_{} = compute({});\n", i, i)),
+            }
+        }
+
+        let first = validator.parse_version_references("big.rs", &content).unwrap();
+        let second = validator.parse_version_references("big.rs", &content).unwrap();
+
+        assert_eq!(first.len(), 30);
+        assert_eq!(first, second);
+        assert_eq!(PATTERN_COMPILATIONS.load(Ordering::Relaxed), 1);
+    }
+
+    fn fixture_path() -> std::path::PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/version-manifest.yml")
+    }
+
+    #[test]
+    fn test_load_manifest_from_file() {
+        let manifest = VersionPinningValidator::load_manifest_from_file(&fixture_path()).unwrap();
+
+        assert_eq!(manifest.repository, "orange-paper");
+        assert_eq!(manifest.versions.len(), 2);
+        assert_eq!(manifest.versions[0].version, "v1.0.0");
+        assert_eq!(manifest.versions[1].version, "v1.1.0");
+        assert!(manifest.versions[1].is_latest);
+        assert_eq!(manifest.latest_version, "v1.1.0");
+    }
+
+    #[test]
+    fn test_load_manifest_errors_are_distinct() {
+        let missing = VersionPinningValidator::load_manifest_from_file(Path::new(
+            "/nonexistent/version-manifest.yml",
+        ));
+        match missing {
+            Err(GovernanceError::ConfigError(msg)) => assert!(msg.contains("not found")),
+            other => panic!("expected missing-file error, got {:?}", other.map(|_| ())),
+        }
+
+        let content = std::fs::read_to_string(fixture_path()).unwrap();
+        let tampered = content.replace("latest_version: v1.1.0", "latest_version: v1.0.0");
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("version-manifest.yml");
+        std::fs::write(&path, tampered).unwrap();
+
+        match VersionPinningValidator::load_manifest_from_file(&path) {
+            Err(GovernanceError::ValidationError(msg)) => assert!(msg.contains("hash mismatch")),
+            other => panic!("expected hash mismatch, got {:?}", other.map(|_| ())),
+        }
+    }
+}
@@ -0,0 +1,381 @@
+//! Governance Commands
+//!
+//! Maintainers and economic nodes drive governance from PR comments with
+//! `/governance-*` commands. This module turns a comment body into a
+//! [`GovernanceCommand`] and decides whether the commenter may run it;
+//! `comment.rs` dispatches the parsed command.
+
+use std::fmt;
+
+use crate::crypto::signatures::SignatureScheme;
+use crate::database::Database;
+use crate::economic_nodes::registry::EconomicNodeRegistry;
+use crate::error::GovernanceError;
+use crate::validation::tier_classification;
+
+/// Every governance command starts with this
+pub const COMMAND_PREFIX: &str = "/governance";
+
+pub const SIGN_USAGE: &str = "/governance-sign [ecdsa|schnorr] <signature>";
+pub const TIER_USAGE: &str = "/governance-tier <1-5> <rationale>";
+pub const OVERRIDE_USAGE: &str = "/governance-override clear <rationale>";
+pub const VETO_USAGE: &str = "/governance-veto <signature> <reason>";
+pub const STATUS_USAGE: &str = "/governance-status";
+
+/// Usage line and what each command does, in the order shown in help replies
+const COMMANDS: [(&str, &str); 5] = [
+    (SIGN_USAGE, "sign off on the PR as a maintainer of its layer"),
+    (TIER_USAGE, "override the PR's tier classification"),
+    (OVERRIDE_USAGE, "drop a manual tier override and use the automatic classification"),
+    (VETO_USAGE, "signal a veto as a registered economic node"),
+    (STATUS_USAGE, "re-evaluate the PR and refresh its status checks"),
+];
+
+/// A command parsed from a PR comment
+#[derive(Debug, Clone, PartialEq)]
+pub enum GovernanceCommand {
+    Sign {
+        scheme: SignatureScheme,
+        signature: String,
+    },
+    Tier {
+        tier: u32,
+        rationale: String,
+    },
+    ClearOverride {
+        rationale: String,
+    },
+    Veto {
+        signature: String,
+        rationale: String,
+    },
+    Status,
+}
+
+/// Who may run a command
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Permission {
+    Anyone,
+    /// A registered maintainer; layer and suspension are checked when signing
+    Maintainer,
+    /// A member of a group in `manual_override.permissions`
+    TierOverride,
+    /// The GitHub account of an active economic node
+    EconomicNode,
+}
+
+impl Permission {
+    fn describe(&self) -> &'static str {
+        match self {
+            Permission::Anyone => "anyone",
+            Permission::Maintainer => "registered maintainers",
+            Permission::TierOverride => "the groups listed in `manual_override.permissions`",
+            Permission::EconomicNode => "active economic nodes with a registered GitHub account",
+        }
+    }
+}
+
+impl GovernanceCommand {
+    pub fn name(&self) -> &'static str {
+        match self {
+            GovernanceCommand::Sign { .. } => "/governance-sign",
+            GovernanceCommand::Tier { .. } => "/governance-tier",
+            GovernanceCommand::ClearOverride { .. } => "/governance-override",
+            GovernanceCommand::Veto { .. } => "/governance-veto",
+            GovernanceCommand::Status => "/governance-status",
+        }
+    }
+
+    pub fn permission(&self) -> Permission {
+        match self {
+            GovernanceCommand::Sign { .. } => Permission::Maintainer,
+            GovernanceCommand::Tier { .. } | GovernanceCommand::ClearOverride { .. } => {
+                Permission::TierOverride
+            }
+            GovernanceCommand::Veto { .. } => Permission::EconomicNode,
+            GovernanceCommand::Status => Permission::Anyone,
+        }
+    }
+
+    /// Reply posted when the commenter lacks the command's permission
+    pub fn denial(&self) -> String {
+        format!(
+            "❌ Rejected: `{}` is limited to {}.",
+            self.name(),
+            self.permission().describe()
+        )
+    }
+}
+
+/// Why a `/governance` comment could not be parsed
+#[derive(Debug, Clone, PartialEq)]
+pub enum CommandError {
+    Unknown(String),
+    Usage {
+        usage: &'static str,
+        problem: String,
+    },
+}
+
+impl CommandError {
+    fn usage(usage: &'static str, problem: &str) -> Self {
+        CommandError::Usage {
+            usage,
+            problem: problem.to_string(),
+        }
+    }
+
+    /// Reply posted on the PR explaining how to fix the command
+    pub fn reply(&self) -> String {
+        match self {
+            CommandError::Unknown(_) => format!("{}.\n\n{}", self, help()),
+            CommandError::Usage { .. } => self.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for CommandError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CommandError::Unknown(name) => write!(f, "Unknown governance command `{}`", name),
+            CommandError::Usage { usage, problem } => write!(f, "{}. Usage: `{}`", problem, usage),
+        }
+    }
+}
+
+/// Markdown list of the available commands
+pub fn help() -> String {
+    let mut body = "Available commands:\n".to_string();
+    for (usage, description) in COMMANDS {
+        body.push_str(&format!("- `{}`: {}\n", usage, description));
+    }
+    body
+}
+
+/// Parse a comment body
+///
+/// Returns `None` for comments that aren't governance commands at all. The
+/// command must open the comment; everything after it, including later
+/// lines, is its arguments.
+pub fn parse(body: &str) -> Option<Result<GovernanceCommand, CommandError>> {
+    let (name, arguments) = split_first(body);
+    if !name.starts_with(COMMAND_PREFIX) {
+        return None;
+    }
+
+    Some(match name {
+        "/governance-sign" => parse_sign(arguments),
+        "/governance-tier" => parse_tier(arguments),
+        "/governance-override" => parse_override(arguments),
+        "/governance-veto" => parse_veto(arguments),
+        "/governance-status" => Ok(GovernanceCommand::Status),
+        other => Err(CommandError::Unknown(other.to_string())),
+    })
+}
+
+/// Split off the first whitespace-separated token, trimming both parts
+fn split_first(text: &str) -> (&str, &str) {
+    let text = text.trim();
+    match text.split_once(char::is_whitespace) {
+        Some((first, rest)) => (first, rest.trim()),
+        None => (text, ""),
+    }
+}
+
+fn parse_sign(arguments: &str) -> Result<GovernanceCommand, CommandError> {
+    // `/governance-sign schnorr <sig>` selects BIP340; a bare signature is ECDSA
+    let (scheme, signature) = match split_first(arguments) {
+        (scheme, signature) if !signature.is_empty() => match scheme.parse::<SignatureScheme>() {
+            Ok(scheme) => (scheme, signature),
+            Err(_) => (SignatureScheme::default(), arguments),
+        },
+        _ => (SignatureScheme::default(), arguments),
+    };
+    if signature.is_empty() {
+        return Err(CommandError::usage(SIGN_USAGE, "Missing signature"));
+    }
+    Ok(GovernanceCommand::Sign {
+        scheme,
+        signature: signature.to_string(),
+    })
+}
+
+fn parse_tier(arguments: &str) -> Result<GovernanceCommand, CommandError> {
+    let (tier, rationale) = split_first(arguments);
+    let tier = match tier.parse::<u32>() {
+        Ok(tier) if (1..=5).contains(&tier) => tier,
+        _ => return Err(CommandError::usage(TIER_USAGE, "Tier must be a number from 1 to 5")),
+    };
+    if rationale.is_empty() {
+        return Err(CommandError::usage(TIER_USAGE, "A tier override needs a rationale"));
+    }
+    Ok(GovernanceCommand::Tier {
+        tier,
+        rationale: rationale.to_string(),
+    })
+}
+
+fn parse_override(arguments: &str) -> Result<GovernanceCommand, CommandError> {
+    let (action, rationale) = split_first(arguments);
+    if action != "clear" {
+        return Err(CommandError::usage(OVERRIDE_USAGE, "The only override action is `clear`"));
+    }
+    if rationale.is_empty() {
+        return Err(CommandError::usage(OVERRIDE_USAGE, "Clearing an override needs a rationale"));
+    }
+    Ok(GovernanceCommand::ClearOverride {
+        rationale: rationale.to_string(),
+    })
+}
+
+fn parse_veto(arguments: &str) -> Result<GovernanceCommand, CommandError> {
+    let (signature, rationale) = split_first(arguments);
+    if signature.is_empty() {
+        return Err(CommandError::usage(VETO_USAGE, "Missing signature"));
+    }
+    if rationale.is_empty() {
+        return Err(CommandError::usage(VETO_USAGE, "A veto needs a reason"));
+    }
+    Ok(GovernanceCommand::Veto {
+        signature: signature.to_string(),
+        rationale: rationale.to_string(),
+    })
+}
+
+/// Check whether a commenter holds a permission
+pub async fn is_permitted(
+    database: &Database,
+    permission: Permission,
+    commenter: &str,
+) -> Result<bool, GovernanceError> {
+    match permission {
+        Permission::Anyone => Ok(true),
+        Permission::Maintainer => Ok(database.get_maintainer(commenter, true).await?.is_some()),
+        Permission::TierOverride => {
+            let config = tier_classification::current_config().await;
+            tier_classification::can_override_tier(database, &config.manual_override.permissions, commenter)
+                .await
+        }
+        Permission::EconomicNode => match database.pool() {
+            Some(pool) => Ok(EconomicNodeRegistry::new(pool.clone())
+                .find_active_node_by_github_username(commenter)
+                .await?
+                .is_some()),
+            // Economic nodes are only stored in SQLite
+            None => Ok(false),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_non_commands_are_ignored() {
+        assert!(parse("This looks good to me!").is_none());
+        assert!(parse("Please run /governance-status").is_none());
+        assert!(parse("").is_none());
+    }
+
+    #[test]
+    fn test_parse_sign() {
+        assert_eq!(
+            parse("/governance-sign   spaced_signature  ").unwrap(),
+            Ok(GovernanceCommand::Sign {
+                scheme: SignatureScheme::default(),
+                signature: "spaced_signature".to_string(),
+            })
+        );
+        assert_eq!(
+            parse("/governance-sign schnorr abc123").unwrap(),
+            Ok(GovernanceCommand::Sign {
+                scheme: SignatureScheme::SchnorrBip340,
+                signature: "abc123".to_string(),
+            })
+        );
+        assert!(matches!(
+            parse("/governance-sign").unwrap(),
+            Err(CommandError::Usage { usage: SIGN_USAGE, .. })
+        ));
+    }
+
+    #[test]
+    fn test_parse_tier() {
+        assert_eq!(
+            parse("/governance-tier 3 Touches block validation\nSee consensus/block.rs").unwrap(),
+            Ok(GovernanceCommand::Tier {
+                tier: 3,
+                rationale: "Touches block validation\nSee consensus/block.rs".to_string(),
+            })
+        );
+        for body in ["/governance-tier 6 Too high", "/governance-tier three Words", "/governance-tier"] {
+            assert!(
+                matches!(parse(body).unwrap(), Err(CommandError::Usage { usage: TIER_USAGE, .. })),
+                "{} should be rejected",
+                body
+            );
+        }
+        assert!(parse("/governance-tier 2").unwrap().is_err());
+    }
+
+    #[test]
+    fn test_parse_override() {
+        assert_eq!(
+            parse("/governance-override clear Docs only after all").unwrap(),
+            Ok(GovernanceCommand::ClearOverride {
+                rationale: "Docs only after all".to_string(),
+            })
+        );
+        assert!(parse("/governance-override set 2 Because").unwrap().is_err());
+        assert!(parse("/governance-override clear").unwrap().is_err());
+    }
+
+    #[test]
+    fn test_parse_veto() {
+        assert_eq!(
+            parse("/governance-veto 3045abcd Breaks our deposit flow").unwrap(),
+            Ok(GovernanceCommand::Veto {
+                signature: "3045abcd".to_string(),
+                rationale: "Breaks our deposit flow".to_string(),
+            })
+        );
+        assert!(parse("/governance-veto 3045abcd").unwrap().is_err());
+    }
+
+    #[test]
+    fn test_parse_status() {
+        assert_eq!(parse("/governance-status").unwrap(), Ok(GovernanceCommand::Status));
+        assert_eq!(parse("  /governance-status please  ").unwrap(), Ok(GovernanceCommand::Status));
+    }
+
+    #[test]
+    fn test_unknown_command_reply_lists_commands() {
+        let error = parse("/governance-merge now").unwrap().unwrap_err();
+        assert_eq!(error, CommandError::Unknown("/governance-merge".to_string()));
+        let reply = error.reply();
+        assert!(reply.starts_with("Unknown governance command `/governance-merge`"));
+        for (usage, _) in COMMANDS {
+            assert!(reply.contains(usage));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_tier_override_denied_without_permission() {
+        let db = Database::new_in_memory().await.unwrap();
+        sqlx::query("INSERT INTO maintainers (github_username, public_key, layer) VALUES ('alice', 'alice_key', 3)")
+            .execute(db.pool().unwrap())
+            .await
+            .unwrap();
+        let command = parse("/governance-tier 1 Docs only").unwrap().unwrap();
+
+        assert!(!is_permitted(&db, command.permission(), "mallory").await.unwrap());
+        assert!(is_permitted(&db, command.permission(), "alice").await.unwrap());
+        assert!(is_permitted(&db, Permission::Anyone, "mallory").await.unwrap());
+        assert!(!is_permitted(&db, Permission::EconomicNode, "alice").await.unwrap());
+        assert_eq!(
+            command.denial(),
+            "❌ Rejected: `/governance-tier` is limited to the groups listed in `manual_override.permissions`."
+        );
+    }
+}
@@ -1,13 +1,31 @@
+use chrono::Utc;
 use serde_json::Value;
 use tracing::{info, warn};
 
-use crate::crypto::signatures::SignatureManager;
+use crate::config::loader::EconomicQuorums;
+use crate::crypto::key_management::{KeyManagementConfig, KeyManager};
+use crate::crypto::key_store::InMemoryKeyStore;
+use crate::crypto::signatures::{build_signing_message, SignatureManager, SignatureScheme};
 use crate::database::Database;
+use crate::economic_nodes::registry::EconomicNodeRegistry;
+use crate::economic_nodes::types::SignalType;
+use crate::economic_nodes::veto::VetoManager;
+use crate::enforcement::pr_record::PrGovernanceRecord;
+use crate::enforcement::pr_status::PrStatus;
+use crate::error::GovernanceError;
+use crate::github::client::GitHubClient;
+use crate::validation::review_period::ReviewPeriodPolicy;
+use crate::validation::tier_classification;
+use crate::webhooks::commands::{self, GovernanceCommand};
+
+/// Event recording the app's reply to a command comment
+const COMMAND_REPLY_EVENT: &str = "command_reply";
 
 pub async fn handle_comment_event(
     database: &Database,
     payload: &Value,
-) -> Result<axum::response::Json<serde_json::Value>, axum::http::StatusCode> {
+    github_client: Option<&GitHubClient>,
+) -> Result<axum::response::Json<serde_json::Value>, GovernanceError> {
     let repo_name = payload
         .get("repository")
         .and_then(|r| r.get("full_name"))
@@ -20,6 +38,11 @@ pub async fn handle_comment_event(
         .and_then(|n| n.as_u64())
         .unwrap_or(0);
 
+    let comment_id = payload
+        .get("comment")
+        .and_then(|c| c.get("id"))
+        .and_then(|id| id.as_u64());
+
     let commenter = payload
         .get("comment")
         .and_then(|c| c.get("user"))
@@ -38,107 +61,611 @@ pub async fn handle_comment_event(
         commenter, pr_number, repo_name
     );
 
-    // Check for governance signature commands
-    if body.starts_with("/governance-sign") {
-        let signature = body.strip_prefix("/governance-sign").unwrap_or("").trim();
-
-        if !signature.is_empty() {
-            info!("Processing governance signature from {}", commenter);
-
-            // Get maintainer public key from database
-            let maintainer = match database.get_maintainer_by_username(commenter).await {
-                Ok(Some(maintainer)) => maintainer,
-                Ok(None) => {
-                    warn!("User {} is not a registered maintainer", commenter);
-                    return Ok(axum::response::Json(
-                        serde_json::json!({"status": "not_maintainer", "error": "User is not a registered maintainer"}),
-                    ));
-                }
-                Err(e) => {
-                    warn!("Failed to get maintainer info: {}", e);
-                    return Err(axum::http::StatusCode::INTERNAL_SERVER_ERROR);
-                }
-            };
-
-            // Verify signature using developer-sdk
-            let signature_manager = SignatureManager::new();
-            let message = format!("PR #{} in {}", pr_number, repo_name);
-
-            match signature_manager.verify_governance_signature(&message, signature, &maintainer.public_key) {
-                Ok(true) => {
-                    info!("Valid signature from {} for PR #{}", commenter, pr_number);
-                    
-                    // Store the verified signature
-                    match database
-                        .add_signature(repo_name, pr_number as i32, commenter, signature)
-                        .await
-                    {
-                        Ok(_) => {
-                            info!("Verified signature added for PR #{}", pr_number);
-
-                            // Log governance event
-                            let _ = database
-                                .log_governance_event(
-                                    "signature_collected",
-                                    Some(repo_name),
-                                    Some(pr_number as i32),
-                                    Some(commenter),
-                                    &serde_json::json!({
-                                        "signature": signature,
-                                        "message": message,
-                                        "verified": true,
-                                        "maintainer_layer": maintainer.layer
-                                    }),
-                                )
-                                .await;
-
-                            Ok(axum::response::Json(
-                                serde_json::json!({"status": "signature_verified", "verified": true}),
-                            ))
-                        }
-                        Err(e) => {
-                            warn!("Failed to add verified signature: {}", e);
-                            Err(axum::http::StatusCode::INTERNAL_SERVER_ERROR)
-                        }
-                    }
-                }
-                Ok(false) => {
-                    warn!("Invalid signature from {} for PR #{}", commenter, pr_number);
-                    
-                    // Log failed verification attempt
+    let replies = CommandReplies {
+        database,
+        github_client,
+        repo_name,
+        pr_number,
+        comment_id,
+    };
+
+    let command = match commands::parse(body) {
+        Some(Ok(command)) => command,
+        Some(Err(e)) => {
+            warn!("Invalid governance command from {}: {}", commenter, e);
+            replies.send(&format!("❌ {}", e.reply())).await;
+            return Ok(axum::response::Json(
+                serde_json::json!({"status": "invalid_command", "error": e.to_string()}),
+            ));
+        }
+        None => {
+            info!("Non-governance comment, ignoring");
+            return Ok(axum::response::Json(
+                serde_json::json!({"status": "ignored"}),
+            ));
+        }
+    };
+
+    if !commands::is_permitted(database, command.permission(), commenter).await? {
+        warn!("{} is not permitted to run {}", commenter, command.name());
+        replies.send(&command.denial()).await;
+        return Ok(axum::response::Json(serde_json::json!({
+            "status": "permission_denied",
+            "command": command.name(),
+            "error": format!("{} is not permitted to run {}", commenter, command.name())
+        })));
+    }
+
+    let (response, acknowledgment) = match command {
+        GovernanceCommand::Sign { scheme, signature } => {
+            let response =
+                handle_signature(database, repo_name, pr_number, commenter, scheme, &signature).await?;
+            let acknowledgment =
+                signature_acknowledgment(database, repo_name, pr_number, commenter, &response.0).await;
+            (response, acknowledgment)
+        }
+        GovernanceCommand::Tier { tier, rationale } => {
+            tier_classification::override_tier(
+                database,
+                repo_name,
+                pr_number as i32,
+                tier,
+                &rationale,
+                commenter,
+            )
+            .await?;
+            (
+                axum::response::Json(serde_json::json!({"status": "tier_overridden", "tier": tier})),
+                format!("✅ Tier set to {} by @{}.", tier, commenter),
+            )
+        }
+        GovernanceCommand::ClearOverride { rationale } => {
+            let cleared = tier_classification::clear_tier_override(
+                database,
+                repo_name,
+                pr_number as i32,
+                &rationale,
+                commenter,
+            )
+            .await?;
+            if cleared {
+                (
+                    axum::response::Json(serde_json::json!({"status": "tier_override_cleared"})),
+                    "✅ Manual tier override cleared; the automatic classification applies again."
+                        .to_string(),
+                )
+            } else {
+                (
+                    axum::response::Json(serde_json::json!({"status": "no_tier_override"})),
+                    "ℹ️ This PR has no manual tier override to clear.".to_string(),
+                )
+            }
+        }
+        GovernanceCommand::Veto { signature, rationale } => {
+            handle_veto(database, repo_name, pr_number, commenter, &signature, &rationale).await?
+        }
+        // The webhook handler re-evaluates the PR, which re-posts its status checks
+        GovernanceCommand::Status => (
+            axum::response::Json(serde_json::json!({"status": "status_refresh"})),
+            "🔄 Re-evaluating governance status.".to_string(),
+        ),
+    };
+
+    replies.send(&acknowledgment).await;
+    Ok(response)
+}
+
+/// Verify a maintainer's `/governance-sign` signature and store it
+async fn handle_signature(
+    database: &Database,
+    repo_name: &str,
+    pr_number: u64,
+    commenter: &str,
+    scheme: SignatureScheme,
+    signature: &str,
+) -> Result<axum::response::Json<serde_json::Value>, GovernanceError> {
+    info!("Processing governance signature from {}", commenter);
+
+    // Get maintainer public key from database
+    // Include suspended maintainers so the rejection can say why
+    let maintainer = match database.get_maintainer(commenter, true).await {
+        Ok(Some(maintainer)) if !maintainer.active => {
+            warn!("Maintainer {} is inactive", commenter);
+            return Ok(axum::response::Json(
+                serde_json::json!({"status": "maintainer_inactive", "error": "Maintainer is inactive"}),
+            ));
+        }
+        Ok(Some(maintainer)) => maintainer,
+        Ok(None) => {
+            warn!("User {} is not a registered maintainer", commenter);
+            return Ok(axum::response::Json(
+                serde_json::json!({"status": "not_maintainer", "error": "User is not a registered maintainer"}),
+            ));
+        }
+        Err(e) => {
+            warn!("Failed to get maintainer info: {}", e);
+            return Err(e);
+        }
+    };
+
+    let pr = match database.get_pull_request(repo_name, pr_number as i32).await {
+        Ok(Some(pr)) => pr,
+        Ok(None) => {
+            warn!("Signature from {} for untracked PR #{}", commenter, pr_number);
+            return Ok(axum::response::Json(serde_json::json!({
+                "status": "pr_not_tracked",
+                "error": "PR is not tracked by governance"
+            })));
+        }
+        Err(e) => {
+            warn!("Failed to get pull request: {}", e);
+            return Err(e);
+        }
+    };
+    if pr.layer != maintainer.layer {
+        warn!(
+            "Maintainer {} (layer {}) cannot sign layer {} PR #{}",
+            commenter, maintainer.layer, pr.layer, pr_number
+        );
+        return Ok(axum::response::Json(serde_json::json!({
+            "status": "wrong_layer",
+            "error": format!("Maintainer belongs to layer {}, PR is layer {}", maintainer.layer, pr.layer)
+        })));
+    }
+
+    if let Err(e) = check_key_usable(database, &maintainer.public_key).await {
+        let GovernanceError::CryptoError(reason) = e else {
+            return Err(e);
+        };
+        warn!("Rejected signature from {} for PR #{}: {}", commenter, pr_number, reason);
+        let _ = database
+            .log_governance_event(
+                "signature_verification_failed",
+                Some(repo_name),
+                Some(pr_number as i32),
+                Some(commenter),
+                &serde_json::json!({
+                    "signature": signature,
+                    "head_sha": pr.head_sha,
+                    "reason": "key_unusable",
+                    "error": reason
+                }),
+            )
+            .await;
+        return Ok(axum::response::Json(serde_json::json!({
+            "status": "key_unusable",
+            "error": reason
+        })));
+    }
+
+    // The signature must cover this PR's current head and tier, under the signer's registered key
+    let events = database.get_pr_governance_events(repo_name, pr.pr_number).await?;
+    let tier = PrGovernanceRecord::classification_from_events(&events).tier;
+    let signature_manager = SignatureManager::new();
+    let message = build_signing_message(repo_name, pr.pr_number, &pr.head_sha, tier);
+
+    match signature_manager.verify_with_scheme(&message, signature, &maintainer.public_key, scheme) {
+        Ok(true) => {
+            info!("Valid signature from {} for PR #{}", commenter, pr_number);
+            
+            // Store the verified signature
+            match database
+                .add_signature_with_scheme(repo_name, pr_number as i32, commenter, signature, scheme)
+                .await
+            {
+                Ok(_) => {
+                    info!("Verified signature added for PR #{}", pr_number);
+
+                    // Log governance event
                     let _ = database
                         .log_governance_event(
-                            "signature_verification_failed",
+                            "signature_collected",
                             Some(repo_name),
                             Some(pr_number as i32),
                             Some(commenter),
                             &serde_json::json!({
                                 "signature": signature,
+                                "scheme": scheme,
                                 "message": message,
-                                "reason": "invalid_signature"
+                                "head_sha": pr.head_sha,
+                                "tier": tier,
+                                "verified": true,
+                                "maintainer_layer": maintainer.layer
                             }),
                         )
                         .await;
 
                     Ok(axum::response::Json(
-                        serde_json::json!({"status": "invalid_signature", "error": "Signature verification failed"}),
+                        serde_json::json!({"status": "signature_verified", "verified": true}),
                     ))
                 }
                 Err(e) => {
-                    warn!("Signature verification error: {}", e);
-                    Err(axum::http::StatusCode::INTERNAL_SERVER_ERROR)
+                    warn!("Failed to add verified signature: {}", e);
+                    Err(e)
                 }
             }
-        } else {
-            warn!("Empty signature provided by {}", commenter);
-            Ok(axum::response::Json(
-                serde_json::json!({"status": "empty_signature"}),
+        }
+        // A malformed signature or key fails verification like a wrong one
+        Ok(false) | Err(GovernanceError::CryptoError(_)) => {
+            warn!("Invalid signature from {} for PR #{}", commenter, pr_number);
+            
+            // Log failed verification attempt
+            let _ = database
+                .log_governance_event(
+                    "signature_verification_failed",
+                    Some(repo_name),
+                    Some(pr_number as i32),
+                    Some(commenter),
+                    &serde_json::json!({
+                        "signature": signature,
+                        "message": message,
+                        "head_sha": pr.head_sha,
+                        "reason": "invalid_signature"
+                    }),
+                )
+                .await;
+
+            Ok(axum::response::Json(serde_json::json!({
+                "status": "invalid_signature",
+                "error": "Signature verification failed",
+                "message": message
+            })))
+        }
+        Err(e) => {
+            warn!("Signature verification error: {}", e);
+            Err(e)
+        }
+    }
+}
+
+/// Reject a maintainer key that key management has revoked or let expire
+///
+/// Keys only registered in `maintainers` aren't managed and are accepted.
+/// Key management lives in SQLite, so Postgres deployments skip the check.
+async fn check_key_usable(database: &Database, public_key: &str) -> Result<(), GovernanceError> {
+    let Some(pool) = database.pool() else {
+        return Ok(());
+    };
+    // Only key metadata is read, so no private keys are needed
+    let config = KeyManagementConfig {
+        hsm_enabled: false,
+        hsm_provider: None,
+        backup_enabled: false,
+        backup_location: None,
+        encryption_enabled: false,
+        rotation_policies: vec![],
+    };
+    let key_manager = KeyManager::with_key_store(pool.clone(), config, Box::new(InMemoryKeyStore::new()));
+    match key_manager.key_id_for_public_key(public_key).await? {
+        Some(key_id) => key_manager.validate_key_usable(&key_id).await,
+        None => Ok(()),
+    }
+}
+
+/// One-line result of a `/governance-sign` command for the PR thread
+async fn signature_acknowledgment(
+    database: &Database,
+    repo_name: &str,
+    pr_number: u64,
+    commenter: &str,
+    response: &Value,
+) -> String {
+    let error = response.get("error").and_then(|e| e.as_str()).unwrap_or("unknown error");
+    match response.get("status").and_then(|s| s.as_str()) {
+        Some("signature_verified") => {
+            // Only the signature progress is read, so the policy and quorums don't matter
+            match PrStatus::load(
+                database,
+                repo_name,
+                pr_number as i32,
+                Utc::now(),
+                &ReviewPeriodPolicy::default(),
+                &EconomicQuorums::default(),
+            )
+            .await
+            {
+                Ok(Some(status)) => format!(
+                    "✅ Signature {}/{} recorded for @{}.",
+                    status.signatures.collected, status.signatures.required, commenter
+                ),
+                _ => format!("✅ Signature recorded for @{}.", commenter),
+            }
+        }
+        Some("wrong_layer") => format!(
+            "❌ Rejected: signer not a maintainer for this layer ({}).",
+            error
+        ),
+        Some("invalid_signature") => format!(
+            "❌ Rejected: signature does not verify against @{}'s registered key. Sign `{}`.",
+            commenter,
+            response.get("message").and_then(|m| m.as_str()).unwrap_or_default()
+        ),
+        Some("pr_not_tracked") => "❌ Rejected: this PR is not tracked by governance.".to_string(),
+        Some("maintainer_inactive") => format!("❌ Rejected: @{} is an inactive maintainer.", commenter),
+        Some("key_unusable") => format!("❌ Rejected: @{}'s signing key is not usable ({}).", commenter, error),
+        _ => format!("❌ Rejected: {}.", error),
+    }
+}
+
+/// Record an economic node's `/governance-veto` signal
+///
+/// A signature that doesn't verify, or a second signal for the same PR, is
+/// answered on the PR rather than failing the delivery.
+async fn handle_veto(
+    database: &Database,
+    repo_name: &str,
+    pr_number: u64,
+    commenter: &str,
+    signature: &str,
+    rationale: &str,
+) -> Result<(axum::response::Json<serde_json::Value>, String), GovernanceError> {
+    let pool = database.pool().ok_or_else(|| {
+        GovernanceError::ConfigError("Economic nodes require the SQLite backend".to_string())
+    })?;
+    let node = EconomicNodeRegistry::new(pool.clone())
+        .find_active_node_by_github_username(commenter)
+        .await?
+        .ok_or_else(|| GovernanceError::not_found("Economic node for GitHub user", commenter))?;
+    let node_id = node
+        .id
+        .ok_or_else(|| GovernanceError::DatabaseError("Economic node without an ID".to_string()))?;
+    let pr = database
+        .get_pull_request(repo_name, pr_number as i32)
+        .await?
+        .ok_or_else(|| GovernanceError::not_found("Pull request", format!("{}#{}", repo_name, pr_number)))?;
+
+    match VetoManager::new(pool.clone())
+        .collect_veto_signal(pr.id, node_id, SignalType::Veto, signature, rationale)
+        .await
+    {
+        Ok(signal_id) => {
+            database
+                .log_governance_event(
+                    "veto_signal_collected",
+                    Some(repo_name),
+                    Some(pr_number as i32),
+                    Some(commenter),
+                    &serde_json::json!({
+                        "signal_id": signal_id,
+                        "node_id": node_id,
+                        "entity_name": node.entity_name,
+                        "rationale": rationale
+                    }),
+                )
+                .await?;
+            Ok((
+                axum::response::Json(serde_json::json!({"status": "veto_recorded", "signal_id": signal_id})),
+                format!("✅ Veto recorded for {}.", node.entity_name),
+            ))
+        }
+        Err(GovernanceError::CryptoError(reason)) => {
+            warn!("Rejected veto from {} on PR #{}: {}", commenter, pr_number, reason);
+            let acknowledgment = format!("❌ Rejected: {}.", reason);
+            Ok((
+                axum::response::Json(serde_json::json!({"status": "veto_rejected", "error": reason})),
+                acknowledgment,
             ))
         }
-    } else {
-        info!("Non-governance comment, ignoring");
-        Ok(axum::response::Json(
-            serde_json::json!({"status": "ignored"}),
-        ))
+        Err(e) => Err(e),
+    }
+}
+
+/// Replies to one command comment on its PR
+struct CommandReplies<'a> {
+    database: &'a Database,
+    github_client: Option<&'a GitHubClient>,
+    repo_name: &'a str,
+    pr_number: u64,
+    comment_id: Option<u64>,
+}
+
+impl CommandReplies<'_> {
+    /// Post `body` as a reply unless this command comment was already answered
+    ///
+    /// Replies are remembered as `command_reply` events keyed on the command's
+    /// comment, so a redelivered webhook doesn't post a second one. Without a
+    /// GitHub client, or when posting fails, the reply is only logged.
+    async fn send(&self, body: &str) {
+        let (Some(github_client), Some(comment_id), Some((owner, repo))) =
+            (self.github_client, self.comment_id, self.repo_name.split_once('/'))
+        else {
+            info!("Not replying on {}#{}: {}", self.repo_name, self.pr_number, body);
+            return;
+        };
+
+        match self.already_answered(comment_id).await {
+            Ok(true) => {
+                info!("Comment {} on {}#{} already answered", comment_id, self.repo_name, self.pr_number);
+                return;
+            }
+            Ok(false) => {}
+            Err(e) => warn!("Failed to check earlier replies to comment {}: {}", comment_id, e),
+        }
+
+        let reply_id = match github_client
+            .create_issue_comment(owner, repo, self.pr_number, body)
+            .await
+        {
+            Ok(reply_id) => reply_id,
+            Err(e) => {
+                warn!("Failed to reply on {}#{}: {}", self.repo_name, self.pr_number, e);
+                return;
+            }
+        };
+        if let Err(e) = self
+            .database
+            .log_governance_event(
+                COMMAND_REPLY_EVENT,
+                Some(self.repo_name),
+                Some(self.pr_number as i32),
+                None,
+                &serde_json::json!({
+                    "comment_id": comment_id,
+                    "reply_id": reply_id,
+                    "body": body
+                }),
+            )
+            .await
+        {
+            warn!("Failed to record reply to comment {}: {}", comment_id, e);
+        }
+    }
+
+    async fn already_answered(&self, comment_id: u64) -> Result<bool, GovernanceError> {
+        Ok(self
+            .database
+            .get_pr_governance_events(self.repo_name, self.pr_number as i32)
+            .await?
+            .iter()
+            .any(|e| {
+                e.event_type == COMMAND_REPLY_EVENT
+                    && e.details.get("comment_id").and_then(|id| id.as_u64()) == Some(comment_id)
+            }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::validation::threshold::ThresholdValidator;
+    use octocrab::Octocrab;
+    use secp256k1::SecretKey;
+    use wiremock::matchers::{body_json, body_string_contains, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    const REPO: &str = "BTCDecoded/developer-sdk";
+
+    fn comment_payload(comment_id: u64, commenter: &str, body: &str) -> Value {
+        serde_json::json!({
+            "action": "created",
+            "repository": {"full_name": REPO},
+            "issue": {"number": 7},
+            "comment": {"id": comment_id, "user": {"login": commenter}, "body": body}
+        })
+    }
+
+    /// A tracked layer 5 PR at `head-sha` and alice's secret key as its maintainer
+    async fn signing_fixture() -> (Database, SecretKey) {
+        let db = Database::new_in_memory().await.unwrap();
+        db.create_pull_request(REPO, 7, "head-sha", 5).await.unwrap();
+        db.log_governance_event("pr_opened", Some(REPO), Some(7), None, &serde_json::json!({"tier": 1}))
+            .await
+            .unwrap();
+        let keypair = SignatureManager::new().generate_keypair().unwrap();
+        sqlx::query("INSERT INTO maintainers (github_username, public_key, layer, active) VALUES ('alice', ?, 5, TRUE)")
+            .bind(keypair.public_key.to_string())
+            .execute(db.pool().unwrap())
+            .await
+            .unwrap();
+        (db, keypair.secret_key)
+    }
+
+    fn mock_client(server: &MockServer) -> GitHubClient {
+        let octocrab = Octocrab::builder().base_uri(server.uri()).unwrap().build().unwrap();
+        GitHubClient::from_octocrab(octocrab, 0)
+    }
+
+    #[tokio::test]
+    async fn test_verified_signature_acknowledged_once() {
+        let (db, secret_key) = signing_fixture().await;
+        let signature = SignatureManager::new()
+            .sign_with_scheme(&build_signing_message(REPO, 7, "head-sha", 1), &secret_key, SignatureScheme::default())
+            .unwrap();
+
+        let (required, _, _) = ThresholdValidator::get_combined_requirements(5, 1);
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/repos/BTCDecoded/developer-sdk/issues/7/comments"))
+            .and(body_json(serde_json::json!({
+                "body": format!("✅ Signature 1/{} recorded for @alice.", required)
+            })))
+            .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!({"id": 900})))
+            .expect(1)
+            .mount(&server)
+            .await;
+        let client = mock_client(&server);
+
+        let payload = comment_payload(555, "alice", &format!("/governance-sign {}", signature));
+        let response = handle_comment_event(&db, &payload, Some(&client)).await.unwrap();
+        assert_eq!(response.0["status"], "signature_verified");
+
+        // A redelivery re-stores the same signature but doesn't reply again
+        handle_comment_event(&db, &payload, Some(&client)).await.unwrap();
+        let replies = db
+            .get_pr_governance_events(REPO, 7)
+            .await
+            .unwrap()
+            .into_iter()
+            .filter(|e| e.event_type == COMMAND_REPLY_EVENT)
+            .count();
+        assert_eq!(replies, 1);
+    }
+
+    #[tokio::test]
+    async fn test_signature_over_other_head_rejected() {
+        let (db, secret_key) = signing_fixture().await;
+        let signature = SignatureManager::new()
+            .sign_with_scheme(&build_signing_message(REPO, 7, "old-sha", 1), &secret_key, SignatureScheme::default())
+            .unwrap();
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/repos/BTCDecoded/developer-sdk/issues/7/comments"))
+            .and(body_string_contains("Rejected: signature does not verify"))
+            .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!({"id": 901})))
+            .expect(1)
+            .mount(&server)
+            .await;
+        let client = mock_client(&server);
+
+        let payload = comment_payload(556, "alice", &format!("/governance-sign {}", signature));
+        let response = handle_comment_event(&db, &payload, Some(&client)).await.unwrap();
+        assert_eq!(response.0["status"], "invalid_signature");
+        assert_eq!(response.0["message"], build_signing_message(REPO, 7, "head-sha", 1));
+        let pr = db.get_pull_request(REPO, 7).await.unwrap().unwrap();
+        assert!(pr.signatures.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_signature_under_revoked_or_expired_key_rejected() {
+        let (db, secret_key) = signing_fixture().await;
+        let signature = SignatureManager::new()
+            .sign_with_scheme(&build_signing_message(REPO, 7, "head-sha", 1), &secret_key, SignatureScheme::default())
+            .unwrap();
+        let public_key = db.get_maintainer("alice", true).await.unwrap().unwrap().public_key;
+        let pool = db.pool().unwrap();
+        let payload = comment_payload(557, "alice", &format!("/governance-sign {}", signature));
+
+        sqlx::query(
+            "INSERT INTO key_metadata (key_id, key_type, owner, public_key, status, expires_at) VALUES ('alice-1', 'maintainer', 'alice', ?, 'revoked', ?)",
+        )
+        .bind(&public_key)
+        .bind(Utc::now() + chrono::Duration::days(30))
+        .execute(pool)
+        .await
+        .unwrap();
+        let response = handle_comment_event(&db, &payload, None).await.unwrap();
+        assert_eq!(response.0["status"], "key_unusable");
+        assert_eq!(response.0["error"], "Key alice-1 is revoked");
+
+        // An active key past its expiry is rejected and marked expired
+        sqlx::query(
+            "INSERT INTO key_metadata (key_id, key_type, owner, public_key, status, expires_at) VALUES ('alice-2', 'maintainer', 'alice', ?, 'active', ?)",
+        )
+        .bind(&public_key)
+        .bind(Utc::now() - chrono::Duration::days(1))
+        .execute(pool)
+        .await
+        .unwrap();
+        let response = handle_comment_event(&db, &payload, None).await.unwrap();
+        assert_eq!(response.0["status"], "key_unusable");
+        let status: String = sqlx::query_scalar("SELECT status FROM key_metadata WHERE key_id = 'alice-2'")
+            .fetch_one(pool)
+            .await
+            .unwrap();
+        assert_eq!(status, "expired");
+
+        let pr = db.get_pull_request(REPO, 7).await.unwrap().unwrap();
+        assert!(pr.signatures.is_empty());
     }
 }
@@ -1,42 +1,322 @@
-use axum::{extract::State, http::StatusCode, response::Json};
+use axum::{
+    body::Bytes,
+    extract::State,
+    http::{HeaderMap, StatusCode},
+    response::Json,
+};
+use chrono::{Duration, Utc};
 use serde_json::Value;
-use tracing::{info, warn};
+use tracing::{error, info, info_span, warn, Instrument, Span};
 
+use crate::auth::webhook_signature_matches;
+use crate::config::AppConfig;
+use crate::database::Database;
+use crate::enforcement::decision_log::DecisionLogger;
+use crate::enforcement::violations::ViolationNotifier;
+use crate::error::GovernanceError;
+use crate::github::client::GitHubClient;
+use crate::metrics::Metrics;
+use crate::webhooks::github_integration::GitHubIntegration;
+use crate::webhooks::queue::{QueuedWebhook, WebhookQueue};
 use crate::webhooks::{comment, pull_request, review};
 
+/// Header GitHub sets to a unique id per delivery, kept the same on redelivery
+const DELIVERY_HEADER: &str = "x-github-delivery";
+
+/// Header GitHub sets to the event type, which the payload's `action` doesn't identify
+const EVENT_HEADER: &str = "x-github-event";
+
+/// Handle `POST /webhooks/github`
+///
+/// Only checks the signature, deduplicates and enqueues, answering 202 well
+/// within GitHub's delivery timeout; the queue workers do the processing.
 pub async fn handle_webhook(
-    State((_config, database)): State<(crate::config::AppConfig, crate::database::Database)>,
-    Json(payload): Json<Value>,
+    State((config, database, queue)): State<(AppConfig, Database, WebhookQueue)>,
+    headers: HeaderMap,
+    body: Bytes,
 ) -> (StatusCode, Json<Value>) {
-    let event_type = payload
-        .get("action")
-        .and_then(|v| v.as_str())
-        .unwrap_or("unknown");
+    if !webhook_signature_matches(&headers, &body, config.github_webhook_secret.expose()) {
+        warn!("Rejected webhook with missing or invalid signature");
+        return GovernanceError::Unauthorized("invalid webhook signature".to_string()).to_response();
+    }
+
+    let payload: Value = match serde_json::from_slice(&body) {
+        Ok(payload) => payload,
+        Err(e) => {
+            return GovernanceError::WebhookError(format!("invalid payload: {}", e)).to_response()
+        }
+    };
+
+    let delivery_id = headers
+        .get(DELIVERY_HEADER)
+        .and_then(|v| v.to_str().ok())
+        .filter(|id| !id.is_empty())
+        .map(str::to_string);
+
+    if let Some(delivery_id) = &delivery_id {
+        let event_name = payload
+            .get("action")
+            .and_then(|v| v.as_str())
+            .unwrap_or("unknown");
+        let now = Utc::now();
+        let expired_before = now - Duration::seconds(config.webhook_delivery_ttl_secs as i64);
+        match database
+            .claim_webhook_delivery(delivery_id, event_name, now, expired_before)
+            .await
+        {
+            Ok(true) => {}
+            Ok(false) => {
+                info!("Skipping redelivered webhook {}", delivery_id);
+                return (
+                    StatusCode::OK,
+                    Json(serde_json::json!({"status": "duplicate", "delivery_id": delivery_id})),
+                );
+            }
+            Err(e) => {
+                // Let GitHub retry rather than risk processing the delivery twice
+                error!("Failed to record webhook delivery {}: {}", delivery_id, e);
+                return e.to_response();
+            }
+        }
+    }
+
+    let event = headers
+        .get(EVENT_HEADER)
+        .and_then(|v| v.to_str().ok())
+        .filter(|event| !event.is_empty())
+        .map(str::to_string);
+    let webhook = QueuedWebhook {
+        delivery_id: delivery_id.clone(),
+        event,
+        payload,
+    };
+    if let Err(e) = queue.try_enqueue(webhook) {
+        warn!("Turning away webhook: {}", e);
+        if let Some(delivery_id) = &delivery_id {
+            release_delivery(&database, delivery_id).await;
+        }
+        return (
+            StatusCode::SERVICE_UNAVAILABLE,
+            Json(serde_json::json!({"error": "webhook queue full, retry later"})),
+        );
+    }
 
-    let event_name = payload
+    (
+        StatusCode::ACCEPTED,
+        Json(serde_json::json!({"status": "queued", "delivery_id": delivery_id})),
+    )
+}
+
+/// Span for everything done on behalf of one delivery
+///
+/// Classification, status posting and re-evaluation all log inside it, so
+/// `repo`, `pr_number` and `delivery_id` are attached to each of their lines.
+fn webhook_span(webhook: &QueuedWebhook) -> Span {
+    let payload = &webhook.payload;
+    let repo = payload
+        .get("repository")
+        .and_then(|r| r.get("full_name"))
+        .and_then(|n| n.as_str());
+    // Comment events carry the PR as an issue
+    let pr_number = payload
+        .get("pull_request")
+        .or_else(|| payload.get("issue"))
+        .and_then(|pr| pr.get("number"))
+        .and_then(|n| n.as_u64());
+    let span = info_span!(
+        "webhook",
+        repo = tracing::field::Empty,
+        pr_number = tracing::field::Empty,
+        delivery_id = tracing::field::Empty,
+    );
+    if let Some(repo) = repo {
+        span.record("repo", repo);
+    }
+    if let Some(pr_number) = pr_number {
+        span.record("pr_number", pr_number);
+    }
+    if let Some(delivery_id) = &webhook.delivery_id {
+        span.record("delivery_id", delivery_id.as_str());
+    }
+    span
+}
+
+/// Process a webhook taken off the queue
+///
+/// GitHub was already answered with 202 and won't redeliver on its own, so a
+/// delivery that fails for a transient reason is retried with backoff. Once
+/// the attempts run out it is dead-lettered as a `webhook_dead_lettered`
+/// governance event and its claim released, so a manual redelivery is
+/// processed rather than skipped as a duplicate. A malformed delivery is not
+/// retried and keeps its claim, since redelivering it cannot succeed.
+pub(crate) async fn process_queued_webhook(
+    config: &AppConfig,
+    database: &Database,
+    metrics: &Metrics,
+    notifier: &ViolationNotifier,
+    github: Option<&GitHubClient>,
+    webhook: &QueuedWebhook,
+) {
+    async {
+        let delivery = webhook.delivery_id.as_deref().unwrap_or("(no delivery id)");
+        let event = webhook.event.as_deref().unwrap_or("unknown");
+        // Counted once per delivery, not per retry
+        metrics.record_webhook(event);
+        let mut attempt = 1;
+        let (status, body) = loop {
+            let (status, body) = process_webhook(config, database, metrics, notifier, github, event, &webhook.payload).await;
+            if status.is_success() {
+                return;
+            }
+            warn!("Webhook {} failed with {} (attempt {})", delivery, status, attempt);
+            if !status.is_server_error() {
+                return;
+            }
+            if attempt >= config.webhook_max_attempts {
+                break (status, body);
+            }
+            let delay = config.webhook_retry_base_delay_ms.saturating_mul(1 << (attempt - 1).min(16));
+            tokio::time::sleep(std::time::Duration::from_millis(delay)).await;
+            attempt += 1;
+        };
+
+        error!("Dead-lettering webhook {} after {} attempts", delivery, attempt);
+        let repo_name = webhook
+            .payload
+            .get("repository")
+            .and_then(|r| r.get("full_name"))
+            .and_then(|n| n.as_str());
+        let pr_number = webhook
+            .payload
+            .get("pull_request")
+            .or_else(|| webhook.payload.get("issue"))
+            .and_then(|pr| pr.get("number"))
+            .and_then(|n| n.as_i64())
+            .map(|n| n as i32);
+        let details = serde_json::json!({
+            "delivery_id": webhook.delivery_id,
+            "attempts": attempt,
+            "status": status.as_u16(),
+            "error": body.0,
+            "payload": webhook.payload,
+        });
+        if let Err(e) = database
+            .log_governance_event("webhook_dead_lettered", repo_name, pr_number, None, &details)
+            .await
+        {
+            error!("Failed to dead-letter webhook {}: {}", delivery, e);
+        }
+        if let Some(delivery_id) = &webhook.delivery_id {
+            release_delivery(database, delivery_id).await;
+        }
+    }
+    .instrument(webhook_span(webhook))
+    .await
+}
+
+async fn release_delivery(database: &Database, delivery_id: &str) {
+    if let Err(e) = database.release_webhook_delivery(delivery_id).await {
+        warn!("Failed to release webhook delivery {}: {}", delivery_id, e);
+    }
+}
+
+/// Dispatch a webhook on its `X-GitHub-Event` type and the payload's `action`
+///
+/// Actions are shared between event types (an issue is `opened` and
+/// `closed` too), so neither identifies a delivery on its own.
+async fn process_webhook(
+    config: &AppConfig,
+    database: &Database,
+    metrics: &Metrics,
+    notifier: &ViolationNotifier,
+    github: Option<&GitHubClient>,
+    event: &str,
+    payload: &Value,
+) -> (StatusCode, Json<Value>) {
+    let action = payload
         .get("action")
         .and_then(|v| v.as_str())
         .unwrap_or("unknown");
 
-    info!("Received webhook: {} - {}", event_name, event_type);
+    info!("Received webhook: {}.{}", event, action);
 
-    match event_name {
-        "opened" | "synchronize" | "reopened" => {
-            match pull_request::handle_pull_request_event(&database, &payload).await {
+    match (event, action) {
+        ("pull_request", "opened" | "synchronize" | "reopened") => {
+            // Without a usable app key, classification falls back to title/body only
+            let github_client = github_client_for(github, payload);
+            match pull_request::handle_pull_request_event(database, payload, github_client.as_ref())
+                .await
+            {
+                Ok(response) => {
+                    if let Some(tier) = response.0.get("tier").and_then(|t| t.as_u64()) {
+                        metrics.record_pr_classified(tier as u32);
+                    }
+                    // A new head needs its merge decision recomputed; a new PR starts its clock
+                    if response.0.get("status").and_then(|s| s.as_str()) == Some("updated") {
+                        let pr_number = payload
+                            .get("pull_request")
+                            .and_then(|pr| pr.get("number"))
+                            .and_then(|n| n.as_u64());
+                        reevaluate_after_event(config, database, metrics, github, payload, pr_number).await;
+                    }
+                    (StatusCode::OK, response)
+                }
+                Err(e) => e.to_response(),
+            }
+        }
+        ("pull_request", "closed") => {
+            match pull_request::handle_pull_request_closed(
+                database,
+                payload,
+                &config.review_period_policy,
+                &config.economic_quorums,
+                notifier,
+            )
+            .await
+            {
                 Ok(response) => (StatusCode::OK, response),
-                Err(status) => (status, Json(serde_json::json!({"error": "failed"}))),
+                Err(e) => e.to_response(),
             }
         }
-        "submitted" => match review::handle_review_event(&database, &payload).await {
-            Ok(response) => (StatusCode::OK, response),
-            Err(status) => (status, Json(serde_json::json!({"error": "failed"}))),
-        },
-        "created" => match comment::handle_comment_event(&database, &payload).await {
-            Ok(response) => (StatusCode::OK, response),
-            Err(status) => (status, Json(serde_json::json!({"error": "failed"}))),
+        ("pull_request_review", "submitted") => match review::handle_review_event(database, payload).await {
+            Ok(response) => {
+                let pr_number = payload
+                    .get("pull_request")
+                    .and_then(|pr| pr.get("number"))
+                    .and_then(|n| n.as_u64());
+                reevaluate_after_event(config, database, metrics, github, payload, pr_number).await;
+                (StatusCode::OK, response)
+            }
+            Err(e) => e.to_response(),
         },
+        ("issue_comment", "created") => {
+            let github_client = github_client_for(github, payload);
+            match comment::handle_comment_event(database, payload, github_client.as_ref()).await {
+                Ok(response) => {
+                    // Only commands that changed governance state, or asked for a refresh, re-evaluate
+                    if matches!(
+                        response.0.get("status").and_then(|s| s.as_str()),
+                        Some(
+                            "signature_verified"
+                                | "tier_overridden"
+                                | "tier_override_cleared"
+                                | "veto_recorded"
+                                | "status_refresh"
+                        )
+                    ) {
+                        let pr_number = payload
+                            .get("issue")
+                            .and_then(|i| i.get("number"))
+                            .and_then(|n| n.as_u64());
+                        reevaluate_after_event(config, database, metrics, github, payload, pr_number).await;
+                    }
+                    (StatusCode::OK, response)
+                }
+                Err(e) => e.to_response(),
+            }
+        }
         _ => {
-            warn!("Unhandled webhook event: {}", event_name);
+            warn!("Unhandled webhook event: {}.{}", event, action);
             (
                 StatusCode::OK,
                 Json(serde_json::json!({"status": "ignored"})),
@@ -44,3 +324,606 @@ pub async fn handle_webhook(
         }
     }
 }
+
+/// Scope the shared app client to the installation a webhook payload came from
+///
+/// `None` when no app key is configured.
+fn github_client_for(github: Option<&GitHubClient>, payload: &Value) -> Option<GitHubClient> {
+    let installation_id = payload
+        .get("installation")
+        .and_then(|i| i.get("id"))
+        .and_then(|id| id.as_u64());
+    github.map(|client| match installation_id {
+        Some(id) => client.installation(id),
+        None => client.clone(),
+    })
+}
+
+/// Recompute the merge decision for a PR after a review or signature lands
+///
+/// Failures are logged rather than returned: the triggering event has
+/// already been recorded, and the next event will re-evaluate again.
+async fn reevaluate_after_event(
+    config: &AppConfig,
+    database: &Database,
+    metrics: &Metrics,
+    github: Option<&GitHubClient>,
+    payload: &Value,
+    pr_number: Option<u64>,
+) {
+    let repo_name = payload
+        .get("repository")
+        .and_then(|r| r.get("full_name"))
+        .and_then(|n| n.as_str());
+    let (Some(repo_name), Some(pr_number)) = (repo_name, pr_number) else {
+        return;
+    };
+    let Some(github_client) = github_client_for(github, payload) else {
+        warn!("Cannot re-evaluate {}#{} without a GitHub client", repo_name, pr_number);
+        return;
+    };
+
+    let decision_logger = DecisionLogger::new(
+        config.dry_run_mode,
+        config.log_enforcement_decisions,
+        config.enforcement_log_path.clone(),
+    );
+    let integration = GitHubIntegration::new(github_client, database.clone(), decision_logger)
+        .with_review_period_policy(config.review_period_policy.clone())
+        .with_economic_quorums(config.economic_quorums.clone())
+        .with_check_runs(config.github_check_runs);
+    match integration.reevaluate_pr(repo_name, pr_number).await {
+        Ok(Some(decision)) => {
+            if decision.blocked {
+                metrics.record_merge_block();
+            }
+            info!(
+                "Re-evaluated {}#{}: {}",
+                repo_name,
+                pr_number,
+                if decision.blocked { "blocked" } else { "mergeable" }
+            );
+        }
+        Ok(None) => {}
+        Err(e) => warn!("Failed to re-evaluate {}#{}: {}", repo_name, pr_number, e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::auth::{sign_webhook_body, WEBHOOK_SIGNATURE_HEADER};
+    use crate::webhooks::queue::WebhookReceiver;
+    use axum::body::{to_bytes, Body};
+    use axum::http::Request;
+    use axum::routing::post;
+    use axum::Router;
+    use std::time::Instant;
+    use tempfile::TempDir;
+    use tower::ServiceExt;
+
+    struct Harness {
+        app: Router,
+        config: AppConfig,
+        database: Database,
+        queue: WebhookQueue,
+        receiver: Option<WebhookReceiver>,
+        _dir: TempDir,
+    }
+
+    /// A file-backed database so every pooled connection sees the same tables
+    async fn harness(capacity: usize) -> Harness {
+        let dir = TempDir::new().unwrap();
+        let url = format!("sqlite://{}?mode=rwc", dir.path().join("governance.db").display());
+        let database = Database::new(&url).await.unwrap();
+        database.run_migrations().await.unwrap();
+        let config = AppConfig::load().unwrap();
+        let (queue, receiver) = WebhookQueue::bounded(capacity, Metrics::new());
+        let app = Router::new()
+            .route("/webhooks/github", post(handle_webhook))
+            .with_state((config.clone(), database.clone(), queue.clone()));
+        Harness {
+            app,
+            config,
+            database,
+            queue,
+            receiver: Some(receiver),
+            _dir: dir,
+        }
+    }
+
+    impl Harness {
+        fn start_workers(&mut self, workers: usize) {
+            let receiver = self.receiver.take().unwrap();
+            receiver.spawn_workers(self.config.clone(), self.database.clone(), workers);
+        }
+
+        async fn deliver(&self, delivery_id: &str, payload: &Value) -> (StatusCode, Value) {
+            let body = payload.to_string();
+            let signature = sign_webhook_body(body.as_bytes(), self.config.github_webhook_secret.expose());
+            let request = Request::post("/webhooks/github")
+                .header("content-type", "application/json")
+                .header(DELIVERY_HEADER, delivery_id)
+                .header(EVENT_HEADER, "pull_request")
+                .header(WEBHOOK_SIGNATURE_HEADER, format!("sha256={}", signature))
+                .body(Body::from(body))
+                .unwrap();
+            send(self.app.clone(), request).await
+        }
+
+        async fn drained(&self) {
+            let deadline = Instant::now() + std::time::Duration::from_secs(10);
+            while self.queue.pending() > 0 {
+                assert!(Instant::now() < deadline, "webhook queue did not drain");
+                tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+            }
+        }
+
+        async fn pr_opened_events(&self) -> i64 {
+            sqlx::query_scalar("SELECT COUNT(*) FROM governance_events WHERE event_type = 'pr_opened'")
+                .fetch_one(self.database.pool().unwrap())
+                .await
+                .unwrap()
+        }
+    }
+
+    async fn send(app: Router, request: Request<Body>) -> (StatusCode, Value) {
+        let response = app.oneshot(request).await.unwrap();
+        let status = response.status();
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        (status, serde_json::from_slice(&body).unwrap())
+    }
+
+    fn pr_opened(number: u64) -> Value {
+        serde_json::json!({
+            "action": "opened",
+            "repository": {"full_name": "BTCDecoded/orange-paper"},
+            "pull_request": {
+                "number": number,
+                "title": "Fix typo in README",
+                "body": "",
+                "head": {"sha": "abc123"}
+            }
+        })
+    }
+
+    #[tokio::test]
+    async fn test_unsigned_webhook_rejected() {
+        let h = harness(8).await;
+        let request = Request::post("/webhooks/github")
+            .header("content-type", "application/json")
+            .header(WEBHOOK_SIGNATURE_HEADER, "sha256=00")
+            .body(Body::from(pr_opened(7).to_string()))
+            .unwrap();
+        let (status, _) = send(h.app.clone(), request).await;
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+        assert_eq!(h.queue.pending(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_redelivered_webhook_processed_once() {
+        let mut h = harness(8).await;
+        h.start_workers(2);
+
+        let (status, body) = h.deliver("delivery-1", &pr_opened(7)).await;
+        assert_eq!(status, StatusCode::ACCEPTED);
+        assert_eq!(body["status"], "queued");
+        h.drained().await;
+
+        let (status, body) = h.deliver("delivery-1", &pr_opened(7)).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["status"], "duplicate");
+        h.drained().await;
+        assert_eq!(h.pr_opened_events().await, 1);
+
+        // A new delivery of the same event is processed, as a re-sync of the known PR
+        h.deliver("delivery-2", &pr_opened(7)).await;
+        h.drained().await;
+        assert_eq!(h.pr_opened_events().await, 1);
+        let synchronized: i64 = sqlx::query_scalar(
+            "SELECT COUNT(*) FROM governance_events WHERE event_type = 'pr_synchronized'",
+        )
+        .fetch_one(h.database.pool().unwrap())
+        .await
+        .unwrap();
+        assert_eq!(synchronized, 1);
+    }
+
+    #[tokio::test]
+    async fn test_concurrent_redeliveries_processed_once() {
+        let mut h = harness(8).await;
+        h.start_workers(2);
+        let h = std::sync::Arc::new(h);
+
+        let mut deliveries = tokio::task::JoinSet::new();
+        for _ in 0..4 {
+            let h = h.clone();
+            deliveries.spawn(async move { h.deliver("delivery-1", &pr_opened(7)).await });
+        }
+        let mut queued = 0;
+        while let Some(result) = deliveries.join_next().await {
+            let (status, body) = result.unwrap();
+            assert!(status.is_success());
+            if body["status"] == "queued" {
+                queued += 1;
+            }
+        }
+        assert_eq!(queued, 1);
+
+        h.drained().await;
+        assert_eq!(h.pr_opened_events().await, 1);
+    }
+
+    #[tokio::test]
+    async fn test_flood_answered_quickly_and_fully_processed() {
+        let mut h = harness(64).await;
+        h.start_workers(4);
+        let h = std::sync::Arc::new(h);
+
+        let mut deliveries = tokio::task::JoinSet::new();
+        for number in 1..=50u64 {
+            let h = h.clone();
+            deliveries.spawn(async move {
+                let started = Instant::now();
+                let (status, _) = h.deliver(&format!("delivery-{}", number), &pr_opened(number)).await;
+                (status, started.elapsed())
+            });
+        }
+        while let Some(result) = deliveries.join_next().await {
+            let (status, elapsed) = result.unwrap();
+            assert_eq!(status, StatusCode::ACCEPTED);
+            assert!(elapsed < std::time::Duration::from_secs(2), "webhook took {:?}", elapsed);
+        }
+
+        h.drained().await;
+        assert_eq!(h.pr_opened_events().await, 50);
+    }
+
+    #[tokio::test]
+    async fn test_malformed_payload_rejected_with_400() {
+        let h = harness(8).await;
+        let body = "{\"action\": \"opened\", ";
+        let signature = sign_webhook_body(body.as_bytes(), h.config.github_webhook_secret.expose());
+        let request = Request::post("/webhooks/github")
+            .header("content-type", "application/json")
+            .header(DELIVERY_HEADER, "delivery-1")
+            .header(WEBHOOK_SIGNATURE_HEADER, format!("sha256={}", signature))
+            .body(Body::from(body))
+            .unwrap();
+        let (status, body) = send(h.app.clone(), request).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["code"], "VALIDATION_ERROR");
+        assert_eq!(h.queue.pending(), 0);
+
+        // Well-formed JSON without a PR number fails in processing, also as a 400
+        let payload = serde_json::json!({
+            "action": "opened",
+            "repository": {"full_name": "BTCDecoded/orange-paper"},
+            "pull_request": {"title": "No number"}
+        });
+        let (status, body) = process_webhook(&h.config, &h.database, &Metrics::new(), &ViolationNotifier::default(), None, "pull_request", &payload).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body.0["code"], "VALIDATION_ERROR");
+    }
+
+    #[tokio::test]
+    async fn test_failed_processing_retried_then_dead_lettered() {
+        let mut h = harness(8).await;
+        h.config.webhook_max_attempts = 3;
+        h.config.webhook_retry_base_delay_ms = 1;
+        // Every attempt fails while governance events can still be written
+        sqlx::query("DROP TABLE pull_requests")
+            .execute(h.database.pool().unwrap())
+            .await
+            .unwrap();
+
+        assert_eq!(h.deliver("delivery-1", &pr_opened(7)).await.0, StatusCode::ACCEPTED);
+        h.start_workers(1);
+        h.drained().await;
+
+        let events = h.database.get_pr_governance_events("BTCDecoded/orange-paper", 7).await.unwrap();
+        let dead_letter = events
+            .iter()
+            .find(|e| e.event_type == "webhook_dead_lettered")
+            .expect("webhook was not dead-lettered");
+        let details = &dead_letter.details;
+        assert_eq!(details["attempts"], 3);
+        assert_eq!(details["delivery_id"], "delivery-1");
+        assert_eq!(details["payload"]["pull_request"]["number"], 7);
+
+        // The claim was released so a manual redelivery is processed
+        assert_eq!(h.deliver("delivery-1", &pr_opened(7)).await.0, StatusCode::ACCEPTED);
+    }
+
+    #[tokio::test]
+    async fn test_database_outage_answered_with_503() {
+        let h = harness(8).await;
+        h.database.pool().unwrap().close().await;
+
+        let (status, body) = h.deliver("delivery-1", &pr_opened(7)).await;
+        assert_eq!(status, StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(body["code"], "SERVICE_UNAVAILABLE");
+        assert_eq!(h.queue.pending(), 0);
+    }
+
+    /// A tracked Tier 1 layer 5 PR, past its review period, signed by `signers`
+    async fn seed_reviewed_pr(database: &Database, number: i32, signers: &[&str]) {
+        let repo = "BTCDecoded/developer-sdk";
+        let pool = database.pool().unwrap();
+        database.create_pull_request(repo, number, "head-sha", 5).await.unwrap();
+        database
+            .log_governance_event("pr_opened", Some(repo), Some(number), None, &serde_json::json!({"tier": 1}))
+            .await
+            .unwrap();
+        sqlx::query("UPDATE pull_requests SET opened_at = datetime('now', '-120 days')")
+            .execute(pool)
+            .await
+            .unwrap();
+        for signer in signers {
+            sqlx::query(
+                "INSERT OR IGNORE INTO maintainers (github_username, public_key, layer, active) VALUES (?, ?, 5, TRUE)",
+            )
+            .bind(signer)
+            .bind(format!("{}_key", signer))
+            .execute(pool)
+            .await
+            .unwrap();
+            database.add_signature(repo, number, signer, "sig").await.unwrap();
+        }
+    }
+
+    fn pr_merged(number: i32) -> Value {
+        serde_json::json!({
+            "action": "closed",
+            "repository": {"full_name": "BTCDecoded/developer-sdk"},
+            "pull_request": {
+                "number": number,
+                "merged": true,
+                "merged_by": {"login": "alice"},
+                "merge_commit_sha": "merge-sha"
+            }
+        })
+    }
+
+    async fn event_types(database: &Database, number: i32) -> Vec<String> {
+        database
+            .get_pr_governance_events("BTCDecoded/developer-sdk", number)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|e| e.event_type)
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_merge_of_satisfied_pr_finalizes_record() {
+        let h = harness(8).await;
+        seed_reviewed_pr(&h.database, 11, &["alice", "bob", "carol"]).await;
+
+        let (status, body) = process_webhook(&h.config, &h.database, &Metrics::new(), &ViolationNotifier::default(), None, "pull_request", &pr_merged(11)).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body.0["status"], "merged");
+        assert_eq!(body.0["violation"], false);
+
+        let pr = h.database.get_pull_request("BTCDecoded/developer-sdk", 11).await.unwrap().unwrap();
+        assert_eq!(pr.governance_status, "merged");
+        let events = event_types(&h.database, 11).await;
+        assert!(events.contains(&"pr_merged".to_string()));
+        assert!(!events.contains(&"governance_violation".to_string()));
+
+        // A redelivered close leaves the record as it was
+        let (_, body) = process_webhook(&h.config, &h.database, &Metrics::new(), &ViolationNotifier::default(), None, "pull_request", &pr_merged(11)).await;
+        assert_eq!(body.0["status"], "already_resolved");
+        assert_eq!(event_types(&h.database, 11).await.len(), events.len());
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_keyed_on_event_type() {
+        let h = harness(8).await;
+        let notifier = ViolationNotifier::default();
+
+        // The same actions under another event type are not pull request events
+        let (status, body) = process_webhook(&h.config, &h.database, &Metrics::new(), &notifier, None, "issues", &pr_opened(8)).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body.0["status"], "ignored");
+        assert!(h.database.get_pull_request("BTCDecoded/orange-paper", 8).await.unwrap().is_none());
+
+        seed_reviewed_pr(&h.database, 15, &["alice", "bob", "carol"]).await;
+        let (_, body) = process_webhook(&h.config, &h.database, &Metrics::new(), &notifier, None, "issues", &pr_merged(15)).await;
+        assert_eq!(body.0["status"], "ignored");
+        let pr = h.database.get_pull_request("BTCDecoded/developer-sdk", 15).await.unwrap().unwrap();
+        assert_eq!(pr.governance_status, "pending");
+
+        let (status, _) = process_webhook(&h.config, &h.database, &Metrics::new(), &notifier, None, "pull_request", &pr_opened(8)).await;
+        assert_eq!(status, StatusCode::OK);
+        assert!(h.database.get_pull_request("BTCDecoded/orange-paper", 8).await.unwrap().is_some());
+    }
+
+    #[tokio::test]
+    async fn test_merge_judged_against_posted_decision() {
+        use crate::enforcement::merge_block::{BlockReason, MergeDecision};
+
+        let h = harness(8).await;
+        seed_reviewed_pr(&h.database, 13, &["alice"]).await;
+        let blocked = MergeDecision {
+            blocked: true,
+            reasons: vec![BlockReason::InsufficientSignatures { have: 1, need: 3 }],
+        };
+        h.database
+            .record_merge_decision("BTCDecoded/developer-sdk", 13, "head-sha", 1, &blocked)
+            .await
+            .unwrap();
+        // Signatures landing after the status was posted don't excuse the merge
+        seed_reviewed_pr(&h.database, 13, &["bob", "carol"]).await;
+
+        let (status, body) = process_webhook(&h.config, &h.database, &Metrics::new(), &ViolationNotifier::default(), None, "pull_request", &pr_merged(13)).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body.0["violation"], true);
+        let events = event_types(&h.database, 13).await;
+        assert!(events.contains(&"governance_violation".to_string()));
+
+        // A decision posted for an older head is ignored in favour of a recompute
+        seed_reviewed_pr(&h.database, 14, &["alice", "bob", "carol"]).await;
+        h.database
+            .record_merge_decision("BTCDecoded/developer-sdk", 14, "old-head-sha", 1, &blocked)
+            .await
+            .unwrap();
+        let (_, body) = process_webhook(&h.config, &h.database, &Metrics::new(), &ViolationNotifier::default(), None, "pull_request", &pr_merged(14)).await;
+        assert_eq!(body.0["violation"], false);
+    }
+
+    #[tokio::test]
+    async fn test_merge_of_blocked_pr_reported_to_every_sink() {
+        use crate::audit::AuditLogger;
+        use crate::enforcement::violations::{ViolationSink, VIOLATION_EVENT_TYPE};
+        use crate::nostr::client::mock_relay;
+        use crate::nostr::events::GOVERNANCE_VIOLATION_KIND;
+        use crate::nostr::NostrClient;
+
+        let h = harness(8).await;
+        seed_reviewed_pr(&h.database, 12, &["alice"]).await;
+
+        let (relay_url, received) = mock_relay::spawn(true).await;
+        let keys = nostr_sdk::Keys::generate();
+        let client = NostrClient::new(keys.secret_key().unwrap().to_secret_hex(), vec![relay_url])
+            .await
+            .unwrap()
+            .with_ack_timeout(std::time::Duration::from_secs(2));
+        for _ in 0..50 {
+            if client.connected_relay_count().await == (1, 1) {
+                break;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+        }
+        let audit_logger = AuditLogger::new(h._dir.path().join("audit.jsonl").to_string_lossy().to_string())
+            .await
+            .unwrap();
+        let notifier = ViolationNotifier::new("governance-01")
+            .with_sink(ViolationSink::Nostr(std::sync::Arc::new(client)))
+            .with_sink(ViolationSink::Audit(audit_logger.clone()));
+
+        let (status, body) = process_webhook(&h.config, &h.database, &Metrics::new(), &notifier, None, "pull_request", &pr_merged(12)).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body.0["violation"], true);
+
+        let violation = h
+            .database
+            .get_pr_governance_events("BTCDecoded/developer-sdk", 12)
+            .await
+            .unwrap()
+            .into_iter()
+            .find(|e| e.event_type == VIOLATION_EVENT_TYPE)
+            .expect("violation recorded");
+        assert_eq!(violation.details["severity"], "high");
+        assert_eq!(violation.details["violation"], "merged_while_blocked");
+        assert_eq!(violation.details["merged_by"], "alice");
+        assert_eq!(violation.details["reasons"][0]["reason"], "insufficient_signatures");
+
+        let published = received.lock().unwrap().clone();
+        assert_eq!(published.len(), 1);
+        assert_eq!(published[0]["kind"], GOVERNANCE_VIOLATION_KIND);
+        let alert: Value = serde_json::from_str(published[0]["content"].as_str().unwrap()).unwrap();
+        assert_eq!(alert["pr_number"], 12);
+        assert_eq!(alert["merge_commit_sha"], "merge-sha");
+
+        let entries = audit_logger.get_entries_by_type(VIOLATION_EVENT_TYPE).await.unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].metadata["pr_number"], "12");
+        assert_eq!(entries[0].metadata["merge_commit_sha"], "merge-sha");
+    }
+
+    #[tokio::test]
+    async fn test_full_queue_rejected_with_503() {
+        let mut h = harness(2).await;
+
+        assert_eq!(h.deliver("delivery-1", &pr_opened(1)).await.0, StatusCode::ACCEPTED);
+        assert_eq!(h.deliver("delivery-2", &pr_opened(2)).await.0, StatusCode::ACCEPTED);
+        assert_eq!(h.deliver("delivery-3", &pr_opened(3)).await.0, StatusCode::SERVICE_UNAVAILABLE);
+
+        h.start_workers(1);
+        h.drained().await;
+        assert_eq!(h.pr_opened_events().await, 2);
+
+        // The rejected delivery wasn't remembered, so GitHub's retry goes through
+        assert_eq!(h.deliver("delivery-3", &pr_opened(3)).await.0, StatusCode::ACCEPTED);
+        h.drained().await;
+        assert_eq!(h.pr_opened_events().await, 3);
+    }
+
+    #[tokio::test]
+    async fn test_expired_delivery_can_be_claimed_again() {
+        let database = Database::new_in_memory().await.unwrap();
+        let received = Utc::now() - Duration::days(8);
+        let ttl_cutoff = Utc::now() - Duration::days(7);
+
+        assert!(database
+            .claim_webhook_delivery("delivery-1", "opened", received, ttl_cutoff)
+            .await
+            .unwrap());
+        assert!(database
+            .claim_webhook_delivery("delivery-1", "opened", Utc::now(), ttl_cutoff)
+            .await
+            .unwrap());
+        assert!(!database
+            .claim_webhook_delivery("delivery-1", "opened", Utc::now(), ttl_cutoff)
+            .await
+            .unwrap());
+
+        assert_eq!(database.prune_webhook_deliveries(Utc::now() + Duration::seconds(1)).await.unwrap(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_webhooks_counted_by_event_header() {
+        let h = harness(8).await;
+        let metrics = Metrics::new();
+        // Both carry the same action; only the header tells them apart
+        for event in ["pull_request", "pull_request_review"] {
+            let webhook = QueuedWebhook {
+                delivery_id: None,
+                event: Some(event.to_string()),
+                payload: serde_json::json!({"action": "edited"}),
+            };
+            process_queued_webhook(&h.config, &h.database, &metrics, &ViolationNotifier::default(), None, &webhook)
+                .await;
+        }
+
+        let rendered = metrics.render(&h.database).await;
+        assert!(rendered.contains("governance_webhooks_received_total{event=\"pull_request\"} 1"));
+        assert!(rendered.contains("governance_webhooks_received_total{event=\"pull_request_review\"} 1"));
+        assert!(!rendered.contains("event=\"edited\""));
+    }
+
+    #[tokio::test]
+    #[tracing_test::traced_test]
+    async fn test_webhook_logs_carry_pr_and_delivery_fields() {
+        let h = harness(8).await;
+        let webhook = QueuedWebhook {
+            delivery_id: Some("delivery-span".to_string()),
+            event: Some("pull_request".to_string()),
+            payload: pr_opened(7),
+        };
+        process_queued_webhook(
+            &h.config,
+            &h.database,
+            &Metrics::new(),
+            &ViolationNotifier::default(),
+            None,
+            &webhook,
+        )
+        .await;
+
+        // Logged by the pull request handler, well below process_queued_webhook
+        logs_assert(|lines: &[&str]| {
+            let line = lines
+                .iter()
+                .find(|line| line.contains("Processing PR #7"))
+                .ok_or("pull request handler did not log")?;
+            for field in ["webhook{", "repo=", "BTCDecoded/orange-paper", "pr_number=7", "delivery_id=", "delivery-span"] {
+                if !line.contains(field) {
+                    return Err(format!("missing {} in: {}", field, line));
+                }
+            }
+            Ok(())
+        });
+    }
+}
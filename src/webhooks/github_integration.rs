@@ -3,37 +3,68 @@
 //! Handles posting status checks and updating merge status based on governance requirements
 
 use serde_json::Value;
-use tracing::info;
+use tracing::{info, warn};
 
+use crate::config::loader::EconomicQuorums;
 use crate::database::Database;
-use crate::enforcement::merge_block::MergeBlocker;
-use crate::enforcement::status_checks::StatusCheckGenerator;
+use crate::economic_nodes::veto::VetoManager;
+use crate::enforcement::merge_block::{MergeBlocker, MergeDecision, MergeRequirements};
+use crate::enforcement::pr_record::PrGovernanceRecord;
+use crate::enforcement::status_checks::{CheckRunSections, StatusCheckGenerator, TIER_COMMENT_MARKER};
 use crate::enforcement::decision_log::DecisionLogger;
 use crate::error::GovernanceError;
 use crate::github::client::GitHubClient;
-use crate::validation::review_period::ReviewPeriodValidator;
+use crate::validation::review_period::{ReviewPeriodPolicy, ReviewPeriodValidator};
 use crate::validation::threshold::ThresholdValidator;
-use crate::validation::tier_classification;
-// use crate::economic_nodes::veto::VetoManager;
+use crate::validation::tier_classification::{self, TierClassificationResult};
 
 pub struct GitHubIntegration {
     github_client: GitHubClient,
     database: Database,
     merge_blocker: MergeBlocker,
     decision_logger: DecisionLogger,
+    review_period_policy: ReviewPeriodPolicy,
+    economic_quorums: EconomicQuorums,
+    check_runs: bool,
 }
 
 impl GitHubIntegration {
     pub fn new(github_client: GitHubClient, database: Database, decision_logger: DecisionLogger) -> Self {
-        let merge_blocker = MergeBlocker::new(Some(github_client.clone()), decision_logger.clone());
+        let merge_blocker = MergeBlocker::new(
+            Some(github_client.clone()),
+            decision_logger.clone(),
+            decision_logger.dry_run_mode,
+        )
+        .with_database(database.clone());
         Self {
             github_client,
             database,
             merge_blocker,
             decision_logger,
+            review_period_policy: ReviewPeriodPolicy::default(),
+            economic_quorums: EconomicQuorums::default(),
+            check_runs: false,
         }
     }
 
+    /// Count review periods under `policy` instead of calendar days
+    pub fn with_review_period_policy(mut self, policy: ReviewPeriodPolicy) -> Self {
+        self.review_period_policy = policy;
+        self
+    }
+
+    /// Require economic node quorums from `quorums` instead of the built-in defaults
+    pub fn with_economic_quorums(mut self, quorums: EconomicQuorums) -> Self {
+        self.economic_quorums = quorums;
+        self
+    }
+
+    /// Also report each merge decision as a check run with per-requirement detail
+    pub fn with_check_runs(mut self, enabled: bool) -> Self {
+        self.check_runs = enabled;
+        self
+    }
+
     /// Handle pull request opened event
     pub async fn handle_pr_opened(&self, payload: &Value) -> Result<(), GovernanceError> {
         let repo_name = self.extract_repo_name(payload)?;
@@ -46,13 +77,18 @@ impl GitHubIntegration {
             owner, repo, pr_number
         );
 
-        // Classify PR tier
-        let tier = tier_classification::classify_pr_tier(payload).await;
-        let tier_name = self.get_tier_name(tier);
+        // Classify PR tier, honouring any manual override
+        let classified =
+            tier_classification::classify_pr_tier_with_client(payload, &self.github_client).await;
+        let classification = self
+            .resolve_payload_classification(&repo_name, pr_number, &head_sha, classified)
+            .await?;
 
-        // Post initial status check
-        self.post_initial_status_check(&owner, &repo, &head_sha, tier, &tier_name)
+        // Post initial status check and explain the tier to the author
+        self.post_initial_status_check(&owner, &repo, &head_sha, &classification)
             .await?;
+        self.post_tier_comment(&owner, &repo, pr_number as u64, &classification)
+            .await;
 
         // Set up required status checks for the branch
         self.merge_blocker
@@ -75,7 +111,7 @@ impl GitHubIntegration {
         );
 
         // Update status checks based on current state
-        self.update_pr_status_checks(&owner, &repo, &head_sha, pr_number as u64, payload)
+        self.update_pr_status_checks(&owner, &repo, &head_sha, pr_number as u64)
             .await?;
 
         Ok(())
@@ -93,8 +129,14 @@ impl GitHubIntegration {
             owner, repo, pr_number
         );
 
+        // A push can change the files, so record the new classification first
+        let classified =
+            tier_classification::classify_pr_tier_with_client(payload, &self.github_client).await;
+        self.resolve_payload_classification(&repo_name, pr_number, &head_sha, classified)
+            .await?;
+
         // Update all status checks
-        self.update_pr_status_checks(&owner, &repo, &head_sha, pr_number as u64, payload)
+        self.update_pr_status_checks(&owner, &repo, &head_sha, pr_number as u64)
             .await?;
 
         Ok(())
@@ -106,18 +148,12 @@ impl GitHubIntegration {
         owner: &str,
         repo: &str,
         sha: &str,
-        tier: u32,
-        tier_name: &str,
+        classification: &TierClassificationResult,
     ) -> Result<(), GovernanceError> {
-        let status_message = format!(
-            "🔍 Governance: Analyzing PR\n\
-            Tier {}: {}\n\
-            Review period and signature requirements will be checked...",
-            tier, tier_name
-        );
+        let status_message = StatusCheckGenerator::generate_tier_status(classification);
 
-        self.github_client
-            .post_status_check(
+        self.merge_blocker
+            .post_status(
                 owner,
                 repo,
                 sha,
@@ -130,25 +166,237 @@ impl GitHubIntegration {
         Ok(())
     }
 
-    /// Update all status checks for a PR
+    /// Create or update the comment explaining the PR's tier
+    ///
+    /// Best effort: the status check already carries the tier.
+    async fn post_tier_comment(
+        &self,
+        owner: &str,
+        repo: &str,
+        pr_number: u64,
+        classification: &TierClassificationResult,
+    ) {
+        if self.decision_logger.dry_run_mode {
+            info!("[DRY-RUN] Not posting tier comment on {}/{}#{}", owner, repo, pr_number);
+            return;
+        }
+        let body = StatusCheckGenerator::generate_tier_comment(classification);
+        if let Err(e) = self
+            .github_client
+            .upsert_pr_comment(owner, repo, pr_number, TIER_COMMENT_MARKER, &body)
+            .await
+        {
+            warn!("Failed to post tier comment on {}/{}#{}: {}", owner, repo, pr_number, e);
+        }
+    }
+
+    /// Re-evaluate a PR after a late review or signature and re-post its statuses
+    ///
+    /// Review and comment payloads don't carry the PR's current head, so the
+    /// SHA is read back from the database; a stale SHA would leave the new
+    /// head blocked. Returns `None` for PRs that aren't tracked.
+    pub async fn reevaluate_pr(
+        &self,
+        repo_name: &str,
+        pr_number: u64,
+    ) -> Result<Option<MergeDecision>, GovernanceError> {
+        let (owner, repo) = self.parse_repo_name(repo_name)?;
+        let pr = match self
+            .database
+            .get_pull_request(repo_name, pr_number as i32)
+            .await?
+        {
+            Some(pr) => pr,
+            None => {
+                warn!("{}#{} is not tracked, skipping re-evaluation", repo_name, pr_number);
+                return Ok(None);
+            }
+        };
+        // Merged or closed PRs keep the statuses they were resolved with
+        if pr.governance_status != "pending" {
+            info!(
+                "{}#{} is {}, skipping re-evaluation",
+                repo_name, pr_number, pr.governance_status
+            );
+            return Ok(None);
+        }
+        let tier = self.recorded_tier(repo_name, pr_number as i32).await?;
+
+        info!(
+            "Re-evaluating {}#{} at {} (Tier {})",
+            repo_name, pr_number, pr.head_sha, tier
+        );
+
+        let decision = self
+            .post_pr_statuses(&owner, &repo, &pr.head_sha, &pr, tier)
+            .await?;
+        Ok(Some(decision))
+    }
+
+    /// Re-run tier classification against the PR's current title, body and files
+    ///
+    /// The result is recorded as a `pr_reclassified` event so later
+    /// re-evaluations use it. The tier status, with any manual override
+    /// applied, is re-posted on the PR's head commit, and a tracked PR's
+    /// requirement statuses and merge decision are re-evaluated at that tier.
+    pub async fn reclassify_pr(
+        &self,
+        repo_name: &str,
+        pr_number: u64,
+    ) -> Result<TierClassificationResult, GovernanceError> {
+        let (owner, repo) = self.parse_repo_name(repo_name)?;
+        let pull_request = self
+            .github_client
+            .get_pull_request(&owner, &repo, pr_number)
+            .await?;
+        let head_sha = pull_request
+            .get("head_sha")
+            .and_then(|s| s.as_str())
+            .ok_or_else(|| {
+                GovernanceError::GitHubError(format!("No head SHA for {}#{}", repo_name, pr_number))
+            })?
+            .to_string();
+        let files = self
+            .github_client
+            .list_pull_request_files(&owner, &repo, pr_number)
+            .await?;
+
+        let payload = serde_json::json!({
+            "repository": {"full_name": repo_name},
+            "pull_request": {
+                "number": pr_number,
+                "title": pull_request.get("title"),
+                "body": pull_request.get("body"),
+                "head": {"sha": head_sha},
+                "files": files
+                    .iter()
+                    .map(|filename| serde_json::json!({"filename": filename}))
+                    .collect::<Vec<_>>()
+            }
+        });
+        let config = tier_classification::current_config().await;
+        let classified = tier_classification::classify_pr_tier_detailed(&payload, &config).await;
+        self.log_reclassification(repo_name, pr_number as i32, &head_sha, &classified)
+            .await?;
+        let result =
+            tier_classification::effective_tier(&self.database, repo_name, pr_number as i32, classified)
+                .await?;
+        info!(
+            "Reclassified {}#{} as Tier {} ({} files)",
+            repo_name,
+            pr_number,
+            result.tier,
+            files.len()
+        );
+
+        self.post_initial_status_check(&owner, &repo, &head_sha, &result)
+            .await?;
+        self.post_tier_comment(&owner, &repo, pr_number, &result).await;
+        self.update_pr_status_checks(&owner, &repo, &head_sha, pr_number)
+            .await?;
+        Ok(result)
+    }
+
+    /// Record a payload's classification when it differs from the PR's last one
+    ///
+    /// Returns the classification with any manual override applied, which is
+    /// the tier `recorded_tier` resolves from then on, so statuses posted from
+    /// a webhook agree with later re-evaluations and `/status`.
+    async fn resolve_payload_classification(
+        &self,
+        repo_name: &str,
+        pr_number: i32,
+        head_sha: &str,
+        classified: TierClassificationResult,
+    ) -> Result<TierClassificationResult, GovernanceError> {
+        let events = self
+            .database
+            .get_pr_governance_events(repo_name, pr_number)
+            .await?;
+        let changed = PrGovernanceRecord::auto_classification(&events)
+            .map_or(true, |recorded| recorded.tier != classified.tier);
+        if changed {
+            self.log_reclassification(repo_name, pr_number, head_sha, &classified)
+                .await?;
+        }
+        tier_classification::effective_tier(&self.database, repo_name, pr_number, classified).await
+    }
+
+    /// Record an automatic classification as a `pr_reclassified` event
+    async fn log_reclassification(
+        &self,
+        repo_name: &str,
+        pr_number: i32,
+        head_sha: &str,
+        classified: &TierClassificationResult,
+    ) -> Result<(), GovernanceError> {
+        PrGovernanceRecord::log_tier_event(
+            &self.database,
+            "pr_reclassified",
+            repo_name,
+            pr_number,
+            None,
+            &serde_json::json!({
+                "tier": classified.tier,
+                "head_sha": head_sha,
+                "classification_confidence": classified.confidence,
+                "classification_rationale": classified.rationale
+            }),
+        )
+        .await
+    }
+
+    /// Tier recorded for the PR, resolved the same way as its status and record
+    async fn recorded_tier(&self, repo_name: &str, pr_number: i32) -> Result<u32, GovernanceError> {
+        let events = self
+            .database
+            .get_pr_governance_events(repo_name, pr_number)
+            .await?;
+        PrGovernanceRecord::recorded_classification(&events)
+            .map(|classification| classification.tier)
+            .ok_or_else(|| {
+                GovernanceError::ValidationError(format!(
+                    "No tier recorded for {}#{}",
+                    repo_name, pr_number
+                ))
+            })
+    }
+
+    /// Update all status checks for a PR at its recorded tier
     async fn update_pr_status_checks(
         &self,
         owner: &str,
         repo: &str,
         sha: &str,
         pr_number: u64,
-        payload: &Value,
     ) -> Result<(), GovernanceError> {
+        let repo_name = format!("{}/{}", owner, repo);
+
         // Get PR information from database
         let pr_info = self
             .database
-            .get_pull_request(owner, pr_number as i32)
+            .get_pull_request(&repo_name, pr_number as i32)
             .await?;
 
         if let Some(pr) = pr_info {
-            let layer = pr.layer;
-            let tier = tier_classification::classify_pr_tier(payload).await;
-            let tier_name = self.get_tier_name(tier);
+            let tier = self.recorded_tier(&repo_name, pr_number as i32).await?;
+            self.post_pr_statuses(owner, repo, sha, &pr, tier).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Evaluate every requirement for a PR and post the resulting status checks
+    async fn post_pr_statuses(
+        &self,
+        owner: &str,
+        repo: &str,
+        sha: &str,
+        pr: &crate::database::models::PullRequest,
+        tier: u32,
+    ) -> Result<MergeDecision, GovernanceError> {
+        let layer = pr.layer;
+        let tier_name = self.get_tier_name(tier);
 
         // Get combined requirements (Layer + Tier)
         let (sigs_req, sigs_total, review_days) = 
@@ -156,11 +404,13 @@ impl GitHubIntegration {
         let _source = ThresholdValidator::get_requirement_source(layer, tier);
 
         // Check review period
-        let review_period_met = self.check_review_period(&pr, review_days).await?;
-        let review_period_status = self.generate_review_period_status(&pr, review_days).await?;
+        let review_period_met = self.check_review_period(pr, review_days).await?;
+        let review_period_status = self.generate_review_period_status(pr, review_days).await?;
 
         // Check signatures
-        let (signatures_met, signature_status) = self.check_signatures(&pr, sigs_req, sigs_total).await?;
+        let (current_signatures, signature_status) =
+            self.check_signatures(pr, sigs_req, sigs_total).await?;
+        let signatures_met = current_signatures >= sigs_req;
 
         // Check economic node veto (Tier 3+)
         let (economic_veto_active, economic_veto_status) = if tier >= 3 {
@@ -169,50 +419,79 @@ impl GitHubIntegration {
             (false, String::new())
         };
 
-            // Post individual status checks
-            self.post_review_period_status(owner, repo, sha, &review_period_status)
-                .await?;
-            self.post_signature_status(owner, repo, sha, &signature_status)
-                .await?;
-
-            if tier >= 3 {
-                self.post_economic_veto_status(owner, repo, sha, &economic_veto_status)
-                    .await?;
-            }
+        // Check economic node quorum (Tier 3+)
+        let (economic_quorum_met, economic_quorum_status) = if tier >= 3 {
+            self.check_economic_quorum(pr.id, tier).await?
+        } else {
+            (true, String::new())
+        };
 
-            // Post combined status
-            self.post_combined_status(
-                owner,
-                repo,
-                sha,
-                layer,
-                tier,
-                &tier_name,
-                review_period_met,
-                signatures_met,
-                economic_veto_active,
-                &review_period_status,
-                &signature_status,
-                &economic_veto_status,
-            )
+        // Post individual status checks
+        self.post_review_period_status(owner, repo, sha, &review_period_status)
+            .await?;
+        self.post_signature_status(owner, repo, sha, &signature_status)
             .await?;
 
-            // Update merge blocking status
-            self.merge_blocker
-                .update_merge_status(
-                    owner,
-                    repo,
-                    sha,
-                    review_period_met,
-                    signatures_met,
-                    economic_veto_active,
-                    tier,
-                    false, // emergency_mode
-                )
+        if tier >= 3 {
+            self.post_economic_veto_status(owner, repo, sha, &economic_veto_status)
+                .await?;
+            self.post_economic_quorum_status(owner, repo, sha, &economic_quorum_status)
                 .await?;
         }
 
-        Ok(())
+        // Post combined status
+        self.post_combined_status(
+            owner,
+            repo,
+            sha,
+            layer,
+            tier,
+            tier_name,
+            review_period_met,
+            signatures_met,
+            economic_veto_active,
+            &review_period_status,
+            &signature_status,
+            &economic_veto_status,
+        )
+        .await?;
+
+        // Update merge blocking status
+        let requirements = MergeRequirements {
+            review_period_met,
+            current_signatures,
+            required_signatures: sigs_req,
+            economic_veto_active,
+            economic_quorum_met,
+            cross_layer_synced: true,
+            tier,
+            emergency_mode: pr.emergency_mode,
+        };
+        let decision = self
+            .merge_blocker
+            .update_merge_status(owner, repo, sha, &requirements)
+            .await?;
+        // Merges are judged against what was posted, not a later recompute
+        self.database
+            .record_merge_decision(&pr.repo_name, pr.pr_number, sha, tier, &decision)
+            .await?;
+
+        // The commit statuses gate the merge; the check run only adds detail
+        if self.check_runs {
+            let sections = CheckRunSections {
+                signatures: signature_status,
+                review_period: review_period_status,
+                economic_veto: (tier >= 3).then_some(economic_veto_status),
+            };
+            if let Err(e) = self
+                .merge_blocker
+                .post_check_run(owner, repo, sha, &decision, &sections)
+                .await
+            {
+                warn!("Failed to create check run for {}/{}@{}: {}", owner, repo, sha, e);
+            }
+        }
+        Ok(decision)
     }
 
         /// Check review period requirements
@@ -222,7 +501,13 @@ impl GitHubIntegration {
             required_days: i64,
         ) -> Result<bool, GovernanceError> {
             let opened_at = pr.opened_at;
-            Ok(ReviewPeriodValidator::validate_review_period(opened_at, required_days, false).is_ok())
+            Ok(ReviewPeriodValidator::validate_review_period_with_policy(
+                opened_at,
+                required_days,
+                pr.emergency_mode,
+                &self.review_period_policy,
+            )
+            .is_ok())
         }
 
         /// Generate review period status message
@@ -232,26 +517,43 @@ impl GitHubIntegration {
             required_days: i64,
         ) -> Result<String, GovernanceError> {
             let opened_at = pr.opened_at;
-            Ok(StatusCheckGenerator::generate_review_period_status(
+            Ok(StatusCheckGenerator::generate_review_period_status_with_policy(
                 opened_at,
                 required_days,
+                pr.emergency_mode,
                 false,
+                &self.review_period_policy,
             ))
         }
 
-        /// Check signature requirements
+        /// Count signatures collected so far and render the signature status
         async fn check_signatures(
             &self,
-            _pr: &crate::database::models::PullRequest,
+            pr: &crate::database::models::PullRequest,
             required: usize,
             total: usize,
-        ) -> Result<(bool, String), GovernanceError> {
-            // TODO: Get actual signature count from database
-            let current_signatures = 0; // Placeholder
-            let signers = vec![]; // Placeholder
-            let pending = vec![]; // Placeholder
+        ) -> Result<(usize, String), GovernanceError> {
+            // Signatures are only stored once verified against the maintainer's key, but
+            // only active maintainers of the PR's layer count towards its threshold
+            let layer_maintainers: Vec<String> = self
+                .database
+                .get_maintainers_for_layer(pr.layer)
+                .await?
+                .into_iter()
+                .map(|m| m.github_username)
+                .collect();
+            let signers: Vec<String> = pr
+                .signatures
+                .iter()
+                .map(|s| s.signer.clone())
+                .filter(|signer| layer_maintainers.contains(signer))
+                .collect();
+            let pending: Vec<String> = layer_maintainers
+                .into_iter()
+                .filter(|username| !signers.contains(username))
+                .collect();
+            let current_signatures = signers.len();
 
-            let signatures_met = current_signatures >= required;
             let status = StatusCheckGenerator::generate_signature_status(
                 current_signatures,
                 required,
@@ -260,20 +562,38 @@ impl GitHubIntegration {
                 &pending,
             );
 
-            Ok((signatures_met, status))
+            Ok((current_signatures, status))
         }
 
     /// Check economic node veto status
-    async fn check_economic_veto(&self, _pr_id: i32) -> Result<(bool, String), GovernanceError> {
-        // let veto_manager = VetoManager::new(self.database.pool().clone());
-        // let threshold = veto_manager.check_veto_threshold(pr_id).await?;
-        // let statistics = veto_manager.get_veto_statistics(pr_id).await?;
+    ///
+    /// Economic nodes live in the SQLite registry; without it no veto can be
+    /// signalled.
+    async fn check_economic_veto(&self, pr_id: i32) -> Result<(bool, String), GovernanceError> {
+        let Some(pool) = self.database.pool() else {
+            return Ok((false, "✅ Economic Node Veto: No veto signals received".to_string()));
+        };
+        let threshold = VetoManager::new(pool.clone())
+            .check_veto_threshold_at(pr_id, chrono::Utc::now())
+            .await?;
+        let status = StatusCheckGenerator::generate_economic_veto_status(&threshold);
 
-        // For now, return mock data
-        let veto_active = false;
-        let status = "Economic node veto: No veto signals received".to_string();
+        Ok((threshold.veto_active, status))
+    }
+
+    /// Check whether enough economic nodes have signalled on a PR
+    async fn check_economic_quorum(
+        &self,
+        pr_id: i32,
+        tier: u32,
+    ) -> Result<(bool, String), GovernanceError> {
+        let quorum = self.economic_quorums.for_tier(tier);
+        let signal_count = self.database.count_economic_signals(pr_id).await?;
+
+        let quorum_met = ThresholdValidator::economic_quorum_met(tier, signal_count, quorum);
+        let status = StatusCheckGenerator::generate_economic_quorum_status(signal_count, quorum);
 
-        Ok((veto_active, status))
+        Ok((quorum_met, status))
     }
 
         /// Post review period status check
@@ -298,8 +618,8 @@ impl GitHubIntegration {
                 status,
             );
 
-            self.github_client
-                .post_status_check(owner, repo, sha, state, status, "governance/review-period")
+            self.merge_blocker
+                .post_status(owner, repo, sha, state, status, "governance/review-period")
                 .await
         }
 
@@ -325,8 +645,8 @@ impl GitHubIntegration {
             status,
         );
 
-        self.github_client
-            .post_status_check(owner, repo, sha, state, status, "governance/signatures")
+        self.merge_blocker
+            .post_status(owner, repo, sha, state, status, "governance/signatures")
             .await
     }
 
@@ -344,8 +664,27 @@ impl GitHubIntegration {
             "failure"
         };
 
-        self.github_client
-            .post_status_check(owner, repo, sha, state, status, "governance/economic-veto")
+        self.merge_blocker
+            .post_status(owner, repo, sha, state, status, "governance/economic-veto")
+            .await
+    }
+
+    /// Post economic quorum status check
+    async fn post_economic_quorum_status(
+        &self,
+        owner: &str,
+        repo: &str,
+        sha: &str,
+        status: &str,
+    ) -> Result<(), GovernanceError> {
+        let state = if status.contains("✅") {
+            "success"
+        } else {
+            "failure"
+        };
+
+        self.merge_blocker
+            .post_status(owner, repo, sha, state, status, "governance/economic-quorum")
             .await
     }
 
@@ -383,8 +722,8 @@ impl GitHubIntegration {
             "failure"
         };
 
-        self.github_client
-            .post_status_check(owner, repo, sha, state, &status, "governance/combined")
+        self.merge_blocker
+            .post_status(owner, repo, sha, state, &status, "governance/combined")
             .await
     }
 
@@ -432,13 +771,6 @@ impl GitHubIntegration {
 
     /// Get tier name from tier number
     fn get_tier_name(&self, tier: u32) -> &'static str {
-        match tier {
-            1 => "Routine Maintenance",
-            2 => "Feature Changes",
-            3 => "Consensus-Adjacent",
-            4 => "Emergency Actions",
-            5 => "Governance Changes",
-            _ => "Unknown",
-        }
+        StatusCheckGenerator::tier_name(tier)
     }
 }
@@ -1,6 +1,11 @@
+pub mod commands;
 pub mod comment;
 pub mod github;
 pub mod github_integration;
 pub mod pull_request;
 pub mod push;
+pub mod reclassify;
+pub mod reconcile;
+pub mod queue;
 pub mod review;
+pub mod simulate;
@@ -1,13 +1,35 @@
+use chrono::Utc;
 use serde_json::Value;
 use tracing::{info, warn};
 
+use crate::config::loader::EconomicQuorums;
+use crate::database::models::{PostedMergeDecision, PullRequestUpsert};
 use crate::database::Database;
+use crate::enforcement::pr_record::PrGovernanceRecord;
+use crate::enforcement::pr_status::PrStatus;
+use crate::enforcement::violations::{GovernanceViolation, ViolationNotifier};
+use crate::error::GovernanceError;
+use crate::github::client::GitHubClient;
+use crate::validation::review_period::ReviewPeriodPolicy;
 use crate::validation::tier_classification;
 
+/// Map a repository name to its governance layer
+pub fn layer_for_repo(repo_name: &str) -> Option<i32> {
+    match repo_name {
+        repo if repo.contains("orange-paper") => Some(1),
+        repo if repo.contains("consensus-proof") => Some(2),
+        repo if repo.contains("protocol-engine") => Some(3),
+        repo if repo.contains("reference-node") => Some(4),
+        repo if repo.contains("developer-sdk") => Some(5),
+        _ => None,
+    }
+}
+
 pub async fn handle_pull_request_event(
     database: &Database,
     payload: &Value,
-) -> Result<axum::response::Json<serde_json::Value>, axum::http::StatusCode> {
+    github_client: Option<&GitHubClient>,
+) -> Result<axum::response::Json<serde_json::Value>, GovernanceError> {
     let repo_name = payload
         .get("repository")
         .and_then(|r| r.get("full_name"))
@@ -18,7 +40,7 @@ pub async fn handle_pull_request_event(
         .get("pull_request")
         .and_then(|pr| pr.get("number"))
         .and_then(|n| n.as_u64())
-        .unwrap_or(0);
+        .ok_or_else(|| GovernanceError::WebhookError("payload missing pull_request.number".to_string()))?;
 
     let head_sha = payload
         .get("pull_request")
@@ -29,13 +51,9 @@ pub async fn handle_pull_request_event(
     info!("Processing PR #{} in {}", pr_number, repo_name);
 
     // Determine layer based on repository
-    let layer = match repo_name {
-        repo if repo.contains("orange-paper") => 1,
-        repo if repo.contains("consensus-proof") => 2,
-        repo if repo.contains("protocol-engine") => 3,
-        repo if repo.contains("reference-node") => 4,
-        repo if repo.contains("developer-sdk") => 5,
-        _ => {
+    let layer = match layer_for_repo(repo_name) {
+        Some(layer) => layer,
+        None => {
             warn!("Unknown repository: {}", repo_name);
             return Ok(axum::response::Json(
                 serde_json::json!({"status": "unknown_repo"}),
@@ -44,7 +62,25 @@ pub async fn handle_pull_request_event(
     };
 
     // Classify PR tier based on file changes
-    let tier = tier_classification::classify_pr_tier(payload).await;
+    let classification = match github_client {
+        Some(client) => tier_classification::classify_pr_tier_with_client(payload, client).await,
+        None => tier_classification::classify_pr_tier_with_rationale(payload).await,
+    };
+    let classification = match tier_classification::effective_tier(
+        database,
+        repo_name,
+        pr_number as i32,
+        classification.clone(),
+    )
+    .await
+    {
+        Ok(effective) => effective,
+        Err(e) => {
+            warn!("Failed to check tier override for PR #{}: {}", pr_number, e);
+            classification
+        }
+    };
+    let tier = classification.tier;
     info!("PR #{} classified as Tier {}", pr_number, tier);
 
     // Store PR in database
@@ -52,33 +88,171 @@ pub async fn handle_pull_request_event(
         .create_pull_request(repo_name, pr_number as i32, head_sha, layer)
         .await
     {
-        Ok(_) => {
-            info!("PR #{} stored in database", pr_number);
+        Ok(upsert) => {
+            if payload.get("action").and_then(|a| a.as_str()) == Some("reopened") {
+                database
+                    .transition_pull_request(repo_name, pr_number as i32, "closed", "pending")
+                    .await?;
+            }
+
+            // Only the first sighting opens the PR; later ones are new pushes or redeliveries
+            let (event_type, status) = match upsert {
+                PullRequestUpsert::Created => ("pr_opened", "stored"),
+                PullRequestUpsert::Updated => ("pr_synchronized", "updated"),
+            };
+            info!("PR #{} {} in database", pr_number, status);
 
             // Log governance event
-            let _ = database
-                .log_governance_event(
-                    "pr_opened",
-                    Some(repo_name),
-                    Some(pr_number as i32),
-                    None,
-                    &serde_json::json!({
-                        "tier": tier,
-                        "layer": layer,
-                        "head_sha": head_sha
-                    }),
-                )
-                .await;
+            let _ = PrGovernanceRecord::log_tier_event(
+                database,
+                event_type,
+                repo_name,
+                pr_number as i32,
+                None,
+                &serde_json::json!({
+                    "tier": tier,
+                    "layer": layer,
+                    "head_sha": head_sha,
+                    "classification_confidence": classification.confidence,
+                    "classification_rationale": classification.rationale
+                }),
+            )
+            .await;
 
             Ok(axum::response::Json(serde_json::json!({
-                "status": "stored",
+                "status": status,
                 "tier": tier,
                 "layer": layer
             })))
         }
         Err(e) => {
             warn!("Failed to store PR: {}", e);
-            Err(axum::http::StatusCode::INTERNAL_SERVER_ERROR)
+            Err(e)
         }
     }
 }
+
+/// Finalize a PR's governance record when it is closed or merged
+///
+/// A merge is judged against the `governance/merge-check` decision last
+/// posted for the PR's head, which is what GitHub showed when the merge went
+/// through. Only when nothing was posted for that head is the status
+/// recomputed. A violation is recorded before the PR leaves `pending`, so a
+/// failed write leaves the PR open to a redelivered webhook.
+pub async fn handle_pull_request_closed(
+    database: &Database,
+    payload: &Value,
+    review_period_policy: &ReviewPeriodPolicy,
+    economic_quorums: &EconomicQuorums,
+    notifier: &ViolationNotifier,
+) -> Result<axum::response::Json<serde_json::Value>, GovernanceError> {
+    let repo_name = payload
+        .get("repository")
+        .and_then(|r| r.get("full_name"))
+        .and_then(|n| n.as_str())
+        .ok_or_else(|| GovernanceError::WebhookError("payload missing repository.full_name".to_string()))?;
+
+    let pull_request = payload
+        .get("pull_request")
+        .ok_or_else(|| GovernanceError::WebhookError("payload missing pull_request".to_string()))?;
+    let pr_number = pull_request
+        .get("number")
+        .and_then(|n| n.as_u64())
+        .ok_or_else(|| GovernanceError::WebhookError("payload missing pull_request.number".to_string()))?
+        as i32;
+    let merged = pull_request
+        .get("merged")
+        .and_then(|m| m.as_bool())
+        .unwrap_or(false);
+    let merged_by = pull_request
+        .get("merged_by")
+        .and_then(|u| u.get("login"))
+        .and_then(|l| l.as_str());
+    let merge_commit_sha = pull_request
+        .get("merge_commit_sha")
+        .and_then(|s| s.as_str());
+
+    let Some(pr) = database.get_pull_request(repo_name, pr_number).await? else {
+        info!("Closed PR #{} in {} is not tracked", pr_number, repo_name);
+        return Ok(axum::response::Json(serde_json::json!({"status": "unknown_pr"})));
+    };
+    if pr.governance_status != "pending" {
+        info!("PR #{} in {} already resolved", pr_number, repo_name);
+        return Ok(axum::response::Json(serde_json::json!({"status": "already_resolved"})));
+    }
+
+    let posted = match database.get_merge_decision(repo_name, pr_number).await? {
+        Some(posted) if posted.head_sha == pr.head_sha => posted,
+        _ => {
+            warn!(
+                "No merge decision posted for PR #{} in {} at {}, recomputing",
+                pr_number, repo_name, pr.head_sha
+            );
+            let Some(status) = PrStatus::load(
+                database,
+                repo_name,
+                pr_number,
+                Utc::now(),
+                review_period_policy,
+                economic_quorums,
+            )
+            .await?
+            else {
+                return Ok(axum::response::Json(serde_json::json!({"status": "unknown_pr"})));
+            };
+            PostedMergeDecision {
+                head_sha: status.head_sha,
+                tier: status.tier,
+                decision: status.decision,
+                decided_at: Utc::now(),
+            }
+        }
+    };
+
+    let violation = merged && posted.decision.blocked;
+    if violation {
+        let violation = GovernanceViolation::merged_while_blocked(
+            repo_name,
+            pr_number,
+            &posted,
+            merged_by,
+            merge_commit_sha,
+        );
+        notifier.report(database, &violation).await?;
+    }
+
+    let resolution = if merged { "merged" } else { "closed" };
+    if !database
+        .transition_pull_request(repo_name, pr_number, "pending", resolution)
+        .await?
+    {
+        info!("PR #{} in {} already resolved", pr_number, repo_name);
+        return Ok(axum::response::Json(serde_json::json!({"status": "already_resolved"})));
+    }
+
+    let details = serde_json::json!({
+        "merged": merged,
+        "merged_by": merged_by,
+        "merge_commit_sha": merge_commit_sha,
+        "head_sha": &posted.head_sha,
+        "tier": posted.tier,
+        "blocked": posted.decision.blocked,
+        "reasons": &posted.decision.reasons,
+        "decided_at": posted.decided_at,
+    });
+    database
+        .log_governance_event(
+            if merged { "pr_merged" } else { "pr_closed" },
+            Some(repo_name),
+            Some(pr_number),
+            merged_by,
+            &details,
+        )
+        .await?;
+
+    info!("PR #{} in {} {}", pr_number, repo_name, resolution);
+    Ok(axum::response::Json(serde_json::json!({
+        "status": resolution,
+        "violation": violation
+    })))
+}
@@ -2,11 +2,12 @@ use serde_json::Value;
 use tracing::{info, warn};
 
 use crate::database::Database;
+use crate::error::GovernanceError;
 
 pub async fn handle_push_event(
     database: &Database,
     payload: &Value,
-) -> Result<axum::response::Json<serde_json::Value>, axum::http::StatusCode> {
+) -> Result<axum::response::Json<serde_json::Value>, GovernanceError> {
     let repo_name = payload
         .get("repository")
         .and_then(|r| r.get("full_name"))
@@ -56,7 +57,7 @@ pub async fn handle_push_event(
             }
             Err(e) => {
                 warn!("Failed to log bypass attempt: {}", e);
-                Err(axum::http::StatusCode::INTERNAL_SERVER_ERROR)
+                Err(e)
             }
         }
     } else {
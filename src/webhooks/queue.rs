@@ -0,0 +1,168 @@
+//! Webhook Queue
+//!
+//! GitHub abandons a delivery after 10 seconds, and classification and
+//! re-evaluation can take several GitHub API round trips. The webhook handler
+//! therefore only authenticates, deduplicates and enqueues; a fixed pool of
+//! workers drains the queue. The queue is bounded so a burst of deliveries is
+//! turned away with 503 instead of growing memory without limit.
+
+use serde_json::Value;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use tokio::sync::{mpsc, Mutex};
+use tokio::task::JoinHandle;
+use tracing::{debug, info_span, warn, Instrument};
+
+use crate::config::AppConfig;
+use crate::database::Database;
+use crate::enforcement::violations::ViolationNotifier;
+use crate::error::GovernanceError;
+use crate::github::client::GitHubClient;
+use crate::metrics::Metrics;
+use crate::webhooks::github::process_queued_webhook;
+
+/// An authenticated webhook waiting to be processed
+#[derive(Debug, Clone)]
+pub struct QueuedWebhook {
+    /// `X-GitHub-Delivery` id, if GitHub sent one
+    pub delivery_id: Option<String>,
+    /// `X-GitHub-Event` type, such as `pull_request` or `issue_comment`
+    pub event: Option<String>,
+    pub payload: Value,
+}
+
+/// Sending side of the webhook queue, cheap to clone
+#[derive(Clone)]
+pub struct WebhookQueue {
+    sender: mpsc::Sender<QueuedWebhook>,
+    pending: Arc<AtomicUsize>,
+    metrics: Metrics,
+}
+
+/// Receiving side of the webhook queue, handed to the worker pool
+pub struct WebhookReceiver {
+    receiver: mpsc::Receiver<QueuedWebhook>,
+    pending: Arc<AtomicUsize>,
+    metrics: Metrics,
+    violation_notifier: ViolationNotifier,
+    github_client: Option<GitHubClient>,
+}
+
+/// Running queue workers
+pub struct WebhookWorkers {
+    handles: Vec<JoinHandle<()>>,
+}
+
+impl WebhookQueue {
+    /// Create a queue holding at most `capacity` unprocessed webhooks
+    pub fn bounded(capacity: usize, metrics: Metrics) -> (Self, WebhookReceiver) {
+        let (sender, receiver) = mpsc::channel(capacity);
+        let pending = Arc::new(AtomicUsize::new(0));
+        (
+            Self {
+                sender,
+                pending: pending.clone(),
+                metrics: metrics.clone(),
+            },
+            WebhookReceiver {
+                receiver,
+                pending,
+                metrics,
+                violation_notifier: ViolationNotifier::default(),
+                github_client: None,
+            },
+        )
+    }
+
+    /// Enqueue a webhook without waiting for room
+    pub fn try_enqueue(&self, webhook: QueuedWebhook) -> Result<(), GovernanceError> {
+        // Count before sending so a fast worker can't decrement first
+        self.pending.fetch_add(1, Ordering::SeqCst);
+        match self.sender.try_send(webhook) {
+            Ok(()) => Ok(()),
+            Err(e) => {
+                self.pending.fetch_sub(1, Ordering::SeqCst);
+                self.metrics.record_webhook_rejected();
+                Err(GovernanceError::WebhookError(match e {
+                    mpsc::error::TrySendError::Full(_) => "Webhook queue is full".to_string(),
+                    mpsc::error::TrySendError::Closed(_) => "Webhook queue is closed".to_string(),
+                }))
+            }
+        }
+    }
+
+    /// Webhooks enqueued but not yet fully processed
+    pub fn pending(&self) -> usize {
+        self.pending.load(Ordering::SeqCst)
+    }
+}
+
+impl WebhookReceiver {
+    /// Report merges that bypassed governance through `notifier`'s sinks
+    pub fn with_violation_notifier(mut self, notifier: ViolationNotifier) -> Self {
+        self.violation_notifier = notifier;
+        self
+    }
+
+    /// Act on GitHub through `client`, scoped to each webhook's installation
+    ///
+    /// Without one, classification falls back to title/body only and merge
+    /// decisions are not re-evaluated.
+    pub fn with_github_client(mut self, client: Option<GitHubClient>) -> Self {
+        self.github_client = client;
+        self
+    }
+
+    /// Start `workers` tasks draining the queue
+    ///
+    /// Workers exit once every `WebhookQueue` has been dropped and the queue is empty.
+    pub fn spawn_workers(self, config: AppConfig, database: Database, workers: usize) -> WebhookWorkers {
+        let receiver = Arc::new(Mutex::new(self.receiver));
+        let handles = (0..workers)
+            .map(|worker| {
+                let receiver = receiver.clone();
+                let pending = self.pending.clone();
+                let config = config.clone();
+                let database = database.clone();
+                let metrics = self.metrics.clone();
+                let notifier = self.violation_notifier.clone();
+                let github_client = self.github_client.clone();
+                tokio::spawn(
+                    async move {
+                        loop {
+                            // Hold the lock only while waiting, not while processing
+                            let next = receiver.lock().await.recv().await;
+                            let Some(webhook) = next else {
+                                break;
+                            };
+                            process_queued_webhook(
+                                &config,
+                                &database,
+                                &metrics,
+                                &notifier,
+                                github_client.as_ref(),
+                                &webhook,
+                            )
+                            .await;
+                            pending.fetch_sub(1, Ordering::SeqCst);
+                        }
+                        debug!("Webhook worker {} stopped", worker);
+                    }
+                    .instrument(info_span!("webhook_worker", worker)),
+                )
+            })
+            .collect();
+        WebhookWorkers { handles }
+    }
+}
+
+impl WebhookWorkers {
+    /// Wait for the workers to finish the queued webhooks
+    pub async fn join(self) {
+        for handle in self.handles {
+            if let Err(e) = handle.await {
+                warn!("Webhook worker panicked: {}", e);
+            }
+        }
+    }
+}
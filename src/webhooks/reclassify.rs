@@ -0,0 +1,319 @@
+//! On-demand Tier Reclassification
+//!
+//! Lets maintainers re-run tier classification after pushing changes or
+//! fixing the classification config, without reopening the PR.
+
+use axum::{
+    extract::{Path, State},
+    http::{HeaderMap, StatusCode},
+    response::Json,
+};
+use serde_json::Value;
+use tracing::warn;
+
+use crate::auth::require_bearer;
+use crate::config::{AppConfig, Secret};
+use crate::database::Database;
+use crate::enforcement::decision_log::DecisionLogger;
+use crate::error::GovernanceError;
+use crate::github::client::GitHubClient;
+use crate::metrics::Metrics;
+use crate::webhooks::github_integration::GitHubIntegration;
+
+/// Handle `POST /prs/:repo/:number/reclassify`; `repo` is the URL-encoded full repository name
+///
+/// Disabled unless `admin_token` is configured; callers must present it as a
+/// bearer token. Returns the new `TierClassificationResult`.
+pub async fn handle_reclassify(
+    State((config, database, metrics, github)): State<(
+        AppConfig,
+        Database,
+        Metrics,
+        Option<GitHubClient>,
+    )>,
+    Path((repo, number)): Path<(String, u64)>,
+    headers: HeaderMap,
+) -> (StatusCode, Json<Value>) {
+    if let Err(e) = require_bearer(&headers, config.admin_token.as_ref().map(Secret::expose)) {
+        warn!("Rejected reclassification request for {}#{}: {}", repo, number, e);
+        return e.to_response();
+    }
+
+    match reclassify(&config, &database, github.as_ref(), &repo, number).await {
+        Ok(result) => {
+            metrics.record_pr_classified(result.tier);
+            (StatusCode::OK, Json(serde_json::json!(result)))
+        }
+        Err(e) => {
+            warn!("Failed to reclassify {}#{}: {}", repo, number, e);
+            e.to_response()
+        }
+    }
+}
+
+async fn reclassify(
+    config: &AppConfig,
+    database: &Database,
+    github: Option<&GitHubClient>,
+    repo_name: &str,
+    pr_number: u64,
+) -> Result<crate::validation::tier_classification::TierClassificationResult, GovernanceError> {
+    let (owner, repo) = repo_name.split_once('/').ok_or_else(|| {
+        GovernanceError::WebhookError(format!("Invalid repository name: {}", repo_name))
+    })?;
+    let github_client = github
+        .ok_or_else(|| {
+            GovernanceError::ConfigError("No GitHub App private key configured".to_string())
+        })?
+        .for_repository(owner, repo)
+        .await?;
+    let decision_logger = DecisionLogger::new(
+        config.dry_run_mode,
+        config.log_enforcement_decisions,
+        config.enforcement_log_path.clone(),
+    );
+    GitHubIntegration::new(github_client, database.clone(), decision_logger)
+        .with_review_period_policy(config.review_period_policy.clone())
+        .with_economic_quorums(config.economic_quorums.clone())
+        .with_check_runs(config.github_check_runs)
+        .reclassify_pr(repo_name, pr_number)
+        .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::body::{to_bytes, Body};
+    use axum::http::Request;
+    use axum::routing::post;
+    use axum::Router;
+    use tower::ServiceExt;
+    use wiremock::matchers::{body_partial_json, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    const TEST_KEY: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/test-app-key.pem");
+
+    async fn mock_github() -> MockServer {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/repos/BTCDecoded/consensus-proof/installation"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({"id": 42})))
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/app/installations/42/access_tokens"))
+            .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!({
+                "token": "ghs_installation",
+                "expires_at": "2099-01-01T00:00:00Z"
+            })))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/repos/BTCDecoded/consensus-proof/pulls/12"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "url": "https://api.github.com/repos/BTCDecoded/consensus-proof/pulls/12",
+                "id": 1012,
+                "number": 12,
+                "title": "Tighten block weight check",
+                "body": "Adjusts validation of block weight",
+                "head": {"ref": "weight", "sha": "abc123"},
+                "base": {"ref": "main", "sha": "def456"}
+            })))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/repos/BTCDecoded/consensus-proof/pulls/12/files"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+                {
+                    "sha": "0123",
+                    "filename": "src/consensus/block.rs",
+                    "status": "modified",
+                    "additions": 3,
+                    "deletions": 1,
+                    "changes": 4,
+                    "blob_url": "https://github.com/BTCDecoded/consensus-proof/blob/abc123/src/consensus/block.rs",
+                    "raw_url": "https://github.com/BTCDecoded/consensus-proof/raw/abc123/src/consensus/block.rs",
+                    "contents_url": "https://api.github.com/repos/BTCDecoded/consensus-proof/contents/src/consensus/block.rs"
+                }
+            ])))
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/repos/BTCDecoded/consensus-proof/statuses/abc123"))
+            .and(body_partial_json(serde_json::json!({"context": "governance/analysis"})))
+            .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!({})))
+            .expect(1)
+            .mount(&server)
+            .await;
+        server
+    }
+
+    async fn app(server: &MockServer, database: Database) -> Router {
+        let mut config = AppConfig::load().unwrap();
+        config.admin_token = Some(Secret::new("admin-secret"));
+        config.github_app_id = 1;
+        config.github_private_key = Some(Secret::new(std::fs::read_to_string(TEST_KEY).unwrap()));
+        config.github_api_url = server.uri();
+        config.dry_run_mode = false;
+        let github = GitHubClient::from_config(&config).ok();
+        Router::new()
+            .route("/prs/:repo/:number/reclassify", post(handle_reclassify))
+            .with_state((config, database, Metrics::new(), github))
+    }
+
+    fn reclassify_request(token: &str) -> Request<Body> {
+        Request::post("/prs/BTCDecoded%2Fconsensus-proof/12/reclassify")
+            .header("authorization", format!("Bearer {}", token))
+            .body(Body::empty())
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_reclassify_fetches_files_and_posts_tier_status() {
+        let server = mock_github().await;
+        let database = Database::new_in_memory().await.unwrap();
+        let app = app(&server, database.clone()).await;
+
+        let response = app.oneshot(reclassify_request("admin-secret")).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let result: Value = serde_json::from_slice(&body).unwrap();
+
+        let expected = crate::validation::tier_classification::classify_pr_tier_detailed(
+            &serde_json::json!({"pull_request": {
+                "title": "Tighten block weight check",
+                "body": "Adjusts validation of block weight",
+                "files": [{"filename": "src/consensus/block.rs"}]
+            }}),
+            &crate::validation::tier_classification::current_config().await,
+        )
+        .await;
+        assert_eq!(result["tier"], expected.tier);
+
+        let events = database
+            .get_pr_governance_events("BTCDecoded/consensus-proof", 12)
+            .await
+            .unwrap();
+        assert!(events.iter().any(|e| e.event_type == "pr_reclassified"
+            && e.details["tier"] == expected.tier));
+        // The status mock expects exactly one governance/analysis post
+        server.verify().await;
+    }
+
+    #[tokio::test]
+    async fn test_reclassify_reevaluates_tracked_pr() {
+        let server = mock_github().await;
+        Mock::given(method("POST"))
+            .and(path("/repos/BTCDecoded/consensus-proof/statuses/abc123"))
+            .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!({})))
+            .mount(&server)
+            .await;
+        let database = Database::new_in_memory().await.unwrap();
+        database
+            .create_pull_request("BTCDecoded/consensus-proof", 12, "abc123", 2)
+            .await
+            .unwrap();
+        database
+            .log_governance_event(
+                "pr_opened",
+                Some("BTCDecoded/consensus-proof"),
+                Some(12),
+                None,
+                &serde_json::json!({"tier": 1}),
+            )
+            .await
+            .unwrap();
+        let app = app(&server, database.clone()).await;
+
+        let response = app.oneshot(reclassify_request("admin-secret")).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let result: Value = serde_json::from_slice(&body).unwrap();
+
+        // The merge decision is re-posted at the new tier, not left at Tier 1
+        let posted = database
+            .get_merge_decision("BTCDecoded/consensus-proof", 12)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(posted.head_sha, "abc123");
+        assert_eq!(serde_json::json!(posted.tier), result["tier"]);
+    }
+
+    #[tokio::test]
+    async fn test_reclassify_honors_manual_override() {
+        let server = mock_github().await;
+        let database = Database::new_in_memory().await.unwrap();
+        database
+            .log_governance_event(
+                "tier_override",
+                Some("BTCDecoded/consensus-proof"),
+                Some(12),
+                Some("alice"),
+                &serde_json::json!({"tier": 5, "rationale": "touches governance config"}),
+            )
+            .await
+            .unwrap();
+        let app = app(&server, database.clone()).await;
+
+        let response = app.oneshot(reclassify_request("admin-secret")).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let result: Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(result["tier"], 5);
+        assert!(result["rationale"].as_str().unwrap().contains("Manual override by alice"));
+
+        // The automatic tier is what gets recorded, so clearing the override restores it
+        let events = database
+            .get_pr_governance_events("BTCDecoded/consensus-proof", 12)
+            .await
+            .unwrap();
+        let reclassified = events.iter().find(|e| e.event_type == "pr_reclassified").unwrap();
+        assert_ne!(reclassified.details["tier"], 5);
+    }
+
+    #[tokio::test]
+    async fn test_reclassify_requires_admin_token() {
+        let server = MockServer::start().await;
+        let app = app(&server, Database::new_in_memory().await.unwrap()).await;
+        let response = app.oneshot(reclassify_request("wrong")).await.unwrap();
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let body: Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(body["code"], "AUTHENTICATION_ERROR");
+    }
+
+    #[tokio::test]
+    async fn test_reclassify_status_follows_error_kind() {
+        // The app is not installed on this repository
+        let server = MockServer::start().await;
+        let app = app(&server, Database::new_in_memory().await.unwrap()).await;
+        let response = app.oneshot(reclassify_request("admin-secret")).await.unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+        // GitHub keeps refusing with a rate limit
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/repos/BTCDecoded/consensus-proof/installation"))
+            .respond_with(ResponseTemplate::new(429).insert_header("retry-after", "0"))
+            .mount(&server)
+            .await;
+        let app = app(&server, Database::new_in_memory().await.unwrap()).await;
+        let response = app.oneshot(reclassify_request("admin-secret")).await.unwrap();
+        assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+
+        // A repository name without an owner is the caller's mistake
+        let server = MockServer::start().await;
+        let app = app(&server, Database::new_in_memory().await.unwrap()).await;
+        let response = app
+            .oneshot(
+                Request::post("/prs/consensus-proof/12/reclassify")
+                    .header("authorization", "Bearer admin-secret")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+}
@@ -0,0 +1,316 @@
+//! Reconciliation With GitHub
+//!
+//! Webhooks GitHub delivered while the server was down are lost, so tracked
+//! PRs drift: a missed push leaves a stale head SHA, a missed merge or close
+//! leaves the PR pending. The reconciler re-reads each pending PR from GitHub,
+//! replays what was missed through the regular webhook handlers and
+//! re-evaluates the merge decision.
+
+use serde_json::Value;
+use tracing::{info, info_span, warn, Instrument};
+
+use crate::config::loader::EconomicQuorums;
+use crate::database::models::PullRequest;
+use crate::database::Database;
+use crate::enforcement::decision_log::DecisionLogger;
+use crate::enforcement::merge_block::MergeDecision;
+use crate::enforcement::violations::ViolationNotifier;
+use crate::error::GovernanceError;
+use crate::github::client::GitHubClient;
+use crate::validation::review_period::ReviewPeriodPolicy;
+use crate::webhooks::github_integration::GitHubIntegration;
+use crate::webhooks::pull_request;
+
+/// What reconciling a single PR found
+#[derive(Debug, Clone)]
+pub enum Reconciled {
+    /// Still open on GitHub; `head_updated` if a missed push moved its head
+    Open {
+        head_updated: bool,
+        decision: Option<MergeDecision>,
+    },
+    /// Merged or closed on GitHub while the event was missed
+    Resolved { merged: bool },
+}
+
+/// Totals for one reconciliation pass
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ReconcileReport {
+    pub checked: usize,
+    pub head_updated: usize,
+    pub resolved: usize,
+    pub failed: usize,
+}
+
+pub struct PrReconciler {
+    github_client: GitHubClient,
+    database: Database,
+    decision_logger: DecisionLogger,
+    review_period_policy: ReviewPeriodPolicy,
+    economic_quorums: EconomicQuorums,
+    violation_notifier: ViolationNotifier,
+    check_runs: bool,
+}
+
+impl PrReconciler {
+    /// `github_client` is an app client; it is scoped to each repository's installation
+    pub fn new(github_client: GitHubClient, database: Database, decision_logger: DecisionLogger) -> Self {
+        Self {
+            github_client,
+            database,
+            decision_logger,
+            review_period_policy: ReviewPeriodPolicy::default(),
+            economic_quorums: EconomicQuorums::default(),
+            violation_notifier: ViolationNotifier::default(),
+            check_runs: false,
+        }
+    }
+
+    /// Count review periods under `policy` instead of calendar days
+    pub fn with_review_period_policy(mut self, policy: ReviewPeriodPolicy) -> Self {
+        self.review_period_policy = policy;
+        self
+    }
+
+    /// Require economic node quorums from `quorums` instead of the built-in defaults
+    pub fn with_economic_quorums(mut self, quorums: EconomicQuorums) -> Self {
+        self.economic_quorums = quorums;
+        self
+    }
+
+    /// Report missed merges that bypassed governance to `notifier`
+    pub fn with_violation_notifier(mut self, notifier: ViolationNotifier) -> Self {
+        self.violation_notifier = notifier;
+        self
+    }
+
+    /// Also report refreshed merge decisions as check runs
+    pub fn with_check_runs(mut self, enabled: bool) -> Self {
+        self.check_runs = enabled;
+        self
+    }
+
+    /// Reconcile every pending PR; merged and closed PRs are left alone
+    ///
+    /// A PR that fails is logged and counted, and the pass moves on.
+    pub async fn run_once(&self) -> Result<ReconcileReport, GovernanceError> {
+        let mut report = ReconcileReport::default();
+
+        for pr in self.database.get_pending_pull_requests().await? {
+            report.checked += 1;
+            let span = info_span!("reconcile", repo = %pr.repo_name, pr_number = pr.pr_number);
+            match self.reconcile_pr(&pr).instrument(span).await {
+                Ok(Reconciled::Open { head_updated, .. }) => {
+                    if head_updated {
+                        report.head_updated += 1;
+                    }
+                }
+                Ok(Reconciled::Resolved { .. }) => report.resolved += 1,
+                Err(e) => {
+                    warn!("Failed to reconcile {}#{}: {}", pr.repo_name, pr.pr_number, e);
+                    report.failed += 1;
+                }
+            }
+        }
+
+        info!(
+            "Reconciled {} pending PRs ({} head updates, {} resolved, {} failed)",
+            report.checked, report.head_updated, report.resolved, report.failed
+        );
+        Ok(report)
+    }
+
+    /// Bring one tracked PR in line with GitHub
+    pub async fn reconcile_pr(&self, pr: &PullRequest) -> Result<Reconciled, GovernanceError> {
+        let (owner, repo) = pr.repo_name.split_once('/').ok_or_else(|| {
+            GovernanceError::WebhookError(format!("Invalid repository name: {}", pr.repo_name))
+        })?;
+        let github_client = self.github_client.for_repository(owner, repo).await?;
+        let remote = github_client
+            .get_pull_request(owner, repo, pr.pr_number as u64)
+            .await?;
+
+        if remote.get("state").and_then(|s| s.as_str()) == Some("closed") {
+            let merged = remote.get("merged_at").is_some_and(|m| !m.is_null());
+            info!(
+                "{}#{} was {} on GitHub while the event was missed",
+                pr.repo_name,
+                pr.pr_number,
+                if merged { "merged" } else { "closed" }
+            );
+            let payload = serde_json::json!({
+                "action": "closed",
+                "repository": {"full_name": &pr.repo_name},
+                "pull_request": {"number": pr.pr_number, "merged": merged}
+            });
+            pull_request::handle_pull_request_closed(
+                &self.database,
+                &payload,
+                &self.review_period_policy,
+                &self.economic_quorums,
+                &self.violation_notifier,
+            )
+            .await?;
+            return Ok(Reconciled::Resolved { merged });
+        }
+
+        let head_sha = remote
+            .get("head_sha")
+            .and_then(|s| s.as_str())
+            .ok_or_else(|| {
+                GovernanceError::GitHubError(format!(
+                    "No head SHA for {}#{}",
+                    pr.repo_name, pr.pr_number
+                ))
+            })?;
+        let head_updated = head_sha != pr.head_sha;
+        if head_updated {
+            info!(
+                "{}#{} moved from {} to {} while the push was missed",
+                pr.repo_name, pr.pr_number, pr.head_sha, head_sha
+            );
+            let payload = synchronize_payload(pr, &remote, head_sha);
+            pull_request::handle_pull_request_event(&self.database, &payload, Some(&github_client))
+                .await?;
+        }
+
+        let decision = GitHubIntegration::new(
+            github_client,
+            self.database.clone(),
+            self.decision_logger.clone(),
+        )
+        .with_review_period_policy(self.review_period_policy.clone())
+        .with_economic_quorums(self.economic_quorums.clone())
+        .with_check_runs(self.check_runs)
+        .reevaluate_pr(&pr.repo_name, pr.pr_number as u64)
+        .await?;
+        Ok(Reconciled::Open {
+            head_updated,
+            decision,
+        })
+    }
+}
+
+/// The `synchronize` webhook payload GitHub would have sent for the new head
+fn synchronize_payload(pr: &PullRequest, remote: &Value, head_sha: &str) -> Value {
+    serde_json::json!({
+        "action": "synchronize",
+        "repository": {"full_name": &pr.repo_name},
+        "pull_request": {
+            "number": pr.pr_number,
+            "title": remote.get("title"),
+            "body": remote.get("body"),
+            "head": {"sha": head_sha}
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    const TEST_KEY: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/test-app-key.pem");
+    const REPO: &str = "BTCDecoded/developer-sdk";
+
+    async fn mock_github(pull_request: Value) -> MockServer {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/repos/BTCDecoded/developer-sdk/installation"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({"id": 42})))
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/app/installations/42/access_tokens"))
+            .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!({
+                "token": "ghs_installation",
+                "expires_at": "2099-01-01T00:00:00Z"
+            })))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/repos/BTCDecoded/developer-sdk/pulls/7"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(pull_request))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/repos/BTCDecoded/developer-sdk/pulls/7/files"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([])))
+            .mount(&server)
+            .await;
+        server
+    }
+
+    fn remote_pr(state: &str, head_sha: &str, merged_at: Option<&str>) -> Value {
+        serde_json::json!({
+            "url": "https://api.github.com/repos/BTCDecoded/developer-sdk/pulls/7",
+            "id": 1007,
+            "number": 7,
+            "state": state,
+            "title": "Add builder for transactions",
+            "body": "",
+            "merged_at": merged_at,
+            "head": {"ref": "builder", "sha": head_sha},
+            "base": {"ref": "main", "sha": "def456"}
+        })
+    }
+
+    fn reconciler(server: &MockServer, database: &Database) -> PrReconciler {
+        let key = std::fs::read_to_string(TEST_KEY).unwrap();
+        let github_client = GitHubClient::from_app_key_with_base_uri(1, &key, &server.uri()).unwrap();
+        let decision_logger = DecisionLogger::new(false, false, None);
+        PrReconciler::new(github_client, database.clone(), decision_logger)
+    }
+
+    #[tokio::test]
+    async fn test_missed_push_refreshes_head_and_decision() {
+        let server = mock_github(remote_pr("open", "new456", None)).await;
+        Mock::given(method("POST"))
+            .and(path("/repos/BTCDecoded/developer-sdk/statuses/new456"))
+            .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!({})))
+            .expect(1..)
+            .mount(&server)
+            .await;
+        let database = Database::new_in_memory().await.unwrap();
+        database.create_pull_request(REPO, 7, "old123", 5).await.unwrap();
+
+        let pr = database.get_pull_request(REPO, 7).await.unwrap().unwrap();
+        let reconciled = reconciler(&server, &database).reconcile_pr(&pr).await.unwrap();
+        let Reconciled::Open { head_updated, decision } = reconciled else {
+            panic!("open PR reported as resolved");
+        };
+        assert!(head_updated);
+        // Just opened, so the review period alone keeps it blocked
+        assert!(decision.unwrap().blocked);
+
+        let pr = database.get_pull_request(REPO, 7).await.unwrap().unwrap();
+        assert_eq!(pr.head_sha, "new456");
+        assert_eq!(pr.governance_status, "pending");
+        let events = database.get_pr_governance_events(REPO, 7).await.unwrap();
+        assert!(events
+            .iter()
+            .any(|e| e.event_type == "pr_synchronized" && e.details["head_sha"] == "new456"));
+
+        // The decision was posted on the new head, not the stale one
+        server.verify().await;
+    }
+
+    #[tokio::test]
+    async fn test_missed_merge_resolves_pr() {
+        let server = mock_github(remote_pr("closed", "old123", Some("2025-01-02T00:00:00Z"))).await;
+        let database = Database::new_in_memory().await.unwrap();
+        database.create_pull_request(REPO, 7, "old123", 5).await.unwrap();
+
+        let pr = database.get_pull_request(REPO, 7).await.unwrap().unwrap();
+        let reconciled = reconciler(&server, &database).reconcile_pr(&pr).await.unwrap();
+        assert!(matches!(reconciled, Reconciled::Resolved { merged: true }));
+
+        let pr = database.get_pull_request(REPO, 7).await.unwrap().unwrap();
+        assert_eq!(pr.governance_status, "merged");
+
+        // Resolved PRs are no longer pending, so the next pass skips them
+        let report = reconciler(&server, &database).run_once().await.unwrap();
+        assert_eq!(report, ReconcileReport::default());
+    }
+}
@@ -2,11 +2,12 @@ use serde_json::Value;
 use tracing::{info, warn};
 
 use crate::database::Database;
+use crate::error::GovernanceError;
 
 pub async fn handle_review_event(
     database: &Database,
     payload: &Value,
-) -> Result<axum::response::Json<serde_json::Value>, axum::http::StatusCode> {
+) -> Result<axum::response::Json<serde_json::Value>, GovernanceError> {
     let repo_name = payload
         .get("repository")
         .and_then(|r| r.get("full_name"))
@@ -17,7 +18,7 @@ pub async fn handle_review_event(
         .get("pull_request")
         .and_then(|pr| pr.get("number"))
         .and_then(|n| n.as_u64())
-        .unwrap_or(0);
+        .ok_or_else(|| GovernanceError::WebhookError("payload missing pull_request.number".to_string()))?;
 
     let reviewer = payload
         .get("review")
@@ -50,7 +51,7 @@ pub async fn handle_review_event(
         }
         Err(e) => {
             warn!("Failed to update review status: {}", e);
-            Err(axum::http::StatusCode::INTERNAL_SERVER_ERROR)
+            Err(e)
         }
     }
 }
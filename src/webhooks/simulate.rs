@@ -0,0 +1,293 @@
+//! Webhook Simulation
+//!
+//! Runs a webhook-shaped payload through classification, threshold computation
+//! and merge-decision logic without calling GitHub, so operators can check a
+//! deployment's governance config against realistic PRs.
+
+use axum::{
+    extract::State,
+    http::{HeaderMap, StatusCode},
+    response::Json,
+};
+use chrono::{DateTime, Utc};
+use serde_json::Value;
+use tracing::{info, warn};
+
+use crate::auth::require_bearer;
+use crate::config::loader::EconomicQuorums;
+use crate::config::{AppConfig, Secret};
+use crate::database::Database;
+use crate::enforcement::merge_block::{BlockReason, MergeBlocker, MergeRequirements};
+use crate::enforcement::pr_status::{PrStatus, ReviewProgress, SignatureProgress, VetoStatus};
+use crate::enforcement::status_checks::StatusCheckGenerator;
+use crate::error::GovernanceError;
+use crate::metrics::Metrics;
+use crate::validation::review_period::{ReviewPeriodPolicy, ReviewPeriodValidator};
+use crate::validation::threshold::ThresholdValidator;
+use crate::validation::tier_classification;
+use crate::webhooks::pull_request::layer_for_repo;
+
+/// Handle `POST /admin/simulate-webhook`
+///
+/// Disabled unless `admin_token` is configured; callers must present it as a
+/// bearer token. Simulation is always dry-run regardless of `dry_run_mode`.
+pub async fn handle_simulate_webhook(
+    State((config, database, _metrics)): State<(AppConfig, Database, Metrics)>,
+    headers: HeaderMap,
+    Json(payload): Json<Value>,
+) -> (StatusCode, Json<Value>) {
+    if let Err(e) = require_bearer(&headers, config.admin_token.as_ref().map(Secret::expose)) {
+        warn!("Rejected webhook simulation request: {}", e);
+        return e.to_response();
+    }
+
+    match simulate_webhook(
+        &database,
+        &payload,
+        &config.review_period_policy,
+        &config.economic_quorums,
+    )
+    .await
+    {
+        Ok(result) => (StatusCode::OK, Json(result)),
+        Err(e) => {
+            warn!("Webhook simulation failed: {}", e);
+            e.to_response()
+        }
+    }
+}
+
+/// Compute the governance decisions a webhook would produce, without side effects
+///
+/// A PR that is already tracked is evaluated exactly as `/status` and the
+/// posted merge check evaluate it: from its stored row, recorded tier and
+/// layer maintainers' signatures. Only an untracked PR is classified from
+/// the payload, as a new PR with no signatures or economic signals yet.
+pub async fn simulate_webhook(
+    database: &Database,
+    payload: &Value,
+    review_period_policy: &ReviewPeriodPolicy,
+    economic_quorums: &EconomicQuorums,
+) -> Result<Value, GovernanceError> {
+    let repo_name = payload
+        .get("repository")
+        .and_then(|r| r.get("full_name"))
+        .and_then(|n| n.as_str())
+        .ok_or_else(|| GovernanceError::WebhookError("Missing repository name".to_string()))?;
+    let pr_number = payload
+        .get("pull_request")
+        .and_then(|pr| pr.get("number"))
+        .and_then(|n| n.as_i64())
+        .ok_or_else(|| GovernanceError::WebhookError("Missing PR number".to_string()))?
+        as i32;
+    let layer = layer_for_repo(repo_name).ok_or_else(|| {
+        GovernanceError::WebhookError(format!("Unknown repository: {}", repo_name))
+    })?;
+
+    info!("Simulating webhook for {}#{}", repo_name, pr_number);
+
+    let now = Utc::now();
+    let (status, tracked) = match PrStatus::load(
+        database,
+        repo_name,
+        pr_number,
+        now,
+        review_period_policy,
+        economic_quorums,
+    )
+    .await?
+    {
+        Some(status) => (status, true),
+        None => (
+            untracked_status(
+                database,
+                payload,
+                repo_name,
+                pr_number,
+                layer,
+                now,
+                review_period_policy,
+                economic_quorums,
+            )
+            .await?,
+            false,
+        ),
+    };
+
+    let mut status_checks = vec![
+        serde_json::json!({
+            "context": "governance/review-period",
+            "description": StatusCheckGenerator::generate_review_period_status_with_policy(
+                status.review.opened_at,
+                status.review.required_days,
+                status.emergency_mode,
+                true,
+                review_period_policy,
+            ),
+        }),
+        serde_json::json!({
+            "context": "governance/signatures",
+            "description": StatusCheckGenerator::generate_signature_status_with_dry_run(
+                status.signatures.collected,
+                status.signatures.required,
+                status.signatures.total,
+                &status.signatures.signers,
+                &status.signatures.pending,
+                true,
+            ),
+        }),
+    ];
+    if status.tier >= 3 {
+        status_checks.push(serde_json::json!({
+            "context": "governance/economic-quorum",
+            "description": format!(
+                "[DRY-RUN] {}",
+                StatusCheckGenerator::generate_economic_quorum_status(
+                    status.veto.signal_count,
+                    status.veto.quorum,
+                )
+            ),
+        }));
+    }
+    status_checks.push(serde_json::json!({
+        "context": "governance/merge-check",
+        "description": format!(
+            "[DRY-RUN] {}",
+            StatusCheckGenerator::generate_merge_decision_status(&status.decision)
+        ),
+    }));
+
+    let blockers: Vec<&str> = status.decision.reasons.iter().map(blocker_name).collect();
+
+    Ok(serde_json::json!({
+        "dry_run": true,
+        "tracked": tracked,
+        "repository": repo_name,
+        "pr_number": pr_number,
+        "layer": status.layer,
+        "tier": status.tier,
+        "requirements": {
+            "signatures_required": status.signatures.required,
+            "signatures_total": status.signatures.total,
+            "review_period_days": status.review.required_days,
+            "economic_quorum": status.veto.quorum,
+            "source": ThresholdValidator::get_requirement_source(status.layer, status.tier)
+        },
+        "state": {
+            "opened_at": status.review.opened_at,
+            "emergency_mode": status.emergency_mode,
+            "signatures": status.signatures.collected,
+            "economic_signals": status.veto.signal_count,
+            "review_period_met": status.review.met,
+            "signatures_met": status.signatures.collected >= status.signatures.required,
+            "economic_veto_active": status.veto.active,
+            "economic_quorum_met": status.veto.quorum_met
+        },
+        "status_checks": status_checks,
+        "merge": {
+            "blocked": status.decision.blocked,
+            "reason": status.decision.summary(),
+            "blockers": blockers
+        }
+    }))
+}
+
+/// Status of a PR the app hasn't seen, classified from the payload
+#[allow(clippy::too_many_arguments)]
+async fn untracked_status(
+    database: &Database,
+    payload: &Value,
+    repo_name: &str,
+    pr_number: i32,
+    layer: i32,
+    now: DateTime<Utc>,
+    review_period_policy: &ReviewPeriodPolicy,
+    economic_quorums: &EconomicQuorums,
+) -> Result<PrStatus, GovernanceError> {
+    let classified = tier_classification::classify_pr_tier_with_rationale(payload).await;
+    let tier = tier_classification::effective_tier(database, repo_name, pr_number, classified)
+        .await?
+        .tier;
+    let (required, total, required_days) = ThresholdValidator::get_combined_requirements(layer, tier);
+
+    let opened_at = payload
+        .get("pull_request")
+        .and_then(|pr| pr.get("created_at"))
+        .and_then(|t| t.as_str())
+        .and_then(|t| DateTime::parse_from_rfc3339(t).ok())
+        .map(|t| t.with_timezone(&Utc))
+        .unwrap_or(now);
+    let earliest_merge = ReviewPeriodValidator::get_earliest_merge_date_with_policy(
+        opened_at,
+        required_days,
+        false,
+        review_period_policy,
+    );
+    let pending = database
+        .get_maintainers_for_layer(layer)
+        .await?
+        .into_iter()
+        .map(|m| m.github_username)
+        .collect();
+    let quorum = economic_quorums.for_tier(tier);
+    let veto = VetoStatus {
+        active: false,
+        quorum,
+        signal_count: 0,
+        quorum_met: ThresholdValidator::economic_quorum_met(tier, 0, quorum),
+        breakdown: None,
+    };
+    let review = ReviewProgress {
+        opened_at,
+        required_days,
+        elapsed_days: (now - opened_at).num_days(),
+        earliest_merge,
+        met: now >= earliest_merge,
+    };
+    let decision = MergeBlocker::evaluate_merge(&MergeRequirements {
+        review_period_met: review.met,
+        current_signatures: 0,
+        required_signatures: required,
+        economic_veto_active: false,
+        economic_quorum_met: veto.quorum_met,
+        cross_layer_synced: true,
+        tier,
+        emergency_mode: false,
+    });
+
+    Ok(PrStatus {
+        repo_name: repo_name.to_string(),
+        pr_number,
+        layer,
+        head_sha: payload
+            .get("pull_request")
+            .and_then(|pr| pr.get("head"))
+            .and_then(|h| h.get("sha"))
+            .and_then(|s| s.as_str())
+            .unwrap_or("unknown")
+            .to_string(),
+        tier,
+        emergency_mode: false,
+        signatures: SignatureProgress {
+            required,
+            total,
+            collected: 0,
+            signers: vec![],
+            pending,
+        },
+        review,
+        veto,
+        decision,
+    })
+}
+
+/// Short name of a blocking requirement, as listed under `merge.blockers`
+fn blocker_name(reason: &BlockReason) -> &'static str {
+    match reason {
+        BlockReason::ReviewPeriodNotMet => "review_period",
+        BlockReason::InsufficientSignatures { .. } => "signatures",
+        BlockReason::EconomicVetoActive => "economic_veto",
+        BlockReason::EconomicQuorumNotMet => "economic_quorum",
+        BlockReason::CrossLayerUnsynced => "cross_layer",
+    }
+}
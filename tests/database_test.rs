@@ -0,0 +1,403 @@
+//! Database Tests
+//!
+//! Round-trip tests for pull request state stored by the database layer
+
+use governance_app::database::models::{PullRequestStatus, PullRequestUpsert};
+use chrono::Utc;
+use governance_app::config::loader::EconomicQuorums;
+use governance_app::database::Database;
+use governance_app::enforcement::pr_status;
+use governance_app::validation::review_period::ReviewPeriodPolicy;
+
+#[tokio::test]
+async fn test_get_pull_request_round_trip() -> Result<(), Box<dyn std::error::Error>> {
+    println!("🧪 Testing pull request round-trip...");
+
+    let db = Database::new_in_memory().await?;
+    let repo_name = "BTCDecoded/consensus-proof";
+
+    db.create_pull_request(repo_name, 123, "abc123def456", 2).await?;
+
+    let pr = db
+        .get_pull_request(repo_name, 123)
+        .await?
+        .expect("pull request should exist");
+    assert_eq!(pr.repo_name, repo_name);
+    assert_eq!(pr.pr_number, 123);
+    assert_eq!(pr.layer, 2);
+    assert_eq!(pr.head_sha, "abc123def456");
+    assert_eq!(pr.governance_status, "pending");
+    assert!(pr.signatures.is_empty());
+    assert!(!pr.emergency_mode);
+    assert!(pr.opened_at <= chrono::Utc::now());
+    println!("✅ Pull request fields round-trip");
+
+    // Updating the head SHA keeps the original row
+    db.create_pull_request(repo_name, 123, "fedcba654321", 2).await?;
+    let updated = db.get_pull_request(repo_name, 123).await?.unwrap();
+    assert_eq!(updated.id, pr.id);
+    assert_eq!(updated.head_sha, "fedcba654321");
+    println!("✅ Head SHA update is visible");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_get_pull_request_missing() -> Result<(), Box<dyn std::error::Error>> {
+    println!("🧪 Testing missing pull request lookup...");
+
+    let db = Database::new_in_memory().await?;
+    db.create_pull_request("BTCDecoded/consensus-proof", 123, "abc123", 2).await?;
+
+    assert!(db.get_pull_request("BTCDecoded/consensus-proof", 999).await?.is_none());
+    assert!(db.get_pull_request("BTCDecoded/protocol-engine", 123).await?.is_none());
+    println!("✅ Unknown pull requests return None");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_add_signature_deduplicates_by_signer() -> Result<(), Box<dyn std::error::Error>> {
+    println!("🧪 Testing signature persistence...");
+
+    let db = Database::new_in_memory().await?;
+    let repo_name = "BTCDecoded/consensus-proof";
+    db.create_pull_request(repo_name, 123, "abc123", 2).await?;
+
+    db.add_signature(repo_name, 123, "alice", "sig_alice_1").await?;
+    db.add_signature(repo_name, 123, "bob", "sig_bob").await?;
+    db.add_signature(repo_name, 123, "charlie", "sig_charlie").await?;
+    db.add_signature(repo_name, 123, "alice", "sig_alice_2").await?;
+
+    let pr = db.get_pull_request(repo_name, 123).await?.unwrap();
+    assert_eq!(pr.signatures.len(), 3);
+
+    let alice: Vec<_> = pr.signatures.iter().filter(|s| s.signer == "alice").collect();
+    assert_eq!(alice.len(), 1);
+    assert_eq!(alice[0].signature, "sig_alice_2");
+    println!("✅ Re-signing replaces the earlier entry");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_add_signature_requires_existing_pr() -> Result<(), Box<dyn std::error::Error>> {
+    println!("🧪 Testing signature on unknown PR...");
+
+    let db = Database::new_in_memory().await?;
+    let result = db.add_signature("BTCDecoded/consensus-proof", 999, "alice", "sig").await;
+    assert!(result.is_err());
+    println!("✅ Signing an unknown PR returns an error");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_review_status_latest_state_wins() -> Result<(), Box<dyn std::error::Error>> {
+    println!("🧪 Testing review status tracking...");
+
+    let db = Database::new_in_memory().await?;
+    let repo_name = "BTCDecoded/consensus-proof";
+
+    db.update_review_status(repo_name, 123, "alice", "APPROVED").await?;
+    db.update_review_status(repo_name, 123, "bob", "approved").await?;
+    db.update_review_status(repo_name, 123, "alice", "CHANGES_REQUESTED").await?;
+
+    let reviews = db.get_reviews(repo_name, 123).await?;
+    assert_eq!(
+        reviews,
+        vec![
+            ("alice".to_string(), "CHANGES_REQUESTED".to_string()),
+            ("bob".to_string(), "APPROVED".to_string()),
+        ]
+    );
+    assert!(db.get_reviews(repo_name, 456).await?.is_empty());
+    println!("✅ Latest review state per reviewer is stored");
+
+    Ok(())
+}
+
+async fn insert_maintainer(
+    db: &Database,
+    username: &str,
+    layer: i32,
+    active: bool,
+) -> Result<(), Box<dyn std::error::Error>> {
+    sqlx::query("INSERT INTO maintainers (github_username, public_key, layer, active) VALUES (?, ?, ?, ?)")
+        .bind(username)
+        .bind(format!("{}_key", username))
+        .bind(layer)
+        .bind(active)
+        .execute(db.get_sqlite_pool().unwrap())
+        .await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_maintainer_lookup_includes_inactive_on_request() -> Result<(), Box<dyn std::error::Error>> {
+    println!("🧪 Testing maintainer lookup by username...");
+
+    let db = Database::new_in_memory().await?;
+    insert_maintainer(&db, "alice", 3, true).await?;
+    insert_maintainer(&db, "mallory", 3, false).await?;
+
+    assert!(db.get_maintainer("alice", false).await?.unwrap().active);
+    assert!(db.get_maintainer("mallory", false).await?.is_none());
+    assert!(db.get_maintainer_by_username("mallory").await?.is_none());
+    let suspended = db.get_maintainer("mallory", true).await?.expect("inactive maintainer");
+    assert!(!suspended.active);
+    assert!(db.get_maintainer("nobody", true).await?.is_none());
+    println!("✅ Inactive maintainers only returned when requested");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_maintainers_for_layer_scoped_to_active_layer_members() -> Result<(), Box<dyn std::error::Error>> {
+    println!("🧪 Testing layer-scoped maintainer sets...");
+
+    let db = Database::new_in_memory().await?;
+    insert_maintainer(&db, "carol", 3, true).await?;
+    insert_maintainer(&db, "alice", 3, true).await?;
+    insert_maintainer(&db, "mallory", 3, false).await?;
+    insert_maintainer(&db, "bob", 1, true).await?;
+    insert_maintainer(&db, "dave", 1, false).await?;
+
+    let layer3: Vec<String> = db
+        .get_maintainers_for_layer(3)
+        .await?
+        .into_iter()
+        .map(|m| m.github_username)
+        .collect();
+    assert_eq!(layer3, vec!["alice", "carol"]);
+
+    let layer1: Vec<String> = db
+        .get_maintainers_for_layer(1)
+        .await?
+        .into_iter()
+        .map(|m| m.github_username)
+        .collect();
+    assert_eq!(layer1, vec!["bob"]);
+    assert!(db.get_maintainers_for_layer(5).await?.is_empty());
+    println!("✅ Only active maintainers of the layer are returned");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_sqlite_performance_stats_not_degraded() -> Result<(), Box<dyn std::error::Error>> {
+    println!("🧪 Testing SQLite performance stats...");
+
+    let db = Database::new_in_memory().await?;
+    let stats = db.get_performance_stats().await?;
+    assert!(!stats.degraded);
+    assert!(stats.degraded_reason.is_none());
+    println!("✅ SQLite stats are complete");
+
+    Ok(())
+}
+
+#[cfg(feature = "postgres-tests")]
+#[tokio::test]
+async fn test_postgres_performance_stats() -> Result<(), Box<dyn std::error::Error>> {
+    println!("🧪 Testing Postgres performance stats...");
+
+    let url = std::env::var("GOVERNANCE_TEST_POSTGRES_URL")
+        .expect("GOVERNANCE_TEST_POSTGRES_URL must be set with the postgres-tests feature");
+    let db = Database::new(&url).await?;
+
+    let stats = db.get_performance_stats_with_threshold(0.0).await?;
+    assert!(stats.cache_size > 0);
+    assert!(stats.wal_checkpoint_threshold > 0);
+    if stats.degraded {
+        assert_eq!(stats.slow_queries_count, 0);
+        assert!(stats.degraded_reason.is_some());
+        println!("✅ Missing pg_stat_statements reported as degraded");
+    } else {
+        assert!(stats.degraded_reason.is_none());
+        println!("✅ Slow query count read from pg_stat_statements");
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_create_pull_request_reports_created_then_updated() -> Result<(), Box<dyn std::error::Error>> {
+    println!("🧪 Testing idempotent pull request creation...");
+
+    let db = Database::new_in_memory().await?;
+    let repo_name = "BTCDecoded/consensus-proof";
+
+    let first = db.create_pull_request(repo_name, 123, "abc123", 2).await?;
+    assert_eq!(first, PullRequestUpsert::Created);
+
+    // Backdate so a reset of opened_at would be visible
+    sqlx::query("UPDATE pull_requests SET opened_at = '2024-01-01 00:00:00' WHERE pr_number = 123")
+        .execute(db.pool().unwrap())
+        .await?;
+    let opened_at = db.get_pull_request(repo_name, 123).await?.unwrap().opened_at;
+
+    let second = db.create_pull_request(repo_name, 123, "def456", 3).await?;
+    assert_eq!(second, PullRequestUpsert::Updated);
+
+    let pr = db.get_pull_request(repo_name, 123).await?.unwrap();
+    assert_eq!(pr.opened_at, opened_at);
+    assert_eq!(pr.head_sha, "def456");
+    assert_eq!(pr.layer, 3);
+    println!("✅ Re-sync refreshes head SHA and layer, keeps opened_at");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_new_head_sha_drops_stale_signatures() -> Result<(), Box<dyn std::error::Error>> {
+    println!("🧪 Testing signatures are dropped when the head moves...");
+
+    let db = Database::new_in_memory().await?;
+    let repo_name = "BTCDecoded/consensus-proof";
+    db.create_pull_request(repo_name, 124, "abc123", 2).await?;
+    db.add_signature(repo_name, 124, "alice", "sig_alice").await?;
+
+    // Redelivery of the same head keeps them
+    db.create_pull_request(repo_name, 124, "abc123", 2).await?;
+    assert_eq!(db.get_pull_request(repo_name, 124).await?.unwrap().signatures.len(), 1);
+
+    db.create_pull_request(repo_name, 124, "def456", 2).await?;
+    assert!(db.get_pull_request(repo_name, 124).await?.unwrap().signatures.is_empty());
+    println!("✅ Signatures over the old head no longer count");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_concurrent_writes_do_not_fail_on_lock() -> Result<(), Box<dyn std::error::Error>> {
+    println!("🧪 Testing concurrent SQLite writes...");
+
+    // A file database so the pool's connections really contend for the write lock
+    let dir = tempfile::tempdir()?;
+    let url = format!("sqlite://{}", dir.path().join("governance.db").display());
+    let db = Database::new_production(&url).await?;
+    let repo_name = "BTCDecoded/consensus-proof";
+    db.create_pull_request(repo_name, 7, "abc123", 2).await?;
+
+    let mut writers = Vec::new();
+    for i in 0..50 {
+        let db = db.clone();
+        writers.push(tokio::spawn(async move {
+            db.log_governance_event(
+                "concurrency_probe",
+                Some(repo_name),
+                Some(7),
+                None,
+                &serde_json::json!({ "writer": i }),
+            )
+            .await?;
+            db.add_signature(repo_name, 7, &format!("maintainer{}", i % 10), "sig").await
+        }));
+    }
+    for writer in writers {
+        let result = writer.await?;
+        assert!(result.is_ok(), "concurrent write failed: {:?}", result.err());
+    }
+
+    let (events,): (i64,) =
+        sqlx::query_as("SELECT COUNT(*) FROM governance_events WHERE event_type = 'concurrency_probe'")
+            .fetch_one(db.pool().unwrap())
+            .await?;
+    assert_eq!(events, 50);
+    let pr = db.get_pull_request(repo_name, 7).await?.unwrap();
+    assert_eq!(pr.signatures.len(), 10);
+    println!("✅ 50 concurrent writers committed without lock errors");
+
+    Ok(())
+}
+
+/// PR numbers `GET /prs` lists for a derived status filter
+async fn listed(
+    db: &Database,
+    repo: Option<&str>,
+    status: Option<PullRequestStatus>,
+    limit: i64,
+    offset: i64,
+) -> Result<Vec<i32>, Box<dyn std::error::Error>> {
+    let prs = pr_status::list_pull_requests(
+        db,
+        repo,
+        status,
+        limit,
+        offset,
+        Utc::now(),
+        &ReviewPeriodPolicy::default(),
+        &EconomicQuorums::default(),
+    )
+    .await?;
+    Ok(prs.into_iter().map(|(pr, _)| pr.pr_number).collect())
+}
+
+#[tokio::test]
+async fn test_list_pull_requests_filters_and_paginates() -> Result<(), Box<dyn std::error::Error>> {
+    println!("🧪 Testing pull request listing...");
+
+    let db = Database::new_in_memory().await?;
+    let sdk = "BTCDecoded/developer-sdk";
+    let proof = "BTCDecoded/consensus-proof";
+
+    for name in ["alice", "bob", "carol", "dave", "erin"] {
+        sqlx::query("INSERT INTO maintainers (github_username, public_key, layer) VALUES (?, ?, 5)")
+            .bind(name)
+            .bind(format!("{}_key", name))
+            .execute(db.pool().unwrap())
+            .await?;
+    }
+    // Layer 5 at Tier 1 needs 3 signatures after 14 days
+    for (pr_number, opened_at) in [(1, "2024-01-01"), (2, "2024-01-02"), (3, "2024-01-03"), (4, "2024-01-04")] {
+        db.create_pull_request(sdk, pr_number, "abc123", 5).await?;
+        sqlx::query("UPDATE pull_requests SET opened_at = ? WHERE repo_name = ? AND pr_number = ?")
+            .bind(format!("{} 00:00:00", opened_at))
+            .bind(sdk)
+            .bind(pr_number)
+            .execute(db.pool().unwrap())
+            .await?;
+    }
+    db.create_pull_request(sdk, 5, "abc123", 5).await?;
+    db.create_pull_request(proof, 9, "abc123", 2).await?;
+    // PR 3 is Tier 3, which needs 5-of-5 and an economic node quorum
+    db.log_governance_event("pr_opened", Some(sdk), Some(3), None, &serde_json::json!({"tier": 3}))
+        .await?;
+    for signer in ["alice", "bob", "carol"] {
+        db.add_signature(sdk, 1, signer, "sig").await?;
+        db.add_signature(sdk, 3, signer, "sig").await?;
+    }
+    // Signers outside the layer don't count
+    for signer in ["alice", "bob", "mallory"] {
+        db.add_signature(sdk, 2, signer, "sig").await?;
+    }
+    db.transition_pull_request(sdk, 4, "pending", "merged").await?;
+
+    let numbers = |prs: Vec<governance_app::database::models::PullRequest>| {
+        prs.into_iter().map(|pr| pr.pr_number).collect::<Vec<_>>()
+    };
+
+    let all = db.list_pull_requests(None, None, 100, 0).await?;
+    assert_eq!(numbers(all), [1, 2, 3, 4, 5, 9]);
+    let sdk_only = db.list_pull_requests(Some(sdk), None, 100, 0).await?;
+    assert_eq!(numbers(sdk_only), [1, 2, 3, 4, 5]);
+    let pending = db.list_pull_requests(None, Some("pending"), 100, 0).await?;
+    assert_eq!(numbers(pending), [1, 2, 3, 5, 9]);
+    println!("✅ Listing is ordered by opened_at and filters by repo and stored status");
+
+    assert_eq!(listed(&db, Some(sdk), Some(PullRequestStatus::Mergeable), 100, 0).await?, [1]);
+    assert_eq!(listed(&db, None, Some(PullRequestStatus::Blocked), 100, 0).await?, [2, 3]);
+    assert_eq!(listed(&db, None, Some(PullRequestStatus::Open), 100, 0).await?, [5, 9]);
+    assert_eq!(listed(&db, None, Some(PullRequestStatus::Merged), 100, 0).await?, [4]);
+    println!("✅ Derived status follows the PR's tier and layer signers");
+
+    let page = db.list_pull_requests(Some(sdk), None, 2, 2).await?;
+    assert_eq!(numbers(page), [3, 4]);
+    assert_eq!(listed(&db, None, Some(PullRequestStatus::Blocked), 1, 1).await?, [3]);
+    let past_end = db.list_pull_requests(None, None, 10, 10).await?;
+    assert!(past_end.is_empty());
+    println!("✅ Pagination applies after filtering");
+
+    Ok(())
+}
@@ -2,6 +2,7 @@ use governance_app::database::Database;
 use governance_app::validation::*;
 use governance_app::enforcement::*;
 use governance_app::crypto::*;
+use governance_app::crypto::signatures::build_signing_message;
 use chrono::{DateTime, Utc, Duration};
 use secp256k1::{SecretKey, Secp256k1, PublicKey};
 use rand::rngs::OsRng;
@@ -31,7 +32,7 @@ async fn test_complete_pr_lifecycle() {
     for (username, secret_key, public_key) in &keypairs {
         public_keys.insert(username.clone(), public_key.to_string());
         
-        let message = format!("governance-signature:{}", username);
+        let message = build_signing_message(repo_name, pr_number, head_sha, 1);
         let signature = signature_manager.create_signature(&message, secret_key).unwrap();
         
         db.add_signature(repo_name, pr_number, username, &signature.to_string()).await.unwrap();
@@ -43,7 +44,7 @@ async fn test_complete_pr_lifecycle() {
     
     let (required, total) = ThresholdValidator::get_threshold_for_layer(layer);
     let result = multisig_manager.verify_multisig(
-        "governance-signature:test",
+        &build_signing_message(repo_name, pr_number, head_sha, 1),
         &signatures,
         &public_keys,
         (required, total),
@@ -186,7 +187,7 @@ async fn test_signature_threshold_validation_across_layers() {
         for (username, secret_key, public_key) in &keypairs {
             public_keys.insert(username.clone(), public_key.to_string());
             
-            let message = format!("governance-signature:{}", username);
+            let message = build_signing_message(&repo_name, pr_number, &head_sha, 1);
             let signature = create_test_signature_manager().create_signature(&message, secret_key).unwrap();
             
             db.add_signature(&repo_name, pr_number, username, &signature.to_string()).await.unwrap();
@@ -197,7 +198,7 @@ async fn test_signature_threshold_validation_across_layers() {
         let signatures: Vec<(String, String)> = pr.signatures.iter().map(|s| (s.signer.clone(), s.signature.clone())).collect();
         
         let result = create_test_multisig_manager().verify_multisig(
-            "governance-signature:test",
+            &build_signing_message(&repo_name, pr_number, &head_sha, 1),
             &signatures,
             &public_keys,
             (required, total),
@@ -274,7 +275,7 @@ async fn test_multi_pr_tracking_across_repos() {
         // Add some signatures
         let keypairs = generate_test_keypairs(2);
         for (username, secret_key, _) in &keypairs {
-            let message = format!("governance-signature:{}", username);
+            let message = build_signing_message(repo_name, pr_number, &head_sha, 1);
             let signature = create_test_signature_manager().create_signature(&message, secret_key).unwrap();
             
             db.add_signature(repo_name, pr_number, username, &signature.to_string()).await.unwrap();
@@ -313,7 +314,7 @@ async fn test_concurrent_signature_additions() {
         let username = username.clone();
         
         tokio::spawn(async move {
-            let message = format!("governance-signature:{}", username);
+            let message = build_signing_message(&repo_name, pr_number, head_sha, 1);
             let signature = create_test_signature_manager().create_signature(&message, secret_key).unwrap();
             
             db.add_signature(&repo_name, pr_number, &username, &signature.to_string()).await
@@ -71,7 +71,7 @@ async fn test_comment_governance_signature() {
     let payload = github_mocks::comment_created_payload("BTCDecoded/consensus-proof", 123, "alice", signature_body);
     
     // Test webhook processing
-    let result = governance_app::webhooks::comment::handle_comment_event(&db, &payload).await;
+    let result = governance_app::webhooks::comment::handle_comment_event(&db, &payload, None).await;
     assert!(result.is_ok());
     
     // Verify signature was added to database
@@ -90,7 +90,7 @@ async fn test_comment_empty_signature() {
     let payload = github_mocks::comment_created_payload("BTCDecoded/consensus-proof", 123, "alice", empty_signature_body);
     
     // Test webhook processing
-    let result = governance_app::webhooks::comment::handle_comment_event(&db, &payload).await;
+    let result = governance_app::webhooks::comment::handle_comment_event(&db, &payload, None).await;
     assert!(result.is_ok());
     
     // Verify no signature was added
@@ -108,7 +108,7 @@ async fn test_comment_non_governance() {
     let payload = github_mocks::comment_created_payload("BTCDecoded/consensus-proof", 123, "alice", regular_comment);
     
     // Test webhook processing
-    let result = governance_app::webhooks::comment::handle_comment_event(&db, &payload).await;
+    let result = governance_app::webhooks::comment::handle_comment_event(&db, &payload, None).await;
     assert!(result.is_ok());
     
     // Verify no signature was added
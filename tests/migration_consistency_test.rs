@@ -0,0 +1,110 @@
+//! Migration Consistency Tests
+//!
+//! SQLite and Postgres are migrated from separate directories, so these tests
+//! introspect both schemas after migration and compare the core tables against
+//! a shared expectation. The Postgres half only runs when
+//! `GOVERNANCE_TEST_POSTGRES_URL` is set.
+
+use governance_app::database::schema::{normalize_type, SHARED_SCHEMA};
+use governance_app::database::Database;
+use std::collections::BTreeMap;
+
+fn expected_columns(columns: &[(&str, &str)]) -> BTreeMap<String, String> {
+    columns
+        .iter()
+        .map(|(name, ty)| (name.to_string(), ty.to_string()))
+        .collect()
+}
+
+#[tokio::test]
+async fn test_sqlite_schema_matches_expectation() -> Result<(), Box<dyn std::error::Error>> {
+    println!("🧪 Testing SQLite schema against shared expectation...");
+
+    let database = Database::new_in_memory().await?;
+
+    for (table, columns) in SHARED_SCHEMA {
+        let actual = database.table_columns(table).await?;
+        assert_eq!(
+            actual,
+            expected_columns(columns),
+            "SQLite table '{}' drifted from the shared schema",
+            table
+        );
+        println!("✅ SQLite {} matches", table);
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_postgres_schema_matches_expectation() -> Result<(), Box<dyn std::error::Error>> {
+    let url = match std::env::var("GOVERNANCE_TEST_POSTGRES_URL") {
+        Ok(url) => url,
+        Err(_) => {
+            println!("⏭️ GOVERNANCE_TEST_POSTGRES_URL not set, skipping Postgres schema check");
+            return Ok(());
+        }
+    };
+
+    println!("🧪 Testing Postgres schema against shared expectation...");
+
+    let database = Database::new(&url).await?;
+    database.run_migrations().await?;
+
+    for (table, columns) in SHARED_SCHEMA {
+        let actual = database.table_columns(table).await?;
+        assert_eq!(
+            actual,
+            expected_columns(columns),
+            "Postgres table '{}' drifted from the shared schema",
+            table
+        );
+        println!("✅ Postgres {} matches", table);
+    }
+    database.verify_schema().await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_startup_schema_check_passes_after_migration() -> Result<(), Box<dyn std::error::Error>> {
+    let database = Database::new_in_memory().await?;
+    database.verify_schema().await?;
+    println!("✅ Startup schema check accepts a freshly migrated SQLite database");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_startup_schema_check_rejects_missing_table() -> Result<(), Box<dyn std::error::Error>> {
+    let dir = tempfile::tempdir()?;
+    let url = format!("sqlite://{}?mode=rwc", dir.path().join("drift.db").display());
+    let database = Database::new(&url).await?;
+    database.run_migrations().await?;
+
+    sqlx::query("DROP TABLE key_metadata")
+        .execute(database.get_sqlite_pool().unwrap())
+        .await?;
+    let error = database.verify_schema().await.unwrap_err().to_string();
+    assert!(error.contains("Table 'key_metadata' is missing on SQLite"), "{}", error);
+
+    sqlx::query("CREATE TABLE key_metadata (id INTEGER PRIMARY KEY)")
+        .execute(database.get_sqlite_pool().unwrap())
+        .await?;
+    let error = database.verify_schema().await.unwrap_err().to_string();
+    assert!(error.contains("'key_metadata' on SQLite drifted"), "{}", error);
+    println!("✅ Startup schema check fails loudly on drift");
+
+    Ok(())
+}
+
+#[test]
+fn test_type_normalization() {
+    assert_eq!(normalize_type("INTEGER"), "integer");
+    assert_eq!(normalize_type("bigint"), "integer");
+    assert_eq!(normalize_type("timestamp without time zone"), "timestamp");
+    assert_eq!(normalize_type("DATETIME"), "timestamp");
+    assert_eq!(normalize_type("jsonb"), "text");
+    assert_eq!(normalize_type("BOOLEAN"), "boolean");
+    assert_eq!(normalize_type("REAL"), "real");
+}
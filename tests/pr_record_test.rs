@@ -0,0 +1,141 @@
+//! PR Record API Tests
+//!
+//! Exercises `GET /prs/:repo/:number/record` against a seeded database
+
+use axum::body::{to_bytes, Body};
+use axum::http::{Request, StatusCode};
+use axum::routing::get;
+use axum::Router;
+use governance_app::config::AppConfig;
+use governance_app::crypto::signatures::{build_signing_message, SignatureManager, SignatureScheme};
+use governance_app::database::Database;
+use governance_app::metrics::Metrics;
+use governance_app::status::pr_record_endpoint;
+use serde_json::{json, Value};
+use tower::ServiceExt;
+
+const REPO: &str = "BTCDecoded/developer-sdk";
+
+async fn insert_maintainer(db: &Database, username: &str, public_key: &str) -> Result<(), Box<dyn std::error::Error>> {
+    sqlx::query("INSERT INTO maintainers (github_username, public_key, layer, active) VALUES (?, ?, 5, TRUE)")
+        .bind(username)
+        .bind(public_key)
+        .execute(db.pool().unwrap())
+        .await?;
+    Ok(())
+}
+
+async fn get_record(config: AppConfig, db: &Database, uri: &str) -> (StatusCode, Value) {
+    let app = Router::new()
+        .route("/prs/:repo/:number/record", get(pr_record_endpoint))
+        .with_state((config, db.clone(), Metrics::new()));
+    let response = app
+        .oneshot(Request::builder().uri(uri).body(Body::empty()).unwrap())
+        .await
+        .unwrap();
+    let status = response.status();
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    (status, serde_json::from_slice(&body).unwrap())
+}
+
+#[tokio::test]
+async fn test_pr_record_reports_seeded_history() -> Result<(), Box<dyn std::error::Error>> {
+    let db = Database::new_in_memory().await?;
+    db.create_pull_request(REPO, 42, "head-sha", 5).await?;
+    db.log_governance_event(
+        "pr_opened",
+        Some(REPO),
+        Some(42),
+        None,
+        &json!({ "tier": 2, "classification_confidence": 0.9, "classification_rationale": "Matched Feature rule" }),
+    )
+    .await?;
+    sqlx::query("UPDATE pull_requests SET opened_at = datetime('now', '-120 days')")
+        .execute(db.pool().unwrap())
+        .await?;
+
+    // alice signs the current head and tier, bob signs something else, carol isn't a maintainer
+    let signature_manager = SignatureManager::new();
+    let alice = signature_manager.generate_keypair()?;
+    let bob = signature_manager.generate_keypair()?;
+    insert_maintainer(&db, "alice", &alice.public_key.to_string()).await?;
+    insert_maintainer(&db, "bob", &bob.public_key.to_string()).await?;
+    let scheme = SignatureScheme::default();
+    let good = signature_manager.sign_with_scheme(&build_signing_message(REPO, 42, "head-sha", 2), &alice.secret_key, scheme)?;
+    let stale = signature_manager.sign_with_scheme(&build_signing_message(REPO, 42, "old-sha", 2), &bob.secret_key, scheme)?;
+    db.add_signature(REPO, 42, "alice", &good).await?;
+    db.add_signature(REPO, 42, "bob", &stale).await?;
+    db.add_signature(REPO, 42, "carol", "sig-c").await?;
+    db.log_governance_event("merge_blocked", Some(REPO), Some(42), None, &json!({ "reason": "insufficient_signatures" }))
+        .await?;
+    db.log_governance_event("emergency_activated", Some(REPO), Some(42), Some("alice"), &json!({ "tier": 1 }))
+        .await?;
+
+    let dir = tempfile::tempdir()?;
+    let key_path = dir.path().join("record-signing.key");
+    let signing_key = signature_manager.generate_keypair()?;
+    std::fs::write(&key_path, hex::encode(signing_key.secret_key.secret_bytes()))?;
+    let mut config = AppConfig::load().unwrap();
+    config.server_id = "governance-test".to_string();
+    config.record_signing_key_path = Some(key_path.to_string_lossy().to_string());
+
+    let (status, body) = get_record(config, &db, "/prs/BTCDecoded%2Fdeveloper-sdk/42/record").await;
+    assert_eq!(status, StatusCode::OK);
+
+    let record = &body["record"];
+    assert_eq!(record["repo_name"], REPO);
+    assert_eq!(record["pr_number"], 42);
+    assert_eq!(record["layer"], 5);
+    assert_eq!(record["head_sha"], "head-sha");
+    assert_eq!(record["governance_status"], "pending");
+
+    assert_eq!(record["classification"]["tier"], 2);
+    assert_eq!(record["classification"]["confidence"], 0.9);
+    assert_eq!(record["classification"]["rationale"], "Matched Feature rule");
+
+    let verification: Vec<(&str, &str)> = record["signatures"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|s| (s["signer"].as_str().unwrap(), s["verification"].as_str().unwrap()))
+        .collect();
+    assert_eq!(
+        verification,
+        vec![("alice", "verified"), ("bob", "invalid"), ("carol", "unknown_signer")]
+    );
+
+    let review = &record["review"];
+    assert_eq!(review["signatures_verified"], 1);
+    assert_eq!(review["review_period_met"], true);
+    assert!(review["signatures_required"].as_u64().unwrap() > 1);
+    assert_eq!(review["threshold_met"], false);
+
+    let decisions: Vec<&str> = record["decisions"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|e| e["event_type"].as_str().unwrap())
+        .collect();
+    assert_eq!(decisions, vec!["pr_opened", "merge_blocked", "emergency_activated"]);
+    assert_eq!(record["emergency"]["events"].as_array().unwrap().len(), 1);
+    assert_eq!(record["emergency"]["events"][0]["event_type"], "emergency_activated");
+
+    let attestation = &body["attestation"];
+    assert_eq!(attestation["server_id"], "governance-test");
+    assert!(attestation["record_hash"].as_str().unwrap().starts_with("sha256:"));
+    assert_eq!(attestation["public_key"], hex::encode(signing_key.public_key.serialize()));
+    assert!(attestation["signature"].is_string());
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_pr_record_unknown_pr_is_not_found() -> Result<(), Box<dyn std::error::Error>> {
+    let db = Database::new_in_memory().await?;
+    db.create_pull_request(REPO, 42, "head-sha", 5).await?;
+
+    let (status, body) = get_record(AppConfig::load().unwrap(), &db, "/prs/BTCDecoded%2Fdeveloper-sdk/43/record").await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    assert_eq!(body["code"], "NOT_FOUND");
+    assert_eq!(body["error"], "Pull request not found: BTCDecoded/developer-sdk#43");
+    Ok(())
+}
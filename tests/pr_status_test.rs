@@ -0,0 +1,162 @@
+//! PR Status API Tests
+//!
+//! Exercises `GET /prs/:repo/:number/status` against a seeded database
+
+use axum::body::{to_bytes, Body};
+use axum::http::{Request, StatusCode};
+use axum::routing::get;
+use axum::Router;
+use governance_app::config::AppConfig;
+use governance_app::crypto::signatures::SignatureManager;
+use governance_app::database::Database;
+use governance_app::economic_nodes::types::*;
+use governance_app::economic_nodes::veto::VetoManager;
+use governance_app::economic_nodes::EconomicNodeRegistry;
+use governance_app::metrics::Metrics;
+use governance_app::status::pr_status_endpoint;
+use serde_json::{json, Value};
+use tower::ServiceExt;
+
+const REPO: &str = "BTCDecoded/developer-sdk";
+
+async fn insert_maintainer(db: &Database, username: &str, layer: i32) -> Result<(), Box<dyn std::error::Error>> {
+    sqlx::query("INSERT INTO maintainers (github_username, public_key, layer, active) VALUES (?, ?, ?, TRUE)")
+        .bind(username)
+        .bind(format!("{}_key", username))
+        .bind(layer)
+        .execute(db.pool().unwrap())
+        .await?;
+    Ok(())
+}
+
+/// Register an active custodian and have it veto the PR
+async fn seed_veto(db: &Database, pr_id: i32) -> Result<(), Box<dyn std::error::Error>> {
+    let pool = db.pool().unwrap().clone();
+    let signature_manager = SignatureManager::new();
+    let keypair = signature_manager.generate_keypair()?;
+    let registry = EconomicNodeRegistry::new(pool.clone());
+    let proof = QualificationProof {
+        node_type: NodeType::Custodian,
+        hashpower_proof: None,
+        holdings_proof: Some(HoldingsProof {
+            addresses: vec!["bc1qexample".to_string()],
+            total_btc: 12_000.0,
+            signature_challenge: "challenge".to_string(),
+        }),
+        volume_proof: None,
+        contact_info: ContactInfo {
+            entity_name: "Test Custodian".to_string(),
+            contact_email: "ops@custodian.example".to_string(),
+            website: None,
+            github_username: None,
+        },
+    };
+    let node_id = registry
+        .register_economic_node(
+            NodeType::Custodian,
+            "Test Custodian",
+            &keypair.public_key.to_string(),
+            &proof,
+            None,
+        )
+        .await?;
+    registry.update_node_status(node_id, NodeStatus::Active).await?;
+
+    let message = format!("PR #{} veto signal from Test Custodian", pr_id);
+    let signature = signature_manager.create_governance_signature(&message, &keypair)?;
+    VetoManager::new(pool)
+        .collect_veto_signal(pr_id, node_id, SignalType::Veto, &signature, "Breaks wallet compatibility")
+        .await?;
+    Ok(())
+}
+
+async fn get_status(db: &Database, uri: &str) -> (StatusCode, Value) {
+    let app = Router::new()
+        .route("/prs/:repo/:number/status", get(pr_status_endpoint))
+        .with_state((AppConfig::load().unwrap(), db.clone(), Metrics::new()));
+    let response = app
+        .oneshot(Request::builder().uri(uri).body(Body::empty()).unwrap())
+        .await
+        .unwrap();
+    let status = response.status();
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    (status, serde_json::from_slice(&body).unwrap())
+}
+
+#[tokio::test]
+async fn test_pr_status_reports_signatures_review_veto_and_decision() -> Result<(), Box<dyn std::error::Error>> {
+    let db = Database::new_in_memory().await?;
+    db.create_pull_request(REPO, 42, "head-sha", 5).await?;
+    db.log_governance_event("pr_opened", Some(REPO), Some(42), None, &json!({ "tier": 3 }))
+        .await?;
+    sqlx::query("UPDATE pull_requests SET opened_at = datetime('now', '-120 days')")
+        .execute(db.pool().unwrap())
+        .await?;
+
+    insert_maintainer(&db, "alice", 5).await?;
+    insert_maintainer(&db, "bob", 5).await?;
+    insert_maintainer(&db, "mallory", 3).await?;
+    db.add_signature(REPO, 42, "alice", "sig-a").await?;
+    db.add_signature(REPO, 42, "mallory", "sig-m").await?;
+
+    let pr = db.get_pull_request(REPO, 42).await?.unwrap();
+    seed_veto(&db, pr.id).await?;
+
+    let (status, body) = get_status(&db, "/prs/BTCDecoded%2Fdeveloper-sdk/42/status").await;
+    assert_eq!(status, StatusCode::OK);
+
+    assert_eq!(body["repo_name"], REPO);
+    assert_eq!(body["pr_number"], 42);
+    assert_eq!(body["layer"], 5);
+    assert_eq!(body["head_sha"], "head-sha");
+    assert_eq!(body["tier"], 3);
+    assert_eq!(body["emergency_mode"], false);
+
+    // Only maintainers of the PR's layer count
+    let signatures = &body["signatures"];
+    assert_eq!(signatures["signers"], json!(["alice"]));
+    assert_eq!(signatures["pending"], json!(["bob"]));
+    assert_eq!(signatures["collected"], 1);
+    assert!(signatures["required"].as_u64().unwrap() > 1);
+    assert!(signatures["total"].as_u64().is_some());
+
+    let review = &body["review"];
+    assert_eq!(review["met"], true);
+    assert!(review["elapsed_days"].as_i64().unwrap() >= 119);
+    assert!(review["required_days"].as_i64().is_some());
+    assert!(review["opened_at"].is_string());
+    assert!(review["earliest_merge"].is_string());
+
+    let veto = &body["veto"];
+    assert_eq!(veto["active"], true);
+    assert!(veto["quorum"].as_u64().is_some());
+    assert!(veto["signal_count"].as_u64().is_some());
+    assert!(veto["quorum_met"].is_boolean());
+    assert_eq!(veto["breakdown"]["economic"]["veto_percent"], 100.0);
+    assert_eq!(veto["breakdown"]["active_signals"], 1);
+
+    let decision = &body["decision"];
+    assert_eq!(decision["blocked"], true);
+    let reasons = decision["reasons"].as_array().unwrap();
+    assert!(reasons.contains(&json!({
+        "reason": "insufficient_signatures",
+        "have": 1,
+        "need": signatures["required"]
+    })));
+    assert!(reasons.contains(&json!({ "reason": "economic_veto_active" })));
+    assert!(!reasons.contains(&json!({ "reason": "review_period_not_met" })));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_pr_status_unknown_pr_is_not_found() -> Result<(), Box<dyn std::error::Error>> {
+    let db = Database::new_in_memory().await?;
+    db.create_pull_request(REPO, 42, "head-sha", 5).await?;
+
+    let (status, body) = get_status(&db, "/prs/BTCDecoded%2Fdeveloper-sdk/43/status").await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    assert_eq!(body["code"], "NOT_FOUND");
+    assert_eq!(body["error"], "Pull request not found: BTCDecoded/developer-sdk#43");
+    Ok(())
+}
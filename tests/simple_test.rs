@@ -140,61 +140,120 @@ async fn test_status_check_generation() -> Result<(), Box<dyn std::error::Error>
 async fn test_merge_blocking_logic() -> Result<(), Box<dyn std::error::Error>> {
     println!("🧪 Testing merge blocking logic...");
 
-    use governance_app::enforcement::merge_block::MergeBlocker;
+    use governance_app::enforcement::decision_log::DecisionLogger;
+    use governance_app::enforcement::merge_block::{MergeBlocker, MergeRequirements};
 
-    let blocker = MergeBlocker::new(None);
+    let blocker = MergeBlocker::new(None, DecisionLogger::new(false, false, None), false);
 
     // Test case: All requirements met
-    let should_block_all_met = MergeBlocker::should_block_merge(
-        true,  // review period met
-        true,  // signatures met
-        false, // economic veto active
-        1,     // tier
-        false, // emergency mode
-    )
-    .unwrap();
+    let should_block_all_met = MergeBlocker::evaluate_merge(&MergeRequirements {
+        review_period_met: true,
+        current_signatures: 3,
+        required_signatures: 3,
+        economic_veto_active: false,
+        economic_quorum_met: true,
+        cross_layer_synced: true,
+        tier: 1,
+        emergency_mode: false,
+    })
+    .blocked;
     assert!(!should_block_all_met);
     println!("✅ Merge not blocked when all requirements met");
 
     // Test case: Review period not met
-    let should_block_review = MergeBlocker::should_block_merge(
-        false, // review period not met
-        true,  // signatures met
-        false, // economic veto active
-        1,     // tier
-        false, // emergency mode
-    )
-    .unwrap();
+    let should_block_review = MergeBlocker::evaluate_merge(&MergeRequirements {
+        review_period_met: false,
+        current_signatures: 3,
+        required_signatures: 3,
+        economic_veto_active: false,
+        economic_quorum_met: true,
+        cross_layer_synced: true,
+        tier: 1,
+        emergency_mode: false,
+    })
+    .blocked;
     assert!(should_block_review);
     println!("✅ Merge blocked when review period not met");
 
     // Test case: Signatures not met
-    let should_block_signatures = MergeBlocker::should_block_merge(
-        true,  // review period met
-        false, // signatures not met
-        false, // economic veto active
-        1,     // tier
-        false, // emergency mode
-    )
-    .unwrap();
+    let should_block_signatures = MergeBlocker::evaluate_merge(&MergeRequirements {
+        review_period_met: true,
+        current_signatures: 2,
+        required_signatures: 3,
+        economic_veto_active: false,
+        economic_quorum_met: true,
+        cross_layer_synced: true,
+        tier: 1,
+        emergency_mode: false,
+    })
+    .blocked;
     assert!(should_block_signatures);
     println!("✅ Merge blocked when signatures not met");
 
     // Test case: Economic veto active
-    let should_block_veto = MergeBlocker::should_block_merge(
-        true,  // review period met
-        true,  // signatures met
-        true,  // economic veto active
-        3,     // tier 3 (consensus-adjacent)
-        false, // emergency mode
-    )
-    .unwrap();
+    let should_block_veto = MergeBlocker::evaluate_merge(&MergeRequirements {
+        review_period_met: true,
+        current_signatures: 3,
+        required_signatures: 3,
+        economic_veto_active: true,
+        economic_quorum_met: true,
+        cross_layer_synced: true,
+        tier: 3,
+        emergency_mode: false,
+    })
+    .blocked;
     assert!(should_block_veto);
     println!("✅ Merge blocked when economic veto active");
 
     Ok(())
 }
 
+#[tokio::test]
+async fn test_economic_quorum_blocking() -> Result<(), Box<dyn std::error::Error>> {
+    println!("🧪 Testing economic node quorum blocking...");
+
+    use governance_app::enforcement::merge_block::{BlockReason, MergeBlocker, MergeRequirements};
+    use governance_app::validation::threshold::ThresholdValidator;
+
+    // Tier 3 PR with zero economic node signals
+    let quorum = ThresholdValidator::get_tier_economic_quorum(3);
+    assert_eq!(quorum, 3);
+    let quorum_met = ThresholdValidator::economic_quorum_met(3, 0, quorum);
+    assert!(!quorum_met);
+
+    let requirements = MergeRequirements {
+        review_period_met: true,
+        current_signatures: 3,
+        required_signatures: 3,
+        economic_veto_active: false,
+        economic_quorum_met: quorum_met,
+        cross_layer_synced: true,
+        tier: 3,
+        emergency_mode: false,
+    };
+    let decision = MergeBlocker::evaluate_merge(&requirements);
+    assert!(decision.blocked);
+    assert_eq!(decision.reasons, vec![BlockReason::EconomicQuorumNotMet]);
+    assert!(decision.summary().contains("Economic node quorum not met"));
+    assert!(!decision.summary().contains("veto"));
+    println!("✅ Tier 3 PR blocked on economic quorum with zero signals");
+
+    // Quorum reached unblocks the PR
+    let quorum_met = ThresholdValidator::economic_quorum_met(3, 3, quorum);
+    let decision = MergeBlocker::evaluate_merge(&MergeRequirements {
+        economic_quorum_met: quorum_met,
+        ..requirements
+    });
+    assert!(!decision.blocked);
+    println!("✅ Tier 3 PR allowed once quorum reached");
+
+    // Lower tiers never require economic input
+    assert!(ThresholdValidator::economic_quorum_met(2, 0, ThresholdValidator::get_tier_economic_quorum(2)));
+    println!("✅ Tier 2 PR does not require economic quorum");
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_threshold_validation() -> Result<(), Box<dyn std::error::Error>> {
     println!("🧪 Testing threshold validation...");
@@ -380,16 +439,19 @@ async fn test_complete_governance_workflow() -> Result<(), Box<dyn std::error::E
     println!("✅ Status checks generated");
 
     // 4. Test merge blocking
-    use governance_app::enforcement::merge_block::MergeBlocker;
-
-    let should_block = MergeBlocker::should_block_merge(
-        true,  // review period met
-        true,  // signatures met
-        false, // no economic veto
-        tier,  // tier
-        false, // emergency mode
-    )
-    .unwrap();
+    use governance_app::enforcement::merge_block::{MergeBlocker, MergeRequirements};
+
+    let should_block = MergeBlocker::evaluate_merge(&MergeRequirements {
+        review_period_met: true,
+        current_signatures: 3,
+        required_signatures: 3,
+        economic_veto_active: false,
+        economic_quorum_met: true,
+        cross_layer_synced: true,
+        tier,
+        emergency_mode: false,
+    })
+    .blocked;
 
     assert!(!should_block);
     println!("✅ PR can be merged when all requirements met");
@@ -181,14 +181,14 @@ async fn test_multisig_duplicate_signers() {
         (username.clone(), signature.to_string()),
     ];
     
-    // This should still work - duplicate signatures are allowed
+    // A signer counts once no matter how often they sign
     let result = multisig_manager.verify_multisig(
         message,
         &signatures,
         &public_keys,
         (2, 2),
     );
-    assert!(result.is_ok());
+    assert!(result.is_err());
 }
 
 #[tokio::test]
@@ -211,13 +211,21 @@ async fn test_multisig_missing_keys() {
         (username.clone(), signature.to_string())
     }).collect();
     
-    // This should fail because we don't have the public key for the third signer
+    // The third signer has no known key and is ignored; the other two meet 2-of-3
     let result = multisig_manager.verify_multisig(
         message,
         &signatures,
         &public_keys,
         (2, 3),
     );
+    assert!(result.is_ok());
+
+    let result = multisig_manager.verify_multisig(
+        message,
+        &signatures,
+        &public_keys,
+        (3, 3),
+    );
     assert!(result.is_err());
 }
 
@@ -1,5 +1,6 @@
 use governance_app::validation::*;
 use governance_app::validation::signatures::SignatureValidator;
+use governance_app::crypto::signatures::build_signing_message;
 use governance_app::validation::cross_layer::CrossLayerValidator;
 use governance_app::validation::emergency::*;
 use chrono::{DateTime, Utc, Duration};
@@ -180,7 +181,7 @@ async fn test_multisig_threshold_validation() {
     }
     
     // Create signatures
-    let message = "governance-signature:test";
+    let message = build_signing_message("BTCDecoded/consensus-proof", 123, "abc123", 1);
     let signatures: Vec<(String, String)> = keypairs.iter().map(|(username, secret_key, _)| {
         let signature = secp.sign_ecdsa(
             &secp256k1::Message::from_slice(&sha2::Sha256::digest(message.as_bytes())).unwrap(),
@@ -190,13 +191,13 @@ async fn test_multisig_threshold_validation() {
     }).collect();
     
     // Test 2-of-3 threshold
-    let result = validator.verify_multisig_threshold(&signatures, (2, 3), &maintainer_keys);
+    let result = validator.verify_multisig_threshold(&message, &signatures, (2, 3), &maintainer_keys);
     assert!(result.is_ok());
     assert!(result.unwrap());
     
     // Test insufficient signatures
     let insufficient_signatures = &signatures[0..1];
-    let result = validator.verify_multisig_threshold(insufficient_signatures, (2, 3), &maintainer_keys);
+    let result = validator.verify_multisig_threshold(&message, insufficient_signatures, (2, 3), &maintainer_keys);
     assert!(result.is_ok());
     assert!(!result.unwrap());
 }